@@ -0,0 +1,157 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use gesture_universe::{
+    pipeline::recognizer::common::{INPUT_SIZE, prepare_rotated_crop},
+    types::{Frame, PixelLayout},
+};
+
+const WIDTH: u32 = 1920;
+const HEIGHT: u32 = 1080;
+const ITERATIONS: u32 = 200;
+/// A hand crop as the tracker hands it over: off center, tilted, and
+/// hanging past the bottom of the frame.
+const CENTER: (f32, f32) = (1210.5, 870.25);
+const SIDE: f32 = 520.0;
+const ANGLE: f32 = 0.61;
+/// In 8-bit channel steps.
+const MAX_CHANNEL_DELTA: f32 = 1.0;
+
+/// Times `prepare_rotated_crop` against the per-pixel loop it replaced on a
+/// fixed 1080p frame, and fails if their crops differ by more than one
+/// channel step anywhere. Pass an iteration count to override the default of
+/// 200.
+fn main() {
+    let iterations = std::env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse::<u32>().ok())
+        .unwrap_or(ITERATIONS)
+        .max(1);
+    let frame = test_frame();
+
+    let (crop, _) =
+        prepare_rotated_crop(&frame, CENTER, SIDE, ANGLE, INPUT_SIZE).expect("frame has its size");
+    let reference = reference_crop(&frame, CENTER, SIDE, ANGLE, INPUT_SIZE);
+    let delta = crop
+        .iter()
+        .zip(&reference)
+        .map(|(a, b)| (a - b).abs() * 255.0)
+        .fold(0.0, f32::max);
+    println!("max channel delta {delta:.4}");
+    assert!(
+        delta <= MAX_CHANNEL_DELTA,
+        "crop drifted {delta} channel steps from the reference"
+    );
+
+    let reference_time = time(iterations, || {
+        black_box(reference_crop(&frame, CENTER, SIDE, ANGLE, INPUT_SIZE));
+    });
+    let crop_time = time(iterations, || {
+        black_box(prepare_rotated_crop(&frame, CENTER, SIDE, ANGLE, INPUT_SIZE).unwrap());
+    });
+    println!("{iterations} crops of {INPUT_SIZE}x{INPUT_SIZE} from {WIDTH}x{HEIGHT}");
+    for (name, [median, mean]) in [("per-pixel", reference_time), ("stepped", crop_time)] {
+        println!(
+            "{name:>9}: median {:.3} ms, mean {:.3} ms",
+            median.as_secs_f64() * 1000.0,
+            mean.as_secs_f64() * 1000.0
+        );
+    }
+    println!(
+        "speedup {:.2}x",
+        reference_time[0].as_secs_f64() / crop_time[0].as_secs_f64()
+    );
+}
+
+/// Median and mean of `iterations` runs, after a few to warm up.
+fn time(iterations: u32, mut run: impl FnMut()) -> [Duration; 2] {
+    for _ in 0..5 {
+        run();
+    }
+    let mut samples: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .collect();
+    samples.sort();
+    let mean = samples.iter().sum::<Duration>() / iterations;
+    [samples[samples.len() / 2], mean]
+}
+
+/// Smooth gradients with a hard-edged checkerboard on top, so both gentle
+/// and abrupt changes between neighbouring pixels are sampled.
+fn test_frame() -> Frame {
+    let mut pixels = Vec::with_capacity(PixelLayout::Rgb.buffer_len(WIDTH, HEIGHT));
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let check = if (x / 37 + y / 29) % 2 == 0 { 60 } else { 0 };
+            pixels.push(((x * 195 / WIDTH) + check) as u8);
+            pixels.push(((y * 195 / HEIGHT) + check) as u8);
+            pixels.push((((x + y) % 256) as u8) / 2 + check as u8);
+        }
+    }
+    let now = Instant::now();
+    Frame {
+        pixels: pixels.into(),
+        layout: PixelLayout::Rgb,
+        width: WIDTH,
+        height: HEIGHT,
+        timestamp: now,
+        acquired_at: now,
+    }
+}
+
+/// The crop as it used to be taken: the full transform for every output
+/// pixel and a bounds-checked fetch for each of the four texels.
+fn reference_crop(
+    frame: &Frame,
+    center: (f32, f32),
+    side: f32,
+    angle: f32,
+    output_size: u32,
+) -> Vec<f32> {
+    let mut data = Vec::with_capacity(output_size as usize * output_size as usize * 3);
+    let half = output_size as f32 / 2.0;
+    let scale = side / output_size as f32;
+    let (sin, cos) = angle.sin_cos();
+    for y in 0..output_size {
+        let dy = (y as f32 + 0.5 - half) * scale;
+        for x in 0..output_size {
+            let dx = (x as f32 + 0.5 - half) * scale;
+            let src_x = center.0 + dx * cos - dy * sin;
+            let src_y = center.1 + dx * sin + dy * cos;
+            data.extend_from_slice(&sample_rgb(frame, src_x, src_y));
+        }
+    }
+    data
+}
+
+fn sample_rgb(frame: &Frame, x: f32, y: f32) -> [f32; 3] {
+    let x0 = x.floor();
+    let y0 = y.floor();
+    let (w, h) = (frame.width as i32, frame.height as i32);
+    let fetch = |cx: f32, cy: f32| -> [f32; 3] {
+        let (ix, iy) = (cx as i32, cy as i32);
+        if ix < 0 || iy < 0 || ix >= w || iy >= h {
+            return [0.0, 0.0, 0.0];
+        }
+        let idx = ((iy as u32 * frame.width + ix as u32) as usize) * frame.layout.channels();
+        [
+            frame.pixels[idx] as f32 / 255.0,
+            frame.pixels[idx + 1] as f32 / 255.0,
+            frame.pixels[idx + 2] as f32 / 255.0,
+        ]
+    };
+
+    let (fx, fy) = (x - x0, y - y0);
+    let c00 = fetch(x0, y0);
+    let c10 = fetch(x0 + 1.0, y0);
+    let c01 = fetch(x0, y0 + 1.0);
+    let c11 = fetch(x0 + 1.0, y0 + 1.0);
+    let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+    [0, 1, 2].map(|c| lerp(lerp(c00[c], c10[c], fx), lerp(c01[c], c11[c], fx), fy))
+}
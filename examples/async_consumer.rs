@@ -0,0 +1,60 @@
+use anyhow::{Result, anyhow};
+use crossbeam_channel::{bounded, unbounded};
+use gesture_universe::{
+    GestureEvent, RecognizerBackend, available_cameras, pipeline::MetricsHandle,
+    start_camera_stream, start_recognizer_async,
+};
+use tokio::sync::broadcast::error::RecvError;
+
+/// Opens the first camera and awaits results and gesture events on a tokio
+/// runtime, the way an async bot embedding the library would.
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> Result<()> {
+    env_logger::init();
+
+    let camera = available_cameras()?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("no camera available"))?;
+    let backend = RecognizerBackend::default().with_model_paths_from_env();
+    let metrics = MetricsHandle::default();
+    let (frame_tx, frame_rx) = bounded(1);
+    let _camera = start_camera_stream(
+        camera.index,
+        None,
+        frame_tx,
+        metrics.clone(),
+        backend.config().mirror,
+    )?;
+    // Kept so the recognizer can be sent a new config; unused here.
+    let (_control_tx, control_rx) = unbounded();
+    let gestures = start_recognizer_async(backend, frame_rx, control_rx, metrics);
+    let (mut results, mut events) = (gestures.results, gestures.events);
+
+    println!("等待手势，按 Ctrl-C 退出");
+    let mut last_label = String::new();
+    loop {
+        tokio::select! {
+            changed = results.changed() => {
+                if changed.is_err() {
+                    break;
+                }
+                let label = results.borrow_and_update().as_ref().map(|result| result.label());
+                // Results come at frame rate; only print when the label moves.
+                if let Some(label) = label.filter(|label| *label != last_label) {
+                    println!("{label}");
+                    last_label = label;
+                }
+            }
+            event = events.recv() => match event {
+                Ok(GestureEvent::Started(kind)) => {
+                    println!("开始 {}{}", kind.emoji(), kind.display_name());
+                }
+                Ok(_) => {}
+                Err(RecvError::Lagged(skipped)) => println!("跳过了 {skipped} 个事件"),
+                Err(RecvError::Closed) => break,
+            },
+        }
+    }
+    Ok(())
+}
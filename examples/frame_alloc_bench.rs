@@ -0,0 +1,120 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Instant,
+};
+
+use gesture_universe::types::{self, Frame, FramePool, PixelLayout};
+
+/// Counts every heap allocation so the two frame paths can be compared.
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size as u64, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const WIDTH: u32 = 1920;
+const HEIGHT: u32 = 1080;
+const FRAMES: u32 = 300;
+/// Frames kept alive at once, roughly one per pipeline stage.
+const IN_FLIGHT: usize = 4;
+
+/// Simulates capture plus the UI's BGRA conversion at 1080p, first the way
+/// frames used to be handled, then with the frame pool. Pass a frame count to
+/// override the default of 300.
+fn main() {
+    let frames = std::env::args()
+        .nth(1)
+        .and_then(|arg| arg.parse::<u32>().ok())
+        .unwrap_or(FRAMES);
+    let len = WIDTH as usize * HEIGHT as usize * 4;
+    println!("{frames} frames at {WIDTH}x{HEIGHT}");
+
+    // Before: a fresh Vec per captured frame, cloned and then swapped for GPUI.
+    measure("before", frames, |idx| {
+        let rgba = vec![(idx % 251) as u8; len];
+        let frame = make_frame(rgba.into());
+        let mut image = frame.pixels.to_vec();
+        for px in image.chunks_exact_mut(4) {
+            px.swap(0, 2);
+        }
+        (frame, image)
+    });
+
+    // After: pooled capture buffers and a single swapping copy.
+    let pool = FramePool::new(IN_FLIGHT + 1);
+    measure("after", frames, |idx| {
+        let mut rgba = pool.take(len);
+        rgba.fill((idx % 251) as u8);
+        let frame = make_frame(rgba);
+        let mut image = Vec::with_capacity(frame.pixels.len());
+        image.extend(
+            frame
+                .pixels
+                .chunks_exact(4)
+                .flat_map(|px| [px[2], px[1], px[0], px[3]]),
+        );
+        (frame, image)
+    });
+    println!("pool allocated {} buffers in total", pool.allocations());
+}
+
+fn make_frame(rgba: types::FrameBuffer) -> Frame {
+    let now = Instant::now();
+    Frame {
+        pixels: rgba,
+        layout: PixelLayout::Rgba,
+        width: WIDTH,
+        height: HEIGHT,
+        timestamp: now,
+        acquired_at: now,
+    }
+}
+
+fn measure<F>(label: &str, frames: u32, mut step: F)
+where
+    F: FnMut(u32) -> (Frame, Vec<u8>),
+{
+    let mut in_flight = std::collections::VecDeque::with_capacity(IN_FLIGHT + 1);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let start = Instant::now();
+
+    for idx in 0..frames {
+        in_flight.push_back(black_box(step(idx)));
+        if in_flight.len() > IN_FLIGHT {
+            in_flight.pop_front();
+        }
+    }
+
+    let elapsed = start.elapsed();
+    let frames = f64::from(frames.max(1));
+    let allocations = (ALLOCATIONS.load(Ordering::Relaxed) - allocations) as f64 / frames;
+    let megabytes =
+        (ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes) as f64 / frames / (1024.0 * 1024.0);
+    println!(
+        "{label:>6}: {allocations:.2} allocations/frame, {megabytes:.2} MiB/frame, {:.2} ms/frame",
+        elapsed.as_secs_f64() * 1000.0 / frames
+    );
+}
@@ -1,223 +1,60 @@
-#[path = "../src/gesture.rs"]
-mod gesture;
-#[allow(dead_code)]
-#[path = "../src/model_download.rs"]
-mod model_download;
-#[allow(dead_code)]
-#[path = "../src/pipeline/recognizer/common.rs"]
-mod recognizer_common;
-#[allow(dead_code)]
-#[path = "../src/types.rs"]
-mod types;
-
-use anyhow::{Context, Result, anyhow};
-use gesture::GestureClassifier;
-use std::path::PathBuf;
-use types::Frame;
-
-use ort::{
-    session::{Session, builder::GraphOptimizationLevel},
-    value::Tensor as OrtTensor,
+use anyhow::{Context, Result};
+use gesture_universe::pipeline::{
+    DEFAULT_IMAGE_INTERVAL, ImageSequenceSource, LabelCsvWriter, MetricsHandle, RecognizerBackend,
+    run_image_sequence,
 };
+use std::path::PathBuf;
 
-type Model = Session;
-type InputTensor = OrtTensor<f32>;
-
+/// Runs every image of a directory (`demo` by default) through the app's full
+/// pipeline, palm detector included. `--csv <file>` also writes one
+/// `filename,kind,confidence,handedness` row per image.
 fn main() -> Result<()> {
     env_logger::init();
 
+    let mut dir = PathBuf::from("demo");
+    let mut csv_path = None;
     let mut args = std::env::args().skip(1);
-    let mut image_paths: Vec<PathBuf> = args.by_ref().map(PathBuf::from).collect();
-    if image_paths.is_empty() {
-        image_paths = demo_images()?;
-    }
-
-    if image_paths.is_empty() {
-        anyhow::bail!("未找到可用的测试图片");
+    while let Some(arg) = args.next() {
+        if arg == "--csv" {
+            csv_path = Some(PathBuf::from(
+                args.next().context("--csv 需要输出文件路径")?,
+            ));
+        } else {
+            dir = PathBuf::from(arg);
+        }
     }
 
-    let model_path = model_download::default_handpose_estimator_model_path();
-    model_download::ensure_handpose_estimator_model_ready(&model_path, |_evt| {})?;
-    let mut model = HandposeModel::new(&model_path)?;
-    let mut classifier = GestureClassifier::new();
-
-    println!(
-        "使用模型 {} 对 {} 张图片进行手势分类",
-        model_path.display(),
-        image_paths.len()
-    );
-
-    for path in image_paths {
-        let frame = load_frame(&path)?;
-        let output = model
-            .infer_landmarks(&frame)
-            .with_context(|| format!("无法推理 {}", path.display()))?;
-
-        if output.confidence < 0.2 {
+    let source = ImageSequenceSource::open(&dir, DEFAULT_IMAGE_INTERVAL)?;
+    let mut csv = csv_path
+        .as_deref()
+        .map(LabelCsvWriter::create)
+        .transpose()?;
+    println!("对 {} 张图片进行手势识别", source.paths().len());
+
+    run_image_sequence(
+        RecognizerBackend::default().with_model_paths_from_env(),
+        &source,
+        MetricsHandle::default(),
+        |path, result| {
+            let handedness = result
+                .detail
+                .as_ref()
+                .map_or("--", |detail| detail.handedness.label());
             println!(
-                "{} -> 未检测到手 (置信度 {:.0}%)",
+                "{} -> {} | {:.0}% | {handedness}",
                 path.display(),
-                output.confidence * 100.0
+                result.label(),
+                result.confidence * 100.0
             );
-            continue;
-        }
-
-        let detail = classifier.classify(
-            &output.raw_landmarks,
-            &output.projected_landmarks,
-            output.confidence,
-            output.handedness,
-            frame.timestamp,
-        );
+            if let Some(csv) = csv.as_mut() {
+                csv.write(path, result)?;
+            }
+            Ok(())
+        },
+    )?;
 
-        if let Some(detail) = detail {
-            let finger_summary = finger_states_to_text(&detail.finger_states);
-            println!(
-                "{} -> {} | {:.0}% | {} | 状态: {} | 手指: {}",
-                path.display(),
-                format!(
-                    "{}{}",
-                    detail.primary.emoji(),
-                    detail.primary.display_name()
-                ),
-                output.confidence * 100.0,
-                detail.handedness.label(),
-                detail.motion.label(),
-                finger_summary
-            );
-        } else {
-            println!(
-                "{} -> 检测到手，但无法稳定识别手势 ({:.0}%)",
-                path.display(),
-                output.confidence * 100.0
-            );
-        }
+    if let Some(csv) = csv {
+        csv.finish()?;
     }
-
     Ok(())
 }
-
-struct HandposeModel {
-    model: Model,
-}
-
-impl HandposeModel {
-    fn new(model_path: &PathBuf) -> Result<Self> {
-        model_download::ensure_handpose_estimator_model_ready(model_path, |_evt| {})?;
-
-        let model = Session::builder()?
-            .with_optimization_level(GraphOptimizationLevel::Level3)?
-            .with_intra_threads(2)?
-            .commit_from_file(model_path)
-            .with_context(|| format!("failed to load model from {}", model_path.display()))?;
-
-        Ok(Self { model })
-    }
-
-    fn infer_landmarks(&mut self, frame: &Frame) -> Result<recognizer_common::HandposeOutput> {
-        let (input, letterbox) = recognizer_common::prepare_frame(frame)?;
-        let inference = run_model(&mut self.model, input)?;
-
-        let projected = recognizer_common::project_landmarks(&inference.landmarks, &letterbox);
-
-        Ok(recognizer_common::HandposeOutput {
-            raw_landmarks: inference.landmarks,
-            projected_landmarks: projected,
-            confidence: inference.confidence.clamp(0.0, 1.0),
-            handedness: inference.handedness,
-            palm_regions: Vec::new(),
-        })
-    }
-}
-
-struct InferenceResult {
-    landmarks: Vec<[f32; 3]>,
-    confidence: f32,
-    handedness: f32,
-}
-
-fn run_model(model: &mut Model, input: ndarray::Array4<f32>) -> Result<InferenceResult> {
-    let tensor: InputTensor = OrtTensor::from_array(input)?;
-    let outputs = model
-        .run(ort::inputs![tensor])
-        .context("failed to run handpose model")?;
-    decode_ort_outputs(&outputs)
-}
-
-fn decode_ort_outputs(outputs: &ort::session::SessionOutputs<'_>) -> Result<InferenceResult> {
-    if outputs.len() == 0 {
-        return Err(anyhow!("model returned no outputs"));
-    }
-
-    let coords = outputs[0].try_extract_array::<f32>()?;
-    let flattened: Vec<f32> = coords.iter().copied().collect();
-    let landmarks = recognizer_common::decode_landmarks(&flattened)?;
-
-    let confidence = if outputs.len() > 1 {
-        outputs[1]
-            .try_extract_array::<f32>()
-            .ok()
-            .and_then(|v| v.iter().next().copied())
-            .unwrap_or(0.0)
-    } else {
-        0.0
-    };
-    let handedness = if outputs.len() > 2 {
-        outputs[2]
-            .try_extract_array::<f32>()
-            .ok()
-            .and_then(|v| v.iter().next().copied())
-            .unwrap_or(0.0)
-    } else {
-        0.0
-    };
-
-    Ok(InferenceResult {
-        landmarks,
-        confidence,
-        handedness,
-    })
-}
-
-fn load_frame(path: &PathBuf) -> Result<Frame> {
-    let image = image::open(path)
-        .with_context(|| format!("无法打开图片 {}", path.display()))?
-        .to_rgba8();
-    let (width, height) = image.dimensions();
-    let rgba = image.into_raw();
-
-    Ok(Frame {
-        rgba,
-        width,
-        height,
-        timestamp: std::time::Instant::now(),
-    })
-}
-
-fn demo_images() -> Result<Vec<PathBuf>> {
-    let mut images = Vec::new();
-    for entry in std::fs::read_dir("demo").context("读取 demo 目录失败")? {
-        let entry = entry?;
-        let path = entry.path();
-        if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
-            if ["png", "jpg", "jpeg"]
-                .iter()
-                .any(|v| ext.eq_ignore_ascii_case(v))
-            {
-                images.push(path);
-            }
-        }
-    }
-    images.sort();
-    Ok(images)
-}
-
-fn finger_states_to_text(states: &[types::FingerState; 5]) -> String {
-    const NAMES: [&str; 5] = ["拇指", "食指", "中指", "无名指", "小指"];
-    NAMES
-        .iter()
-        .zip(states.iter())
-        .map(|(name, state)| format!("{name} {}", state.label()))
-        .collect::<Vec<_>>()
-        .join("，")
-}
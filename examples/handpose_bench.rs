@@ -1,21 +1,18 @@
-#[allow(dead_code)]
-#[path = "../src/model_download.rs"]
-mod model_download;
-
 use anyhow::{Context, Result};
-use image::{RgbaImage, imageops::FilterType};
-use model_download::{
+use gesture_universe::model_download::{
     default_handpose_estimator_model_path, ensure_handpose_estimator_model_ready,
+    handpose_estimator_model_path_from_env,
 };
+use image::{RgbaImage, imageops::FilterType};
 use std::{
-    path::PathBuf,
+    path::{Path, PathBuf},
     time::{Duration, Instant},
 };
 
-use ort::{
-    session::{Session, builder::GraphOptimizationLevel},
-    value::Tensor as OrtTensor,
+use gesture_universe::pipeline::recognizer::common::{
+    self, ExecutionProvider, HandposeOutputMap, HandposeOutputNames, TensorLayout,
 };
+use ort::{session::Session, value::Tensor as OrtTensor};
 
 type Model = Session;
 type InputArray = ndarray::Array4<f32>;
@@ -39,22 +36,28 @@ fn main() -> Result<()> {
     let model_path = args
         .next()
         .map(PathBuf::from)
+        .or_else(handpose_estimator_model_path_from_env)
         .unwrap_or_else(default_handpose_estimator_model_path);
     let duration_secs = args.next().and_then(|s| s.parse::<u64>().ok()).unwrap_or(1);
 
-    let input_tensor = prepare_tensor(&input_image).context("failed to read input image")?;
     ensure_handpose_estimator_model_ready(&model_path, |_evt| {})?;
-    let mut model = load_model(&model_path)?;
+    let (mut model, provider) = load_model(&model_path)?;
+    let layout = TensorLayout::of_session(&model);
+    let output_map = HandposeOutputMap::of_session(&model, &HandposeOutputNames::default())?;
+    let input_tensor =
+        prepare_tensor(&input_image, layout).context("failed to read input image")?;
 
     println!(
-        "Benchmarking model {} on {} for {}s",
+        "Benchmarking model {} on {} for {}s ({}, {})",
         model_path.display(),
         input_image.display(),
-        duration_secs
+        duration_secs,
+        provider.label(),
+        layout.label()
     );
 
     // Warm-up once to trigger any lazy initialisation.
-    let warmup = infer(&mut model, input_tensor.clone())?;
+    let warmup = infer(&mut model, output_map, input_tensor.clone())?;
     let warmup_conf = warmup.confidence;
     println!("Warm-up done (conf {:.3})", warmup_conf);
 
@@ -63,7 +66,7 @@ fn main() -> Result<()> {
     let mut iterations: u64 = 0;
     let mut last_conf = warmup_conf;
     while start.elapsed() < duration {
-        let outputs = infer(&mut model, input_tensor.clone())?;
+        let outputs = infer(&mut model, output_map, input_tensor.clone())?;
         last_conf = outputs.confidence;
         iterations += 1;
     }
@@ -81,16 +84,12 @@ fn main() -> Result<()> {
     Ok(())
 }
 
-fn load_model(model_path: &PathBuf) -> Result<Model> {
-    let session = Session::builder()?
-        .with_optimization_level(GraphOptimizationLevel::Level3)?
-        .with_intra_threads(2)?
-        .commit_from_file(model_path)
-        .with_context(|| format!("failed to load model from {}", model_path.display()))?;
-    Ok(session)
+fn load_model(model_path: &Path) -> Result<(Model, ExecutionProvider)> {
+    common::build_session(model_path, ExecutionProvider::from_env())
+        .with_context(|| format!("failed to load model from {}", model_path.display()))
 }
 
-fn prepare_tensor(path: &PathBuf) -> Result<InputTensor> {
+fn prepare_tensor(path: &Path, layout: TensorLayout) -> Result<InputTensor> {
     let image = image::open(path)
         .with_context(|| format!("failed to open image {}", path.display()))?
         .to_rgba8();
@@ -122,28 +121,16 @@ fn prepare_tensor(path: &PathBuf) -> Result<InputTensor> {
         }
     }
 
-    array_to_input(input)
+    array_to_input(layout.arrange(input))
 }
 
 fn array_to_input(arr: InputArray) -> Result<InputTensor> {
     OrtTensor::from_array(arr).context("failed to build ORT tensor from input image")
 }
 
-fn infer(model: &mut Model, input: InputTensor) -> Result<InferenceResult> {
+fn infer(model: &mut Model, map: HandposeOutputMap, input: InputTensor) -> Result<InferenceResult> {
     let outputs = model.run(ort::inputs![input])?;
-    decode_ort_outputs(&outputs)
-}
-
-fn decode_ort_outputs(outputs: &ort::session::SessionOutputs<'_>) -> Result<InferenceResult> {
-    let confidence = if outputs.len() > 1 {
-        outputs[1]
-            .try_extract_array::<f32>()
-            .ok()
-            .and_then(|v| v.iter().next().copied())
-            .unwrap_or(0.0)
-    } else {
-        0.0
-    };
-
-    Ok(InferenceResult { confidence })
+    Ok(InferenceResult {
+        confidence: common::output_scalar(&outputs, map.confidence),
+    })
 }
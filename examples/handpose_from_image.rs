@@ -1,12 +1,9 @@
-#[allow(dead_code)]
-#[path = "../src/model_download.rs"]
-mod model_download;
-
 use anyhow::{Context, Result, anyhow};
-use image::{Rgba, RgbaImage, imageops::FilterType};
-use model_download::{
+use gesture_universe::model_download::{
     default_handpose_estimator_model_path, ensure_handpose_estimator_model_ready,
+    handpose_estimator_model_path_from_env,
 };
+use image::{Rgba, RgbaImage, imageops::FilterType};
 use std::path::PathBuf;
 
 use ort::{
@@ -75,6 +72,7 @@ fn main() -> Result<()> {
     let model_path = args
         .next()
         .map(PathBuf::from)
+        .or_else(handpose_estimator_model_path_from_env)
         .unwrap_or_else(default_handpose_estimator_model_path);
 
     let (input_tensor, mut canvas, letterbox) =
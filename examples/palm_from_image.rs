@@ -1,26 +1,18 @@
-#[allow(dead_code)]
-#[path = "../src/pipeline/recognizer/common.rs"]
-mod common;
-#[allow(dead_code)]
-#[path = "../src/model_download.rs"]
-mod model_download;
-#[allow(dead_code)]
-#[path = "../src/pipeline/recognizer/palm/mod.rs"]
-mod palm;
-#[allow(dead_code)]
-#[path = "../src/pipeline/skeleton.rs"]
-mod skeleton;
-#[allow(dead_code)]
-#[path = "../src/types.rs"]
-mod types;
-
 use anyhow::{Context, Result, anyhow};
+use gesture_universe::{
+    model_download::{
+        default_palm_detector_model_path, ensure_palm_detector_model_ready,
+        palm_detector_model_path_from_env,
+    },
+    pipeline::{
+        ExecutionProvider,
+        recognizer::palm::{PalmDetector, PalmDetectorConfig},
+        skeleton,
+    },
+    types::{Frame, PalmRegion, PixelLayout},
+};
 use image::RgbaImage;
-use model_download::{default_palm_detector_model_path, ensure_palm_detector_model_ready};
 use std::path::PathBuf;
-use types::{Frame, PalmRegion};
-
-use crate::palm::{PalmDetector, PalmDetectorConfig};
 
 fn main() -> Result<()> {
     env_logger::init();
@@ -37,11 +29,19 @@ fn main() -> Result<()> {
 
     let mut frame = load_frame(&input_image).context("failed to read input image")?;
 
-    let palm_detector_model_path = default_palm_detector_model_path();
+    let palm_detector_model_path =
+        palm_detector_model_path_from_env().unwrap_or_else(default_palm_detector_model_path);
     ensure_palm_detector_model_ready(&palm_detector_model_path, |_evt| {})?;
 
-    let mut palm_detector =
-        PalmDetector::new(&palm_detector_model_path, PalmDetectorConfig::default())?;
+    let mut palm_detector = PalmDetector::new(
+        &palm_detector_model_path,
+        PalmDetectorConfig::default(),
+        ExecutionProvider::from_env(),
+    )?;
+    println!(
+        "Palm detector running on {}",
+        palm_detector.execution_provider().label()
+    );
 
     let palms = palm_detector.detect(&frame)?;
     if palms.is_empty() {
@@ -57,7 +57,7 @@ fn main() -> Result<()> {
 
     overlay(&mut frame, &palms);
 
-    let output = RgbaImage::from_raw(frame.width, frame.height, frame.rgba)
+    let output = RgbaImage::from_raw(frame.width, frame.height, frame.pixels.to_vec())
         .ok_or_else(|| anyhow!("failed to build image buffer"))?;
     output
         .save(&output_image)
@@ -72,15 +72,24 @@ fn load_frame(path: &PathBuf) -> Result<Frame> {
         .with_context(|| format!("failed to open image {}", path.display()))?
         .to_rgba8();
     let (width, height) = image.dimensions();
-    let rgba = image.into_raw();
+    let now = std::time::Instant::now();
     Ok(Frame {
-        rgba,
+        pixels: image.into_raw().into(),
+        layout: PixelLayout::Rgba,
         width,
         height,
-        timestamp: std::time::Instant::now(),
+        timestamp: now,
+        acquired_at: now,
     })
 }
 
 fn overlay(frame: &mut Frame, palms: &[PalmRegion]) {
-    skeleton::draw_palm_regions(&mut frame.rgba, frame.width, frame.height, palms);
+    skeleton::draw_palm_regions(
+        &mut frame.pixels,
+        frame.width,
+        frame.height,
+        palms,
+        None,
+        &skeleton::SkeletonStyle::default(),
+    );
 }
@@ -0,0 +1,173 @@
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, anyhow};
+use gesture_universe::{
+    config::{AppSettings, default_settings_path},
+    model_download::{ensure_handpose_estimator_model_ready, ensure_palm_detector_model_ready},
+    pipeline::{
+        DEFAULT_IMAGE_INTERVAL, ImageSequenceSource, MetricsHandle, PalmDetectorConfig,
+        RecognizerBackend,
+        image_sequence::load_image_frame,
+        recognizer::{HandposeEngine, OrtEngine, common::InferenceTimings},
+    },
+    types::Frame,
+};
+
+const REPORT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Runs the app's own palm detection, hand crop and handpose path over an image,
+/// or every image of a directory (`demo` by default), and reports the time per
+/// stage and the end-to-end rate. The saved settings pick the models and the
+/// provider, as in the app, and `GU_HANDPOSE_MODEL` / `GU_PALM_MODEL` override
+/// the models.
+///
+/// `--seconds N` sets how long to run (5 by default). `--skip-palm-every N` only
+/// detects palms every N frames while the hands stay tracked, the way
+/// `palm_detect_interval` does; without it every frame runs detection.
+fn main() -> Result<()> {
+    env_logger::init();
+
+    let mut input = PathBuf::from("demo");
+    let mut seconds: u64 = 5;
+    let mut detect_interval: u32 = 1;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seconds" => seconds = parse_value(args.next(), "--seconds")?,
+            "--skip-palm-every" => detect_interval = parse_value(args.next(), "--skip-palm-every")?,
+            _ => input = PathBuf::from(arg),
+        }
+    }
+
+    let mut frames = load_frames(&input)?;
+    let settings = AppSettings::load_or_default(&default_settings_path());
+    let backend = settings
+        .apply_to_backend(RecognizerBackend::default())
+        .with_model_paths_from_env();
+    let backend = backend.clone().with_palm_config(PalmDetectorConfig {
+        detect_interval: detect_interval.max(1),
+        ..backend.palm_config()
+    });
+    ensure_handpose_estimator_model_ready(&backend.handpose_estimator_model_path(), |_| {})?;
+    ensure_palm_detector_model_ready(&backend.palm_detector_model_path(), |_| {})?;
+    let mut engine = OrtEngine::from_backend(&backend)?;
+
+    println!(
+        "Benchmarking {} ({} image(s)) for {seconds}s on {}, palm detection every {} frame(s)",
+        input.display(),
+        frames.len(),
+        backend.execution_provider().label(),
+        detect_interval.max(1)
+    );
+
+    // Warm-up once so lazy session initialisation stays out of the numbers.
+    frames[0].timestamp = Instant::now();
+    engine.infer(&frames[0])?;
+
+    // Fed the same way the worker feeds it, so the rolling numbers below match
+    // what the app shows for a live run.
+    let metrics = MetricsHandle::default();
+    let mut totals = StageTotals::default();
+    let duration = Duration::from_secs(seconds.max(1));
+    let start = Instant::now();
+    let mut last_report = start;
+    while start.elapsed() < duration {
+        let idx = totals.frames as usize % frames.len();
+        let frame = &mut frames[idx];
+        frame.timestamp = Instant::now();
+        let output = engine.infer(frame)?;
+        metrics.record_inference(&output);
+        totals.add(output.timings, output.hands.len());
+
+        if last_report.elapsed() >= REPORT_INTERVAL {
+            last_report = Instant::now();
+            let snapshot = metrics.snapshot();
+            println!(
+                "  input {}x{}, palm {:.1} ms on {:.0}% of frames, handpose {:.1} ms (crop {:.1} ms)",
+                snapshot.input_width,
+                snapshot.input_height,
+                snapshot.palm_detect_ms,
+                snapshot.palm_detect_rate * 100.0,
+                snapshot.handpose_ms,
+                snapshot.crop_prep_ms
+            );
+        }
+    }
+    let elapsed = start.elapsed();
+
+    totals.print(elapsed);
+    Ok(())
+}
+
+fn parse_value<T: FromStr>(value: Option<String>, flag: &str) -> Result<T> {
+    value
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| anyhow!("{flag} expects a number"))
+}
+
+fn load_frames(input: &Path) -> Result<Vec<Frame>> {
+    let now = Instant::now();
+    if input.is_file() {
+        return Ok(vec![load_image_frame(input, now)?]);
+    }
+    let source = ImageSequenceSource::open(input, DEFAULT_IMAGE_INTERVAL)?;
+    source
+        .paths()
+        .iter()
+        .map(|path| load_image_frame(path, now))
+        .collect::<Result<Vec<_>>>()
+        .with_context(|| format!("failed to load the images in {}", input.display()))
+}
+
+#[derive(Default)]
+struct StageTotals {
+    frames: u64,
+    palm_runs: u64,
+    hands: u64,
+    palm_detect: Duration,
+    crop_prep: Duration,
+    handpose: Duration,
+}
+
+impl StageTotals {
+    fn add(&mut self, timings: InferenceTimings, hands: usize) {
+        self.frames += 1;
+        self.hands += hands as u64;
+        if let Some(palm_detect) = timings.palm_detect {
+            self.palm_runs += 1;
+            self.palm_detect += palm_detect;
+        }
+        self.crop_prep += timings.crop_prep;
+        self.handpose += timings.handpose;
+    }
+
+    fn print(&self, elapsed: Duration) {
+        let average_ms =
+            |total: Duration, count: u64| total.as_secs_f64() * 1000.0 / count.max(1) as f64;
+        println!(
+            "Ran {} frames in {:.3}s -> {:.1} fps end to end, {:.2} hands per frame",
+            self.frames,
+            elapsed.as_secs_f64(),
+            self.frames as f64 / elapsed.as_secs_f64(),
+            self.hands as f64 / self.frames.max(1) as f64
+        );
+        println!(
+            "  palm detect {:.2} ms on {} of {} frames",
+            average_ms(self.palm_detect, self.palm_runs),
+            self.palm_runs,
+            self.frames
+        );
+        println!(
+            "  crop prep   {:.2} ms per frame",
+            average_ms(self.crop_prep, self.frames)
+        );
+        println!(
+            "  handpose    {:.2} ms per frame, crop prep included",
+            average_ms(self.handpose, self.frames)
+        );
+    }
+}
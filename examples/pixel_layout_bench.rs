@@ -0,0 +1,117 @@
+use std::{hint::black_box, sync::Arc, time::Instant};
+
+use gesture_universe::{
+    pipeline::{
+        recognizer::common::{INPUT_SIZE, prepare_frame_with_size},
+        rgb_converter::mirror_pixels,
+        skeleton::{self, OverlayMode, SkeletonStyle},
+    },
+    types::{Frame, FramePool, PixelLayout},
+};
+
+const WIDTH: u32 = 1920;
+const HEIGHT: u32 = 1080;
+const FRAMES: u32 = 120;
+
+/// Runs 1080p frames through the stages every camera frame passes — decode
+/// from a raw RGB camera buffer, mirroring, the handpose input, the skeleton
+/// overlay and the preview's BGRA copy — once carrying RGBA as frames used to
+/// and once carrying RGB. Pass a frame count to override the default of 120.
+fn main() {
+    let frames = std::env::args()
+        .nth(1)
+        .and_then(|arg| arg.parse::<u32>().ok())
+        .unwrap_or(FRAMES);
+    println!("{frames} frames at {WIDTH}x{HEIGHT}");
+
+    let camera: Vec<u8> = (0..PixelLayout::Rgb.buffer_len(WIDTH, HEIGHT))
+        .map(|idx| (idx % 251) as u8)
+        .collect();
+    let hand = synthetic_hand();
+    let style = SkeletonStyle::default();
+
+    for layout in [PixelLayout::Rgba, PixelLayout::Rgb] {
+        let pool = FramePool::new(2);
+        let start = Instant::now();
+        for _ in 0..frames {
+            let mut frame = decode(&camera, &pool, layout);
+            mirror_pixels(&mut frame.pixels, frame.width, frame.layout);
+            black_box(prepare_frame_with_size(&frame, INPUT_SIZE).expect("frame has its size"));
+            skeleton::draw_hand(
+                &mut frame.pixels,
+                frame.width,
+                frame.height,
+                &hand,
+                None,
+                &style,
+                OverlayMode::Full,
+            );
+            black_box(to_bgra(&frame));
+        }
+        let elapsed = start.elapsed().as_secs_f64();
+        let frame_bytes = layout.buffer_len(WIDTH, HEIGHT) as f64 / (1024.0 * 1024.0);
+        println!(
+            "{:>4}: {:.2} ms/frame, {:.1} fps, {frame_bytes:.2} MiB/frame",
+            format!("{layout:?}"),
+            elapsed * 1000.0 / f64::from(frames.max(1)),
+            f64::from(frames) / elapsed
+        );
+    }
+}
+
+/// The camera thread's share: RGBA expands every pixel, RGB is a plain copy.
+fn decode(camera: &[u8], pool: &Arc<FramePool>, layout: PixelLayout) -> Frame {
+    let mut pixels = pool.take(layout.buffer_len(WIDTH, HEIGHT));
+    match layout {
+        PixelLayout::Rgb => pixels.copy_from_slice(camera),
+        PixelLayout::Rgba => {
+            for (dst, src) in pixels.chunks_exact_mut(4).zip(camera.chunks_exact(3)) {
+                dst[..3].copy_from_slice(src);
+                dst[3] = u8::MAX;
+            }
+        }
+    }
+    let now = Instant::now();
+    Frame {
+        pixels,
+        layout,
+        width: WIDTH,
+        height: HEIGHT,
+        timestamp: now,
+        acquired_at: now,
+    }
+}
+
+/// The same conversion the preview does before handing a frame to GPUI.
+fn to_bgra(frame: &Frame) -> Vec<u8> {
+    let mut bgra = Vec::with_capacity(frame.width as usize * frame.height as usize * 4);
+    match frame.layout {
+        PixelLayout::Rgb => bgra.extend(
+            frame
+                .pixels
+                .chunks_exact(3)
+                .flat_map(|px| [px[2], px[1], px[0], 255]),
+        ),
+        PixelLayout::Rgba => bgra.extend(
+            frame
+                .pixels
+                .chunks_exact(4)
+                .flat_map(|px| [px[2], px[1], px[0], px[3]]),
+        ),
+    }
+    bgra
+}
+
+/// An open hand in the middle of the frame.
+fn synthetic_hand() -> Vec<(f32, f32)> {
+    let wrist = (WIDTH as f32 * 0.5, HEIGHT as f32 * 0.7);
+    let mut points = vec![wrist];
+    for finger in 0..5 {
+        let angle = (-60.0 + 30.0 * finger as f32).to_radians();
+        for joint in 1..=4 {
+            let reach = 60.0 * joint as f32;
+            points.push((wrist.0 + angle.sin() * reach, wrist.1 - angle.cos() * reach));
+        }
+    }
+    points
+}
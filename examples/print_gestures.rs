@@ -0,0 +1,19 @@
+use anyhow::Result;
+use gesture_universe::{GestureEvent, GestureUniverse};
+
+/// Opens the first camera and prints each gesture as it starts and ends, the
+/// way an app embedding the library would.
+fn main() -> Result<()> {
+    env_logger::init();
+
+    let universe = GestureUniverse::builder().start()?;
+    println!("等待手势，按 Ctrl-C 退出");
+    for event in universe.events() {
+        match event {
+            GestureEvent::Started(kind) => println!("开始 {}{}", kind.emoji(), kind.display_name()),
+            GestureEvent::Ended(kind) => println!("结束 {}", kind.display_name()),
+            _ => {}
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use std::{
+    fs,
+    path::PathBuf,
+    time::{Duration, Instant, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result, anyhow};
+use gesture_universe::{
+    gesture::GestureClassifier,
+    pipeline::recorder::{RecordedFrame, SESSION_INDEX_FILENAME},
+    types::{GestureKind, RecognizerConfig},
+};
+
+/// Re-runs the current classifier over the landmarks of a recorded session and
+/// lists every hand whose label changed.
+fn main() -> Result<()> {
+    env_logger::init();
+
+    let session_dir = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .ok_or_else(|| anyhow!("usage: replay_session <recordings/session-...>"))?;
+    let index_path = session_dir.join(SESSION_INDEX_FILENAME);
+    let text = fs::read_to_string(&index_path)
+        .with_context(|| format!("failed to read {}", index_path.display()))?;
+
+    let mut classifier = GestureClassifier::new(&RecognizerConfig::default());
+    // Motion tracking needs increasing timestamps; rebuild them from the
+    // recorded wall-clock capture times.
+    let base = Instant::now();
+    let mut frames = 0usize;
+    let mut hands = 0usize;
+    let mut changed = 0usize;
+
+    for (line_no, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry: RecordedFrame = serde_json::from_str(line)
+            .with_context(|| format!("invalid entry on line {}", line_no + 1))?;
+        frames += 1;
+
+        let offset = entry
+            .result
+            .captured_at
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO);
+        let timestamp = base + offset;
+
+        for hand in &entry.result.hands {
+            if hand.raw_landmarks.len() < 21 {
+                continue;
+            }
+            hands += 1;
+            let recorded = hand
+                .detail
+                .as_ref()
+                .map_or(GestureKind::Unknown, |d| d.primary);
+            let replayed = classifier
+                .classify(
+                    hand.track_id,
+                    &hand.raw_landmarks,
+                    &hand.landmarks,
+                    hand.confidence,
+                    hand.handedness_score,
+                    timestamp,
+                )
+                .map_or(GestureKind::Unknown, |d| d.primary);
+
+            if recorded != replayed {
+                changed += 1;
+                println!(
+                    "{} (hand {}): {}{} -> {}{}",
+                    entry.image,
+                    hand.track_id,
+                    recorded.emoji(),
+                    recorded.display_name(),
+                    replayed.emoji(),
+                    replayed.display_name()
+                );
+            }
+        }
+    }
+
+    println!("{frames} frames, {hands} hands, {changed} labels changed");
+    Ok(())
+}
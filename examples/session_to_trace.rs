@@ -0,0 +1,60 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, anyhow};
+use gesture_universe::{
+    gesture::GestureClassifier,
+    pipeline::recorder::{RecordedFrame, SESSION_INDEX_FILENAME},
+    trace::{read_jsonl, replay, trace_from_session},
+    types::RecognizerConfig,
+};
+use serde::Serialize;
+
+/// Turns a recorded session into a landmark trace for `testdata/`, following
+/// one hand: the given track id, or the first hand of every frame. The labels
+/// the rules give today go next to it as `<name>.expected.jsonl`, a draft to
+/// correct against the recording before the trace guards them.
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let usage = "usage: session_to_trace <recordings/session-...> <out.jsonl> [track id]";
+    let session_dir = args
+        .next()
+        .map(PathBuf::from)
+        .ok_or_else(|| anyhow!(usage))?;
+    let out_path = args
+        .next()
+        .map(PathBuf::from)
+        .ok_or_else(|| anyhow!(usage))?;
+    let track_id = args
+        .next()
+        .map(|id| id.parse::<u64>().context("track id must be a number"))
+        .transpose()?;
+
+    let entries: Vec<RecordedFrame> = read_jsonl(&session_dir.join(SESSION_INDEX_FILENAME))?;
+    let frames = trace_from_session(&entries, track_id);
+    let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+    let steps = replay(&mut classifier, &frames);
+    let expected_path = out_path.with_extension("expected.jsonl");
+    write_jsonl(&out_path, &frames)?;
+    write_jsonl(&expected_path, &steps)?;
+
+    println!(
+        "{} of {} frames written to {} and {}",
+        frames.len(),
+        entries.len(),
+        out_path.display(),
+        expected_path.display()
+    );
+    Ok(())
+}
+
+fn write_jsonl<T: Serialize>(path: &Path, items: &[T]) -> Result<()> {
+    let mut text = String::new();
+    for item in items {
+        text.push_str(&serde_json::to_string(item)?);
+        text.push('\n');
+    }
+    fs::write(path, text).with_context(|| format!("failed to write {}", path.display()))
+}
@@ -0,0 +1,121 @@
+use std::{
+    fs,
+    path::PathBuf,
+    time::{Duration, Instant, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result, anyhow};
+use gesture_universe::{
+    gesture::{
+        GestureClassifier,
+        plugin::{GestureContext, GesturePlugin},
+    },
+    pipeline::recorder::{RecordedFrame, SESSION_INDEX_FILENAME},
+    types::{CustomGesture, FingerState, GestureKind, RecognizerConfig},
+};
+
+const VULCAN_SALUTE: CustomGesture = CustomGesture {
+    id: "vulcan_salute",
+    display_name: "瓦肯举手礼",
+    emoji: "🖖 ",
+};
+
+/// Tips of the paired fingers at most this far apart, in the normalized
+/// landmark units the finger rules use.
+const PAIR_MAX_GAP: f32 = 0.3;
+/// The split between middle and ring tips, as a multiple of the wider pair.
+const SPLIT_MIN_RATIO: f32 = 1.8;
+
+/// Every finger up, index with middle and ring with little finger held
+/// together, and a wide gap between the two pairs.
+struct VulcanSalute;
+
+impl GesturePlugin for VulcanSalute {
+    fn name(&self) -> &str {
+        "vulcan_salute"
+    }
+
+    fn detect(&mut self, ctx: &GestureContext<'_>) -> Option<(GestureKind, f32)> {
+        if ctx.finger_states[1..]
+            .iter()
+            .any(|state| *state != FingerState::Extended)
+        {
+            return None;
+        }
+        let gap = |a: usize, b: usize| {
+            let (pa, pb) = (ctx.landmarks[a], ctx.landmarks[b]);
+            ((pa[0] - pb[0]).powi(2) + (pa[1] - pb[1]).powi(2) + (pa[2] - pb[2]).powi(2)).sqrt()
+        };
+        let pairs = gap(8, 12).max(gap(16, 20));
+        let split = gap(12, 16);
+        if pairs > PAIR_MAX_GAP || split < pairs.max(0.05) * SPLIT_MIN_RATIO {
+            return None;
+        }
+        // Tighter pairs read as more certain.
+        Some((VULCAN_SALUTE.kind(), 1.0 - 0.5 * pairs / PAIR_MAX_GAP))
+    }
+
+    fn custom_gestures(&self) -> &[CustomGesture] {
+        &[VULCAN_SALUTE]
+    }
+}
+
+/// Re-runs a recorded session with the Vulcan salute plugin registered and
+/// lists the frames it fires on. In the app the same plugin is added with
+/// `RecognizerBackend::default().with_plugin(|| VulcanSalute)`.
+fn main() -> Result<()> {
+    env_logger::init();
+
+    let session_dir = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .ok_or_else(|| anyhow!("usage: vulcan_salute <recordings/session-...>"))?;
+    let index_path = session_dir.join(SESSION_INDEX_FILENAME);
+    let text = fs::read_to_string(&index_path)
+        .with_context(|| format!("failed to read {}", index_path.display()))?;
+
+    let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+    classifier.register_plugin(Box::new(VulcanSalute));
+    println!("plugins: {}", classifier.plugin_names().join(" → "));
+
+    let base = Instant::now();
+    let (mut hands, mut salutes) = (0usize, 0usize);
+    for (line_no, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry: RecordedFrame = serde_json::from_str(line)
+            .with_context(|| format!("invalid entry on line {}", line_no + 1))?;
+        let offset = entry
+            .result
+            .captured_at
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO);
+
+        for hand in &entry.result.hands {
+            hands += 1;
+            let detail = classifier.classify(
+                hand.track_id,
+                &hand.raw_landmarks,
+                &hand.landmarks,
+                hand.confidence,
+                hand.handedness_score,
+                base + offset,
+            );
+            if detail.is_some_and(|detail| detail.primary == VULCAN_SALUTE.kind()) {
+                salutes += 1;
+                let kind = VULCAN_SALUTE.kind();
+                println!(
+                    "{} (hand {}): {}{}",
+                    entry.image,
+                    hand.track_id,
+                    kind.emoji(),
+                    kind.display_name()
+                );
+            }
+        }
+    }
+
+    println!("{hands} hands, {salutes} read as the Vulcan salute");
+    Ok(())
+}
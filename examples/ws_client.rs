@@ -0,0 +1,23 @@
+use anyhow::{Context, Result};
+use tungstenite::Message;
+
+fn main() -> Result<()> {
+    let url = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "ws://127.0.0.1:9002".to_string());
+
+    let (mut socket, _) = tungstenite::connect(url.as_str())
+        .with_context(|| format!("failed to connect to {url}"))?;
+    eprintln!("connected to {url}, waiting for gesture results...");
+
+    loop {
+        match socket.read().context("websocket read failed")? {
+            Message::Text(text) => println!("{text}"),
+            Message::Close(_) => {
+                eprintln!("server closed the connection");
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,111 @@
+#!/usr/bin/env python3
+"""Write the tiny channels-first handpose stand-ins under testdata/.
+
+The models take a (batch, 3, 4, 4) image and answer with the three outputs the
+handpose engine reads: every landmark is the per-channel mean of the image,
+the score is the mean of all channels and the handedness is its sigmoid. Only
+ops from models/ops.config are used, so a trimmed ONNX Runtime loads them.
+
+Needs no onnx package; the protobuf is encoded by hand.
+Usage: scripts/make_layout_fixtures.py
+"""
+
+import struct
+from pathlib import Path
+
+TESTDATA = Path(__file__).resolve().parent.parent / "testdata"
+FLOAT, INT64 = 1, 7
+
+
+def varint(value):
+    value &= (1 << 64) - 1
+    out = bytearray()
+    while True:
+        byte = value & 0x7F
+        value >>= 7
+        if value:
+            out.append(byte | 0x80)
+        else:
+            out.append(byte)
+            return bytes(out)
+
+
+def field_int(number, value):
+    return varint(number << 3) + varint(value)
+
+
+def field_bytes(number, payload):
+    if isinstance(payload, str):
+        payload = payload.encode()
+    return varint(number << 3 | 2) + varint(len(payload)) + payload
+
+
+def tensor(name, dims, elem_type, values):
+    fmt = "<%d%s" % (len(values), "f" if elem_type == FLOAT else "q")
+    return (
+        b"".join(field_int(1, dim) for dim in dims)
+        + field_int(2, elem_type)
+        + field_bytes(8, name)
+        + field_bytes(9, struct.pack(fmt, *values))
+    )
+
+
+def value_info(name, dims):
+    shape = b"".join(
+        field_bytes(1, field_bytes(2, dim) if isinstance(dim, str) else field_int(1, dim))
+        for dim in dims
+    )
+    tensor_type = field_int(1, FLOAT) + field_bytes(2, shape)
+    return field_bytes(1, name) + field_bytes(2, field_bytes(1, tensor_type))
+
+
+def node(op_type, inputs, outputs, **ints):
+    attributes = b"".join(
+        field_bytes(5, field_bytes(1, key) + field_int(3, value) + field_int(20, 2))
+        for key, value in ints.items()
+    )
+    return (
+        b"".join(field_bytes(1, name) for name in inputs)
+        + b"".join(field_bytes(2, name) for name in outputs)
+        + field_bytes(3, outputs[0])
+        + field_bytes(4, op_type)
+        + attributes
+    )
+
+
+def model(batch):
+    nodes = [
+        node("GlobalAveragePool", ["input"], ["means"]),
+        node("Reshape", ["means", "row_shape"], ["row"]),
+        node("Concat", ["row"] * 21, ["rows"], axis=1),
+        node("Reshape", ["rows", "landmark_shape"], ["Identity"]),
+        node("Reshape", ["means", "channel_shape"], ["channels"]),
+        node("Gemm", ["channels", "average"], ["Identity_1"]),
+        node("Sigmoid", ["Identity_1"], ["Identity_2"]),
+    ]
+    initializers = [
+        tensor("row_shape", [3], INT64, [-1, 1, 3]),
+        tensor("landmark_shape", [2], INT64, [-1, 63]),
+        tensor("channel_shape", [2], INT64, [-1, 3]),
+        tensor("average", [3, 1], FLOAT, [1 / 3] * 3),
+    ]
+    graph = (
+        b"".join(field_bytes(1, entry) for entry in nodes)
+        + field_bytes(2, "nchw_layout")
+        + b"".join(field_bytes(5, entry) for entry in initializers)
+        + field_bytes(11, value_info("input", [batch, 3, 4, 4]))
+        + field_bytes(12, value_info("Identity", [batch, 63]))
+        + field_bytes(12, value_info("Identity_1", [batch, 1]))
+        + field_bytes(12, value_info("Identity_2", [batch, 1]))
+    )
+    return (
+        field_int(1, 7)
+        + field_bytes(2, "make_layout_fixtures")
+        + field_bytes(7, graph)
+        + field_bytes(8, field_bytes(1, "") + field_int(2, 13))
+    )
+
+
+if __name__ == "__main__":
+    (TESTDATA / "nchw_fixed_batch.onnx").write_bytes(model(1))
+    (TESTDATA / "nchw_dynamic_batch.onnx").write_bytes(model("batch"))
@@ -0,0 +1,492 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, anyhow};
+use enigo::{Button, Coordinate, Direction, Enigo, Key, Keyboard, Mouse, Settings};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    locale::{Locale, locale, tr},
+    types::{GestureEvent, GestureKind, GestureMotion},
+};
+
+pub mod cursor;
+
+use cursor::{CursorCommand, CursorConfig};
+
+const ACTIONS_CONFIG_FILENAME: &str = "actions.json";
+pub const DEFAULT_COOLDOWN_MS: u64 = 800;
+
+pub fn default_actions_config_path() -> PathBuf {
+    PathBuf::from("config").join(ACTIONS_CONFIG_FILENAME)
+}
+
+/// A key plus modifiers, stored with the key names gpui reports (`space`,
+/// `left`, `f5`, `a`, ...).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyCombo {
+    #[serde(default)]
+    pub ctrl: bool,
+    #[serde(default)]
+    pub alt: bool,
+    #[serde(default)]
+    pub shift: bool,
+    #[serde(default)]
+    pub meta: bool,
+    pub key: String,
+}
+
+impl KeyCombo {
+    pub fn new(key: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn label(&self) -> String {
+        let mut parts = Vec::new();
+        if self.ctrl {
+            parts.push("Ctrl".to_string());
+        }
+        if self.alt {
+            parts.push("Alt".to_string());
+        }
+        if self.shift {
+            parts.push("Shift".to_string());
+        }
+        if self.meta {
+            parts.push("Meta".to_string());
+        }
+        if self.key.is_empty() {
+            parts.push(tr("未设置", "Not set").to_string());
+        } else {
+            parts.push(key_display_name(&self.key));
+        }
+        parts.join("+")
+    }
+
+    fn modifier_keys(&self) -> Vec<Key> {
+        let mut keys = Vec::new();
+        if self.ctrl {
+            keys.push(Key::Control);
+        }
+        if self.alt {
+            keys.push(Key::Alt);
+        }
+        if self.shift {
+            keys.push(Key::Shift);
+        }
+        if self.meta {
+            keys.push(Key::Meta);
+        }
+        keys
+    }
+}
+
+/// What a plain gesture binding waits for before it fires.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Confirm {
+    /// Fires as the gesture starts.
+    #[default]
+    Immediate,
+    /// Fires once the gesture has been held this long, with a progress ring
+    /// drawn around the hand meanwhile; releasing early aborts. Stored in
+    /// milliseconds.
+    HoldFor(#[serde(with = "millis")] Duration),
+}
+
+impl Confirm {
+    /// The choices offered in the actions panel.
+    pub const PRESETS: [Confirm; 3] = [
+        Confirm::Immediate,
+        Confirm::HoldFor(Duration::from_secs(1)),
+        Confirm::HoldFor(Duration::from_secs(2)),
+    ];
+
+    pub fn label(&self) -> String {
+        match self {
+            Confirm::Immediate => tr("立即", "Immediately").to_string(),
+            Confirm::HoldFor(hold) => match locale() {
+                Locale::ZhCn => format!("按住{:.1}秒", hold.as_secs_f32()),
+                Locale::En => format!("Hold {:.1}s", hold.as_secs_f32()),
+            },
+        }
+    }
+
+    /// The next preset; a hold time set in the file goes back to immediate.
+    pub fn next(self) -> Self {
+        Self::PRESETS
+            .iter()
+            .position(|confirm| *confirm == self)
+            .map_or(Confirm::Immediate, |idx| {
+                Self::PRESETS[(idx + 1) % Self::PRESETS.len()]
+            })
+    }
+}
+
+mod millis {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_millis() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_millis)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionBinding {
+    pub gesture: GestureKind,
+    /// When set the binding only fires while the hand also shows this motion.
+    #[serde(default)]
+    pub motion: Option<GestureMotion>,
+    /// When set the binding fires when this named sequence completes, and the
+    /// gesture and motion are ignored.
+    #[serde(default)]
+    pub sequence: Option<String>,
+    pub keys: KeyCombo,
+    #[serde(default = "default_cooldown_ms")]
+    pub cooldown_ms: u64,
+    /// Only used by bindings without a motion or sequence.
+    #[serde(default)]
+    pub confirm: Confirm,
+}
+
+impl ActionBinding {
+    pub fn new(gesture: GestureKind, keys: KeyCombo) -> Self {
+        Self {
+            gesture,
+            motion: None,
+            sequence: None,
+            keys,
+            cooldown_ms: DEFAULT_COOLDOWN_MS,
+            confirm: Confirm::Immediate,
+        }
+    }
+
+    /// How long the gesture has to be held before the binding fires, for
+    /// plain gesture bindings marked [`Confirm::HoldFor`].
+    pub fn hold_time(&self) -> Option<Duration> {
+        match self.confirm {
+            Confirm::HoldFor(hold) if self.motion.is_none() && self.sequence.is_none() => {
+                Some(hold)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn default_cooldown_ms() -> u64 {
+    DEFAULT_COOLDOWN_MS
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActionConfig {
+    /// Global switch; nothing is sent while this is off.
+    pub enabled: bool,
+    pub bindings: Vec<ActionBinding>,
+    /// Drags the mouse with the left button held while a fist drags.
+    #[serde(default)]
+    pub mouse_drag: bool,
+    /// Moving the pointer with the index finger, toggled at runtime; only
+    /// its settings and calibration are saved.
+    #[serde(default)]
+    pub cursor: CursorConfig,
+}
+
+impl Default for ActionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bindings: vec![ActionBinding::new(
+                GestureKind::Fist,
+                KeyCombo::new("space"),
+            )],
+            mouse_drag: false,
+            cursor: CursorConfig::default(),
+        }
+    }
+}
+
+impl ActionConfig {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("failed to read action config {}", path.display()))?;
+        serde_json::from_str(&text)
+            .with_context(|| format!("failed to parse action config {}", path.display()))
+    }
+
+    /// Falls back to the defaults when the file is missing or unreadable.
+    pub fn load_or_default(path: &Path) -> Self {
+        if !path.exists() {
+            return Self::default();
+        }
+        Self::load(path).unwrap_or_else(|err| {
+            log::warn!("{err:#}, using default actions");
+            Self::default()
+        })
+    }
+
+    /// The bindings that wait for a held gesture, as `(index, gesture, hold)`;
+    /// none while actions are off.
+    pub fn hold_bindings(&self) -> Vec<(usize, GestureKind, Duration)> {
+        if !self.enabled {
+            return Vec::new();
+        }
+        self.bindings
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, binding)| Some((idx, binding.gesture, binding.hold_time()?)))
+            .collect()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let text = serde_json::to_string_pretty(self)?;
+        fs::write(path, text)
+            .with_context(|| format!("failed to write action config {}", path.display()))
+    }
+}
+
+/// Fires key presses for bindings matched by debounced gesture events, and
+/// drags the mouse along with fist drags when that is on.
+#[derive(Default)]
+pub struct ActionDispatcher {
+    // Created on first use: on macOS this is what triggers the accessibility
+    // permission prompt, which should not happen while actions are disabled.
+    enigo: Option<Enigo>,
+    last_fired: HashMap<usize, Instant>,
+}
+
+impl ActionDispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Plain bindings fire once when their gesture starts; motion bindings fire
+    /// on each [`GestureEvent::Motion`] of their gesture, limited by their
+    /// cooldown. Sequence bindings fire when their sequence completes.
+    /// Hold-to-confirm bindings fire from [`ActionDispatcher::confirm_hold`]
+    /// instead.
+    pub fn handle(&mut self, config: &ActionConfig, event: &GestureEvent, now: Instant) {
+        if !config.enabled {
+            return;
+        }
+
+        let (kind, motion) = match event {
+            GestureEvent::Started(kind) => (*kind, None),
+            GestureEvent::Motion { kind, motion } => (*kind, Some(*motion)),
+            GestureEvent::SequenceMatched(name) => {
+                for (idx, binding) in config.bindings.iter().enumerate() {
+                    if binding.sequence.as_ref() == Some(name) {
+                        self.fire(idx, binding, &format!("sequence {name:?}"), now);
+                    }
+                }
+                return;
+            }
+            GestureEvent::DragStarted { pos }
+            | GestureEvent::DragMoved { pos, .. }
+            | GestureEvent::DragEnded { pos, .. } => {
+                if config.mouse_drag
+                    && let Err(err) = self.drag_mouse(event, *pos)
+                {
+                    log::warn!("failed to drag the mouse: {err:#}");
+                }
+                return;
+            }
+            GestureEvent::Held { .. }
+            | GestureEvent::Ended(_)
+            | GestureEvent::DwellCompleted(_) => return,
+        };
+
+        for (idx, binding) in config.bindings.iter().enumerate() {
+            if binding.gesture != kind
+                || binding.sequence.is_some()
+                || binding.hold_time().is_some()
+            {
+                continue;
+            }
+            if binding.motion == motion {
+                self.fire(
+                    idx,
+                    binding,
+                    &format!("gesture {}", kind.display_name()),
+                    now,
+                );
+            }
+        }
+    }
+
+    /// Fires binding `idx` after its gesture was held for the hold time, as
+    /// reported by the compositor, which times the hold and draws its ring.
+    pub fn confirm_hold(&mut self, config: &ActionConfig, idx: usize, now: Instant) {
+        let Some(binding) = config.bindings.get(idx).filter(|_| config.enabled) else {
+            return;
+        };
+        if binding.hold_time().is_some() {
+            let trigger = format!("holding {}", binding.gesture.display_name());
+            self.fire(idx, binding, &trigger, now);
+        }
+    }
+
+    /// Sends the binding's keys unless it is still cooling down.
+    fn fire(&mut self, idx: usize, binding: &ActionBinding, trigger: &str, now: Instant) {
+        let cooldown = Duration::from_millis(binding.cooldown_ms);
+        if let Some(last) = self.last_fired.get(&idx)
+            && now.saturating_duration_since(*last) < cooldown
+        {
+            return;
+        }
+
+        match self.send(&binding.keys) {
+            Ok(()) => {
+                log::info!("{trigger} triggered {}", binding.keys.label());
+                self.last_fired.insert(idx, now);
+            }
+            Err(err) => log::warn!("failed to send {}: {err:#}", binding.keys.label()),
+        }
+    }
+
+    /// Forgets cooldowns, e.g. after the bindings were edited.
+    pub fn reset(&mut self) {
+        self.last_fired.clear();
+    }
+
+    fn send(&mut self, combo: &KeyCombo) -> Result<()> {
+        let key = parse_key(&combo.key).ok_or_else(|| anyhow!("unknown key {:?}", combo.key))?;
+        let enigo = self.enigo()?;
+
+        let modifiers = combo.modifier_keys();
+        for modifier in &modifiers {
+            enigo.key(*modifier, Direction::Press)?;
+        }
+        let result = enigo.key(key, Direction::Click);
+        for modifier in modifiers.iter().rev() {
+            enigo.key(*modifier, Direction::Release)?;
+        }
+        Ok(result?)
+    }
+
+    /// Moves the pointer to the drag position on the main display, pressing
+    /// the left button as the drag starts and releasing it as it ends. A
+    /// cancelled drag drops where it was, like an opened palm.
+    fn drag_mouse(&mut self, event: &GestureEvent, (x, y): (f32, f32)) -> Result<()> {
+        let enigo = self.enigo()?;
+        let (width, height) = enigo.main_display()?;
+        let x = (x.clamp(0.0, 1.0) * width as f32) as i32;
+        let y = (y.clamp(0.0, 1.0) * height as f32) as i32;
+        enigo.move_mouse(x, y, Coordinate::Abs)?;
+        match event {
+            GestureEvent::DragStarted { .. } => enigo.button(Button::Left, Direction::Press)?,
+            GestureEvent::DragEnded { .. } => enigo.button(Button::Left, Direction::Release)?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Sends the cursor mode's commands, fractions of the main display for
+    /// moves. Nothing is sent while actions are off.
+    pub fn apply_cursor(&mut self, config: &ActionConfig, commands: &[CursorCommand]) {
+        if !config.enabled || commands.is_empty() {
+            return;
+        }
+        if let Err(err) = self.send_cursor(commands) {
+            log::warn!("failed to move the cursor: {err:#}");
+        }
+    }
+
+    fn send_cursor(&mut self, commands: &[CursorCommand]) -> Result<()> {
+        let enigo = self.enigo()?;
+        let (width, height) = enigo.main_display()?;
+        for command in commands {
+            match *command {
+                CursorCommand::MoveTo(x, y) => {
+                    let x = (x.clamp(0.0, 1.0) * width as f32) as i32;
+                    let y = (y.clamp(0.0, 1.0) * height as f32) as i32;
+                    enigo.move_mouse(x, y, Coordinate::Abs)?;
+                }
+                CursorCommand::Press => enigo.button(Button::Left, Direction::Press)?,
+                CursorCommand::Release => enigo.button(Button::Left, Direction::Release)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn enigo(&mut self) -> Result<&mut Enigo> {
+        Ok(match &mut self.enigo {
+            Some(enigo) => enigo,
+            slot @ None => slot.insert(
+                Enigo::new(&Settings::default())
+                    .map_err(|err| anyhow!("failed to create input simulator: {err}"))?,
+            ),
+        })
+    }
+}
+
+fn parse_key(name: &str) -> Option<Key> {
+    let key = match name.to_ascii_lowercase().as_str() {
+        "space" => Key::Space,
+        "enter" | "return" => Key::Return,
+        "escape" | "esc" => Key::Escape,
+        "tab" => Key::Tab,
+        "backspace" => Key::Backspace,
+        "delete" => Key::Delete,
+        "left" => Key::LeftArrow,
+        "right" => Key::RightArrow,
+        "up" => Key::UpArrow,
+        "down" => Key::DownArrow,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp,
+        "pagedown" => Key::PageDown,
+        "f1" => Key::F1,
+        "f2" => Key::F2,
+        "f3" => Key::F3,
+        "f4" => Key::F4,
+        "f5" => Key::F5,
+        "f6" => Key::F6,
+        "f7" => Key::F7,
+        "f8" => Key::F8,
+        "f9" => Key::F9,
+        "f10" => Key::F10,
+        "f11" => Key::F11,
+        "f12" => Key::F12,
+        other => {
+            let mut chars = other.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Key::Unicode(c),
+                _ => return None,
+            }
+        }
+    };
+    Some(key)
+}
+
+fn key_display_name(name: &str) -> String {
+    match name {
+        "left" => "←".to_string(),
+        "right" => "→".to_string(),
+        "up" => "↑".to_string(),
+        "down" => "↓".to_string(),
+        other => {
+            let mut chars = other.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+    }
+}
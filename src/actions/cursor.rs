@@ -0,0 +1,297 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::millis;
+use crate::{
+    gesture::HoldConfirm,
+    locale::{Locale, locale},
+    types::{GestureKind, GestureResult},
+};
+
+const INDEX_TIP: usize = 8;
+/// Calibrated corners closer together than this, as a fraction of the frame,
+/// would make the cursor jump across the screen for the slightest twitch.
+const MIN_MAPPING_SPAN: f32 = 0.05;
+
+/// Gesture cursor settings, saved with the actions.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CursorConfig {
+    /// `[x1, y1, x2, y2]` as fractions of the frame. The fingertip at a
+    /// corner of this rectangle puts the cursor at that corner of the screen.
+    pub mapping: [f32; 4],
+    /// How much farther the cursor moves than the fingertip, about the
+    /// middle of the mapping.
+    pub gain: f32,
+    /// Moves shorter than this, as a fraction of the screen, are dropped so
+    /// the cursor keeps still while the finger trembles.
+    pub dead_zone: f32,
+    /// How much of each new fingertip position goes into the smoothed one,
+    /// 0–1; 1 follows the finger without smoothing.
+    pub smoothing: f32,
+    /// Held for `panic_hold`, turns the cursor mode off, or back on.
+    pub panic_gesture: GestureKind,
+    #[serde(with = "millis")]
+    pub panic_hold: Duration,
+}
+
+impl Default for CursorConfig {
+    fn default() -> Self {
+        Self {
+            mapping: [0.2, 0.2, 0.8, 0.8],
+            gain: 1.0,
+            dead_zone: 0.003,
+            smoothing: 0.4,
+            panic_gesture: GestureKind::Fist,
+            panic_hold: Duration::from_secs(1),
+        }
+    }
+}
+
+impl CursorConfig {
+    /// The choices offered in the actions panel.
+    pub const GAIN_PRESETS: [f32; 5] = [0.75, 1.0, 1.25, 1.5, 2.0];
+    pub const DEAD_ZONE_PRESETS: [f32; 4] = [0.0, 0.003, 0.006, 0.012];
+
+    /// Where a fingertip at `(x, y)`, as fractions of the frame, puts the
+    /// cursor, as fractions of the screen.
+    pub fn map(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let [x1, y1, x2, y2] = self.mapping;
+        let scale = |value: f32, low: f32, high: f32| {
+            let span = high - low;
+            let fraction = if span.abs() < f32::EPSILON {
+                0.5
+            } else {
+                (value - low) / span
+            };
+            (0.5 + (fraction - 0.5) * self.gain).clamp(0.0, 1.0)
+        };
+        (scale(x, x1, x2), scale(y, y1, y2))
+    }
+}
+
+/// What the cursor mode wants done to the pointer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CursorCommand {
+    /// Fractions of the main display.
+    MoveTo(f32, f32),
+    Press,
+    Release,
+}
+
+/// Drives the pointer from the primary hand: the index fingertip moves it
+/// while the hand points, and a pinch holds the left button down, so a
+/// quick pinch clicks and a held one drags. Holding the panic gesture turns
+/// the mode on and off.
+pub struct CursorController {
+    config: CursorConfig,
+    active: bool,
+    panic: HoldConfirm,
+    smoothed: Option<(f32, f32)>,
+    /// Where the cursor was last sent.
+    sent: Option<(f32, f32)>,
+    /// Set once the hand has pointed, so a pinch made without aiming first
+    /// does not click wherever the cursor happens to be.
+    aimed: bool,
+    pressed: bool,
+}
+
+impl CursorController {
+    pub fn new(config: CursorConfig) -> Self {
+        let panic = HoldConfirm::new(config.panic_gesture, config.panic_hold);
+        Self {
+            config,
+            active: false,
+            panic,
+            smoothed: None,
+            sent: None,
+            aimed: false,
+            pressed: false,
+        }
+    }
+
+    pub fn set_config(&mut self, config: CursorConfig) {
+        self.panic = HoldConfirm::new(config.panic_gesture, config.panic_hold);
+        self.config = config;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Turning the mode off lets go of a held button.
+    pub fn set_active(&mut self, active: bool) -> Vec<CursorCommand> {
+        self.active = active;
+        let commands = if !active && self.pressed {
+            vec![CursorCommand::Release]
+        } else {
+            Vec::new()
+        };
+        self.smoothed = None;
+        self.sent = None;
+        self.aimed = false;
+        self.pressed = false;
+        commands
+    }
+
+    pub fn toggle(&mut self) -> Vec<CursorCommand> {
+        self.set_active(!self.active)
+    }
+
+    /// The pointer commands for one frame. The panic gesture is watched even
+    /// while the mode is off, so it can be turned back on by hand. Losing the
+    /// hand lets go of a held button.
+    pub fn observe(&mut self, result: &GestureResult) -> Vec<CursorCommand> {
+        if self
+            .panic
+            .observe(result)
+            .is_some_and(|progress| progress.completed)
+        {
+            return self.toggle();
+        }
+        if !self.active {
+            return Vec::new();
+        }
+
+        let mut commands = Vec::new();
+        let (Some(detail), Some(tip)) = (&result.detail, fingertip(result)) else {
+            if self.pressed {
+                commands.push(CursorCommand::Release);
+            }
+            self.smoothed = None;
+            self.aimed = false;
+            self.pressed = false;
+            return commands;
+        };
+
+        let pointing = matches!(detail.primary, GestureKind::Point | GestureKind::One);
+        let pinched = detail.pinch.is_some_and(|pinch| pinch.closed);
+        self.aimed |= pointing;
+        if pointing || self.pressed {
+            let weight = self.config.smoothing.clamp(0.01, 1.0);
+            let smoothed = match self.smoothed {
+                Some((x, y)) => (x + (tip.0 - x) * weight, y + (tip.1 - y) * weight),
+                None => tip,
+            };
+            self.smoothed = Some(smoothed);
+            let target = self.config.map(smoothed);
+            let moved = self
+                .sent
+                .is_none_or(|(x, y)| (target.0 - x).hypot(target.1 - y) >= self.config.dead_zone);
+            if moved {
+                commands.push(CursorCommand::MoveTo(target.0, target.1));
+                self.sent = Some(target);
+            }
+        }
+
+        if pinched && !self.pressed && self.aimed {
+            self.pressed = true;
+            commands.push(CursorCommand::Press);
+        } else if !pinched && self.pressed {
+            self.pressed = false;
+            commands.push(CursorCommand::Release);
+        }
+        commands
+    }
+}
+
+/// The four corners of the guided calibration, in the order asked for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomRight,
+    BottomLeft,
+}
+
+impl Corner {
+    pub const ALL: [Corner; 4] = [
+        Corner::TopLeft,
+        Corner::TopRight,
+        Corner::BottomRight,
+        Corner::BottomLeft,
+    ];
+
+    pub fn label(self) -> &'static str {
+        self.label_in(locale())
+    }
+
+    pub fn label_in(self, locale: Locale) -> &'static str {
+        let (zh, en) = match self {
+            Corner::TopLeft => ("左上角", "Top-left corner"),
+            Corner::TopRight => ("右上角", "Top-right corner"),
+            Corner::BottomRight => ("右下角", "Bottom-right corner"),
+            Corner::BottomLeft => ("左下角", "Bottom-left corner"),
+        };
+        locale.pick(zh, en)
+    }
+}
+
+/// Guided calibration of [`CursorConfig::mapping`]: point at each corner of
+/// the screen in turn and pinch to take it. The corner is where the finger
+/// pointed just before the pinch, since pinching pulls the tip aside.
+#[derive(Debug, Default)]
+pub struct CursorCalibration {
+    corners: Vec<(f32, f32)>,
+    aim: Option<(f32, f32)>,
+    pinched: bool,
+}
+
+impl CursorCalibration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The corner to point at next.
+    pub fn corner(&self) -> Corner {
+        Corner::ALL[self.corners.len().min(Corner::ALL.len() - 1)]
+    }
+
+    /// Takes the next corner when a pinch closes, and returns the mapping
+    /// once the fourth is in. Corners too close together to make a usable
+    /// mapping start the calibration over.
+    pub fn observe(&mut self, result: &GestureResult) -> Option<[f32; 4]> {
+        let detail = result.detail.as_ref()?;
+        if matches!(detail.primary, GestureKind::Point | GestureKind::One) {
+            self.aim = fingertip(result);
+        }
+        let pinched = detail.pinch.is_some_and(|pinch| pinch.closed);
+        let closed = pinched && !self.pinched;
+        self.pinched = pinched;
+        if !closed {
+            return None;
+        }
+        self.corners.push(self.aim.take()?);
+        if self.corners.len() < Corner::ALL.len() {
+            return None;
+        }
+
+        let corners = std::mem::take(&mut self.corners);
+        let [top_left, top_right, bottom_right, bottom_left] = [0, 1, 2, 3].map(|i| corners[i]);
+        let mapping = [
+            (top_left.0 + bottom_left.0) / 2.0,
+            (top_left.1 + top_right.1) / 2.0,
+            (top_right.0 + bottom_right.0) / 2.0,
+            (bottom_left.1 + bottom_right.1) / 2.0,
+        ];
+        let span = (mapping[2] - mapping[0])
+            .abs()
+            .min((mapping[3] - mapping[1]).abs());
+        if span < MIN_MAPPING_SPAN {
+            log::warn!("calibrated corners {mapping:?} are too close together, starting over");
+            return None;
+        }
+        Some(mapping)
+    }
+}
+
+/// The primary hand's index fingertip as fractions of the frame.
+fn fingertip(result: &GestureResult) -> Option<(f32, f32)> {
+    let (width, height) = result.frame_size;
+    if width == 0 || height == 0 {
+        return None;
+    }
+    let (x, y) = *result.landmarks.as_ref()?.get(INDEX_TIP)?;
+    Some((x / width as f32, y / height as f32))
+}
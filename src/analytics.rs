@@ -0,0 +1,206 @@
+//! Which gestures get used and for how long, kept in memory for the session
+//! and written out only when asked. Nothing leaves the machine.
+
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::types::{GestureEvent, GestureKind, GestureResult};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 2] = [ExportFormat::Csv, ExportFormat::Json];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Json => "JSON",
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+/// Totals for one gesture kind.
+#[derive(Clone, Copy, Debug, Default)]
+struct KindTotals {
+    /// Debounced starts, so a gesture held for a minute counts once.
+    count: u32,
+    held: Duration,
+    confidence_sum: f64,
+    /// Frames whose primary gesture was this kind.
+    frames: u64,
+}
+
+/// Counts, held time and confidence per gesture kind over a session.
+/// Everything is a map update per event or frame.
+#[derive(Clone, Debug, Default)]
+pub struct GestureAnalytics {
+    kinds: HashMap<GestureKind, KindTotals>,
+    /// The gesture held right now and for how long so far.
+    holding: Option<(GestureKind, Duration)>,
+    frames: u64,
+    detected_frames: u64,
+}
+
+/// One row of [`AnalyticsSummary`].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct KindSummary {
+    pub kind: GestureKind,
+    pub count: u32,
+    pub held_secs: f64,
+    /// `None` when no frame had this kind as its primary gesture.
+    pub average_confidence: Option<f32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AnalyticsSummary {
+    pub frames: u64,
+    pub detected_frames: u64,
+    /// Fraction of frames with a hand detected, `None` before any frame.
+    pub efficiency: Option<f32>,
+    /// Most often performed first, then longest held.
+    pub gestures: Vec<KindSummary>,
+}
+
+impl GestureAnalytics {
+    pub fn observe_event(&mut self, event: &GestureEvent) {
+        match *event {
+            GestureEvent::Started(kind) => {
+                self.finish_hold();
+                self.kinds.entry(kind).or_default().count += 1;
+                self.holding = Some((kind, Duration::ZERO));
+            }
+            GestureEvent::Held { kind, duration } => match &mut self.holding {
+                Some((held, so_far)) if *held == kind => *so_far = (*so_far).max(duration),
+                _ => self.holding = Some((kind, duration)),
+            },
+            GestureEvent::Ended(_) => self.finish_hold(),
+            GestureEvent::Motion { .. }
+            | GestureEvent::DwellCompleted(_)
+            | GestureEvent::SequenceMatched(_)
+            | GestureEvent::DragStarted { .. }
+            | GestureEvent::DragMoved { .. }
+            | GestureEvent::DragEnded { .. } => {}
+        }
+    }
+
+    pub fn observe_frame(&mut self, result: &GestureResult) {
+        self.frames += 1;
+        if !result.hands.is_empty() {
+            self.detected_frames += 1;
+        }
+        if let Some(detail) = &result.detail {
+            let totals = self.kinds.entry(detail.primary).or_default();
+            totals.confidence_sum += f64::from(result.confidence);
+            totals.frames += 1;
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// The totals so far, a gesture still held counted up to now.
+    pub fn summary(&self) -> AnalyticsSummary {
+        let mut gestures: Vec<KindSummary> = self
+            .kinds
+            .iter()
+            .map(|(&kind, totals)| {
+                let holding = match self.holding {
+                    Some((held, so_far)) if held == kind => so_far,
+                    _ => Duration::ZERO,
+                };
+                KindSummary {
+                    kind,
+                    count: totals.count,
+                    held_secs: (totals.held + holding).as_secs_f64(),
+                    average_confidence: (totals.frames > 0)
+                        .then(|| (totals.confidence_sum / totals.frames as f64) as f32),
+                }
+            })
+            .collect();
+        gestures.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then(b.held_secs.total_cmp(&a.held_secs))
+                .then_with(|| a.kind.display_name().cmp(b.kind.display_name()))
+        });
+        AnalyticsSummary {
+            frames: self.frames,
+            detected_frames: self.detected_frames,
+            efficiency: (self.frames > 0).then(|| self.detected_frames as f32 / self.frames as f32),
+            gestures,
+        }
+    }
+
+    fn finish_hold(&mut self) {
+        if let Some((kind, held)) = self.holding.take() {
+            self.kinds.entry(kind).or_default().held += held;
+        }
+    }
+}
+
+impl AnalyticsSummary {
+    /// One `kind,count,held_secs,average_confidence` row per gesture, with
+    /// the frame totals in a leading comment.
+    pub fn to_csv(&self) -> String {
+        let efficiency = self.efficiency.map_or(String::new(), |e| format!("{e:.4}"));
+        let mut csv = format!(
+            "# frames={} detected_frames={} efficiency={efficiency}\n",
+            self.frames, self.detected_frames
+        );
+        csv.push_str("kind,count,held_secs,average_confidence\n");
+        for row in &self.gestures {
+            let kind = serde_json::to_value(row.kind)
+                .ok()
+                .and_then(|value| value.as_str().map(str::to_string))
+                .unwrap_or_default();
+            let confidence = row
+                .average_confidence
+                .map_or(String::new(), |c| format!("{c:.4}"));
+            let _ = writeln!(
+                csv,
+                "{kind},{},{:.3},{confidence}",
+                row.count, row.held_secs
+            );
+        }
+        csv
+    }
+
+    /// Writes the summary as `analytics-<unix ms>.<ext>` into `dir` and
+    /// returns the path.
+    pub fn export(&self, dir: &Path, format: ExportFormat) -> Result<PathBuf> {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create directory {}", dir.display()))?;
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let path = dir.join(format!("analytics-{stamp}.{}", format.extension()));
+        let text = match format {
+            ExportFormat::Csv => self.to_csv(),
+            ExportFormat::Json => serde_json::to_string_pretty(self)?,
+        };
+        fs::write(&path, text).with_context(|| format!("failed to write {}", path.display()))?;
+        Ok(path)
+    }
+}
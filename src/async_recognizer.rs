@@ -0,0 +1,88 @@
+//! Recognizer results as tokio channels, for async apps. The worker runs on
+//! its own threads as usual; one adapter thread relays frames to it and
+//! publishes what comes back, and stops it once nobody is listening.
+
+use std::{thread, time::Duration};
+
+use crossbeam_channel::{Receiver, bounded, never, select, unbounded};
+use tokio::sync::{broadcast, watch};
+
+use crate::{
+    pipeline::{MetricsHandle, RecognizerBackend, RecognizerControl, start_recognizer},
+    types::{Frame, GestureEvent, GestureResult},
+};
+
+/// Events kept for each subscriber; one that falls further behind skips
+/// ahead with [`broadcast::error::RecvError::Lagged`].
+const EVENT_CAPACITY: usize = 64;
+/// How often the adapter checks for dropped receivers while no frames come.
+const CLOSED_POLL: Duration = Duration::from_millis(200);
+
+/// What [`start_recognizer_async`] publishes. Once both are dropped, along
+/// with every receiver cloned or subscribed from them, the worker stops.
+pub struct AsyncGestures {
+    /// The latest result, `None` until the first frame is recognized. Like
+    /// the blocking channels, a slow reader skips results rather than
+    /// queueing them.
+    pub results: watch::Receiver<Option<GestureResult>>,
+    /// Debounced gesture starts, holds and ends, plus dwell, sequence and
+    /// drag events.
+    pub events: broadcast::Receiver<GestureEvent>,
+}
+
+/// Starts the recognizer on `frames` the way [`start_recognizer`] does, but
+/// publishes through tokio channels. Needs no runtime of its own, so it can
+/// be called from inside or outside one.
+pub fn start_recognizer_async(
+    backend: RecognizerBackend,
+    frames: Receiver<Frame>,
+    control_rx: Receiver<RecognizerControl>,
+    metrics: MetricsHandle,
+) -> AsyncGestures {
+    let (frame_tx, frame_rx) = bounded(1);
+    let (result_tx, result_rx) = bounded(1);
+    let (event_tx, event_rx) = unbounded();
+    let worker = start_recognizer(backend, frame_rx, control_rx, result_tx, event_tx, metrics);
+
+    let (results_tx, results) = watch::channel(None);
+    let (events_tx, events) = broadcast::channel(EVENT_CAPACITY);
+    thread::spawn(move || {
+        let mut frames = frames;
+        // Dropping the sender ends the worker loop, as when a camera stops.
+        let mut frame_tx = Some(frame_tx);
+        while !(results_tx.is_closed() && events_tx.receiver_count() == 0) {
+            select! {
+                recv(frames) -> frame => match frame {
+                    // A full channel means the worker is still busy; like a
+                    // camera, the adapter drops the frame rather than queue it.
+                    Ok(frame) => {
+                        if let Some(frame_tx) = &frame_tx {
+                            let _ = frame_tx.try_send(frame);
+                        }
+                    }
+                    Err(_) => {
+                        frames = never();
+                        frame_tx = None;
+                    }
+                },
+                recv(result_rx) -> recognized => match recognized {
+                    Ok(recognized) => {
+                        results_tx.send_replace(Some(recognized.result));
+                    }
+                    Err(_) => break,
+                },
+                recv(event_rx) -> event => match event {
+                    // No subscribers is not an error to the worker.
+                    Ok(event) => {
+                        let _ = events_tx.send(event);
+                    }
+                    Err(_) => break,
+                },
+                default(CLOSED_POLL) => {}
+            }
+        }
+        drop(frame_tx);
+        let _ = worker.join();
+    });
+    AsyncGestures { results, events }
+}
@@ -0,0 +1,455 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    locale::{Locale, locale},
+    mqtt_output::MqttConfig,
+    osc_output::OscConfig,
+    pipeline::{
+        CameraKnob, CameraOpenOptions, ClipFormat, DEFAULT_CAPTURES_DIR,
+        DEFAULT_VIRTUAL_CAMERA_DEVICE, EXECUTION_PROVIDER_ENV, ExecutionProvider,
+        HandposeOutputNames, OutputSize, PalmDetectorConfig, PhotoBoothConfig, RecognizerBackend,
+        parse_frame_format,
+        recorder::RecorderSettings,
+        skeleton::{OverlayMode, SkeletonStyle},
+    },
+    types::{
+        DwellConfig, GestureKind, GestureSequence, LabelStabilizerConfig, LandmarkNormalization,
+        MotionGateConfig, QualityGateConfig, RecognizerConfig, SmoothingConfig,
+    },
+};
+
+const SETTINGS_DIRNAME: &str = "gesture-universe";
+const SETTINGS_FILENAME: &str = "settings.toml";
+
+pub const SKELETON_STYLE_DEFAULT: &str = "default";
+pub const SKELETON_STYLE_PER_FINGER: &str = "per_finger";
+
+/// `gesture-universe/settings.toml` in the platform config directory, e.g.
+/// `~/.config` on Linux; `config/` next to the working directory where the
+/// platform has none.
+pub fn default_settings_path() -> PathBuf {
+    dirs::config_dir()
+        .map_or_else(|| PathBuf::from("config"), |dir| dir.join(SETTINGS_DIRNAME))
+        .join(SETTINGS_FILENAME)
+}
+
+/// User choices that survive a restart. Unset fields keep the built-in
+/// defaults, and keys this version does not know about are written back
+/// untouched so newer settings are not lost.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppSettings {
+    /// Matched against the camera labels first; the index is the fallback for
+    /// devices that share a label.
+    pub camera_label: Option<String>,
+    pub camera_index: Option<usize>,
+    /// Capture mode to ask the camera for; unset lets the driver choose.
+    pub camera_format: Option<CameraFormatSetting>,
+    pub mirror: Option<bool>,
+    /// Exposure, gain and white balance set in the tuning panel, per camera
+    /// label; reapplied whenever that camera is opened.
+    pub camera_controls: Option<BTreeMap<String, BTreeMap<CameraKnob, i64>>>,
+    pub detection_threshold: Option<f32>,
+    pub classification_threshold: Option<f32>,
+    pub overlay_threshold: Option<f32>,
+    pub pinch_threshold: Option<f32>,
+    pub palm_score_threshold: Option<f32>,
+    /// Long side of the inference input in pixels; 0 keeps full resolution.
+    pub max_input_dimension: Option<u32>,
+    pub inference_budget_ms: Option<u64>,
+    /// Frames between palm detections while hands are tracked; 1 detects on
+    /// every frame.
+    pub palm_detect_interval: Option<u32>,
+    /// Frames a lost hand keeps its track id for.
+    pub track_max_misses: Option<u32>,
+    /// `[x1, y1, x2, y2]` fractions of the frame gestures are taken from;
+    /// unset uses the whole frame.
+    pub roi: Option<[f32; 4]>,
+    pub depth_overlay: Option<bool>,
+    pub count_half_bent: Option<bool>,
+    /// `bounding_box` restores the old landmark scaling while thresholds are
+    /// being compared.
+    pub normalization: Option<LandmarkNormalization>,
+    /// [`SKELETON_STYLE_DEFAULT`] or [`SKELETON_STYLE_PER_FINGER`].
+    pub skeleton_style: Option<String>,
+    pub overlay_mode: Option<OverlayMode>,
+    pub handpose_model_path: Option<PathBuf>,
+    pub palm_detector_model_path: Option<PathBuf>,
+    /// Base URL, `http(s)://` or `file://`, to fetch the models from before
+    /// the published location; `GU_MODEL_MIRROR` wins over it.
+    pub model_mirror: Option<String>,
+    /// Output names for a custom handpose model; missing keys keep the
+    /// MediaPipe names.
+    pub handpose_outputs: Option<HandposeOutputNames>,
+    /// Execution provider label; the `GESTURE_EP` variable still wins.
+    pub execution_provider: Option<String>,
+    /// Hold-to-select target; unset leaves dwell detection off.
+    pub dwell: Option<DwellConfig>,
+    /// Landmark smoothing; unset leaves it off.
+    pub smoothing: Option<SmoothingConfig>,
+    /// Dark and washed-out frame limits; the built-in ones unless set.
+    pub quality_gate: Option<QualityGateConfig>,
+    /// Idling on a still scene; off unless set.
+    pub motion_gate: Option<MotionGateConfig>,
+    /// Named gesture combos reported when completed; none unless set.
+    pub sequences: Option<Vec<GestureSequence>>,
+    /// Gestures the classifier may report; all of them unless set.
+    pub enabled_gestures: Option<Vec<GestureKind>>,
+    /// Gestures the overlay pixelates instead of drawing; none unless set.
+    pub censor_gestures: Option<Vec<GestureKind>>,
+    /// Draws the gesture label into the composited frame itself.
+    pub burn_in_label: Option<bool>,
+    /// Font with CJK glyphs for the burned-in label; a system font otherwise.
+    pub label_font_path: Option<PathBuf>,
+    /// Draws a ray where pointing hands are aimed.
+    pub pointing_ray: Option<bool>,
+    /// Shows the overlay on a blank background instead of the camera image and
+    /// turns recording, captures and clips off; off unless set.
+    pub privacy_mode: Option<bool>,
+    /// Voting behind the big gesture label; the built-in one unless set.
+    pub label_stabilizer: Option<LabelStabilizerConfig>,
+    /// Size the composited frames are fitted into; the camera's own unless set.
+    pub output_size: Option<OutputSize>,
+    /// What the clip button exports the last seconds as.
+    pub clip_format: Option<ClipFormat>,
+    /// Where the capture button writes its PNG and JSON pairs.
+    pub captures_dir: Option<PathBuf>,
+    /// Where sessions are recorded, at what frame rate and up to what size;
+    /// the built-in limits unless set.
+    pub recording: Option<RecorderSettings>,
+    /// Countdown photos triggered by a held gesture; off unless enabled.
+    pub photo_booth: Option<PhotoBoothConfig>,
+    /// v4l2loopback device the virtual camera writes to.
+    pub virtual_camera_device: Option<PathBuf>,
+    /// Broker to publish gesture events to; unset leaves MQTT off.
+    pub mqtt: Option<MqttConfig>,
+    /// Receiver to send each result to over OSC; unset leaves OSC off.
+    pub osc: Option<OscConfig>,
+    /// The always-on-top mini window: whether it is open and where it sits.
+    pub mini_overlay: Option<MiniOverlaySettings>,
+    /// Counts gestures for the stats panel; off unless turned on. The counts
+    /// themselves are never saved.
+    pub gesture_analytics: Option<bool>,
+    /// Language of the gesture names and other labels; from the system
+    /// locale unless set.
+    pub locale: Option<Locale>,
+    #[serde(flatten)]
+    pub extra: toml::Table,
+}
+
+/// A camera mode as saved, e.g. `{ width = 1920, height = 1080, fps = 30,
+/// format = "MJPEG" }`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CameraFormatSetting {
+    pub width: u32,
+    pub height: u32,
+    pub fps: u32,
+    pub format: String,
+}
+
+impl From<CameraOpenOptions> for CameraFormatSetting {
+    fn from(options: CameraOpenOptions) -> Self {
+        Self {
+            width: options.resolution.width(),
+            height: options.resolution.height(),
+            fps: options.fps,
+            format: options.format.to_string(),
+        }
+    }
+}
+
+/// Where the mini window goes until it has been moved somewhere of its own.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlayCorner {
+    #[default]
+    TopRight,
+    BottomRight,
+    BottomLeft,
+    TopLeft,
+}
+
+impl OverlayCorner {
+    pub const ALL: [OverlayCorner; 4] = [
+        OverlayCorner::TopRight,
+        OverlayCorner::BottomRight,
+        OverlayCorner::BottomLeft,
+        OverlayCorner::TopLeft,
+    ];
+
+    pub fn label(self) -> &'static str {
+        self.label_in(locale())
+    }
+
+    pub fn label_in(self, locale: Locale) -> &'static str {
+        let (zh, en) = match self {
+            OverlayCorner::TopRight => ("右上", "Top right"),
+            OverlayCorner::BottomRight => ("右下", "Bottom right"),
+            OverlayCorner::BottomLeft => ("左下", "Bottom left"),
+            OverlayCorner::TopLeft => ("左上", "Top left"),
+        };
+        locale.pick(zh, en)
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL
+            .iter()
+            .position(|corner| *corner == self)
+            .unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MiniOverlaySettings {
+    /// Opens it with the main window; set by `--mini` and never saved.
+    #[serde(skip)]
+    pub open: bool,
+    pub corner: OverlayCorner,
+    /// UUID of the monitor it was last on; the primary one when that is gone.
+    pub display: Option<String>,
+    /// Top-left corner relative to that monitor once moved; unset places it
+    /// in `corner`.
+    pub offset: Option<[f32; 2]>,
+}
+
+impl AppSettings {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("failed to read settings {}", path.display()))?;
+        toml::from_str(&text)
+            .with_context(|| format!("failed to parse settings {}", path.display()))
+    }
+
+    /// Falls back to the defaults when the file is missing or unreadable. A
+    /// key whose value does not fit is left out on its own, so the rest of the
+    /// file, unknown keys included, survives the next save. Either way the file
+    /// is first copied to a `.bak` beside it, which is never overwritten.
+    pub fn load_or_default(path: &Path) -> Self {
+        if !path.exists() {
+            return Self::default();
+        }
+        let table = fs::read_to_string(path)
+            .with_context(|| format!("failed to read settings {}", path.display()))
+            .and_then(|text| {
+                text.parse::<toml::Table>()
+                    .with_context(|| format!("failed to parse settings {}", path.display()))
+            });
+        let table = match table {
+            Ok(table) => table,
+            Err(err) => {
+                log::warn!("{err:#}, using default settings");
+                back_up(path);
+                return Self::default();
+            }
+        };
+        let (settings, rejected) = Self::from_table(table);
+        if !rejected.is_empty() {
+            log::warn!(
+                "ignoring settings {} cannot use: {}",
+                path.display(),
+                rejected.join(", ")
+            );
+            back_up(path);
+        }
+        settings
+    }
+
+    /// Reads `table` without the top-level keys whose values do not fit, and
+    /// returns which keys those were.
+    fn from_table(mut table: toml::Table) -> (Self, Vec<String>) {
+        let read = |table: toml::Table| toml::Value::Table(table).try_into::<Self>();
+        if let Ok(settings) = read(table.clone()) {
+            return (settings, Vec::new());
+        }
+        let rejected: Vec<String> = table
+            .iter()
+            .filter(|(key, value)| {
+                read(toml::Table::from_iter([((*key).clone(), (*value).clone())])).is_err()
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in &rejected {
+            table.remove(key);
+        }
+        let settings = read(table).unwrap_or_else(|err| {
+            log::warn!("failed to read settings: {err}, using default settings");
+            Self::default()
+        });
+        (settings, rejected)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let text = toml::to_string_pretty(self)?;
+        // Written next to the target first so a crash mid-write cannot leave a
+        // truncated file behind.
+        let tmp = path.with_extension("toml.tmp");
+        fs::write(&tmp, text)
+            .with_context(|| format!("failed to write settings {}", tmp.display()))?;
+        fs::rename(&tmp, path)
+            .with_context(|| format!("failed to replace settings {}", path.display()))
+    }
+
+    pub fn recognizer_config(&self, base: RecognizerConfig) -> RecognizerConfig {
+        RecognizerConfig {
+            detection_threshold: self.detection_threshold.unwrap_or(base.detection_threshold),
+            classification_threshold: self
+                .classification_threshold
+                .unwrap_or(base.classification_threshold),
+            overlay_threshold: self.overlay_threshold.unwrap_or(base.overlay_threshold),
+            pinch_threshold: self.pinch_threshold.unwrap_or(base.pinch_threshold),
+            mirror: self.mirror.unwrap_or(base.mirror),
+            depth_overlay: self.depth_overlay.unwrap_or(base.depth_overlay),
+            count_half_bent: self.count_half_bent.unwrap_or(base.count_half_bent),
+            normalization: self.normalization.unwrap_or(base.normalization),
+            dwell: self.dwell.or(base.dwell),
+            smoothing: self.smoothing.or(base.smoothing),
+            quality_gate: self.quality_gate.unwrap_or(base.quality_gate),
+            motion_gate: self.motion_gate.unwrap_or(base.motion_gate),
+            ..base
+        }
+    }
+
+    pub fn skeleton_style(&self) -> SkeletonStyle {
+        match self.skeleton_style.as_deref() {
+            Some(SKELETON_STYLE_PER_FINGER) => SkeletonStyle::per_finger(),
+            Some(SKELETON_STYLE_DEFAULT) | None => SkeletonStyle::default(),
+            Some(other) => {
+                log::warn!("unknown skeleton style {other:?} in settings, using the default");
+                SkeletonStyle::default()
+            }
+        }
+    }
+
+    pub fn skeleton_style_name(style: &SkeletonStyle) -> &'static str {
+        if *style == SkeletonStyle::per_finger() {
+            SKELETON_STYLE_PER_FINGER
+        } else {
+            SKELETON_STYLE_DEFAULT
+        }
+    }
+
+    pub fn censor_gestures(&self) -> HashSet<GestureKind> {
+        self.censor_gestures.iter().flatten().copied().collect()
+    }
+
+    pub fn captures_dir(&self) -> PathBuf {
+        self.captures_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_CAPTURES_DIR))
+    }
+
+    pub fn virtual_camera_device(&self) -> PathBuf {
+        self.virtual_camera_device
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_VIRTUAL_CAMERA_DEVICE))
+    }
+
+    pub fn camera_format(&self) -> Option<CameraOpenOptions> {
+        let saved = self.camera_format.as_ref()?;
+        let Some(format) = parse_frame_format(&saved.format) else {
+            log::warn!(
+                "unknown camera format {:?} in settings, ignoring it",
+                saved.format
+            );
+            return None;
+        };
+        Some(CameraOpenOptions {
+            resolution: nokhwa::utils::Resolution::new(saved.width, saved.height),
+            fps: saved.fps,
+            format,
+        })
+    }
+
+    pub fn execution_provider(&self) -> Option<ExecutionProvider> {
+        let label = self.execution_provider.as_deref()?;
+        let provider = ExecutionProvider::parse(label);
+        if provider.is_none() {
+            log::warn!("unknown execution provider {label:?} in settings, ignoring it");
+        }
+        provider
+    }
+
+    /// Layers the saved choices over `backend`.
+    pub fn apply_to_backend(&self, mut backend: RecognizerBackend) -> RecognizerBackend {
+        let config = self.recognizer_config(backend.config());
+        backend = backend.with_config(config);
+        if let Some(path) = &self.handpose_model_path {
+            backend = backend.with_handpose_estimator_model_path(path.clone());
+        }
+        if let Some(path) = &self.palm_detector_model_path {
+            backend = backend.with_palm_detector_model_path(path.clone());
+        }
+        if let Some(names) = &self.handpose_outputs {
+            backend = backend.with_handpose_output_names(names.clone());
+        }
+        if let Some(sequences) = &self.sequences {
+            backend = backend.with_sequences(sequences.clone());
+        }
+        if let Some(gestures) = &self.enabled_gestures {
+            backend = backend.with_enabled_gestures(gestures.iter().copied().collect());
+        }
+        let palm_config = backend.palm_config();
+        backend = backend.with_palm_config(PalmDetectorConfig {
+            score_threshold: self
+                .palm_score_threshold
+                .unwrap_or(palm_config.score_threshold),
+            max_input_dimension: self
+                .max_input_dimension
+                .unwrap_or(palm_config.max_input_dimension),
+            inference_budget: self
+                .inference_budget_ms
+                .map_or(palm_config.inference_budget, Duration::from_millis),
+            detect_interval: self
+                .palm_detect_interval
+                .unwrap_or(palm_config.detect_interval),
+            track_max_misses: self
+                .track_max_misses
+                .unwrap_or(palm_config.track_max_misses),
+            roi: self.roi.or(palm_config.roi),
+            keep_candidates: palm_config.keep_candidates
+                || self.overlay_mode == Some(OverlayMode::Debug),
+            ..palm_config
+        });
+        if std::env::var_os(EXECUTION_PROVIDER_ENV).is_none()
+            && let Some(provider) = self.execution_provider()
+        {
+            backend = backend.with_execution_provider(provider);
+        }
+        backend
+    }
+}
+
+/// Copies `path` to the first of `settings.toml.bak`, `settings.toml.bak.1`,
+/// … that does not exist yet, unless one of them already holds the same
+/// bytes, e.g. when `--headless` keeps starting from the same file.
+fn back_up(path: &Path) {
+    let contents = fs::read(path).ok();
+    let backup = (0..)
+        .map(|n| match n {
+            0 => path.with_extension("toml.bak"),
+            n => path.with_extension(format!("toml.bak.{n}")),
+        })
+        .find(|candidate| !candidate.exists() || fs::read(candidate).ok() == contents)
+        .expect("some backup name is free");
+    if backup.exists() {
+        return;
+    }
+    match fs::copy(path, &backup) {
+        Ok(_) => log::warn!("kept the previous settings as {}", backup.display()),
+        Err(err) => log::warn!("failed to back up {}: {err}", path.display()),
+    }
+}
@@ -1,5 +1,6 @@
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
+    f32::consts::{PI, TAU},
     time::{Duration, Instant},
 };
 
@@ -7,34 +8,282 @@ use crate::{
     model_download::{
         default_gesture_classifier_model_path, ensure_gesture_classifier_model_ready,
     },
-    types::{FingerState, GestureDetail, GestureKind, GestureMotion, Handedness},
+    types::{
+        CompositeGesture, DwellConfig, DwellProgress, FingerAngles, FingerState, GestureDetail,
+        GestureEvent, GestureKind, GestureMotion, GestureResult, GestureSequence, HandOrientation,
+        HandResult, Handedness, LabelStabilizerConfig, LandmarkNormalization, PinchInfo,
+        PointingInfo, RecognizerConfig, RotationInfo, register_custom_gesture,
+    },
 };
 use ndarray::Array2;
 use ort::session::Session;
 
-const MIN_CONFIDENCE: f32 = 0.2;
+pub mod plugin;
+
+use plugin::{GestureContext, GesturePlugin, builtin_plugins};
+
 const MOTION_WINDOW: Duration = Duration::from_millis(1_200);
+/// Net travel, in hand spans, a stroke needs to count as a swipe.
+const SWIPE_MIN_DISTANCE: f32 = 0.8;
+/// Spans per second; slower drifts stay `Moving`.
+const SWIPE_MIN_SPEED: f32 = 1.5;
+/// How much the swipe axis must dominate the other one.
+const SWIPE_AXIS_RATIO: f32 = 2.0;
+/// Only the most recent part of the history is used for push/pull.
+const DEPTH_WINDOW: Duration = Duration::from_millis(600);
+/// Relative hand span growth (or shrink) that counts as a push (or pull).
+const DEPTH_MIN_SCALE: f32 = 1.4;
+/// Wrist travel, in hand spans, beyond which a span change is not push/pull.
+const DEPTH_MAX_LATERAL: f32 = 0.35;
+/// Samples averaged per span value, to ride out palm re-crops.
+const DEPTH_SMOOTHING: usize = 3;
+/// Wrist and knuckles: the part of the hand whose size only changes with its
+/// distance from the camera, not with the fingers opening or closing.
+const PALM_LANDMARKS: [usize; 6] = [0, 1, 5, 9, 13, 17];
+/// A smoothed span may back off this much and still count as monotonic.
+const DEPTH_JITTER: f32 = 0.05;
+/// A closed pinch reopens at this multiple of the close threshold.
+const PINCH_RELEASE_RATIO: f32 = 1.4;
+/// Weight of the newest sample in the smoothed pinch distance.
+const PINCH_SMOOTHING: f32 = 0.4;
+/// Weight of the newest sample in the smoothed pointing direction; fingertip
+/// jitter swings the raw angle by several degrees a frame.
+const POINT_SMOOTHING: f32 = 0.3;
+/// Wrist path a dial turn is fitted to.
+const ROTATION_WINDOW: Duration = Duration::from_millis(1_000);
+const ROTATION_MIN_SAMPLES: usize = 8;
+/// Fitted radius, in hand spans, a dial turn has to stay within; a path that
+/// straightens out fits ever larger circles.
+const ROTATION_MIN_RADIUS: f32 = 0.3;
+const ROTATION_MAX_RADIUS: f32 = 3.0;
+/// RMS distance of the path from the fitted circle, as a fraction of its
+/// radius, beyond which the path is not round.
+const ROTATION_MAX_RESIDUAL: f32 = 0.15;
+/// Turn around the fitted centre before rotation is reported.
+const ROTATION_ENGAGE_DEG: f32 = 120.0;
+/// How far, as a fraction of the radius, the circle fitted to the older half
+/// of the path may sit from the one fitted to all of it. A circle fits the
+/// same way throughout; a figure-eight lobe tightens as it goes.
+const ROTATION_MAX_DRIFT: f32 = 0.1;
+/// Share of the palm's normal along the camera axis below which the palm is
+/// seen edge-on; about 65° off square.
+const ORIENTATION_SIDE_BELOW: f32 = 0.4;
+/// Bounding box side of an open hand measured in palm lengths. Bounding box
+/// normalization is rescaled by it so both schemes share one set of thresholds,
+/// each twice its old box-unit value.
+const BOX_SPAN_IN_PALM_LENGTHS: f32 = 2.0;
+/// Candidates kept in `GestureDetail::scores`.
+const MAX_SCORES: usize = 5;
+/// Lowest rule template score that still counts as a match.
+const RULE_MIN_SCORE: f32 = 0.5;
+/// Thumb and index tip gap, in palm lengths, that closes the OK ring.
+const OK_TIP_GAP: f32 = 0.25;
+/// Thumb tip distance from the index knuckle, in palm lengths, below which the
+/// touching tips are a pinch rather than a ring.
+const OK_MIN_KNUCKLE_GAP: f32 = 0.35;
+/// Thumb tip height in front of the palm, in palm lengths, below which touching
+/// tips are the thumb pressed against the index rather than opposed to it.
+const OK_MIN_OPPOSITION: f32 = 0.1;
+/// Cosine of the index PIP angle above which the finger is too straight to
+/// curl into a ring.
+const OK_MAX_PIP_STRAIGHTNESS: f32 = 0.8;
+/// Index and middle tip gap, in palm lengths, within which the two fingers can
+/// be crossed; a V is spread well beyond it.
+const CROSSED_TIP_GAP: f32 = 0.3;
+/// Thumb to palm line angles, in degrees, beyond which the thumb is clearly
+/// held out (I love you) or clearly lies along the fingers (Rock); in between
+/// the thumb state decides.
+const I_LOVE_YOU_MIN_ABDUCTION_DEG: f32 = 125.0;
+const ROCK_MAX_ABDUCTION_DEG: f32 = 100.0;
+/// Thumb cutoffs, in palm lengths, measured on the thumb flattened onto the
+/// palm plane. A tip within the spread of the index or little knuckle folds
+/// the thumb when it is bent or barely reaches past its MCP joint; a long,
+/// straight thumb whose tip reaches well past its IP joint is extended.
+const THUMB_FOLDED_MAX_SPREAD: f32 = 0.5;
+const THUMB_FOLDED_MAX_REACH: f32 = 0.3;
+const THUMB_EXTENDED_MIN_LENGTH: f32 = 0.6;
+const THUMB_EXTENDED_MIN_EXTENSION: f32 = 0.16;
+/// Mean cosine between the thumb's bones above which it counts as straight.
+const THUMB_MIN_STRAIGHTNESS: f32 = 0.28;
+/// A thumb tip this far in front of the palm and this far in from the index
+/// knuckle towards the little one lies tucked across the fingers.
+const THUMB_TUCKED_MIN_OPPOSITION: f32 = 0.2;
+const THUMB_TUCKED_MIN_INSET: f32 = 0.1;
+/// Joint angles, in degrees, that split the four fingers' states. Extended
+/// needs both joints above their bound; either joint below its folded bound
+/// folds the finger.
+const EXTENDED_MIN_MCP_DEG: f32 = 140.0;
+const EXTENDED_MIN_PIP_DEG: f32 = 150.0;
+const FOLDED_MAX_MCP_DEG: f32 = 110.0;
+const FOLDED_MAX_PIP_DEG: f32 = 100.0;
+/// Frames of finger counts voted over.
+const COUNT_WINDOW: usize = 5;
+/// Frames a held two-hand pose must persist before it is reported.
+const COMPOSITE_MIN_FRAMES: u32 = 4;
+/// How far back a clap looks for the hands coming together.
+const CLAP_WINDOW: Duration = Duration::from_millis(500);
+/// Distance, in hand spans, the palms must close by within the window.
+const CLAP_MIN_APPROACH: f32 = 1.0;
+/// Palm gap, in hand spans, that counts as touching.
+const CLAP_CONTACT: f32 = 0.9;
+/// Touching frames needed, so a single overlapping frame is not a clap.
+const CLAP_CONTACT_FRAMES: u32 = 2;
+/// Fingertip gap, in hand spans, that counts as adjacent for the heart.
+const HEART_TIP_GAP: f32 = 0.35;
+/// How long an open palm has to keep fanning before it counts as a wave.
+const WAVE_MIN_DURATION: Duration = Duration::from_millis(600);
+/// Frames without fanning within this keep the wave, so one dropped frame does
+/// not demote it back to a palm.
+const WAVE_GRACE: Duration = Duration::from_millis(250);
+/// A fist arms a grab only this soon after an open palm, so closing the hand
+/// over a few unclassified frames still counts.
+const DRAG_ARM_WINDOW: Duration = Duration::from_millis(600);
+/// How long a drag survives frames that are not a fist, or lack the hand,
+/// before it is cancelled.
+const DRAG_GRACE: Duration = Duration::from_millis(300);
+/// Unknown frames, or frames without a hand, forgiven during a hold to
+/// confirm.
+const HOLD_CONFIRM_GRACE: Duration = Duration::from_millis(200);
+/// Weight of the newest wrist position in the smoothed drag position.
+const DRAG_SMOOTHING: f32 = 0.5;
+/// Decayed votes below this are dropped, so a gesture seen once long ago does
+/// not linger as the runner-up.
+const LABEL_VOTE_FLOOR: f32 = 0.05;
+/// Handedness scores a tracked hand has to cross to switch sides; in between
+/// it keeps its last side, so a score wavering around 0.5 does not flicker.
+const HANDEDNESS_RIGHT_ABOVE: f32 = 0.65;
+const HANDEDNESS_LEFT_BELOW: f32 = 0.35;
 
 pub struct GestureClassifier {
-    motion_tracker: MotionTracker,
+    min_confidence: f32,
+    mirror: bool,
+    pinch_threshold: f32,
+    motion_trackers: HashMap<u64, MotionTracker>,
+    pinch_trackers: HashMap<u64, PinchTracker>,
+    point_trackers: HashMap<u64, PointTracker>,
+    rotation_trackers: HashMap<u64, RotationTracker>,
+    count_trackers: HashMap<u64, CountTracker>,
+    wave_trackers: HashMap<u64, WaveTracker>,
+    /// Side each tracked hand was last seen as, before `mirror`.
+    handedness_trackers: HashMap<u64, Handedness>,
+    count_half_bent: bool,
+    normalization: LandmarkNormalization,
+    two_hand: TwoHandClassifier,
+    /// Gestures left out here fall through to the next candidate.
+    enabled_gestures: HashSet<GestureKind>,
     model_session: Option<Session>,
     class_to_gesture: HashMap<usize, GestureKind>,
+    /// Run from the back over the model's ranking; the built-in ones unless set.
+    plugins: Vec<Box<dyn GesturePlugin>>,
+    /// How many plugins at the front of `plugins` came from
+    /// [`GestureClassifier::register_plugin`].
+    registered_plugins: usize,
 }
 
 impl GestureClassifier {
-    pub fn new() -> Self {
+    pub fn new(config: &RecognizerConfig) -> Self {
         let (model_session, class_to_gesture) = Self::load_model_and_classes();
 
         if model_session.is_none() {
             log::warn!(
-                "Failed to load gesture classification model, will use Unknown for all gestures"
+                "Failed to load gesture classification model, falling back to finger-state rules"
             );
         }
+        Self::with_model(config, model_session, class_to_gesture)
+    }
 
+    /// Classifies with the finger-state rules alone, without downloading or
+    /// loading the model, so replays give the same answer on every machine.
+    pub fn rules_only(config: &RecognizerConfig) -> Self {
+        Self::with_model(config, None, HashMap::new())
+    }
+
+    fn with_model(
+        config: &RecognizerConfig,
+        model_session: Option<Session>,
+        class_to_gesture: HashMap<usize, GestureKind>,
+    ) -> Self {
         Self {
-            motion_tracker: MotionTracker::new(),
+            min_confidence: config.classification_threshold,
+            mirror: config.mirror,
+            pinch_threshold: config.pinch_threshold,
+            motion_trackers: HashMap::new(),
+            pinch_trackers: HashMap::new(),
+            point_trackers: HashMap::new(),
+            rotation_trackers: HashMap::new(),
+            count_trackers: HashMap::new(),
+            wave_trackers: HashMap::new(),
+            handedness_trackers: HashMap::new(),
+            count_half_bent: config.count_half_bent,
+            normalization: config.normalization,
+            two_hand: TwoHandClassifier::default(),
+            enabled_gestures: GestureKind::ALL.into_iter().collect(),
             model_session,
             class_to_gesture,
+            plugins: builtin_plugins(),
+            registered_plugins: 0,
+        }
+    }
+
+    pub fn set_config(&mut self, config: &RecognizerConfig) {
+        self.min_confidence = config.classification_threshold;
+        self.mirror = config.mirror;
+        self.pinch_threshold = config.pinch_threshold;
+        self.count_half_bent = config.count_half_bent;
+        self.normalization = config.normalization;
+    }
+
+    /// [`GestureKind::Unknown`] is what is left when nothing enabled matches,
+    /// so it is never filtered. Custom gestures are never filtered either;
+    /// leaving out their plugin turns them off.
+    pub fn set_enabled_gestures(&mut self, gestures: HashSet<GestureKind>) {
+        self.enabled_gestures = gestures;
+    }
+
+    fn is_enabled(&self, kind: GestureKind) -> bool {
+        matches!(kind, GestureKind::Unknown | GestureKind::Custom(_))
+            || self.enabled_gestures.contains(&kind)
+    }
+
+    /// Adds `plugin` after the ones registered before it and ahead of the
+    /// built-in ones, and registers the gestures it introduces.
+    pub fn register_plugin(&mut self, plugin: Box<dyn GesturePlugin>) {
+        for gesture in plugin.custom_gestures() {
+            register_custom_gesture(*gesture);
+        }
+        log::info!("gesture plugin {} registered", plugin.name());
+        self.plugins.insert(self.registered_plugins, plugin);
+        self.registered_plugins += 1;
+    }
+
+    /// Replaces every plugin, built-in ones included, with `plugins` in the
+    /// order given. Registered later, a plugin goes ahead of all of them.
+    pub fn set_plugins(&mut self, plugins: Vec<Box<dyn GesturePlugin>>) {
+        for gesture in plugins.iter().flat_map(|plugin| plugin.custom_gestures()) {
+            register_custom_gesture(*gesture);
+        }
+        self.plugins = plugins;
+        self.registered_plugins = 0;
+    }
+
+    /// Names of the plugins in the order they are consulted.
+    pub fn plugin_names(&self) -> Vec<&str> {
+        self.plugins.iter().map(|plugin| plugin.name()).collect()
+    }
+
+    /// Forgets motion, pinch, pointing, rotation, count, wave and handedness
+    /// history for every hand.
+    pub fn reset_tracking(&mut self) {
+        self.motion_trackers.clear();
+        self.pinch_trackers.clear();
+        self.point_trackers.clear();
+        self.rotation_trackers.clear();
+        self.count_trackers.clear();
+        self.wave_trackers.clear();
+        self.handedness_trackers.clear();
+        self.two_hand.reset();
+        for plugin in &mut self.plugins {
+            plugin.reset();
         }
     }
 
@@ -43,7 +292,10 @@ impl GestureClassifier {
 
         // Ensure model is downloaded
         if let Err(e) = ensure_gesture_classifier_model_ready(&model_path, |_evt| {}) {
-            log::error!("Failed to prepare gesture classifier model: {}", e);
+            log::error!(
+                "Failed to prepare gesture classifier model: {}",
+                e.describe()
+            );
             return (None, HashMap::new());
         }
 
@@ -122,47 +374,187 @@ impl GestureClassifier {
 
     pub fn classify(
         &mut self,
+        track_id: u64,
         raw_landmarks: &[[f32; 3]],
         projected_landmarks: &[(f32, f32)],
         confidence: f32,
-        handedness_score: f32,
+        handedness_score: Option<f32>,
         timestamp: Instant,
     ) -> Option<GestureDetail> {
-        if confidence < MIN_CONFIDENCE {
+        if confidence < self.min_confidence {
             return None;
         }
         if raw_landmarks.len() < 21 || projected_landmarks.len() < 21 {
             return None;
         }
 
-        // Keep the existing normalization for finger state detection
-        let (normalized, _hand_span) = normalize_landmarks(raw_landmarks);
-        let wrist_px = projected_landmarks.get(0).copied().unwrap_or((0.0, 0.0));
+        // The score describes the hand as the camera saw it, before `mirror`,
+        // which is what the landmark geometry follows.
+        let seen_handedness = match handedness_score {
+            Some(score) => {
+                let previous = self.handedness_trackers.get(&track_id).copied();
+                let side = handedness_from_score(score, previous);
+                self.handedness_trackers.insert(track_id, side);
+                side
+            }
+            None => Handedness::Unknown,
+        };
+        let handedness_confidence = match seen_handedness {
+            Handedness::Unknown => 0.0,
+            _ => handedness_score.map_or(0.0, |score| ((score - 0.5).abs() * 2.0).min(1.0)),
+        };
+
+        // Keep the existing normalization for finger state detection. Left hands
+        // are reflected so every rule below only has to handle a right hand.
+        let mut normalized = normalize_landmarks(raw_landmarks, self.normalization);
+        if seen_handedness == Handedness::Left {
+            mirror_about_hand_axis(&mut normalized);
+        }
+        let wrist_px = projected_landmarks.first().copied().unwrap_or((0.0, 0.0));
         let span_px = projected_span(projected_landmarks);
+        let palm_px = projected_span(&PALM_LANDMARKS.map(|idx| projected_landmarks[idx]));
+        let finger_angles = [
+            measure_thumb(&normalized),
+            measure_finger(&normalized, [5, 6, 7]),
+            measure_finger(&normalized, [9, 10, 11]),
+            measure_finger(&normalized, [13, 14, 15]),
+            measure_finger(&normalized, [17, 18, 19]),
+        ];
         let finger_states = [
             classify_thumb(&normalized),
-            classify_finger(&normalized, [5, 6, 7, 8]),
-            classify_finger(&normalized, [9, 10, 11, 12]),
-            classify_finger(&normalized, [13, 14, 15, 16]),
-            classify_finger(&normalized, [17, 18, 19, 20]),
+            classify_finger(finger_angles[1]),
+            classify_finger(finger_angles[2]),
+            classify_finger(finger_angles[3]),
+            classify_finger(finger_angles[4]),
         ];
 
-        let handedness = handedness_from_score(handedness_score);
-
-        // Use ONNX model for primary gesture detection
-        let primary = self.detect_gesture_with_model(raw_landmarks);
+        // Motion detection is symmetric in x, so only handedness needs correcting
+        // for mirrored frames. It stays the true hand even though the geometry
+        // above was canonicalized.
+        let handedness = if self.mirror {
+            seen_handedness.mirrored()
+        } else {
+            seen_handedness
+        };
 
+        // The model ranks the hand when it can. The plugins then rework that
+        // ranking from the back of the list, so the rule templates stand in
+        // for a missing model before the thumb and the detectors at the front
+        // get the last word. Every plugin sees every hand, so ones that keep
+        // state stay in step.
+        let mut scores = self.score_gestures_with_model(raw_landmarks);
+        let wrist_history: Vec<(Instant, (f32, f32))> = self
+            .motion_trackers
+            .get(&track_id)
+            .map(MotionTracker::wrist_history)
+            .unwrap_or_default();
+        let context = GestureContext {
+            track_id,
+            timestamp,
+            landmarks: &normalized,
+            projected: projected_landmarks,
+            finger_states,
+            finger_angles,
+            handedness,
+            wrist_history: &wrist_history,
+            model_ranked: !scores.is_empty(),
+        };
+        for plugin in self.plugins.iter_mut().rev() {
+            plugin.rank(&context, &mut scores);
+        }
+        // A disabled gesture makes way for the next candidate.
+        scores.retain(|(kind, _)| self.is_enabled(*kind));
+        scores.truncate(MAX_SCORES);
+        let primary = scores
+            .first()
+            .map_or(GestureKind::Unknown, |(kind, _)| *kind);
+        let secondary = scores.get(1).map(|(kind, _)| *kind);
+
+        // Each tracked hand keeps its own history so two hands never blend into
+        // one trajectory; hands that left the frame are forgotten.
+        self.motion_trackers
+            .retain(|id, tracker| *id == track_id || tracker.is_active(timestamp));
         let motion = self
-            .motion_tracker
-            .update(wrist_px, span_px, timestamp, primary);
+            .motion_trackers
+            .entry(track_id)
+            .or_insert_with(MotionTracker::new)
+            .update(wrist_px, span_px, palm_px, timestamp, primary);
+
+        let motion_trackers = &self.motion_trackers;
+        // Promoted here so callers that only read `primary` see the wave; the
+        // palm underneath becomes the secondary guess.
+        self.wave_trackers
+            .retain(|id, _| motion_trackers.contains_key(id));
+        let waving = self
+            .wave_trackers
+            .entry(track_id)
+            .or_default()
+            .update(primary, motion, timestamp)
+            && self.is_enabled(GestureKind::Wave);
+        let (primary, secondary) = if waving {
+            (GestureKind::Wave, Some(primary))
+        } else {
+            (primary, secondary)
+        };
 
-        Some(GestureDetail {
+        self.pinch_trackers
+            .retain(|id, _| motion_trackers.contains_key(id));
+        self.handedness_trackers
+            .retain(|id, _| motion_trackers.contains_key(id));
+        let pinch_distance = distance3(normalized[4], normalized[8]);
+        let pinch = self
+            .pinch_trackers
+            .entry(track_id)
+            .or_default()
+            .update(pinch_distance, self.pinch_threshold);
+
+        // The rules call a lone index finger `One`; only the model says `Point`.
+        self.point_trackers
+            .retain(|id, _| motion_trackers.contains_key(id));
+        let pointing = matches!(primary, GestureKind::Point | GestureKind::One)
+            .then(|| pointing_direction(raw_landmarks, projected_landmarks))
+            .flatten();
+        let point_direction = self
+            .point_trackers
+            .entry(track_id)
+            .or_default()
+            .update(pointing);
+
+        // A dial is turned with a pinch or a pointed finger.
+        self.rotation_trackers
+            .retain(|id, _| motion_trackers.contains_key(id));
+        let turning = pinch.closed || matches!(primary, GestureKind::Point | GestureKind::One);
+        let rotation = self
+            .rotation_trackers
+            .entry(track_id)
+            .or_default()
+            .update(wrist_px, span_px, timestamp, turning);
+
+        let mut detail = GestureDetail {
             primary,
-            secondary: None, // No longer using secondary detection
+            secondary,
             handedness,
+            handedness_confidence,
             finger_states,
+            finger_angles,
             motion,
-        })
+            pinch: Some(pinch),
+            count: None,
+            scores,
+            point_direction,
+            rotation,
+            orientation: hand_orientation(raw_landmarks, seen_handedness),
+        };
+        self.count_trackers
+            .retain(|id, _| motion_trackers.contains_key(id));
+        let count = detail.finger_count(self.count_half_bent);
+        detail.count = Some(
+            self.count_trackers
+                .entry(track_id)
+                .or_default()
+                .update(count),
+        );
+        Some(detail)
     }
 
     /// Normalize landmarks for ONNX model input (matching training normalization)
@@ -217,29 +609,31 @@ impl GestureClassifier {
         Some(result)
     }
 
-    fn detect_gesture_with_model(&mut self, raw_landmarks: &[[f32; 3]]) -> GestureKind {
+    /// Softmax over the model's classes, highest first. Empty when the model is
+    /// missing or could not run.
+    fn score_gestures_with_model(&mut self, raw_landmarks: &[[f32; 3]]) -> Vec<(GestureKind, f32)> {
         let session = match &mut self.model_session {
             Some(s) => s,
-            None => return GestureKind::Unknown,
+            None => return Vec::new(),
         };
 
         // Normalize landmarks for model input
         let input_vec = match Self::normalize_for_model(raw_landmarks) {
             Some(v) => v,
-            None => return GestureKind::Unknown,
+            None => return Vec::new(),
         };
 
         // Create ndarray input (1, 42) shape
         let input_array = match Array2::from_shape_vec((1, 42), input_vec) {
             Ok(arr) => arr,
-            Err(_) => return GestureKind::Unknown,
+            Err(_) => return Vec::new(),
         };
 
         // Create tensor from array
         use ort::value::Tensor;
         let tensor = match Tensor::from_array(input_array) {
             Ok(t) => t,
-            Err(_) => return GestureKind::Unknown,
+            Err(_) => return Vec::new(),
         };
 
         // Run model inference
@@ -247,7 +641,7 @@ impl GestureClassifier {
             Ok(outputs) => outputs,
             Err(e) => {
                 log::warn!("Model inference failed: {}", e);
-                return GestureKind::Unknown;
+                return Vec::new();
             }
         };
 
@@ -256,272 +650,2881 @@ impl GestureClassifier {
             Ok(arr) => arr,
             Err(e) => {
                 log::warn!("Failed to extract logits: {}", e);
-                return GestureKind::Unknown;
+                return Vec::new();
             }
         };
 
-        // Find the class with highest logit value (argmax)
-        let predicted_class = logits_array
+        // Softmax, shifted by the largest logit to stay finite
+        let max_logit = logits_array
             .iter()
-            .enumerate()
-            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
-            .map(|(idx, _)| idx)
-            .unwrap_or(0);
-
-        // Map class index to GestureKind
-        self.class_to_gesture
-            .get(&predicted_class)
             .copied()
-            .unwrap_or(GestureKind::Unknown)
-    }
-}
+            .fold(f32::NEG_INFINITY, f32::max);
+        let exps: Vec<f32> = logits_array.iter().map(|l| (l - max_logit).exp()).collect();
+        let total: f32 = exps.iter().sum();
+        if !total.is_finite() || total <= 0.0 {
+            return Vec::new();
+        }
 
-fn handedness_from_score(score: f32) -> Handedness {
-    if score >= 0.5 {
-        Handedness::Right
-    } else if score > 0.0 {
-        Handedness::Left
-    } else {
-        Handedness::Unknown
+        // Map class indices to GestureKind
+        let mut scores: Vec<(GestureKind, f32)> = exps
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, exp)| {
+                let kind = self.class_to_gesture.get(&idx).copied()?;
+                Some((kind, exp / total))
+            })
+            .collect();
+        sort_scores(&mut scores);
+        scores
     }
 }
 
-fn normalize_landmarks(points: &[[f32; 3]]) -> (Vec<[f32; 3]>, f32) {
-    let mut min_x = f32::MAX;
-    let mut min_y = f32::MAX;
-    let mut max_x = f32::MIN;
-    let mut max_y = f32::MIN;
-
-    for [x, y, _z] in points {
-        min_x = min_x.min(*x);
-        min_y = min_y.min(*y);
-        max_x = max_x.max(*x);
-        max_y = max_y.max(*y);
+impl GestureClassifier {
+    /// Called once per frame with the classified hands, best first.
+    pub fn classify_composite(
+        &mut self,
+        hands: &[HandResult],
+        timestamp: Instant,
+    ) -> Option<CompositeGesture> {
+        self.two_hand.observe(hands, timestamp)
     }
-
-    let span = (max_x - min_x).max(max_y - min_y).max(1e-3);
-    let normalized = points
-        .iter()
-        .map(|[x, y, z]| [(*x - min_x) / span, (*y - min_y) / span, *z / span])
-        .collect();
-
-    (normalized, span)
 }
 
-fn projected_span(points: &[(f32, f32)]) -> f32 {
-    let mut min_x = f32::MAX;
-    let mut min_y = f32::MAX;
-    let mut max_x = f32::MIN;
-    let mut max_y = f32::MIN;
+/// Recognizes gestures made with both hands from the two best hands of each
+/// frame. Held poses need a few frames in a row; claps need the palms to
+/// approach quickly before they touch.
+#[derive(Default)]
+pub struct TwoHandClassifier {
+    /// Recent palm-to-palm distances in hand spans.
+    gaps: VecDeque<(Instant, f32)>,
+    contact_frames: u32,
+    candidate: Option<CompositeGesture>,
+    streak: u32,
+}
 
-    for &(x, y) in points {
-        min_x = min_x.min(x);
-        min_y = min_y.min(y);
-        max_x = max_x.max(x);
-        max_y = max_y.max(y);
+impl TwoHandClassifier {
+    pub fn reset(&mut self) {
+        self.gaps.clear();
+        self.contact_frames = 0;
+        self.candidate = None;
+        self.streak = 0;
     }
 
-    (max_x - min_x).max(max_y - min_y).max(1.0)
-}
+    pub fn observe(
+        &mut self,
+        hands: &[HandResult],
+        timestamp: Instant,
+    ) -> Option<CompositeGesture> {
+        let (a, b) = match hands {
+            [a, b, ..] if a.landmarks.len() >= 21 && b.landmarks.len() >= 21 => (a, b),
+            _ => {
+                self.reset();
+                return None;
+            }
+        };
+        let spans = (projected_span(&a.landmarks), projected_span(&b.landmarks));
+        // A hand collapsed onto one point is at the span's 1 px floor, and
+        // gaps measured against it are meaningless.
+        if spans.0.min(spans.1) <= 1.0 {
+            self.reset();
+            return None;
+        }
+        let norm = (spans.0 + spans.1) * 0.5;
 
-fn classify_finger(points: &[[f32; 3]], idx: [usize; 4]) -> FingerState {
-    let wrist = points[0];
-    let mcp = points[idx[0]];
-    let pip = points[idx[1]];
-    let dip = points[idx[2]];
-    let tip = points[idx[3]];
+        if self.update_clap(a, b, norm, timestamp) {
+            self.candidate = None;
+            self.streak = 0;
+            return Some(CompositeGesture::Clap);
+        }
 
-    let dist_tip = distance3(tip, wrist);
-    let dist_pip = distance3(pip, wrist);
-    let dist_mcp = distance3(mcp, wrist);
+        let held = if is_heart(a, b, norm) {
+            Some(CompositeGesture::Heart)
+        } else if is_frame(a, b, norm) {
+            Some(CompositeGesture::Frame)
+        } else {
+            None
+        };
+        if held.is_some() && held == self.candidate {
+            self.streak += 1;
+        } else {
+            self.candidate = held;
+            self.streak = 1;
+        }
+        self.candidate
+            .filter(|_| self.streak >= COMPOSITE_MIN_FRAMES)
+    }
 
-    let straightness = average_straightness(sub(pip, mcp), sub(dip, pip), sub(tip, dip));
+    fn update_clap(&mut self, a: &HandResult, b: &HandResult, norm: f32, now: Instant) -> bool {
+        // Middle finger knuckles stand in for the palm centers.
+        let gap = distance2(a.landmarks[9], b.landmarks[9]) / norm;
+        self.gaps.push_back((now, gap));
+        while let Some(&(time, _)) = self.gaps.front() {
+            if now.duration_since(time) > CLAP_WINDOW {
+                self.gaps.pop_front();
+            } else {
+                break;
+            }
+        }
 
-    let extension = dist_tip - dist_pip;
-    let reach = dist_tip - dist_mcp;
+        if gap > CLAP_CONTACT || !is_open_hand(a) || !is_open_hand(b) {
+            self.contact_frames = 0;
+            return false;
+        }
+        self.contact_frames += 1;
 
-    // Relaxed thresholds to reduce half-bent false positives (especially for pinky)
-    if extension > 0.15 && straightness > 0.40 && reach > 0.06 {
-        FingerState::Extended
-    } else if extension < 0.08 || straightness < 0.18 || reach < 0.05 {
-        FingerState::Folded
-    } else {
-        FingerState::HalfBent
+        let widest = self.gaps.iter().map(|&(_, g)| g).fold(0.0f32, f32::max);
+        if self.contact_frames >= CLAP_CONTACT_FRAMES && widest - gap >= CLAP_MIN_APPROACH {
+            self.gaps.clear();
+            self.contact_frames = 0;
+            return true;
+        }
+        false
     }
 }
 
-fn classify_thumb(points: &[[f32; 3]]) -> FingerState {
-    let wrist = points[0];
-    let cmc = points[1]; // Carpometacarpal joint
-    let mcp = points[2]; // Metacarpophalangeal joint (corrected from points[1])
-    let ip = points[3]; // Interphalangeal joint (corrected from points[2])
-    let tip = points[4]; // Thumb tip
-    let index_mcp = points[5];
-    let pinky_mcp = points[17];
-
-    // Calculate distances from wrist to various thumb joints
-    let dist_tip_wrist = distance3(tip, wrist);
-    let dist_ip_wrist = distance3(ip, wrist);
-    let dist_mcp_wrist = distance3(mcp, wrist);
-
-    // Calculate distances to other fingers to detect folding
-    let dist_tip_index = distance3(tip, index_mcp);
-    let dist_tip_pinky = distance3(tip, pinky_mcp);
-
-    // Calculate straightness of thumb segments
-    let straightness = average_straightness(sub(mcp, cmc), sub(ip, mcp), sub(tip, ip));
-
-    // Minimum distance to index or pinky (indicates how close thumb is to palm)
-    let spread = dist_tip_index.min(dist_tip_pinky);
-
-    // Extension metric: how far tip extends beyond IP joint
-    let extension = dist_tip_wrist - dist_ip_wrist;
-
-    // Reach metric: how far tip extends beyond MCP joint
-    let reach = dist_tip_wrist - dist_mcp_wrist;
+fn is_open_hand(hand: &HandResult) -> bool {
+    hand.detail.as_ref().is_some_and(|detail| {
+        matches!(
+            detail.primary,
+            GestureKind::Palm
+                | GestureKind::Four
+                | GestureKind::Stop
+                | GestureKind::StopInverted
+                | GestureKind::Wave
+        ) || detail.finger_states[1..]
+            .iter()
+            .all(|state| *state == FingerState::Extended)
+    })
+}
 
-    // Folded: thumb is close to palm and not straight (relaxed thresholds)
-    if spread < 0.25 && (straightness < 0.28 || reach < 0.15) {
-        FingerState::Folded
-    // Extended: thumb is far from wrist, straight, and extends well beyond joints
-    } else if dist_tip_wrist > 0.30 && straightness > 0.28 && extension > 0.08 {
-        FingerState::Extended
-    } else {
-        FingerState::HalfBent
+/// Thumb tips touching below touching index tips, the two hands curving
+/// into a heart between them.
+fn is_heart(a: &HandResult, b: &HandResult, norm: f32) -> bool {
+    if !is_half_heart(a) || !is_half_heart(b) {
+        return false;
+    }
+    let (pa, pb) = (&a.landmarks, &b.landmarks);
+    let max_gap = HEART_TIP_GAP * norm;
+    if distance2(pa[8], pb[8]) > max_gap || distance2(pa[4], pb[4]) > max_gap {
+        return false;
     }
+    // Image y grows downwards, so the index tips sit at the smaller y.
+    let index_y = (pa[8].1 + pb[8].1) * 0.5;
+    let thumb_y = (pa[4].1 + pb[4].1) * 0.5;
+    // The tips meet in the middle while the wrists stay apart.
+    thumb_y - index_y > 0.3 * norm && distance2(pa[0], pb[0]) > 0.5 * norm
 }
 
-fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
-    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+/// A finger heart, or an index finger curved with no finger straight out.
+fn is_half_heart(hand: &HandResult) -> bool {
+    use FingerState::{Extended, HalfBent};
+
+    hand.detail.as_ref().is_some_and(|detail| {
+        let [_, index, fingers @ ..] = detail.finger_states;
+        matches!(
+            detail.primary,
+            GestureKind::HandHeart | GestureKind::HandHeart2
+        ) || (index == HalfBent && fingers.iter().all(|state| *state != Extended))
+    })
 }
 
-fn distance3(a: [f32; 3], b: [f32; 3]) -> f32 {
-    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+/// Two L shapes, thumbs and index fingers pointing in opposite directions,
+/// spread out like the corners of a rectangle.
+fn is_frame(a: &HandResult, b: &HandResult, norm: f32) -> bool {
+    if !is_l_shape(a) || !is_l_shape(b) {
+        return false;
+    }
+    let (pa, pb) = (&a.landmarks, &b.landmarks);
+    let index_a = direction2(pa[5], pa[8]);
+    let index_b = direction2(pb[5], pb[8]);
+    let thumb_a = direction2(pa[2], pa[4]);
+    let thumb_b = direction2(pb[2], pb[4]);
+    dot2(index_a, index_b) < -0.5 && dot2(thumb_a, thumb_b) < -0.5 && distance2(pa[0], pb[0]) > norm
 }
 
-fn average_straightness(a: [f32; 3], b: [f32; 3], c: [f32; 3]) -> f32 {
-    let ab = dot(normalize(a), normalize(b));
-    let bc = dot(normalize(b), normalize(c));
-    ((ab + bc) / 2.0).clamp(-1.0, 1.0)
+fn is_l_shape(hand: &HandResult) -> bool {
+    use FingerState::Extended;
+
+    hand.detail.as_ref().is_some_and(|detail| {
+        let [thumb, index, middle, ring, pinky] = detail.finger_states;
+        matches!(
+            detail.primary,
+            GestureKind::ThumbIndex | GestureKind::ThumbIndex2 | GestureKind::TakePicture
+        ) || (thumb == Extended
+            && index == Extended
+            && middle != Extended
+            && ring != Extended
+            && pinky != Extended)
+    })
 }
 
-fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
-    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+fn distance2(a: (f32, f32), b: (f32, f32)) -> f32 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
 }
 
-fn normalize(v: [f32; 3]) -> [f32; 3] {
-    let len = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
-    if len < 1e-5 {
-        [0.0, 0.0, 0.0]
-    } else {
-        [v[0] / len, v[1] / len, v[2] / len]
-    }
+fn direction2(from: (f32, f32), to: (f32, f32)) -> (f32, f32) {
+    let len = distance2(from, to).max(f32::EPSILON);
+    ((to.0 - from.0) / len, (to.1 - from.1) / len)
 }
 
-#[derive(Clone)]
-struct MotionSample {
-    time: Instant,
-    x: f32,
-    y: f32,
-    span: f32,
+fn dot2(a: (f32, f32), b: (f32, f32)) -> f32 {
+    a.0 * b.0 + a.1 * b.1
 }
 
-struct MotionTracker {
-    history: VecDeque<MotionSample>,
+/// Wraps the classifier and turns its per-frame output into start/hold/end
+/// events, so a single misclassified frame does not flap downstream consumers.
+pub struct GestureEventTracker {
+    classifier: GestureClassifier,
+    start_frames: usize,
+    end_frames: usize,
+    candidate: Option<(GestureKind, usize)>,
+    active: Option<ActiveGesture>,
 }
 
-impl MotionTracker {
-    fn new() -> Self {
+struct ActiveGesture {
+    kind: GestureKind,
+    started_at: Instant,
+    missed_frames: usize,
+}
+
+impl GestureEventTracker {
+    pub fn new(classifier: GestureClassifier, config: &RecognizerConfig) -> Self {
         Self {
-            history: VecDeque::new(),
+            classifier,
+            start_frames: config.event_start_frames.max(1),
+            end_frames: config.event_end_frames.max(1),
+            candidate: None,
+            active: None,
         }
     }
 
-    fn update(
-        &mut self,
-        point: (f32, f32),
-        span: f32,
-        now: Instant,
-        primary: GestureKind,
-    ) -> GestureMotion {
-        self.history.push_back(MotionSample {
-            time: now,
-            x: point.0,
-            y: point.1,
-            span: span.max(1.0),
-        });
+    pub fn classifier_mut(&mut self) -> &mut GestureClassifier {
+        &mut self.classifier
+    }
 
-        while let Some(front) = self.history.front() {
-            if now.duration_since(front.time) > MOTION_WINDOW {
-                self.history.pop_front();
+    pub fn set_config(&mut self, config: &RecognizerConfig) {
+        self.classifier.set_config(config);
+        self.start_frames = config.event_start_frames.max(1);
+        self.end_frames = config.event_end_frames.max(1);
+    }
+
+    /// Starts over without reporting an end for the gesture in progress.
+    pub fn reset(&mut self) {
+        self.classifier.reset_tracking();
+        self.candidate = None;
+        self.active = None;
+    }
+
+    /// The [`GestureEvent::Motion`] for the gesture in progress, unless it is
+    /// steady or nothing has started. A frame that coasts over a dropout still
+    /// counts, so a blurred swipe is not lost.
+    pub fn motion_event(&self, motion: GestureMotion) -> Option<GestureEvent> {
+        let active = self.active.as_ref()?;
+        (motion != GestureMotion::Steady).then_some(GestureEvent::Motion {
+            kind: active.kind,
+            motion,
+        })
+    }
+
+    /// Feeds the gesture seen in one frame, `None` meaning no hand or no
+    /// classification, and returns the transitions it caused.
+    pub fn observe(&mut self, kind: Option<GestureKind>, timestamp: Instant) -> Vec<GestureEvent> {
+        let kind = kind.filter(|k| !matches!(k, GestureKind::Unknown | GestureKind::NoGesture));
+        let mut events = Vec::new();
+
+        if let Some(mut active) = self.active.take() {
+            if kind == Some(active.kind) {
+                active.missed_frames = 0;
+                self.candidate = None;
+                events.push(GestureEvent::Held {
+                    kind: active.kind,
+                    duration: timestamp.saturating_duration_since(active.started_at),
+                });
+                self.active = Some(active);
+                return events;
+            }
+
+            active.missed_frames += 1;
+            if active.missed_frames >= self.end_frames {
+                events.push(GestureEvent::Ended(active.kind));
             } else {
-                break;
+                self.active = Some(active);
             }
         }
 
-        if self.history.len() < 3 {
-            return GestureMotion::Steady;
+        // A replacement gesture keeps counting while the previous one is fading
+        // out, so switching gestures does not cost the full start delay twice.
+        let previous = self.candidate;
+        self.candidate = kind.map(|k| match previous {
+            Some((prev, count)) if prev == k => (k, count + 1),
+            _ => (k, 1),
+        });
+
+        if self.active.is_none()
+            && let Some((k, count)) = self.candidate
+            && count >= self.start_frames
+        {
+            events.push(GestureEvent::Started(k));
+            self.active = Some(ActiveGesture {
+                kind: k,
+                started_at: timestamp,
+                missed_frames: 0,
+            });
+            self.candidate = None;
         }
 
-        let avg_span =
-            self.history.iter().map(|s| s.span).sum::<f32>() / (self.history.len() as f32);
-        let norm = avg_span.max(1.0);
+        events
+    }
+}
 
-        let (min_x, max_x, min_y, max_y) =
-            self.history
-                .iter()
-                .fold((f32::MAX, f32::MIN, f32::MAX, f32::MIN), |acc, s| {
-                    (
-                        acc.0.min(s.x),
-                        acc.1.max(s.x),
-                        acc.2.min(s.y),
-                        acc.3.max(s.y),
-                    )
-                });
+/// How long something has held over a run of frames, forgiving lapses no
+/// longer than a grace period. Behind both dwell and hold to confirm.
+#[derive(Clone, Copy, Debug, Default)]
+struct GraceTimer {
+    started_at: Option<Instant>,
+    last_match: Option<Instant>,
+}
 
-        let span_x = (max_x - min_x) / norm;
-        let span_y = (max_y - min_y) / norm;
+impl GraceTimer {
+    /// Time held at `now`, or `None` once a lapse outlasted `grace`, which
+    /// restarts the timer.
+    fn observe(&mut self, matched: bool, now: Instant, grace: Duration) -> Option<Duration> {
+        if matched {
+            self.started_at.get_or_insert(now);
+            self.last_match = Some(now);
+        } else if now.saturating_duration_since(self.last_match?) > grace {
+            *self = Self::default();
+            return None;
+        }
+        Some(now.saturating_duration_since(self.started_at?))
+    }
+}
 
-        let samples: Vec<MotionSample> = self.history.iter().cloned().collect();
+/// Times how long the primary hand holds the [`DwellConfig`] gesture steady
+/// with its wrist inside the target. Lapses shorter than the grace period do
+/// not restart the timer, so tracking jitter is forgiven.
+pub struct DwellDetector {
+    config: DwellConfig,
+    timer: GraceTimer,
+    /// Where the wrist was when the hand last matched.
+    wrist: Option<(f32, f32)>,
+    completed: bool,
+}
 
-        let direction_changes_x = direction_changes(&samples, |s| s.x, norm * 0.08);
-        let direction_changes_y = direction_changes(&samples, |s| s.y, norm * 0.08);
+impl DwellDetector {
+    pub fn new(config: DwellConfig) -> Self {
+        Self {
+            config,
+            timer: GraceTimer::default(),
+            wrist: None,
+            completed: false,
+        }
+    }
 
-        let is_open_palm = matches!(
-            primary,
-            GestureKind::Palm | GestureKind::Four | GestureKind::Unknown
-        );
+    pub fn config(&self) -> DwellConfig {
+        self.config
+    }
 
-        if span_x > 0.55 && direction_changes_x >= 2 && is_open_palm {
-            GestureMotion::Fanning
-        } else if span_y > 0.55 && direction_changes_y >= 2 {
-            GestureMotion::VerticalWave
-        } else if span_x > 0.25 || span_y > 0.25 {
-            GestureMotion::Moving
-        } else {
-            GestureMotion::Steady
-        }
+    pub fn reset(&mut self) {
+        self.timer = GraceTimer::default();
+        self.wrist = None;
+        self.completed = false;
     }
-}
 
-fn direction_changes<F>(samples: &[MotionSample], select: F, min_step: f32) -> usize
-where
-    F: Fn(&MotionSample) -> f32,
-{
-    let mut changes = 0;
-    let mut last_sign = 0i8;
+    /// Progress for this frame, or `None` while no dwell is running. The hold
+    /// completes once, on a matching frame, and needs a reset to fire again.
+    pub fn observe(&mut self, result: &GestureResult) -> Option<DwellProgress> {
+        let matched = self.matching_wrist(result);
+        if matched.is_some() {
+            self.wrist = matched;
+        }
+        let Some(held) =
+            self.timer
+                .observe(matched.is_some(), result.timestamp, self.config.grace())
+        else {
+            self.reset();
+            return None;
+        };
 
-    for pair in samples.windows(2) {
-        let delta = select(&pair[1]) - select(&pair[0]);
-        if delta.abs() < min_step {
-            continue;
+        let progress = hold_progress(held, self.config.hold());
+        let completed = matched.is_some() && progress >= 1.0 && !self.completed;
+        self.completed |= completed;
+        Some(DwellProgress {
+            kind: self.config.gesture,
+            progress,
+            wrist: self.wrist?,
+            completed,
+        })
+    }
+
+    fn matching_wrist(&self, result: &GestureResult) -> Option<(f32, f32)> {
+        let detail = result.detail.as_ref()?;
+        if detail.primary != self.config.gesture || detail.motion != GestureMotion::Steady {
+            return None;
         }
-        let sign = if delta > 0.0 { 1 } else { -1 };
-        if last_sign != 0 && sign != last_sign {
-            changes += 1;
+        let wrist = *result.landmarks.as_ref()?.first()?;
+        let (width, height) = result.frame_size;
+        if width == 0 || height == 0 {
+            return None;
         }
-        last_sign = sign;
+        let (x, y) = (wrist.0 / width as f32, wrist.1 / height as f32);
+        let [x1, y1, x2, y2] = self.config.target;
+        ((x1..=x2).contains(&x) && (y1..=y2).contains(&y)).then_some(wrist)
+    }
+}
+
+/// Hold to confirm: the primary hand has to keep showing `gesture` for the
+/// hold time before it counts, so a binding with a drastic action does not
+/// fire on a passing fist. Unknown frames and missed hands shorter than
+/// [`HOLD_CONFIRM_GRACE`] are forgiven; any other gesture aborts the hold.
+pub struct HoldConfirm {
+    gesture: GestureKind,
+    hold: Duration,
+    timer: GraceTimer,
+    wrist: Option<(f32, f32)>,
+    completed: bool,
+}
+
+impl HoldConfirm {
+    pub fn new(gesture: GestureKind, hold: Duration) -> Self {
+        Self {
+            gesture,
+            hold,
+            timer: GraceTimer::default(),
+            wrist: None,
+            completed: false,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.timer = GraceTimer::default();
+        self.wrist = None;
+        self.completed = false;
+    }
+
+    /// Progress for this frame, or `None` while the gesture is not held. A
+    /// hold completes once and can only complete again after a release.
+    pub fn observe(&mut self, result: &GestureResult) -> Option<DwellProgress> {
+        let primary = result.detail.as_ref().map(|detail| detail.primary);
+        if primary.is_some_and(|kind| kind != self.gesture && kind != GestureKind::Unknown) {
+            self.reset();
+            return None;
+        }
+        let wrist = result
+            .landmarks
+            .as_ref()
+            .and_then(|points| points.first().copied());
+        let matched = primary == Some(self.gesture) && wrist.is_some();
+        if matched {
+            self.wrist = wrist;
+        }
+        let Some(held) = self
+            .timer
+            .observe(matched, result.timestamp, HOLD_CONFIRM_GRACE)
+        else {
+            self.reset();
+            return None;
+        };
+
+        let progress = hold_progress(held, self.hold);
+        let completed = matched && progress >= 1.0 && !self.completed;
+        self.completed |= completed;
+        Some(DwellProgress {
+            kind: self.gesture,
+            progress,
+            wrist: self.wrist?,
+            completed,
+        })
+    }
+}
+
+/// Fraction of `hold` that `held` covers, capped at 1.
+fn hold_progress(held: Duration, hold: Duration) -> f32 {
+    let hold = hold.as_secs_f32();
+    if hold > 0.0 {
+        (held.as_secs_f32() / hold).min(1.0)
+    } else {
+        1.0
+    }
+}
+
+/// Grab and drag: an open palm closing into a fist starts a drag of that
+/// hand, which follows its smoothed wrist until the palm opens again. Frames
+/// that show anything else, or miss the hand, are sat out for
+/// [`DRAG_GRACE`] before the drag is cancelled.
+#[derive(Default)]
+pub struct DragTracker {
+    /// When an open palm was last seen, arming a grab.
+    palm_at: Option<Instant>,
+    drag: Option<Drag>,
+}
+
+struct Drag {
+    track_id: u64,
+    pos: (f32, f32),
+    last_fist: Instant,
+}
+
+impl DragTracker {
+    pub fn reset(&mut self) {
+        self.palm_at = None;
+        self.drag = None;
+    }
+
+    /// The drag event for this frame, if any: a start, a move on every fist
+    /// frame of a drag, or its end.
+    pub fn observe(&mut self, result: &GestureResult) -> Option<GestureEvent> {
+        let now = result.timestamp;
+        let Some(drag) = &mut self.drag else {
+            let hand = result.hands.first()?;
+            let kind = hand.detail.as_ref()?.primary;
+            if is_open_palm(kind) {
+                self.palm_at = Some(now);
+                return None;
+            }
+            let armed = self
+                .palm_at
+                .is_some_and(|at| now.saturating_duration_since(at) <= DRAG_ARM_WINDOW);
+            if kind != GestureKind::Fist || !armed {
+                return None;
+            }
+            let pos = wrist_fraction(hand, result.frame_size)?;
+            self.palm_at = None;
+            self.drag = Some(Drag {
+                track_id: hand.track_id,
+                pos,
+                last_fist: now,
+            });
+            return Some(GestureEvent::DragStarted { pos });
+        };
+
+        let hand = result
+            .hands
+            .iter()
+            .find(|hand| hand.track_id == drag.track_id);
+        let kind = hand
+            .and_then(|hand| hand.detail.as_ref())
+            .map(|detail| detail.primary);
+        let wrist = hand.and_then(|hand| wrist_fraction(hand, result.frame_size));
+        match (kind, wrist) {
+            (Some(GestureKind::Fist), Some(wrist)) => {
+                let pos = (
+                    drag.pos.0 + (wrist.0 - drag.pos.0) * DRAG_SMOOTHING,
+                    drag.pos.1 + (wrist.1 - drag.pos.1) * DRAG_SMOOTHING,
+                );
+                let delta = (pos.0 - drag.pos.0, pos.1 - drag.pos.1);
+                drag.pos = pos;
+                drag.last_fist = now;
+                Some(GestureEvent::DragMoved { pos, delta })
+            }
+            (Some(kind), _) if is_open_palm(kind) => {
+                let pos = drag.pos;
+                self.drag = None;
+                self.palm_at = Some(now);
+                Some(GestureEvent::DragEnded {
+                    pos,
+                    cancelled: false,
+                })
+            }
+            _ if now.saturating_duration_since(drag.last_fist) > DRAG_GRACE => {
+                let pos = drag.pos;
+                self.drag = None;
+                Some(GestureEvent::DragEnded {
+                    pos,
+                    cancelled: true,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+fn is_open_palm(kind: GestureKind) -> bool {
+    matches!(kind, GestureKind::Palm | GestureKind::Stop)
+}
+
+fn wrist_fraction(hand: &HandResult, (width, height): (u32, u32)) -> Option<(f32, f32)> {
+    let wrist = hand.landmarks.first()?;
+    if width == 0 || height == 0 {
+        return None;
+    }
+    Some((wrist.0 / width as f32, wrist.1 / height as f32))
+}
+
+/// What the gesture panel shows as the big label.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StableLabel {
+    pub kind: GestureKind,
+    /// The latest frames lean elsewhere, but not by enough to replace `kind`.
+    pub uncertain: bool,
+}
+
+/// Keeps the displayed gesture from flickering on borderline poses, where the
+/// per-frame primary alternates between two close candidates; see
+/// [`LabelStabilizerConfig`].
+pub struct LabelStabilizer {
+    config: LabelStabilizerConfig,
+    votes: HashMap<GestureKind, f32>,
+    last_at: Option<Instant>,
+    stable: Option<StableLabel>,
+}
+
+impl LabelStabilizer {
+    pub fn new(config: LabelStabilizerConfig) -> Self {
+        Self {
+            config,
+            votes: HashMap::new(),
+            last_at: None,
+            stable: None,
+        }
+    }
+
+    pub fn config(&self) -> LabelStabilizerConfig {
+        self.config
+    }
+
+    pub fn set_config(&mut self, config: LabelStabilizerConfig) {
+        self.config = config;
+        self.reset();
+    }
+
+    pub fn reset(&mut self) {
+        self.votes.clear();
+        self.last_at = None;
+        self.stable = None;
+    }
+
+    /// Feeds the primary gesture of one frame, `None` meaning no hand, and
+    /// returns the label to show; `None` until some gesture has won a vote
+    /// and again once every vote has decayed away.
+    pub fn observe(
+        &mut self,
+        kind: Option<GestureKind>,
+        confidence: f32,
+        at: Instant,
+    ) -> Option<StableLabel> {
+        let time_constant = self.config.time_constant().as_secs_f32();
+        if time_constant <= 0.0 {
+            self.stable = kind.map(|kind| StableLabel {
+                kind,
+                uncertain: false,
+            });
+            return self.stable;
+        }
+
+        let elapsed = self
+            .last_at
+            .map_or(0.0, |last| at.saturating_duration_since(last).as_secs_f32());
+        self.last_at = Some(at);
+        let decay = (-elapsed / time_constant).exp();
+        self.votes.retain(|_, vote| {
+            *vote *= decay;
+            *vote >= LABEL_VOTE_FLOOR
+        });
+        if let Some(kind) = kind {
+            *self.votes.entry(kind).or_default() += confidence.clamp(0.0, 1.0);
+        }
+
+        let mut ranked: Vec<(GestureKind, f32)> = self
+            .votes
+            .iter()
+            .map(|(&kind, &vote)| (kind, vote))
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        let Some(&(leader, lead)) = ranked.first() else {
+            self.stable = None;
+            return None;
+        };
+        let runner_up = ranked.get(1).map_or(0.0, |&(_, vote)| vote);
+        if lead - runner_up >= self.config.margin {
+            self.stable = Some(StableLabel {
+                kind: leader,
+                uncertain: false,
+            });
+        } else if let Some(stable) = &mut self.stable {
+            stable.uncertain = true;
+        }
+        self.stable
+    }
+}
+
+/// Watches the debounced events for [`GestureSequence`]s. Each sequence keeps
+/// its own partial matches, so sequences sharing a prefix advance together and
+/// a repeated first step can begin a new attempt while an older one runs.
+pub struct SequenceMatcher {
+    sequences: Vec<GestureSequence>,
+    progress: Vec<Vec<SequenceProgress>>,
+}
+
+#[derive(Clone, Copy)]
+struct SequenceProgress {
+    /// Steps matched so far.
+    matched: usize,
+    started_at: Instant,
+    last_step_at: Instant,
+}
+
+impl SequenceMatcher {
+    pub fn new(sequences: Vec<GestureSequence>) -> Self {
+        let progress = vec![Vec::new(); sequences.len()];
+        Self {
+            sequences,
+            progress,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.progress.iter_mut().for_each(Vec::clear);
+    }
+
+    /// Feeds one frame: its primary gesture and the events the tracker produced
+    /// for it. Only `Started` events count as steps; any other gesture starting
+    /// drops the partial matches that did not expect it.
+    pub fn observe(
+        &mut self,
+        primary: Option<GestureKind>,
+        events: &[GestureEvent],
+        now: Instant,
+    ) -> Vec<GestureEvent> {
+        let recognized = primary
+            .is_some_and(|kind| !matches!(kind, GestureKind::Unknown | GestureKind::NoGesture));
+        let mut matched = Vec::new();
+        for (sequence, progress) in self.sequences.iter().zip(&mut self.progress) {
+            let Some(&first) = sequence.steps.first() else {
+                continue;
+            };
+            progress.retain(|p| {
+                now.saturating_duration_since(p.last_step_at) <= sequence.max_gap()
+                    && now.saturating_duration_since(p.started_at) <= sequence.timeout()
+            });
+            if !recognized && !sequence.ignore_unknown {
+                progress.clear();
+            }
+
+            for event in events {
+                let &GestureEvent::Started(kind) = event else {
+                    continue;
+                };
+                progress.retain_mut(|p| {
+                    let advanced = sequence.steps[p.matched] == kind;
+                    if advanced {
+                        p.matched += 1;
+                        p.last_step_at = now;
+                    }
+                    advanced
+                });
+                if kind == first {
+                    progress.push(SequenceProgress {
+                        matched: 1,
+                        started_at: now,
+                        last_step_at: now,
+                    });
+                }
+                if progress.iter().any(|p| p.matched == sequence.steps.len()) {
+                    progress.clear();
+                    matched.push(GestureEvent::SequenceMatched(sequence.name.clone()));
+                }
+            }
+        }
+        matched
+    }
+}
+
+/// What a rule template expects from one finger.
+#[derive(Clone, Copy)]
+enum Want {
+    Extended,
+    Folded,
+    /// Folded or half bent.
+    NotExtended,
+    Any,
+}
+
+impl Want {
+    /// 1.0 for a clear match, 0.5 for a half-bent finger where a straight or
+    /// folded one was wanted, 0.0 otherwise.
+    fn score(self, state: FingerState) -> f32 {
+        use FingerState::{Extended, Folded, HalfBent};
+
+        match (self, state) {
+            (Want::Any, _) => 1.0,
+            (Want::Extended, Extended) | (Want::Folded, Folded) => 1.0,
+            (Want::Extended | Want::Folded, HalfBent) => 0.5,
+            (Want::NotExtended, Extended) => 0.0,
+            (Want::NotExtended, _) => 1.0,
+            (Want::Extended, Folded) | (Want::Folded, Extended) => 0.0,
+        }
+    }
+}
+
+/// Finger templates for the rule fallback, thumb first. Earlier entries win
+/// ties: Call and Rock share fingers with Like and One.
+const RULE_TEMPLATES: [(GestureKind, [Want; 5]); 10] = {
+    use Want::{Any, Extended as E, Folded as F, NotExtended as N};
+    [
+        (GestureKind::Call, [E, F, F, F, E]),
+        // Rock or ILoveYou, decided by the `RockOrILoveYou` plugin.
+        (GestureKind::Rock, [Any, E, F, F, E]),
+        // Like or Dislike, decided by the `ThumbDirection` plugin.
+        (GestureKind::Like, [E, F, F, F, F]),
+        (GestureKind::Fist, [N, F, F, F, F]),
+        (GestureKind::One, [N, E, F, F, F]),
+        (GestureKind::MiddleFinger, [N, F, E, F, F]),
+        (GestureKind::Peace, [Any, E, E, F, F]),
+        (GestureKind::Three, [Any, E, E, E, F]),
+        (GestureKind::Palm, [E, E, E, E, E]),
+        (GestureKind::Four, [N, E, E, E, E]),
+    ]
+};
+
+/// Product of the per-finger scores of each template, highest first. Only
+/// templates with at most one half-bent finger stay in the list.
+fn template_scores(states: &[FingerState; 5]) -> Vec<(GestureKind, f32)> {
+    let mut scores: Vec<(GestureKind, f32)> = RULE_TEMPLATES
+        .iter()
+        .filter_map(|(kind, wants)| {
+            let score: f32 = wants
+                .iter()
+                .zip(states)
+                .map(|(want, state)| want.score(*state))
+                .product();
+            (score >= RULE_MIN_SCORE).then_some((*kind, score))
+        })
+        .collect();
+    sort_scores(&mut scores);
+    scores
+}
+
+/// Scores OK only for a closed ring: thumb and index tips touching, the index
+/// curled at its PIP joint, the tips clear of the index knuckle so a pinch does
+/// not count, the thumb opposed in front of the palm, and the other three
+/// fingers extended.
+fn ok_score(points: &[[f32; 3]], states: &[FingerState; 5]) -> Option<f32> {
+    if distance3(points[4], points[8]) > OK_TIP_GAP {
+        return None;
+    }
+    if distance3(points[4], points[5]) < OK_MIN_KNUCKLE_GAP {
+        return None;
+    }
+    if thumb_opposition(points) < OK_MIN_OPPOSITION {
+        return None;
+    }
+    let pip = dot(
+        normalize(sub(points[6], points[5])),
+        normalize(sub(points[7], points[6])),
+    );
+    if pip > OK_MAX_PIP_STRAIGHTNESS {
+        return None;
+    }
+    let score: f32 = states[2..]
+        .iter()
+        .map(|state| Want::Extended.score(*state))
+        .product();
+    (score >= RULE_MIN_SCORE).then_some(score)
+}
+
+/// Scores crossed fingers when the index and middle are up with their tips
+/// together and in the opposite order across the hand from their knuckles. The
+/// order is taken across the wrist to middle knuckle axis, so it holds for a
+/// tilted hand; tips that touch without crossing stay a V.
+fn crossed_fingers_score(points: &[[f32; 3]], states: &[FingerState; 5]) -> Option<f32> {
+    if distance3(points[8], points[12]) > CROSSED_TIP_GAP {
+        return None;
+    }
+    let origin = points[0];
+    let (ax, ay) = (points[9][0] - origin[0], points[9][1] - origin[1]);
+    let across = |point: [f32; 3]| (point[0] - origin[0]) * ay - (point[1] - origin[1]) * ax;
+    let knuckles = across(points[5]) - across(points[9]);
+    let tips = across(points[8]) - across(points[12]);
+    if knuckles * tips >= 0.0 {
+        return None;
+    }
+    let score: f32 = [Want::Extended, Want::Extended, Want::Folded, Want::Folded]
+        .iter()
+        .zip(&states[1..])
+        .map(|(want, state)| want.score(*state))
+        .product();
+    (score >= RULE_MIN_SCORE).then_some(score)
+}
+
+/// Scores the finger gun: thumb and index extended, the rest folded, the thumb
+/// pointing up in the image and the index more sideways than up. An L shape
+/// held up with the thumb out to the side does not count.
+fn finger_gun_score(states: &[FingerState; 5], projected: &[(f32, f32)]) -> Option<f32> {
+    if thumb_direction(projected) != Some(GestureKind::Like) {
+        return None;
+    }
+    let (knuckle, tip) = (projected.get(5)?, projected.get(8)?);
+    if (tip.0 - knuckle.0).abs() <= (tip.1 - knuckle.1).abs() {
+        return None;
+    }
+    let wants = [
+        Want::Extended,
+        Want::Extended,
+        Want::Folded,
+        Want::Folded,
+        Want::Folded,
+    ];
+    let score: f32 = wants
+        .iter()
+        .zip(states)
+        .map(|(want, state)| want.score(*state))
+        .product();
+    (score >= RULE_MIN_SCORE).then_some(score)
+}
+
+/// Stable, so equal scores keep their original order.
+fn sort_scores(scores: &mut [(GestureKind, f32)]) {
+    scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+}
+
+/// Angle between the thumb, MCP to tip, and the palm line from the index to
+/// the pinky knuckle, in degrees. A thumb held out away from the palm is near
+/// 180°, one lying across or alongside the folded fingers 90° or less. A
+/// reflection does not change it, so left hands need no mirroring.
+fn thumb_abduction(points: &[[f32; 3]]) -> f32 {
+    let thumb = normalize(sub(points[4], points[2]));
+    let palm_line = normalize(sub(points[17], points[5]));
+    dot(thumb, palm_line).clamp(-1.0, 1.0).acos().to_degrees()
+}
+
+/// Rock and ILoveYou share every finger but the thumb.
+fn rock_or_i_love_you(points: &[[f32; 3]], thumb: FingerState) -> GestureKind {
+    let abduction = thumb_abduction(points);
+    let held_out = if abduction >= I_LOVE_YOU_MIN_ABDUCTION_DEG {
+        true
+    } else if abduction <= ROCK_MAX_ABDUCTION_DEG {
+        false
+    } else {
+        thumb == FingerState::Extended
+    };
+    if held_out {
+        GestureKind::ILoveYou
+    } else {
+        GestureKind::Rock
+    }
+}
+
+/// The index finger's direction from its knuckle to its tip: the angle from
+/// the frame-pixel landmarks, and the depth share from the crop landmarks,
+/// whose z is in the same pixels as their x and y. `None` for a finger too
+/// short on screen to give a direction.
+pub fn pointing_direction(raw: &[[f32; 3]], projected: &[(f32, f32)]) -> Option<PointingInfo> {
+    let (knuckle, tip) = (projected.get(5)?, projected.get(8)?);
+    // Image y grows downwards.
+    let (dx, dy) = (tip.0 - knuckle.0, knuckle.1 - tip.1);
+    if dx.hypot(dy) < 1.0 {
+        return None;
+    }
+    let finger = sub(*raw.get(8)?, *raw.get(5)?);
+    let length = dot(finger, finger).sqrt();
+    // Smaller z is closer to the camera.
+    let toward = if length > 1e-3 {
+        (-finger[2] / length).clamp(-1.0, 1.0)
+    } else {
+        0.0
+    };
+    Some(PointingInfo {
+        angle_deg: dy.atan2(dx).to_degrees(),
+        toward,
+    })
+}
+
+/// Which way the palm faces, from its normal: the cross product of the
+/// wrist→index-knuckle and wrist→little-knuckle vectors on the crop landmarks,
+/// and how much of it lies along the camera axis, which is also how much of
+/// the palm's area shows. `seen` is the handedness as the camera saw it,
+/// which flips the normal: a right hand's points out of the palm, a left
+/// hand's out of the back. `None` for an unknown side or a degenerate palm.
+pub fn hand_orientation(raw: &[[f32; 3]], seen: Handedness) -> Option<HandOrientation> {
+    let sign = match seen {
+        Handedness::Right => 1.0,
+        Handedness::Left => -1.0,
+        Handedness::Unknown => return None,
+    };
+    let wrist = *raw.first()?;
+    let normal = cross(sub(*raw.get(5)?, wrist), sub(*raw.get(17)?, wrist));
+    let length = dot(normal, normal).sqrt();
+    if length < 1e-3 {
+        return None;
+    }
+    // Smaller z is closer to the camera, so 1 is a palm square on to it.
+    let facing = -sign * normal[2] / length;
+    Some(if facing.abs() < ORIENTATION_SIDE_BELOW {
+        HandOrientation::Side
+    } else if facing > 0.0 {
+        HandOrientation::PalmToward
+    } else {
+        HandOrientation::BackToward
+    })
+}
+
+/// Like or Dislike from the thumb MCP→tip vector in image coordinates, or
+/// `None` when the thumb points more sideways than up or down.
+fn thumb_direction(projected: &[(f32, f32)]) -> Option<GestureKind> {
+    let (mcp, tip) = (projected.get(2)?, projected.get(4)?);
+    let dx = tip.0 - mcp.0;
+    let dy = tip.1 - mcp.1;
+    if dy.abs() < dx.abs() * 0.5 {
+        return None;
+    }
+    // Image y grows downwards.
+    Some(if dy < 0.0 {
+        GestureKind::Like
+    } else {
+        GestureKind::Dislike
+    })
+}
+
+/// A new hand is split at 0.5; a tracked one only changes sides once the
+/// score clears the hysteresis band.
+fn handedness_from_score(score: f32, previous: Option<Handedness>) -> Handedness {
+    if score > HANDEDNESS_RIGHT_ABOVE {
+        Handedness::Right
+    } else if score < HANDEDNESS_LEFT_BELOW {
+        Handedness::Left
+    } else {
+        match previous {
+            Some(side @ (Handedness::Left | Handedness::Right)) => side,
+            _ if score >= 0.5 => Handedness::Right,
+            _ => Handedness::Left,
+        }
+    }
+}
+
+/// Landmarks relative to the bounding box corner, in palm lengths. See
+/// [`LandmarkNormalization`].
+fn normalize_landmarks(points: &[[f32; 3]], scheme: LandmarkNormalization) -> Vec<[f32; 3]> {
+    let mut min_x = f32::MAX;
+    let mut min_y = f32::MAX;
+    let mut max_x = f32::MIN;
+    let mut max_y = f32::MIN;
+
+    for [x, y, _z] in points {
+        min_x = min_x.min(*x);
+        min_y = min_y.min(*y);
+        max_x = max_x.max(*x);
+        max_y = max_y.max(*y);
+    }
+
+    let box_scale = (max_x - min_x).max(max_y - min_y).max(1e-3) / BOX_SPAN_IN_PALM_LENGTHS;
+    let scale = match scheme {
+        LandmarkNormalization::PalmLength => {
+            let palm_length = distance3(points[0], points[9]);
+            // A collapsed palm would blow distances up; the box still works there.
+            if palm_length > 1e-3 {
+                palm_length
+            } else {
+                box_scale
+            }
+        }
+        LandmarkNormalization::BoundingBox => box_scale,
+    };
+    points
+        .iter()
+        .map(|[x, y, z]| [(*x - min_x) / scale, (*y - min_y) / scale, *z / scale])
+        .collect()
+}
+
+/// Reflects the landmarks across the line from the wrist through the middle
+/// knuckle in the image plane, turning a left hand into a right one. Lengths and
+/// joint angles are unchanged; only which side of the palm a point lies on flips.
+fn mirror_about_hand_axis(points: &mut [[f32; 3]]) {
+    let origin = points[0];
+    let (ax, ay) = (points[9][0] - origin[0], points[9][1] - origin[1]);
+    let len = (ax * ax + ay * ay).sqrt();
+    if len < 1e-6 {
+        return;
+    }
+    let (ux, uy) = (ax / len, ay / len);
+    for point in points {
+        let (dx, dy) = (point[0] - origin[0], point[1] - origin[1]);
+        let along = dx * ux + dy * uy;
+        point[0] = origin[0] + 2.0 * along * ux - dx;
+        point[1] = origin[1] + 2.0 * along * uy - dy;
+    }
+}
+
+fn projected_span(points: &[(f32, f32)]) -> f32 {
+    let mut min_x = f32::MAX;
+    let mut min_y = f32::MAX;
+    let mut max_x = f32::MIN;
+    let mut max_y = f32::MIN;
+
+    for &(x, y) in points {
+        min_x = min_x.min(x);
+        min_y = min_y.min(y);
+        max_x = max_x.max(x);
+        max_y = max_y.max(y);
+    }
+
+    (max_x - min_x).max(max_y - min_y).max(1.0)
+}
+
+/// `idx` is the finger's MCP, PIP and DIP landmarks.
+fn measure_finger(points: &[[f32; 3]], idx: [usize; 3]) -> FingerAngles {
+    let [mcp, pip, dip] = idx.map(|i| points[i]);
+    FingerAngles {
+        mcp: joint_angle(points[0], mcp, pip),
+        pip: joint_angle(mcp, pip, dip),
+        abduction: None,
+        opposition: None,
+    }
+}
+
+fn measure_thumb(points: &[[f32; 3]]) -> FingerAngles {
+    let [cmc, mcp, ip, tip] = [points[1], points[2], points[3], points[4]];
+    let spread = dot(
+        normalize(sub(mcp, cmc)),
+        normalize(sub(points[5], points[0])),
+    );
+    FingerAngles {
+        mcp: joint_angle(cmc, mcp, ip),
+        pip: joint_angle(mcp, ip, tip),
+        abduction: Some(spread.clamp(-1.0, 1.0).acos().to_degrees()),
+        opposition: Some(thumb_opposition(points)),
+    }
+}
+
+/// Angle at `joint` between the bones towards `prev` and `next`, in degrees.
+fn joint_angle(prev: [f32; 3], joint: [f32; 3], next: [f32; 3]) -> f32 {
+    let cos = dot(normalize(sub(prev, joint)), normalize(sub(next, joint)));
+    cos.clamp(-1.0, 1.0).acos().to_degrees()
+}
+
+fn classify_finger(angles: FingerAngles) -> FingerState {
+    if angles.mcp >= EXTENDED_MIN_MCP_DEG && angles.pip >= EXTENDED_MIN_PIP_DEG {
+        FingerState::Extended
+    } else if angles.mcp < FOLDED_MAX_MCP_DEG || angles.pip < FOLDED_MAX_PIP_DEG {
+        FingerState::Folded
+    } else {
+        FingerState::HalfBent
+    }
+}
+
+/// Judged on the thumb flattened onto the palm plane, so depth the landmark
+/// model misreads, which is worst with the hand side on, neither bends the
+/// thumb nor moves it towards the knuckles. The depth taken out is the
+/// opposition: a tip well in front of the palm and inside its width is tucked
+/// across the fingers.
+fn classify_thumb(points: &[[f32; 3]]) -> FingerState {
+    let wrist = points[0];
+    let index_mcp = points[5];
+    let pinky_mcp = points[17];
+    let normal = palm_normal(points);
+    let [cmc, mcp, ip, tip] = [1, 2, 3, 4].map(|i| onto_palm(points[i], wrist, normal));
+
+    let dist_tip_wrist = distance3(tip, wrist);
+    // How close the tip comes to the palm's index or little finger edge.
+    let spread = distance3(tip, index_mcp).min(distance3(tip, pinky_mcp));
+    let straightness = average_straightness(sub(mcp, cmc), sub(ip, mcp), sub(tip, ip));
+    // How far the tip reaches past the IP and MCP joints.
+    let extension = dist_tip_wrist - distance3(ip, wrist);
+    let reach = dist_tip_wrist - distance3(mcp, wrist);
+    // How far the tip sits in from the index knuckle towards the little one.
+    let inset = dot(sub(tip, index_mcp), normalize(sub(pinky_mcp, index_mcp)));
+
+    let tucked =
+        thumb_opposition(points) > THUMB_TUCKED_MIN_OPPOSITION && inset > THUMB_TUCKED_MIN_INSET;
+    let against_palm = spread < THUMB_FOLDED_MAX_SPREAD
+        && (straightness < THUMB_MIN_STRAIGHTNESS || reach < THUMB_FOLDED_MAX_REACH);
+    if tucked || against_palm {
+        FingerState::Folded
+    } else if dist_tip_wrist > THUMB_EXTENDED_MIN_LENGTH
+        && straightness > THUMB_MIN_STRAIGHTNESS
+        && extension > THUMB_EXTENDED_MIN_EXTENSION
+    {
+        FingerState::Extended
+    } else {
+        FingerState::HalfBent
+    }
+}
+
+/// Unit normal of the palm plane through the wrist and the index and little
+/// knuckles. On the normalized landmarks, where left hands are mirrored, it
+/// points out of the palm side; zero for a degenerate hand.
+fn palm_normal(points: &[[f32; 3]]) -> [f32; 3] {
+    let wrist = points[0];
+    normalize(cross(sub(points[5], wrist), sub(points[17], wrist)))
+}
+
+/// `point` moved along `normal` onto the palm plane through `wrist`.
+fn onto_palm(point: [f32; 3], wrist: [f32; 3], normal: [f32; 3]) -> [f32; 3] {
+    let height = dot(sub(point, wrist), normal);
+    sub(point, normal.map(|axis| axis * height))
+}
+
+/// Height of the thumb tip above the palm plane, in palm lengths; positive in
+/// front of the palm.
+fn thumb_opposition(points: &[[f32; 3]]) -> f32 {
+    dot(sub(points[4], points[0]), palm_normal(points))
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn distance3(a: [f32; 3], b: [f32; 3]) -> f32 {
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+}
+
+fn average_straightness(a: [f32; 3], b: [f32; 3], c: [f32; 3]) -> f32 {
+    let ab = dot(normalize(a), normalize(b));
+    let bc = dot(normalize(b), normalize(c));
+    ((ab + bc) / 2.0).clamp(-1.0, 1.0)
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn normalize(v: [f32; 3]) -> [f32; 3] {
+    let len = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    if len < 1e-5 {
+        [0.0, 0.0, 0.0]
+    } else {
+        [v[0] / len, v[1] / len, v[2] / len]
+    }
+}
+
+#[derive(Clone)]
+struct MotionSample {
+    time: Instant,
+    x: f32,
+    y: f32,
+    span: f32,
+    /// Span of [`PALM_LANDMARKS`] alone, for push/pull.
+    palm: f32,
+}
+
+struct MotionTracker {
+    history: VecDeque<MotionSample>,
+}
+
+impl MotionTracker {
+    fn new() -> Self {
+        Self {
+            history: VecDeque::new(),
+        }
+    }
+
+    fn is_active(&self, now: Instant) -> bool {
+        self.history
+            .back()
+            .is_some_and(|last| now.duration_since(last.time) <= MOTION_WINDOW)
+    }
+
+    fn wrist_history(&self) -> Vec<(Instant, (f32, f32))> {
+        self.history
+            .iter()
+            .map(|sample| (sample.time, (sample.x, sample.y)))
+            .collect()
+    }
+
+    fn update(
+        &mut self,
+        point: (f32, f32),
+        span: f32,
+        palm: f32,
+        now: Instant,
+        primary: GestureKind,
+    ) -> GestureMotion {
+        self.history.push_back(MotionSample {
+            time: now,
+            x: point.0,
+            y: point.1,
+            span: span.max(1.0),
+            palm: palm.max(1.0),
+        });
+
+        while let Some(front) = self.history.front() {
+            if now.duration_since(front.time) > MOTION_WINDOW {
+                self.history.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if self.history.len() < 3 {
+            return GestureMotion::Steady;
+        }
+
+        let avg_span =
+            self.history.iter().map(|s| s.span).sum::<f32>() / (self.history.len() as f32);
+        let norm = avg_span.max(1.0);
+
+        let (min_x, max_x, min_y, max_y) =
+            self.history
+                .iter()
+                .fold((f32::MAX, f32::MIN, f32::MAX, f32::MIN), |acc, s| {
+                    (
+                        acc.0.min(s.x),
+                        acc.1.max(s.x),
+                        acc.2.min(s.y),
+                        acc.3.max(s.y),
+                    )
+                });
+
+        let span_x = (max_x - min_x) / norm;
+        let span_y = (max_y - min_y) / norm;
+
+        let samples: Vec<MotionSample> = self.history.iter().cloned().collect();
+
+        let direction_changes_x = direction_changes(&samples, |s| s.x, norm * 0.08);
+        let direction_changes_y = direction_changes(&samples, |s| s.y, norm * 0.08);
+
+        if let Some(swipe) = detect_swipe(&samples, norm, direction_changes_x, direction_changes_y)
+        {
+            // Start over so the hand drifting back to center does not read as
+            // a swipe the other way.
+            self.history.clear();
+            return swipe;
+        }
+
+        if let Some(depth) = detect_depth_motion(&samples) {
+            self.history.clear();
+            return depth;
+        }
+
+        let is_open_palm = matches!(
+            primary,
+            GestureKind::Palm | GestureKind::Four | GestureKind::Unknown
+        );
+
+        if span_x > 0.55 && direction_changes_x >= 2 && is_open_palm {
+            GestureMotion::Fanning
+        } else if span_y > 0.55 && direction_changes_y >= 2 {
+            GestureMotion::VerticalWave
+        } else if span_x > 0.25 || span_y > 0.25 {
+            GestureMotion::Moving
+        } else {
+            GestureMotion::Steady
+        }
+    }
+}
+
+#[derive(Default)]
+struct PinchTracker {
+    smoothed: Option<f32>,
+    closed: bool,
+    /// Smoothed distance when the pinch last closed.
+    anchor: Option<f32>,
+}
+
+impl PinchTracker {
+    fn update(&mut self, distance: f32, threshold: f32) -> PinchInfo {
+        let smoothed = match self.smoothed {
+            Some(prev) => prev + (distance - prev) * PINCH_SMOOTHING,
+            None => distance,
+        };
+        self.smoothed = Some(smoothed);
+
+        if self.closed {
+            if smoothed > threshold * PINCH_RELEASE_RATIO {
+                self.closed = false;
+                self.anchor = None;
+            }
+        } else if smoothed < threshold {
+            self.closed = true;
+            self.anchor = Some(smoothed);
+        }
+
+        PinchInfo {
+            distance: smoothed,
+            delta: self.anchor.map_or(0.0, |anchor| smoothed - anchor),
+            closed: self.closed,
+        }
+    }
+}
+
+/// Smooths the pointing direction as a unit vector, so the average does not
+/// jump where the angle wraps from 180° to -180°. Starts over whenever the
+/// hand stops pointing.
+#[derive(Default)]
+struct PointTracker {
+    smoothed: Option<((f32, f32), f32)>,
+}
+
+impl PointTracker {
+    fn update(&mut self, pointing: Option<PointingInfo>) -> Option<PointingInfo> {
+        let Some(pointing) = pointing else {
+            self.smoothed = None;
+            return None;
+        };
+        let angle = pointing.angle_deg.to_radians();
+        let ((x, y), toward) = match self.smoothed {
+            Some(((x, y), toward)) => (
+                (
+                    x + (angle.cos() - x) * POINT_SMOOTHING,
+                    y + (angle.sin() - y) * POINT_SMOOTHING,
+                ),
+                toward + (pointing.toward - toward) * POINT_SMOOTHING,
+            ),
+            None => ((angle.cos(), angle.sin()), pointing.toward),
+        };
+        self.smoothed = Some(((x, y), toward));
+        Some(PointingInfo {
+            angle_deg: y.atan2(x).to_degrees(),
+            toward,
+        })
+    }
+}
+
+/// A wrist going round in a circle, like turning a dial. The recent path is
+/// fitted to a circle; once it has gone [`ROTATION_ENGAGE_DEG`] round a centre
+/// that holds still, every frame reports the angle turned since the last one,
+/// clockwise on screen positive. A path that stops being round, or the hand
+/// leaving the pose, ends the turn.
+#[derive(Default)]
+pub struct RotationTracker {
+    samples: VecDeque<MotionSample>,
+    /// Degrees turned so far while engaged.
+    total: Option<f32>,
+}
+
+impl RotationTracker {
+    /// `wrist` and `span` are in pixels; `active` says whether the hand is in
+    /// a pose that turns a dial.
+    pub fn update(
+        &mut self,
+        wrist: (f32, f32),
+        span: f32,
+        now: Instant,
+        active: bool,
+    ) -> Option<RotationInfo> {
+        if !active {
+            self.samples.clear();
+            self.total = None;
+            return None;
+        }
+        self.samples.push_back(MotionSample {
+            time: now,
+            x: wrist.0,
+            y: wrist.1,
+            span: span.max(1.0),
+            palm: span.max(1.0),
+        });
+        while self
+            .samples
+            .front()
+            .is_some_and(|front| now.saturating_duration_since(front.time) > ROTATION_WINDOW)
+        {
+            self.samples.pop_front();
+        }
+        if self.samples.len() < ROTATION_MIN_SAMPLES {
+            self.total = None;
+            return None;
+        }
+
+        let points: Vec<(f32, f32)> = self.samples.iter().map(|s| (s.x, s.y)).collect();
+        let span = self.samples.iter().map(|s| s.span).sum::<f32>() / points.len() as f32;
+        let Some(circle) = fit_circle(&points).filter(|circle| circle.is_dial(span)) else {
+            self.total = None;
+            return None;
+        };
+        let steps: Vec<f32> = points
+            .windows(2)
+            .map(|pair| circle.angle_between(pair[0], pair[1]))
+            .collect();
+
+        let (total, delta) = match self.total {
+            Some(total) => {
+                let delta = steps.last().copied().unwrap_or_default();
+                (total + delta, delta)
+            }
+            None => {
+                let travel: f32 = steps.iter().sum();
+                let steady = fit_circle(&points[..points.len() / 2])
+                    .is_some_and(|older| older.drift_from(&circle) <= ROTATION_MAX_DRIFT);
+                if travel.abs() < ROTATION_ENGAGE_DEG || !steady {
+                    return None;
+                }
+                (travel, 0.0)
+            }
+        };
+        self.total = Some(total);
+        Some(RotationInfo {
+            total_degrees: total,
+            delta_degrees: delta,
+        })
+    }
+}
+
+struct Circle {
+    center: (f32, f32),
+    radius: f32,
+    /// RMS distance of the points from the circle.
+    residual: f32,
+}
+
+impl Circle {
+    fn is_dial(&self, span: f32) -> bool {
+        (ROTATION_MIN_RADIUS..=ROTATION_MAX_RADIUS).contains(&(self.radius / span))
+            && self.residual <= self.radius * ROTATION_MAX_RESIDUAL
+    }
+
+    /// Signed degrees from `a` to `b` around the centre, clockwise on screen
+    /// positive since image y points down.
+    fn angle_between(&self, a: (f32, f32), b: (f32, f32)) -> f32 {
+        let angle = |(x, y): (f32, f32)| (y - self.center.1).atan2(x - self.center.0);
+        let turn = (angle(b) - angle(a) + PI).rem_euclid(TAU) - PI;
+        turn.to_degrees()
+    }
+
+    /// How far apart the two circles are, centre and radius, as a fraction of
+    /// `other`'s radius.
+    fn drift_from(&self, other: &Circle) -> f32 {
+        let center = distance2(self.center, other.center);
+        let radius = (self.radius - other.radius).abs();
+        center.max(radius) / other.radius
+    }
+}
+
+/// Least-squares circle through `points` (the Kåsa fit), `None` when they are
+/// too few or lie on a line.
+fn fit_circle(points: &[(f32, f32)]) -> Option<Circle> {
+    if points.len() < 3 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| f64::from(p.0)).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| f64::from(p.1)).sum::<f64>() / n;
+    let (mut suu, mut svv, mut suv, mut suuu, mut svvv, mut suvv, mut svuu) =
+        (0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    for &(x, y) in points {
+        let (u, v) = (f64::from(x) - mean_x, f64::from(y) - mean_y);
+        suu += u * u;
+        svv += v * v;
+        suv += u * v;
+        suuu += u * u * u;
+        svvv += v * v * v;
+        suvv += u * v * v;
+        svuu += v * u * u;
+    }
+    let det = suu * svv - suv * suv;
+    if det.abs() <= suu * svv * 1e-9 {
+        return None;
+    }
+    let (b1, b2) = ((suuu + suvv) / 2.0, (svvv + svuu) / 2.0);
+    let uc = (b1 * svv - b2 * suv) / det;
+    let vc = (b2 * suu - b1 * suv) / det;
+    let radius = (uc * uc + vc * vc + (suu + svv) / n).sqrt() as f32;
+    let center = ((uc + mean_x) as f32, (vc + mean_y) as f32);
+    let squares: f32 = points
+        .iter()
+        .map(|&point| (distance2(point, center) - radius).powi(2))
+        .sum();
+    Some(Circle {
+        center,
+        radius,
+        residual: (squares / points.len() as f32).sqrt(),
+    })
+}
+
+/// Fanning from an open palm sustained for [`WAVE_MIN_DURATION`]. An open palm
+/// held still never fans, so a stop pose stays a palm.
+#[derive(Default)]
+struct WaveTracker {
+    fanning_since: Option<Instant>,
+    last_fanning: Option<Instant>,
+}
+
+impl WaveTracker {
+    fn update(&mut self, primary: GestureKind, motion: GestureMotion, now: Instant) -> bool {
+        let open_palm = matches!(
+            primary,
+            GestureKind::Palm | GestureKind::Four | GestureKind::Stop
+        );
+        if open_palm && motion == GestureMotion::Fanning {
+            self.fanning_since.get_or_insert(now);
+            self.last_fanning = Some(now);
+        } else if self
+            .last_fanning
+            .is_none_or(|last| now.saturating_duration_since(last) > WAVE_GRACE)
+        {
+            self.fanning_since = None;
+            self.last_fanning = None;
+        }
+        self.fanning_since
+            .is_some_and(|since| now.saturating_duration_since(since) >= WAVE_MIN_DURATION)
+    }
+}
+
+/// Majority vote over the last [`COUNT_WINDOW`] counts; ties go to the most
+/// recent count.
+#[derive(Default)]
+struct CountTracker {
+    recent: VecDeque<u8>,
+}
+
+impl CountTracker {
+    fn update(&mut self, count: u8) -> u8 {
+        if self.recent.len() == COUNT_WINDOW {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(count);
+
+        let mut votes = [0usize; 6];
+        for &recent in &self.recent {
+            votes[usize::from(recent.min(5))] += 1;
+        }
+        // `max_by_key` keeps the last maximum, i.e. the newest.
+        self.recent
+            .iter()
+            .copied()
+            .max_by_key(|&recent| votes[usize::from(recent.min(5))])
+            .unwrap_or(count)
+    }
+}
+
+fn detect_swipe(
+    samples: &[MotionSample],
+    norm: f32,
+    direction_changes_x: usize,
+    direction_changes_y: usize,
+) -> Option<GestureMotion> {
+    let (first, last) = (samples.first()?, samples.last()?);
+    let elapsed = last.time.duration_since(first.time).as_secs_f32();
+    if elapsed <= 0.0 {
+        return None;
+    }
+
+    let dx = (last.x - first.x) / norm;
+    let dy = (last.y - first.y) / norm;
+    let (distance, other, changes) = if dx.abs() >= dy.abs() {
+        (dx.abs(), dy.abs(), direction_changes_x)
+    } else {
+        (dy.abs(), dx.abs(), direction_changes_y)
+    };
+
+    if distance < SWIPE_MIN_DISTANCE
+        || distance < other * SWIPE_AXIS_RATIO
+        || distance / elapsed < SWIPE_MIN_SPEED
+        || changes > 0
+    {
+        return None;
+    }
+
+    // Image coordinates, so y grows downwards.
+    let swipe = if dx.abs() >= dy.abs() {
+        if dx > 0.0 {
+            GestureMotion::SwipeRight
+        } else {
+            GestureMotion::SwipeLeft
+        }
+    } else if dy > 0.0 {
+        GestureMotion::SwipeDown
+    } else {
+        GestureMotion::SwipeUp
+    };
+    Some(swipe)
+}
+
+/// Push/pull: the palm span grows or shrinks steadily over the last
+/// [`DEPTH_WINDOW`] while the wrist stays roughly in place. The palm rather
+/// than the whole hand, so a fist opening in place is not a push.
+fn detect_depth_motion(samples: &[MotionSample]) -> Option<GestureMotion> {
+    let last = samples.last()?;
+    let start = samples
+        .iter()
+        .position(|s| last.time.duration_since(s.time) <= DEPTH_WINDOW)?;
+    let recent = &samples[start..];
+    if recent.len() < DEPTH_SMOOTHING + 2 {
+        return None;
+    }
+
+    // A single re-cropped palm box shifts one span; averaging keeps it from
+    // passing as a push.
+    let smoothed: Vec<f32> = recent
+        .windows(DEPTH_SMOOTHING)
+        .map(|w| w.iter().map(|s| s.palm).sum::<f32>() / DEPTH_SMOOTHING as f32)
+        .collect();
+    let (first_span, last_span) = (*smoothed.first()?, *smoothed.last()?);
+    let scale = last_span / first_span.max(1.0);
+    let growing = if scale >= DEPTH_MIN_SCALE {
+        true
+    } else if scale <= 1.0 / DEPTH_MIN_SCALE {
+        false
+    } else {
+        return None;
+    };
+
+    let monotonic = smoothed.windows(2).all(|pair| {
+        let step = (pair[1] - pair[0]) / pair[0].max(1.0);
+        if growing {
+            step >= -DEPTH_JITTER
+        } else {
+            step <= DEPTH_JITTER
+        }
+    });
+    if !monotonic {
+        return None;
+    }
+
+    let first = recent.first()?;
+    let norm = first.span.min(last.span).max(1.0);
+    let lateral = ((last.x - first.x).powi(2) + (last.y - first.y).powi(2)).sqrt() / norm;
+    if lateral > DEPTH_MAX_LATERAL {
+        return None;
+    }
+
+    Some(if growing {
+        GestureMotion::Approaching
+    } else {
+        GestureMotion::Receding
+    })
+}
+
+fn direction_changes<F>(samples: &[MotionSample], select: F, min_step: f32) -> usize
+where
+    F: Fn(&MotionSample) -> f32,
+{
+    let mut changes = 0;
+    let mut last_sign = 0i8;
+
+    for pair in samples.windows(2) {
+        let delta = select(&pair[1]) - select(&pair[0]);
+        if delta.abs() < min_step {
+            continue;
+        }
+        let sign = if delta > 0.0 { 1 } else { -1 };
+        if last_sign != 0 && sign != last_sign {
+            changes += 1;
+        }
+        last_sign = sign;
     }
 
     changes
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use FingerState::{Extended as E, Folded as F, HalfBent as H};
+
+    /// Knuckles of the index, middle, ring and little fingers of an upright
+    /// right hand, in palm lengths from the wrist. Image y grows downwards and
+    /// smaller z is closer to the camera, so the palm faces it.
+    const KNUCKLES: [[f32; 3]; 4] = [
+        [0.3, -0.95, 0.0],
+        [0.0, -1.0, 0.0],
+        [-0.25, -0.95, 0.0],
+        [-0.45, -0.85, 0.0],
+    ];
+
+    #[derive(Clone, Copy, Debug)]
+    enum Thumb {
+        /// Held out sideways, away from the palm.
+        Out,
+        /// Extended and pointing up the image.
+        Up,
+        /// Lying across the palm in front of the folded fingers.
+        Tucked,
+    }
+
+    fn along(from: [f32; 3], direction: [f32; 3], length: f32) -> [f32; 3] {
+        [0, 1, 2].map(|axis| from[axis] + direction[axis] * length)
+    }
+
+    /// MCP, PIP, DIP and tip of one finger, pointing away from the wrist.
+    fn finger(knuckle: [f32; 3], state: FingerState) -> [[f32; 3]; 4] {
+        let out = normalize(knuckle);
+        let back = out.map(|axis| -axis);
+        let forward = [0.0, 0.0, -1.0];
+        match state {
+            FingerState::Extended => {
+                let pip = along(knuckle, out, 0.4);
+                let dip = along(pip, out, 0.25);
+                [knuckle, pip, dip, along(dip, out, 0.2)]
+            }
+            // Bent 55° at the PIP joint.
+            FingerState::HalfBent => {
+                let bent = normalize([0, 1, 2].map(|axis| out[axis] * 0.57 + forward[axis] * 0.82));
+                let pip = along(knuckle, out, 0.4);
+                let dip = along(pip, bent, 0.25);
+                [knuckle, pip, dip, along(dip, bent, 0.2)]
+            }
+            // Square at the knuckle and the PIP joint, curled back towards the
+            // wrist in front of the palm.
+            FingerState::Folded => {
+                let pip = along(knuckle, forward, 0.35);
+                let dip = along(pip, back, 0.25);
+                [knuckle, pip, dip, along(dip, back, 0.15)]
+            }
+        }
+    }
+
+    /// All 21 landmarks of a right hand with the thumb and fingers as given,
+    /// index first, in palm lengths from the wrist.
+    fn hand(thumb: Thumb, fingers: [FingerState; 4]) -> Vec<[f32; 3]> {
+        let thumb = match thumb {
+            Thumb::Out => [
+                [0.2, -0.12, 0.0],
+                [0.42, -0.22, 0.0],
+                [0.62, -0.3, 0.0],
+                [0.82, -0.37, 0.0],
+            ],
+            Thumb::Up => [
+                [0.2, -0.15, 0.0],
+                [0.4, -0.35, 0.0],
+                [0.5, -0.6, 0.0],
+                [0.58, -0.85, 0.0],
+            ],
+            Thumb::Tucked => [
+                [0.2, -0.15, 0.0],
+                [0.35, -0.35, -0.1],
+                [0.2, -0.55, -0.3],
+                [0.0, -0.6, -0.3],
+            ],
+        };
+        let mut points = vec![[0.0; 3]];
+        points.extend(thumb);
+        for (knuckle, state) in KNUCKLES.into_iter().zip(fingers) {
+            points.extend(finger(knuckle, state));
+        }
+        points
+    }
+
+    /// `points` in pixels, as both the crop and the frame landmarks.
+    fn in_pixels(points: &[[f32; 3]]) -> (Vec<[f32; 3]>, Vec<(f32, f32)>) {
+        let raw: Vec<[f32; 3]> = points
+            .iter()
+            .map(|[x, y, z]| [320.0 + x * 80.0, 300.0 + y * 80.0, z * 80.0])
+            .collect();
+        let projected = raw.iter().map(|[x, y, _]| (*x, *y)).collect();
+        (raw, projected)
+    }
+
+    fn classify(classifier: &mut GestureClassifier, points: &[[f32; 3]]) -> GestureDetail {
+        let (raw, projected) = in_pixels(points);
+        classifier
+            .classify(1, &raw, &projected, 0.9, Some(0.9), Instant::now())
+            .expect("hand is confident enough to classify")
+    }
+
+    fn primary(points: &[[f32; 3]]) -> GestureKind {
+        let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+        classify(&mut classifier, points).primary
+    }
+
+    #[test]
+    fn the_synthetic_hand_has_the_finger_states_it_was_built_with() {
+        let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+        let detail = classify(&mut classifier, &hand(Thumb::Out, [E, H, F, E]));
+        let states = [E, E, FingerState::HalfBent, F, E];
+        assert_eq!(detail.finger_states, states);
+        let tucked = classify(&mut classifier, &hand(Thumb::Tucked, [F; 4]));
+        assert_eq!(tucked.finger_states[0], F);
+        let up = classify(&mut classifier, &hand(Thumb::Up, [F; 4]));
+        assert_eq!(up.finger_states[0], E);
+    }
+
+    #[test]
+    fn thumb_and_little_finger_out_is_call() {
+        assert_eq!(primary(&hand(Thumb::Out, [F, F, F, E])), GestureKind::Call);
+    }
+
+    #[test]
+    fn near_misses_of_call_are_not_call() {
+        // The little finger folded leaves a thumb up.
+        assert_eq!(primary(&hand(Thumb::Up, [F, F, F, F])), GestureKind::Like);
+        // The index up with the little finger and the thumb tucked is the horns.
+        assert_eq!(
+            primary(&hand(Thumb::Tucked, [E, F, F, E])),
+            GestureKind::Rock
+        );
+        // With the thumb tucked instead, only the little finger is up.
+        assert_ne!(
+            primary(&hand(Thumb::Tucked, [F, F, F, E])),
+            GestureKind::Call
+        );
+    }
+
+    #[test]
+    fn a_fast_stroke_is_a_swipe_for_one_frame() {
+        let mut tracker = MotionTracker::new();
+        let start = Instant::now();
+        let frame = Duration::from_millis(33);
+        let mut reported = Vec::new();
+        // One hand span every two frames to the left, then holding still.
+        for i in 0..10u32 {
+            let x = 600.0 - 50.0 * i.min(4) as f32;
+            reported.push(tracker.update(
+                (x, 300.0),
+                100.0,
+                50.0,
+                start + frame * i,
+                GestureKind::Palm,
+            ));
+        }
+        let swipes = reported
+            .iter()
+            .filter(|motion| !matches!(motion, GestureMotion::Steady | GestureMotion::Moving))
+            .collect::<Vec<_>>();
+        assert_eq!(swipes, [&GestureMotion::SwipeLeft], "{reported:?}");
+    }
+
+    /// One sample every 33 ms of a wrist at `(x, y)` with the hand and its
+    /// palm `span` wide.
+    fn samples(points: impl IntoIterator<Item = (f32, f32, f32)>) -> Vec<MotionSample> {
+        let start = Instant::now();
+        points
+            .into_iter()
+            .enumerate()
+            .map(|(i, (x, y, span))| MotionSample {
+                time: start + Duration::from_millis(33 * i as u64),
+                x,
+                y,
+                span,
+                palm: span,
+            })
+            .collect()
+    }
+
+    /// Every motion `tracker` reports for a wrist at `xs`, one every 33 ms,
+    /// on a hand 100 px wide.
+    fn track(tracker: &mut MotionTracker, xs: impl IntoIterator<Item = f32>) -> Vec<GestureMotion> {
+        let start = Instant::now();
+        xs.into_iter()
+            .zip(0u32..)
+            .map(|(x, i)| {
+                let now = start + Duration::from_millis(33) * i;
+                tracker.update((x, 300.0), 100.0, 50.0, now, GestureKind::Palm)
+            })
+            .collect()
+    }
+
+    fn is_swipe(motion: &GestureMotion) -> bool {
+        matches!(
+            motion,
+            GestureMotion::SwipeLeft
+                | GestureMotion::SwipeRight
+                | GestureMotion::SwipeUp
+                | GestureMotion::SwipeDown
+        )
+    }
+
+    #[test]
+    fn a_slow_drift_is_moving_rather_than_a_swipe() {
+        // Three pixels a frame: well over a span within the window, but at
+        // under one span a second.
+        let reported = track(
+            &mut MotionTracker::new(),
+            (0..60).map(|i| 200.0 + 3.0 * i as f32),
+        );
+        assert!(
+            reported
+                .iter()
+                .all(|motion| matches!(motion, GestureMotion::Steady | GestureMotion::Moving)),
+            "{reported:?}"
+        );
+        assert_eq!(reported.last(), Some(&GestureMotion::Moving));
+
+        let drift = samples((0..40).map(|i| (200.0 + 3.0 * i as f32, 300.0, 100.0)));
+        assert_eq!(detect_swipe(&drift, 100.0, 0, 0), None);
+    }
+
+    #[test]
+    fn a_stroke_that_turns_back_is_not_a_swipe() {
+        let out = [0.0, 60.0, 120.0, 180.0, 240.0].map(|x| (200.0 + x, 300.0, 100.0));
+        let changes = |points: &[MotionSample]| direction_changes(points, |s| s.x, 8.0);
+        let stroke = samples(out);
+        assert_eq!(
+            detect_swipe(&stroke, 100.0, changes(&stroke), 0),
+            Some(GestureMotion::SwipeRight)
+        );
+
+        // Still two spans to the right overall, but it waved back at the end.
+        let waved_back = samples(out.into_iter().chain([(400.0, 300.0, 100.0)]));
+        assert_eq!(changes(&waved_back), 1);
+        assert_eq!(
+            detect_swipe(&waved_back, 100.0, changes(&waved_back), 0),
+            None
+        );
+    }
+
+    #[test]
+    fn a_slow_return_after_a_swipe_is_not_a_swipe_back() {
+        // Half a span a frame to the left, a short hold, then three pixels a
+        // frame back to where the stroke started.
+        let stroke = (0..5).map(|i| 600.0 - 50.0 * i as f32);
+        let hold = std::iter::repeat_n(400.0, 5);
+        let back = (1..=67).map(|i| 400.0 + 3.0 * i as f32);
+        let reported = track(&mut MotionTracker::new(), stroke.chain(hold).chain(back));
+        let swipes: Vec<_> = reported.iter().filter(|motion| is_swipe(motion)).collect();
+        assert_eq!(swipes, [&GestureMotion::SwipeLeft], "{reported:?}");
+    }
+
+    #[test]
+    fn a_growing_span_in_place_is_a_push_and_a_shrinking_one_a_pull() {
+        let push = samples((0..10).map(|i| (320.0 + i as f32, 300.0, 100.0 + 8.0 * i as f32)));
+        assert_eq!(detect_depth_motion(&push), Some(GestureMotion::Approaching));
+
+        let pull = samples((0..10).map(|i| (320.0, 300.0 - i as f32, 172.0 - 8.0 * i as f32)));
+        assert_eq!(detect_depth_motion(&pull), Some(GestureMotion::Receding));
+
+        // The same growth while the hand crosses the frame is not a push.
+        let drifting =
+            samples((0..10).map(|i| (320.0 + 10.0 * i as f32, 300.0, 100.0 + 8.0 * i as f32)));
+        assert_eq!(detect_depth_motion(&drifting), None);
+    }
+
+    #[test]
+    fn moving_sideways_at_a_constant_span_is_not_a_push() {
+        let lateral = samples((0..10).map(|i| (200.0 + 30.0 * i as f32, 300.0, 100.0)));
+        assert_eq!(detect_depth_motion(&lateral), None);
+    }
+
+    #[test]
+    fn fingers_opening_in_place_are_not_a_push() {
+        // A fist opening into a palm grows the hand, but not the palm.
+        let opening: Vec<MotionSample> =
+            samples((0..10).map(|i| (320.0, 300.0, 60.0 + 12.0 * i as f32)))
+                .into_iter()
+                .map(|sample| MotionSample {
+                    palm: 60.0,
+                    ..sample
+                })
+                .collect();
+        assert_eq!(detect_depth_motion(&opening), None);
+    }
+
+    #[test]
+    fn a_jumpy_span_is_not_a_push() {
+        // The palm box re-cropped once, for a single frame.
+        let recropped = samples(
+            [100.0, 100.0, 100.0, 100.0, 180.0, 100.0, 100.0, 100.0]
+                .map(|span| (320.0, 300.0, span)),
+        );
+        assert_eq!(detect_depth_motion(&recropped), None);
+
+        // Ends up bigger, but back and forth on the way there.
+        let noisy = samples(
+            [
+                100.0, 150.0, 95.0, 160.0, 105.0, 170.0, 120.0, 175.0, 140.0, 180.0,
+            ]
+            .map(|span| (320.0, 300.0, span)),
+        );
+        assert_eq!(detect_depth_motion(&noisy), None);
+    }
+
+    /// A hand with every landmark on its wrist but the `points` given, in
+    /// image pixels, showing `kind` with `fingers` after the thumb.
+    fn hand_2d(
+        wrist: (f32, f32),
+        points: &[(usize, (f32, f32))],
+        kind: GestureKind,
+        fingers: [FingerState; 4],
+    ) -> HandResult {
+        let mut landmarks = vec![wrist; 21];
+        for &(idx, point) in points {
+            landmarks[idx] = point;
+        }
+        let mut detail = GestureDetail::new(kind);
+        detail.finger_states = [E, fingers[0], fingers[1], fingers[2], fingers[3]];
+        HandResult {
+            track_id: 1,
+            confidence: 0.9,
+            landmarks,
+            raw_landmarks: Vec::new(),
+            handedness_score: None,
+            detail: Some(detail),
+        }
+    }
+
+    /// An open palm 100 px across around its middle knuckle at `(x, y)`.
+    fn palm_at(x: f32, y: f32) -> HandResult {
+        hand_2d(
+            (x, y + 50.0),
+            &[
+                (9, (x, y)),
+                (12, (x, y - 50.0)),
+                (4, (x - 50.0, y)),
+                (20, (x + 50.0, y)),
+            ],
+            GestureKind::Palm,
+            [E; 4],
+        )
+    }
+
+    /// Both hands each frame, 33 ms apart, and what the classifier made of them.
+    fn composites(
+        frames: impl IntoIterator<Item = [HandResult; 2]>,
+    ) -> Vec<Option<CompositeGesture>> {
+        let mut classifier = TwoHandClassifier::default();
+        let start = Instant::now();
+        frames
+            .into_iter()
+            .enumerate()
+            .map(|(i, hands)| {
+                classifier.observe(&hands, start + Duration::from_millis(33 * i as u64))
+            })
+            .collect()
+    }
+
+    fn count(seen: &[Option<CompositeGesture>], gesture: CompositeGesture) -> usize {
+        seen.iter().filter(|seen| **seen == Some(gesture)).count()
+    }
+
+    #[test]
+    fn palms_meeting_quickly_clap_once() {
+        let gaps = [240.0, 160.0, 80.0, 40.0, 40.0, 40.0, 40.0];
+        let seen = composites(gaps.map(|gap| {
+            [
+                palm_at(320.0 - gap / 2.0, 240.0),
+                palm_at(320.0 + gap / 2.0, 240.0),
+            ]
+        }));
+        assert_eq!(seen[3], Some(CompositeGesture::Clap), "{seen:?}");
+        assert_eq!(count(&seen, CompositeGesture::Clap), 1, "{seen:?}");
+    }
+
+    #[test]
+    fn palms_resting_together_or_fists_meeting_do_not_clap() {
+        let resting = composites((0..10).map(|_| [palm_at(300.0, 240.0), palm_at(340.0, 240.0)]));
+        assert_eq!(count(&resting, CompositeGesture::Clap), 0, "{resting:?}");
+
+        let fist = |x: f32| {
+            let mut hand = palm_at(x, 240.0);
+            hand.detail = Some(GestureDetail::new(GestureKind::Fist));
+            hand
+        };
+        let gaps = [240.0, 160.0, 80.0, 40.0, 40.0, 40.0];
+        let fists = composites(gaps.map(|gap| [fist(320.0 - gap / 2.0), fist(320.0 + gap / 2.0)]));
+        assert_eq!(count(&fists, CompositeGesture::Clap), 0, "{fists:?}");
+    }
+
+    #[test]
+    fn hands_collapsed_onto_a_point_make_nothing() {
+        let point = |x: f32| {
+            let mut hand = palm_at(x, 240.0);
+            hand.landmarks = vec![(x, 240.0); 21];
+            hand
+        };
+        let gaps = [240.0, 160.0, 80.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let seen = composites(gaps.map(|gap| [point(320.0 - gap / 2.0), point(320.0 + gap / 2.0)]));
+        assert!(seen.iter().all(Option::is_none), "{seen:?}");
+    }
+
+    /// Index tips touching above touching thumb tips, wrists apart.
+    fn heart_pair(kind: GestureKind, fingers: [FingerState; 4]) -> [HandResult; 2] {
+        let left = hand_2d(
+            (220.0, 320.0),
+            &[
+                (9, (250.0, 260.0)),
+                (8, (315.0, 200.0)),
+                (4, (315.0, 260.0)),
+            ],
+            kind,
+            fingers,
+        );
+        let right = hand_2d(
+            (420.0, 320.0),
+            &[
+                (9, (390.0, 260.0)),
+                (8, (325.0, 200.0)),
+                (4, (325.0, 260.0)),
+            ],
+            kind,
+            fingers,
+        );
+        [left, right]
+    }
+
+    #[test]
+    fn finger_hearts_or_curved_hands_held_together_make_a_heart() {
+        let seen = composites((0..5).map(|_| heart_pair(GestureKind::HandHeart, [F; 4])));
+        let min_frames = COMPOSITE_MIN_FRAMES as usize;
+        assert!(
+            seen[..min_frames - 1].iter().all(Option::is_none),
+            "{seen:?}"
+        );
+        assert_eq!(
+            seen[min_frames - 1],
+            Some(CompositeGesture::Heart),
+            "{seen:?}"
+        );
+
+        let curved = composites((0..5).map(|_| heart_pair(GestureKind::Unknown, [H, H, F, F])));
+        assert_eq!(
+            curved.last(),
+            Some(&Some(CompositeGesture::Heart)),
+            "{curved:?}"
+        );
+    }
+
+    #[test]
+    fn straight_fingers_touching_are_not_a_heart() {
+        let seen = composites((0..6).map(|_| heart_pair(GestureKind::Palm, [E; 4])));
+        assert_eq!(count(&seen, CompositeGesture::Heart), 0, "{seen:?}");
+
+        // A single frame of a heart is not enough.
+        let mut frames: Vec<_> = (0..6)
+            .map(|_| heart_pair(GestureKind::Palm, [E; 4]))
+            .collect();
+        frames[3] = heart_pair(GestureKind::HandHeart, [F; 4]);
+        let flicker = composites(frames);
+        assert_eq!(count(&flicker, CompositeGesture::Heart), 0, "{flicker:?}");
+    }
+
+    /// Opposite corners: index up and thumb right at the lower left, index
+    /// down and thumb left at the upper right.
+    fn frame_pair(kind: GestureKind) -> [HandResult; 2] {
+        let lower_left = hand_2d(
+            (150.0, 350.0),
+            &[
+                (5, (160.0, 300.0)),
+                (8, (160.0, 230.0)),
+                (2, (180.0, 330.0)),
+                (4, (240.0, 330.0)),
+            ],
+            kind,
+            [E, F, F, F],
+        );
+        let upper_right = hand_2d(
+            (490.0, 130.0),
+            &[
+                (5, (480.0, 180.0)),
+                (8, (480.0, 250.0)),
+                (2, (460.0, 150.0)),
+                (4, (400.0, 150.0)),
+            ],
+            kind,
+            [E, F, F, F],
+        );
+        [lower_left, upper_right]
+    }
+
+    #[test]
+    fn two_l_shapes_at_opposite_corners_make_a_frame() {
+        let seen = composites((0..5).map(|_| frame_pair(GestureKind::ThumbIndex)));
+        assert_eq!(
+            seen.last(),
+            Some(&Some(CompositeGesture::Frame)),
+            "{seen:?}"
+        );
+
+        // Both pointing the same way is two L shapes, not a frame.
+        let [lower_left, _] = frame_pair(GestureKind::ThumbIndex);
+        let mut beside = lower_left.clone();
+        for point in &mut beside.landmarks {
+            point.0 += 300.0;
+        }
+        let same_way = composites((0..5).map(|_| [lower_left.clone(), beside.clone()]));
+        assert!(same_way.iter().all(Option::is_none), "{same_way:?}");
+    }
+
+    #[test]
+    fn the_count_is_a_majority_of_the_last_frames() {
+        let mut tracker = CountTracker::default();
+        let seen: Vec<u8> = [3, 4, 3, 3, 4, 4, 4]
+            .map(|count| tracker.update(count))
+            .to_vec();
+        // The early tie goes to the newest count; after that 4 takes over only
+        // once it holds most of the last five frames.
+        assert_eq!(seen, [3, 4, 3, 3, 3, 4, 4]);
+    }
+
+    #[test]
+    fn a_hand_matching_no_gesture_still_has_a_count() {
+        let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+        let points = hand(Thumb::Tucked, [F, E, F, E]);
+        let detail = classify(&mut classifier, &points);
+        assert_eq!(detail.primary, GestureKind::Unknown);
+        assert_eq!(detail.count, Some(2));
+    }
+
+    /// `points` tipped `pitch` degrees about the image x axis, fingers towards
+    /// the camera, then turned `yaw` degrees about the vertical axis.
+    fn turned(points: &[[f32; 3]], pitch: f32, yaw: f32) -> Vec<[f32; 3]> {
+        let (pitch_sin, pitch_cos) = pitch.to_radians().sin_cos();
+        let (yaw_sin, yaw_cos) = yaw.to_radians().sin_cos();
+        points
+            .iter()
+            .map(|[x, y, z]| {
+                let (y, z) = (y * pitch_cos - z * pitch_sin, y * pitch_sin + z * pitch_cos);
+                [x * yaw_cos - z * yaw_sin, y, x * yaw_sin + z * yaw_cos]
+            })
+            .collect()
+    }
+
+    /// The index finger out and the thumb tip 0.4 palm lengths from its tip,
+    /// inside the default pinch threshold.
+    fn pinching() -> Vec<[f32; 3]> {
+        let mut points = hand(Thumb::Out, [E, F, F, F]);
+        points[4] = along(points[8], [0.8, 0.6, 0.0], 0.4);
+        points
+    }
+
+    #[test]
+    fn palm_lengths_read_a_pinch_the_same_from_any_side() {
+        let poses = [
+            pinching(),
+            // Edge-on to the camera.
+            turned(&pinching(), 0.0, 75.0),
+            // Fingers pointed at the camera.
+            turned(&pinching(), 70.0, 0.0),
+        ];
+        for (i, points) in poses.iter().enumerate() {
+            let (raw, _) = in_pixels(points);
+            let normalized = normalize_landmarks(&raw, LandmarkNormalization::PalmLength);
+            let gap = distance3(normalized[4], normalized[8]);
+            assert!((gap - 0.4).abs() < 1e-3, "pose {i}: {gap}");
+
+            let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+            let pinch = classify(&mut classifier, points).pinch.unwrap();
+            assert!(pinch.closed, "pose {i}: {pinch:?}");
+        }
+    }
+
+    #[test]
+    fn pinch_delta_only_counts_while_closed() {
+        let mut tracker = PinchTracker::default();
+        let closing = tracker.update(0.3, 0.5);
+        assert!(closing.closed && closing.delta == 0.0, "{closing:?}");
+
+        // Opening the fingers a little zooms without letting go.
+        let moved = tracker.update(0.6, 0.5);
+        assert!(moved.closed, "{moved:?}");
+        assert!((moved.delta - 0.12).abs() < 1e-4, "{moved:?}");
+
+        let mut released = moved;
+        while released.closed {
+            released = tracker.update(1.2, 0.5);
+        }
+        assert_eq!(released.delta, 0.0, "{released:?}");
+        let open = tracker.update(1.0, 0.5);
+        assert!(!open.closed && open.delta == 0.0, "{open:?}");
+
+        // The next close measures from where it closes, not the last anchor.
+        while !tracker.update(0.2, 0.5).closed {}
+        let reclosed = tracker.update(0.2, 0.5);
+        assert!(
+            reclosed.delta <= 0.0 && reclosed.delta > -0.2,
+            "{reclosed:?}"
+        );
+    }
+
+    #[test]
+    fn the_bounding_box_opens_a_foreshortened_pinch() {
+        let config = RecognizerConfig {
+            normalization: LandmarkNormalization::BoundingBox,
+            ..RecognizerConfig::default()
+        };
+        let pinch = |points: &[[f32; 3]]| {
+            let mut classifier = GestureClassifier::rules_only(&config);
+            classify(&mut classifier, points).pinch.unwrap()
+        };
+        let facing = pinch(&pinching());
+        // Fingers pointed at the camera shrink the box but not the pinch.
+        let foreshortened = pinch(&turned(&pinching(), 70.0, 0.0));
+        assert!(facing.closed, "{facing:?}");
+        assert!(!foreshortened.closed, "{foreshortened:?}");
+        assert!(foreshortened.distance > facing.distance * 1.2);
+    }
+
+    /// The thumb tip brought to `gap` palm lengths from the index tip.
+    fn thumb_to_index(mut points: Vec<[f32; 3]>, gap: f32) -> Vec<[f32; 3]> {
+        points[4] = along(points[8], [1.0, 0.0, 0.0], gap);
+        points
+    }
+
+    /// The index hooked tight, its tip back beside its own knuckle where a
+    /// pinching thumb meets it.
+    fn hooked_index() -> Vec<[f32; 3]> {
+        let mut points = hand(Thumb::Out, [F, E, E, E]);
+        let knuckle = points[5];
+        points[6] = along(knuckle, [0.0, 0.0, -1.0], 0.3);
+        points[7] = along(points[6], [0.0, 0.6, 0.8], 0.15);
+        points[8] = along(knuckle, [0.0, 0.4, -0.9], 0.25);
+        points
+    }
+
+    #[test]
+    fn a_curled_index_meeting_the_thumb_is_ok() {
+        let ring = thumb_to_index(hand(Thumb::Out, [H, E, E, E]), 0.05);
+        assert_eq!(primary(&ring), GestureKind::Ok);
+    }
+
+    #[test]
+    fn poses_on_the_way_to_a_ring_are_not_ok() {
+        // Relaxing out of an open palm the tips meet with the index straight,
+        // and curling in they are still apart.
+        let straight = thumb_to_index(hand(Thumb::Out, [E; 4]), 0.05);
+        let open_ring = thumb_to_index(hand(Thumb::Out, [H, E, E, E]), 0.4);
+        let middle_folded = thumb_to_index(hand(Thumb::Out, [H, F, E, E]), 0.05);
+        let poses = [
+            ("straight index", straight),
+            ("open ring", open_ring),
+            ("pinch", thumb_to_index(hooked_index(), 0.05)),
+            ("middle folded", middle_folded),
+        ];
+        for (pose, points) in poses {
+            assert_ne!(primary(&points), GestureKind::Ok, "{pose}");
+        }
+    }
+
+    /// Wrist, knuckle and the next two joints of an index finger bent by
+    /// `bend` degrees at both its knuckle and its middle joint.
+    fn bent_index(bend: f32) -> Vec<[f32; 3]> {
+        let knuckle = KNUCKLES[0];
+        let out = normalize(knuckle);
+        let toward = |turns: f32| {
+            let (sin, cos) = (bend * turns).to_radians().sin_cos();
+            [out[0] * cos, out[1] * cos, -sin]
+        };
+        let pip = along(knuckle, toward(1.0), 0.4);
+        let dip = along(pip, toward(2.0), 0.25);
+        vec![[0.0; 3], knuckle, pip, dip]
+    }
+
+    #[test]
+    fn joint_angles_follow_the_bend() {
+        for (bend, state) in [(0.0, E), (45.0, H), (90.0, F), (110.0, F)] {
+            let angles = measure_finger(&bent_index(bend), [1, 2, 3]);
+            assert!(
+                (angles.mcp - (180.0 - bend)).abs() < 0.1,
+                "{bend}: {angles:?}"
+            );
+            assert!(
+                (angles.pip - (180.0 - bend)).abs() < 0.1,
+                "{bend}: {angles:?}"
+            );
+            assert_eq!(classify_finger(angles), state, "{bend}: {angles:?}");
+        }
+    }
+
+    #[test]
+    fn the_detail_reports_straight_and_curled_fingers() {
+        let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+        let detail = classify(&mut classifier, &hand(Thumb::Out, [E, F, E, F]));
+        for finger in [1, 3] {
+            let angles = detail.finger_angles[finger];
+            assert!(angles.mcp > 179.0 && angles.pip > 179.0, "{angles:?}");
+            assert_eq!(angles.abduction, None);
+        }
+        for finger in [2, 4] {
+            let angles = detail.finger_angles[finger];
+            assert!((60.0..=90.5).contains(&angles.mcp), "{angles:?}");
+            assert!((60.0..=90.5).contains(&angles.pip), "{angles:?}");
+        }
+
+        let spread = detail.finger_angles[0].abduction.unwrap();
+        let up = classify(&mut classifier, &hand(Thumb::Up, [E, F, E, F]));
+        let closer = up.finger_angles[0].abduction.unwrap();
+        assert!(spread > closer + 15.0, "out {spread}, up {closer}");
+    }
+
+    /// Primary gesture of each frame of an open palm whose wrist sits
+    /// `offset(t)` palm lengths right of centre at `t` seconds, at 30 fps.
+    fn palm_track(seconds: f32, offset: impl Fn(f32) -> f32) -> Vec<GestureKind> {
+        let classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+        palm_track_with(classifier, seconds, offset)
+    }
+
+    fn palm_track_with(
+        mut classifier: GestureClassifier,
+        seconds: f32,
+        offset: impl Fn(f32) -> f32,
+    ) -> Vec<GestureKind> {
+        let start = Instant::now();
+        let frames = (seconds * 30.0) as u32;
+        (0..frames)
+            .map(|frame| {
+                let t = frame as f32 / 30.0;
+                let dx = offset(t);
+                let points: Vec<[f32; 3]> = hand(Thumb::Out, [E; 4])
+                    .iter()
+                    .map(|[x, y, z]| [x + dx, *y, *z])
+                    .collect();
+                let (raw, projected) = in_pixels(&points);
+                let at = start + Duration::from_secs_f32(t);
+                classifier
+                    .classify(1, &raw, &projected, 0.9, Some(0.9), at)
+                    .expect("hand is confident enough to classify")
+                    .primary
+            })
+            .collect()
+    }
+
+    #[test]
+    fn a_palm_waved_side_to_side_becomes_a_wave() {
+        let seen = palm_track(2.0, |t| 0.7 * (t * 2.0 * std::f32::consts::TAU).sin());
+        let first = seen.iter().position(|kind| *kind == GestureKind::Wave);
+        // Not before the oscillation has gone on for long enough.
+        assert!(first.is_some_and(|frame| frame >= 18), "{seen:?}");
+        assert_eq!(seen.last(), Some(&GestureKind::Wave), "{seen:?}");
+    }
+
+    #[test]
+    fn a_palm_held_still_or_slid_stays_a_palm() {
+        let still = palm_track(2.0, |_| 0.0);
+        assert!(
+            still.iter().all(|kind| *kind == GestureKind::Palm),
+            "{still:?}"
+        );
+        let slid = palm_track(2.0, |t| t * 0.3);
+        assert!(!slid.contains(&GestureKind::Wave), "{slid:?}");
+    }
+
+    #[test]
+    fn a_wave_survives_a_dropped_frame_but_not_a_pause() {
+        let mut tracker = WaveTracker::default();
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+        let fanning = GestureMotion::Fanning;
+        for ms in (0..600).step_by(33) {
+            assert!(!tracker.update(GestureKind::Palm, fanning, at(ms)));
+        }
+        assert!(tracker.update(GestureKind::Palm, fanning, at(600)));
+        // One blurred frame reads as Unknown and still moving.
+        assert!(tracker.update(GestureKind::Unknown, GestureMotion::Moving, at(633)));
+        assert!(tracker.update(GestureKind::Palm, fanning, at(666)));
+
+        // Held still past the grace period, the palm is a palm again.
+        assert!(tracker.update(GestureKind::Palm, GestureMotion::Steady, at(800)));
+        assert!(!tracker.update(GestureKind::Palm, GestureMotion::Steady, at(1_000)));
+        assert!(!tracker.update(GestureKind::Palm, fanning, at(1_033)));
+    }
+
+    /// The left hand that is the mirror image of a right hand's `points`.
+    fn as_left(points: &[[f32; 3]]) -> Vec<[f32; 3]> {
+        points.iter().map(|[x, y, z]| [-x, *y, *z]).collect()
+    }
+
+    /// Classifies `points` with a handedness score firmly on one side.
+    fn classify_hand(points: &[[f32; 3]], handedness: Handedness) -> GestureDetail {
+        let score = if handedness == Handedness::Left {
+            0.1
+        } else {
+            0.9
+        };
+        let (raw, projected) = in_pixels(points);
+        GestureClassifier::rules_only(&RecognizerConfig::default())
+            .classify(1, &raw, &projected, 0.9, Some(score), Instant::now())
+            .expect("hand is confident enough to classify")
+    }
+
+    /// Whether the thumb tips of two hands lie as far in front of the palm.
+    /// Which side is the front flips with the hand, so a left hand only
+    /// agrees with a right one once it is reflected.
+    fn same_opposition(a: &GestureDetail, b: &GestureDetail) -> bool {
+        let opposition = |detail: &GestureDetail| detail.finger_angles[0].opposition.unwrap();
+        (opposition(a) - opposition(b)).abs() < 1e-3
+    }
+
+    #[test]
+    fn a_left_thumbs_up_reads_like_a_right_one() {
+        // The tip a little in front of the palm, where a real thumb rests.
+        let mut right = hand(Thumb::Up, [F; 4]);
+        right[4][2] = -0.2;
+        let left = as_left(&right);
+        let direction = |points: &[[f32; 3]]| thumb_direction(&in_pixels(points).1);
+        assert_eq!(direction(&right), Some(GestureKind::Like));
+        assert_eq!(direction(&left), Some(GestureKind::Like));
+
+        let (right, left) = (
+            classify_hand(&right, Handedness::Right),
+            classify_hand(&left, Handedness::Left),
+        );
+        assert_eq!(right.handedness, Handedness::Right);
+        assert_eq!(left.handedness, Handedness::Left);
+        assert_eq!(left.finger_states, right.finger_states);
+        assert!(same_opposition(&left, &right));
+        assert_eq!(right.primary, GestureKind::Like);
+        assert_eq!(left.primary, GestureKind::Like);
+    }
+
+    #[test]
+    fn a_left_pinch_reads_like_a_right_one() {
+        // Meeting the index from in front of the palm.
+        let mut points = pinching();
+        points[4] = along(points[8], [0.8, 0.0, -0.6], 0.4);
+        let right = classify_hand(&points, Handedness::Right);
+        let left = classify_hand(&as_left(&points), Handedness::Left);
+        assert_eq!(right.handedness, Handedness::Right);
+        assert_eq!(left.handedness, Handedness::Left);
+        assert_eq!(left.finger_states, right.finger_states);
+        assert!(same_opposition(&left, &right));
+
+        let (left, right) = (left.pinch.unwrap(), right.pinch.unwrap());
+        assert!(
+            (left.distance - right.distance).abs() < 1e-3,
+            "{left:?} {right:?}"
+        );
+        assert!(left.closed && right.closed, "{left:?} {right:?}");
+    }
+
+    #[test]
+    fn a_left_finger_heart_measures_like_a_right_one() {
+        // The one-hand heart itself comes from the model; what the rules see
+        // of it has to agree between the hands.
+        let right = thumb_to_index(hand(Thumb::Out, [H, F, F, F]), 0.05);
+        let (right, left) = (
+            classify_hand(&right, Handedness::Right),
+            classify_hand(&as_left(&right), Handedness::Left),
+        );
+
+        assert_eq!(right.handedness, Handedness::Right);
+        assert_eq!(left.handedness, Handedness::Left);
+        assert_eq!(left.finger_states, right.finger_states);
+        for (left, right) in left.finger_angles.iter().zip(&right.finger_angles) {
+            assert!((left.mcp - right.mcp).abs() < 0.05, "{left:?} {right:?}");
+            assert!((left.pip - right.pip).abs() < 0.05, "{left:?} {right:?}");
+        }
+        assert!(same_opposition(&left, &right));
+        let (left, right) = (left.pinch.unwrap(), right.pinch.unwrap());
+        assert!((left.distance - right.distance).abs() < 1e-3);
+    }
+
+    /// Index and middle up with their tips `gap` palm lengths apart, the index
+    /// tip on the little finger side of the middle one when `crossed`.
+    fn index_and_middle(gap: f32, crossed: bool) -> Vec<[f32; 3]> {
+        let mut points = hand(Thumb::Tucked, [E, E, F, F]);
+        let side = if crossed { -1.0 } else { 1.0 };
+        points[8] = [side * gap / 2.0, -1.8, 0.0];
+        points[12] = [-side * gap / 2.0, -1.8, 0.0];
+        points
+    }
+
+    #[test]
+    fn tips_that_cross_are_crossed_fingers_and_tips_that_touch_are_victory() {
+        assert_eq!(
+            primary(&index_and_middle(0.12, true)),
+            GestureKind::CrossedFingers
+        );
+        // Close enough to cross, but still in knuckle order.
+        assert_eq!(primary(&index_and_middle(0.12, false)), GestureKind::Peace);
+        // Crossed over, but too far apart to be one crossing.
+        assert_eq!(primary(&index_and_middle(0.5, true)), GestureKind::Peace);
+        assert_eq!(
+            primary(&hand(Thumb::Tucked, [E, E, F, F])),
+            GestureKind::Peace
+        );
+    }
+
+    #[test]
+    fn thumb_up_and_index_sideways_is_a_finger_gun() {
+        // Turned a quarter so the index points left and the thumb up.
+        let sideways = |points: Vec<[f32; 3]>| -> Vec<[f32; 3]> {
+            points.iter().map(|[x, y, z]| [*y, -x, *z]).collect()
+        };
+        let gun = sideways(hand(Thumb::Out, [E, F, F, F]));
+        assert_eq!(primary(&gun), GestureKind::FingerGun);
+
+        // Held upright the thumb points sideways, so it is no gun; with the
+        // thumb folded only the index is left.
+        assert_ne!(
+            primary(&hand(Thumb::Out, [E, F, F, F])),
+            GestureKind::FingerGun
+        );
+        let point = sideways(hand(Thumb::Tucked, [E, F, F, F]));
+        assert_eq!(primary(&point), GestureKind::One);
+    }
+
+    /// A classifier with every gesture but `disabled` enabled.
+    fn without(disabled: &[GestureKind]) -> GestureClassifier {
+        let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+        let enabled = GestureKind::ALL
+            .into_iter()
+            .filter(|kind| !disabled.contains(kind))
+            .collect();
+        classifier.set_enabled_gestures(enabled);
+        classifier
+    }
+
+    #[test]
+    fn a_disabled_gesture_makes_way_for_the_next_candidate() {
+        // The middle finger half way up reads as One or Peace.
+        let between = hand(Thumb::Tucked, [E, H, F, F]);
+        let detail = classify(&mut without(&[]), &between);
+        assert_eq!(
+            (detail.primary, detail.secondary),
+            (GestureKind::One, Some(GestureKind::Peace))
+        );
+        let detail = classify(&mut without(&[GestureKind::One]), &between);
+        assert_eq!(
+            (detail.primary, detail.secondary),
+            (GestureKind::Peace, None)
+        );
+
+        // A ring closing on the way to a pinch falls through to the open hand.
+        let ring = thumb_to_index(hand(Thumb::Out, [H, E, E, E]), 0.05);
+        assert_eq!(classify(&mut without(&[]), &ring).primary, GestureKind::Ok);
+        assert_eq!(
+            classify(&mut without(&[GestureKind::Ok]), &ring).primary,
+            GestureKind::Palm
+        );
+    }
+
+    #[test]
+    fn with_every_candidate_disabled_the_hand_is_unknown() {
+        let detail = classify(
+            &mut without(&GestureKind::ALL),
+            &hand(Thumb::Tucked, [F; 4]),
+        );
+        assert_eq!(detail.primary, GestureKind::Unknown);
+        assert!(detail.scores.is_empty(), "{:?}", detail.scores);
+    }
+
+    #[test]
+    fn a_disabled_wave_leaves_the_palm() {
+        let waving = |t: f32| 0.7 * (t * 2.0 * std::f32::consts::TAU).sin();
+        let seen = palm_track_with(without(&[GestureKind::Wave]), 2.0, waving);
+        assert!(
+            seen.iter().all(|kind| *kind == GestureKind::Palm),
+            "{seen:?}"
+        );
+    }
+
+    #[test]
+    fn the_thumb_tells_rock_from_i_love_you_in_either_hand() {
+        for (thumb, expected) in [
+            (Thumb::Out, GestureKind::ILoveYou),
+            (Thumb::Tucked, GestureKind::Rock),
+        ] {
+            let right = hand(thumb, [E, F, F, E]);
+            let left: Vec<[f32; 3]> = right.iter().map(|[x, y, z]| [-x, *y, *z]).collect();
+            for (points, score) in [(right, 0.9), (left, 0.1)] {
+                let (raw, projected) = in_pixels(&points);
+                let detail = GestureClassifier::rules_only(&RecognizerConfig::default())
+                    .classify(1, &raw, &projected, 0.9, Some(score), Instant::now())
+                    .expect("hand is confident enough to classify");
+                assert_eq!(detail.primary, expected, "{thumb:?}, handedness {score}");
+            }
+        }
+    }
+
+    #[test]
+    fn reordering_the_plugins_changes_which_kind_wins() {
+        let points = hand(Thumb::Out, [E, F, F, E]);
+        let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+        assert_eq!(
+            classify(&mut classifier, &points).primary,
+            GestureKind::ILoveYou
+        );
+
+        // Run before the templates, the thumb has no Rock to settle yet.
+        classifier.set_plugins(vec![
+            Box::new(plugin::RuleTemplates),
+            Box::new(plugin::RockOrILoveYou),
+        ]);
+        assert_eq!(
+            classify(&mut classifier, &points).primary,
+            GestureKind::Rock
+        );
+        classifier.set_plugins(vec![
+            Box::new(plugin::RockOrILoveYou),
+            Box::new(plugin::RuleTemplates),
+        ]);
+        assert_eq!(
+            classify(&mut classifier, &points).primary,
+            GestureKind::ILoveYou
+        );
+    }
+
+    #[test]
+    fn a_half_bent_thumb_is_decided_by_its_abduction() {
+        // The thumb state alone would not say which of the two this is.
+        let tucked = hand(Thumb::Tucked, [E, F, F, E]);
+        assert!(thumb_abduction(&tucked) <= ROCK_MAX_ABDUCTION_DEG);
+        assert_eq!(
+            rock_or_i_love_you(&tucked, FingerState::HalfBent),
+            GestureKind::Rock
+        );
+        let out = hand(Thumb::Out, [E, F, F, E]);
+        assert!(thumb_abduction(&out) >= I_LOVE_YOU_MIN_ABDUCTION_DEG);
+        assert_eq!(
+            rock_or_i_love_you(&out, FingerState::HalfBent),
+            GestureKind::ILoveYou
+        );
+    }
+
+    #[test]
+    fn rock_i_love_you_and_three_have_their_own_emoji() {
+        let emoji =
+            [GestureKind::Rock, GestureKind::ILoveYou, GestureKind::Three].map(|k| k.emoji());
+        assert_ne!(emoji[0], emoji[1]);
+        assert_ne!(emoji[0], emoji[2]);
+        assert_ne!(emoji[1], emoji[2]);
+    }
+
+    #[test]
+    fn a_tracked_hand_keeps_its_side_inside_the_hysteresis_band() {
+        let right = Some(Handedness::Right);
+        for score in [0.45, 0.5, 0.6, 0.36] {
+            assert_eq!(handedness_from_score(score, right), Handedness::Right);
+        }
+        assert_eq!(handedness_from_score(0.3, right), Handedness::Left);
+        assert_eq!(
+            handedness_from_score(0.64, Some(Handedness::Left)),
+            Handedness::Left
+        );
+        assert_eq!(
+            handedness_from_score(0.7, Some(Handedness::Left)),
+            Handedness::Right
+        );
+        // A new hand has nothing to hold on to.
+        assert_eq!(handedness_from_score(0.55, None), Handedness::Right);
+        assert_eq!(handedness_from_score(0.45, None), Handedness::Left);
+    }
+
+    #[test]
+    fn an_oscillating_score_gives_one_stable_label_per_hand() {
+        let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+        let (raw, projected) = in_pixels(&hand(Thumb::Out, [E; 4]));
+        let start = Instant::now();
+        let mut sides = |track_id: u64, scores: &[f32]| {
+            scores
+                .iter()
+                .zip(0u32..)
+                .map(|(&score, idx)| {
+                    let at = start + Duration::from_millis(33) * idx;
+                    classifier
+                        .classify(track_id, &raw, &projected, 0.9, Some(score), at)
+                        .expect("hand is confident enough to classify")
+                        .handedness
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let wobbling_right = [0.7, 0.45, 0.6, 0.48, 0.55, 0.45, 0.62, 0.46, 0.5, 0.58];
+        let seen = sides(1, &wobbling_right);
+        assert!(
+            seen.iter().all(|side| *side == Handedness::Right),
+            "{seen:?}"
+        );
+        // Each track keeps its own side.
+        let wobbling_left = [0.3, 0.55, 0.4, 0.6, 0.42, 0.52];
+        let seen = sides(2, &wobbling_left);
+        assert!(
+            seen.iter().all(|side| *side == Handedness::Left),
+            "{seen:?}"
+        );
+    }
+
+    #[test]
+    fn only_a_missing_score_leaves_the_side_unknown() {
+        let (raw, projected) = in_pixels(&hand(Thumb::Out, [E; 4]));
+        let detail = |score| {
+            GestureClassifier::rules_only(&RecognizerConfig::default())
+                .classify(1, &raw, &projected, 0.9, score, Instant::now())
+                .expect("hand is confident enough to classify")
+        };
+        let zero = detail(Some(0.0));
+        assert_eq!(zero.handedness, Handedness::Left);
+        assert_eq!(zero.handedness_confidence, 1.0);
+        let missing = detail(None);
+        assert_eq!(missing.handedness, Handedness::Unknown);
+        assert_eq!(missing.handedness_confidence, 0.0);
+        assert!((detail(Some(0.6)).handedness_confidence - 0.2).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,221 @@
+use std::{fmt, sync::Arc, time::Instant};
+
+use super::{
+    crossed_fingers_score, finger_gun_score, ok_score, rock_or_i_love_you, sort_scores,
+    template_scores, thumb_direction,
+};
+use crate::types::{CustomGesture, FingerAngles, FingerState, GestureKind, Handedness};
+
+/// What a plugin gets to look at for one hand on one frame.
+#[derive(Clone, Copy, Debug)]
+pub struct GestureContext<'a> {
+    pub track_id: u64,
+    pub timestamp: Instant,
+    /// The 21 landmarks normalized like the finger rules see them. A left
+    /// hand is reflected so every hand reads as a right one.
+    pub landmarks: &'a [[f32; 3]],
+    /// The same landmarks in frame pixels, unreflected.
+    pub projected: &'a [(f32, f32)],
+    /// Thumb first.
+    pub finger_states: [FingerState; 5],
+    pub finger_angles: [FingerAngles; 5],
+    /// The hand as the person holds it, mirroring taken into account.
+    pub handedness: Handedness,
+    /// Where the wrist was in frame pixels on this hand's earlier frames
+    /// over the last second or so, oldest first.
+    pub wrist_history: &'a [(Instant, (f32, f32))],
+    /// Whether the gesture model ranked this hand. Without it the rule
+    /// templates and the OK ring stand in.
+    pub model_ranked: bool,
+}
+
+/// One step of the classifier's ladder. The plugins run from the back of the
+/// list to the front over one ranking, highest first, that starts out as the
+/// gesture model's, so the front of the list has the last word. The first
+/// enabled gesture left in the ranking becomes the primary one.
+pub trait GesturePlugin: Send {
+    /// For logs and for checking the order plugins run in.
+    fn name(&self) -> &str;
+
+    /// The gesture this hand is making and a score from 0 to 1, or `None`
+    /// to leave the ranking as it is.
+    fn detect(&mut self, _ctx: &GestureContext<'_>) -> Option<(GestureKind, f32)> {
+        None
+    }
+
+    /// Reworks `ranking` as the plugins behind this one left it. By default
+    /// the detected gesture goes in front.
+    fn rank(&mut self, ctx: &GestureContext<'_>, ranking: &mut Vec<(GestureKind, f32)>) {
+        if let Some(hit) = self.detect(ctx) {
+            ranking.insert(0, hit);
+        }
+    }
+
+    /// New gestures this plugin reports, registered with their names when
+    /// the plugin is added to a classifier.
+    fn custom_gestures(&self) -> &[CustomGesture] {
+        &[]
+    }
+
+    /// Forget any per-hand state, e.g. after the frame source changed.
+    fn reset(&mut self) {}
+}
+
+/// Builds a plugin on the recognizer thread, see
+/// [`crate::pipeline::RecognizerBackend::with_plugin`], so plugins need not be
+/// `Clone` or `Sync`.
+#[derive(Clone)]
+pub struct PluginFactory(Arc<dyn Fn() -> Box<dyn GesturePlugin> + Send + Sync>);
+
+impl PluginFactory {
+    pub fn new<P: GesturePlugin + 'static>(build: impl Fn() -> P + Send + Sync + 'static) -> Self {
+        Self(Arc::new(move || Box::new(build())))
+    }
+
+    pub fn build(&self) -> Box<dyn GesturePlugin> {
+        (self.0)()
+    }
+}
+
+impl fmt::Debug for PluginFactory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PluginFactory")
+    }
+}
+
+/// The ladder that comes with the classifier, front first. The rule templates
+/// and the OK ring stand in for a missing model, the thumb then settles what
+/// the model or the templates guessed, and the two geometric detectors win
+/// over the Peace or thumb-index guess made for them.
+pub fn builtin_plugins() -> Vec<Box<dyn GesturePlugin>> {
+    vec![
+        Box::new(CrossedFingers),
+        Box::new(FingerGun),
+        Box::new(RockOrILoveYou),
+        Box::new(ThumbDirection),
+        Box::new(OkRing),
+        Box::new(RuleTemplates),
+    ]
+}
+
+/// Index and middle extended with their tips crossed over.
+pub struct CrossedFingers;
+
+impl GesturePlugin for CrossedFingers {
+    fn name(&self) -> &str {
+        "crossed_fingers"
+    }
+
+    fn detect(&mut self, ctx: &GestureContext<'_>) -> Option<(GestureKind, f32)> {
+        crossed_fingers_score(ctx.landmarks, &ctx.finger_states)
+            .map(|score| (GestureKind::CrossedFingers, score))
+    }
+}
+
+/// Thumb up with the index pointing sideways, the other fingers folded.
+pub struct FingerGun;
+
+impl GesturePlugin for FingerGun {
+    fn name(&self) -> &str {
+        "finger_gun"
+    }
+
+    fn detect(&mut self, ctx: &GestureContext<'_>) -> Option<(GestureKind, f32)> {
+        finger_gun_score(&ctx.finger_states, ctx.projected)
+            .map(|score| (GestureKind::FingerGun, score))
+    }
+}
+
+/// Rock and ILoveYou share every finger but the thumb, and the model has one
+/// class for both. A tucked thumb often reads as half bent, so its abduction
+/// decides.
+pub struct RockOrILoveYou;
+
+impl GesturePlugin for RockOrILoveYou {
+    fn name(&self) -> &str {
+        "rock_or_i_love_you"
+    }
+
+    fn rank(&mut self, ctx: &GestureContext<'_>, ranking: &mut Vec<(GestureKind, f32)>) {
+        let decided = rock_or_i_love_you(ctx.landmarks, ctx.finger_states[0]);
+        settle(ranking, [GestureKind::Rock, GestureKind::ILoveYou], decided);
+    }
+}
+
+/// Like or Dislike from where the thumb points in the image. The model and
+/// the finger states see the hand in the rotated crop, where "up" follows the
+/// hand axis, so neither can tell.
+pub struct ThumbDirection;
+
+impl GesturePlugin for ThumbDirection {
+    fn name(&self) -> &str {
+        "thumb_direction"
+    }
+
+    fn rank(&mut self, ctx: &GestureContext<'_>, ranking: &mut Vec<(GestureKind, f32)>) {
+        let pair = [GestureKind::Like, GestureKind::Dislike];
+        match thumb_direction(ctx.projected) {
+            Some(decided) => settle(ranking, pair, decided),
+            // A sideways thumb does not match the thumb template.
+            None if !ctx.model_ranked => ranking.retain(|(kind, _)| !pair.contains(kind)),
+            None => {}
+        }
+    }
+}
+
+/// A closed thumb and index ring with the other fingers up, when the model
+/// did not rank the hand. It goes ahead of every template it scores at least
+/// as well as.
+pub struct OkRing;
+
+impl GesturePlugin for OkRing {
+    fn name(&self) -> &str {
+        "ok_ring"
+    }
+
+    fn rank(&mut self, ctx: &GestureContext<'_>, ranking: &mut Vec<(GestureKind, f32)>) {
+        if ctx.model_ranked {
+            return;
+        }
+        if let Some(score) = ok_score(ctx.landmarks, &ctx.finger_states) {
+            let at = ranking
+                .iter()
+                .position(|(_, other)| *other <= score)
+                .unwrap_or(ranking.len());
+            ranking.insert(at, (GestureKind::Ok, score));
+        }
+    }
+}
+
+/// The finger state templates, when the model is missing or could not run.
+pub struct RuleTemplates;
+
+impl GesturePlugin for RuleTemplates {
+    fn name(&self) -> &str {
+        "rule_templates"
+    }
+
+    fn rank(&mut self, ctx: &GestureContext<'_>, ranking: &mut Vec<(GestureKind, f32)>) {
+        if ctx.model_ranked {
+            return;
+        }
+        ranking.extend(template_scores(&ctx.finger_states));
+        sort_scores(ranking);
+    }
+}
+
+/// Swaps the two kinds of `pair` throughout `ranking` when the first of them
+/// in it is not `decided`.
+fn settle(ranking: &mut [(GestureKind, f32)], pair: [GestureKind; 2], decided: GestureKind) {
+    let first = ranking.iter().find(|(kind, _)| pair.contains(kind));
+    if first.is_none_or(|(kind, _)| *kind == decided) {
+        return;
+    }
+    for (kind, _) in ranking {
+        if *kind == pair[0] {
+            *kind = pair[1];
+        } else if *kind == pair[1] {
+            *kind = pair[0];
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use std::{
+    io::{self, Write},
+    path::Path,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use crossbeam_channel::RecvTimeoutError;
+use serde::Serialize;
+
+use crate::{
+    pipeline::{
+        ImageSequenceSource, LabelCsvWriter, MetricsHandle, RecognizerBackend, run_image_sequence,
+    },
+    types::{
+        CompositeGesture, FingerAngles, GestureKind, GestureMotion, GestureResult, HandOrientation,
+        Handedness, PinchInfo, RotationInfo,
+    },
+    universe::GestureUniverse,
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// One line of headless output.
+#[derive(Serialize)]
+struct GestureRecord<'a> {
+    frame_id: u64,
+    label: String,
+    kind: Option<GestureKind>,
+    composite: Option<CompositeGesture>,
+    confidence: f32,
+    handedness: Option<Handedness>,
+    handedness_confidence: Option<f32>,
+    motion: Option<GestureMotion>,
+    pinch: Option<PinchInfo>,
+    rotation: Option<RotationInfo>,
+    orientation: Option<HandOrientation>,
+    count: Option<u8>,
+    finger_angles: Option<[FingerAngles; 5]>,
+    landmark_count: usize,
+    raw_landmarks: Option<&'a [[f32; 3]]>,
+    timestamp_ms: u64,
+}
+
+impl<'a> GestureRecord<'a> {
+    fn new(result: &'a GestureResult, started_at: Instant) -> Self {
+        let detail = result.detail.as_ref();
+        Self {
+            frame_id: result.frame_id,
+            label: result.label(),
+            kind: detail.map(|d| d.primary),
+            composite: result.composite,
+            confidence: result.confidence,
+            handedness: detail.map(|d| d.handedness),
+            handedness_confidence: detail.map(|d| d.handedness_confidence),
+            motion: detail.map(|d| d.motion),
+            count: detail.and_then(|d| d.count),
+            finger_angles: detail.map(|d| d.finger_angles),
+            pinch: detail.and_then(|d| d.pinch),
+            rotation: detail.and_then(|d| d.rotation),
+            orientation: detail.and_then(|d| d.orientation),
+            landmark_count: result.landmarks.as_ref().map_or(0, Vec::len),
+            raw_landmarks: result.raw_landmarks.as_deref(),
+            timestamp_ms: result
+                .timestamp
+                .saturating_duration_since(started_at)
+                .as_millis() as u64,
+        }
+    }
+}
+
+/// Runs camera and recognizer without a window, writing one JSON line per
+/// result to stdout until Ctrl-C.
+pub fn run(backend: RecognizerBackend, metrics: MetricsHandle) -> Result<()> {
+    let running = Arc::new(AtomicBool::new(true));
+    let running_flag = running.clone();
+    ctrlc::set_handler(move || running_flag.store(false, Ordering::SeqCst))
+        .context("failed to install Ctrl-C handler")?;
+
+    let started_at = Instant::now();
+    let universe = GestureUniverse::builder()
+        .backend(backend)
+        .metrics(metrics)
+        .start()?;
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    while running.load(Ordering::SeqCst) {
+        let recognized = match universe.results().recv_timeout(POLL_INTERVAL) {
+            Ok(recognized) => recognized,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => {
+                log::warn!("recognizer stopped unexpectedly");
+                break;
+            }
+        };
+
+        let record = GestureRecord::new(&recognized.result, started_at);
+        let line = serde_json::to_string(&record)?;
+        if writeln!(out, "{line}").and_then(|_| out.flush()).is_err() {
+            // stdout closed, e.g. the consumer of a pipe went away.
+            break;
+        }
+    }
+
+    log::info!("shutting down headless pipeline");
+    universe.stop();
+
+    Ok(())
+}
+
+/// Runs every image in `dir` through the recognizer once, writing the same JSON
+/// lines as [`run`] and, with `csv`, one `filename,kind,confidence,handedness`
+/// row per image.
+pub fn run_images(
+    backend: RecognizerBackend,
+    metrics: MetricsHandle,
+    dir: &Path,
+    interval: Duration,
+    csv: Option<&Path>,
+) -> Result<()> {
+    let source = ImageSequenceSource::open(dir, interval)?;
+    log::info!(
+        "headless mode replaying {} images from {}",
+        source.paths().len(),
+        dir.display()
+    );
+    let mut csv = csv.map(LabelCsvWriter::create).transpose()?;
+
+    let started_at = Instant::now();
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    run_image_sequence(backend, &source, metrics, |path, result| {
+        let record = GestureRecord::new(result, started_at);
+        writeln!(out, "{}", serde_json::to_string(&record)?)?;
+        if let Some(csv) = csv.as_mut() {
+            csv.write(path, result)?;
+        }
+        Ok(())
+    })?;
+    out.flush()?;
+
+    if let Some(csv) = csv {
+        csv.finish()?;
+    }
+    Ok(())
+}
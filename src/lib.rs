@@ -0,0 +1,51 @@
+//! The app's modules as a library, so the binary and the examples share one
+//! copy of the pipeline instead of including its files by path.
+//!
+//! Apps embedding the recognizer need little more than the items re-exported
+//! here. [`GestureUniverse::builder`] opens a camera and starts the recognizer
+//! on it; [`start_recognizer`] takes frames from any source instead:
+//!
+//! ```no_run
+//! use gesture_universe::GestureUniverse;
+//!
+//! let universe = GestureUniverse::builder().start()?;
+//! for recognized in universe.results() {
+//!     println!("{}", recognized.result.label());
+//! }
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+//!
+//! Result timestamps are [`std::time::Instant`]s, good for measuring between
+//! results in the same process only; `captured_at` is the wall-clock time to
+//! store or send elsewhere.
+
+pub mod actions;
+pub mod analytics;
+#[cfg(feature = "async")]
+pub mod async_recognizer;
+pub mod config;
+pub mod gesture;
+pub mod headless;
+pub mod locale;
+#[cfg(feature = "metrics-http")]
+pub mod metrics_http;
+pub mod model_download;
+pub mod mqtt_output;
+pub mod osc_output;
+pub mod pipeline;
+pub mod self_test;
+pub mod trace;
+pub mod types;
+pub mod ui;
+pub mod universe;
+#[cfg(feature = "ws-output")]
+pub mod ws_output;
+
+#[cfg(feature = "async")]
+pub use async_recognizer::{AsyncGestures, start_recognizer_async};
+pub use pipeline::{
+    CameraDevice, CameraOpenOptions, CameraStream, RecognizerBackend, RecognizerControl,
+    available_cameras, start_camera_stream, start_recognizer,
+};
+pub use types::{Frame, GestureDetail, GestureEvent, GestureKind, GestureResult, RecognizedFrame};
+pub use universe::{GestureUniverse, GestureUniverseBuilder};
@@ -0,0 +1,99 @@
+//! The language gesture names and the other recognition labels are shown
+//! in. Labels are looked up when they are drawn, so switching the language
+//! also relabels history and results already on screen.
+
+use std::sync::RwLock;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Locale {
+    #[default]
+    ZhCn,
+    En,
+}
+
+static LOCALE: RwLock<Locale> = RwLock::new(Locale::ZhCn);
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::ZhCn, Locale::En];
+
+    /// Each language's name in that language, for the picker.
+    pub fn label(self) -> &'static str {
+        match self {
+            Locale::ZhCn => "中文",
+            Locale::En => "English",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Locale::ZhCn => Locale::En,
+            Locale::En => Locale::ZhCn,
+        }
+    }
+
+    /// `zh` or `en`, whichever is this language's.
+    pub fn pick(self, zh: &'static str, en: &'static str) -> &'static str {
+        match self {
+            Locale::ZhCn => zh,
+            Locale::En => en,
+        }
+    }
+
+    /// Chinese for `zh*` tags such as `zh_CN.UTF-8`, English for any other.
+    pub fn from_language_tag(tag: &str) -> Self {
+        if tag.to_ascii_lowercase().starts_with("zh") {
+            Locale::ZhCn
+        } else {
+            Locale::En
+        }
+    }
+
+    /// From `LC_ALL`, `LC_MESSAGES` or `LANG`, the first one set; Chinese
+    /// when none is, or when it is the bare `C`/`POSIX` locale.
+    pub fn from_system() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .filter(|value| !matches!(value.as_str(), "C" | "POSIX") && !value.starts_with("C."))
+            .map_or(Locale::ZhCn, |tag| Locale::from_language_tag(&tag))
+    }
+}
+
+/// The language labels are looked up in from now on.
+pub fn set_locale(locale: Locale) {
+    *LOCALE
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = locale;
+}
+
+pub fn locale() -> Locale {
+    *LOCALE
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// `zh` or `en`, whichever is the current language's.
+pub fn tr(zh: &'static str, en: &'static str) -> &'static str {
+    locale().pick(zh, en)
+}
+
+/// Thumb to little finger, in the order of `GestureDetail::finger_states`.
+pub fn finger_names() -> [&'static str; 5] {
+    match locale() {
+        Locale::ZhCn => ["拇指", "食指", "中指", "无名指", "小指"],
+        Locale::En => ["Thumb", "Index", "Middle", "Ring", "Little"],
+    }
+}
+
+/// What a toggle button shows.
+pub fn on_off(on: bool) -> &'static str {
+    if on {
+        tr("开", "On")
+    } else {
+        tr("关", "Off")
+    }
+}
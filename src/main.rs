@@ -1,23 +1,147 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-mod gesture;
-mod model_download;
-mod pipeline;
-mod types;
-mod ui;
-
 use anyhow::Result;
 use crossbeam_channel::bounded;
+#[cfg(feature = "metrics-http")]
+use gesture_universe::metrics_http;
+#[cfg(feature = "ws-output")]
+use gesture_universe::ws_output;
+use gesture_universe::{
+    config::{AppSettings, default_settings_path},
+    headless,
+    locale::{self, Locale},
+    model_download,
+    mqtt_output::MqttPublisher,
+    osc_output::OscSender,
+    pipeline::{self, MetricsHandle, RecognizerBackend, metrics::spawn_metrics_logger},
+    self_test, ui,
+};
 use gpui::Application;
-use gpui_component;
-use pipeline::RecognizerBackend;
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 fn main() -> Result<()> {
     env_logger::init();
 
-    let (camera_frame_tx, camera_frame_rx) = bounded(1);
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let mut settings = AppSettings::load_or_default(&default_settings_path());
+    model_download::set_model_mirror(settings.model_mirror.clone());
+    locale::set_locale(settings.locale.unwrap_or_else(Locale::from_system));
+    if args.iter().any(|arg| arg == "--mini") {
+        settings.mini_overlay.get_or_insert_default().open = true;
+    }
+    let mut recognizer_backend = settings
+        .apply_to_backend(RecognizerBackend::default())
+        .with_model_paths_from_env();
+    if let Some(path) = arg_value(&args, "--handpose-model") {
+        recognizer_backend = recognizer_backend.with_handpose_estimator_model_path(path.into());
+    }
+    if let Some(path) = arg_value(&args, "--palm-model") {
+        recognizer_backend = recognizer_backend.with_palm_detector_model_path(path.into());
+    }
+    // Before the custom-model check, which would stop at the first bad model
+    // instead of reporting on all of them.
+    if args.iter().any(|arg| arg == "--self-test") {
+        if !self_test::run(&recognizer_backend) {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+    recognizer_backend.check_custom_models()?;
+    let metrics = MetricsHandle::default();
+
+    // Kept alive for the whole run; dropping it stops the server.
+    #[cfg(feature = "ws-output")]
+    let _ws_server = {
+        let port = match args.iter().position(|arg| arg == "--ws-port") {
+            Some(idx) => args
+                .get(idx + 1)
+                .and_then(|value| value.parse::<u16>().ok())
+                .ok_or_else(|| anyhow::anyhow!("--ws-port expects a port number"))?,
+            None => ws_output::DEFAULT_WS_PORT,
+        };
+        let bind = match arg_value(&args, "--ws-bind") {
+            Some(value) => value
+                .parse()
+                .map_err(|_| anyhow::anyhow!("--ws-bind expects an IP address"))?,
+            None => ws_output::DEFAULT_WS_BIND,
+        };
+        let server = ws_output::WsServer::start(bind, port)?;
+        recognizer_backend = recognizer_backend.with_result_tap(server.result_sender());
+        server
+    };
+
+    // Scraped remotely on kiosks. Every mode below runs its pipeline until the
+    // process exits, so this lives exactly as long: it starts before the models
+    // load, so a scrape during a download reads zeros instead of being refused,
+    // and it is dropped when `main` returns, after the pipeline has shut down.
+    #[cfg(feature = "metrics-http")]
+    let _metrics_server = {
+        let port = match arg_value(&args, "--metrics-port") {
+            Some(value) => value
+                .parse::<u16>()
+                .map_err(|_| anyhow::anyhow!("--metrics-port expects a port number"))?,
+            None => metrics_http::DEFAULT_METRICS_PORT,
+        };
+        let bind = match arg_value(&args, "--metrics-bind") {
+            Some(value) => value
+                .parse()
+                .map_err(|_| anyhow::anyhow!("--metrics-bind expects an IP address"))?,
+            None => metrics_http::DEFAULT_METRICS_BIND,
+        };
+        metrics_http::MetricsServer::start(bind, port, metrics.clone())?
+    };
+
+    // Likewise kept alive for the whole run. A broker that is down is retried in
+    // the background; only a config this build cannot use stops here.
+    let mqtt = match &settings.mqtt {
+        Some(config) => {
+            let publisher = MqttPublisher::start(config)?;
+            recognizer_backend = recognizer_backend.with_event_tap(publisher.event_sender());
+            Some(publisher)
+        }
+        None => None,
+    };
+    let _osc = match &settings.osc {
+        Some(config) => {
+            let sender = OscSender::start(config)?;
+            recognizer_backend = recognizer_backend.with_result_tap(sender.result_sender());
+            Some(sender)
+        }
+        None => None,
+    };
 
-    let recognizer_backend = RecognizerBackend::default();
+    if args.iter().any(|arg| arg == "--metrics") {
+        spawn_metrics_logger(metrics.clone(), Duration::from_secs(2));
+    }
+
+    if let Some(dir) = arg_value(&args, "--image-dir") {
+        let interval = match arg_value(&args, "--image-interval-ms") {
+            Some(value) => value
+                .parse::<u64>()
+                .map(Duration::from_millis)
+                .map_err(|_| anyhow::anyhow!("--image-interval-ms expects milliseconds"))?,
+            None => pipeline::DEFAULT_IMAGE_INTERVAL,
+        };
+        let csv = arg_value(&args, "--csv").map(PathBuf::from);
+        return headless::run_images(
+            recognizer_backend,
+            metrics,
+            Path::new(dir),
+            interval,
+            csv.as_deref(),
+        );
+    }
+
+    if args.iter().any(|arg| arg == "--headless") {
+        return headless::run(recognizer_backend, metrics);
+    }
+
+    let (camera_frame_tx, camera_frame_rx) = bounded(1);
+    let mqtt_status = mqtt.as_ref().map(MqttPublisher::status);
 
     Application::new()
         .with_assets(gpui_component_assets::Assets)
@@ -29,6 +153,9 @@ fn main() -> Result<()> {
                 camera_frame_rx,
                 camera_frame_tx,
                 recognizer_backend.clone(),
+                metrics.clone(),
+                settings.clone(),
+                mqtt_status.clone(),
             ) {
                 eprintln!("failed to launch ui: {err:?}");
             }
@@ -36,3 +163,9 @@ fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// The argument following `flag`, if both are present.
+fn arg_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    let idx = args.iter().position(|arg| arg == flag)?;
+    args.get(idx + 1).map(String::as_str)
+}
@@ -0,0 +1,219 @@
+use std::{
+    fmt::Write as _,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Result, anyhow};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::{
+    pipeline::{
+        MetricsHandle,
+        metrics::{Channel, LATENCY_BUCKETS, MetricsTotals, Stage},
+    },
+    types::{GestureKind, custom_gestures},
+};
+
+pub const DEFAULT_METRICS_PORT: u16 = 9464;
+/// Loopback only, like the WebSocket output; a kiosk scraped from another
+/// machine passes its address explicitly.
+pub const DEFAULT_METRICS_BIND: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+const PREFIX: &str = "gesture_universe";
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Serves the pipeline totals at `/metrics` in the Prometheus text format
+/// until dropped. A scrape only copies the totals out under the metrics lock,
+/// so a slow scraper never holds up a stage.
+pub struct MetricsServer {
+    addr: SocketAddr,
+    stop: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl MetricsServer {
+    pub fn start(bind: IpAddr, port: u16, metrics: MetricsHandle) -> Result<Self> {
+        let server = Server::http((bind, port))
+            .map_err(|err| anyhow!("failed to listen on {bind} port {port}: {err}"))?;
+        let addr = server
+            .server_addr()
+            .to_ip()
+            .ok_or_else(|| anyhow!("metrics server is not on a TCP port"))?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let handle = {
+            let stop = stop.clone();
+            thread::spawn(move || serve(server, metrics, stop))
+        };
+
+        log::info!("prometheus metrics on http://{addr}/metrics");
+        Ok(Self {
+            addr,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+}
+
+impl Drop for MetricsServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn serve(server: Server, metrics: MetricsHandle, stop: Arc<AtomicBool>) {
+    while !stop.load(Ordering::Relaxed) {
+        match server.recv_timeout(POLL_INTERVAL) {
+            Ok(Some(request)) => respond(request, &metrics),
+            Ok(None) => {}
+            Err(err) => {
+                log::warn!("metrics request failed: {err:?}");
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+fn respond(request: Request, metrics: &MetricsHandle) {
+    let result = if *request.method() == Method::Get && request.url() == "/metrics" {
+        let content_type =
+            Header::from_bytes("Content-Type", CONTENT_TYPE).expect("content type header is valid");
+        let response = Response::from_string(render(&metrics.totals())).with_header(content_type);
+        request.respond(response)
+    } else {
+        request.respond(Response::empty(404))
+    };
+    if let Err(err) = result {
+        log::debug!("failed to answer metrics request: {err:?}");
+    }
+}
+
+/// The totals in the Prometheus text exposition format.
+pub fn render(totals: &MetricsTotals) -> String {
+    let mut out = String::new();
+    counter(
+        &mut out,
+        "frames_captured_total",
+        "Frames the camera handed over.",
+        &[(String::new(), totals.captured_frames)],
+    );
+    counter(
+        &mut out,
+        "frames_inferred_total",
+        "Frames the recognizer finished.",
+        &[(String::new(), totals.inferred_frames)],
+    );
+    let per_channel = |values: &[u64; 3]| -> Vec<(String, u64)> {
+        Channel::ALL
+            .into_iter()
+            .map(|channel| {
+                (
+                    format!("channel=\"{}\"", channel.name()),
+                    values[channel as usize],
+                )
+            })
+            .collect()
+    };
+    counter(
+        &mut out,
+        "frames_dropped_total",
+        "Frames a full channel refused.",
+        &per_channel(&totals.dropped),
+    );
+    counter(
+        &mut out,
+        "frames_skipped_total",
+        "Frames a consumer passed over for a newer one.",
+        &per_channel(&totals.skipped),
+    );
+    counter(
+        &mut out,
+        "recognizer_errors_total",
+        "Frames the recognizer failed on, wholly or in palm detection.",
+        &[(String::new(), totals.recognizer_errors)],
+    );
+    let custom = custom_gestures().into_iter().map(|gesture| gesture.kind());
+    let recognitions: Vec<(String, u64)> = GestureKind::ALL
+        .into_iter()
+        .chain(custom)
+        .map(|kind| {
+            let count = totals.recognitions.get(&kind).copied().unwrap_or(0);
+            (format!("gesture=\"{}\"", gesture_name(kind)), count)
+        })
+        .collect();
+    counter(
+        &mut out,
+        "gestures_recognized_total",
+        "Gestures reported as started.",
+        &recognitions,
+    );
+
+    let name = format!("{PREFIX}_last_confidence");
+    let _ = writeln!(
+        out,
+        "# HELP {name} Confidence of the newest recognized frame."
+    );
+    let _ = writeln!(out, "# TYPE {name} gauge");
+    if let Some(confidence) = totals.last_confidence {
+        let _ = writeln!(out, "{name} {confidence}");
+    }
+
+    let name = format!("{PREFIX}_stage_duration_seconds");
+    let _ = writeln!(out, "# HELP {name} Time spent in each pipeline stage.");
+    let _ = writeln!(out, "# TYPE {name} histogram");
+    for stage in Stage::ALL {
+        let histogram = &totals.latency[stage as usize];
+        let stage = stage.name();
+        for (bound, count) in LATENCY_BUCKETS.iter().zip(histogram.buckets) {
+            let _ = writeln!(
+                out,
+                "{name}_bucket{{stage=\"{stage}\",le=\"{bound}\"}} {count}"
+            );
+        }
+        let count = histogram.count;
+        let _ = writeln!(
+            out,
+            "{name}_bucket{{stage=\"{stage}\",le=\"+Inf\"}} {count}"
+        );
+        let _ = writeln!(
+            out,
+            "{name}_sum{{stage=\"{stage}\"}} {}",
+            histogram.sum_secs
+        );
+        let _ = writeln!(out, "{name}_count{{stage=\"{stage}\"}} {count}");
+    }
+    out
+}
+
+/// Writes one counter family; an empty label set writes the bare name.
+fn counter(out: &mut String, name: &str, help: &str, samples: &[(String, u64)]) {
+    let _ = writeln!(out, "# HELP {PREFIX}_{name} {help}");
+    let _ = writeln!(out, "# TYPE {PREFIX}_{name} counter");
+    for (labels, value) in samples {
+        if labels.is_empty() {
+            let _ = writeln!(out, "{PREFIX}_{name} {value}");
+        } else {
+            let _ = writeln!(out, "{PREFIX}_{name}{{{labels}}} {value}");
+        }
+    }
+}
+
+fn gesture_name(kind: GestureKind) -> String {
+    match serde_json::to_value(kind) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => format!("{kind:?}"),
+    }
+}
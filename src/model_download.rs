@@ -1,13 +1,20 @@
 use std::{
-    fs,
+    fs::{self, OpenOptions},
     io::{Read, Write},
     path::{Path, PathBuf},
+    sync::RwLock,
     time::Duration,
 };
 
-use anyhow::Context;
+use anyhow::{Context, anyhow};
 use indicatif::{ProgressBar, ProgressStyle};
-use reqwest::blocking::Client;
+use reqwest::{NoProxy, Proxy, StatusCode, Url, blocking::Client, header::RANGE};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    locale::{Locale, locale},
+    pipeline::RecognizerError,
+};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ModelKind {
@@ -16,6 +23,30 @@ pub enum ModelKind {
     GestureClassifier,
 }
 
+impl ModelKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            ModelKind::HandposeEstimator => "handpose estimator",
+            ModelKind::PalmDetector => "palm detector",
+            ModelKind::GestureClassifier => "gesture classifier",
+        }
+    }
+
+    /// For the download screen; [`ModelKind::label`] is what logs and errors say.
+    pub fn display_name(self) -> &'static str {
+        self.display_name_in(locale())
+    }
+
+    pub fn display_name_in(self, locale: Locale) -> &'static str {
+        let (zh, en) = match self {
+            ModelKind::HandposeEstimator => ("手部关键点", "Handpose estimator"),
+            ModelKind::PalmDetector => ("手掌检测", "Palm detector"),
+            ModelKind::GestureClassifier => ("手势分类", "Gesture classifier"),
+        };
+        locale.pick(zh, en)
+    }
+}
+
 const HANDPOSE_ESTIMATOR_MODEL_FILENAME: &str = "handpose_estimation.onnx";
 const HANDPOSE_ESTIMATOR_MODEL_URL: &str = "https://raw.githubusercontent.com/weidix/gesture-universe/refs/heads/main/models/handpose_estimation.onnx";
 const PALM_DETECTOR_MODEL_FILENAME: &str = "palm_detection.onnx";
@@ -35,6 +66,87 @@ pub fn default_gesture_classifier_model_path() -> PathBuf {
     PathBuf::from("models").join(GESTURE_CLASSIFIER_MODEL_FILENAME)
 }
 
+/// Names a handpose model to use instead of the default one; the
+/// `--handpose-model` flag wins over it, and it wins over the settings file.
+pub const HANDPOSE_MODEL_ENV: &str = "GU_HANDPOSE_MODEL";
+/// Likewise for the palm detector and `--palm-model`.
+pub const PALM_MODEL_ENV: &str = "GU_PALM_MODEL";
+
+pub fn handpose_estimator_model_path_from_env() -> Option<PathBuf> {
+    path_from_env(HANDPOSE_MODEL_ENV)
+}
+
+pub fn palm_detector_model_path_from_env() -> Option<PathBuf> {
+    path_from_env(PALM_MODEL_ENV)
+}
+
+/// Base URL tried before the published location, e.g. an internal mirror or
+/// a `file://` share holding the model files under their usual names. Wins
+/// over the `model_mirror` setting.
+pub const MODEL_MIRROR_ENV: &str = "GU_MODEL_MIRROR";
+
+static MODEL_MIRROR: RwLock<Option<String>> = RwLock::new(None);
+
+/// The mirror from the settings file, used while [`MODEL_MIRROR_ENV`] is
+/// unset.
+pub fn set_model_mirror(mirror: Option<String>) {
+    *MODEL_MIRROR
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = mirror;
+}
+
+pub fn model_mirror() -> Option<String> {
+    std::env::var(MODEL_MIRROR_ENV)
+        .ok()
+        .filter(|value| !value.is_empty())
+        .or_else(|| {
+            MODEL_MIRROR
+                .read()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .clone()
+        })
+}
+
+/// Every URL `model` is downloaded from, in the order they are tried: the
+/// file under `mirror`, if set, then the published one.
+pub fn model_urls(model: ModelKind, mirror: Option<&str>) -> Vec<String> {
+    let spec = model_spec(model);
+    let mut urls = Vec::new();
+    if let Some(mirror) = mirror
+        .map(|mirror| mirror.trim_end_matches('/'))
+        .filter(|mirror| !mirror.is_empty())
+    {
+        urls.push(format!("{mirror}/{}", spec.filename));
+    }
+    urls.push(spec.url.to_string());
+    urls
+}
+
+fn path_from_env(name: &str) -> Option<PathBuf> {
+    std::env::var_os(name)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Expected digests of the published models; a cached or downloaded file that
+/// does not match is discarded and fetched again.
+const HANDPOSE_ESTIMATOR_MODEL_SHA256: &str =
+    "10f7743a828792a7cebe46905c0bb12ec52ff9dd5f6f433577f670b3cb069199";
+const PALM_DETECTOR_MODEL_SHA256: &str =
+    "78ff51c38496b7fc8b8ebdb6cc8c1abb02fa6c38427c6848254cdaba57fcce7c";
+const GESTURE_CLASSIFIER_MODEL_SHA256: &str =
+    "6376ead4b5e0177115b23b43ba2d8785bef36974dd716c79fffcda504aa41c0f";
+
+/// The digest a model at its default path must have; one anywhere else is
+/// the user's own and is not checked.
+pub fn expected_sha256(model: ModelKind) -> &'static str {
+    match model {
+        ModelKind::HandposeEstimator => HANDPOSE_ESTIMATOR_MODEL_SHA256,
+        ModelKind::PalmDetector => PALM_DETECTOR_MODEL_SHA256,
+        ModelKind::GestureClassifier => GESTURE_CLASSIFIER_MODEL_SHA256,
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum ModelDownloadEvent {
     AlreadyPresent {
@@ -49,26 +161,154 @@ pub enum ModelDownloadEvent {
         downloaded: u64,
         total: Option<u64>,
     },
+    /// Checking the SHA-256 digest of a cached or freshly downloaded file.
+    Verifying {
+        model: ModelKind,
+    },
     Finished {
         model: ModelKind,
     },
+    Failed {
+        model: ModelKind,
+        error: String,
+    },
+}
+
+struct ModelSpec {
+    kind: ModelKind,
+    filename: &'static str,
+    url: &'static str,
+    sha256: &'static str,
+    default_path: fn() -> PathBuf,
+}
+
+impl ModelSpec {
+    fn label(&self) -> &'static str {
+        self.kind.label()
+    }
+}
+
+const HANDPOSE_ESTIMATOR_MODEL: ModelSpec = ModelSpec {
+    kind: ModelKind::HandposeEstimator,
+    filename: HANDPOSE_ESTIMATOR_MODEL_FILENAME,
+    url: HANDPOSE_ESTIMATOR_MODEL_URL,
+    sha256: HANDPOSE_ESTIMATOR_MODEL_SHA256,
+    default_path: default_handpose_estimator_model_path,
+};
+const PALM_DETECTOR_MODEL: ModelSpec = ModelSpec {
+    kind: ModelKind::PalmDetector,
+    filename: PALM_DETECTOR_MODEL_FILENAME,
+    url: PALM_DETECTOR_MODEL_URL,
+    sha256: PALM_DETECTOR_MODEL_SHA256,
+    default_path: default_palm_detector_model_path,
+};
+const GESTURE_CLASSIFIER_MODEL: ModelSpec = ModelSpec {
+    kind: ModelKind::GestureClassifier,
+    filename: GESTURE_CLASSIFIER_MODEL_FILENAME,
+    url: GESTURE_CLASSIFIER_MODEL_URL,
+    sha256: GESTURE_CLASSIFIER_MODEL_SHA256,
+    default_path: default_gesture_classifier_model_path,
+};
+
+fn model_spec(model: ModelKind) -> &'static ModelSpec {
+    match model {
+        ModelKind::HandposeEstimator => &HANDPOSE_ESTIMATOR_MODEL,
+        ModelKind::PalmDetector => &PALM_DETECTOR_MODEL,
+        ModelKind::GestureClassifier => &GESTURE_CLASSIFIER_MODEL,
+    }
 }
 
 pub fn ensure_handpose_estimator_model_ready<F>(
     model_path: &Path,
     mut on_event: F,
+) -> Result<(), RecognizerError>
+where
+    F: FnMut(ModelDownloadEvent),
+{
+    ensure_model_ready(&HANDPOSE_ESTIMATOR_MODEL, model_path, None, &mut on_event)
+}
+
+pub fn ensure_palm_detector_model_ready<F>(
+    model_path: &Path,
+    mut on_event: F,
+) -> Result<(), RecognizerError>
+where
+    F: FnMut(ModelDownloadEvent),
+{
+    let bundled = Path::new("palm_detection_mediapipe").join(PALM_DETECTOR_MODEL_FILENAME);
+    ensure_model_ready(
+        &PALM_DETECTOR_MODEL,
+        model_path,
+        Some(&bundled),
+        &mut on_event,
+    )
+}
+
+pub fn ensure_gesture_classifier_model_ready<F>(
+    model_path: &Path,
+    mut on_event: F,
+) -> Result<(), RecognizerError>
+where
+    F: FnMut(ModelDownloadEvent),
+{
+    ensure_model_ready(&GESTURE_CLASSIFIER_MODEL, model_path, None, &mut on_event)
+}
+
+fn ensure_model_ready<F>(
+    spec: &ModelSpec,
+    model_path: &Path,
+    bundled: Option<&Path>,
+    on_event: &mut F,
+) -> Result<(), RecognizerError>
+where
+    F: FnMut(ModelDownloadEvent),
+{
+    // Anything but the default path is the user's own model: it is used as is,
+    // never checked against the published digest or replaced by a download.
+    if model_path != (spec.default_path)() {
+        if !model_path.is_file() {
+            return Err(RecognizerError::ModelMissing(model_path.to_path_buf()));
+        }
+        on_event(ModelDownloadEvent::AlreadyPresent { model: spec.kind });
+        on_event(ModelDownloadEvent::Finished { model: spec.kind });
+        return Ok(());
+    }
+    prepare_model(spec, model_path, bundled, on_event).map_err(|source| {
+        on_event(ModelDownloadEvent::Failed {
+            model: spec.kind,
+            error: format!("{source:#}"),
+        });
+        RecognizerError::ModelDownloadFailed {
+            path: model_path.to_path_buf(),
+            source,
+        }
+    })
+}
+
+fn prepare_model<F>(
+    spec: &ModelSpec,
+    model_path: &Path,
+    bundled: Option<&Path>,
+    on_event: &mut F,
 ) -> anyhow::Result<()>
 where
     F: FnMut(ModelDownloadEvent),
 {
+    let model = spec.kind;
     if model_path.exists() {
-        on_event(ModelDownloadEvent::AlreadyPresent {
-            model: ModelKind::HandposeEstimator,
-        });
-        on_event(ModelDownloadEvent::Finished {
-            model: ModelKind::HandposeEstimator,
-        });
-        return Ok(());
+        on_event(ModelDownloadEvent::Verifying { model });
+        if verify_sha256(model_path, spec.sha256)? {
+            on_event(ModelDownloadEvent::AlreadyPresent { model });
+            on_event(ModelDownloadEvent::Finished { model });
+            return Ok(());
+        }
+        log::warn!(
+            "cached {} model at {} is corrupted, downloading it again",
+            spec.label(),
+            model_path.display()
+        );
+        fs::remove_file(model_path)
+            .with_context(|| format!("failed to remove corrupted {}", model_path.display()))?;
     }
 
     if let Some(parent) = model_path.parent() {
@@ -76,214 +316,378 @@ where
             .with_context(|| format!("failed to create model directory {}", parent.display()))?;
     }
 
+    if let Some(bundled) = bundled.filter(|path| path.exists()) {
+        if copy_bundled(spec, bundled, model_path, on_event)? {
+            return Ok(());
+        }
+        log::warn!(
+            "bundled {} model at {} does not match the expected digest, downloading instead",
+            spec.label(),
+            bundled.display()
+        );
+    }
+
+    let urls = model_urls(model, model_mirror().as_deref());
     let mut progress: Option<ProgressBar> = None;
-    download_to_path(
-        ModelKind::HandposeEstimator,
-        HANDPOSE_ESTIMATOR_MODEL_URL,
-        model_path,
-        &mut |event| {
-            match &event {
-                ModelDownloadEvent::Started { total, .. } => {
-                    progress = Some(create_progress_bar(*total));
-                }
-                ModelDownloadEvent::Progress { downloaded, .. } => {
-                    if let Some(pb) = progress.as_ref() {
-                        pb.set_position(*downloaded);
-                    }
+    download_model(model, &urls, spec.sha256, model_path, &mut |event| {
+        match &event {
+            ModelDownloadEvent::Started { total, .. } => {
+                progress = Some(create_progress_bar(*total));
+            }
+            ModelDownloadEvent::Progress { downloaded, .. } => {
+                if let Some(pb) = progress.as_ref() {
+                    pb.set_position(*downloaded);
                 }
-                ModelDownloadEvent::Finished { .. } => {
-                    if let Some(pb) = progress.take() {
-                        pb.finish_with_message("handpose model ready");
-                    }
+            }
+            ModelDownloadEvent::Finished { .. } => {
+                if let Some(pb) = progress.take() {
+                    pb.finish_with_message(format!("{} model ready", spec.label()));
                 }
-                ModelDownloadEvent::AlreadyPresent { .. } => {}
             }
-            on_event(event);
-        },
-    )
+            ModelDownloadEvent::AlreadyPresent { .. }
+            | ModelDownloadEvent::Verifying { .. }
+            | ModelDownloadEvent::Failed { .. } => {}
+        }
+        on_event(event);
+    })
+    .with_context(|| {
+        format!(
+            "failed to download {} model to {}",
+            spec.label(),
+            model_path.display()
+        )
+    })
 }
 
-fn download_to_path<F>(
+/// Returns false when the bundled copy fails verification.
+fn copy_bundled<F>(
+    spec: &ModelSpec,
+    bundled: &Path,
+    dest: &Path,
+    on_event: &mut F,
+) -> anyhow::Result<bool>
+where
+    F: FnMut(ModelDownloadEvent),
+{
+    let model = spec.kind;
+    on_event(ModelDownloadEvent::Started { model, total: None });
+    let part_path = part_path(dest);
+    fs::copy(bundled, &part_path).with_context(|| {
+        format!(
+            "failed to copy bundled {} model from {} to {}",
+            spec.label(),
+            bundled.display(),
+            part_path.display()
+        )
+    })?;
+
+    on_event(ModelDownloadEvent::Verifying { model });
+    if !verify_sha256(&part_path, spec.sha256)? {
+        let _ = fs::remove_file(&part_path);
+        return Ok(false);
+    }
+    move_into_place(&part_path, dest)?;
+    on_event(ModelDownloadEvent::Finished { model });
+    Ok(true)
+}
+
+/// Downloads `model` to `dest` from the first of `urls` that serves a file
+/// with the `sha256` digest. `file://` URLs are copied from disk, and
+/// `HTTPS_PROXY` and `HTTP_PROXY` are honoured for the others. When every URL
+/// fails, the error lists each one with its reason.
+pub fn download_model<F>(
     model: ModelKind,
-    url: &str,
+    urls: &[String],
+    sha256: &str,
     dest: &Path,
     on_event: &mut F,
 ) -> anyhow::Result<()>
 where
     F: FnMut(ModelDownloadEvent),
 {
-    let model_label = match model {
-        ModelKind::HandposeEstimator => "handpose estimator",
-        ModelKind::PalmDetector => "palm detector",
-        ModelKind::GestureClassifier => "gesture classifier",
-    };
-    log::info!(
-        "downloading {model_label} model from {url} to {}",
-        dest.display()
-    );
+    let client = http_client()?;
+    let mut failures = Vec::new();
+    for url in urls {
+        let source = ModelSource { model, url, sha256 };
+        match download_to_path(&client, &source, dest, on_event) {
+            Ok(()) => return Ok(()),
+            Err(err) => {
+                log::warn!(
+                    "downloading the {} model from {url} failed: {err:#}",
+                    model.label()
+                );
+                failures.push(format!("{url} ({err:#})"));
+            }
+        }
+    }
+    Err(anyhow!("tried {}", failures.join(", ")))
+}
 
-    let client = Client::new();
-    let mut response = client
-        .get(url)
-        .send()
-        .context("failed to start model download")?
-        .error_for_status()
-        .context("model download returned error status")?;
+/// One URL a model is downloaded from.
+struct ModelSource<'a> {
+    model: ModelKind,
+    url: &'a str,
+    sha256: &'a str,
+}
 
-    let total_size = response.content_length();
-    on_event(ModelDownloadEvent::Started {
-        model,
-        total: total_size,
-    });
+/// Proxies from `HTTPS_PROXY` and `HTTP_PROXY`, upper or lower case, for the
+/// matching scheme, leaving out the hosts in `NO_PROXY`.
+fn http_client() -> anyhow::Result<Client> {
+    let mut builder = Client::builder();
+    if let Some(url) = proxy_from_env("HTTPS_PROXY") {
+        let proxy = Proxy::https(url.as_str()).context("HTTPS_PROXY is not a valid proxy URL")?;
+        builder = builder.proxy(proxy.no_proxy(NoProxy::from_env()));
+    }
+    if let Some(url) = proxy_from_env("HTTP_PROXY") {
+        let proxy = Proxy::http(url.as_str()).context("HTTP_PROXY is not a valid proxy URL")?;
+        builder = builder.proxy(proxy.no_proxy(NoProxy::from_env()));
+    }
+    builder
+        .build()
+        .context("failed to set up the model download client")
+}
 
-    let tmp_path = dest.with_extension("download");
-    let mut file = fs::File::create(&tmp_path)
-        .with_context(|| format!("failed to create {}", tmp_path.display()))?;
+fn proxy_from_env(name: &str) -> Option<String> {
+    let url = std::env::var(name)
+        .or_else(|_| std::env::var(name.to_ascii_lowercase()))
+        .ok()
+        .filter(|url| !url.is_empty())?;
+    log::info!("downloading models through the proxy in {name}");
+    Some(url)
+}
 
-    let mut downloaded: u64 = 0;
-    let mut buffer = [0u8; 16 * 1024];
-    loop {
-        let bytes_read = response
-            .read(&mut buffer)
-            .context("failed while reading model bytes")?;
-        if bytes_read == 0 {
-            break;
+/// Downloads into `<dest>.part`, resuming an earlier partial file with an HTTP
+/// range request, and only renames it over `dest` once the digest matches.
+fn download_to_path<F>(
+    client: &Client,
+    source: &ModelSource<'_>,
+    dest: &Path,
+    on_event: &mut F,
+) -> anyhow::Result<()>
+where
+    F: FnMut(ModelDownloadEvent),
+{
+    let part_path = part_path(dest);
+    let resumed = part_path.exists();
+    match download_part(client, source, &part_path, on_event) {
+        Ok(()) => {}
+        // A stale partial file from another model revision can make the server
+        // reject the range; start over once before giving up.
+        Err(err) if resumed => {
+            log::warn!(
+                "resuming {} failed ({err:#}), restarting",
+                part_path.display()
+            );
+            let _ = fs::remove_file(&part_path);
+            download_part(client, source, &part_path, on_event)?;
         }
+        Err(err) => return Err(err),
+    }
+    move_into_place(&part_path, dest)?;
+    on_event(ModelDownloadEvent::Finished {
+        model: source.model,
+    });
+    Ok(())
+}
 
-        file.write_all(&buffer[..bytes_read])
-            .context("failed while writing model to disk")?;
-        downloaded += bytes_read as u64;
-        on_event(ModelDownloadEvent::Progress {
-            model,
-            downloaded,
-            total: total_size,
-        });
+fn download_part<F>(
+    client: &Client,
+    source: &ModelSource<'_>,
+    part_path: &Path,
+    on_event: &mut F,
+) -> anyhow::Result<()>
+where
+    F: FnMut(ModelDownloadEvent),
+{
+    let model = source.model;
+    if source.url.starts_with("file://") {
+        copy_file_url(source, part_path, on_event)?;
+    } else {
+        fetch_part(client, source, part_path, on_event)?;
     }
 
-    file.sync_all()
-        .context("failed to flush downloaded model to disk")?;
-    fs::rename(&tmp_path, dest).with_context(|| {
+    on_event(ModelDownloadEvent::Verifying { model });
+    if !verify_sha256(part_path, source.sha256)? {
+        let _ = fs::remove_file(part_path);
+        return Err(anyhow!(
+            "downloaded {} model does not match the expected SHA-256",
+            model.label()
+        ));
+    }
+    Ok(())
+}
+
+/// Copies a `file://` URL, e.g. on a mounted share, as a whole.
+fn copy_file_url<F>(
+    source: &ModelSource<'_>,
+    part_path: &Path,
+    on_event: &mut F,
+) -> anyhow::Result<()>
+where
+    F: FnMut(ModelDownloadEvent),
+{
+    let path = Url::parse(source.url)
+        .ok()
+        .and_then(|url| url.to_file_path().ok())
+        .ok_or_else(|| anyhow!("not a local file URL"))?;
+    log::info!(
+        "copying {} model from {} to {}",
+        source.model.label(),
+        path.display(),
+        part_path.display()
+    );
+    let total = fs::metadata(&path)
+        .with_context(|| format!("failed to read {}", path.display()))?
+        .len();
+    on_event(ModelDownloadEvent::Started {
+        model: source.model,
+        total: Some(total),
+    });
+    fs::copy(&path, part_path).with_context(|| {
         format!(
-            "failed to move temp model {} into place at {}",
-            tmp_path.display(),
-            dest.display()
+            "failed to copy {} to {}",
+            path.display(),
+            part_path.display()
         )
     })?;
-
-    on_event(ModelDownloadEvent::Finished { model });
+    on_event(ModelDownloadEvent::Progress {
+        model: source.model,
+        downloaded: total,
+        total: Some(total),
+    });
     Ok(())
 }
 
-pub fn ensure_palm_detector_model_ready<F>(model_path: &Path, mut on_event: F) -> anyhow::Result<()>
+fn fetch_part<F>(
+    client: &Client,
+    source: &ModelSource<'_>,
+    part_path: &Path,
+    on_event: &mut F,
+) -> anyhow::Result<()>
 where
     F: FnMut(ModelDownloadEvent),
 {
-    if model_path.exists() {
-        on_event(ModelDownloadEvent::AlreadyPresent {
-            model: ModelKind::PalmDetector,
-        });
-        on_event(ModelDownloadEvent::Finished {
-            model: ModelKind::PalmDetector,
-        });
-        return Ok(());
+    let model = source.model;
+    let offset = fs::metadata(part_path).map(|meta| meta.len()).unwrap_or(0);
+    log::info!(
+        "downloading {} model from {} to {}{}",
+        model.label(),
+        source.url,
+        part_path.display(),
+        if offset > 0 {
+            format!(" (resuming at {offset} bytes)")
+        } else {
+            String::new()
+        }
+    );
+
+    let mut request = client.get(source.url);
+    if offset > 0 {
+        request = request.header(RANGE, format!("bytes={offset}-"));
     }
-    if let Some(parent) = model_path.parent() {
-        fs::create_dir_all(parent).with_context(|| {
-            format!(
-                "failed to create palm detector model directory {}",
-                parent.display()
+    let mut response = request.send().context("failed to start model download")?;
+
+    let mut file = match response.status() {
+        // The partial file already holds every byte; only verification is left.
+        StatusCode::RANGE_NOT_SATISFIABLE if offset > 0 => None,
+        StatusCode::PARTIAL_CONTENT if offset > 0 => Some(
+            OpenOptions::new()
+                .append(true)
+                .open(part_path)
+                .with_context(|| format!("failed to open {}", part_path.display()))?,
+        ),
+        _ => {
+            response = response
+                .error_for_status()
+                .context("model download returned error status")?;
+            Some(
+                fs::File::create(part_path)
+                    .with_context(|| format!("failed to create {}", part_path.display()))?,
             )
-        })?;
-    }
+        }
+    };
 
-    let bundled = Path::new("palm_detection_mediapipe").join(PALM_DETECTOR_MODEL_FILENAME);
-    if bundled.exists() {
+    if let Some(file) = file.as_mut() {
+        let mut downloaded = file.metadata().map(|meta| meta.len()).unwrap_or(0);
+        let total_size = response.content_length().map(|len| len + downloaded);
         on_event(ModelDownloadEvent::Started {
-            model: ModelKind::PalmDetector,
-            total: None,
-        });
-        fs::copy(&bundled, model_path).with_context(|| {
-            format!(
-                "failed to copy bundled palm detector model from {} to {}",
-                bundled.display(),
-                model_path.display()
-            )
-        })?;
-        on_event(ModelDownloadEvent::Finished {
-            model: ModelKind::PalmDetector,
+            model,
+            total: total_size,
         });
-        return Ok(());
+
+        let mut buffer = [0u8; 16 * 1024];
+        loop {
+            let bytes_read = response
+                .read(&mut buffer)
+                .context("failed while reading model bytes")?;
+            if bytes_read == 0 {
+                break;
+            }
+
+            file.write_all(&buffer[..bytes_read])
+                .context("failed while writing model to disk")?;
+            downloaded += bytes_read as u64;
+            on_event(ModelDownloadEvent::Progress {
+                model,
+                downloaded,
+                total: total_size,
+            });
+        }
+
+        file.sync_all()
+            .context("failed to flush downloaded model to disk")?;
     }
+    Ok(())
+}
 
-    log::info!(
-        "bundled palm detector not found, downloading from {}",
-        PALM_DETECTOR_MODEL_URL
-    );
-    download_to_path(
-        ModelKind::PalmDetector,
-        PALM_DETECTOR_MODEL_URL,
-        model_path,
-        &mut on_event,
-    )
-    .with_context(|| {
+fn part_path(dest: &Path) -> PathBuf {
+    let mut name = dest.file_name().unwrap_or_default().to_os_string();
+    name.push(".part");
+    dest.with_file_name(name)
+}
+
+fn move_into_place(part_path: &Path, dest: &Path) -> anyhow::Result<()> {
+    fs::rename(part_path, dest).with_context(|| {
         format!(
-            "failed to download palm detector model to {}",
-            model_path.display()
+            "failed to move temp model {} into place at {}",
+            part_path.display(),
+            dest.display()
         )
     })
 }
 
-pub fn ensure_gesture_classifier_model_ready<F>(
-    model_path: &Path,
-    mut on_event: F,
-) -> anyhow::Result<()>
-where
-    F: FnMut(ModelDownloadEvent),
-{
-    if model_path.exists() {
-        on_event(ModelDownloadEvent::AlreadyPresent {
-            model: ModelKind::GestureClassifier,
-        });
-        on_event(ModelDownloadEvent::Finished {
-            model: ModelKind::GestureClassifier,
-        });
-        return Ok(());
+/// SHA-256 of the file at `path` as lowercase hex.
+pub fn file_sha256(path: &Path) -> anyhow::Result<String> {
+    let mut file =
+        fs::File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let bytes_read = file
+            .read(&mut buffer)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        if bytes_read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..bytes_read]);
     }
 
-    if let Some(parent) = model_path.parent() {
-        fs::create_dir_all(parent).with_context(|| {
-            format!(
-                "failed to create gesture classifier model directory {}",
-                parent.display()
-            )
-        })?;
-    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
 
-    let mut progress: Option<ProgressBar> = None;
-    download_to_path(
-        ModelKind::GestureClassifier,
-        GESTURE_CLASSIFIER_MODEL_URL,
-        model_path,
-        &mut |event| {
-            match &event {
-                ModelDownloadEvent::Started { total, .. } => {
-                    progress = Some(create_progress_bar(*total));
-                }
-                ModelDownloadEvent::Progress { downloaded, .. } => {
-                    if let Some(pb) = progress.as_ref() {
-                        pb.set_position(*downloaded);
-                    }
-                }
-                ModelDownloadEvent::Finished { .. } => {
-                    if let Some(pb) = progress.take() {
-                        pb.finish_with_message("gesture classifier model ready");
-                    }
-                }
-                ModelDownloadEvent::AlreadyPresent { .. } => {}
-            }
-            on_event(event);
-        },
-    )
+fn verify_sha256(path: &Path, expected: &str) -> anyhow::Result<bool> {
+    let digest = file_sha256(path)?;
+    if digest != expected {
+        log::warn!(
+            "{} has SHA-256 {digest}, expected {expected}",
+            path.display()
+        );
+    }
+    Ok(digest == expected)
 }
 
 fn create_progress_bar(total_size: Option<u64>) -> ProgressBar {
@@ -0,0 +1,566 @@
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, anyhow, bail};
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender, bounded};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{GestureEvent, GestureKind};
+
+pub const DEFAULT_MQTT_TOPIC_PREFIX: &str = "gesture-universe";
+const DEFAULT_MQTT_PORT: u16 = 1883;
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Messages kept while the broker is unreachable; the oldest go first.
+const PENDING_LIMIT: usize = 64;
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Broker settings, saved as the `mqtt` entry of the settings file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MqttConfig {
+    /// `mqtt://host:port`; the port defaults to 1883.
+    pub broker_url: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// Messages go to `<prefix>/state` and `<prefix>/events`.
+    pub topic_prefix: String,
+    /// 0, 1 or 2.
+    pub qos: u8,
+    pub client_id: String,
+}
+
+impl Default for MqttConfig {
+    fn default() -> Self {
+        Self {
+            broker_url: format!("mqtt://localhost:{DEFAULT_MQTT_PORT}"),
+            username: None,
+            password: None,
+            topic_prefix: DEFAULT_MQTT_TOPIC_PREFIX.to_string(),
+            qos: 0,
+            client_id: DEFAULT_MQTT_TOPIC_PREFIX.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum MqttStatus {
+    #[default]
+    Connecting,
+    Connected,
+    /// Why the last attempt failed; another follows after a backoff.
+    Disconnected(String),
+}
+
+/// Connection state as the UI reads it.
+#[derive(Clone, Default)]
+pub struct MqttStatusHandle(Arc<Mutex<MqttStatus>>);
+
+impl MqttStatusHandle {
+    pub fn get(&self) -> MqttStatus {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    fn set(&self, status: MqttStatus) {
+        *self
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = status;
+    }
+
+    fn is_connected(&self) -> bool {
+        self.get() == MqttStatus::Connected
+    }
+}
+
+/// Retained on `<prefix>/state`: the gesture being held, if any.
+#[derive(Serialize)]
+struct MqttStateMessage {
+    gesture: Option<GestureKind>,
+}
+
+/// Sent on `<prefix>/events`, one per gesture event. Holds are reported once
+/// per full second rather than every frame, and drag moves not at all.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum MqttEventMessage<'a> {
+    Started {
+        gesture: GestureKind,
+    },
+    Held {
+        gesture: GestureKind,
+        duration_ms: u64,
+    },
+    Ended {
+        gesture: GestureKind,
+    },
+    DwellCompleted {
+        gesture: GestureKind,
+    },
+    SequenceMatched {
+        name: &'a str,
+    },
+    DragStarted {
+        pos: (f32, f32),
+    },
+    DragEnded {
+        pos: (f32, f32),
+        cancelled: bool,
+    },
+}
+
+/// One message to publish; only read by the rumqttc backend.
+#[cfg_attr(not(feature = "mqtt-output"), allow(dead_code))]
+struct Outgoing {
+    topic: String,
+    payload: String,
+    retain: bool,
+}
+
+/// Publishes gesture events to an MQTT broker until dropped. It connects in
+/// the background and reconnects with a growing backoff; the recognizer only
+/// try_sends into it, so a slow or absent broker never holds up recognition.
+pub struct MqttPublisher {
+    event_tx: Sender<GestureEvent>,
+    status: MqttStatusHandle,
+    stop: Arc<AtomicBool>,
+    handles: Vec<thread::JoinHandle<()>>,
+}
+
+impl MqttPublisher {
+    /// Fails right away on a malformed config or a build without MQTT;
+    /// broker trouble shows up in [`MqttPublisher::status`] instead.
+    pub fn start(config: &MqttConfig) -> Result<Self> {
+        let (host, port) = parse_broker_url(&config.broker_url)?;
+        if config.qos > 2 {
+            bail!("MQTT QoS must be 0, 1 or 2, not {}", config.qos);
+        }
+        let (client, connection) = broker::connect(config, &host, port)?;
+
+        let (event_tx, event_rx) = bounded(PENDING_LIMIT);
+        let status = MqttStatusHandle::default();
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let connection_handle = {
+            let status = status.clone();
+            let stop = stop.clone();
+            thread::spawn(move || connection_loop(connection, status, stop))
+        };
+        let publish_handle = {
+            let prefix = config.topic_prefix.clone();
+            let status = status.clone();
+            let stop = stop.clone();
+            thread::spawn(move || publish_loop(client, event_rx, &prefix, status, stop))
+        };
+
+        log::info!("mqtt output publishing to {host}:{port}");
+        Ok(Self {
+            event_tx,
+            status,
+            stop,
+            handles: vec![connection_handle, publish_handle],
+        })
+    }
+
+    /// Sender to hand to the recognizer as its event tap.
+    pub fn event_sender(&self) -> Sender<GestureEvent> {
+        self.event_tx.clone()
+    }
+
+    pub fn status(&self) -> MqttStatusHandle {
+        self.status.clone()
+    }
+}
+
+impl Drop for MqttPublisher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn parse_broker_url(url: &str) -> Result<(String, u16)> {
+    let address = url
+        .strip_prefix("mqtt://")
+        .or_else(|| url.strip_prefix("tcp://"))
+        .ok_or_else(|| anyhow!("MQTT broker URL {url:?} must start with mqtt://"))?
+        .trim_end_matches('/');
+    match address.rsplit_once(':') {
+        Some((host, port)) => {
+            let port = port
+                .parse()
+                .with_context(|| format!("bad port in MQTT broker URL {url:?}"))?;
+            Ok((host.to_string(), port))
+        }
+        None => Ok((address.to_string(), DEFAULT_MQTT_PORT)),
+    }
+}
+
+fn connection_loop(
+    mut connection: broker::Connection,
+    status: MqttStatusHandle,
+    stop: Arc<AtomicBool>,
+) {
+    let mut backoff = INITIAL_BACKOFF;
+    while !stop.load(Ordering::Relaxed) {
+        match connection.poll(POLL_INTERVAL) {
+            Ok(true) => {
+                log::info!("mqtt output connected");
+                status.set(MqttStatus::Connected);
+                backoff = INITIAL_BACKOFF;
+            }
+            Ok(false) => {}
+            Err(err) => {
+                let message = format!("{err:#}");
+                log::warn!(
+                    "mqtt connection lost, retrying in {}s: {message}",
+                    backoff.as_secs()
+                );
+                status.set(MqttStatus::Disconnected(message));
+                let retry_at = Instant::now() + backoff;
+                while Instant::now() < retry_at && !stop.load(Ordering::Relaxed) {
+                    thread::sleep(POLL_INTERVAL);
+                }
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+        }
+    }
+}
+
+fn publish_loop(
+    mut client: broker::Client,
+    event_rx: Receiver<GestureEvent>,
+    prefix: &str,
+    status: MqttStatusHandle,
+    stop: Arc<AtomicBool>,
+) {
+    let mut encoder = EventEncoder::new(prefix);
+    let mut pending: VecDeque<Outgoing> = VecDeque::with_capacity(PENDING_LIMIT);
+    let mut dropped: u64 = 0;
+    while !stop.load(Ordering::Relaxed) {
+        match event_rx.recv_timeout(POLL_INTERVAL) {
+            Ok(event) => {
+                for outgoing in encoder.encode(&event) {
+                    if enqueue(&mut pending, outgoing) {
+                        dropped += 1;
+                        log::debug!("mqtt queue full, {dropped} message(s) dropped so far");
+                    }
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+
+        while status.is_connected() {
+            let Some(outgoing) = pending.front() else {
+                break;
+            };
+            if let Err(err) = client.try_publish(outgoing) {
+                log::debug!("mqtt publish deferred: {err:#}");
+                break;
+            }
+            pending.pop_front();
+        }
+    }
+    client.disconnect();
+}
+
+/// Queues a message behind the others, dropping the oldest once
+/// [`PENDING_LIMIT`] are waiting. Returns whether one was dropped.
+fn enqueue(pending: &mut VecDeque<Outgoing>, outgoing: Outgoing) -> bool {
+    let full = pending.len() >= PENDING_LIMIT;
+    if full {
+        pending.pop_front();
+    }
+    pending.push_back(outgoing);
+    full
+}
+
+/// Turns events into messages. Holds come every frame, so only the first one
+/// past each full second is published.
+struct EventEncoder {
+    prefix: String,
+    held_seconds: u64,
+}
+
+impl EventEncoder {
+    fn new(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.trim_end_matches('/').to_string(),
+            held_seconds: 0,
+        }
+    }
+
+    /// The messages for one event, events topic first.
+    fn encode(&mut self, event: &GestureEvent) -> Vec<Outgoing> {
+        let (message, state) = match event {
+            GestureEvent::Started(kind) => {
+                self.held_seconds = 0;
+                let state = MqttStateMessage {
+                    gesture: Some(*kind),
+                };
+                (MqttEventMessage::Started { gesture: *kind }, Some(state))
+            }
+            GestureEvent::Held { kind, duration } => {
+                if duration.as_secs() <= self.held_seconds {
+                    return Vec::new();
+                }
+                self.held_seconds = duration.as_secs();
+                let message = MqttEventMessage::Held {
+                    gesture: *kind,
+                    duration_ms: self.held_seconds * 1000,
+                };
+                (message, None)
+            }
+            GestureEvent::Ended(kind) => (
+                MqttEventMessage::Ended { gesture: *kind },
+                Some(MqttStateMessage { gesture: None }),
+            ),
+            GestureEvent::DwellCompleted(kind) => {
+                (MqttEventMessage::DwellCompleted { gesture: *kind }, None)
+            }
+            GestureEvent::SequenceMatched(name) => {
+                (MqttEventMessage::SequenceMatched { name }, None)
+            }
+            GestureEvent::DragStarted { pos } => {
+                (MqttEventMessage::DragStarted { pos: *pos }, None)
+            }
+            GestureEvent::Motion { .. } | GestureEvent::DragMoved { .. } => return Vec::new(),
+            GestureEvent::DragEnded { pos, cancelled } => {
+                let message = MqttEventMessage::DragEnded {
+                    pos: *pos,
+                    cancelled: *cancelled,
+                };
+                (message, None)
+            }
+        };
+
+        let mut outgoing = Vec::with_capacity(2);
+        let mut push =
+            |topic: &str, payload: serde_json::Result<String>, retain: bool| match payload {
+                Ok(payload) => outgoing.push(Outgoing {
+                    topic: format!("{}/{topic}", self.prefix),
+                    payload,
+                    retain,
+                }),
+                Err(err) => log::warn!("failed to encode mqtt message: {err:?}"),
+            };
+        push("events", serde_json::to_string(&message), false);
+        if let Some(state) = state {
+            push("state", serde_json::to_string(&state), true);
+        }
+        outgoing
+    }
+}
+
+#[cfg(feature = "mqtt-output")]
+mod broker {
+    use std::time::Duration;
+
+    use anyhow::{Result, anyhow};
+    use rumqttc::{Event, MqttOptions, Packet, QoS, RecvTimeoutError};
+
+    use super::{MqttConfig, Outgoing};
+
+    /// Requests rumqttc buffers between the client and its event loop.
+    const REQUEST_CAPACITY: usize = 16;
+
+    pub struct Client {
+        client: rumqttc::Client,
+        qos: QoS,
+    }
+
+    pub struct Connection(rumqttc::Connection);
+
+    pub fn connect(config: &MqttConfig, host: &str, port: u16) -> Result<(Client, Connection)> {
+        let mut options = MqttOptions::new(&config.client_id, host, port);
+        options.set_keep_alive(Duration::from_secs(30));
+        if let Some(username) = &config.username {
+            options.set_credentials(username, config.password.as_deref().unwrap_or_default());
+        }
+        let qos = match config.qos {
+            0 => QoS::AtMostOnce,
+            1 => QoS::AtLeastOnce,
+            _ => QoS::ExactlyOnce,
+        };
+        let (client, connection) = rumqttc::Client::new(options, REQUEST_CAPACITY);
+        Ok((Client { client, qos }, Connection(connection)))
+    }
+
+    impl Client {
+        pub fn try_publish(&mut self, outgoing: &Outgoing) -> Result<()> {
+            self.client
+                .try_publish(
+                    &outgoing.topic,
+                    self.qos,
+                    outgoing.retain,
+                    outgoing.payload.as_bytes(),
+                )
+                .map_err(|err| anyhow!("{err}"))
+        }
+
+        pub fn disconnect(&mut self) {
+            let _ = self.client.try_disconnect();
+        }
+    }
+
+    impl Connection {
+        /// Drives the connection for up to `timeout`; `true` once the broker
+        /// accepted a (re)connect. rumqttc reconnects on the next poll after
+        /// an error.
+        pub fn poll(&mut self, timeout: Duration) -> Result<bool> {
+            match self.0.recv_timeout(timeout) {
+                Ok(Ok(Event::Incoming(Packet::ConnAck(_)))) => Ok(true),
+                Ok(Ok(_)) | Err(RecvTimeoutError::Timeout) => Ok(false),
+                Ok(Err(err)) => Err(anyhow!("{err}")),
+                Err(RecvTimeoutError::Disconnected) => Err(anyhow!("mqtt client closed")),
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "mqtt-output"))]
+mod broker {
+    use std::time::Duration;
+
+    use anyhow::{Result, bail};
+
+    use super::{MqttConfig, Outgoing};
+
+    /// Stand-ins on builds without MQTT; never constructed.
+    pub enum Client {}
+    pub enum Connection {}
+
+    pub fn connect(_config: &MqttConfig, _host: &str, _port: u16) -> Result<(Client, Connection)> {
+        bail!("this build has no MQTT output; rebuild with --features mqtt-output");
+    }
+
+    impl Client {
+        pub fn try_publish(&mut self, _outgoing: &Outgoing) -> Result<()> {
+            match *self {}
+        }
+
+        pub fn disconnect(&mut self) {
+            match *self {}
+        }
+    }
+
+    impl Connection {
+        pub fn poll(&mut self, _timeout: Duration) -> Result<bool> {
+            match *self {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encoded(encoder: &mut EventEncoder, event: GestureEvent) -> Vec<(String, String, bool)> {
+        encoder
+            .encode(&event)
+            .into_iter()
+            .map(|outgoing| (outgoing.topic, outgoing.payload, outgoing.retain))
+            .collect()
+    }
+
+    #[test]
+    fn a_held_gesture_publishes_its_state_and_events() {
+        let mut encoder = EventEncoder::new("home/gestures/");
+        let held = |millis| GestureEvent::Held {
+            kind: GestureKind::Palm,
+            duration: Duration::from_millis(millis),
+        };
+
+        assert_eq!(
+            encoded(&mut encoder, GestureEvent::Started(GestureKind::Palm)),
+            [
+                (
+                    "home/gestures/events".into(),
+                    r#"{"type":"started","gesture":"palm"}"#.into(),
+                    false
+                ),
+                (
+                    "home/gestures/state".into(),
+                    r#"{"gesture":"palm"}"#.into(),
+                    true
+                ),
+            ]
+        );
+        // Only the first hold past each full second is sent.
+        assert!(encoded(&mut encoder, held(500)).is_empty());
+        let first = encoded(&mut encoder, held(1_030));
+        assert_eq!(
+            first[0].1,
+            r#"{"type":"held","gesture":"palm","duration_ms":1000}"#
+        );
+        assert!(encoded(&mut encoder, held(1_500)).is_empty());
+        assert_eq!(encoded(&mut encoder, held(2_010)).len(), 1);
+
+        let ended = encoded(&mut encoder, GestureEvent::Ended(GestureKind::Palm));
+        assert_eq!(ended[0].1, r#"{"type":"ended","gesture":"palm"}"#);
+        assert_eq!(
+            ended[1],
+            (
+                "home/gestures/state".into(),
+                r#"{"gesture":null}"#.into(),
+                true
+            )
+        );
+
+        // A new gesture counts its holds from zero again.
+        encoded(&mut encoder, GestureEvent::Started(GestureKind::Fist));
+        assert_eq!(encoded(&mut encoder, held(1_100)).len(), 1);
+    }
+
+    #[test]
+    fn drag_moves_are_not_published() {
+        let mut encoder = EventEncoder::new(DEFAULT_MQTT_TOPIC_PREFIX);
+        let moved = GestureEvent::DragMoved {
+            pos: (1.0, 2.0),
+            delta: (0.5, 0.5),
+        };
+        assert!(encoded(&mut encoder, moved).is_empty());
+        let ended = GestureEvent::DragEnded {
+            pos: (1.0, 2.0),
+            cancelled: true,
+        };
+        assert_eq!(
+            encoded(&mut encoder, ended)[0].1,
+            r#"{"type":"drag_ended","pos":[1.0,2.0],"cancelled":true}"#
+        );
+    }
+
+    #[test]
+    fn a_full_queue_drops_the_oldest_message() {
+        let message = |n: usize| Outgoing {
+            topic: "gesture-universe/events".into(),
+            payload: n.to_string(),
+            retain: false,
+        };
+        let mut pending = VecDeque::new();
+        let dropped = (0..PENDING_LIMIT + 3)
+            .filter(|&n| enqueue(&mut pending, message(n)))
+            .count();
+
+        assert_eq!(dropped, 3);
+        assert_eq!(pending.len(), PENDING_LIMIT);
+        assert_eq!(pending.front().unwrap().payload, "3");
+        assert_eq!(
+            pending.back().unwrap().payload,
+            (PENDING_LIMIT + 2).to_string()
+        );
+    }
+}
@@ -0,0 +1,409 @@
+use std::{
+    net::{ToSocketAddrs, UdpSocket},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Context, Result, anyhow};
+use crossbeam_channel::{Receiver, RecvTimeoutError};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    pipeline::{Delivery, DeliveryMode, recognizer::common::INPUT_SIZE},
+    types::{GestureKind, GestureResult},
+};
+
+/// TouchDesigner's OSC In default.
+const DEFAULT_OSC_PORT: u16 = 9000;
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Results waiting to be sent; past that the oldest are dropped.
+const RESULT_QUEUE: usize = 64;
+
+/// Receiver settings, saved as the `osc` entry of the settings file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OscConfig {
+    pub host: String,
+    pub port: u16,
+    /// Put in front of every address, e.g. `/gu` for `/gu/gesture/kind`.
+    pub address_prefix: String,
+    /// Also sends the 21 landmarks of the primary hand, about 1 KB a frame.
+    pub landmarks: bool,
+}
+
+impl Default for OscConfig {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: DEFAULT_OSC_PORT,
+            address_prefix: String::new(),
+            landmarks: false,
+        }
+    }
+}
+
+/// One OSC message before encoding; only read by the rosc backend.
+#[cfg_attr(not(feature = "osc-output"), allow(dead_code))]
+struct Message {
+    address: String,
+    args: Vec<Arg>,
+}
+
+#[cfg_attr(not(feature = "osc-output"), allow(dead_code))]
+enum Arg {
+    Float(f32),
+    Str(String),
+}
+
+/// Sends every recognized frame as one OSC bundle over UDP until dropped. The
+/// recognizer only try_sends into it and the socket never blocks, so a
+/// receiver that is gone costs nothing but the lost packets.
+pub struct OscSender {
+    result_tx: Delivery<GestureResult>,
+    stop: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl OscSender {
+    /// Fails right away when the host does not resolve or the build has no
+    /// OSC support.
+    pub fn start(config: &OscConfig) -> Result<Self> {
+        let encoder = wire::Encoder::new()?;
+        let target = (config.host.as_str(), config.port)
+            .to_socket_addrs()
+            .with_context(|| format!("failed to resolve OSC host {:?}", config.host))?
+            .next()
+            .ok_or_else(|| anyhow!("OSC host {:?} has no address", config.host))?;
+        let local = if target.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        };
+        let socket = UdpSocket::bind(local).context("failed to open an OSC socket")?;
+        socket
+            .connect(target)
+            .with_context(|| format!("failed to address OSC packets to {target}"))?;
+        socket.set_nonblocking(true)?;
+
+        let (result_tx, result_rx) = DeliveryMode::Lossless {
+            capacity: RESULT_QUEUE,
+        }
+        .channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let handle = {
+            let config = config.clone();
+            let stop = stop.clone();
+            thread::spawn(move || send_loop(socket, encoder, result_rx, &config, stop))
+        };
+
+        log::info!("osc output sending to {target}");
+        Ok(Self {
+            result_tx,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    /// Sender to hand to the recognizer as a result tap.
+    pub fn result_sender(&self) -> Delivery<GestureResult> {
+        self.result_tx.clone()
+    }
+}
+
+impl Drop for OscSender {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn send_loop(
+    socket: UdpSocket,
+    encoder: wire::Encoder,
+    result_rx: Receiver<GestureResult>,
+    config: &OscConfig,
+    stop: Arc<AtomicBool>,
+) {
+    let prefix = config.address_prefix.trim_end_matches('/');
+    while !stop.load(Ordering::Relaxed) {
+        let result = match result_rx.recv_timeout(POLL_INTERVAL) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => break,
+        };
+        let messages = frame_messages(&result, prefix, config.landmarks);
+        let sent = encoder
+            .encode_bundle(&messages)
+            .and_then(|packet| socket.send(&packet).map_err(anyhow::Error::from));
+        // At frame rate, so not a warning; a closed port reads as an error
+        // on the next send.
+        if let Err(err) = sent {
+            log::debug!("osc packet dropped: {err:#}");
+        }
+    }
+}
+
+/// `/gesture/kind` is the gesture name as in the settings file, or `none`
+/// without a hand. Landmarks are x and y over the frame size, and wrist
+/// relative depth over the crop size, all roughly 0..1 or -1..1.
+fn frame_messages(result: &GestureResult, prefix: &str, landmarks: bool) -> Vec<Message> {
+    let message = |address: &str, args: Vec<Arg>| Message {
+        address: format!("{prefix}{address}"),
+        args,
+    };
+    let detail = result.detail.as_ref();
+    let kind = detail.map_or_else(|| "none".to_string(), |d| gesture_name(d.primary));
+
+    let mut messages = vec![
+        message("/gesture/kind", vec![Arg::Str(kind)]),
+        message("/gesture/confidence", vec![Arg::Float(result.confidence)]),
+    ];
+    if let Some(pinch) = detail.and_then(|d| d.pinch) {
+        messages.push(message(
+            "/hand/pinch_distance",
+            vec![Arg::Float(pinch.distance)],
+        ));
+    }
+    if let Some(rotation) = detail.and_then(|d| d.rotation) {
+        messages.push(message(
+            "/hand/rotation",
+            vec![Arg::Float(rotation.delta_degrees)],
+        ));
+    }
+    if landmarks {
+        let (width, height) = result.frame_size;
+        let (width, height) = (width.max(1) as f32, height.max(1) as f32);
+        let projected = result.landmarks.iter().flatten();
+        let raw = result.raw_landmarks.iter().flatten();
+        for (i, ((x, y), point)) in projected.zip(raw).enumerate() {
+            let args = vec![
+                Arg::Float(x / width),
+                Arg::Float(y / height),
+                Arg::Float(point[2] / INPUT_SIZE as f32),
+            ];
+            messages.push(message(&format!("/hand/landmark/{i}"), args));
+        }
+    }
+    messages
+}
+
+fn gesture_name(kind: GestureKind) -> String {
+    match serde_json::to_value(kind) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => format!("{kind:?}"),
+    }
+}
+
+#[cfg(feature = "osc-output")]
+mod wire {
+    use anyhow::{Result, anyhow};
+    use rosc::{OscBundle, OscMessage, OscPacket, OscTime, OscType, encoder};
+
+    use super::{Arg, Message};
+
+    /// "Immediately" as an OSC time tag.
+    const IMMEDIATE: OscTime = OscTime {
+        seconds: 0,
+        fractional: 1,
+    };
+
+    pub struct Encoder;
+
+    impl Encoder {
+        pub fn new() -> Result<Self> {
+            Ok(Self)
+        }
+
+        pub fn encode_bundle(&self, messages: &[Message]) -> Result<Vec<u8>> {
+            let content = messages
+                .iter()
+                .map(|message| {
+                    OscPacket::Message(OscMessage {
+                        addr: message.address.clone(),
+                        args: message
+                            .args
+                            .iter()
+                            .map(|arg| match arg {
+                                Arg::Float(value) => OscType::Float(*value),
+                                Arg::Str(value) => OscType::String(value.clone()),
+                            })
+                            .collect(),
+                    })
+                })
+                .collect();
+            let bundle = OscPacket::Bundle(OscBundle {
+                timetag: IMMEDIATE,
+                content,
+            });
+            encoder::encode(&bundle).map_err(|err| anyhow!("failed to encode OSC bundle: {err}"))
+        }
+    }
+}
+
+#[cfg(not(feature = "osc-output"))]
+mod wire {
+    use anyhow::{Result, bail};
+
+    use super::Message;
+
+    /// Stands in on builds without OSC; never constructed.
+    pub enum Encoder {}
+
+    impl Encoder {
+        pub fn new() -> Result<Self> {
+            bail!("this build has no OSC output; rebuild with --features osc-output");
+        }
+
+        pub fn encode_bundle(&self, _messages: &[Message]) -> Result<Vec<u8>> {
+            match *self {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{GestureDetail, PinchInfo};
+
+    /// A 640×480 frame with a pinching palm whose landmarks run diagonally
+    /// across the frame and from behind to in front of the wrist.
+    fn pinching() -> GestureResult {
+        let mut detail = GestureDetail::new(GestureKind::Palm);
+        detail.pinch = Some(PinchInfo {
+            distance: 0.3,
+            delta: 0.0,
+            closed: false,
+        });
+        let step = |i: usize| i as f32 / 20.0;
+        GestureResult {
+            confidence: 0.8,
+            frame_size: (640, 480),
+            landmarks: Some(
+                (0..21)
+                    .map(|i| (640.0 * step(i), 480.0 * step(i)))
+                    .collect(),
+            ),
+            raw_landmarks: Some(
+                (0..21)
+                    .map(|i| [0.0, 0.0, (step(i) - 0.5) * INPUT_SIZE as f32])
+                    .collect(),
+            ),
+            detail: Some(detail),
+            ..GestureResult::default()
+        }
+    }
+
+    fn floats(message: &Message) -> Vec<f32> {
+        message
+            .args
+            .iter()
+            .map(|arg| match arg {
+                Arg::Float(value) => *value,
+                Arg::Str(value) => panic!("{} carries {value:?}", message.address),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn a_frame_without_landmarks_sends_the_gesture_and_pinch() {
+        let messages = frame_messages(&pinching(), "/gu", false);
+        let addresses: Vec<&str> = messages.iter().map(|m| m.address.as_str()).collect();
+        assert_eq!(
+            addresses,
+            [
+                "/gu/gesture/kind",
+                "/gu/gesture/confidence",
+                "/gu/hand/pinch_distance"
+            ]
+        );
+        assert!(matches!(&messages[0].args[..], [Arg::Str(kind)] if kind == "palm"));
+        assert_eq!(floats(&messages[1]), [0.8]);
+        assert_eq!(floats(&messages[2]), [0.3]);
+    }
+
+    #[test]
+    fn landmarks_are_normalized_to_the_frame_and_crop() {
+        let messages = frame_messages(&pinching(), "", true);
+        let landmarks: Vec<&Message> = messages
+            .iter()
+            .filter(|m| m.address.starts_with("/hand/landmark/"))
+            .collect();
+        assert_eq!(landmarks.len(), 21);
+        for (i, message) in landmarks.iter().enumerate() {
+            assert_eq!(message.address, format!("/hand/landmark/{i}"));
+            let [x, y, z] = floats(message)[..] else {
+                panic!(
+                    "{} carries {:?} floats",
+                    message.address,
+                    message.args.len()
+                );
+            };
+            assert!(
+                (0.0..=1.0).contains(&x) && (0.0..=1.0).contains(&y),
+                "{x} {y}"
+            );
+            assert!((-0.5..=0.5).contains(&z), "{z}");
+        }
+        assert_eq!(floats(landmarks[20]), [1.0, 1.0, 0.5]);
+    }
+
+    #[test]
+    fn no_hand_sends_none() {
+        let messages = frame_messages(&GestureResult::default(), "", true);
+        assert_eq!(messages.len(), 2);
+        assert!(matches!(&messages[0].args[..], [Arg::Str(kind)] if kind == "none"));
+    }
+
+    #[cfg(feature = "osc-output")]
+    #[test]
+    fn a_frame_arrives_as_one_bundle() {
+        use rosc::{OscPacket, OscType, decoder};
+
+        let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+        receiver
+            .set_read_timeout(Some(Duration::from_secs(2)))
+            .unwrap();
+        let sender = OscSender::start(&OscConfig {
+            port: receiver.local_addr().unwrap().port(),
+            address_prefix: "/gu/".to_string(),
+            landmarks: true,
+            ..OscConfig::default()
+        })
+        .unwrap();
+        sender.result_sender().deliver(pinching());
+
+        let mut buffer = [0; decoder::MTU];
+        let size = receiver.recv(&mut buffer).unwrap();
+        let (_, packet) = decoder::decode_udp(&buffer[..size]).unwrap();
+        let OscPacket::Bundle(bundle) = packet else {
+            panic!("expected a bundle, got {packet:?}");
+        };
+        assert_eq!(bundle.content.len(), 3 + 21);
+        let messages: Vec<_> = bundle
+            .content
+            .into_iter()
+            .map(|packet| match packet {
+                OscPacket::Message(message) => message,
+                OscPacket::Bundle(inner) => panic!("nested bundle {inner:?}"),
+            })
+            .collect();
+        assert_eq!(messages[0].addr, "/gu/gesture/kind");
+        assert_eq!(messages[0].args, [OscType::String("palm".into())]);
+        assert_eq!(messages[2].addr, "/gu/hand/pinch_distance");
+        assert_eq!(messages[23].addr, "/gu/hand/landmark/20");
+        assert_eq!(
+            messages[23].args,
+            [
+                OscType::Float(1.0),
+                OscType::Float(1.0),
+                OscType::Float(0.5)
+            ]
+        );
+    }
+}
@@ -1,25 +1,35 @@
 use std::{
+    collections::BTreeMap,
     sync::{
-        Arc,
+        Arc, Mutex,
         atomic::{AtomicBool, Ordering},
     },
-    thread,
-    time::Instant,
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
 };
 
-use anyhow::{Result, anyhow};
-use crossbeam_channel::Sender;
+use anyhow::{Context, Result, anyhow, bail};
+use crossbeam_channel::{Receiver, Sender, bounded};
 use nokhwa::{
     Camera,
     pixel_format::RgbFormat,
     query,
     utils::{
-        ApiBackend, CameraIndex, CameraInfo, FrameFormat, RequestedFormat, RequestedFormatType,
+        ApiBackend, CameraFormat, CameraIndex, CameraInfo, ControlValueDescription,
+        ControlValueSetter, FrameFormat, KnownCameraControl, KnownCameraControlFlag,
+        RequestedFormat, RequestedFormatType, Resolution,
     },
 };
+use serde::{Deserialize, Serialize};
 
-use super::rgba_converter;
-use crate::types::Frame;
+use super::metrics::{Channel, MetricsHandle, Stage};
+use super::recognizer::quality::frame_quality;
+use super::rgb_converter;
+use super::source::{FrameSource, start_frame_source};
+use crate::{
+    locale::{Locale, locale},
+    types::{Frame, FramePool, FrameQuality, PixelLayout, QualityGateConfig},
+};
 
 // Prefer pixel formats that are widely supported on macOS (the built-in cameras
 // often reject YUYV even though Nokhwa reports it).
@@ -49,6 +59,229 @@ fn requested_formats() -> [RequestedFormat<'static>; 4] {
     ]
 }
 
+/// A capture mode a device offers, and the one to ask for first when opening
+/// it. Modes the device rejects fall back to the usual preference list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CameraOpenOptions {
+    pub resolution: Resolution,
+    pub fps: u32,
+    pub format: FrameFormat,
+}
+
+impl CameraOpenOptions {
+    /// e.g. "1920×1080 @ 30 (MJPEG)".
+    pub fn label(&self) -> String {
+        describe_mode(
+            self.resolution.width(),
+            self.resolution.height(),
+            self.fps,
+            self.format,
+        )
+    }
+
+    fn requested(&self) -> RequestedFormat<'static> {
+        let format = CameraFormat::new(self.resolution, self.format, self.fps);
+        RequestedFormat::new::<RgbFormat>(RequestedFormatType::Exact(format))
+    }
+}
+
+impl From<CameraFormat> for CameraOpenOptions {
+    fn from(format: CameraFormat) -> Self {
+        Self {
+            resolution: format.resolution(),
+            fps: format.frame_rate(),
+            format: format.format(),
+        }
+    }
+}
+
+pub fn describe_mode(width: u32, height: u32, fps: u32, format: FrameFormat) -> String {
+    format!("{width}×{height} @ {fps} ({format})")
+}
+
+/// Inverse of the names `describe_mode` prints, for saved settings.
+pub fn parse_frame_format(name: &str) -> Option<FrameFormat> {
+    PREFERRED_PIXEL_FORMATS
+        .iter()
+        .copied()
+        .find(|format| format.to_string().eq_ignore_ascii_case(name))
+}
+
+/// Consecutive failed reads after which the camera is reopened.
+const RECONNECT_AFTER_ERRORS: u32 = 10;
+const RECONNECT_BASE_DELAY: Duration = Duration::from_millis(250);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(8);
+/// Status updates the UI may fall behind on before new ones are dropped.
+const STATUS_QUEUE: usize = 16;
+/// Frames that can be alive at once between capture and the UI: one per
+/// channel and stage, plus the UI's latest frame and a recorder copy.
+pub(super) const FRAME_POOL_IDLE: usize = 8;
+/// How long one camera may take to open and show a picture while probing.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+/// Frames read per camera while probing; the first few are often black while
+/// the exposure settles.
+const PROBE_FRAMES: usize = 10;
+/// How long a timed-out probe gets to close its camera before it is parked.
+const PROBE_RELEASE_TIMEOUT: Duration = Duration::from_millis(500);
+const PROBE_RELEASE_POLL: Duration = Duration::from_millis(10);
+
+/// Probe readers that timed out inside a hung driver call, with the camera
+/// each still holds. Opening that camera again waits for its reader first.
+static STRAY_PROBES: Mutex<Vec<(CameraIndex, JoinHandle<()>)>> = Mutex::new(Vec::new());
+
+/// Health of a running camera, reported by the capture thread.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CameraStatus {
+    /// The mode the driver actually negotiated.
+    Streaming {
+        width: u32,
+        height: u32,
+        fps: u32,
+        format: FrameFormat,
+    },
+    /// Reads kept failing; the device is being reopened.
+    Disconnected { error: String },
+    /// Waiting `delay` before reopen attempt number `attempt`.
+    Reconnecting { attempt: u32, delay: Duration },
+}
+
+/// Decides when a failing camera should be reopened and how long to wait
+/// between attempts. Kept apart from nokhwa so the policy can be reasoned
+/// about on its own.
+#[derive(Clone, Debug)]
+pub struct ReconnectBackoff {
+    errors_before_reconnect: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    consecutive_errors: u32,
+    attempt: u32,
+}
+
+impl Default for ReconnectBackoff {
+    fn default() -> Self {
+        Self::new(
+            RECONNECT_AFTER_ERRORS,
+            RECONNECT_BASE_DELAY,
+            RECONNECT_MAX_DELAY,
+        )
+    }
+}
+
+impl ReconnectBackoff {
+    pub fn new(errors_before_reconnect: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            errors_before_reconnect: errors_before_reconnect.max(1),
+            base_delay,
+            max_delay,
+            consecutive_errors: 0,
+            attempt: 0,
+        }
+    }
+
+    pub fn frame_ok(&mut self) {
+        self.consecutive_errors = 0;
+    }
+
+    /// Returns true once enough reads failed in a row to give up on the
+    /// current handle.
+    pub fn frame_failed(&mut self) -> bool {
+        self.consecutive_errors += 1;
+        self.consecutive_errors >= self.errors_before_reconnect
+    }
+
+    /// Starts the next reopen attempt and returns how long to wait before it:
+    /// the base delay, doubled per failed attempt up to the maximum.
+    pub fn next_delay(&mut self) -> Duration {
+        let doublings = self.attempt.min(16);
+        self.attempt += 1;
+        self.base_delay
+            .saturating_mul(1 << doublings)
+            .min(self.max_delay)
+    }
+
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// The camera is back; the next outage starts from the base delay.
+    pub fn connected(&mut self) {
+        self.consecutive_errors = 0;
+        self.attempt = 0;
+    }
+}
+
+/// Mean luma of a palm region that "optimize for hands" aims the exposure at:
+/// a little under mid-gray, so skin keeps its detail against a bright window.
+pub const HAND_TARGET_LUMA: f32 = 120.0;
+/// Control changes the UI may queue before the capture thread picks them up.
+const CONTROL_QUEUE: usize = 16;
+
+/// The camera controls the tuning panel offers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CameraKnob {
+    Exposure,
+    Gain,
+    WhiteBalance,
+}
+
+impl CameraKnob {
+    pub const ALL: [CameraKnob; 3] = [
+        CameraKnob::Exposure,
+        CameraKnob::Gain,
+        CameraKnob::WhiteBalance,
+    ];
+
+    pub fn label(self) -> &'static str {
+        self.label_in(locale())
+    }
+
+    pub fn label_in(self, locale: Locale) -> &'static str {
+        let (zh, en) = match self {
+            CameraKnob::Exposure => ("曝光", "Exposure"),
+            CameraKnob::Gain => ("增益", "Gain"),
+            CameraKnob::WhiteBalance => ("白平衡", "White balance"),
+        };
+        locale.pick(zh, en)
+    }
+
+    fn known(self) -> KnownCameraControl {
+        match self {
+            CameraKnob::Exposure => KnownCameraControl::Exposure,
+            CameraKnob::Gain => KnownCameraControl::Gain,
+            CameraKnob::WhiteBalance => KnownCameraControl::WhiteBalance,
+        }
+    }
+}
+
+/// Range and current value of one control a device lets us set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CameraControlInfo {
+    pub knob: CameraKnob,
+    pub min: i64,
+    pub max: i64,
+    pub step: i64,
+    pub default: i64,
+    pub value: i64,
+}
+
+impl CameraControlInfo {
+    /// `value` snapped to the control's step and kept inside its range.
+    pub fn clamp(&self, value: i64) -> i64 {
+        let step = self.step.max(1);
+        let steps = (value.saturating_sub(self.min) + step / 2).div_euclid(step);
+        let snapped = self.min.saturating_add(steps.saturating_mul(step));
+        snapped.clamp(self.min, self.max)
+    }
+}
+
+/// The exposure that brings a region metered at `luma` to
+/// [`HAND_TARGET_LUMA`], assuming brightness scales with exposure time.
+pub fn exposure_for_luma(exposure: &CameraControlInfo, luma: f32) -> i64 {
+    let scaled = exposure.value as f32 * HAND_TARGET_LUMA / luma.max(1.0);
+    exposure.clamp(scaled.round() as i64)
+}
+
 #[derive(Clone, Debug)]
 pub struct CameraDevice {
     pub index: CameraIndex,
@@ -58,10 +291,89 @@ pub struct CameraDevice {
 #[derive(Debug)]
 pub struct CameraStream {
     stop: Arc<AtomicBool>,
+    mirror: Arc<AtomicBool>,
+    /// Only read by camera devices; see [`CameraStream::set_paused`].
+    paused: Arc<AtomicBool>,
     handle: Option<thread::JoinHandle<()>>,
+    status_rx: Option<Receiver<CameraStatus>>,
+    /// What the open device offers, kept current by the capture thread.
+    controls: Arc<Mutex<Vec<CameraControlInfo>>>,
+    control_tx: Option<Sender<(CameraKnob, i64)>>,
 }
 
 impl CameraStream {
+    pub(super) fn from_parts(
+        stop: Arc<AtomicBool>,
+        mirror: Arc<AtomicBool>,
+        handle: thread::JoinHandle<()>,
+    ) -> Self {
+        Self {
+            stop,
+            mirror,
+            paused: Arc::new(AtomicBool::new(false)),
+            handle: Some(handle),
+            status_rx: None,
+            controls: Arc::default(),
+            control_tx: None,
+        }
+    }
+
+    fn with_status(mut self, status_rx: Receiver<CameraStatus>) -> Self {
+        self.status_rx = Some(status_rx);
+        self
+    }
+
+    fn with_pause_flag(mut self, paused: Arc<AtomicBool>) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    fn with_controls(
+        mut self,
+        controls: Arc<Mutex<Vec<CameraControlInfo>>>,
+        control_tx: Sender<(CameraKnob, i64)>,
+    ) -> Self {
+        self.controls = controls;
+        self.control_tx = Some(control_tx);
+        self
+    }
+
+    /// Latest status reported since the last call, if any. Sources without
+    /// status reporting (video files) never return anything.
+    pub fn poll_status(&self) -> Option<CameraStatus> {
+        self.status_rx.as_ref()?.try_iter().last()
+    }
+
+    /// Flips subsequent frames horizontally before they enter the pipeline, so
+    /// landmarks and palm regions are computed on the mirrored pixels.
+    pub fn set_mirror(&self, mirror: bool) {
+        self.mirror.store(mirror, Ordering::Relaxed);
+    }
+
+    /// A paused camera keeps the device open and reads frames so resuming is
+    /// instant, but stops decoding and sending them. Video files and image
+    /// sequences play on, and the paused recognizer drops their frames.
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::Relaxed);
+    }
+
+    /// The controls the device lets us set; empty for video files, image
+    /// sequences and cameras without any.
+    pub fn controls(&self) -> Vec<CameraControlInfo> {
+        self.controls
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Asks the capture thread to set `knob`, clamped to its range. Ignored
+    /// when the device does not offer it.
+    pub fn set_control(&self, knob: CameraKnob, value: i64) {
+        if let Some(control_tx) = &self.control_tx {
+            let _ = control_tx.try_send((knob, value));
+        }
+    }
+
     pub fn stop(mut self) {
         self.stop.store(true, Ordering::SeqCst);
         if let Some(handle) = self.handle.take() {
@@ -79,6 +391,141 @@ impl Drop for CameraStream {
     }
 }
 
+/// Owns the running frame source and keeps feeding the same `Sender<Frame>`
+/// across switches, so the recognizer and compositor never notice.
+pub struct CameraManager {
+    /// `None` once closed, so the pipeline downstream sees its input end.
+    frame_tx: Option<Sender<Frame>>,
+    metrics: MetricsHandle,
+    mirror: bool,
+    /// Carried over to every source opened, like `mirror`.
+    paused: bool,
+    /// Control values applied to every camera opened, like `mirror`.
+    control_values: BTreeMap<CameraKnob, i64>,
+    active: Option<(FrameSource, CameraStream)>,
+}
+
+impl CameraManager {
+    pub fn new(frame_tx: Sender<Frame>, metrics: MetricsHandle, mirror: bool) -> Self {
+        Self {
+            frame_tx: Some(frame_tx),
+            metrics,
+            mirror,
+            paused: false,
+            control_values: BTreeMap::new(),
+            active: None,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.active.is_some()
+    }
+
+    pub fn set_camera(
+        &mut self,
+        index: CameraIndex,
+        options: Option<CameraOpenOptions>,
+    ) -> Result<()> {
+        self.set_source(FrameSource::Camera { index, options })
+    }
+
+    /// Stops the current source and opens `source` in its place. When the new
+    /// one fails to open the previous source is restarted and the error is
+    /// returned to the caller.
+    pub fn set_source(&mut self, source: FrameSource) -> Result<()> {
+        // Most drivers refuse to open a device twice, so the old stream has to
+        // release it before the new one (or the rollback) can start.
+        let previous = self.active.take().map(|(previous, stream)| {
+            stream.stop();
+            previous
+        });
+
+        match self.open(source.clone()) {
+            Ok(stream) => {
+                self.active = Some((source, stream));
+                Ok(())
+            }
+            Err(err) => {
+                if let Some(previous) = previous {
+                    match self.open(previous.clone()) {
+                        Ok(stream) => {
+                            log::warn!("switching source failed, restored {previous:?}");
+                            self.active = Some((previous, stream));
+                        }
+                        Err(rollback_err) => {
+                            log::error!("failed to restore {previous:?}: {rollback_err:#}");
+                        }
+                    }
+                }
+                Err(err)
+            }
+        }
+    }
+
+    pub fn poll_status(&self) -> Option<CameraStatus> {
+        self.active.as_ref()?.1.poll_status()
+    }
+
+    pub fn set_mirror(&mut self, mirror: bool) {
+        self.mirror = mirror;
+        if let Some((_, stream)) = &self.active {
+            stream.set_mirror(mirror);
+        }
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+        if let Some((_, stream)) = &self.active {
+            stream.set_paused(paused);
+        }
+    }
+
+    /// Values to apply to the next camera opened, e.g. the ones saved for it.
+    /// The running stream keeps its own.
+    pub fn set_saved_controls(&mut self, values: BTreeMap<CameraKnob, i64>) {
+        self.control_values = values;
+    }
+
+    pub fn controls(&self) -> Vec<CameraControlInfo> {
+        self.active
+            .as_ref()
+            .map(|(_, stream)| stream.controls())
+            .unwrap_or_default()
+    }
+
+    pub fn set_control(&mut self, knob: CameraKnob, value: i64) {
+        self.control_values.insert(knob, value);
+        if let Some((_, stream)) = &self.active {
+            stream.set_control(knob, value);
+        }
+    }
+
+    pub fn stop(&mut self) {
+        if let Some((_, stream)) = self.active.take() {
+            stream.stop();
+        }
+    }
+
+    /// Stops the current source and drops the frame sender for good.
+    pub fn close(&mut self) {
+        self.stop();
+        self.frame_tx = None;
+    }
+
+    fn open(&self, source: FrameSource) -> Result<CameraStream> {
+        let frame_tx = self
+            .frame_tx
+            .clone()
+            .ok_or_else(|| anyhow!("camera manager is closed"))?;
+        let stream = start_frame_source(source, frame_tx, self.metrics.clone(), self.mirror)?;
+        stream.set_paused(self.paused);
+        for (&knob, &value) in &self.control_values {
+            stream.set_control(knob, value);
+        }
+        Ok(stream)
+    }
+}
+
 pub fn available_cameras() -> Result<Vec<CameraDevice>> {
     let cameras = query(ApiBackend::Auto)?;
     Ok(cameras
@@ -94,8 +541,127 @@ fn format_camera_label(info: &CameraInfo) -> String {
     info.human_name()
 }
 
-fn build_camera(index: CameraIndex) -> Result<Camera> {
-    let mut last_err = None;
+/// Every mode `index` reports, largest and fastest first. The device is opened
+/// without starting a stream, so call this before streaming from it: some
+/// drivers refuse a second handle.
+pub fn supported_formats(index: &CameraIndex) -> Result<Vec<CameraOpenOptions>> {
+    let requested = RequestedFormat::new::<RgbFormat>(RequestedFormatType::None);
+    let mut camera = Camera::new(index.clone(), requested)
+        .context("failed to open camera to list its formats")?;
+    let mut formats: Vec<CameraOpenOptions> = camera
+        .compatible_camera_formats()
+        .context("failed to list camera formats")?
+        .into_iter()
+        .map(CameraOpenOptions::from)
+        .collect();
+    formats.sort_by_key(|mode| {
+        let pixels = mode.resolution.width() * mode.resolution.height();
+        (
+            std::cmp::Reverse(pixels),
+            std::cmp::Reverse(mode.fps),
+            mode.format.to_string(),
+        )
+    });
+    formats.dedup();
+    Ok(formats)
+}
+
+/// The exposure, gain and white-balance controls `index` lets us set, with
+/// their ranges. Like [`supported_formats`] this opens the device without
+/// streaming, so call it before streaming from it.
+pub fn controls(index: &CameraIndex) -> Result<Vec<CameraControlInfo>> {
+    let requested = RequestedFormat::new::<RgbFormat>(RequestedFormatType::None);
+    let camera = Camera::new(index.clone(), requested)
+        .context("failed to open camera to list its controls")?;
+    Ok(read_controls(&camera))
+}
+
+/// Controls the driver reports as settable integer ranges. Anything else,
+/// or a driver that cannot list controls at all, counts as unsupported.
+fn read_controls(camera: &Camera) -> Vec<CameraControlInfo> {
+    let reported = match camera.camera_controls() {
+        Ok(reported) => reported,
+        Err(err) => {
+            log::debug!("camera does not list its controls: {err}");
+            return Vec::new();
+        }
+    };
+    CameraKnob::ALL
+        .into_iter()
+        .filter_map(|knob| {
+            let control = reported
+                .iter()
+                .find(|control| control.control() == knob.known())?;
+            let locked = control.flag().iter().any(|flag| {
+                matches!(
+                    flag,
+                    KnownCameraControlFlag::ReadOnly | KnownCameraControlFlag::Disabled
+                )
+            });
+            match *control.description() {
+                ControlValueDescription::IntegerRange {
+                    min,
+                    max,
+                    value,
+                    step,
+                    default,
+                } if !locked && min < max => Some(CameraControlInfo {
+                    knob,
+                    min,
+                    max,
+                    step,
+                    default,
+                    value,
+                }),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn refresh_controls(camera: &Camera, controls: &Mutex<Vec<CameraControlInfo>>) {
+    *controls
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = read_controls(camera);
+}
+
+/// Sets one control on the capture thread and updates the shared copy.
+fn apply_control(
+    camera: &mut Camera,
+    controls: &Mutex<Vec<CameraControlInfo>>,
+    knob: CameraKnob,
+    value: i64,
+) {
+    let mut controls = controls
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let Some(info) = controls.iter_mut().find(|info| info.knob == knob) else {
+        log::debug!("camera has no {knob:?} control, ignoring");
+        return;
+    };
+    let value = info.clamp(value);
+    match camera.set_camera_control(knob.known(), ControlValueSetter::Integer(value)) {
+        Ok(()) => info.value = value,
+        Err(err) => log::warn!("failed to set camera {knob:?} to {value}: {err}"),
+    }
+}
+
+fn build_camera(index: CameraIndex, options: Option<CameraOpenOptions>) -> Result<Camera> {
+    wait_for_stray_probe(&index)?;
+    let mut last_err: Option<anyhow::Error> = None;
+
+    if let Some(options) = options {
+        match Camera::new(index.clone(), options.requested()) {
+            Ok(mut camera) => match camera.open_stream() {
+                Ok(()) => return Ok(camera),
+                Err(err) => last_err = Some(err.into()),
+            },
+            Err(err) => last_err = Some(err.into()),
+        }
+        if let Some(err) = &last_err {
+            log::warn!("camera rejected {}, falling back: {err:#}", options.label());
+        }
+    }
 
     for requested in requested_formats() {
         match Camera::new(index.clone(), requested) {
@@ -110,58 +676,506 @@ fn build_camera(index: CameraIndex) -> Result<Camera> {
     Err(last_err.unwrap_or_else(|| anyhow!("failed to open camera with any supported format")))
 }
 
-pub fn start_camera_stream(index: CameraIndex, frame_tx: Sender<Frame>) -> Result<CameraStream> {
-    // Fail fast before spawning the capture thread.
-    build_camera(index.clone())?;
-
+pub fn start_camera_stream(
+    index: CameraIndex,
+    options: Option<CameraOpenOptions>,
+    frame_tx: Sender<Frame>,
+    metrics: MetricsHandle,
+    mirror: bool,
+) -> Result<CameraStream> {
     let stop = Arc::new(AtomicBool::new(false));
     let stop_flag = stop.clone();
+    let mirror = Arc::new(AtomicBool::new(mirror));
+    let mirror_flag = mirror.clone();
+    let paused = Arc::new(AtomicBool::new(false));
+    let paused_flag = paused.clone();
+    let controls: Arc<Mutex<Vec<CameraControlInfo>>> = Arc::default();
+    let shared_controls = controls.clone();
 
+    let (status_tx, status_rx) = bounded(STATUS_QUEUE);
+    let (control_tx, control_rx) = bounded::<(CameraKnob, i64)>(CONTROL_QUEUE);
+
+    // The camera is opened on the capture thread, which reports back whether
+    // that worked so the caller sees the real error.
+    let (ready_tx, ready_rx) = bounded(1);
     let handle = thread::spawn(move || {
-        let mut camera = match build_camera(index) {
-            Ok(cam) => cam,
+        let mut camera = match build_camera(index.clone(), options) {
+            Ok(cam) => {
+                let _ = ready_tx.send(Ok(()));
+                cam
+            }
             Err(err) => {
-                log::error!("failed to open camera: {err:?}");
+                let _ = ready_tx.send(Err(err));
                 return;
             }
         };
+        report_streaming(&camera, &status_tx);
+        refresh_controls(&camera, &shared_controls);
+        let mut backoff = ReconnectBackoff::default();
+        let pool = FramePool::new(FRAME_POOL_IDLE);
+        // Everything set so far, to put back after a reconnect.
+        let mut applied = BTreeMap::new();
 
         while !stop_flag.load(Ordering::Relaxed) {
+            for (knob, value) in control_rx.try_iter() {
+                apply_control(&mut camera, &shared_controls, knob, value);
+                applied.insert(knob, value);
+            }
+
             let frame_start = Instant::now();
             let frame = match camera.frame() {
-                Ok(frame) => frame,
+                Ok(frame) => {
+                    backoff.frame_ok();
+                    frame
+                }
                 Err(err) => {
                     log::warn!(
                         "camera frame read failed (after {:?}): {err:?}",
                         frame_start.elapsed()
                     );
+                    if backoff.frame_failed() {
+                        let _ = status_tx.try_send(CameraStatus::Disconnected {
+                            error: err.to_string(),
+                        });
+                        // Release the device before asking for it again.
+                        drop(camera);
+                        match reconnect(&index, options, &stop_flag, &mut backoff, &status_tx) {
+                            Some(reopened) => camera = reopened,
+                            None => return,
+                        }
+                        refresh_controls(&camera, &shared_controls);
+                        for (&knob, &value) in &applied {
+                            apply_control(&mut camera, &shared_controls, knob, value);
+                        }
+                    }
                     continue;
                 }
             };
 
-            let converted = match rgba_converter::convert_camera_frame(&frame) {
-                Ok(rgba) => rgba,
+            // Read anyway, so the driver's queue holds a fresh frame on resume.
+            if paused_flag.load(Ordering::Relaxed) {
+                continue;
+            }
+
+            let acquired_at = Instant::now();
+            let mut converted = match rgb_converter::convert_camera_frame(&frame, &pool) {
+                Ok(rgb) => rgb,
                 Err(err) => {
                     log::warn!("failed to decode camera frame {err:?}");
                     continue;
                 }
             };
+            if mirror_flag.load(Ordering::Relaxed) {
+                rgb_converter::mirror_pixels(&mut converted.rgb, converted.width, PixelLayout::Rgb);
+            }
 
             let frame_timestamp = Instant::now();
             let frame = Frame {
-                rgba: converted.rgba,
+                pixels: converted.rgb,
+                layout: PixelLayout::Rgb,
                 width: converted.width,
                 height: converted.height,
                 timestamp: frame_timestamp,
+                acquired_at,
             };
 
             // Drop if the worker is busy, otherwise forward every frame.
-            let _ = frame_tx.try_send(frame);
+            metrics.record_capture(frame_timestamp);
+            metrics.record_stage(Stage::Decode, frame_timestamp - acquired_at);
+            if frame_tx.try_send(frame).is_err() {
+                metrics.record_drop(Channel::Camera);
+            }
+        }
+        log::debug!(
+            "camera stopped after {} frame buffer allocations",
+            pool.allocations()
+        );
+    });
+
+    match ready_rx
+        .recv()
+        .context("camera thread exited before opening the device")?
+    {
+        Ok(()) => Ok(CameraStream::from_parts(stop, mirror, handle)
+            .with_status(status_rx)
+            .with_pause_flag(paused)
+            .with_controls(controls, control_tx)),
+        Err(err) => {
+            let _ = handle.join();
+            Err(err)
+        }
+    }
+}
+
+fn report_streaming(camera: &Camera, status_tx: &Sender<CameraStatus>) {
+    let format = camera.camera_format();
+    let _ = status_tx.try_send(CameraStatus::Streaming {
+        width: format.resolution().width(),
+        height: format.resolution().height(),
+        fps: format.frame_rate(),
+        format: format.format(),
+    });
+}
+
+/// Reopens the camera, backing off between attempts, until it works or the
+/// stream is stopped.
+fn reconnect(
+    index: &CameraIndex,
+    options: Option<CameraOpenOptions>,
+    stop: &AtomicBool,
+    backoff: &mut ReconnectBackoff,
+    status_tx: &Sender<CameraStatus>,
+) -> Option<Camera> {
+    const SLEEP_SLICE: Duration = Duration::from_millis(50);
+
+    while !stop.load(Ordering::Relaxed) {
+        let delay = backoff.next_delay();
+        let attempt = backoff.attempt();
+        let _ = status_tx.try_send(CameraStatus::Reconnecting { attempt, delay });
+        log::info!("reopening camera in {delay:?} (attempt {attempt})");
+
+        // Sleep in slices so stopping the stream is not held up.
+        let deadline = Instant::now() + delay;
+        while Instant::now() < deadline {
+            if stop.load(Ordering::Relaxed) {
+                return None;
+            }
+            thread::sleep(SLEEP_SLICE.min(deadline.saturating_duration_since(Instant::now())));
+        }
+
+        match build_camera(index.clone(), options) {
+            Ok(camera) => {
+                log::info!("camera reconnected after {attempt} attempt(s)");
+                backoff.connected();
+                report_streaming(&camera, status_tx);
+                return Some(camera);
+            }
+            Err(err) => log::warn!("camera reopen attempt {attempt} failed: {err:#}"),
         }
+    }
+    None
+}
+
+/// Progress of [`probe_cameras`].
+#[derive(Clone, Debug)]
+pub enum CameraProbe {
+    /// The camera at this position in the list is being tried.
+    Trying(usize),
+    /// The first camera that showed a picture, or `None` when none did.
+    Done(Option<usize>),
+}
+
+/// Tries `cameras` in order on a background thread and reports the first
+/// that delivers a frame with some contrast, so a virtual camera sending
+/// black frames is passed over. A camera is closed again before the next is
+/// tried or the result is sent.
+pub fn probe_cameras(cameras: Vec<CameraDevice>) -> Receiver<CameraProbe> {
+    let (probe_tx, probe_rx) = bounded(cameras.len() + 1);
+    thread::spawn(move || {
+        let live = first_live(&cameras, &probe_tx, probe_camera);
+        let _ = probe_tx.send(CameraProbe::Done(live));
     });
+    probe_rx
+}
 
-    Ok(CameraStream {
-        stop,
-        handle: Some(handle),
+/// Reports each camera as it is tried and stops at the first that passes
+/// `probe`.
+fn first_live<T>(
+    cameras: &[T],
+    probe_tx: &Sender<CameraProbe>,
+    mut probe: impl FnMut(&T) -> bool,
+) -> Option<usize> {
+    cameras.iter().enumerate().find_map(|(idx, camera)| {
+        let _ = probe_tx.send(CameraProbe::Trying(idx));
+        probe(camera).then_some(idx)
     })
 }
+
+/// Whether `device` opens and shows a picture within [`PROBE_TIMEOUT`].
+fn probe_camera(device: &CameraDevice) -> bool {
+    match grab_test_frame(device) {
+        Ok((_, true)) => true,
+        Ok((_, false)) => {
+            log::info!("camera {:?} only delivered uniform frames", device.label);
+            false
+        }
+        Err(err) => {
+            log::info!("camera {:?} failed the probe: {err:#}", device.label);
+            false
+        }
+    }
+}
+
+/// Opens `device`, reads until a frame shows a picture or [`PROBE_FRAMES`]
+/// came in, and closes it again. Returns the last frame read and whether it
+/// had any contrast. The read runs on its own thread so a driver that hangs
+/// only costs [`PROBE_TIMEOUT`]; the reader is told to stop then, and one
+/// stuck in the driver is parked until it lets go of the camera.
+pub fn grab_test_frame(device: &CameraDevice) -> Result<(Frame, bool)> {
+    let (done_tx, done_rx) = bounded(1);
+    let stop = Arc::new(AtomicBool::new(false));
+    let reader = {
+        let index = device.index.clone();
+        let stop = Arc::clone(&stop);
+        thread::spawn(move || {
+            let _ = done_tx.send(read_test_frame(index, &stop));
+        })
+    };
+    match done_rx.recv_timeout(PROBE_TIMEOUT) {
+        Ok(result) => {
+            let _ = reader.join();
+            result
+        }
+        Err(_) => {
+            stop.store(true, Ordering::Relaxed);
+            release_probe(device.index.clone(), reader);
+            Err(anyhow!("no frame within {:?}", PROBE_TIMEOUT))
+        }
+    }
+}
+
+/// Joins a timed-out probe once it closes its camera, or parks it in
+/// [`STRAY_PROBES`] when it is still stuck after [`PROBE_RELEASE_TIMEOUT`].
+fn release_probe(index: CameraIndex, reader: JoinHandle<()>) {
+    let deadline = Instant::now() + PROBE_RELEASE_TIMEOUT;
+    while !reader.is_finished() && Instant::now() < deadline {
+        thread::sleep(PROBE_RELEASE_POLL);
+    }
+    if reader.is_finished() {
+        let _ = reader.join();
+    } else {
+        log::warn!("camera {index} probe is stuck in the driver, parking it");
+        lock_stray_probes().push((index, reader));
+    }
+}
+
+/// Waits up to [`PROBE_TIMEOUT`] for a parked probe of `index` to close the
+/// camera, so opening it again does not fail on a device that is still busy.
+fn wait_for_stray_probe(index: &CameraIndex) -> Result<()> {
+    let deadline = Instant::now() + PROBE_TIMEOUT;
+    loop {
+        let mut stray = lock_stray_probes();
+        let (finished, running) = stray
+            .drain(..)
+            .partition::<Vec<_>, _>(|(_, reader)| reader.is_finished());
+        *stray = running;
+        let held = stray.iter().any(|(held, _)| held == index);
+        drop(stray);
+        for (_, reader) in finished {
+            let _ = reader.join();
+        }
+        if !held {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            bail!("camera {index} is still held by a stuck probe");
+        }
+        thread::sleep(PROBE_RELEASE_POLL);
+    }
+}
+
+fn lock_stray_probes() -> std::sync::MutexGuard<'static, Vec<(CameraIndex, JoinHandle<()>)>> {
+    STRAY_PROBES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Only the contrast part of the frame-quality check: a dark room is still a
+/// live camera, an all-black or single-color frame is not.
+fn read_test_frame(index: CameraIndex, stop: &AtomicBool) -> Result<(Frame, bool)> {
+    let mut camera = build_camera(index, None)?;
+    let pool = FramePool::new(1);
+    first_picture(|| {
+        if stop.load(Ordering::Relaxed) {
+            bail!("the probe timed out");
+        }
+        let buffer = camera.frame().context("failed to read a frame")?;
+        let converted = rgb_converter::convert_camera_frame(&buffer, &pool)?;
+        let now = Instant::now();
+        Ok(Frame {
+            pixels: converted.rgb,
+            layout: PixelLayout::Rgb,
+            width: converted.width,
+            height: converted.height,
+            timestamp: now,
+            acquired_at: now,
+        })
+    })
+}
+
+/// Reads frames until one shows a picture or [`PROBE_FRAMES`] came in.
+fn first_picture(mut next_frame: impl FnMut() -> Result<Frame>) -> Result<(Frame, bool)> {
+    let uniform_only = QualityGateConfig {
+        min_luma: 0,
+        max_luma: u8::MAX,
+        ..QualityGateConfig::default()
+    };
+    let mut frames_read = 0;
+    loop {
+        let frame = next_frame()?;
+        frames_read += 1;
+        let shows_picture = frame_quality(&frame, &uniform_only) == FrameQuality::Good;
+        if shows_picture || frames_read >= PROBE_FRAMES {
+            return Ok((frame, shows_picture));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn backoff() -> ReconnectBackoff {
+        ReconnectBackoff::new(3, Duration::from_millis(100), Duration::from_millis(1_000))
+    }
+
+    #[test]
+    fn only_enough_failures_in_a_row_call_for_a_reconnect() {
+        let mut backoff = backoff();
+        assert!(!backoff.frame_failed());
+        assert!(!backoff.frame_failed());
+        // A good frame in between starts the count over.
+        backoff.frame_ok();
+        assert!(!backoff.frame_failed());
+        assert!(!backoff.frame_failed());
+        assert!(backoff.frame_failed());
+        assert!(backoff.frame_failed());
+    }
+
+    #[test]
+    fn the_delay_doubles_up_to_the_maximum() {
+        let mut backoff = backoff();
+        let delays: Vec<u64> = (0..7)
+            .map(|_| backoff.next_delay().as_millis() as u64)
+            .collect();
+        assert_eq!(delays, [100, 200, 400, 800, 1_000, 1_000, 1_000]);
+        assert_eq!(backoff.attempt(), 7);
+
+        // Far past the doublings a u32 shift allows.
+        for _ in 0..40 {
+            backoff.next_delay();
+        }
+        assert_eq!(backoff.next_delay(), Duration::from_millis(1_000));
+    }
+
+    #[test]
+    fn reconnecting_starts_the_next_outage_afresh() {
+        let mut backoff = backoff();
+        for _ in 0..3 {
+            backoff.frame_failed();
+            backoff.next_delay();
+        }
+        backoff.connected();
+        assert_eq!(backoff.attempt(), 0);
+        assert_eq!(backoff.next_delay(), Duration::from_millis(100));
+        assert!(!backoff.frame_failed());
+    }
+
+    #[test]
+    fn a_zero_threshold_reconnects_on_the_first_failure() {
+        let mut backoff = ReconnectBackoff::new(0, Duration::ZERO, Duration::ZERO);
+        assert!(backoff.frame_failed());
+    }
+
+    /// A 64×48 frame, uniform at `shade` or with a bright square on it.
+    fn test_frame(shade: u8, square: bool) -> Frame {
+        let (width, height) = (64, 48);
+        let mut pixels = vec![shade; PixelLayout::Rgb.buffer_len(width, height)];
+        if square {
+            for y in 16..32 {
+                let row = (y * width as usize) * 3;
+                pixels[row + 48..row + 96].fill(200);
+            }
+        }
+        let now = Instant::now();
+        Frame {
+            pixels: pixels.into(),
+            layout: PixelLayout::Rgb,
+            width,
+            height,
+            timestamp: now,
+            acquired_at: now,
+        }
+    }
+
+    #[test]
+    fn probing_stops_at_the_first_live_camera() {
+        let (probe_tx, probe_rx) = bounded(8);
+        let live = [false, false, true, true];
+        assert_eq!(first_live(&live, &probe_tx, |live| *live), Some(2));
+        let tried: Vec<usize> = probe_rx
+            .try_iter()
+            .map(|probe| match probe {
+                CameraProbe::Trying(idx) => idx,
+                other => panic!("unexpected {other:?}"),
+            })
+            .collect();
+        assert_eq!(tried, [0, 1, 2]);
+
+        assert_eq!(first_live(&[false, false], &probe_tx, |live| *live), None);
+        assert_eq!(probe_rx.try_iter().count(), 2);
+    }
+
+    #[test]
+    fn a_camera_warming_up_passes_once_it_shows_a_picture() {
+        let mut frames = (0..).map(|idx| test_frame(0, idx >= 3));
+        let mut reads = 0;
+        let (frame, live) = first_picture(|| {
+            reads += 1;
+            Ok(frames.next().unwrap())
+        })
+        .unwrap();
+        assert!(live);
+        assert_eq!(reads, 4);
+        assert_eq!(frame.pixels[(20 * 64 + 20) * 3], 200);
+    }
+
+    #[test]
+    fn uniform_frames_fail_the_probe_but_a_dark_picture_passes() {
+        for shade in [0, 128, 255] {
+            let mut reads = 0;
+            let (_, live) = first_picture(|| {
+                reads += 1;
+                Ok(test_frame(shade, false))
+            })
+            .unwrap();
+            assert!(!live, "shade {shade}");
+            assert_eq!(reads, PROBE_FRAMES);
+        }
+
+        // Too dark for recognition, but a camera showing a dim room is live.
+        let mut dim = test_frame(0, false);
+        for (idx, value) in dim.pixels.iter_mut().enumerate() {
+            *value = ((idx / 3) % 64 / 2) as u8;
+        }
+        let (_, live) = first_picture(|| Ok(dim.clone())).unwrap();
+        assert!(live);
+    }
+
+    #[test]
+    fn a_read_error_fails_the_probe() {
+        assert!(first_picture(|| Err(anyhow!("device busy"))).is_err());
+    }
+
+    #[test]
+    fn a_stuck_probe_holds_only_its_own_camera_until_it_finishes() {
+        let stuck = CameraIndex::Index(9_001);
+        let reader = thread::spawn(|| thread::sleep(PROBE_RELEASE_TIMEOUT * 2));
+        release_probe(stuck.clone(), reader);
+        assert!(lock_stray_probes().iter().any(|(held, _)| *held == stuck));
+
+        let started = Instant::now();
+        wait_for_stray_probe(&CameraIndex::Index(9_002)).unwrap();
+        assert!(started.elapsed() < PROBE_RELEASE_TIMEOUT);
+
+        wait_for_stray_probe(&stuck).unwrap();
+        assert!(lock_stray_probes().iter().all(|(held, _)| *held != stuck));
+    }
+
+    #[test]
+    fn a_probe_that_stops_in_time_is_joined() {
+        let index = CameraIndex::Index(9_003);
+        release_probe(index.clone(), thread::spawn(|| {}));
+        assert!(lock_stray_probes().iter().all(|(held, _)| *held != index));
+    }
+}
@@ -0,0 +1,60 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use crossbeam_channel::{Receiver, bounded};
+use image::ExtendedColorType;
+
+use super::recorder::RecordedFrame;
+use crate::types::{Frame, GestureResult, PixelLayout};
+
+pub const DEFAULT_CAPTURES_DIR: &str = "captures";
+
+/// Writes `frame` as `capture-<unix ms>.png` into `dir`, with the result next
+/// to it as `capture-<unix ms>.json`. Encoding runs on its own thread; the
+/// receiver yields the PNG path once both files are on disk.
+pub fn spawn_capture(
+    dir: PathBuf,
+    frame: Frame,
+    result: GestureResult,
+) -> Receiver<Result<PathBuf>> {
+    let (done_tx, done_rx) = bounded(1);
+    thread::spawn(move || {
+        let _ = done_tx.send(write_capture(&dir, &frame, result));
+    });
+    done_rx
+}
+
+fn write_capture(dir: &Path, frame: &Frame, result: GestureResult) -> Result<PathBuf> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("failed to create captures directory {}", dir.display()))?;
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let image = format!("capture-{stamp}.png");
+    let image_path = dir.join(&image);
+    let color = match frame.layout {
+        PixelLayout::Rgb => ExtendedColorType::Rgb8,
+        PixelLayout::Rgba => ExtendedColorType::Rgba8,
+    };
+    image::save_buffer(&image_path, &frame.pixels, frame.width, frame.height, color)
+        .with_context(|| format!("failed to write {}", image_path.display()))?;
+
+    // Same shape as a recording index line, so capture and session tooling can
+    // share a reader.
+    let entry = RecordedFrame {
+        image,
+        width: frame.width,
+        height: frame.height,
+        result,
+    };
+    let json_path = image_path.with_extension("json");
+    fs::write(&json_path, serde_json::to_string_pretty(&entry)?)
+        .with_context(|| format!("failed to write {}", json_path.display()))?;
+    Ok(image_path)
+}
@@ -0,0 +1,274 @@
+use std::{
+    collections::VecDeque,
+    fmt, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::{Arc, Mutex, MutexGuard},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result, anyhow};
+use crossbeam_channel::{Receiver, Sender, unbounded};
+use fast_image_resize as fir;
+use serde::{Deserialize, Serialize};
+
+use crate::types::Frame;
+
+/// How far back an exported clip reaches.
+pub const CLIP_DURATION: Duration = Duration::from_secs(5);
+const CLIP_FPS: u32 = 15;
+/// Long side of the kept frames; 5 s of 640x480 RGB at 15 fps is about 69 MB.
+const CLIP_MAX_DIMENSION: u32 = 640;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipFormat {
+    /// H.264, for sharing.
+    #[default]
+    Mp4,
+    Gif,
+}
+
+impl ClipFormat {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ClipFormat::Mp4 => "MP4",
+            ClipFormat::Gif => "GIF",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            ClipFormat::Mp4 => ClipFormat::Gif,
+            ClipFormat::Gif => ClipFormat::Mp4,
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            ClipFormat::Mp4 => "mp4",
+            ClipFormat::Gif => "gif",
+        }
+    }
+
+    /// ffmpeg options between the raw input and the output path.
+    fn encoder_args(&self) -> &'static [&'static str] {
+        match self {
+            ClipFormat::Mp4 => &[
+                "-c:v",
+                "libx264",
+                "-pix_fmt",
+                "yuv420p",
+                "-movflags",
+                "+faststart",
+            ],
+            // A palette built from the clip itself instead of the web-safe one.
+            ClipFormat::Gif => &["-vf", "split[a][b];[a]palettegen[p];[b][p]paletteuse"],
+        }
+    }
+}
+
+/// A downscaled composited frame kept for export. The pixels are shared, so
+/// taking a snapshot does not copy them.
+#[derive(Clone)]
+pub struct ClipFrame {
+    rgb: Arc<[u8]>,
+    width: u32,
+    height: u32,
+    at: Instant,
+}
+
+/// The last [`CLIP_DURATION`] of composited frames at up to 15 fps, fed by the
+/// compositor; cloning shares the same ring.
+#[derive(Clone, Default)]
+pub struct ClipBuffer {
+    inner: Arc<Mutex<ClipRing>>,
+}
+
+struct ClipRing {
+    frames: VecDeque<ClipFrame>,
+    resizer: fir::Resizer,
+}
+
+impl Default for ClipRing {
+    fn default() -> Self {
+        Self {
+            frames: VecDeque::new(),
+            resizer: fir::Resizer::new(),
+        }
+    }
+}
+
+impl fmt::Debug for ClipBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClipBuffer")
+            .field("frames", &self.lock().frames.len())
+            .finish()
+    }
+}
+
+impl ClipBuffer {
+    /// Keeps a downscaled copy of `frame` unless one was kept less than a
+    /// frame interval ago. Runs on the compositor thread, so it only resizes.
+    pub fn offer(&self, frame: &Frame) {
+        let at = frame.timestamp;
+        let interval = Duration::from_secs(1) / CLIP_FPS;
+        let mut ring = self.lock();
+        let recent = ring
+            .frames
+            .back()
+            .is_some_and(|last| at.saturating_duration_since(last.at) < interval);
+        if recent {
+            return;
+        }
+
+        match ring.downscale(frame) {
+            Ok(kept) => ring.frames.push_back(kept),
+            Err(err) => {
+                log::debug!("clip buffer skipped a frame: {err:#}");
+                return;
+            }
+        }
+        while ring
+            .frames
+            .front()
+            .is_some_and(|first| at.saturating_duration_since(first.at) > CLIP_DURATION)
+        {
+            ring.frames.pop_front();
+        }
+    }
+
+    /// The kept frames, oldest first.
+    pub fn snapshot(&self) -> Vec<ClipFrame> {
+        self.lock().frames.iter().cloned().collect()
+    }
+
+    /// Drops every kept frame.
+    pub fn clear(&self) {
+        self.lock().frames.clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ClipRing> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl ClipRing {
+    /// Even sides, which the H.264 chroma layout needs.
+    fn downscale(&mut self, frame: &Frame) -> Result<ClipFrame> {
+        let long_side = frame.width.max(frame.height);
+        let ratio = (CLIP_MAX_DIMENSION as f32 / long_side as f32).min(1.0);
+        let width = ((frame.width as f32 * ratio) as u32 & !1).max(2);
+        let height = ((frame.height as f32 * ratio) as u32 & !1).max(2);
+
+        let rgb = frame.to_rgb();
+        let src = fir::images::ImageRef::new(frame.width, frame.height, &rgb, fir::PixelType::U8x3)
+            .context("frame buffer does not match its size")?;
+        let mut dst = fir::images::Image::new(width, height, fir::PixelType::U8x3);
+        let options = fir::ResizeOptions::new()
+            .resize_alg(fir::ResizeAlg::Interpolation(fir::FilterType::Bilinear));
+        self.resizer
+            .resize(&src, &mut dst, Some(&options))
+            .context("clip downscale failed")?;
+        Ok(ClipFrame {
+            rgb: dst.into_vec().into(),
+            width,
+            height,
+            at: frame.timestamp,
+        })
+    }
+}
+
+/// Progress of a clip being written, then where it went.
+#[derive(Debug)]
+pub enum ClipExport {
+    Progress { written: usize, total: usize },
+    Done(Result<PathBuf>),
+}
+
+/// Encodes `frames` through the `ffmpeg` binary as `clip-<unix ms>.<ext>` in
+/// `dir`, on its own thread.
+pub fn spawn_clip_export(
+    dir: PathBuf,
+    frames: Vec<ClipFrame>,
+    format: ClipFormat,
+) -> Receiver<ClipExport> {
+    let (progress_tx, progress_rx) = unbounded();
+    thread::spawn(move || {
+        let done = write_clip(&dir, &frames, format, &progress_tx);
+        let _ = progress_tx.send(ClipExport::Done(done));
+    });
+    progress_rx
+}
+
+fn write_clip(
+    dir: &Path,
+    frames: &[ClipFrame],
+    format: ClipFormat,
+    progress_tx: &Sender<ClipExport>,
+) -> Result<PathBuf> {
+    // A camera switch changes the size part way through; keep the frames
+    // since then.
+    let last = frames
+        .last()
+        .ok_or_else(|| anyhow!("no frames to export yet"))?;
+    let (width, height) = (last.width, last.height);
+    let start = frames
+        .iter()
+        .rposition(|frame| (frame.width, frame.height) != (width, height))
+        .map_or(0, |idx| idx + 1);
+    let frames = &frames[start..];
+
+    fs::create_dir_all(dir)
+        .with_context(|| format!("failed to create captures directory {}", dir.display()))?;
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let path = dir.join(format!("clip-{stamp}.{}", format.extension()));
+
+    let mut child = Command::new("ffmpeg")
+        .args([
+            "-v", "error", "-y", "-f", "rawvideo", "-pix_fmt", "rgb24", "-s",
+        ])
+        .arg(format!("{width}x{height}"))
+        .args(["-r", &CLIP_FPS.to_string(), "-i", "-"])
+        .args(format.encoder_args())
+        .arg(&path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("failed to run ffmpeg, is it installed?")?;
+    let mut stdin = child
+        .stdin
+        .take()
+        .ok_or_else(|| anyhow!("ffmpeg stdin unavailable"))?;
+    for (idx, frame) in frames.iter().enumerate() {
+        // A write error means ffmpeg gave up; its exit status says why.
+        if stdin.write_all(&frame.rgb).is_err() {
+            break;
+        }
+        let _ = progress_tx.send(ClipExport::Progress {
+            written: idx + 1,
+            total: frames.len(),
+        });
+    }
+    drop(stdin);
+
+    let output = child
+        .wait_with_output()
+        .context("failed to wait for ffmpeg")?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "ffmpeg failed to write {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(path)
+}
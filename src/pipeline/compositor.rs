@@ -1,70 +1,502 @@
 use std::{
+    collections::HashSet,
+    path::PathBuf,
+    sync::Arc,
     thread,
     time::{Duration, Instant},
 };
 
+use anyhow::{Context, Result};
 use crossbeam_channel::{Receiver, Sender};
+use fast_image_resize as fir;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    pipeline::skeleton,
-    types::{Frame, GestureResult, RecognizedFrame},
+    gesture::HoldConfirm,
+    locale::tr,
+    pipeline::{
+        camera::FRAME_POOL_IDLE,
+        clip::ClipBuffer,
+        latest::recv_latest,
+        metrics::{Channel, MetricsHandle, Stage},
+        photo_booth::{BoothOverlay, PhotoBooth, PhotoBoothConfig, PhotoBoothEvent},
+        recognizer::common::fir_pixel_type,
+        skeleton::{self, LabelPainter, OverlayMode, SkeletonStyle},
+    },
+    types::{
+        DwellProgress, Frame, FramePool, GestureKind, GestureResult, HandResult, PalmRegion,
+        RecognizedFrame, RecognizerConfig,
+    },
 };
 
 const MAX_COMPOSITED_FPS: u64 = 30;
 const MIN_COMPOSITED_FPS: u64 = 12;
 const SLOWDOWN_FACTOR: f64 = 1.25;
 const RECOVERY_FACTOR: f64 = 0.85;
-const OVERLAY_CONFIDENCE_THRESHOLD: f32 = 0.2;
+/// What privacy mode draws the overlay onto instead of the camera image.
+pub const PRIVACY_BACKGROUND: [u8; 3] = [0x0f, 0x17, 0x2a];
+
+/// Largest frame the compositor hands on. A bigger one is scaled down to fit,
+/// aspect ratio kept, before the overlay is drawn, so lines stay sharp and
+/// the UI never uploads more than this; a smaller one passes through as is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputSize {
+    /// Whatever the camera delivers.
+    #[default]
+    Native,
+    Fit1080p,
+    Fit720p,
+    Fit480p,
+}
+
+impl OutputSize {
+    pub const ALL: [OutputSize; 4] = [
+        OutputSize::Native,
+        OutputSize::Fit1080p,
+        OutputSize::Fit720p,
+        OutputSize::Fit480p,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            OutputSize::Native => tr("原始", "Native"),
+            OutputSize::Fit1080p => "1920×1080",
+            OutputSize::Fit720p => "1280×720",
+            OutputSize::Fit480p => "854×480",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|size| *size == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    /// The box frames are fit into, `None` for the native size.
+    pub fn bounds(self) -> Option<(u32, u32)> {
+        match self {
+            OutputSize::Native => None,
+            OutputSize::Fit1080p => Some((1920, 1080)),
+            OutputSize::Fit720p => Some((1280, 720)),
+            OutputSize::Fit480p => Some((854, 480)),
+        }
+    }
+
+    /// The size a `width` by `height` frame leaves the compositor at.
+    pub fn fit(self, (width, height): (u32, u32)) -> (u32, u32) {
+        let Some((max_width, max_height)) = self.bounds() else {
+            return (width, height);
+        };
+        let ratio =
+            (max_width as f32 / width.max(1) as f32).min(max_height as f32 / height.max(1) as f32);
+        if ratio >= 1.0 {
+            return (width, height);
+        }
+        (
+            (width as f32 * ratio).round().max(1.0) as u32,
+            (height as f32 * ratio).round().max(1.0) as u32,
+        )
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct CompositedFrame {
     pub frame: Frame,
     pub result: GestureResult,
+    /// Set when the camera pixels were replaced by [`PRIVACY_BACKGROUND`].
+    pub blanked: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct CompositorSettings {
+    pub overlay_threshold: f32,
+    pub depth_overlay: bool,
+    pub skeleton_style: SkeletonStyle,
+    pub overlay_mode: OverlayMode,
+    /// Hands showing one of these are pixelated instead of drawn. Empty by
+    /// default.
+    pub censor_gestures: HashSet<GestureKind>,
+    /// Draws the gesture and motion into the pixels, for consumers that only
+    /// see the frame.
+    pub burn_in_label: bool,
+    /// Font for the burned-in label; a system CJK font is looked up if unset.
+    pub label_font: Option<PathBuf>,
+    /// Extra consumer of every composited frame, e.g. the virtual camera.
+    /// Full or closed taps never hold up the compositor.
+    pub frame_tap: Option<Sender<Frame>>,
+    /// Recognition zone, outlined faintly while the overlay is on.
+    pub roi: Option<[f32; 4]>,
+    /// Draws where pointing hands are aimed while the overlay is on.
+    pub pointing_ray: bool,
+    /// Keeps the last few seconds of composited frames for clip export.
+    pub clip_buffer: Option<ClipBuffer>,
+    /// Countdown photos and where to send them; unset while the booth is off.
+    pub photo_booth: Option<(PhotoBoothConfig, Sender<PhotoBoothEvent>)>,
+    pub output_size: OutputSize,
+    /// Unset while no action binding waits for a held gesture.
+    pub hold_confirm: Option<HoldConfirmSettings>,
+    /// Draws everything onto a blank frame instead of the camera image, and
+    /// keeps nothing for clips or the photo booth.
+    pub privacy_mode: bool,
+}
+
+/// Action bindings that fire only once their gesture has been held, timed
+/// here so the compositor can draw the progress around the hand.
+#[derive(Clone, Debug)]
+pub struct HoldConfirmSettings {
+    /// `(binding index, gesture, hold time)`.
+    pub bindings: Vec<(usize, GestureKind, Duration)>,
+    /// Gets the binding index of every completed hold.
+    pub confirmed: Sender<usize>,
+}
+
+impl Default for CompositorSettings {
+    fn default() -> Self {
+        Self {
+            overlay_threshold: RecognizerConfig::default().overlay_threshold,
+            depth_overlay: RecognizerConfig::default().depth_overlay,
+            skeleton_style: SkeletonStyle::default(),
+            overlay_mode: OverlayMode::default(),
+            censor_gestures: HashSet::new(),
+            burn_in_label: false,
+            label_font: None,
+            frame_tap: None,
+            roi: None,
+            pointing_ray: false,
+            clip_buffer: None,
+            photo_booth: None,
+            output_size: OutputSize::Native,
+            hold_confirm: None,
+            privacy_mode: false,
+        }
+    }
+}
+
+impl CompositorSettings {
+    pub fn with_skeleton_style(mut self, style: SkeletonStyle) -> Self {
+        self.skeleton_style = style;
+        self
+    }
+
+    pub fn with_overlay_mode(mut self, mode: OverlayMode) -> Self {
+        self.overlay_mode = mode;
+        self
+    }
+
+    pub fn with_censor_gestures(mut self, gestures: HashSet<GestureKind>) -> Self {
+        self.censor_gestures = gestures;
+        self
+    }
+
+    pub fn with_burn_in_label(mut self, enabled: bool, font: Option<PathBuf>) -> Self {
+        self.burn_in_label = enabled;
+        self.label_font = font;
+        self
+    }
+
+    pub fn with_frame_tap(mut self, tap: Option<Sender<Frame>>) -> Self {
+        self.frame_tap = tap;
+        self
+    }
+
+    pub fn with_roi(mut self, roi: Option<[f32; 4]>) -> Self {
+        self.roi = roi;
+        self
+    }
+
+    pub fn with_pointing_ray(mut self, enabled: bool) -> Self {
+        self.pointing_ray = enabled;
+        self
+    }
+
+    pub fn with_clip_buffer(mut self, buffer: ClipBuffer) -> Self {
+        self.clip_buffer = Some(buffer);
+        self
+    }
+
+    /// Leaves the booth off unless `config` enables it.
+    pub fn with_photo_booth(
+        mut self,
+        config: PhotoBoothConfig,
+        events: Sender<PhotoBoothEvent>,
+    ) -> Self {
+        self.photo_booth = config.enabled.then_some((config, events));
+        self
+    }
+
+    pub fn with_output_size(mut self, size: OutputSize) -> Self {
+        self.output_size = size;
+        self
+    }
+
+    pub fn with_hold_confirm(
+        mut self,
+        bindings: Vec<(usize, GestureKind, Duration)>,
+        confirmed: Sender<usize>,
+    ) -> Self {
+        self.hold_confirm = (!bindings.is_empty()).then_some(HoldConfirmSettings {
+            bindings,
+            confirmed,
+        });
+        self
+    }
+
+    pub fn with_privacy_mode(mut self, enabled: bool) -> Self {
+        self.privacy_mode = enabled;
+        self
+    }
+}
+
+impl From<&RecognizerConfig> for CompositorSettings {
+    fn from(config: &RecognizerConfig) -> Self {
+        Self {
+            overlay_threshold: config.overlay_threshold,
+            depth_overlay: config.depth_overlay,
+            skeleton_style: SkeletonStyle::default(),
+            overlay_mode: OverlayMode::default(),
+            censor_gestures: HashSet::new(),
+            burn_in_label: false,
+            label_font: None,
+            frame_tap: None,
+            roi: None,
+            pointing_ray: false,
+            clip_buffer: None,
+            photo_booth: None,
+            output_size: OutputSize::Native,
+            hold_confirm: None,
+            privacy_mode: false,
+        }
+    }
 }
 
 pub fn start_frame_compositor(
     recognized_rx: Receiver<RecognizedFrame>,
+    settings: CompositorSettings,
+    settings_rx: Receiver<CompositorSettings>,
+    metrics: MetricsHandle,
 ) -> (Receiver<CompositedFrame>, thread::JoinHandle<()>) {
     let (tx, rx) = crossbeam_channel::bounded(1);
-    let handle = thread::spawn(move || compositor_loop(recognized_rx, tx));
+    let handle =
+        thread::spawn(move || compositor_loop(recognized_rx, settings, settings_rx, tx, metrics));
     (rx, handle)
 }
 
 fn compositor_loop(
     recognized_rx: Receiver<RecognizedFrame>,
+    mut settings: CompositorSettings,
+    settings_rx: Receiver<CompositorSettings>,
     composited_tx: Sender<CompositedFrame>,
+    metrics: MetricsHandle,
 ) {
     let min_interval = Duration::from_millis(1_000 / MAX_COMPOSITED_FPS);
     let max_interval = Duration::from_millis(1_000 / MIN_COMPOSITED_FPS);
     let mut target_interval = min_interval;
+    // Loaded on first use and again when the font setting changes; a failed
+    // load is not retried until then.
+    let mut label: Option<(Option<PathBuf>, Option<LabelPainter>)> = None;
+    let mut booth: Option<PhotoBooth> = None;
+    let mut scaler = OutputScaler::default();
+    let mut holds: Vec<((usize, GestureKind, Duration), HoldConfirm)> = Vec::new();
 
-    while let Ok(mut recognized) = recognized_rx.recv() {
-        while let Ok(newer) = recognized_rx.try_recv() {
-            recognized = newer;
+    while let Some((recognized, skipped)) = recv_latest(&recognized_rx) {
+        metrics.record_skips(Channel::Recognized, skipped);
+        while let Ok(newer) = settings_rx.try_recv() {
+            settings = newer;
+        }
+        // The booth saves the camera image itself, so privacy mode stops it.
+        let booth_config = settings
+            .photo_booth
+            .as_ref()
+            .filter(|_| !settings.privacy_mode)
+            .map(|(config, _)| *config);
+        if booth.as_ref().map(PhotoBooth::config) != booth_config {
+            booth = settings
+                .photo_booth
+                .clone()
+                .filter(|_| !settings.privacy_mode)
+                .map(|(config, events)| PhotoBooth::new(config, events));
+        }
+        let hold_bindings = settings
+            .hold_confirm
+            .as_ref()
+            .map_or(&[][..], |hold_confirm| hold_confirm.bindings.as_slice());
+        if !holds.iter().map(|(binding, _)| binding).eq(hold_bindings) {
+            holds = hold_bindings
+                .iter()
+                .map(|&binding| (binding, HoldConfirm::new(binding.1, binding.2)))
+                .collect();
         }
 
-        let mut frame = recognized.frame;
-        let result = recognized.result;
+        let mut result = recognized.result;
 
         let compose_start = Instant::now();
-        if !result.palm_regions.is_empty() {
+        // Before anything is drawn, since a shot saves the clean frame, and
+        // before scaling, so it saves it at full size.
+        let booth_overlay = booth
+            .as_mut()
+            .map(|booth| booth.observe(&recognized.frame, &result));
+        let scaled = scaler
+            .fit(&recognized.frame, &mut result, settings.output_size)
+            .unwrap_or_else(|err| {
+                log::warn!("output scaling failed, keeping the full frame: {err:#}");
+                None
+            });
+        let mut frame = scaled.unwrap_or(recognized.frame);
+        metrics.record_output_size((frame.width, frame.height));
+        let blanked = settings.privacy_mode;
+        if blanked {
+            blank(&mut frame);
+        }
+        // After scaling, so the ring is placed on the scaled wrist.
+        let mut hold_ring: Option<DwellProgress> = None;
+        for ((idx, ..), hold) in &mut holds {
+            let Some(progress) = hold.observe(&result) else {
+                continue;
+            };
+            let confirmed = settings
+                .hold_confirm
+                .as_ref()
+                .filter(|_| progress.completed);
+            if let Some(hold_confirm) = confirmed {
+                let _ = hold_confirm.confirmed.try_send(*idx);
+            }
+            if hold_ring.is_none_or(|ring| progress.progress > ring.progress) {
+                hold_ring = Some(progress);
+            }
+        }
+        let mode = settings.overlay_mode;
+        if let Some(roi) = settings.roi.filter(|_| mode != OverlayMode::None) {
+            skeleton::draw_roi(
+                &mut frame.pixels,
+                frame.width,
+                frame.height,
+                roi,
+                &settings.skeleton_style,
+            );
+        }
+        let palm_debug = result
+            .palm_debug
+            .as_ref()
+            .filter(|_| mode == OverlayMode::Debug);
+        if mode.draws_boxes() && (!result.palm_regions.is_empty() || palm_debug.is_some()) {
             skeleton::draw_palm_regions(
-                &mut frame.rgba,
+                &mut frame.pixels,
                 frame.width,
                 frame.height,
                 &result.palm_regions,
+                palm_debug,
+                &settings.skeleton_style,
             );
         }
-        if let Some(points) = overlay_points(&result) {
-            skeleton::draw_skeleton(&mut frame.rgba, frame.width, frame.height, points);
+        for hand in overlay_hands(&result, settings.overlay_threshold) {
+            if is_censored(hand, &settings.censor_gestures) {
+                continue;
+            }
+            let depths = settings
+                .depth_overlay
+                .then(|| landmark_depths(hand))
+                .flatten();
+            skeleton::draw_hand(
+                &mut frame.pixels,
+                frame.width,
+                frame.height,
+                &hand.landmarks,
+                depths.as_deref(),
+                &settings.skeleton_style.for_confidence(hand.confidence),
+                mode,
+            );
+            let pointing = hand
+                .detail
+                .as_ref()
+                .and_then(|detail| detail.point_direction)
+                .filter(|_| settings.pointing_ray && mode != OverlayMode::None);
+            if let Some(pointing) = pointing {
+                skeleton::draw_pointing_ray(
+                    &mut frame.pixels,
+                    frame.width,
+                    frame.height,
+                    &hand.landmarks,
+                    &pointing,
+                    &settings.skeleton_style,
+                );
+            }
+        }
+        let dwell = result.dwell.as_ref().filter(|_| mode != OverlayMode::None);
+        if let (Some(dwell), Some(points)) = (dwell, &result.landmarks) {
+            skeleton::draw_dwell_ring(
+                &mut frame.pixels,
+                frame.width,
+                frame.height,
+                dwell,
+                points,
+                &settings.skeleton_style,
+            );
+        }
+        // Drawn whatever the overlay mode, since it warns of an action.
+        if let (Some(ring), Some(points)) = (&hold_ring, &result.landmarks) {
+            skeleton::draw_dwell_ring(
+                &mut frame.pixels,
+                frame.width,
+                frame.height,
+                ring,
+                points,
+                &settings.skeleton_style,
+            );
+        }
+        // After everything else, so palm boxes over the hand are covered too.
+        for hand in &result.hands {
+            if is_censored(hand, &settings.censor_gestures)
+                && let Some(bbox) = censor_box(hand, &result.palm_regions)
+            {
+                skeleton::pixelate_region(&mut frame.pixels, frame.width, frame.height, bbox);
+            }
+        }
+        if settings.burn_in_label {
+            let stale = label
+                .as_ref()
+                .is_none_or(|(font, _)| *font != settings.label_font);
+            if stale {
+                let painter = LabelPainter::load(settings.label_font.as_deref())
+                    .inspect_err(|err| log::warn!("gesture label not burned in: {err:#}"))
+                    .ok();
+                label = Some((settings.label_font.clone(), painter));
+            }
+            if let Some((_, Some(painter))) = &mut label {
+                painter.draw(
+                    &mut frame.pixels,
+                    frame.width,
+                    frame.height,
+                    &label_lines(&result),
+                );
+            }
+        }
+        if let Some(overlay) = booth_overlay {
+            draw_booth_overlay(&mut frame, &result, &overlay, &settings.skeleton_style);
         }
         let compose_time = compose_start.elapsed();
+        metrics.record_stage(Stage::Compose, compose_time);
 
+        if let Some(tap) = settings.frame_tap.as_ref().filter(|tap| !tap.is_full()) {
+            let _ = tap.try_send(frame.clone());
+        }
+        if let Some(clip_buffer) = &settings.clip_buffer {
+            // Emptied too, so frames kept before the mode was turned on go.
+            if blanked {
+                clip_buffer.clear();
+            } else {
+                clip_buffer.offer(&frame);
+            }
+        }
+        metrics.record_stage(Stage::EndToEnd, frame.acquired_at.elapsed());
         let packet = CompositedFrame {
             frame,
             result: result.clone(),
+            blanked,
         };
         let dropped_frame = composited_tx.try_send(packet).is_err();
+        if dropped_frame {
+            metrics.record_drop(Channel::Composited);
+        }
 
         target_interval = adjust_interval(
             target_interval,
@@ -73,14 +505,113 @@ fn compositor_loop(
             max_interval,
             dropped_frame,
         );
-        if let Some(sleep_for) = target_interval.checked_sub(compose_time) {
-            if !sleep_for.is_zero() {
-                thread::sleep(sleep_for);
-            }
+        if let Some(sleep_for) = target_interval.checked_sub(compose_time)
+            && !sleep_for.is_zero()
+        {
+            thread::sleep(sleep_for);
+        }
+    }
+}
+
+/// Paints every pixel of `frame` [`PRIVACY_BACKGROUND`], opaque.
+fn blank(frame: &mut Frame) {
+    let channels = frame.layout.channels();
+    for pixel in frame.pixels.chunks_exact_mut(channels) {
+        pixel[..3].copy_from_slice(&PRIVACY_BACKGROUND);
+        if let Some(alpha) = pixel.get_mut(3) {
+            *alpha = 255;
         }
     }
 }
 
+/// Scales frames down to the output size, reusing its buffers between frames.
+struct OutputScaler {
+    resizer: fir::Resizer,
+    pool: Arc<FramePool>,
+}
+
+impl Default for OutputScaler {
+    fn default() -> Self {
+        Self {
+            resizer: fir::Resizer::new(),
+            pool: FramePool::new(FRAME_POOL_IDLE),
+        }
+    }
+}
+
+impl OutputScaler {
+    /// The scaled copy of `frame`, with every pixel coordinate in `result`
+    /// moved onto it, or `None` when the frame already fits.
+    fn fit(
+        &mut self,
+        frame: &Frame,
+        result: &mut GestureResult,
+        size: OutputSize,
+    ) -> Result<Option<Frame>> {
+        let (width, height) = size.fit((frame.width, frame.height));
+        if (width, height) == (frame.width, frame.height) {
+            return Ok(None);
+        }
+
+        let pixel_type = fir_pixel_type(frame.layout);
+        let src = fir::images::ImageRef::new(frame.width, frame.height, &frame.pixels, pixel_type)
+            .context("frame buffer does not match its size")?;
+        let mut pixels = self.pool.take(frame.layout.buffer_len(width, height));
+        let mut dst = fir::images::Image::from_slice_u8(width, height, &mut pixels, pixel_type)
+            .context("scaled buffer does not match its size")?;
+        let options = fir::ResizeOptions::new()
+            .resize_alg(fir::ResizeAlg::Convolution(fir::FilterType::Lanczos3));
+        self.resizer
+            .resize(&src, &mut dst, Some(&options))
+            .context("output downscale failed")?;
+
+        scale_result(
+            result,
+            width as f32 / frame.width as f32,
+            height as f32 / frame.height as f32,
+        );
+        result.frame_size = (width, height);
+        Ok(Some(Frame {
+            pixels,
+            layout: frame.layout,
+            width,
+            height,
+            timestamp: frame.timestamp,
+            acquired_at: frame.acquired_at,
+        }))
+    }
+}
+
+/// Moves the landmarks, palm boxes and their debug overlay, and the dwell ring
+/// of `result` onto a frame scaled by `sx` and `sy`. Model-space landmarks do
+/// not depend on the frame.
+fn scale_result(result: &mut GestureResult, sx: f32, sy: f32) {
+    let scale = |point: &mut (f32, f32)| {
+        point.0 *= sx;
+        point.1 *= sy;
+    };
+    result.landmarks.iter_mut().flatten().for_each(scale);
+    for hand in &mut result.hands {
+        hand.landmarks.iter_mut().for_each(scale);
+    }
+    let debug = result.palm_debug.iter_mut().flat_map(|debug| {
+        for (center, side, _) in &mut debug.tracked {
+            scale(center);
+            // Stays square, so it cannot follow an uneven scale exactly.
+            *side *= (sx + sy) * 0.5;
+        }
+        debug.candidates.iter_mut().chain(&mut debug.selected)
+    });
+    for region in result.palm_regions.iter_mut().chain(debug) {
+        let [x0, y0, x1, y1] = region.bbox;
+        region.bbox = [x0 * sx, y0 * sy, x1 * sx, y1 * sy];
+        region.landmarks.iter_mut().for_each(scale);
+    }
+    if let Some(dwell) = &mut result.dwell {
+        scale(&mut dwell.wrist);
+    }
+}
+
 fn adjust_interval(
     current: Duration,
     compose_time: Duration,
@@ -104,10 +635,82 @@ fn adjust_interval(
     }
 }
 
-fn overlay_points(result: &GestureResult) -> Option<&[(f32, f32)]> {
-    if result.confidence >= OVERLAY_CONFIDENCE_THRESHOLD {
-        result.landmarks.as_deref()
-    } else {
-        None
+fn overlay_hands(result: &GestureResult, threshold: f32) -> impl Iterator<Item = &HandResult> {
+    result
+        .hands
+        .iter()
+        .filter(move |hand| hand.confidence >= threshold)
+}
+
+/// Per-joint z of a hand, or `None` when the raw landmarks do not line up with
+/// the projected ones.
+fn landmark_depths(hand: &HandResult) -> Option<Vec<f32>> {
+    (hand.raw_landmarks.len() == hand.landmarks.len())
+        .then(|| hand.raw_landmarks.iter().map(|point| point[2]).collect())
+}
+
+/// Drawn last so the flash covers the label and the skeleton too.
+fn draw_booth_overlay(
+    frame: &mut Frame,
+    result: &GestureResult,
+    overlay: &BoothOverlay,
+    style: &SkeletonStyle,
+) {
+    let (width, height) = (frame.width, frame.height);
+    if let (Some(arming), Some(points)) = (&overlay.arming, &result.landmarks) {
+        skeleton::draw_dwell_ring(&mut frame.pixels, width, height, arming, points, style);
+    }
+    if let Some(digit) = overlay.countdown {
+        skeleton::draw_countdown(&mut frame.pixels, width, height, digit);
+    }
+    if overlay.cancelled {
+        skeleton::draw_cancelled(&mut frame.pixels, width, height);
+    }
+    skeleton::draw_flash(&mut frame.pixels, width, height, overlay.flash);
+}
+
+fn label_lines(result: &GestureResult) -> Vec<String> {
+    let mut lines = vec![result.display_text()];
+    if let Some(detail) = &result.detail {
+        lines.push(detail.motion.label().to_string());
+    }
+    lines
+}
+
+fn is_censored(hand: &HandResult, censor: &HashSet<GestureKind>) -> bool {
+    hand.detail
+        .as_ref()
+        .is_some_and(|detail| censor.contains(&detail.primary))
+}
+
+/// Box around the hand's landmarks, widened to any palm region centered inside
+/// it since the landmarks alone stop short of the skin.
+fn censor_box(hand: &HandResult, palm_regions: &[PalmRegion]) -> Option<[f32; 4]> {
+    let mut points = hand.landmarks.iter();
+    let &(x, y) = points.next()?;
+    let mut bbox = [x, y, x, y];
+    for &(x, y) in points {
+        bbox = [
+            bbox[0].min(x),
+            bbox[1].min(y),
+            bbox[2].max(x),
+            bbox[3].max(y),
+        ];
+    }
+    let landmarks_box = bbox;
+    for region in palm_regions {
+        let [x1, y1, x2, y2] = region.bbox;
+        let center = ((x1 + x2) * 0.5, (y1 + y2) * 0.5);
+        let inside = (landmarks_box[0]..=landmarks_box[2]).contains(&center.0)
+            && (landmarks_box[1]..=landmarks_box[3]).contains(&center.1);
+        if inside {
+            bbox = [
+                bbox[0].min(x1),
+                bbox[1].min(y1),
+                bbox[2].max(x2),
+                bbox[3].max(y2),
+            ];
+        }
     }
+    Some(bbox)
 }
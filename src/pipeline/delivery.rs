@@ -0,0 +1,159 @@
+use std::{
+    fmt,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use crossbeam_channel::{Receiver, Sender, TrySendError, bounded, never, select};
+
+/// What the recognizer does with a result while its consumer is still busy
+/// with earlier ones.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DeliveryMode {
+    /// One result waits; newer ones are dropped until it is taken. What a
+    /// preview wants, since only the newest frame is worth drawing.
+    #[default]
+    LatestOnly,
+    /// Up to `capacity` results wait; once they do, the oldest makes way for
+    /// each new one. For loggers and network sinks that should see every
+    /// result unless they fall far behind.
+    Lossless { capacity: usize },
+    /// The worker waits for the consumer, so nothing is dropped and a slow
+    /// consumer slows recognition down. For offline and batch runs. The
+    /// worker stops waiting once the receiver is dropped, or once the release
+    /// given to [`Delivery::with_release`] fires.
+    Blocking,
+}
+
+impl DeliveryMode {
+    /// A channel that delivers the way this mode says.
+    pub fn channel<T>(self) -> (Delivery<T>, Receiver<T>) {
+        let (tx, rx) = match self {
+            DeliveryMode::LatestOnly | DeliveryMode::Blocking => bounded(1),
+            DeliveryMode::Lossless { capacity } => bounded(capacity.max(1)),
+        };
+        let delivery = Delivery {
+            tx,
+            oldest: matches!(self, DeliveryMode::Lossless { .. }).then(|| rx.clone()),
+            blocking: self == DeliveryMode::Blocking,
+            release: never(),
+            dropped: Arc::default(),
+        };
+        (delivery, rx)
+    }
+}
+
+/// The sending half of a [`DeliveryMode::channel`]. A plain [`Sender`]
+/// converts into one that delivers [`DeliveryMode::LatestOnly`].
+pub struct Delivery<T> {
+    tx: Sender<T>,
+    /// For [`DeliveryMode::Lossless`], the queue's own end to take the oldest
+    /// result off. It keeps the channel open after the consumer is gone.
+    oldest: Option<Receiver<T>>,
+    blocking: bool,
+    /// Ends a blocking wait when it fires or disconnects, for an owner that
+    /// cannot count on every clone of the receiver being dropped.
+    release: Receiver<()>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl<T> Delivery<T> {
+    /// Lets a blocking send give up once `release` fires or its sender is
+    /// dropped, even while someone still holds the receiver. The item is then
+    /// dropped like one the receiver was gone for.
+    pub fn with_release(mut self, release: Receiver<()>) -> Self {
+        self.release = release;
+        self
+    }
+
+    /// Hands `item` over, or drops it or an older one as the mode says.
+    /// Returns whether nothing was dropped.
+    pub fn deliver(&self, item: T) -> bool {
+        if self.blocking {
+            // Fails only once the receiver is gone or the owner lets go, and
+            // nobody is waiting on either.
+            select! {
+                send(self.tx, item) -> sent => return sent.is_ok(),
+                recv(self.release) -> _ => return false,
+            }
+        }
+        let mut item = item;
+        loop {
+            match self.tx.try_send(item) {
+                Ok(()) => return true,
+                Err(TrySendError::Full(full)) => match &self.oldest {
+                    Some(oldest) => {
+                        // The consumer may take it first, which makes room just
+                        // the same.
+                        if oldest.try_recv().is_ok() {
+                            self.dropped.fetch_add(1, Ordering::Relaxed);
+                            if self.tx.try_send(full).is_err() {
+                                // Another clone filled the slot first.
+                                self.dropped.fetch_add(1, Ordering::Relaxed);
+                            }
+                            return false;
+                        }
+                        item = full;
+                    }
+                    None => break,
+                },
+                Err(TrySendError::Disconnected(_)) => break,
+            }
+        }
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+        false
+    }
+
+    /// Results dropped so far, new or old, by this sender and its clones.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// The mode this sender was made with.
+    pub fn mode(&self) -> DeliveryMode {
+        if self.blocking {
+            DeliveryMode::Blocking
+        } else if self.oldest.is_some() {
+            DeliveryMode::Lossless {
+                capacity: self.tx.capacity().unwrap_or(1),
+            }
+        } else {
+            DeliveryMode::LatestOnly
+        }
+    }
+}
+
+impl<T> fmt::Debug for Delivery<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Delivery")
+            .field("mode", &self.mode())
+            .field("dropped", &self.dropped())
+            .finish()
+    }
+}
+
+impl<T> Clone for Delivery<T> {
+    fn clone(&self) -> Self {
+        Self {
+            tx: self.tx.clone(),
+            oldest: self.oldest.clone(),
+            blocking: self.blocking,
+            release: self.release.clone(),
+            dropped: self.dropped.clone(),
+        }
+    }
+}
+
+impl<T> From<Sender<T>> for Delivery<T> {
+    fn from(tx: Sender<T>) -> Self {
+        Self {
+            tx,
+            oldest: None,
+            blocking: false,
+            release: never(),
+            dropped: Arc::default(),
+        }
+    }
+}
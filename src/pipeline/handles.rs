@@ -0,0 +1,98 @@
+use std::{
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use crossbeam_channel::Sender;
+
+use super::camera::CameraManager;
+use crate::types::RecognizedFrame;
+
+const JOIN_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Owns the camera and every pipeline thread so the whole chain can be torn
+/// down in order when the app exits.
+pub struct PipelineHandles {
+    camera: CameraManager,
+    recognized_tx: Option<Sender<RecognizedFrame>>,
+    recognizer: Option<JoinHandle<()>>,
+    compositor: Option<JoinHandle<()>>,
+}
+
+impl PipelineHandles {
+    pub fn new(
+        camera: CameraManager,
+        recognized_tx: Sender<RecognizedFrame>,
+        compositor: JoinHandle<()>,
+    ) -> Self {
+        Self {
+            camera,
+            recognized_tx: Some(recognized_tx),
+            recognizer: None,
+            compositor: Some(compositor),
+        }
+    }
+
+    pub fn camera(&self) -> &CameraManager {
+        &self.camera
+    }
+
+    pub fn camera_mut(&mut self) -> &mut CameraManager {
+        &mut self.camera
+    }
+
+    /// Sender the recognizer publishes into; `None` after shutdown.
+    pub fn recognized_sender(&self) -> Option<Sender<RecognizedFrame>> {
+        self.recognized_tx.clone()
+    }
+
+    pub fn recognizer_started(&self) -> bool {
+        self.recognizer.is_some()
+    }
+
+    pub fn set_recognizer(&mut self, handle: JoinHandle<()>) {
+        self.recognizer = Some(handle);
+    }
+
+    /// Stops the camera and closes the channels stage by stage, so each worker
+    /// sees its input disconnect and returns. Threads still running after
+    /// `timeout` are left detached; returns whether everything was joined.
+    pub fn shutdown(&mut self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+
+        // Dropping the last frame sender ends the recognizer's frame loop.
+        self.camera.close();
+        let recognizer_done = join_until(self.recognizer.take(), "recognizer", deadline);
+
+        // The compositor only sees a disconnect once the worker's sender is gone too.
+        self.recognized_tx = None;
+        let compositor_done = join_until(self.compositor.take(), "compositor", deadline);
+
+        recognizer_done && compositor_done
+    }
+}
+
+impl Drop for PipelineHandles {
+    fn drop(&mut self) {
+        if self.recognized_tx.is_some() {
+            self.shutdown(Duration::from_secs(2));
+        }
+    }
+}
+
+fn join_until(handle: Option<JoinHandle<()>>, name: &str, deadline: Instant) -> bool {
+    let Some(handle) = handle else {
+        return true;
+    };
+    while !handle.is_finished() {
+        if Instant::now() >= deadline {
+            log::warn!("{name} thread did not stop in time, detaching it");
+            return false;
+        }
+        thread::sleep(JOIN_POLL_INTERVAL);
+    }
+    if handle.join().is_err() {
+        log::warn!("{name} thread panicked");
+    }
+    true
+}
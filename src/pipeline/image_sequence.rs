@@ -0,0 +1,222 @@
+use std::{
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, anyhow};
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender, bounded, unbounded};
+use image::DynamicImage;
+
+use super::{
+    delivery::DeliveryMode,
+    metrics::MetricsHandle,
+    recognizer::{RecognizerBackend, RecognizerControl, start_recognizer},
+    rgb_converter,
+};
+use crate::types::{Frame, GestureResult, PixelLayout, RecognizedFrame};
+
+pub const DEFAULT_IMAGE_INTERVAL: Duration = Duration::from_millis(200);
+/// How long one image may take through palm detection and handpose.
+const RESULT_TIMEOUT: Duration = Duration::from_secs(30);
+const IMAGE_EXTENSIONS: [&str; 5] = ["png", "jpg", "jpeg", "bmp", "webp"];
+
+/// The images of one directory in file name order, replayed as frames.
+#[derive(Clone, Debug)]
+pub struct ImageSequenceSource {
+    paths: Vec<PathBuf>,
+    interval: Duration,
+}
+
+impl ImageSequenceSource {
+    pub fn open(dir: &Path, interval: Duration) -> Result<Self> {
+        let entries = fs::read_dir(dir)
+            .with_context(|| format!("failed to read image directory {}", dir.display()))?;
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| is_image(path))
+            .collect();
+        if paths.is_empty() {
+            return Err(anyhow!("no images found in {}", dir.display()));
+        }
+        paths.sort();
+        Ok(Self { paths, interval })
+    }
+
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+}
+
+fn is_image(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+}
+
+pub fn load_image_frame(path: &Path, timestamp: Instant) -> Result<Frame> {
+    let image =
+        image::open(path).with_context(|| format!("failed to open image {}", path.display()))?;
+    Ok(image_frame(&image, timestamp))
+}
+
+/// `image` as an RGB frame captured at `timestamp`.
+pub fn image_frame(image: &DynamicImage, timestamp: Instant) -> Frame {
+    let image = image.to_rgb8();
+    Frame {
+        width: image.width(),
+        height: image.height(),
+        pixels: image.into_raw().into(),
+        layout: PixelLayout::Rgb,
+        timestamp,
+        acquired_at: timestamp,
+    }
+}
+
+/// Feeds every image through palm detection, handpose and classification one at
+/// a time and hands each result to `on_result`. Tracking is reset before each
+/// image so motion and count smoothing never carry over between photos.
+pub fn run_image_sequence<F>(
+    backend: RecognizerBackend,
+    source: &ImageSequenceSource,
+    metrics: MetricsHandle,
+    mut on_result: F,
+) -> Result<()>
+where
+    F: FnMut(&Path, &GestureResult) -> Result<()>,
+{
+    let mirror = backend.config().mirror;
+    let (frame_tx, frame_rx) = bounded(1);
+    let (control_tx, control_rx) = unbounded();
+    // Every image is waited on, so none of its results may be dropped.
+    let (result_tx, result_rx) = DeliveryMode::Blocking.channel();
+    let (event_tx, _event_rx) = bounded(64);
+    let recognizer_handle =
+        start_recognizer(backend, frame_rx, control_rx, result_tx, event_tx, metrics);
+
+    let outcome = feed_images(
+        source,
+        &frame_tx,
+        &control_tx,
+        &result_rx,
+        mirror,
+        &mut on_result,
+    );
+
+    // Dropping the frame sender ends the worker loop.
+    drop(frame_tx);
+    drop(control_tx);
+    drop(result_rx);
+    let _ = recognizer_handle.join();
+    outcome
+}
+
+fn feed_images<F>(
+    source: &ImageSequenceSource,
+    frame_tx: &Sender<Frame>,
+    control_tx: &Sender<RecognizerControl>,
+    result_rx: &Receiver<RecognizedFrame>,
+    mirror: bool,
+    on_result: &mut F,
+) -> Result<()>
+where
+    F: FnMut(&Path, &GestureResult) -> Result<()>,
+{
+    let started_at = Instant::now();
+    for (idx, path) in source.paths().iter().enumerate() {
+        // Timestamps follow the sequence, not the wall clock, like video replay.
+        let timestamp = started_at + source.interval() * idx as u32;
+        if let Some(wait) = timestamp.checked_duration_since(Instant::now()) {
+            thread::sleep(wait);
+        }
+        let mut frame = match load_image_frame(path, timestamp) {
+            Ok(frame) => frame,
+            Err(err) => {
+                log::warn!("skipping {}: {err:#}", path.display());
+                continue;
+            }
+        };
+
+        if mirror {
+            rgb_converter::mirror_pixels(&mut frame.pixels, frame.width, frame.layout);
+        }
+
+        let _ = control_tx.send(RecognizerControl::ResetTracking);
+        frame_tx
+            .send(frame)
+            .map_err(|_| anyhow!("recognizer stopped unexpectedly"))?;
+        let recognized = match result_rx.recv_timeout(RESULT_TIMEOUT) {
+            Ok(recognized) => recognized,
+            Err(RecvTimeoutError::Timeout) => {
+                log::warn!("no result for {} in time, skipping it", path.display());
+                continue;
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(anyhow!("recognizer stopped unexpectedly"));
+            }
+        };
+        on_result(path, &recognized.result)?;
+    }
+    Ok(())
+}
+
+/// `filename,kind,confidence,handedness`, one row per image, so runs over the
+/// same folder can be diffed across commits.
+pub struct LabelCsvWriter {
+    out: BufWriter<File>,
+}
+
+impl LabelCsvWriter {
+    pub fn create(path: &Path) -> Result<Self> {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let file =
+            File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
+        let mut out = BufWriter::new(file);
+        writeln!(out, "filename,kind,confidence,handedness")?;
+        Ok(Self { out })
+    }
+
+    pub fn write(&mut self, image: &Path, result: &GestureResult) -> Result<()> {
+        let filename = image
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| image.display().to_string());
+        let detail = result.detail.as_ref();
+        let kind = detail.map_or("none".to_string(), |d| format!("{:?}", d.primary));
+        let handedness = detail.map_or("none".to_string(), |d| format!("{:?}", d.handedness));
+        writeln!(
+            self.out,
+            "{},{kind},{:.3},{handedness}",
+            csv_field(&filename),
+            result.confidence
+        )?;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<()> {
+        self.out.flush()?;
+        Ok(())
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
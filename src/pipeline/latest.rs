@@ -0,0 +1,73 @@
+use crossbeam_channel::Receiver;
+
+/// Frames passed over per call at most. A producer that refills the channel as
+/// fast as it is drained would otherwise keep the consumer here for good.
+const MAX_DRAIN_SKIPS: usize = 8;
+
+/// Blocks for the next message, then takes newer ones that are already queued
+/// in its place. Returns it with the number passed over, `None` once every
+/// sender is gone.
+pub(crate) fn recv_latest<T>(rx: &Receiver<T>) -> Option<(T, usize)> {
+    let first = rx.recv().ok()?;
+    Some(take_latest(rx, first))
+}
+
+/// Like [`recv_latest`] for a message that was already received, e.g. by a
+/// `select!` over several channels.
+pub(crate) fn take_latest<T>(rx: &Receiver<T>, first: T) -> (T, usize) {
+    let mut latest = first;
+    let mut skipped = 0;
+    while skipped < MAX_DRAIN_SKIPS {
+        let Ok(newer) = rx.try_recv() else {
+            break;
+        };
+        latest = newer;
+        skipped += 1;
+    }
+    (latest, skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn a_deep_backlog_is_drained_a_bounded_step_at_a_time() {
+        let (tx, rx) = crossbeam_channel::unbounded();
+        for n in 0..20 {
+            tx.send(n).unwrap();
+        }
+        drop(tx);
+        assert_eq!(recv_latest(&rx), Some((8, 8)));
+        assert_eq!(recv_latest(&rx), Some((17, 8)));
+        assert_eq!(recv_latest(&rx), Some((19, 1)));
+        assert_eq!(recv_latest(&rx), None);
+    }
+
+    #[test]
+    fn a_flooding_producer_cannot_stall_the_consumer() {
+        const SENT: u64 = 200_000;
+        let (tx, rx) = crossbeam_channel::bounded(4);
+        let producer = thread::spawn(move || {
+            for n in 0..SENT {
+                tx.send(n).unwrap();
+            }
+        });
+
+        let (mut taken, mut skipped, mut last) = (0u64, 0u64, None);
+        while let Some((n, passed_over)) = recv_latest(&rx) {
+            assert!(passed_over <= MAX_DRAIN_SKIPS);
+            assert!(last.is_none_or(|last| n > last), "{n} after {last:?}");
+            taken += 1;
+            skipped += passed_over as u64;
+            last = Some(n);
+        }
+        producer.join().unwrap();
+
+        // Every message was either taken or counted as skipped.
+        assert_eq!(taken + skipped, SENT);
+        assert_eq!(last, Some(SENT - 1));
+    }
+}
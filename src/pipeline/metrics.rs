@@ -0,0 +1,425 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard},
+    thread,
+    time::{Duration, Instant},
+};
+
+use super::recognizer::common::HandposeOutput;
+use crate::types::{GestureEvent, GestureKind};
+
+const METRICS_WINDOW: Duration = Duration::from_secs(2);
+/// Upper bounds, in seconds, of the stage latency histogram buckets.
+pub const LATENCY_BUCKETS: [f64; 10] =
+    [0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    /// Camera read returning to the frame being decoded and mirrored.
+    Decode,
+    PalmDetect,
+    /// Part of [`Stage::Handpose`], tracked on its own.
+    CropPrep,
+    Handpose,
+    Classify,
+    Compose,
+    /// Camera read returning to the composited frame going out.
+    EndToEnd,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 7] = [
+        Stage::Decode,
+        Stage::PalmDetect,
+        Stage::CropPrep,
+        Stage::Handpose,
+        Stage::Classify,
+        Stage::Compose,
+        Stage::EndToEnd,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Stage::Decode => "decode",
+            Stage::PalmDetect => "palm_detect",
+            Stage::CropPrep => "crop_prep",
+            Stage::Handpose => "handpose",
+            Stage::Classify => "classify",
+            Stage::Compose => "compose",
+            Stage::EndToEnd => "end_to_end",
+        }
+    }
+}
+
+/// Channels whose `try_send` drops frames under backpressure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Channel {
+    Camera,
+    Recognized,
+    Composited,
+}
+
+impl Channel {
+    pub const ALL: [Channel; 3] = [Channel::Camera, Channel::Recognized, Channel::Composited];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Channel::Camera => "camera",
+            Channel::Recognized => "recognized",
+            Channel::Composited => "composited",
+        }
+    }
+}
+
+/// Rolling averages over the last two seconds of the pipeline.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PipelineMetrics {
+    pub capture_fps: f32,
+    /// Frames the recognizer finished, per second.
+    pub inference_fps: f32,
+    /// Frames the camera handed over within the window.
+    pub captured_frames: usize,
+    pub decode_ms: f32,
+    pub palm_detect_ms: f32,
+    pub crop_prep_ms: f32,
+    pub handpose_ms: f32,
+    pub classify_ms: f32,
+    pub compose_ms: f32,
+    /// From the camera handing a frame over to it leaving the compositor.
+    pub latency_ms: f32,
+    pub dropped_camera: usize,
+    pub dropped_recognized: usize,
+    pub dropped_composited: usize,
+    /// Frames the recognizer, then the compositor, passed over for a newer
+    /// one already waiting.
+    pub skipped_camera: usize,
+    pub skipped_recognized: usize,
+    /// Frames the recognizer failed on, wholly or in palm detection.
+    pub recognizer_errors: usize,
+    /// Resolution of the last frame fed to palm detection, after downscaling.
+    pub input_width: u32,
+    pub input_height: u32,
+    /// Size of the last composited frame, after fitting the output size.
+    pub output_width: u32,
+    pub output_height: u32,
+    /// Fraction of inferred frames that ran palm detection rather than reusing
+    /// tracked hands.
+    pub palm_detect_rate: f32,
+    /// Whether the recognizer is skipping inference on a still scene.
+    pub idle: bool,
+}
+
+impl PipelineMetrics {
+    /// Share of the captured frames, 0 to 100, that were dropped or skipped
+    /// on their way to the screen. Each frame is lost at most once, so the
+    /// channels add up.
+    pub fn lost_percent(&self) -> f32 {
+        if self.captured_frames == 0 {
+            return 0.0;
+        }
+        let lost = self.dropped_camera
+            + self.dropped_recognized
+            + self.dropped_composited
+            + self.skipped_camera
+            + self.skipped_recognized;
+        (lost as f32 * 100.0 / self.captured_frames as f32).min(100.0)
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "capture {:.1} fps, inference {:.1} fps, decode {:.1} ms, latency {:.0} ms, input {}x{}, output {}x{}, palm {:.1} ms on {:.0}% of frames, handpose {:.1} ms (crop {:.1} ms), classify {:.1} ms, compose {:.1} ms, dropped {}/{}/{}, skipped {}/{}, errors {}{}",
+            self.capture_fps,
+            self.inference_fps,
+            self.decode_ms,
+            self.latency_ms,
+            self.input_width,
+            self.input_height,
+            self.output_width,
+            self.output_height,
+            self.palm_detect_ms,
+            self.palm_detect_rate * 100.0,
+            self.handpose_ms,
+            self.crop_prep_ms,
+            self.classify_ms,
+            self.compose_ms,
+            self.dropped_camera,
+            self.dropped_recognized,
+            self.dropped_composited,
+            self.skipped_camera,
+            self.skipped_recognized,
+            self.recognizer_errors,
+            if self.idle { ", idle" } else { "" }
+        )
+    }
+}
+
+/// Counts since the pipeline started, for scrapers that take their own rates;
+/// unlike [`PipelineMetrics`] nothing here ages out.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetricsTotals {
+    pub captured_frames: u64,
+    /// Frames the recognizer finished.
+    pub inferred_frames: u64,
+    /// Indexed by [`Channel`].
+    pub dropped: [u64; 3],
+    pub skipped: [u64; 3],
+    pub recognizer_errors: u64,
+    /// Indexed by [`Stage`].
+    pub latency: [LatencyHistogram; 7],
+    /// Confidence of the newest recognized frame.
+    pub last_confidence: Option<f32>,
+    /// Gestures reported as started, each hold counted once.
+    pub recognitions: HashMap<GestureKind, u64>,
+}
+
+/// Stage durations bucketed by [`LATENCY_BUCKETS`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LatencyHistogram {
+    /// Samples at or under each bound, so later buckets include earlier ones.
+    pub buckets: [u64; LATENCY_BUCKETS.len()],
+    pub count: u64,
+    pub sum_secs: f64,
+}
+
+impl LatencyHistogram {
+    fn observe(&mut self, duration: Duration) {
+        let secs = duration.as_secs_f64();
+        for (bucket, bound) in self.buckets.iter_mut().zip(LATENCY_BUCKETS) {
+            if secs <= bound {
+                *bucket += 1;
+            }
+        }
+        self.count += 1;
+        self.sum_secs += secs;
+    }
+}
+
+/// Shared sink every stage records into; cloning shares the same state.
+#[derive(Clone, Default)]
+pub struct MetricsHandle {
+    inner: Arc<Mutex<MetricsState>>,
+}
+
+#[derive(Default)]
+struct MetricsState {
+    captures: VecDeque<Instant>,
+    stages: [VecDeque<(Instant, Duration)>; 7],
+    drops: [VecDeque<Instant>; 3],
+    skips: [VecDeque<Instant>; 3],
+    errors: VecDeque<Instant>,
+    palm_skips: VecDeque<Instant>,
+    input_size: (u32, u32),
+    output_size: (u32, u32),
+    idle: bool,
+    totals: MetricsTotals,
+}
+
+impl MetricsHandle {
+    pub fn record_capture(&self, at: Instant) {
+        let mut state = self.lock();
+        state.captures.push_back(at);
+        state.totals.captured_frames += 1;
+        state.prune(at);
+    }
+
+    pub fn record_stage(&self, stage: Stage, duration: Duration) {
+        let now = Instant::now();
+        let mut state = self.lock();
+        state.stages[stage as usize].push_back((now, duration));
+        state.totals.latency[stage as usize].observe(duration);
+        if stage == Stage::Classify {
+            state.totals.inferred_frames += 1;
+        }
+        state.prune(now);
+    }
+
+    pub fn record_drop(&self, channel: Channel) {
+        let now = Instant::now();
+        let mut state = self.lock();
+        state.drops[channel as usize].push_back(now);
+        state.totals.dropped[channel as usize] += 1;
+        state.prune(now);
+    }
+
+    /// Frames a consumer of `channel` passed over for a newer one.
+    pub fn record_skips(&self, channel: Channel, count: usize) {
+        if count == 0 {
+            return;
+        }
+        let now = Instant::now();
+        let mut state = self.lock();
+        state.skips[channel as usize].extend(std::iter::repeat_n(now, count));
+        state.totals.skipped[channel as usize] += count as u64;
+        state.prune(now);
+    }
+
+    /// A frame the recognizer failed on; only the count is kept.
+    pub fn record_error(&self) {
+        let now = Instant::now();
+        let mut state = self.lock();
+        state.errors.push_back(now);
+        state.totals.recognizer_errors += 1;
+        state.prune(now);
+    }
+
+    /// A frame that went straight to handpose from tracked hands.
+    pub fn record_palm_skip(&self) {
+        let now = Instant::now();
+        let mut state = self.lock();
+        state.palm_skips.push_back(now);
+        state.prune(now);
+    }
+
+    /// The confidence of a recognized frame and the gesture events it
+    /// raised.
+    pub fn record_recognition(&self, confidence: f32, events: &[GestureEvent]) {
+        let mut state = self.lock();
+        state.totals.last_confidence = Some(confidence);
+        for event in events {
+            if let GestureEvent::Started(kind) = event {
+                *state.totals.recognitions.entry(*kind).or_default() += 1;
+            }
+        }
+    }
+
+    pub fn record_input_size(&self, (width, height): (u32, u32)) {
+        self.lock().input_size = (width, height);
+    }
+
+    pub fn record_output_size(&self, (width, height): (u32, u32)) {
+        self.lock().output_size = (width, height);
+    }
+
+    pub fn record_idle(&self, idle: bool) {
+        self.lock().idle = idle;
+    }
+
+    /// The palm, crop and handpose stages of one inferred frame, and its input
+    /// size.
+    pub fn record_inference(&self, output: &HandposeOutput) {
+        let timings = output.timings;
+        match timings.palm_detect {
+            Some(duration) => self.record_stage(Stage::PalmDetect, duration),
+            None => self.record_palm_skip(),
+        }
+        self.record_stage(Stage::CropPrep, timings.crop_prep);
+        self.record_stage(Stage::Handpose, timings.handpose);
+        self.record_input_size(output.input_size);
+        if let Some(err) = &output.palm_error {
+            self.record_error();
+            log::debug!("{}", err.describe());
+        }
+    }
+
+    pub fn snapshot(&self) -> PipelineMetrics {
+        let mut state = self.lock();
+        state.prune(Instant::now());
+
+        let capture_fps = rate(state.captures.iter().copied());
+        let inference_fps = rate(
+            state.stages[Stage::Classify as usize]
+                .iter()
+                .map(|(at, _)| *at),
+        );
+        let palm_runs = state.stages[Stage::PalmDetect as usize].len();
+        let palm_frames = palm_runs + state.palm_skips.len();
+        let palm_detect_rate = if palm_frames > 0 {
+            palm_runs as f32 / palm_frames as f32
+        } else {
+            0.0
+        };
+
+        PipelineMetrics {
+            capture_fps,
+            inference_fps,
+            captured_frames: state.captures.len(),
+            decode_ms: state.average_ms(Stage::Decode),
+            palm_detect_ms: state.average_ms(Stage::PalmDetect),
+            crop_prep_ms: state.average_ms(Stage::CropPrep),
+            handpose_ms: state.average_ms(Stage::Handpose),
+            classify_ms: state.average_ms(Stage::Classify),
+            compose_ms: state.average_ms(Stage::Compose),
+            latency_ms: state.average_ms(Stage::EndToEnd),
+            dropped_camera: state.drops[Channel::Camera as usize].len(),
+            dropped_recognized: state.drops[Channel::Recognized as usize].len(),
+            dropped_composited: state.drops[Channel::Composited as usize].len(),
+            skipped_camera: state.skips[Channel::Camera as usize].len(),
+            skipped_recognized: state.skips[Channel::Recognized as usize].len(),
+            recognizer_errors: state.errors.len(),
+            input_width: state.input_size.0,
+            input_height: state.input_size.1,
+            output_width: state.output_size.0,
+            output_height: state.output_size.1,
+            palm_detect_rate,
+            idle: state.idle,
+        }
+    }
+
+    pub fn totals(&self) -> MetricsTotals {
+        self.lock().totals.clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MetricsState> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl MetricsState {
+    fn prune(&mut self, now: Instant) {
+        let expired = |at: &Instant| now.saturating_duration_since(*at) > METRICS_WINDOW;
+        while self.captures.front().is_some_and(expired) {
+            self.captures.pop_front();
+        }
+        for samples in &mut self.stages {
+            while samples.front().is_some_and(|(at, _)| expired(at)) {
+                samples.pop_front();
+            }
+        }
+        for drops in self.drops.iter_mut().chain(&mut self.skips) {
+            while drops.front().is_some_and(expired) {
+                drops.pop_front();
+            }
+        }
+        while self.errors.front().is_some_and(expired) {
+            self.errors.pop_front();
+        }
+        while self.palm_skips.front().is_some_and(expired) {
+            self.palm_skips.pop_front();
+        }
+    }
+
+    fn average_ms(&self, stage: Stage) -> f32 {
+        let samples = &self.stages[stage as usize];
+        if samples.is_empty() {
+            return 0.0;
+        }
+        let total: Duration = samples.iter().map(|(_, d)| *d).sum();
+        total.as_secs_f32() * 1000.0 / samples.len() as f32
+    }
+}
+
+/// Events per second between the first and last of `times`, oldest first.
+fn rate(mut times: impl ExactSizeIterator<Item = Instant>) -> f32 {
+    let count = times.len();
+    let (Some(first), Some(last)) = (times.next(), times.last()) else {
+        return 0.0;
+    };
+    let span = last.saturating_duration_since(first).as_secs_f32();
+    if span > 0.0 {
+        (count - 1) as f32 / span
+    } else {
+        0.0
+    }
+}
+
+/// Logs a snapshot every `interval` for the `--metrics` flag.
+pub fn spawn_metrics_logger(metrics: MetricsHandle, interval: Duration) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        loop {
+            thread::sleep(interval);
+            log::info!("pipeline metrics: {}", metrics.snapshot().summary());
+        }
+    })
+}
@@ -1,10 +1,41 @@
 pub mod camera;
+pub mod capture;
+pub mod clip;
 pub mod compositor;
+pub mod delivery;
+pub mod handles;
+pub mod image_sequence;
+mod latest;
+pub mod metrics;
+pub mod photo_booth;
 pub mod recognizer;
-pub mod rgba_converter;
+pub mod recorder;
+pub mod rgb_converter;
 pub mod skeleton;
+pub mod source;
+pub mod virtual_camera;
 
 // Re-exports for convenience
-pub use camera::{CameraDevice, CameraStream, available_cameras, start_camera_stream};
-pub use compositor::{CompositedFrame, start_frame_compositor};
-pub use recognizer::{RecognizerBackend, start_recognizer};
+pub use camera::{
+    CameraControlInfo, CameraDevice, CameraKnob, CameraManager, CameraOpenOptions, CameraProbe,
+    CameraStatus, CameraStream, available_cameras, describe_mode, exposure_for_luma,
+    grab_test_frame, parse_frame_format, probe_cameras, start_camera_stream, supported_formats,
+};
+pub use capture::{DEFAULT_CAPTURES_DIR, spawn_capture};
+pub use clip::{ClipBuffer, ClipExport, ClipFormat, spawn_clip_export};
+pub use compositor::{CompositedFrame, CompositorSettings, OutputSize, start_frame_compositor};
+pub use delivery::{Delivery, DeliveryMode};
+pub use handles::PipelineHandles;
+pub use image_sequence::{
+    DEFAULT_IMAGE_INTERVAL, ImageSequenceSource, LabelCsvWriter, run_image_sequence,
+};
+pub use metrics::{MetricsHandle, PipelineMetrics};
+pub use photo_booth::{PhotoBoothConfig, PhotoBoothEvent};
+pub use recognizer::{
+    EXECUTION_PROVIDER_ENV, ExecutionProvider, HandposeOutputNames, INFERENCE_BACKEND_ENV,
+    InferenceBackend, PalmDetectorConfig, RecognizerBackend, RecognizerControl, RecognizerError,
+    RecognizerStatus, start_recognizer,
+};
+pub use recorder::Recorder;
+pub use source::{FrameSource, start_frame_source};
+pub use virtual_camera::{DEFAULT_VIRTUAL_CAMERA_DEVICE, VirtualCamera};
@@ -0,0 +1,184 @@
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::Sender;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    gesture::DwellDetector,
+    types::{DwellConfig, DwellProgress, Frame, GestureKind, GestureResult},
+};
+
+/// The flash fades out over this long after the shot.
+const FLASH_DURATION: Duration = Duration::from_millis(300);
+/// How long the cancelled cross stays up.
+const CANCELLED_DURATION: Duration = Duration::from_millis(800);
+/// Frames without a hand tolerated mid-countdown, so one missed detection
+/// does not cancel it.
+const HAND_LOST_GRACE: Duration = Duration::from_millis(300);
+/// Lapses in the trigger gesture forgiven while it is being held.
+const HOLD_GRACE_MS: u64 = 200;
+
+/// Gesture-triggered countdown photos, saved as the `photo_booth` entry of
+/// the settings file.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PhotoBoothConfig {
+    pub enabled: bool,
+    /// Held steady for `hold_ms` to start the countdown.
+    pub gesture: GestureKind,
+    pub hold_ms: u64,
+    /// Seconds counted down before the shot, at most 9.
+    pub countdown_secs: u32,
+    /// No new countdown starts this long after a shot, so one pose does not
+    /// take a photo every few seconds.
+    pub cooldown_ms: u64,
+}
+
+impl Default for PhotoBoothConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            gesture: GestureKind::Peace,
+            hold_ms: 1_000,
+            countdown_secs: 3,
+            cooldown_ms: 5_000,
+        }
+    }
+}
+
+impl PhotoBoothConfig {
+    pub fn countdown(&self) -> Duration {
+        Duration::from_secs(u64::from(self.countdown_secs.min(9)))
+    }
+
+    pub fn cooldown(&self) -> Duration {
+        Duration::from_millis(self.cooldown_ms)
+    }
+}
+
+/// Sent by the compositor to whoever saves the photos.
+#[derive(Debug)]
+pub enum PhotoBoothEvent {
+    /// The frame at full resolution without any overlay, with its result.
+    Shot {
+        frame: Frame,
+        result: Box<GestureResult>,
+    },
+    /// The hand left the frame during the countdown.
+    Cancelled,
+}
+
+/// What the compositor draws for the booth on this frame.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BoothOverlay {
+    /// Progress of the trigger hold, drawn as the dwell ring.
+    pub arming: Option<DwellProgress>,
+    /// Seconds left, drawn as a big digit.
+    pub countdown: Option<u32>,
+    /// 1.0 on the shot, fading to 0.
+    pub flash: f32,
+    pub cancelled: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Phase {
+    Idle,
+    Countdown {
+        started: Instant,
+        hand_seen: Instant,
+    },
+    /// The photo was taken; the flash fades and the cooldown runs.
+    Shot {
+        at: Instant,
+    },
+    Cancelled {
+        at: Instant,
+    },
+}
+
+/// Holding the trigger gesture arms a countdown, after which the clean frame
+/// is sent out as a [`PhotoBoothEvent::Shot`]. Runs on the compositor thread,
+/// which sees every frame before the overlay is drawn.
+pub struct PhotoBooth {
+    config: PhotoBoothConfig,
+    hold: DwellDetector,
+    phase: Phase,
+    events: Sender<PhotoBoothEvent>,
+}
+
+impl PhotoBooth {
+    pub fn new(config: PhotoBoothConfig, events: Sender<PhotoBoothEvent>) -> Self {
+        let hold = DwellDetector::new(DwellConfig {
+            gesture: config.gesture,
+            hold_ms: config.hold_ms,
+            grace_ms: HOLD_GRACE_MS,
+            ..DwellConfig::default()
+        });
+        Self {
+            config,
+            hold,
+            phase: Phase::Idle,
+            events,
+        }
+    }
+
+    pub fn config(&self) -> PhotoBoothConfig {
+        self.config
+    }
+
+    /// Advances the booth by one frame; `frame` must not have the overlay
+    /// drawn yet, since it is what a shot saves.
+    pub fn observe(&mut self, frame: &Frame, result: &GestureResult) -> BoothOverlay {
+        let now = result.timestamp;
+        let mut overlay = BoothOverlay::default();
+        match &mut self.phase {
+            Phase::Idle => {
+                overlay.arming = self.hold.observe(result);
+                if overlay.arming.is_some_and(|hold| hold.completed) {
+                    self.hold.reset();
+                    self.phase = Phase::Countdown {
+                        started: now,
+                        hand_seen: now,
+                    };
+                    overlay.arming = None;
+                    overlay.countdown = Some(self.config.countdown_secs.min(9));
+                }
+            }
+            Phase::Countdown { started, hand_seen } => {
+                if !result.hands.is_empty() {
+                    *hand_seen = now;
+                }
+                let elapsed = now.saturating_duration_since(*started);
+                if now.saturating_duration_since(*hand_seen) > HAND_LOST_GRACE {
+                    self.phase = Phase::Cancelled { at: now };
+                    overlay.cancelled = true;
+                    let _ = self.events.try_send(PhotoBoothEvent::Cancelled);
+                } else if let Some(left) = self.config.countdown().checked_sub(elapsed) {
+                    overlay.countdown = Some(left.as_secs_f32().ceil().max(1.0) as u32);
+                } else {
+                    self.phase = Phase::Shot { at: now };
+                    overlay.flash = 1.0;
+                    let _ = self.events.try_send(PhotoBoothEvent::Shot {
+                        frame: frame.clone(),
+                        result: Box::new(result.clone()),
+                    });
+                }
+            }
+            Phase::Shot { at } => {
+                let since = now.saturating_duration_since(*at);
+                overlay.flash = 1.0 - (since.as_secs_f32() / FLASH_DURATION.as_secs_f32()).min(1.0);
+                if since >= self.config.cooldown() {
+                    self.phase = Phase::Idle;
+                }
+            }
+            Phase::Cancelled { at } => {
+                if now.saturating_duration_since(*at) < CANCELLED_DURATION {
+                    overlay.cancelled = true;
+                } else {
+                    self.phase = Phase::Idle;
+                }
+            }
+        }
+        overlay
+    }
+}
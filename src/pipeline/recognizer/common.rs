@@ -1,21 +1,448 @@
 use anyhow::{Context, Result, anyhow};
 use fast_image_resize as fir;
 use ndarray::Array4;
+use ort::execution_providers::{
+    CUDAExecutionProvider, CoreMLExecutionProvider, DirectMLExecutionProvider,
+};
+use ort::session::SessionOutputs;
+use ort::session::{
+    Session,
+    builder::{GraphOptimizationLevel, SessionBuilder},
+};
+use ort::value::ValueType;
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use crate::types::Frame;
+use crate::{
+    locale::{Locale, locale},
+    types::{Frame, PixelLayout},
+};
 
 pub const INPUT_SIZE: u32 = 224;
 pub const NUM_LANDMARKS: usize = 21;
 pub const PALM_INPUT_SIZE: u32 = 192;
 
+pub const MAX_HANDS: usize = 2;
+
+/// Environment variable the examples read to pick an execution provider.
+pub const EXECUTION_PROVIDER_ENV: &str = "GESTURE_EP";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ExecutionProvider {
+    #[default]
+    Cpu,
+    CoreMl,
+    Cuda,
+    DirectMl,
+    /// The platform's accelerated provider, falling back to CPU.
+    Auto,
+}
+
+impl ExecutionProvider {
+    pub const ALL: [ExecutionProvider; 5] = [
+        ExecutionProvider::Cpu,
+        ExecutionProvider::CoreMl,
+        ExecutionProvider::Cuda,
+        ExecutionProvider::DirectMl,
+        ExecutionProvider::Auto,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExecutionProvider::Cpu => "CPU",
+            ExecutionProvider::CoreMl => "CoreML",
+            ExecutionProvider::Cuda => "CUDA",
+            ExecutionProvider::DirectMl => "DirectML",
+            ExecutionProvider::Auto => "Auto",
+        }
+    }
+
+    /// For the UI; [`ExecutionProvider::label`] is the name settings store.
+    pub fn display_name(&self) -> &'static str {
+        self.display_name_in(locale())
+    }
+
+    pub fn display_name_in(&self, locale: Locale) -> &'static str {
+        match self {
+            ExecutionProvider::Auto => locale.pick("自动", "Auto"),
+            other => other.label(),
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "cpu" => Some(ExecutionProvider::Cpu),
+            "coreml" => Some(ExecutionProvider::CoreMl),
+            "cuda" => Some(ExecutionProvider::Cuda),
+            "directml" | "dml" => Some(ExecutionProvider::DirectMl),
+            "auto" => Some(ExecutionProvider::Auto),
+            _ => None,
+        }
+    }
+
+    /// Reads [`EXECUTION_PROVIDER_ENV`], defaulting to CPU when unset or invalid.
+    pub fn from_env() -> Self {
+        match std::env::var(EXECUTION_PROVIDER_ENV) {
+            Ok(value) => Self::parse(&value).unwrap_or_else(|| {
+                log::warn!("unknown execution provider {value:?}, using CPU");
+                ExecutionProvider::Cpu
+            }),
+            Err(_) => ExecutionProvider::Cpu,
+        }
+    }
+
+    fn candidates(&self) -> Vec<ExecutionProvider> {
+        match self {
+            ExecutionProvider::Cpu => vec![ExecutionProvider::Cpu],
+            ExecutionProvider::Auto if cfg!(target_os = "macos") => {
+                vec![ExecutionProvider::CoreMl, ExecutionProvider::Cpu]
+            }
+            ExecutionProvider::Auto if cfg!(target_os = "windows") => {
+                vec![ExecutionProvider::DirectMl, ExecutionProvider::Cpu]
+            }
+            ExecutionProvider::Auto => vec![ExecutionProvider::Cuda, ExecutionProvider::Cpu],
+            other => vec![*other, ExecutionProvider::Cpu],
+        }
+    }
+}
+
+/// Axis order a model wants its image input in. The prepare functions always
+/// build NHWC; [`TensorLayout::arrange`] reorders for channels-first models.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TensorLayout {
+    #[default]
+    Nhwc,
+    Nchw,
+}
+
+impl TensorLayout {
+    /// Reads the first input's shape. See [`TensorLayout::of_shape`].
+    pub fn of_session(session: &Session) -> Self {
+        let input_type = session.inputs.first().map(|input| &input.input_type);
+        let Some(ValueType::Tensor { shape, .. }) = input_type else {
+            return Self::Nhwc;
+        };
+        Self::of_shape(shape)
+    }
+
+    /// Channels-first is assumed when dim 1 is 3 and the last dim is not;
+    /// dynamic dims (-1) never match, so a dynamic batch is fine.
+    pub fn of_shape(shape: &[i64]) -> Self {
+        if shape.len() == 4 && shape[1] == 3 && shape[3] != 3 {
+            Self::Nchw
+        } else {
+            Self::Nhwc
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Nhwc => "NHWC",
+            Self::Nchw => "NCHW",
+        }
+    }
+
+    /// `input` is (N, H, W, 3) as the prepare functions return it.
+    pub fn arrange(self, input: Array4<f32>) -> Array4<f32> {
+        match self {
+            Self::Nhwc => input,
+            Self::Nchw => input
+                .permuted_axes([0, 3, 1, 2])
+                .as_standard_layout()
+                .into_owned(),
+        }
+    }
+}
+
+/// Names of the handpose model outputs. The defaults are the names MediaPipe's
+/// hand_landmark export uses.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HandposeOutputNames {
+    pub landmarks: String,
+    pub confidence: String,
+    pub handedness: String,
+}
+
+impl Default for HandposeOutputNames {
+    fn default() -> Self {
+        Self {
+            landmarks: "Identity".to_string(),
+            confidence: "Identity_1".to_string(),
+            handedness: "Identity_2".to_string(),
+        }
+    }
+}
+
+/// Which output index holds what, resolved once per session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HandposeOutputMap {
+    pub landmarks: usize,
+    pub confidence: Option<usize>,
+    pub handedness: Option<usize>,
+}
+
+impl HandposeOutputMap {
+    pub fn of_session(session: &Session, names: &HandposeOutputNames) -> Result<Self> {
+        let outputs: Vec<(&str, Option<usize>)> = session
+            .outputs
+            .iter()
+            .map(|output| (output.name.as_str(), element_count(&output.output_type)))
+            .collect();
+        Self::resolve(names, &outputs)
+    }
+
+    /// `outputs` are name and element count pairs in session order. When any
+    /// configured name is missing, the first output with 63 values is taken as
+    /// the landmarks and the first two single values as score then handedness.
+    pub fn resolve(names: &HandposeOutputNames, outputs: &[(&str, Option<usize>)]) -> Result<Self> {
+        let by_name = |name: &str| outputs.iter().position(|(output, _)| *output == name);
+        if let (Some(landmarks), Some(confidence), Some(handedness)) = (
+            by_name(&names.landmarks),
+            by_name(&names.confidence),
+            by_name(&names.handedness),
+        ) {
+            return Ok(Self {
+                landmarks,
+                confidence: Some(confidence),
+                handedness: Some(handedness),
+            });
+        }
+
+        let landmark_values = NUM_LANDMARKS * 3;
+        let landmarks = outputs
+            .iter()
+            .position(|(_, len)| *len == Some(landmark_values))
+            .ok_or_else(|| {
+                anyhow!(
+                    "no output is named {:?} or holds {landmark_values} values",
+                    names.landmarks
+                )
+            })?;
+        let mut scalars = outputs
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, len))| *len == Some(1))
+            .map(|(idx, _)| idx);
+        Ok(Self {
+            landmarks,
+            confidence: scalars.next(),
+            handedness: scalars.next(),
+        })
+    }
+
+    /// e.g. `landmarks=Identity confidence=Identity_1 handedness=-`.
+    pub fn describe(&self, session: &Session) -> String {
+        let name = |idx: Option<usize>| {
+            idx.and_then(|idx| session.outputs.get(idx))
+                .map_or("-", |output| output.name.as_str())
+        };
+        format!(
+            "landmarks={} confidence={} handedness={}",
+            name(Some(self.landmarks)),
+            name(self.confidence),
+            name(self.handedness)
+        )
+    }
+}
+
+/// Dynamic dims count as 1, which they are for the batch.
+fn element_count(value_type: &ValueType) -> Option<usize> {
+    match value_type {
+        ValueType::Tensor { shape, .. } => {
+            Some(shape.iter().map(|&dim| dim.max(1) as usize).product())
+        }
+        _ => None,
+    }
+}
+
+/// First value of output `idx`, or 0 when the model has no such output.
+pub fn output_scalar(outputs: &SessionOutputs<'_>, idx: Option<usize>) -> f32 {
+    let Some(idx) = idx else {
+        return 0.0;
+    };
+    outputs[idx]
+        .try_extract_array::<f32>()
+        .ok()
+        .and_then(|arr| arr.iter().next().copied())
+        .unwrap_or(0.0)
+}
+
+/// Values of output `idx` split evenly over `batch` entries, the first of
+/// each share; 0 when the model has no such output or too few values.
+pub fn output_batch_scalars(
+    outputs: &SessionOutputs<'_>,
+    idx: Option<usize>,
+    batch: usize,
+) -> Vec<f32> {
+    let values: Vec<f32> = idx
+        .and_then(|idx| outputs[idx].try_extract_array::<f32>().ok())
+        .map(|arr| arr.iter().copied().collect())
+        .unwrap_or_default();
+    let share = values.len() / batch.max(1);
+    (0..batch)
+        .map(|entry| {
+            if share > 0 {
+                values[entry * share]
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Whether the first input's leading dim is dynamic, so several crops can go
+/// through one session call. A fixed batch of 1 means one call per hand.
+pub fn has_dynamic_batch(session: &Session) -> bool {
+    match session.inputs.first().map(|input| &input.input_type) {
+        Some(ValueType::Tensor { shape, .. }) => !shape.is_empty() && shape[0] < 0,
+        _ => false,
+    }
+}
+
+/// One input or output of a model session.
+#[derive(Clone, Debug, Serialize)]
+pub struct TensorInfo {
+    pub name: String,
+    /// The value type as ORT prints it.
+    pub value_type: String,
+    /// `None` for anything but a tensor; dynamic dims are negative.
+    pub shape: Option<Vec<i64>>,
+}
+
+impl TensorInfo {
+    fn new(name: &str, value_type: &ValueType) -> Self {
+        let shape = match value_type {
+            ValueType::Tensor { shape, .. } => Some(shape.to_vec()),
+            _ => None,
+        };
+        Self {
+            name: name.to_string(),
+            value_type: format!("{value_type:?}"),
+            shape,
+        }
+    }
+}
+
+/// What a session takes and returns, both in session order.
+#[derive(Clone, Debug, Serialize)]
+pub struct SessionInfo {
+    pub inputs: Vec<TensorInfo>,
+    pub outputs: Vec<TensorInfo>,
+}
+
+impl SessionInfo {
+    pub fn of_session(session: &Session) -> Self {
+        Self {
+            inputs: session
+                .inputs
+                .iter()
+                .map(|input| TensorInfo::new(&input.name, &input.input_type))
+                .collect(),
+            outputs: session
+                .outputs
+                .iter()
+                .map(|output| TensorInfo::new(&output.name, &output.output_type))
+                .collect(),
+        }
+    }
+}
+
+/// Builds a session with the requested provider registered. Providers that fail
+/// to register fall back to CPU; the provider actually used is returned.
+pub fn build_session(
+    model_path: &std::path::Path,
+    provider: ExecutionProvider,
+) -> Result<(Session, ExecutionProvider)> {
+    for candidate in provider.candidates() {
+        let session = session_builder(candidate).and_then(|builder| {
+            builder
+                .commit_from_file(model_path)
+                .map_err(anyhow::Error::from)
+        });
+        match session {
+            Ok(session) => return Ok((session, candidate)),
+            Err(err) if candidate != ExecutionProvider::Cpu => {
+                log::warn!(
+                    "{} execution provider unavailable for {}, falling back: {err:#}",
+                    candidate.label(),
+                    model_path.display()
+                );
+            }
+            Err(err) => {
+                return Err(err).with_context(|| {
+                    format!("failed to load session from {}", model_path.display())
+                });
+            }
+        }
+    }
+
+    Err(anyhow!(
+        "no execution provider available for {}",
+        model_path.display()
+    ))
+}
+
+fn session_builder(provider: ExecutionProvider) -> Result<SessionBuilder> {
+    let builder = Session::builder()?
+        .with_optimization_level(GraphOptimizationLevel::Level3)?
+        .with_intra_threads(2)?;
+
+    let dispatch = match provider {
+        ExecutionProvider::Cpu | ExecutionProvider::Auto => return Ok(builder),
+        ExecutionProvider::CoreMl => CoreMLExecutionProvider::default().build(),
+        ExecutionProvider::Cuda => CUDAExecutionProvider::default().build(),
+        ExecutionProvider::DirectMl => DirectMLExecutionProvider::default().build(),
+    };
+
+    Ok(builder.with_execution_providers([dispatch.error_on_failure()])?)
+}
+
 #[derive(Clone, Debug)]
-pub struct HandposeOutput {
+pub struct HandLandmarks {
+    pub track_id: u64,
     pub raw_landmarks: Vec<[f32; 3]>,
     pub projected_landmarks: Vec<(f32, f32)>,
     pub confidence: f32,
-    pub handedness: f32,
+    /// `None` when the model has no handedness output.
+    pub handedness: Option<f32>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InferenceTimings {
+    /// `None` when the frame reused tracked hands instead of detecting palms.
+    pub palm_detect: Option<std::time::Duration>,
+    /// Cutting the rotated hand crops, already included in `handpose`.
+    pub crop_prep: std::time::Duration,
+    pub handpose: std::time::Duration,
+}
+
+#[derive(Debug)]
+pub struct HandposeOutput {
+    pub hands: Vec<HandLandmarks>,
     pub palm_regions: Vec<crate::types::PalmRegion>,
+    /// Only set while the palm config keeps candidates for the debug overlay.
+    pub palm_debug: Option<crate::types::PalmDebug>,
+    pub timings: InferenceTimings,
+    /// Resolution palm detection and the hand crops actually sampled.
+    pub input_size: (u32, u32),
+    /// Set when palm detection failed this frame; tracked hands still went
+    /// through handpose.
+    pub palm_error: Option<super::error::RecognizerError>,
+}
+
+impl HandposeOutput {
+    /// Stands in for a frame that was not run through the models.
+    pub fn skipped() -> Self {
+        Self {
+            hands: Vec::new(),
+            palm_regions: Vec::new(),
+            palm_debug: None,
+            timings: InferenceTimings::default(),
+            input_size: (0, 0),
+            palm_error: None,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -46,28 +473,17 @@ pub fn prepare_frame_with_size(
     frame: &Frame,
     target_size: u32,
 ) -> Result<(Array4<f32>, LetterboxInfo)> {
-    let expected_len = (frame.width as usize)
-        .saturating_mul(frame.height as usize)
-        .saturating_mul(4);
-    if frame.rgba.len() != expected_len {
-        return Err(anyhow!(
-            "frame buffer size mismatch: got {}, expected {}",
-            frame.rgba.len(),
-            expected_len
-        ));
-    }
+    check_frame_len(frame)?;
 
     let scale = target_size as f32 / (frame.width.max(frame.height) as f32);
     let new_w = (frame.width as f32 * scale).round().max(1.0) as u32;
     let new_h = (frame.height as f32 * scale).round().max(1.0) as u32;
 
-    let src_image = fir::images::Image::from_vec_u8(
-        frame.width,
-        frame.height,
-        frame.rgba.clone(),
-        fir::PixelType::U8x4,
-    )?;
-    let mut dst_image = fir::images::Image::new(new_w, new_h, fir::PixelType::U8x4);
+    let channels = frame.layout.channels();
+    let pixel_type = fir_pixel_type(frame.layout);
+    let src_image =
+        fir::images::ImageRef::new(frame.width, frame.height, &frame.pixels, pixel_type)?;
+    let mut dst_image = fir::images::Image::new(new_w, new_h, pixel_type);
     let mut resizer = fir::Resizer::new();
     let resize_options = fir::ResizeOptions::new()
         .resize_alg(fir::ResizeAlg::Interpolation(fir::FilterType::Bilinear));
@@ -78,14 +494,11 @@ pub fn prepare_frame_with_size(
 
     let pad_x = ((target_size as i64 - new_w as i64) / 2).max(0) as usize;
     let pad_y = ((target_size as i64 - new_h as i64) / 2).max(0) as usize;
-    let mut canvas = vec![0u8; (target_size as usize) * (target_size as usize) * 4];
-    for px in canvas.chunks_mut(4) {
-        px[3] = 255;
-    }
-    let dst_stride = target_size as usize * 4;
-    let src_stride = new_w as usize * 4;
+    let mut canvas = vec![0u8; (target_size as usize) * (target_size as usize) * channels];
+    let dst_stride = target_size as usize * channels;
+    let src_stride = new_w as usize * channels;
     for row in 0..(new_h as usize) {
-        let dst_offset = (pad_y + row) * dst_stride + pad_x * 4;
+        let dst_offset = (pad_y + row) * dst_stride + pad_x * channels;
         let src_offset = row * src_stride;
         let dst_slice = &mut canvas[dst_offset..dst_offset + src_stride];
         let src_slice = &resized[src_offset..src_offset + src_stride];
@@ -93,7 +506,7 @@ pub fn prepare_frame_with_size(
     }
 
     let normalized: Vec<f32> = canvas
-        .par_chunks_exact(4)
+        .par_chunks_exact(channels)
         .flat_map_iter(|px| {
             [
                 px[0] as f32 / 255.0,
@@ -119,6 +532,26 @@ pub fn prepare_frame_with_size(
     Ok((input, letterbox))
 }
 
+/// The resizer's name for `layout`.
+pub fn fir_pixel_type(layout: PixelLayout) -> fir::PixelType {
+    match layout {
+        PixelLayout::Rgb => fir::PixelType::U8x3,
+        PixelLayout::Rgba => fir::PixelType::U8x4,
+    }
+}
+
+fn check_frame_len(frame: &Frame) -> Result<()> {
+    let expected_len = frame.expected_len();
+    if frame.pixels.len() != expected_len {
+        return Err(anyhow!(
+            "frame buffer size mismatch: got {}, expected {}",
+            frame.pixels.len(),
+            expected_len
+        ));
+    }
+    Ok(())
+}
+
 pub fn decode_landmarks(flat: &[f32]) -> Result<Vec<[f32; 3]>> {
     if flat.len() < NUM_LANDMARKS * 3 {
         return Err(anyhow!(
@@ -156,32 +589,35 @@ pub fn prepare_rotated_crop(
     angle: f32,
     output_size: u32,
 ) -> Result<(Array4<f32>, CropTransform)> {
-    let expected_len = (frame.width as usize)
-        .saturating_mul(frame.height as usize)
-        .saturating_mul(4);
-    if frame.rgba.len() != expected_len {
-        return Err(anyhow!(
-            "frame buffer size mismatch: got {}, expected {}",
-            frame.rgba.len(),
-            expected_len
-        ));
-    }
-    let mut data =
-        Vec::with_capacity((output_size as usize).saturating_mul(output_size as usize * 3));
+    check_frame_len(frame)?;
+    let size = output_size as usize;
+    let mut data = vec![0.0; size.saturating_mul(size * 3)];
     let half = output_size as f32 / 2.0;
     let scale = side / output_size as f32;
     let cos = angle.cos();
     let sin = angle.sin();
+    // One output pixel to the right is this far in the frame.
+    let (step_x, step_y) = (scale * cos, scale * sin);
+    let sampler = Sampler::new(frame);
 
-    for y in 0..output_size {
-        let dy = (y as f32 + 0.5 - half) * scale;
-        for x in 0..output_size {
-            let dx = (x as f32 + 0.5 - half) * scale;
-            let src_x = center.0 + dx * cos - dy * sin;
-            let src_y = center.1 + dx * sin + dy * cos;
-            let rgb = sample_rgb(frame, src_x, src_y);
-            data.extend_from_slice(&rgb);
-        }
+    if size > 0 {
+        data.par_chunks_exact_mut(size * 3)
+            .enumerate()
+            .for_each(|(y, row)| {
+                let dy = (y as f32 + 0.5 - half) * scale;
+                let dx = (0.5 - half) * scale;
+                // Stepped relative to the center, where the offsets are small,
+                // so the rounding that builds up along a row stays far below a
+                // pixel.
+                let mut offset_x = dx * cos - dy * sin;
+                let mut offset_y = dx * sin + dy * cos;
+                for pixel in row.chunks_exact_mut(3) {
+                    let rgb = sampler.sample(center.0 + offset_x, center.1 + offset_y);
+                    pixel.copy_from_slice(&rgb);
+                    offset_x += step_x;
+                    offset_y += step_y;
+                }
+            });
     }
 
     let array =
@@ -227,44 +663,80 @@ impl CropTransform {
     }
 }
 
-fn sample_rgb(frame: &Frame, x: f32, y: f32) -> [f32; 3] {
-    if x.is_nan() || y.is_nan() {
-        return [0.0, 0.0, 0.0];
+/// Bilinear sampling of a frame's first three channels as 0–1, black outside
+/// the frame.
+struct Sampler<'a> {
+    pixels: &'a [u8],
+    width: i32,
+    height: i32,
+    channels: usize,
+    stride: usize,
+}
+
+impl<'a> Sampler<'a> {
+    fn new(frame: &'a Frame) -> Self {
+        let channels = frame.layout.channels();
+        Self {
+            pixels: &frame.pixels,
+            width: frame.width as i32,
+            height: frame.height as i32,
+            channels,
+            stride: frame.width as usize * channels,
+        }
     }
-    let x0 = x.floor();
-    let y0 = y.floor();
-    let x1 = x0 + 1.0;
-    let y1 = y0 + 1.0;
-
-    let (w, h) = (frame.width as i32, frame.height as i32);
-    let fetch = |cx: f32, cy: f32| -> [f32; 3] {
-        let ix = cx as i32;
-        let iy = cy as i32;
-        if ix < 0 || iy < 0 || ix >= w || iy >= h {
+
+    fn sample(&self, x: f32, y: f32) -> [f32; 3] {
+        if x.is_nan() || y.is_nan() {
             return [0.0, 0.0, 0.0];
         }
-        let idx = ((iy as u32 * frame.width + ix as u32) as usize) * 4;
-        if idx + 2 >= frame.rgba.len() {
+        let x0 = x.floor();
+        let y0 = y.floor();
+        let (fx, fy) = (x - x0, y - y0);
+        let (ix, iy) = (x0 as i32, y0 as i32);
+
+        // Most of a crop lies inside the frame, where the four texels can be
+        // read without checking each one.
+        let [c00, c10, c01, c11] =
+            if ix >= 0 && iy >= 0 && ix + 1 < self.width && iy + 1 < self.height {
+                let idx = iy as usize * self.stride + ix as usize * self.channels;
+                [
+                    self.texel(idx),
+                    self.texel(idx + self.channels),
+                    self.texel(idx + self.stride),
+                    self.texel(idx + self.stride + self.channels),
+                ]
+            } else {
+                let (jx, jy) = (ix.saturating_add(1), iy.saturating_add(1));
+                [
+                    self.fetch(ix, iy),
+                    self.fetch(jx, iy),
+                    self.fetch(ix, jy),
+                    self.fetch(jx, jy),
+                ]
+            };
+
+        let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+        let blend =
+            |c: usize| lerp(lerp(c00[c], c10[c], fx), lerp(c01[c], c11[c], fx), fy) * (1.0 / 255.0);
+        [blend(0), blend(1), blend(2)]
+    }
+
+    fn fetch(&self, ix: i32, iy: i32) -> [f32; 3] {
+        if ix < 0 || iy < 0 || ix >= self.width || iy >= self.height {
+            return [0.0, 0.0, 0.0];
+        }
+        let idx = iy as usize * self.stride + ix as usize * self.channels;
+        if idx + 2 >= self.pixels.len() {
             return [0.0, 0.0, 0.0];
         }
+        self.texel(idx)
+    }
+
+    fn texel(&self, idx: usize) -> [f32; 3] {
         [
-            frame.rgba[idx] as f32 / 255.0,
-            frame.rgba[idx + 1] as f32 / 255.0,
-            frame.rgba[idx + 2] as f32 / 255.0,
+            self.pixels[idx] as f32,
+            self.pixels[idx + 1] as f32,
+            self.pixels[idx + 2] as f32,
         ]
-    };
-
-    let fx = x - x0;
-    let fy = y - y0;
-    let c00 = fetch(x0, y0);
-    let c10 = fetch(x1, y0);
-    let c01 = fetch(x0, y1);
-    let c11 = fetch(x1, y1);
-
-    let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
-    [
-        lerp(lerp(c00[0], c10[0], fx), lerp(c01[0], c11[0], fx), fy),
-        lerp(lerp(c00[1], c10[1], fx), lerp(c01[1], c11[1], fx), fy),
-        lerp(lerp(c00[2], c10[2], fx), lerp(c01[2], c11[2], fx), fy),
-    ]
+    }
 }
@@ -0,0 +1,72 @@
+use std::{collections::HashMap, f32::consts::PI};
+
+/// Weight of the new crop when blending it with the track's last one.
+const CROP_BLEND: f32 = 0.6;
+/// Overlap of the crop squares below which the hand is taken to have moved
+/// and the new crop is used as is.
+const CROP_BLEND_IOU: f32 = 0.5;
+
+/// Steadies the rotated handpose crop, `(center, side, angle)`, of each
+/// track, so detector jitter does not make the landmarks jump. A crop that
+/// still overlaps the track's last one is blended with it; one that does not
+/// replaces it on the spot.
+#[derive(Debug, Default)]
+pub struct CropSmoother {
+    crops: HashMap<u64, ((f32, f32), f32, f32)>,
+}
+
+impl CropSmoother {
+    /// Blends `crop` into `track_id`'s last crop and returns the crop to use.
+    pub fn smooth(
+        &mut self,
+        track_id: u64,
+        crop: ((f32, f32), f32, f32),
+    ) -> ((f32, f32), f32, f32) {
+        let (center, side, angle) = crop;
+        let smoothed = match self.crops.get(&track_id) {
+            Some(&(last_center, last_side, last_angle))
+                if square_iou(center, side, last_center, last_side) >= CROP_BLEND_IOU =>
+            {
+                let blend = |last: f32, new: f32| last + (new - last) * CROP_BLEND;
+                (
+                    (
+                        blend(last_center.0, center.0),
+                        blend(last_center.1, center.1),
+                    ),
+                    blend(last_side, side),
+                    wrap_angle(last_angle + wrap_angle(angle - last_angle) * CROP_BLEND),
+                )
+            }
+            _ => crop,
+        };
+        self.crops.insert(track_id, smoothed);
+        smoothed
+    }
+
+    /// Forgets the tracks `keep` turns down.
+    pub fn retain(&mut self, mut keep: impl FnMut(u64) -> bool) {
+        self.crops.retain(|&id, _| keep(id));
+    }
+
+    pub fn reset(&mut self) {
+        self.crops.clear();
+    }
+}
+
+/// Intersection over union of two axis-aligned squares given by centre and side.
+pub(super) fn square_iou(a: (f32, f32), a_side: f32, b: (f32, f32), b_side: f32) -> f32 {
+    let overlap = |a: f32, b: f32| {
+        let lo = (a - a_side * 0.5).max(b - b_side * 0.5);
+        let hi = (a + a_side * 0.5).min(b + b_side * 0.5);
+        (hi - lo).max(0.0)
+    };
+    let inter = overlap(a.0, b.0) * overlap(a.1, b.1);
+    let union = a_side * a_side + b_side * b_side - inter;
+    if union <= 0.0 { 0.0 } else { inter / union }
+}
+
+/// `radians` brought into `[-π, π)`.
+fn wrap_angle(radians: f32) -> f32 {
+    let two_pi = 2.0 * PI;
+    radians - two_pi * ((radians + PI) / two_pi).floor()
+}
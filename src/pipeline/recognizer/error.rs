@@ -0,0 +1,70 @@
+use std::path::{Path, PathBuf};
+
+/// Why the recognizer could not start, or could not get through a frame.
+#[derive(Debug, thiserror::Error)]
+pub enum RecognizerError {
+    #[error("model file {} not found", .0.display())]
+    ModelMissing(PathBuf),
+    #[error("failed to download or verify model {}", .path.display())]
+    ModelDownloadFailed {
+        path: PathBuf,
+        source: anyhow::Error,
+    },
+    #[error("failed to load model {}", .path.display())]
+    ModelLoadFailed {
+        path: PathBuf,
+        source: anyhow::Error,
+    },
+    #[error("palm detection failed")]
+    PalmDetectFailed(#[source] anyhow::Error),
+    #[error("handpose inference failed")]
+    InferenceFailed(#[source] anyhow::Error),
+    #[error("unexpected output shape {got:?}, expected {expected}")]
+    BadOutputShape {
+        expected: &'static str,
+        got: Vec<usize>,
+    },
+}
+
+impl RecognizerError {
+    /// A session that failed to build, reported as [`RecognizerError::ModelMissing`]
+    /// when the file is not there at all.
+    pub fn load_failed(path: &Path, source: anyhow::Error) -> Self {
+        if path.is_file() {
+            RecognizerError::ModelLoadFailed {
+                path: path.to_path_buf(),
+                source,
+            }
+        } else {
+            RecognizerError::ModelMissing(path.to_path_buf())
+        }
+    }
+
+    /// Keeps a `RecognizerError` raised further down, e.g. a bad shape, and
+    /// wraps anything else with `wrap`.
+    pub(crate) fn from_anyhow(err: anyhow::Error, wrap: fn(anyhow::Error) -> Self) -> Self {
+        err.downcast().unwrap_or_else(wrap)
+    }
+
+    /// The model file the error is about, for the error panel.
+    pub fn model_path(&self) -> Option<&Path> {
+        match self {
+            RecognizerError::ModelMissing(path)
+            | RecognizerError::ModelDownloadFailed { path, .. }
+            | RecognizerError::ModelLoadFailed { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// The message followed by every cause, as `{:#}` prints an anyhow error.
+    pub fn describe(&self) -> String {
+        let mut text = self.to_string();
+        let mut source = std::error::Error::source(self);
+        while let Some(cause) = source {
+            text.push_str(": ");
+            text.push_str(&cause.to_string());
+            source = cause.source();
+        }
+        text
+    }
+}
@@ -0,0 +1,283 @@
+use std::{sync::Arc, time::Duration};
+
+use anyhow::{Context, Result};
+use fast_image_resize as fir;
+
+use super::common::{CropTransform, fir_pixel_type};
+use crate::types::{Frame, FramePool, PalmRegion};
+
+/// The controller never shrinks the long side below this.
+const MIN_INPUT_DIMENSION: u32 = 256;
+/// Frames between cap adjustments, so a single slow frame does not move it.
+const ADJUST_INTERVAL: u32 = 15;
+/// Factor applied to the cap per adjustment while over budget.
+const ADJUST_STEP: f32 = 0.85;
+/// Below this fraction of the budget the cap grows back towards the maximum.
+const RECOVER_RATIO: f32 = 0.6;
+const LATENCY_SMOOTHING: f32 = 0.2;
+/// One frame in inference plus one being filled.
+const SCALED_POOL_IDLE: usize = 2;
+
+/// Per-axis factor from original frame coordinates to the downscaled input.
+/// The axes differ slightly because both sides are rounded to whole pixels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrameScale {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl FrameScale {
+    pub const IDENTITY: Self = Self { x: 1.0, y: 1.0 };
+
+    pub fn between(original: (u32, u32), scaled: (u32, u32)) -> Self {
+        Self {
+            x: scaled.0 as f32 / original.0.max(1) as f32,
+            y: scaled.1 as f32 / original.1.max(1) as f32,
+        }
+    }
+
+    pub fn to_scaled(self, (x, y): (f32, f32)) -> (f32, f32) {
+        (x * self.x, y * self.y)
+    }
+
+    pub fn to_original(self, (x, y): (f32, f32)) -> (f32, f32) {
+        (x / self.x, y / self.y)
+    }
+
+    /// Lengths use the mean of both axes; rounding keeps them well under a
+    /// pixel apart.
+    pub fn length_to_scaled(self, length: f32) -> f32 {
+        length * (self.x + self.y) / 2.0
+    }
+
+    pub fn region_to_original(self, region: PalmRegion) -> PalmRegion {
+        let [x0, y0, x1, y1] = region.bbox;
+        let (x0, y0) = self.to_original((x0, y0));
+        let (x1, y1) = self.to_original((x1, y1));
+        PalmRegion {
+            bbox: [x0, y0, x1, y1],
+            landmarks: region
+                .landmarks
+                .into_iter()
+                .map(|point| self.to_original(point))
+                .collect(),
+            score: region.score,
+        }
+    }
+
+    /// `transform` sampled the downscaled input; the result projects crop
+    /// coordinates straight onto the original frame.
+    pub fn crop_to_original(self, transform: CropTransform, original: (u32, u32)) -> CropTransform {
+        CropTransform {
+            center: self.to_original(transform.center),
+            side: transform.side * 2.0 / (self.x + self.y),
+            orig_w: original.0,
+            orig_h: original.1,
+            ..transform
+        }
+    }
+}
+
+/// Shrinks frames to a maximum long side before palm detection, and lowers that
+/// cap further while inference runs over its time budget.
+pub struct InputScaler {
+    /// Configured cap; 0 turns scaling off.
+    max_dimension: u32,
+    budget: Duration,
+    /// Cap currently in effect, at most `max_dimension`.
+    cap: u32,
+    smoothed_ms: Option<f32>,
+    frames_since_adjust: u32,
+    resizer: fir::Resizer,
+    pool: Arc<FramePool>,
+}
+
+impl InputScaler {
+    pub fn new(max_dimension: u32, budget: Duration) -> Self {
+        Self {
+            max_dimension,
+            budget,
+            cap: max_dimension,
+            smoothed_ms: None,
+            frames_since_adjust: 0,
+            resizer: fir::Resizer::new(),
+            pool: FramePool::new(SCALED_POOL_IDLE),
+        }
+    }
+
+    /// Starts over from the new maximum when it changed.
+    pub fn configure(&mut self, max_dimension: u32, budget: Duration) {
+        if max_dimension != self.max_dimension {
+            self.max_dimension = max_dimension;
+            self.cap = max_dimension;
+            self.smoothed_ms = None;
+            self.frames_since_adjust = 0;
+        }
+        self.budget = budget;
+    }
+
+    /// The downscaled copy of `frame` and its scale, or `None` when the frame
+    /// already fits under the cap.
+    pub fn downscale(&mut self, frame: &Frame) -> Result<Option<(Frame, FrameScale)>> {
+        let long_side = frame.width.max(frame.height);
+        if self.cap == 0 || long_side <= self.cap {
+            return Ok(None);
+        }
+        let ratio = self.cap as f32 / long_side as f32;
+        let width = (frame.width as f32 * ratio).round().max(1.0) as u32;
+        let height = (frame.height as f32 * ratio).round().max(1.0) as u32;
+
+        let pixel_type = fir_pixel_type(frame.layout);
+        let src = fir::images::ImageRef::new(frame.width, frame.height, &frame.pixels, pixel_type)
+            .context("frame buffer does not match its size")?;
+        let mut pixels = self.pool.take(frame.layout.buffer_len(width, height));
+        let mut dst = fir::images::Image::from_slice_u8(width, height, &mut pixels, pixel_type)
+            .context("scaled buffer does not match its size")?;
+        let options = fir::ResizeOptions::new()
+            .resize_alg(fir::ResizeAlg::Interpolation(fir::FilterType::Bilinear));
+        self.resizer
+            .resize(&src, &mut dst, Some(&options))
+            .context("input downscale failed")?;
+
+        let scaled = Frame {
+            pixels,
+            layout: frame.layout,
+            width,
+            height,
+            timestamp: frame.timestamp,
+            acquired_at: frame.acquired_at,
+        };
+        let scale = FrameScale::between((frame.width, frame.height), (width, height));
+        Ok(Some((scaled, scale)))
+    }
+
+    /// Feeds one frame's inference time into the controller.
+    pub fn observe(&mut self, elapsed: Duration) {
+        if self.max_dimension == 0 {
+            return;
+        }
+        let ms = elapsed.as_secs_f32() * 1000.0;
+        let smoothed = self
+            .smoothed_ms
+            .map_or(ms, |prev| prev + (ms - prev) * LATENCY_SMOOTHING);
+        self.smoothed_ms = Some(smoothed);
+        self.frames_since_adjust += 1;
+        if self.frames_since_adjust < ADJUST_INTERVAL {
+            return;
+        }
+
+        let budget_ms = self.budget.as_secs_f32() * 1000.0;
+        let previous = self.cap;
+        if smoothed > budget_ms {
+            let floor = MIN_INPUT_DIMENSION.min(self.max_dimension);
+            self.cap = ((self.cap as f32 * ADJUST_STEP) as u32).max(floor);
+        } else if smoothed < budget_ms * RECOVER_RATIO {
+            self.cap = ((self.cap as f32 / ADJUST_STEP).round() as u32).min(self.max_dimension);
+        }
+        if self.cap != previous {
+            log::info!(
+                "input cap {previous} -> {} px, inference {smoothed:.1} ms of {budget_ms:.0} ms",
+                self.cap
+            );
+            self.frames_since_adjust = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 4K frame squeezed to 1000 px, where the rounded height leaves the two
+    /// axes with slightly different, non-integer ratios.
+    const ORIGINAL: (u32, u32) = (3840, 2160);
+    const SCALED: (u32, u32) = (1000, 563);
+
+    fn close(a: (f32, f32), b: (f32, f32), tolerance: f32) -> bool {
+        (a.0 - b.0).abs() <= tolerance && (a.1 - b.1).abs() <= tolerance
+    }
+
+    #[test]
+    fn points_come_back_to_where_they_started() {
+        let scale = FrameScale::between(ORIGINAL, SCALED);
+        assert_ne!(scale.x, scale.y);
+        for point in [
+            (0.0, 0.0),
+            (1.5, 2159.0),
+            (1234.5, 987.25),
+            (3839.0, 1080.0),
+        ] {
+            let back = scale.to_original(scale.to_scaled(point));
+            assert!(close(back, point, 1e-3), "{point:?} came back as {back:?}");
+        }
+        let corner = scale.to_original((SCALED.0 as f32, SCALED.1 as f32));
+        assert!(close(corner, (3840.0, 2160.0), 1e-2), "{corner:?}");
+    }
+
+    #[test]
+    fn a_palm_region_maps_back_to_the_original_frame() {
+        let scale = FrameScale::between(ORIGINAL, SCALED);
+        let original = PalmRegion {
+            bbox: [1000.0, 500.0, 1600.0, 1300.0],
+            landmarks: vec![(1200.0, 700.0), (1450.5, 1100.25)],
+            score: 0.9,
+        };
+        let [x0, y0, x1, y1] = original.bbox;
+        let (x0, y0) = scale.to_scaled((x0, y0));
+        let (x1, y1) = scale.to_scaled((x1, y1));
+        let detected = PalmRegion {
+            bbox: [x0, y0, x1, y1],
+            landmarks: original
+                .landmarks
+                .iter()
+                .map(|point| scale.to_scaled(*point))
+                .collect(),
+            score: original.score,
+        };
+
+        let back = scale.region_to_original(detected);
+        for (seen, expected) in back.bbox.iter().zip(original.bbox) {
+            assert!((seen - expected).abs() < 1e-2, "{:?}", back.bbox);
+        }
+        for (seen, expected) in back.landmarks.iter().zip(&original.landmarks) {
+            assert!(close(*seen, *expected, 1e-2), "{seen:?} for {expected:?}");
+        }
+        assert_eq!(back.score, 0.9);
+    }
+
+    #[test]
+    fn a_crop_projects_onto_the_original_frame_as_on_the_scaled_one() {
+        let scale = FrameScale::between(ORIGINAL, SCALED);
+        let on_scaled = CropTransform {
+            center: (480.5, 270.25),
+            side: 210.0,
+            angle: 0.4,
+            output_size: 224,
+            orig_w: SCALED.0,
+            orig_h: SCALED.1,
+        };
+        let on_original = scale.crop_to_original(on_scaled.clone(), ORIGINAL);
+        assert_eq!((on_original.orig_w, on_original.orig_h), ORIGINAL);
+
+        for (x, y) in [(0.0, 0.0), (112.0, 112.0), (30.5, 200.0), (223.0, 5.0)] {
+            let expected = scale.to_original(on_scaled.project(x, y));
+            let seen = on_original.project(x, y);
+            // Within a pixel despite the crop side using the mean of both axes.
+            assert!(close(seen, expected, 1.0), "{seen:?} for {expected:?}");
+        }
+    }
+
+    #[test]
+    fn the_cap_drops_over_budget_and_recovers_under_it() {
+        let mut scaler = InputScaler::new(640, Duration::from_millis(33));
+        for _ in 0..ADJUST_INTERVAL {
+            scaler.observe(Duration::from_millis(50));
+        }
+        assert_eq!(scaler.cap, 544);
+        // The smoothed time has to come down before the cap grows back.
+        for _ in 0..ADJUST_INTERVAL * 4 {
+            scaler.observe(Duration::from_millis(5));
+        }
+        assert_eq!(scaler.cap, 640);
+    }
+}
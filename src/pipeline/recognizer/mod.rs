@@ -1,133 +1,973 @@
-mod common;
+pub mod common;
+pub mod crop_smoothing;
+mod error;
+mod input_scale;
+pub mod motion;
+#[cfg(feature = "backend-ort")]
 mod ort;
-pub(crate) mod palm;
+pub mod palm;
+pub(crate) mod quality;
+mod smoothing;
+mod tracking;
+#[cfg(feature = "backend-tract")]
+mod tract;
 
-use std::{path::PathBuf, thread};
+#[cfg(not(any(feature = "backend-ort", feature = "backend-tract")))]
+compile_error!("enable `backend-ort`, `backend-tract` or both");
 
-use crossbeam_channel::{Receiver, Sender};
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+    thread,
+    time::{Instant, SystemTime},
+};
+
+use crossbeam_channel::{Receiver, Sender, select};
 
 use crate::{
-    gesture::GestureClassifier,
-    model_download::{default_handpose_estimator_model_path, default_palm_detector_model_path},
-    types::{Frame, GestureResult, RecognizedFrame},
+    gesture::{
+        DragTracker, DwellDetector, GestureClassifier, GestureEventTracker, SequenceMatcher,
+        plugin::{GesturePlugin, PluginFactory},
+    },
+    model_download::{
+        ModelDownloadEvent, default_handpose_estimator_model_path,
+        default_palm_detector_model_path, ensure_handpose_estimator_model_ready,
+        ensure_palm_detector_model_ready, handpose_estimator_model_path_from_env,
+        palm_detector_model_path_from_env,
+    },
+    pipeline::{
+        delivery::Delivery,
+        latest::take_latest,
+        metrics::{Channel, MetricsHandle, Stage},
+        recorder::Recorder,
+    },
+    types::{
+        Frame, FrameQuality, GestureEvent, GestureKind, GestureResult, GestureSequence, HandResult,
+        RecognizedFrame, RecognizerConfig,
+    },
 };
 
 use self::common::HandposeOutput;
+pub use self::common::{EXECUTION_PROVIDER_ENV, ExecutionProvider, HandposeOutputNames};
+pub use self::error::RecognizerError;
+use self::motion::MotionGate;
+#[cfg(feature = "backend-ort")]
+pub use self::ort::OrtEngine;
+pub use self::palm::PalmDetectorConfig;
+use self::quality::frame_quality;
+use self::smoothing::LandmarkSmoother;
+#[cfg(feature = "backend-tract")]
+pub use self::tract::TractEngine;
+
+pub trait HandposeEngine: Send + 'static {
+    fn infer(&mut self, frame: &Frame) -> Result<HandposeOutput, RecognizerError>;
 
-pub(crate) trait HandposeEngine: Send + 'static {
-    fn infer(&mut self, frame: &Frame) -> anyhow::Result<HandposeOutput>;
+    /// Rebuilds the model sessions on another execution provider.
+    fn set_execution_provider(
+        &mut self,
+        provider: ExecutionProvider,
+    ) -> Result<(), RecognizerError>;
+
+    fn set_palm_config(&mut self, config: PalmDetectorConfig);
+
+    /// Reloads the model files from disk. On error the current sessions stay in use.
+    fn reload_models(&mut self) -> Result<(), RecognizerError>;
 }
 
-fn run_worker_loop<E: HandposeEngine>(
+/// Messages the worker picks up between frames.
+#[derive(Clone, Debug)]
+pub enum RecognizerControl {
+    UpdateConfig(RecognizerConfig),
+    SetExecutionProvider(ExecutionProvider),
+    SetPalmConfig(PalmDetectorConfig),
+    SetSequences(Vec<GestureSequence>),
+    SetEnabledGestures(HashSet<GestureKind>),
+    /// Rebuilds the model sessions from the files currently on disk.
+    ReloadModels,
+    /// Drops per-hand history, e.g. after the frame source changed.
+    ResetTracking,
+    /// A paused worker drops frames without running the models, keeping its
+    /// sessions loaded; resuming also resets tracking.
+    SetPaused(bool),
+}
+
+/// How the worker's startup went, for a backend with a status tap.
+#[derive(Debug)]
+pub enum RecognizerStatus {
+    Ready,
+    /// The models could not be prepared or loaded. The worker waits for
+    /// [`RecognizerControl::ReloadModels`] to try again.
+    Failed(RecognizerError),
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn run_worker_loop<E: HandposeEngine>(
     mut engine: E,
+    classifier: GestureClassifier,
+    backend: &RecognizerBackend,
     frame_rx: Receiver<Frame>,
-    result_tx: Sender<RecognizedFrame>,
+    control_rx: Receiver<RecognizerControl>,
+    result_tx: Delivery<RecognizedFrame>,
+    event_tx: Sender<GestureEvent>,
+    metrics: MetricsHandle,
 ) {
-    let mut classifier = GestureClassifier::new();
+    let mut config = backend.config();
+    let result_taps = backend.result_taps();
+    let event_tap = backend.event_tap();
+    let recorder = backend.recorder();
+    let mut tracker = GestureEventTracker::new(classifier, &config);
+    let mut dwell = config.dwell.map(DwellDetector::new);
+    let mut sequences = SequenceMatcher::new(backend.sequences().to_vec());
+    let mut drag = DragTracker::default();
+    let mut smoother = LandmarkSmoother::default();
+    let mut motion = MotionGate::default();
+    let mut next_frame_id: u64 = 0;
 
-    while let Some(frame) = recv_latest_frame(&frame_rx) {
-        match engine.infer(&frame) {
+    let mut paused = false;
+    // Swapped for a channel that never fires once every control sender is gone.
+    let mut control_rx = control_rx;
+    loop {
+        // Controls are taken as they arrive, so a reload or pause does not
+        // wait on a camera that has stalled or a video that has ended.
+        let mut controls = Vec::new();
+        let mut next_frame = None;
+        let mut controls_closed = false;
+        select! {
+            recv(frame_rx) -> frame => match frame {
+                Ok(frame) => next_frame = Some(frame),
+                Err(_) => break,
+            },
+            recv(control_rx) -> control => match control {
+                Ok(control) => controls.push(control),
+                Err(_) => controls_closed = true,
+            },
+        }
+        if controls_closed {
+            control_rx = crossbeam_channel::never();
+        }
+        controls.extend(control_rx.try_iter());
+
+        let mut reset_tracking = false;
+        for control in controls {
+            match control {
+                RecognizerControl::UpdateConfig(new_config) => {
+                    log::info!("recognizer config updated: {new_config:?}");
+                    tracker.set_config(&new_config);
+                    if new_config.dwell != dwell.as_ref().map(DwellDetector::config) {
+                        dwell = new_config.dwell.map(DwellDetector::new);
+                    }
+                    config = new_config;
+                }
+                RecognizerControl::SetExecutionProvider(provider) => {
+                    log::info!("switching execution provider to {}", provider.label());
+                    if let Err(err) = engine.set_execution_provider(provider) {
+                        log::warn!("failed to switch execution provider: {}", err.describe());
+                    }
+                }
+                RecognizerControl::SetPalmConfig(palm_config) => {
+                    log::info!("palm detector config updated: {palm_config:?}");
+                    engine.set_palm_config(palm_config);
+                }
+                RecognizerControl::SetSequences(new_sequences) => {
+                    log::info!("gesture sequences updated: {new_sequences:?}");
+                    sequences = SequenceMatcher::new(new_sequences);
+                }
+                RecognizerControl::SetEnabledGestures(gestures) => {
+                    log::info!("enabled gestures updated: {gestures:?}");
+                    tracker.classifier_mut().set_enabled_gestures(gestures);
+                }
+                RecognizerControl::ReloadModels => {
+                    if let Err(err) = engine.reload_models() {
+                        log::warn!(
+                            "failed to reload models, keeping the loaded ones: {}",
+                            err.describe()
+                        );
+                    }
+                }
+                RecognizerControl::ResetTracking => reset_tracking = true,
+                RecognizerControl::SetPaused(value) => {
+                    log::info!("recognizer {}", if value { "paused" } else { "resumed" });
+                    // Hands seen before the pause say nothing about the ones after.
+                    reset_tracking |= paused && !value;
+                    paused = value;
+                }
+            }
+        }
+        if reset_tracking {
+            tracker.reset();
+            sequences.reset();
+            smoother.reset();
+            motion.reset();
+            drag.reset();
+            if let Some(dwell) = dwell.as_mut() {
+                dwell.reset();
+            }
+        }
+        let Some(frame) = next_frame else {
+            continue;
+        };
+        let (frame, skipped) = take_latest(&frame_rx, frame);
+        metrics.record_skips(Channel::Camera, skipped);
+        if paused {
+            continue;
+        }
+
+        // A frame the gates turn away still produces a result, with no hands,
+        // so held gestures end and the UI can say why.
+        let mut quality = frame_quality(&frame, &config.quality_gate);
+        if quality != FrameQuality::Good {
+            motion.reset();
+        } else if motion.observe(&frame, &config.motion_gate) {
+            quality = FrameQuality::Idle;
+        }
+        metrics.record_idle(motion.is_idle());
+        let inferred = if quality == FrameQuality::Good {
+            engine
+                .infer(&frame)
+                .inspect(|output| metrics.record_inference(output))
+        } else {
+            Ok(HandposeOutput::skipped())
+        };
+        match inferred {
             Ok(output) => {
-                let gesture = build_gesture_result(output, &frame, &mut classifier);
+                let frame_id = next_frame_id;
+                next_frame_id += 1;
+
+                let classify_start = Instant::now();
+                let mut gesture = build_gesture_result(
+                    output,
+                    &frame,
+                    frame_id,
+                    &config,
+                    tracker.classifier_mut(),
+                    &mut smoother,
+                );
+                metrics.record_stage(Stage::Classify, classify_start.elapsed());
+                gesture.frame_quality = quality;
+                if !gesture.hands.is_empty() {
+                    motion.keep_awake();
+                }
+
+                let primary = gesture.detail.as_ref().map(|d| d.primary);
+                let events = tracker.observe(primary, frame.acquired_at);
+                metrics.record_recognition(gesture.confidence, &events);
+                let moved = gesture
+                    .detail
+                    .as_ref()
+                    .and_then(|detail| tracker.motion_event(detail.motion));
+                let matched = sequences.observe(primary, &events, frame.acquired_at);
+                gesture.dwell = dwell.as_mut().and_then(|dwell| dwell.observe(&gesture));
+                let dwelled = gesture
+                    .dwell
+                    .filter(|progress| progress.completed)
+                    .map(|progress| GestureEvent::DwellCompleted(progress.kind));
+                let dragged = drag.observe(&gesture);
+                let events = events
+                    .into_iter()
+                    .chain(moved)
+                    .chain(matched)
+                    .chain(dwelled)
+                    .chain(dragged);
+                for event in events {
+                    if let Some(tap) = &event_tap {
+                        let _ = tap.try_send(event.clone());
+                    }
+                    let _ = event_tx.try_send(event);
+                }
+                for tap in &result_taps {
+                    tap.deliver(gesture.clone());
+                }
                 let recognized = RecognizedFrame {
                     frame,
                     result: gesture,
                 };
-                let _ = result_tx.try_send(recognized);
+                if let Some(recorder) = &recorder {
+                    recorder.offer(&recognized);
+                }
+                if !result_tx.deliver(recognized) {
+                    metrics.record_drop(Channel::Recognized);
+                }
             }
+            // Counted rather than logged as warnings, which would come at frame rate.
             Err(err) => {
-                log::warn!("handpose inference failed: {err:?}");
+                metrics.record_error();
+                log::debug!("{}", err.describe());
             }
         }
     }
 }
 
-fn recv_latest_frame(frame_rx: &Receiver<Frame>) -> Option<Frame> {
-    let mut frame = frame_rx.recv().ok()?;
-    while let Ok(newer) = frame_rx.try_recv() {
-        frame = newer;
+/// Environment variable that picks the [`InferenceBackend`].
+pub const INFERENCE_BACKEND_ENV: &str = "GESTURE_BACKEND";
+
+/// What runs the palm and handpose models. Each one is there when its cargo
+/// feature is on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InferenceBackend {
+    /// ONNX Runtime, on the execution provider in the settings.
+    #[cfg(feature = "backend-ort")]
+    Ort,
+    /// tract, in pure Rust and on the CPU only.
+    #[cfg(feature = "backend-tract")]
+    Tract,
+}
+
+impl InferenceBackend {
+    pub fn label(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "backend-ort")]
+            InferenceBackend::Ort => "ort",
+            #[cfg(feature = "backend-tract")]
+            InferenceBackend::Tract => "tract",
+        }
+    }
+
+    /// `None` for a backend this build leaves out.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            #[cfg(feature = "backend-ort")]
+            "ort" | "onnxruntime" => Some(InferenceBackend::Ort),
+            #[cfg(feature = "backend-tract")]
+            "tract" => Some(InferenceBackend::Tract),
+            _ => None,
+        }
+    }
+
+    /// Reads [`INFERENCE_BACKEND_ENV`], defaulting to ORT when the build has it.
+    pub fn from_env() -> Self {
+        match std::env::var(INFERENCE_BACKEND_ENV) {
+            Ok(value) => Self::parse(&value).unwrap_or_else(|| {
+                let fallback = Self::default();
+                log::warn!(
+                    "unknown or disabled backend {value:?}, using {}",
+                    fallback.label()
+                );
+                fallback
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+}
+
+impl Default for InferenceBackend {
+    fn default() -> Self {
+        #[cfg(feature = "backend-ort")]
+        return InferenceBackend::Ort;
+        #[cfg(not(feature = "backend-ort"))]
+        return InferenceBackend::Tract;
     }
-    Some(frame)
 }
 
 #[derive(Clone, Debug)]
 pub struct RecognizerBackend {
+    inference_backend: InferenceBackend,
     handpose_estimator_model_path: PathBuf,
     palm_detector_model_path: PathBuf,
+    config: RecognizerConfig,
+    execution_provider: ExecutionProvider,
+    palm_config: PalmDetectorConfig,
+    handpose_output_names: HandposeOutputNames,
+    sequences: Vec<GestureSequence>,
+    /// Gestures the classifier may report; the rest are skipped over.
+    enabled_gestures: HashSet<GestureKind>,
+    /// Registered with the classifier in order, ahead of the built-in ones.
+    plugins: Vec<PluginFactory>,
+    /// Extra consumers that get a copy of every result, e.g. the WebSocket and
+    /// OSC outputs, each delivered as its own mode says. Only a blocking tap
+    /// can hold up the worker.
+    result_taps: Vec<Delivery<GestureResult>>,
+    /// Extra consumer of the gesture events, e.g. the MQTT output; fed the
+    /// same way as `result_taps`.
+    event_tap: Option<Sender<GestureEvent>>,
+    /// Told whether the worker started; a worker without one gives up on a
+    /// failed start instead of waiting for a retry.
+    status_tap: Option<Sender<RecognizerStatus>>,
+    recorder: Option<Recorder>,
 }
 
 impl RecognizerBackend {
+    pub fn inference_backend(&self) -> InferenceBackend {
+        self.inference_backend
+    }
+
+    pub fn with_inference_backend(mut self, backend: InferenceBackend) -> Self {
+        self.inference_backend = backend;
+        self
+    }
+
     pub fn handpose_estimator_model_path(&self) -> PathBuf {
         self.handpose_estimator_model_path.clone()
     }
 
+    pub fn with_handpose_estimator_model_path(mut self, path: PathBuf) -> Self {
+        self.handpose_estimator_model_path = path;
+        self
+    }
+
     pub fn palm_detector_model_path(&self) -> PathBuf {
         self.palm_detector_model_path.clone()
     }
 
+    pub fn with_palm_detector_model_path(mut self, path: PathBuf) -> Self {
+        self.palm_detector_model_path = path;
+        self
+    }
+
+    /// Takes the model paths from [`crate::model_download::HANDPOSE_MODEL_ENV`]
+    /// and [`crate::model_download::PALM_MODEL_ENV`] where they are set.
+    pub fn with_model_paths_from_env(mut self) -> Self {
+        if let Some(path) = handpose_estimator_model_path_from_env() {
+            self.handpose_estimator_model_path = path;
+        }
+        if let Some(path) = palm_detector_model_path_from_env() {
+            self.palm_detector_model_path = path;
+        }
+        self
+    }
+
+    pub fn config(&self) -> RecognizerConfig {
+        self.config
+    }
+
+    pub fn with_config(mut self, config: RecognizerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn execution_provider(&self) -> ExecutionProvider {
+        self.execution_provider
+    }
+
+    pub fn with_execution_provider(mut self, provider: ExecutionProvider) -> Self {
+        self.execution_provider = provider;
+        self
+    }
+
+    pub fn palm_config(&self) -> PalmDetectorConfig {
+        self.palm_config
+    }
+
+    pub fn with_palm_config(mut self, config: PalmDetectorConfig) -> Self {
+        self.palm_config = config;
+        self
+    }
+
+    pub fn handpose_output_names(&self) -> HandposeOutputNames {
+        self.handpose_output_names.clone()
+    }
+
+    /// Names the handpose outputs are looked up by; shapes decide when the
+    /// model uses other names.
+    pub fn with_handpose_output_names(mut self, names: HandposeOutputNames) -> Self {
+        self.handpose_output_names = names;
+        self
+    }
+
+    pub fn sequences(&self) -> &[GestureSequence] {
+        &self.sequences
+    }
+
+    pub fn with_sequences(mut self, sequences: Vec<GestureSequence>) -> Self {
+        self.sequences = sequences;
+        self
+    }
+
+    pub fn enabled_gestures(&self) -> &HashSet<GestureKind> {
+        &self.enabled_gestures
+    }
+
+    pub fn with_enabled_gestures(mut self, gestures: HashSet<GestureKind>) -> Self {
+        self.enabled_gestures = gestures;
+        self
+    }
+
+    pub fn plugins(&self) -> &[PluginFactory] {
+        &self.plugins
+    }
+
+    /// Adds a gesture plugin after the ones already added; `build` runs on
+    /// the recognizer thread.
+    pub fn with_plugin<P: GesturePlugin + 'static>(
+        mut self,
+        build: impl Fn() -> P + Send + Sync + 'static,
+    ) -> Self {
+        self.plugins.push(PluginFactory::new(build));
+        self
+    }
+
+    pub fn result_taps(&self) -> Vec<Delivery<GestureResult>> {
+        self.result_taps.clone()
+    }
+
+    /// Adds to the taps already set. A plain sender is fed
+    /// [`DeliveryMode::LatestOnly`](crate::pipeline::DeliveryMode::LatestOnly).
+    pub fn with_result_tap(mut self, tap: impl Into<Delivery<GestureResult>>) -> Self {
+        self.result_taps.push(tap.into());
+        self
+    }
+
+    pub fn event_tap(&self) -> Option<Sender<GestureEvent>> {
+        self.event_tap.clone()
+    }
+
+    pub fn with_event_tap(mut self, tap: Sender<GestureEvent>) -> Self {
+        self.event_tap = Some(tap);
+        self
+    }
+
+    pub fn status_tap(&self) -> Option<Sender<RecognizerStatus>> {
+        self.status_tap.clone()
+    }
+
+    pub fn with_status_tap(mut self, tap: Sender<RecognizerStatus>) -> Self {
+        self.status_tap = Some(tap);
+        self
+    }
+
+    pub fn recorder(&self) -> Option<Recorder> {
+        self.recorder.clone()
+    }
+
+    pub fn with_recorder(mut self, recorder: Recorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
     pub fn backend_label(&self) -> &'static str {
-        "ort"
+        self.inference_backend.label()
+    }
+
+    /// Loads the model files that were named explicitly, so a wrong path or a
+    /// file that is not a usable model stops startup with the path instead of
+    /// surfacing later in the error panel. The default files are left to the
+    /// download step.
+    pub fn check_custom_models(&self) -> Result<(), RecognizerError> {
+        match self.inference_backend {
+            #[cfg(feature = "backend-ort")]
+            InferenceBackend::Ort => ort::check_custom_models(self),
+            #[cfg(feature = "backend-tract")]
+            InferenceBackend::Tract => tract::check_custom_models(self),
+        }
+    }
+
+    /// The classifier the worker starts with: the enabled gestures set and
+    /// the plugins built and registered.
+    pub fn build_classifier(&self) -> GestureClassifier {
+        let mut classifier = GestureClassifier::new(&self.config);
+        classifier.set_enabled_gestures(self.enabled_gestures.clone());
+        for plugin in &self.plugins {
+            classifier.register_plugin(plugin.build());
+        }
+        classifier
     }
 }
 
 impl Default for RecognizerBackend {
     fn default() -> Self {
         RecognizerBackend {
+            inference_backend: InferenceBackend::from_env(),
             handpose_estimator_model_path: default_handpose_estimator_model_path(),
             palm_detector_model_path: default_palm_detector_model_path(),
+            config: RecognizerConfig::default(),
+            execution_provider: ExecutionProvider::from_env(),
+            palm_config: PalmDetectorConfig::default(),
+            handpose_output_names: HandposeOutputNames::default(),
+            sequences: Vec::new(),
+            enabled_gestures: GestureKind::ALL.into_iter().collect(),
+            plugins: Vec::new(),
+            result_taps: Vec::new(),
+            event_tap: None,
+            status_tap: None,
+            recorder: None,
         }
     }
 }
 
+/// Starts the worker on `frame_rx`. Results go to `result_tx`; a plain
+/// sender keeps only the latest, and a [`Delivery`] from
+/// [`DeliveryMode::channel`](crate::pipeline::DeliveryMode::channel) can queue
+/// them or wait for the reader instead.
 pub fn start_recognizer(
     backend: RecognizerBackend,
     frame_rx: Receiver<Frame>,
-    result_tx: Sender<RecognizedFrame>,
+    control_rx: Receiver<RecognizerControl>,
+    result_tx: impl Into<Delivery<RecognizedFrame>>,
+    event_tx: Sender<GestureEvent>,
+    metrics: MetricsHandle,
 ) -> thread::JoinHandle<()> {
     log::info!("starting handpose backend: {}", backend.backend_label());
 
-    ort::start_worker(backend, frame_rx, result_tx)
+    let result_tx = result_tx.into();
+    match backend.inference_backend() {
+        #[cfg(feature = "backend-ort")]
+        InferenceBackend::Ort => spawn_worker(
+            backend,
+            ort::load_engine,
+            frame_rx,
+            control_rx,
+            result_tx,
+            event_tx,
+            metrics,
+        ),
+        #[cfg(feature = "backend-tract")]
+        InferenceBackend::Tract => spawn_worker(
+            backend,
+            tract::load_engine,
+            frame_rx,
+            control_rx,
+            result_tx,
+            event_tx,
+            metrics,
+        ),
+    }
+}
+
+/// Runs the worker on a thread of its own, once `load` has built an engine
+/// for `backend`. A failed start is reported on the status tap and retried on
+/// [`RecognizerControl::ReloadModels`].
+#[allow(clippy::too_many_arguments)]
+fn spawn_worker<E: HandposeEngine>(
+    backend: RecognizerBackend,
+    load: fn(&RecognizerBackend) -> Result<E, RecognizerError>,
+    frame_rx: Receiver<Frame>,
+    control_rx: Receiver<RecognizerControl>,
+    result_tx: Delivery<RecognizedFrame>,
+    event_tx: Sender<GestureEvent>,
+    metrics: MetricsHandle,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        let mut backend = backend;
+        let status_tap = backend.status_tap();
+        let engine = loop {
+            match prepare_engine(&backend, load) {
+                Ok(engine) => break engine,
+                Err(err) => {
+                    log::error!("recognizer failed to start: {}", err.describe());
+                    // Without anyone to offer a retry there is nothing to wait for.
+                    let Some(tap) = &status_tap else {
+                        return;
+                    };
+                    let _ = tap.send(RecognizerStatus::Failed(err));
+                    if !wait_for_retry(&mut backend, &frame_rx, &control_rx) {
+                        return;
+                    }
+                }
+            }
+        };
+        if let Some(tap) = &status_tap {
+            let _ = tap.send(RecognizerStatus::Ready);
+        }
+
+        let classifier = backend.build_classifier();
+        run_worker_loop(
+            engine, classifier, &backend, frame_rx, control_rx, result_tx, event_tx, metrics,
+        );
+    })
+}
+
+/// Makes sure both models are on disk and loads them.
+fn prepare_engine<E>(
+    backend: &RecognizerBackend,
+    load: fn(&RecognizerBackend) -> Result<E, RecognizerError>,
+) -> Result<E, RecognizerError> {
+    ensure_handpose_estimator_model_ready(
+        &backend.handpose_estimator_model_path(),
+        log_model_event,
+    )?;
+    ensure_palm_detector_model_ready(&backend.palm_detector_model_path(), log_model_event)?;
+    load(backend)
+}
+
+/// Parks a worker that failed to start until [`RecognizerControl::ReloadModels`]
+/// asks for another attempt, keeping any settings changed meanwhile. Returns
+/// `false` once the pipeline shuts down instead.
+fn wait_for_retry(
+    backend: &mut RecognizerBackend,
+    frame_rx: &Receiver<Frame>,
+    control_rx: &Receiver<RecognizerControl>,
+) -> bool {
+    loop {
+        select! {
+            recv(control_rx) -> control => match control {
+                Ok(RecognizerControl::ReloadModels) => return true,
+                Ok(control) => {
+                    let current = backend.clone();
+                    *backend = match control {
+                        RecognizerControl::UpdateConfig(config) => current.with_config(config),
+                        RecognizerControl::SetExecutionProvider(provider) => {
+                            current.with_execution_provider(provider)
+                        }
+                        RecognizerControl::SetPalmConfig(config) => {
+                            current.with_palm_config(config)
+                        }
+                        RecognizerControl::SetSequences(sequences) => {
+                            current.with_sequences(sequences)
+                        }
+                        RecognizerControl::SetEnabledGestures(gestures) => {
+                            current.with_enabled_gestures(gestures)
+                        }
+                        RecognizerControl::ReloadModels
+                        | RecognizerControl::ResetTracking
+                        | RecognizerControl::SetPaused(_) => current,
+                    };
+                }
+                Err(_) => return false,
+            },
+            // Frames are dropped until there is an engine to run them.
+            recv(frame_rx) -> frame => {
+                if frame.is_err() {
+                    return false;
+                }
+            }
+        }
+    }
+}
+
+/// The UI downloads models before the camera starts, so by the time the worker
+/// runs this is normally just the digest check; headless runs see the rest here.
+fn log_model_event(event: ModelDownloadEvent) {
+    match event {
+        ModelDownloadEvent::Started { model, total } => {
+            log::info!("downloading {model:?} model ({total:?} bytes)")
+        }
+        ModelDownloadEvent::Finished { model } => log::info!("{model:?} model ready"),
+        ModelDownloadEvent::Failed { model, error } => {
+            log::warn!("{model:?} model unavailable: {error}")
+        }
+        _ => {}
+    }
+}
+
+/// Recognizes one frame outside the worker, with nothing carried over from
+/// earlier frames; for one-off checks such as `--self-test`.
+pub fn recognize_frame<E: HandposeEngine>(
+    engine: &mut E,
+    classifier: &mut GestureClassifier,
+    frame: &Frame,
+    config: &RecognizerConfig,
+) -> Result<GestureResult, RecognizerError> {
+    let output = engine.infer(frame)?;
+    let mut smoother = LandmarkSmoother::default();
+    Ok(build_gesture_result(
+        output,
+        frame,
+        0,
+        config,
+        classifier,
+        &mut smoother,
+    ))
 }
 
 pub(crate) fn build_gesture_result(
     output: HandposeOutput,
     frame: &Frame,
+    frame_id: u64,
+    config: &RecognizerConfig,
     classifier: &mut GestureClassifier,
+    smoother: &mut LandmarkSmoother,
 ) -> GestureResult {
-    let has_detection = output.confidence >= 0.2;
-    let detail = if has_detection {
-        classifier.classify(
-            &output.raw_landmarks,
-            &output.projected_landmarks,
-            output.confidence,
-            output.handedness,
-            frame.timestamp,
-        )
+    // Motion and filter steps are timed from acquisition, so frames that sat
+    // in the driver's buffer or the decoder do not skew velocities.
+    let at = frame.acquired_at;
+    // Filter state only carries across frames while smoothing is on.
+    if config.smoothing.is_some() {
+        smoother.prune(at);
     } else {
-        None
-    };
-
-    let label = detail
-        .as_ref()
-        .map(|d| format!("{}{}", d.primary.emoji(), d.primary.display_name()))
-        .unwrap_or_else(|| {
-            if has_detection {
-                "检测到手".to_string()
-            } else {
-                "未检测到手".to_string()
+        smoother.reset();
+    }
+    let best_confidence = output
+        .hands
+        .iter()
+        .map(|hand| hand.confidence)
+        .fold(0.0f32, f32::max);
+    let mut hands: Vec<HandResult> = output
+        .hands
+        .into_iter()
+        .filter(|hand| hand.confidence >= config.detection_threshold)
+        .map(|mut hand| {
+            if let Some(smoothing) = &config.smoothing {
+                smoother.smooth(smoothing, &mut hand, at);
             }
-        });
+            let detail = classifier.classify(
+                hand.track_id,
+                &hand.raw_landmarks,
+                &hand.projected_landmarks,
+                hand.confidence,
+                hand.handedness,
+                at,
+            );
+            HandResult {
+                track_id: hand.track_id,
+                confidence: hand.confidence,
+                landmarks: hand.projected_landmarks,
+                raw_landmarks: hand.raw_landmarks,
+                handedness_score: hand.handedness,
+                detail,
+            }
+        })
+        .collect();
+    hands.sort_by(|a, b| {
+        b.confidence
+            .partial_cmp(&a.confidence)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let composite = classifier.classify_composite(&hands, at);
+    let primary = hands.first();
+    let detail = primary.and_then(|hand| hand.detail.clone());
 
     GestureResult {
-        label,
-        confidence: output.confidence,
+        confidence: best_confidence,
         timestamp: frame.timestamp,
-        landmarks: if has_detection {
-            Some(output.projected_landmarks)
-        } else {
-            None
-        },
+        frame_id,
+        captured_at: SystemTime::now()
+            .checked_sub(at.elapsed())
+            .unwrap_or_else(SystemTime::now),
+        frame_size: (frame.width, frame.height),
+        track_id: primary.map(|hand| hand.track_id),
+        landmarks: primary.map(|hand| hand.landmarks.clone()),
+        raw_landmarks: primary.map(|hand| hand.raw_landmarks.clone()),
         detail,
+        composite,
         palm_regions: output.palm_regions,
+        palm_debug: output.palm_debug,
+        dwell: None,
+        hands,
+        frame_quality: FrameQuality::Good,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, time::Duration};
+
+    use crossbeam_channel::Sender;
+
+    use super::*;
+    use crate::{
+        trace::{TraceFrame, read_jsonl},
+        types::{GestureMotion, PixelLayout},
+    };
+    use common::{HandLandmarks, HandposeOutput};
+    use smoothing::LandmarkSmoother;
+
+    const FRAME: Duration = Duration::from_millis(33);
+
+    /// An open palm from the wave trace, moved `dx` pixels to the right.
+    fn palm(dx: f32) -> HandLandmarks {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/open_palm_wave.jsonl");
+        let frames: Vec<TraceFrame> = read_jsonl(&path).unwrap();
+        let first = &frames[0];
+        HandLandmarks {
+            track_id: 1,
+            raw_landmarks: first.raw_landmarks.clone(),
+            projected_landmarks: first
+                .projected_landmarks
+                .iter()
+                .map(|&(x, y)| (x + dx, y))
+                .collect(),
+            confidence: first.confidence,
+            handedness: first.handedness,
+        }
+    }
+
+    /// The motion reported for each frame of a stroke 200 px to the right,
+    /// acquired every 33 ms and ready `delays[i]` after acquisition.
+    fn stroke(delays: &[Duration]) -> Vec<GestureMotion> {
+        let config = RecognizerConfig::default();
+        let mut classifier = GestureClassifier::rules_only(&config);
+        let mut smoother = LandmarkSmoother::default();
+        let start = Instant::now();
+        delays
+            .iter()
+            .zip(0u32..)
+            .map(|(delay, idx)| {
+                let acquired_at = start + FRAME * idx;
+                let frame = Frame {
+                    pixels: vec![0; PixelLayout::Rgb.buffer_len(640, 480)].into(),
+                    layout: PixelLayout::Rgb,
+                    width: 640,
+                    height: 480,
+                    timestamp: acquired_at + *delay,
+                    acquired_at,
+                };
+                let output = HandposeOutput {
+                    hands: vec![palm(25.0 * idx.min(8) as f32)],
+                    ..HandposeOutput::skipped()
+                };
+                let result = build_gesture_result(
+                    output,
+                    &frame,
+                    idx.into(),
+                    &config,
+                    &mut classifier,
+                    &mut smoother,
+                );
+                result.detail.map_or(GestureMotion::Steady, |d| d.motion)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn a_swipe_is_timed_from_acquisition_not_decode() {
+        let on_time = stroke(&[Duration::ZERO; 14]);
+        assert!(on_time.contains(&GestureMotion::SwipeRight), "{on_time:?}");
+
+        // A decode backlog growing by 60 ms a frame would stretch the stroke
+        // to almost three times as long and below swipe speed.
+        let backlog: Vec<Duration> = (0..14).map(|idx| Duration::from_millis(60 * idx)).collect();
+        assert_eq!(stroke(&backlog), on_time);
+    }
+
+    /// Reports each reload on a channel and never sees a hand.
+    struct ReloadProbe(Sender<()>);
+
+    impl HandposeEngine for ReloadProbe {
+        fn infer(&mut self, _: &Frame) -> Result<HandposeOutput, RecognizerError> {
+            Ok(HandposeOutput::skipped())
+        }
+
+        fn set_execution_provider(&mut self, _: ExecutionProvider) -> Result<(), RecognizerError> {
+            Ok(())
+        }
+
+        fn set_palm_config(&mut self, _: PalmDetectorConfig) {}
+
+        fn reload_models(&mut self) -> Result<(), RecognizerError> {
+            let _ = self.0.send(());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn a_reload_is_handled_while_the_camera_is_stalled() {
+        let (reloaded_tx, reloaded_rx) = crossbeam_channel::unbounded();
+        let (frame_tx, frame_rx) = crossbeam_channel::bounded::<Frame>(1);
+        let (control_tx, control_rx) = crossbeam_channel::unbounded();
+        let (result_tx, _result_rx) = crossbeam_channel::bounded(1);
+        let (event_tx, _event_rx) = crossbeam_channel::unbounded();
+        let worker = thread::spawn(move || {
+            let backend = RecognizerBackend::default();
+            let classifier = GestureClassifier::rules_only(&backend.config());
+            run_worker_loop(
+                ReloadProbe(reloaded_tx),
+                classifier,
+                &backend,
+                frame_rx,
+                control_rx,
+                result_tx.into(),
+                event_tx,
+                MetricsHandle::default(),
+            );
+        });
+
+        // No frame is ever sent.
+        control_tx.send(RecognizerControl::ReloadModels).unwrap();
+        reloaded_rx
+            .recv_timeout(Duration::from_secs(2))
+            .expect("the reload runs without waiting for a frame");
+
+        drop(frame_tx);
+        worker.join().unwrap();
     }
 }
@@ -0,0 +1,100 @@
+use crate::types::{Frame, MotionGateConfig};
+
+use super::quality::{GRID_COLUMNS, GRID_ROWS};
+
+/// Tells the recognizer when a scene has gone still, so the models can stop
+/// running on it. Each frame is compared against the last one inferred, on
+/// the same coarse luma grid as the quality gate.
+#[derive(Debug, Default)]
+pub struct MotionGate {
+    /// Luma grid of the last frame let through, and that frame's size.
+    reference: Vec<u8>,
+    reference_size: (u32, u32),
+    /// Reused for each new frame's grid.
+    scratch: Vec<u8>,
+    still_frames: u32,
+    idle: bool,
+}
+
+impl MotionGate {
+    /// Whether the last frame observed should skip inference.
+    pub fn is_idle(&self) -> bool {
+        self.idle
+    }
+
+    /// Forgets the reference frame, so the next one starts the count over.
+    pub fn reset(&mut self) {
+        self.reference.clear();
+        self.still_frames = 0;
+        self.idle = false;
+    }
+
+    /// A hand was found: however still it is, someone is there.
+    pub fn keep_awake(&mut self) {
+        self.still_frames = 0;
+    }
+
+    /// Takes `frame` in and returns whether it should skip inference. The gate
+    /// goes idle after more than `idle_frames` still frames in a row and
+    /// wakes on the first frame past `wake_threshold`. While idle the
+    /// reference stays the last inferred frame, so slow drift wakes it too.
+    pub fn observe(&mut self, frame: &Frame, config: &MotionGateConfig) -> bool {
+        if !config.enabled || frame.width == 0 || frame.height == 0 {
+            self.reset();
+            return false;
+        }
+
+        luma_grid(frame, &mut self.scratch);
+        let size = (frame.width, frame.height);
+        if self.reference.is_empty() || self.reference_size != size {
+            std::mem::swap(&mut self.reference, &mut self.scratch);
+            self.reference_size = size;
+            self.still_frames = 0;
+            self.idle = false;
+            return false;
+        }
+
+        let difference = mean_abs_difference(&self.reference, &self.scratch);
+        if self.idle {
+            if difference <= config.wake_threshold {
+                return true;
+            }
+            self.idle = false;
+            self.still_frames = 0;
+        } else if difference < config.idle_threshold {
+            self.still_frames += 1;
+        } else {
+            self.still_frames = 0;
+        }
+        std::mem::swap(&mut self.reference, &mut self.scratch);
+        self.idle = self.still_frames > config.idle_frames;
+        self.idle
+    }
+}
+
+/// BT.601 luma of every step-th pixel, into `grid`.
+fn luma_grid(frame: &Frame, grid: &mut Vec<u8>) {
+    let step_x = (frame.width / GRID_COLUMNS).max(1) as usize;
+    let step_y = (frame.height / GRID_ROWS).max(1) as usize;
+    let channels = frame.layout.channels();
+    let stride = frame.width as usize * channels;
+    grid.clear();
+    for row in frame.pixels.chunks_exact(stride).step_by(step_y) {
+        for pixel in row.chunks_exact(channels).step_by(step_x) {
+            let [r, g, b] = [pixel[0], pixel[1], pixel[2]].map(u32::from);
+            grid.push(((77 * r + 150 * g + 29 * b) >> 8) as u8);
+        }
+    }
+}
+
+fn mean_abs_difference(a: &[u8], b: &[u8]) -> f32 {
+    if a.is_empty() {
+        return 0.0;
+    }
+    let total: u64 = a
+        .iter()
+        .zip(b)
+        .map(|(&a, &b)| u64::from(a.abs_diff(b)))
+        .sum();
+    total as f32 / a.len() as f32
+}
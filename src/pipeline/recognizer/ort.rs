@@ -1,283 +1,545 @@
 use std::{
-    path::PathBuf,
-    thread,
-    time::{Duration, Instant},
+    path::{Path, PathBuf},
+    time::{Duration, Instant, UNIX_EPOCH},
 };
 
 use anyhow::{Context, Result, anyhow};
-use crossbeam_channel::{Receiver, Sender};
-use ort::session::{Session, builder::GraphOptimizationLevel};
+use ndarray::{Array4, Axis};
+use ort::session::Session;
 use ort::value::Tensor;
 
 use super::{
     HandposeEngine, RecognizerBackend,
-    common::{self, HandposeOutput},
-    palm::{PalmDetector, PalmDetectorConfig, crop_from_palm, pick_primary_region},
-    run_worker_loop,
+    common::{
+        self, ExecutionProvider, HandLandmarks, HandposeOutput, HandposeOutputMap,
+        HandposeOutputNames, InferenceTimings, TensorLayout,
+    },
+    error::RecognizerError,
+    input_scale::{FrameScale, InputScaler},
+    palm::{PalmDetection, PalmDetector, PalmDetectorConfig, roi_contains},
+    tracking::{HandCrop, HandTracking, RawHand, frame_palm_regions},
 };
 use crate::{
-    model_download::{ensure_handpose_estimator_model_ready, ensure_palm_detector_model_ready},
-    types::{Frame, RecognizedFrame},
+    model_download::{default_handpose_estimator_model_path, default_palm_detector_model_path},
+    types::{Frame, PalmDebug},
 };
 
-pub fn start_worker(
-    backend: RecognizerBackend,
-    frame_rx: Receiver<Frame>,
-    result_tx: Sender<RecognizedFrame>,
-) -> thread::JoinHandle<()> {
-    thread::spawn(move || {
-        let handpose_estimator_model_path = backend.handpose_estimator_model_path();
-        let palm_detector_model_path = backend.palm_detector_model_path();
-
-        if let Err(err) =
-            ensure_handpose_estimator_model_ready(&handpose_estimator_model_path, |_evt| {})
-        {
-            log::error!(
-                "failed to prepare handpose model at {}: {err:?}",
-                handpose_estimator_model_path.display()
-            );
-            return;
-        }
+/// Loads the ORT engine for `backend` once its models are on disk.
+pub(super) fn load_engine(backend: &RecognizerBackend) -> Result<OrtEngine, RecognizerError> {
+    let engine = OrtEngine::from_backend(backend)?;
+    log::info!(
+        "handpose ORT backend ready using {} ({}) and palm detector {} ({} / {})",
+        backend.handpose_estimator_model_path().display(),
+        engine.handpose_layout.label(),
+        backend.palm_detector_model_path().display(),
+        engine.handpose_provider.label(),
+        engine.palm_detector.execution_provider().label()
+    );
+    engine.log_output_map();
+    Ok(engine)
+}
 
-        if let Err(err) = ensure_palm_detector_model_ready(&palm_detector_model_path, |_evt| {}) {
-            log::error!(
-                "failed to prepare palm detector model at {}: {err:?}",
-                palm_detector_model_path.display()
-            );
-            return;
-        }
+/// Path, size and modification time, for the reload log line.
+fn describe_model_file(path: &Path) -> String {
+    let Ok(meta) = std::fs::metadata(path) else {
+        return path.display().to_string();
+    };
+    let modified = meta
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or_else(|| "?".to_string(), |since| since.as_secs().to_string());
+    format!(
+        "{} ({} bytes, modified at unix {modified})",
+        path.display(),
+        meta.len()
+    )
+}
 
-        let engine = match OrtEngine::new(&handpose_estimator_model_path, &palm_detector_model_path)
-        {
-            Ok(engine) => {
-                log::info!(
-                    "handpose ORT backend ready using {} and palm detector {}",
-                    handpose_estimator_model_path.display(),
-                    palm_detector_model_path.display()
-                );
-                engine
-            }
-            Err(err) => {
-                log::error!("failed to load ORT handpose model: {err:?}");
-                return;
-            }
-        };
+/// See [`RecognizerBackend::check_custom_models`]; ORT loads them on the CPU.
+pub(super) fn check_custom_models(backend: &RecognizerBackend) -> Result<(), RecognizerError> {
+    let handpose_path = backend.handpose_estimator_model_path();
+    if handpose_path != default_handpose_estimator_model_path() {
+        let (session, _) = load_handpose(&handpose_path, ExecutionProvider::Cpu)?;
+        map_handpose_outputs(&session, &handpose_path, &backend.handpose_output_names())?;
+    }
+    let palm_path = backend.palm_detector_model_path();
+    if palm_path != default_palm_detector_model_path() {
+        common::build_session(&palm_path, ExecutionProvider::Cpu)
+            .map_err(|err| RecognizerError::load_failed(&palm_path, err))?;
+    }
+    Ok(())
+}
+
+fn load_handpose(
+    path: &Path,
+    provider: ExecutionProvider,
+) -> Result<(Session, ExecutionProvider), RecognizerError> {
+    common::build_session(path, provider).map_err(|err| RecognizerError::load_failed(path, err))
+}
 
-        run_worker_loop(engine, frame_rx, result_tx);
+fn map_handpose_outputs(
+    session: &Session,
+    path: &Path,
+    names: &HandposeOutputNames,
+) -> Result<HandposeOutputMap, RecognizerError> {
+    HandposeOutputMap::of_session(session, names).map_err(|source| {
+        RecognizerError::ModelLoadFailed {
+            path: path.to_path_buf(),
+            source: source.context("unrecognized handpose outputs"),
+        }
     })
 }
 
-struct OrtEngine {
+/// Frames between latency log lines after the first one.
+const LATENCY_LOG_INTERVAL: u64 = 300;
+
+/// Palm detection, hand crops and handpose for one frame at a time, with the
+/// tracking state that carries hands between frames.
+pub struct OrtEngine {
     handpose: Session,
+    handpose_provider: ExecutionProvider,
+    handpose_layout: TensorLayout,
+    handpose_outputs: HandposeOutputMap,
+    /// Whether the handpose input takes several crops per call. Turned off
+    /// for good if a batched call fails.
+    handpose_batched: bool,
+    output_names: HandposeOutputNames,
+    requested_provider: ExecutionProvider,
+    handpose_model_path: PathBuf,
     palm_detector: PalmDetector,
-    tracker: HandTracker,
+    palm_detector_model_path: PathBuf,
+    tracking: HandTracking,
+    scaler: InputScaler,
+    frames: u64,
 }
 
 impl OrtEngine {
-    fn new(model_path: &PathBuf, palm_detector_model_path: &PathBuf) -> Result<Self> {
-        let handpose = Session::builder()?
-            .with_optimization_level(GraphOptimizationLevel::Level3)?
-            .with_intra_threads(2)?
-            .commit_from_file(model_path)
-            .with_context(|| format!("failed to load ORT session from {}", model_path.display()))?;
+    /// Loads both sessions from the paths, provider and palm config of
+    /// `backend`; the models must already be on disk.
+    pub fn from_backend(backend: &RecognizerBackend) -> Result<Self, RecognizerError> {
+        Self::new(
+            &backend.handpose_estimator_model_path(),
+            &backend.palm_detector_model_path(),
+            backend.palm_config(),
+            backend.execution_provider(),
+            backend.handpose_output_names(),
+        )
+    }
 
-        let palm_detector =
-            PalmDetector::new(palm_detector_model_path, PalmDetectorConfig::default())?;
+    fn new(
+        model_path: &Path,
+        palm_detector_model_path: &Path,
+        palm_config: PalmDetectorConfig,
+        provider: ExecutionProvider,
+        output_names: HandposeOutputNames,
+    ) -> Result<Self, RecognizerError> {
+        let (handpose, handpose_provider) = load_handpose(model_path, provider)?;
+        let handpose_layout = TensorLayout::of_session(&handpose);
+        let handpose_outputs = map_handpose_outputs(&handpose, model_path, &output_names)?;
+        let handpose_batched = common::has_dynamic_batch(&handpose);
+        let palm_detector = PalmDetector::new(palm_detector_model_path, palm_config, provider)?;
 
         Ok(Self {
             handpose,
+            handpose_provider,
+            handpose_layout,
+            handpose_outputs,
+            handpose_batched,
+            output_names,
+            requested_provider: provider,
+            handpose_model_path: model_path.to_path_buf(),
             palm_detector,
-            tracker: HandTracker::new(),
+            palm_detector_model_path: palm_detector_model_path.to_path_buf(),
+            tracking: HandTracking::new(),
+            scaler: InputScaler::new(
+                palm_config.max_input_dimension,
+                palm_config.inference_budget,
+            ),
+            frames: 0,
         })
     }
+
+    /// `batched` says whether the hands went through one handpose call; the
+    /// per-hand time is the handpose time shared out over them.
+    fn log_latency(
+        &mut self,
+        palm_time: Duration,
+        handpose_time: Duration,
+        hands: usize,
+        batched: bool,
+    ) {
+        if self.frames.is_multiple_of(LATENCY_LOG_INTERVAL) {
+            log::info!(
+                "inference latency: palm {:.1} ms ({}), handpose {:.1} ms for {hands} hand(s), \
+                 {:.1} ms per hand {} ({})",
+                palm_time.as_secs_f64() * 1000.0,
+                self.palm_detector.execution_provider().label(),
+                handpose_time.as_secs_f64() * 1000.0,
+                handpose_time.as_secs_f64() * 1000.0 / hands.max(1) as f64,
+                if batched { "batched" } else { "serial" },
+                self.handpose_provider.label()
+            );
+        }
+        self.frames += 1;
+    }
+
+    fn log_output_map(&self) {
+        log::info!(
+            "handpose outputs: {}, {} batch",
+            self.handpose_outputs.describe(&self.handpose),
+            if self.handpose_batched {
+                "dynamic"
+            } else {
+                "fixed"
+            }
+        );
+    }
 }
 
 impl HandposeEngine for OrtEngine {
-    fn infer(&mut self, frame: &Frame) -> Result<HandposeOutput> {
-        let now = frame.timestamp;
-        let palm_regions = self.palm_detector.detect(frame).unwrap_or_else(|err| {
-            log::warn!("palm detection failed: {err:?}");
-            Vec::new()
-        });
-
-        let mut used_tracking_fallback = false;
-        let (center, side, angle, prior_score) = if let Some(selected) =
-            pick_primary_region(&palm_regions).or_else(|| palm_regions.get(0))
-        {
-            let (center, side, angle) = crop_from_palm(selected);
-            (center, side, angle, selected.score)
-        } else if let Some((tracked, score)) = self.tracker.estimate_roi(now) {
-            used_tracking_fallback = true;
-            (tracked.0, tracked.1, tracked.2, score)
-        } else {
-            return Ok(HandposeOutput {
-                raw_landmarks: Vec::new(),
-                projected_landmarks: Vec::new(),
-                confidence: 0.0,
-                handedness: 0.0,
-                palm_regions,
-            });
-        };
+    fn set_execution_provider(
+        &mut self,
+        provider: ExecutionProvider,
+    ) -> Result<(), RecognizerError> {
+        let (handpose, handpose_provider) = load_handpose(&self.handpose_model_path, provider)?;
+        let palm_detector = PalmDetector::new(
+            &self.palm_detector_model_path,
+            self.palm_detector.config(),
+            provider,
+        )?;
+        let handpose_outputs =
+            map_handpose_outputs(&handpose, &self.handpose_model_path, &self.output_names)?;
+        self.handpose_layout = TensorLayout::of_session(&handpose);
+        self.handpose_outputs = handpose_outputs;
+        self.handpose_batched = common::has_dynamic_batch(&handpose);
+        self.handpose = handpose;
+        self.handpose_provider = handpose_provider;
+        self.palm_detector = palm_detector;
+        self.requested_provider = provider;
+        self.frames = 0;
+        Ok(())
+    }
 
-        let (input, transform) =
-            common::prepare_rotated_crop(frame, center, side, angle, common::INPUT_SIZE)?;
-        let tensor = Tensor::from_array(input)?;
-        let outputs = self
-            .handpose
-            .run(ort::inputs![tensor])
-            .context("failed to run ORT session")?;
+    fn set_palm_config(&mut self, config: PalmDetectorConfig) {
+        self.scaler
+            .configure(config.max_input_dimension, config.inference_budget);
+        self.palm_detector.set_config(config);
+    }
 
-        if outputs.len() < 1 {
-            return Err(anyhow!("model returned no outputs"));
-        }
+    fn reload_models(&mut self) -> Result<(), RecognizerError> {
+        // Ask for the provider the user picked, not whatever the last fallback
+        // settled on.
+        let provider = self.requested_provider;
+        let (handpose, handpose_provider) = load_handpose(&self.handpose_model_path, provider)?;
+        let palm_detector = PalmDetector::new(
+            &self.palm_detector_model_path,
+            self.palm_detector.config(),
+            provider,
+        )?;
+
+        let handpose_outputs =
+            map_handpose_outputs(&handpose, &self.handpose_model_path, &self.output_names)?;
+        self.handpose_layout = TensorLayout::of_session(&handpose);
+        self.handpose_outputs = handpose_outputs;
+        self.handpose_batched = common::has_dynamic_batch(&handpose);
+        self.handpose = handpose;
+        self.handpose_provider = handpose_provider;
+        self.palm_detector = palm_detector;
+        self.tracking.reset();
+        self.frames = 0;
+        log::info!(
+            "reloaded models: handpose {}, palm detector {}",
+            describe_model_file(&self.handpose_model_path),
+            describe_model_file(&self.palm_detector_model_path)
+        );
+        self.log_output_map();
+        Ok(())
+    }
 
-        let coords = outputs[0].try_extract_array::<f32>()?;
-        let flattened: Vec<f32> = coords.iter().copied().collect();
-        let landmarks = common::decode_landmarks(&flattened)?;
-
-        let confidence = if outputs.len() > 1 {
-            outputs[1]
-                .try_extract_array::<f32>()
-                .ok()
-                .and_then(|arr| arr.iter().next().copied())
-                .unwrap_or(0.0)
-        } else {
-            0.0
+    fn infer(&mut self, frame: &Frame) -> Result<HandposeOutput, RecognizerError> {
+        let infer_start = Instant::now();
+        // Everything after detection stays in original-resolution coordinates;
+        // only the pixel sampling goes through the scale.
+        let scaled = self.scaler.downscale(frame).unwrap_or_else(|err| {
+            log::warn!("input downscale failed, using the full frame: {err:?}");
+            None
+        });
+        let (input, scale) = match &scaled {
+            Some((scaled, scale)) => (scaled, *scale),
+            None => (frame, FrameScale::IDENTITY),
         };
-        let handedness = if outputs.len() > 2 {
-            outputs[2]
-                .try_extract_array::<f32>()
-                .ok()
-                .and_then(|arr| arr.iter().next().copied())
-                .unwrap_or(0.0)
+
+        let palm_config = self.palm_detector.config();
+        let detect = self.tracking.should_detect(&palm_config);
+        let palm_start = Instant::now();
+        let in_roi = |point| roi_contains(palm_config.roi, point, frame.width, frame.height);
+        let mut palm_debug = palm_config.keep_candidates.then(PalmDebug::default);
+        let (palm_regions, palm_error) = if detect {
+            let detection = if palm_debug.is_some() {
+                self.palm_detector.detect_with_candidates(input)
+            } else {
+                self.palm_detector
+                    .detect(input)
+                    .map(|regions| PalmDetection {
+                        regions,
+                        candidates: Vec::new(),
+                    })
+            };
+            frame_palm_regions(detection, scale, in_roi, &mut palm_debug)
         } else {
-            0.0
+            (Vec::new(), None)
         };
-
-        let projected = common::project_landmarks_with_transform(&landmarks, &transform);
-        let mut confidence = (confidence * prior_score).clamp(0.0, 1.0);
-        if used_tracking_fallback {
-            confidence *= 0.9;
-        }
-
-        if !landmarks.is_empty() {
-            self.tracker.update(&transform, &projected, confidence, now);
+        let palm_time = detect.then(|| palm_start.elapsed());
+        let crops = self
+            .tracking
+            .plan_crops(&palm_regions, &palm_config, in_roi, &mut palm_debug);
+
+        let handpose_start = Instant::now();
+        let crop_count = crops.len();
+        let mut crop_prep = Duration::ZERO;
+        let mut batched = self.handpose_batched && crop_count > 1;
+        let mut hands = None;
+        if batched {
+            match self.infer_hands(frame, input, scale, &crops, true, &mut crop_prep) {
+                Ok(batch) => hands = Some(batch),
+                Err(err) => {
+                    log::warn!("batched handpose failed, running hands one at a time: {err:#}");
+                    self.handpose_batched = false;
+                    batched = false;
+                }
+            }
         }
+        let hands = match hands {
+            Some(hands) => hands,
+            None => self
+                .infer_hands(frame, input, scale, &crops, false, &mut crop_prep)
+                .map_err(|err| {
+                    RecognizerError::from_anyhow(err, RecognizerError::InferenceFailed)
+                })?,
+        };
+        self.tracking.end_frame(&hands, &palm_config);
+        let handpose_time = handpose_start.elapsed();
+        self.log_latency(
+            palm_time.unwrap_or_default(),
+            handpose_time,
+            crop_count,
+            batched,
+        );
+        self.scaler.observe(infer_start.elapsed());
 
         Ok(HandposeOutput {
-            raw_landmarks: landmarks,
-            projected_landmarks: projected,
-            confidence,
-            handedness,
+            hands,
             palm_regions,
+            palm_debug,
+            timings: InferenceTimings {
+                palm_detect: palm_time,
+                crop_prep,
+                handpose: handpose_time,
+            },
+            input_size: (input.width, input.height),
+            palm_error,
         })
     }
 }
 
-// Keep a short-lived track so the hand does not disappear immediately when palm
-// detection drops (e.g. back-of-hand rotations).
-const TRACK_MAX_AGE: Duration = Duration::from_millis(450);
-const TRACK_MIN_CONF: f32 = 0.15;
+impl OrtEngine {
+    /// Handpose for every crop, in one session call when `batched` and one
+    /// call per crop otherwise. `input` is `frame` or its downscaled copy,
+    /// related by `scale`. Hands come back in crop order, each projected with
+    /// its own crop's transform; the time spent cutting the crops is added to
+    /// `crop_prep`. The tracker is only touched once every call succeeded.
+    fn infer_hands(
+        &mut self,
+        frame: &Frame,
+        input: &Frame,
+        scale: FrameScale,
+        crops: &[HandCrop],
+        batched: bool,
+        crop_prep: &mut Duration,
+    ) -> Result<Vec<HandLandmarks>> {
+        let prep_start = Instant::now();
+        let mut tensors = Vec::with_capacity(crops.len());
+        let mut transforms = Vec::with_capacity(crops.len());
+        for crop in crops {
+            let (tensor, transform) = common::prepare_rotated_crop(
+                input,
+                scale.to_scaled(crop.center),
+                scale.length_to_scaled(crop.side),
+                crop.angle,
+                common::INPUT_SIZE,
+            )?;
+            tensors.push(tensor);
+            transforms.push(scale.crop_to_original(transform, (frame.width, frame.height)));
+        }
+        *crop_prep += prep_start.elapsed();
 
-struct TrackedHand {
-    transform: common::CropTransform,
-    projected: Vec<(f32, f32)>,
-    confidence: f32,
-    last_seen: Instant,
-}
+        let raw = if batched {
+            self.run_handpose(tensors)?
+        } else {
+            let mut raw = Vec::with_capacity(tensors.len());
+            for tensor in tensors {
+                raw.extend(self.run_handpose(vec![tensor])?);
+            }
+            raw
+        };
 
-impl TrackedHand {
-    fn is_stale(&self, now: Instant) -> bool {
-        now.duration_since(self.last_seen) > TRACK_MAX_AGE || self.confidence < TRACK_MIN_CONF
+        Ok(crops
+            .iter()
+            .zip(&transforms)
+            .zip(raw)
+            .filter_map(|((crop, transform), raw)| self.tracking.finish_hand(crop, transform, raw))
+            .collect())
     }
 
-    fn estimate_roi(&self) -> Option<((f32, f32), f32, f32)> {
-        if self.projected.len() < 3 {
-            return None;
-        }
-
-        let (min_x, max_x, min_y, max_y) = self
-            .projected
-            .iter()
-            .fold((f32::MAX, f32::MIN, f32::MAX, f32::MIN), |acc, (x, y)| {
-                (acc.0.min(*x), acc.1.max(*x), acc.2.min(*y), acc.3.max(*y))
-            });
+    /// Stacks `crops` into one (N, H, W, 3) input and splits the outputs back
+    /// into one [`RawHand`] per crop, in order.
+    fn run_handpose(&mut self, mut crops: Vec<Array4<f32>>) -> Result<Vec<RawHand>> {
+        let count = crops.len();
+        let batch = if count == 1 {
+            crops.swap_remove(0)
+        } else {
+            let views: Vec<_> = crops.iter().map(Array4::view).collect();
+            ndarray::concatenate(Axis(0), &views).context("failed to stack hand crops")?
+        };
+        let tensor = Tensor::from_array(self.handpose_layout.arrange(batch))?;
+        let outputs = self
+            .handpose
+            .run(ort::inputs![tensor])
+            .context("failed to run ORT session")?;
 
-        if !min_x.is_finite() || !max_x.is_finite() || !min_y.is_finite() || !max_y.is_finite() {
-            return None;
+        let map = self.handpose_outputs;
+        if outputs.len() <= map.landmarks {
+            return Err(anyhow!("model returned no landmark output"));
         }
-
-        let span = (max_x - min_x).max(max_y - min_y).max(1.0);
-        let expanded = span * 1.8;
-        let side = expanded
-            .max(self.transform.side * 0.7)
-            .min(self.transform.side * 2.5)
-            .max(80.0);
-
-        let center = ((min_x + max_x) * 0.5, (min_y + max_y) * 0.5);
-        let angle =
-            estimate_orientation_from_landmarks(&self.projected).unwrap_or(self.transform.angle);
-
-        Some((center, side, angle))
+        let coords = outputs[map.landmarks].try_extract_array::<f32>()?;
+        let flattened: Vec<f32> = coords.iter().copied().collect();
+        let confidences = common::output_batch_scalars(&outputs, map.confidence, count);
+        let handedness = map
+            .handedness
+            .map(|idx| common::output_batch_scalars(&outputs, Some(idx), count));
+
+        let share = flattened.len() / count.max(1);
+        (0..count)
+            .map(|entry| {
+                Ok(RawHand {
+                    landmarks: common::decode_landmarks(
+                        &flattened[entry * share..(entry + 1) * share],
+                    )?,
+                    confidence: confidences[entry],
+                    handedness: handedness.as_ref().map(|values| values[entry]),
+                })
+            })
+            .collect()
     }
 }
 
-struct HandTracker {
-    last: Option<TrackedHand>,
-}
-
-impl HandTracker {
-    fn new() -> Self {
-        Self { last: None }
-    }
-
-    fn update(
-        &mut self,
-        transform: &common::CropTransform,
-        projected: &[(f32, f32)],
-        confidence: f32,
-        now: Instant,
-    ) {
-        if projected.is_empty() {
-            self.last = None;
-            return;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PixelLayout;
+
+    fn blank_frame() -> Frame {
+        let now = Instant::now();
+        Frame {
+            pixels: vec![0; PixelLayout::Rgb.buffer_len(640, 480)].into(),
+            layout: PixelLayout::Rgb,
+            width: 640,
+            height: 480,
+            timestamp: now,
+            acquired_at: now,
         }
-
-        self.last = Some(TrackedHand {
-            transform: transform.clone(),
-            projected: projected.to_vec(),
-            confidence,
-            last_seen: now,
-        });
     }
 
-    fn estimate_roi(&self, now: Instant) -> Option<(((f32, f32), f32, f32), f32)> {
-        let tracked = self.last.as_ref()?;
-        if tracked.is_stale(now) {
-            return None;
-        }
-        tracked.estimate_roi().map(|roi| (roi, tracked.confidence))
+    #[test]
+    fn a_corrupt_replacement_model_keeps_the_loaded_engine() {
+        let models = Path::new(env!("CARGO_MANIFEST_DIR")).join("models");
+        let dir = std::env::temp_dir().join(format!("gu-reload-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (handpose, palm) = (dir.join("handpose.onnx"), dir.join("palm.onnx"));
+        std::fs::copy(models.join("handpose_estimation.onnx"), &handpose).unwrap();
+        std::fs::copy(models.join("palm_detection.onnx"), &palm).unwrap();
+
+        let mut engine = OrtEngine::new(
+            &handpose,
+            &palm,
+            PalmDetectorConfig::default(),
+            ExecutionProvider::Cpu,
+            HandposeOutputNames::default(),
+        )
+        .unwrap();
+        engine.infer(&blank_frame()).unwrap();
+
+        std::fs::write(&handpose, b"not an onnx model").unwrap();
+        let err = engine.reload_models().unwrap_err();
+        assert_eq!(err.model_path(), Some(handpose.as_path()));
+        // Still running on the sessions loaded before.
+        let output = engine.infer(&blank_frame()).unwrap();
+        assert!(output.hands.is_empty());
+
+        std::fs::copy(models.join("handpose_estimation.onnx"), &handpose).unwrap();
+        engine.reload_models().unwrap();
+        engine.infer(&blank_frame()).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
     }
-}
-
-fn estimate_orientation_from_landmarks(points: &[(f32, f32)]) -> Option<f32> {
-    use std::f32::consts::PI;
 
-    if points.len() <= 17 {
-        return None;
+    #[test]
+    fn a_replacement_model_with_unknown_outputs_keeps_the_loaded_engine() {
+        let models = Path::new(env!("CARGO_MANIFEST_DIR")).join("models");
+        let dir = std::env::temp_dir().join(format!("gu-remap-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (handpose, palm) = (dir.join("handpose.onnx"), dir.join("palm.onnx"));
+        std::fs::copy(models.join("handpose_estimation.onnx"), &handpose).unwrap();
+        std::fs::copy(models.join("palm_detection.onnx"), &palm).unwrap();
+
+        let mut engine = OrtEngine::new(
+            &handpose,
+            &palm,
+            PalmDetectorConfig::default(),
+            ExecutionProvider::Cpu,
+            HandposeOutputNames::default(),
+        )
+        .unwrap();
+        let (layout, outputs, batched) = (
+            engine.handpose_layout,
+            engine.handpose_outputs,
+            engine.handpose_batched,
+        );
+
+        // The palm detector loads as a session but has no 63 value output.
+        std::fs::copy(models.join("palm_detection.onnx"), &handpose).unwrap();
+        let err = engine.reload_models().unwrap_err();
+        assert_eq!(err.model_path(), Some(handpose.as_path()));
+        let err = engine
+            .set_execution_provider(ExecutionProvider::Cpu)
+            .unwrap_err();
+        assert_eq!(err.model_path(), Some(handpose.as_path()));
+        assert_eq!(engine.handpose_layout, layout);
+        assert_eq!(engine.handpose_outputs, outputs);
+        assert_eq!(engine.handpose_batched, batched);
+        engine.infer(&blank_frame()).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
     }
 
-    let wrist = points[0];
-    let index = points[5];
-    let pinky = points[17];
-    let axis_x = ((index.0 + pinky.0) * 0.5) - wrist.0;
-    let axis_y = ((index.1 + pinky.1) * 0.5) - wrist.1;
-
-    if axis_x.abs() < f32::EPSILON && axis_y.abs() < f32::EPSILON {
-        return None;
+    #[test]
+    fn a_channels_first_handpose_model_runs_in_the_engine() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let mut engine = OrtEngine::new(
+            &root.join("testdata/nchw_dynamic_batch.onnx"),
+            &root.join("models/palm_detection.onnx"),
+            PalmDetectorConfig::default(),
+            ExecutionProvider::Cpu,
+            HandposeOutputNames::default(),
+        )
+        .unwrap();
+        assert_eq!(engine.handpose_layout, TensorLayout::Nchw);
+        assert!(engine.handpose_batched);
+
+        let crop = Array4::from_elem((1, 4, 4, 3), 0.5);
+        let hands = engine.run_handpose(vec![crop.clone()]).unwrap();
+        assert_eq!(hands.len(), 1);
+        assert_eq!(hands[0].landmarks.len(), common::NUM_LANDMARKS);
+        let hands = engine.run_handpose(vec![crop.clone(), crop]).unwrap();
+        assert_eq!(hands.len(), 2);
     }
-
-    let radians = PI / 2.0 - (-(axis_y)).atan2(axis_x);
-    let two_pi = 2.0 * PI;
-    Some(radians - two_pi * ((radians + PI) / two_pi).floor())
 }
@@ -1,2021 +0,0 @@
-// Auto-generated from palm_detection_mediapipe/mp_palmdet.py
-// Do not edit manually.
-pub const NUM_ANCHORS: usize = 2016;
-pub const ANCHORS: [[f32; 2]; NUM_ANCHORS] = [
-    [0.02083333f32, 0.02083333f32],
-    [0.02083333f32, 0.02083333f32],
-    [0.06250000f32, 0.02083333f32],
-    [0.06250000f32, 0.02083333f32],
-    [0.10416666f32, 0.02083333f32],
-    [0.10416666f32, 0.02083333f32],
-    [0.14583333f32, 0.02083333f32],
-    [0.14583333f32, 0.02083333f32],
-    [0.18750000f32, 0.02083333f32],
-    [0.18750000f32, 0.02083333f32],
-    [0.22916667f32, 0.02083333f32],
-    [0.22916667f32, 0.02083333f32],
-    [0.27083334f32, 0.02083333f32],
-    [0.27083334f32, 0.02083333f32],
-    [0.31250000f32, 0.02083333f32],
-    [0.31250000f32, 0.02083333f32],
-    [0.35416666f32, 0.02083333f32],
-    [0.35416666f32, 0.02083333f32],
-    [0.39583334f32, 0.02083333f32],
-    [0.39583334f32, 0.02083333f32],
-    [0.43750000f32, 0.02083333f32],
-    [0.43750000f32, 0.02083333f32],
-    [0.47916666f32, 0.02083333f32],
-    [0.47916666f32, 0.02083333f32],
-    [0.52083330f32, 0.02083333f32],
-    [0.52083330f32, 0.02083333f32],
-    [0.56250000f32, 0.02083333f32],
-    [0.56250000f32, 0.02083333f32],
-    [0.60416670f32, 0.02083333f32],
-    [0.60416670f32, 0.02083333f32],
-    [0.64583330f32, 0.02083333f32],
-    [0.64583330f32, 0.02083333f32],
-    [0.68750000f32, 0.02083333f32],
-    [0.68750000f32, 0.02083333f32],
-    [0.72916670f32, 0.02083333f32],
-    [0.72916670f32, 0.02083333f32],
-    [0.77083330f32, 0.02083333f32],
-    [0.77083330f32, 0.02083333f32],
-    [0.81250000f32, 0.02083333f32],
-    [0.81250000f32, 0.02083333f32],
-    [0.85416670f32, 0.02083333f32],
-    [0.85416670f32, 0.02083333f32],
-    [0.89583330f32, 0.02083333f32],
-    [0.89583330f32, 0.02083333f32],
-    [0.93750000f32, 0.02083333f32],
-    [0.93750000f32, 0.02083333f32],
-    [0.97916670f32, 0.02083333f32],
-    [0.97916670f32, 0.02083333f32],
-    [0.02083333f32, 0.06250000f32],
-    [0.02083333f32, 0.06250000f32],
-    [0.06250000f32, 0.06250000f32],
-    [0.06250000f32, 0.06250000f32],
-    [0.10416666f32, 0.06250000f32],
-    [0.10416666f32, 0.06250000f32],
-    [0.14583333f32, 0.06250000f32],
-    [0.14583333f32, 0.06250000f32],
-    [0.18750000f32, 0.06250000f32],
-    [0.18750000f32, 0.06250000f32],
-    [0.22916667f32, 0.06250000f32],
-    [0.22916667f32, 0.06250000f32],
-    [0.27083334f32, 0.06250000f32],
-    [0.27083334f32, 0.06250000f32],
-    [0.31250000f32, 0.06250000f32],
-    [0.31250000f32, 0.06250000f32],
-    [0.35416666f32, 0.06250000f32],
-    [0.35416666f32, 0.06250000f32],
-    [0.39583334f32, 0.06250000f32],
-    [0.39583334f32, 0.06250000f32],
-    [0.43750000f32, 0.06250000f32],
-    [0.43750000f32, 0.06250000f32],
-    [0.47916666f32, 0.06250000f32],
-    [0.47916666f32, 0.06250000f32],
-    [0.52083330f32, 0.06250000f32],
-    [0.52083330f32, 0.06250000f32],
-    [0.56250000f32, 0.06250000f32],
-    [0.56250000f32, 0.06250000f32],
-    [0.60416670f32, 0.06250000f32],
-    [0.60416670f32, 0.06250000f32],
-    [0.64583330f32, 0.06250000f32],
-    [0.64583330f32, 0.06250000f32],
-    [0.68750000f32, 0.06250000f32],
-    [0.68750000f32, 0.06250000f32],
-    [0.72916670f32, 0.06250000f32],
-    [0.72916670f32, 0.06250000f32],
-    [0.77083330f32, 0.06250000f32],
-    [0.77083330f32, 0.06250000f32],
-    [0.81250000f32, 0.06250000f32],
-    [0.81250000f32, 0.06250000f32],
-    [0.85416670f32, 0.06250000f32],
-    [0.85416670f32, 0.06250000f32],
-    [0.89583330f32, 0.06250000f32],
-    [0.89583330f32, 0.06250000f32],
-    [0.93750000f32, 0.06250000f32],
-    [0.93750000f32, 0.06250000f32],
-    [0.97916670f32, 0.06250000f32],
-    [0.97916670f32, 0.06250000f32],
-    [0.02083333f32, 0.10416666f32],
-    [0.02083333f32, 0.10416666f32],
-    [0.06250000f32, 0.10416666f32],
-    [0.06250000f32, 0.10416666f32],
-    [0.10416666f32, 0.10416666f32],
-    [0.10416666f32, 0.10416666f32],
-    [0.14583333f32, 0.10416666f32],
-    [0.14583333f32, 0.10416666f32],
-    [0.18750000f32, 0.10416666f32],
-    [0.18750000f32, 0.10416666f32],
-    [0.22916667f32, 0.10416666f32],
-    [0.22916667f32, 0.10416666f32],
-    [0.27083334f32, 0.10416666f32],
-    [0.27083334f32, 0.10416666f32],
-    [0.31250000f32, 0.10416666f32],
-    [0.31250000f32, 0.10416666f32],
-    [0.35416666f32, 0.10416666f32],
-    [0.35416666f32, 0.10416666f32],
-    [0.39583334f32, 0.10416666f32],
-    [0.39583334f32, 0.10416666f32],
-    [0.43750000f32, 0.10416666f32],
-    [0.43750000f32, 0.10416666f32],
-    [0.47916666f32, 0.10416666f32],
-    [0.47916666f32, 0.10416666f32],
-    [0.52083330f32, 0.10416666f32],
-    [0.52083330f32, 0.10416666f32],
-    [0.56250000f32, 0.10416666f32],
-    [0.56250000f32, 0.10416666f32],
-    [0.60416670f32, 0.10416666f32],
-    [0.60416670f32, 0.10416666f32],
-    [0.64583330f32, 0.10416666f32],
-    [0.64583330f32, 0.10416666f32],
-    [0.68750000f32, 0.10416666f32],
-    [0.68750000f32, 0.10416666f32],
-    [0.72916670f32, 0.10416666f32],
-    [0.72916670f32, 0.10416666f32],
-    [0.77083330f32, 0.10416666f32],
-    [0.77083330f32, 0.10416666f32],
-    [0.81250000f32, 0.10416666f32],
-    [0.81250000f32, 0.10416666f32],
-    [0.85416670f32, 0.10416666f32],
-    [0.85416670f32, 0.10416666f32],
-    [0.89583330f32, 0.10416666f32],
-    [0.89583330f32, 0.10416666f32],
-    [0.93750000f32, 0.10416666f32],
-    [0.93750000f32, 0.10416666f32],
-    [0.97916670f32, 0.10416666f32],
-    [0.97916670f32, 0.10416666f32],
-    [0.02083333f32, 0.14583333f32],
-    [0.02083333f32, 0.14583333f32],
-    [0.06250000f32, 0.14583333f32],
-    [0.06250000f32, 0.14583333f32],
-    [0.10416666f32, 0.14583333f32],
-    [0.10416666f32, 0.14583333f32],
-    [0.14583333f32, 0.14583333f32],
-    [0.14583333f32, 0.14583333f32],
-    [0.18750000f32, 0.14583333f32],
-    [0.18750000f32, 0.14583333f32],
-    [0.22916667f32, 0.14583333f32],
-    [0.22916667f32, 0.14583333f32],
-    [0.27083334f32, 0.14583333f32],
-    [0.27083334f32, 0.14583333f32],
-    [0.31250000f32, 0.14583333f32],
-    [0.31250000f32, 0.14583333f32],
-    [0.35416666f32, 0.14583333f32],
-    [0.35416666f32, 0.14583333f32],
-    [0.39583334f32, 0.14583333f32],
-    [0.39583334f32, 0.14583333f32],
-    [0.43750000f32, 0.14583333f32],
-    [0.43750000f32, 0.14583333f32],
-    [0.47916666f32, 0.14583333f32],
-    [0.47916666f32, 0.14583333f32],
-    [0.52083330f32, 0.14583333f32],
-    [0.52083330f32, 0.14583333f32],
-    [0.56250000f32, 0.14583333f32],
-    [0.56250000f32, 0.14583333f32],
-    [0.60416670f32, 0.14583333f32],
-    [0.60416670f32, 0.14583333f32],
-    [0.64583330f32, 0.14583333f32],
-    [0.64583330f32, 0.14583333f32],
-    [0.68750000f32, 0.14583333f32],
-    [0.68750000f32, 0.14583333f32],
-    [0.72916670f32, 0.14583333f32],
-    [0.72916670f32, 0.14583333f32],
-    [0.77083330f32, 0.14583333f32],
-    [0.77083330f32, 0.14583333f32],
-    [0.81250000f32, 0.14583333f32],
-    [0.81250000f32, 0.14583333f32],
-    [0.85416670f32, 0.14583333f32],
-    [0.85416670f32, 0.14583333f32],
-    [0.89583330f32, 0.14583333f32],
-    [0.89583330f32, 0.14583333f32],
-    [0.93750000f32, 0.14583333f32],
-    [0.93750000f32, 0.14583333f32],
-    [0.97916670f32, 0.14583333f32],
-    [0.97916670f32, 0.14583333f32],
-    [0.02083333f32, 0.18750000f32],
-    [0.02083333f32, 0.18750000f32],
-    [0.06250000f32, 0.18750000f32],
-    [0.06250000f32, 0.18750000f32],
-    [0.10416666f32, 0.18750000f32],
-    [0.10416666f32, 0.18750000f32],
-    [0.14583333f32, 0.18750000f32],
-    [0.14583333f32, 0.18750000f32],
-    [0.18750000f32, 0.18750000f32],
-    [0.18750000f32, 0.18750000f32],
-    [0.22916667f32, 0.18750000f32],
-    [0.22916667f32, 0.18750000f32],
-    [0.27083334f32, 0.18750000f32],
-    [0.27083334f32, 0.18750000f32],
-    [0.31250000f32, 0.18750000f32],
-    [0.31250000f32, 0.18750000f32],
-    [0.35416666f32, 0.18750000f32],
-    [0.35416666f32, 0.18750000f32],
-    [0.39583334f32, 0.18750000f32],
-    [0.39583334f32, 0.18750000f32],
-    [0.43750000f32, 0.18750000f32],
-    [0.43750000f32, 0.18750000f32],
-    [0.47916666f32, 0.18750000f32],
-    [0.47916666f32, 0.18750000f32],
-    [0.52083330f32, 0.18750000f32],
-    [0.52083330f32, 0.18750000f32],
-    [0.56250000f32, 0.18750000f32],
-    [0.56250000f32, 0.18750000f32],
-    [0.60416670f32, 0.18750000f32],
-    [0.60416670f32, 0.18750000f32],
-    [0.64583330f32, 0.18750000f32],
-    [0.64583330f32, 0.18750000f32],
-    [0.68750000f32, 0.18750000f32],
-    [0.68750000f32, 0.18750000f32],
-    [0.72916670f32, 0.18750000f32],
-    [0.72916670f32, 0.18750000f32],
-    [0.77083330f32, 0.18750000f32],
-    [0.77083330f32, 0.18750000f32],
-    [0.81250000f32, 0.18750000f32],
-    [0.81250000f32, 0.18750000f32],
-    [0.85416670f32, 0.18750000f32],
-    [0.85416670f32, 0.18750000f32],
-    [0.89583330f32, 0.18750000f32],
-    [0.89583330f32, 0.18750000f32],
-    [0.93750000f32, 0.18750000f32],
-    [0.93750000f32, 0.18750000f32],
-    [0.97916670f32, 0.18750000f32],
-    [0.97916670f32, 0.18750000f32],
-    [0.02083333f32, 0.22916667f32],
-    [0.02083333f32, 0.22916667f32],
-    [0.06250000f32, 0.22916667f32],
-    [0.06250000f32, 0.22916667f32],
-    [0.10416666f32, 0.22916667f32],
-    [0.10416666f32, 0.22916667f32],
-    [0.14583333f32, 0.22916667f32],
-    [0.14583333f32, 0.22916667f32],
-    [0.18750000f32, 0.22916667f32],
-    [0.18750000f32, 0.22916667f32],
-    [0.22916667f32, 0.22916667f32],
-    [0.22916667f32, 0.22916667f32],
-    [0.27083334f32, 0.22916667f32],
-    [0.27083334f32, 0.22916667f32],
-    [0.31250000f32, 0.22916667f32],
-    [0.31250000f32, 0.22916667f32],
-    [0.35416666f32, 0.22916667f32],
-    [0.35416666f32, 0.22916667f32],
-    [0.39583334f32, 0.22916667f32],
-    [0.39583334f32, 0.22916667f32],
-    [0.43750000f32, 0.22916667f32],
-    [0.43750000f32, 0.22916667f32],
-    [0.47916666f32, 0.22916667f32],
-    [0.47916666f32, 0.22916667f32],
-    [0.52083330f32, 0.22916667f32],
-    [0.52083330f32, 0.22916667f32],
-    [0.56250000f32, 0.22916667f32],
-    [0.56250000f32, 0.22916667f32],
-    [0.60416670f32, 0.22916667f32],
-    [0.60416670f32, 0.22916667f32],
-    [0.64583330f32, 0.22916667f32],
-    [0.64583330f32, 0.22916667f32],
-    [0.68750000f32, 0.22916667f32],
-    [0.68750000f32, 0.22916667f32],
-    [0.72916670f32, 0.22916667f32],
-    [0.72916670f32, 0.22916667f32],
-    [0.77083330f32, 0.22916667f32],
-    [0.77083330f32, 0.22916667f32],
-    [0.81250000f32, 0.22916667f32],
-    [0.81250000f32, 0.22916667f32],
-    [0.85416670f32, 0.22916667f32],
-    [0.85416670f32, 0.22916667f32],
-    [0.89583330f32, 0.22916667f32],
-    [0.89583330f32, 0.22916667f32],
-    [0.93750000f32, 0.22916667f32],
-    [0.93750000f32, 0.22916667f32],
-    [0.97916670f32, 0.22916667f32],
-    [0.97916670f32, 0.22916667f32],
-    [0.02083333f32, 0.27083334f32],
-    [0.02083333f32, 0.27083334f32],
-    [0.06250000f32, 0.27083334f32],
-    [0.06250000f32, 0.27083334f32],
-    [0.10416666f32, 0.27083334f32],
-    [0.10416666f32, 0.27083334f32],
-    [0.14583333f32, 0.27083334f32],
-    [0.14583333f32, 0.27083334f32],
-    [0.18750000f32, 0.27083334f32],
-    [0.18750000f32, 0.27083334f32],
-    [0.22916667f32, 0.27083334f32],
-    [0.22916667f32, 0.27083334f32],
-    [0.27083334f32, 0.27083334f32],
-    [0.27083334f32, 0.27083334f32],
-    [0.31250000f32, 0.27083334f32],
-    [0.31250000f32, 0.27083334f32],
-    [0.35416666f32, 0.27083334f32],
-    [0.35416666f32, 0.27083334f32],
-    [0.39583334f32, 0.27083334f32],
-    [0.39583334f32, 0.27083334f32],
-    [0.43750000f32, 0.27083334f32],
-    [0.43750000f32, 0.27083334f32],
-    [0.47916666f32, 0.27083334f32],
-    [0.47916666f32, 0.27083334f32],
-    [0.52083330f32, 0.27083334f32],
-    [0.52083330f32, 0.27083334f32],
-    [0.56250000f32, 0.27083334f32],
-    [0.56250000f32, 0.27083334f32],
-    [0.60416670f32, 0.27083334f32],
-    [0.60416670f32, 0.27083334f32],
-    [0.64583330f32, 0.27083334f32],
-    [0.64583330f32, 0.27083334f32],
-    [0.68750000f32, 0.27083334f32],
-    [0.68750000f32, 0.27083334f32],
-    [0.72916670f32, 0.27083334f32],
-    [0.72916670f32, 0.27083334f32],
-    [0.77083330f32, 0.27083334f32],
-    [0.77083330f32, 0.27083334f32],
-    [0.81250000f32, 0.27083334f32],
-    [0.81250000f32, 0.27083334f32],
-    [0.85416670f32, 0.27083334f32],
-    [0.85416670f32, 0.27083334f32],
-    [0.89583330f32, 0.27083334f32],
-    [0.89583330f32, 0.27083334f32],
-    [0.93750000f32, 0.27083334f32],
-    [0.93750000f32, 0.27083334f32],
-    [0.97916670f32, 0.27083334f32],
-    [0.97916670f32, 0.27083334f32],
-    [0.02083333f32, 0.31250000f32],
-    [0.02083333f32, 0.31250000f32],
-    [0.06250000f32, 0.31250000f32],
-    [0.06250000f32, 0.31250000f32],
-    [0.10416666f32, 0.31250000f32],
-    [0.10416666f32, 0.31250000f32],
-    [0.14583333f32, 0.31250000f32],
-    [0.14583333f32, 0.31250000f32],
-    [0.18750000f32, 0.31250000f32],
-    [0.18750000f32, 0.31250000f32],
-    [0.22916667f32, 0.31250000f32],
-    [0.22916667f32, 0.31250000f32],
-    [0.27083334f32, 0.31250000f32],
-    [0.27083334f32, 0.31250000f32],
-    [0.31250000f32, 0.31250000f32],
-    [0.31250000f32, 0.31250000f32],
-    [0.35416666f32, 0.31250000f32],
-    [0.35416666f32, 0.31250000f32],
-    [0.39583334f32, 0.31250000f32],
-    [0.39583334f32, 0.31250000f32],
-    [0.43750000f32, 0.31250000f32],
-    [0.43750000f32, 0.31250000f32],
-    [0.47916666f32, 0.31250000f32],
-    [0.47916666f32, 0.31250000f32],
-    [0.52083330f32, 0.31250000f32],
-    [0.52083330f32, 0.31250000f32],
-    [0.56250000f32, 0.31250000f32],
-    [0.56250000f32, 0.31250000f32],
-    [0.60416670f32, 0.31250000f32],
-    [0.60416670f32, 0.31250000f32],
-    [0.64583330f32, 0.31250000f32],
-    [0.64583330f32, 0.31250000f32],
-    [0.68750000f32, 0.31250000f32],
-    [0.68750000f32, 0.31250000f32],
-    [0.72916670f32, 0.31250000f32],
-    [0.72916670f32, 0.31250000f32],
-    [0.77083330f32, 0.31250000f32],
-    [0.77083330f32, 0.31250000f32],
-    [0.81250000f32, 0.31250000f32],
-    [0.81250000f32, 0.31250000f32],
-    [0.85416670f32, 0.31250000f32],
-    [0.85416670f32, 0.31250000f32],
-    [0.89583330f32, 0.31250000f32],
-    [0.89583330f32, 0.31250000f32],
-    [0.93750000f32, 0.31250000f32],
-    [0.93750000f32, 0.31250000f32],
-    [0.97916670f32, 0.31250000f32],
-    [0.97916670f32, 0.31250000f32],
-    [0.02083333f32, 0.35416666f32],
-    [0.02083333f32, 0.35416666f32],
-    [0.06250000f32, 0.35416666f32],
-    [0.06250000f32, 0.35416666f32],
-    [0.10416666f32, 0.35416666f32],
-    [0.10416666f32, 0.35416666f32],
-    [0.14583333f32, 0.35416666f32],
-    [0.14583333f32, 0.35416666f32],
-    [0.18750000f32, 0.35416666f32],
-    [0.18750000f32, 0.35416666f32],
-    [0.22916667f32, 0.35416666f32],
-    [0.22916667f32, 0.35416666f32],
-    [0.27083334f32, 0.35416666f32],
-    [0.27083334f32, 0.35416666f32],
-    [0.31250000f32, 0.35416666f32],
-    [0.31250000f32, 0.35416666f32],
-    [0.35416666f32, 0.35416666f32],
-    [0.35416666f32, 0.35416666f32],
-    [0.39583334f32, 0.35416666f32],
-    [0.39583334f32, 0.35416666f32],
-    [0.43750000f32, 0.35416666f32],
-    [0.43750000f32, 0.35416666f32],
-    [0.47916666f32, 0.35416666f32],
-    [0.47916666f32, 0.35416666f32],
-    [0.52083330f32, 0.35416666f32],
-    [0.52083330f32, 0.35416666f32],
-    [0.56250000f32, 0.35416666f32],
-    [0.56250000f32, 0.35416666f32],
-    [0.60416670f32, 0.35416666f32],
-    [0.60416670f32, 0.35416666f32],
-    [0.64583330f32, 0.35416666f32],
-    [0.64583330f32, 0.35416666f32],
-    [0.68750000f32, 0.35416666f32],
-    [0.68750000f32, 0.35416666f32],
-    [0.72916670f32, 0.35416666f32],
-    [0.72916670f32, 0.35416666f32],
-    [0.77083330f32, 0.35416666f32],
-    [0.77083330f32, 0.35416666f32],
-    [0.81250000f32, 0.35416666f32],
-    [0.81250000f32, 0.35416666f32],
-    [0.85416670f32, 0.35416666f32],
-    [0.85416670f32, 0.35416666f32],
-    [0.89583330f32, 0.35416666f32],
-    [0.89583330f32, 0.35416666f32],
-    [0.93750000f32, 0.35416666f32],
-    [0.93750000f32, 0.35416666f32],
-    [0.97916670f32, 0.35416666f32],
-    [0.97916670f32, 0.35416666f32],
-    [0.02083333f32, 0.39583334f32],
-    [0.02083333f32, 0.39583334f32],
-    [0.06250000f32, 0.39583334f32],
-    [0.06250000f32, 0.39583334f32],
-    [0.10416666f32, 0.39583334f32],
-    [0.10416666f32, 0.39583334f32],
-    [0.14583333f32, 0.39583334f32],
-    [0.14583333f32, 0.39583334f32],
-    [0.18750000f32, 0.39583334f32],
-    [0.18750000f32, 0.39583334f32],
-    [0.22916667f32, 0.39583334f32],
-    [0.22916667f32, 0.39583334f32],
-    [0.27083334f32, 0.39583334f32],
-    [0.27083334f32, 0.39583334f32],
-    [0.31250000f32, 0.39583334f32],
-    [0.31250000f32, 0.39583334f32],
-    [0.35416666f32, 0.39583334f32],
-    [0.35416666f32, 0.39583334f32],
-    [0.39583334f32, 0.39583334f32],
-    [0.39583334f32, 0.39583334f32],
-    [0.43750000f32, 0.39583334f32],
-    [0.43750000f32, 0.39583334f32],
-    [0.47916666f32, 0.39583334f32],
-    [0.47916666f32, 0.39583334f32],
-    [0.52083330f32, 0.39583334f32],
-    [0.52083330f32, 0.39583334f32],
-    [0.56250000f32, 0.39583334f32],
-    [0.56250000f32, 0.39583334f32],
-    [0.60416670f32, 0.39583334f32],
-    [0.60416670f32, 0.39583334f32],
-    [0.64583330f32, 0.39583334f32],
-    [0.64583330f32, 0.39583334f32],
-    [0.68750000f32, 0.39583334f32],
-    [0.68750000f32, 0.39583334f32],
-    [0.72916670f32, 0.39583334f32],
-    [0.72916670f32, 0.39583334f32],
-    [0.77083330f32, 0.39583334f32],
-    [0.77083330f32, 0.39583334f32],
-    [0.81250000f32, 0.39583334f32],
-    [0.81250000f32, 0.39583334f32],
-    [0.85416670f32, 0.39583334f32],
-    [0.85416670f32, 0.39583334f32],
-    [0.89583330f32, 0.39583334f32],
-    [0.89583330f32, 0.39583334f32],
-    [0.93750000f32, 0.39583334f32],
-    [0.93750000f32, 0.39583334f32],
-    [0.97916670f32, 0.39583334f32],
-    [0.97916670f32, 0.39583334f32],
-    [0.02083333f32, 0.43750000f32],
-    [0.02083333f32, 0.43750000f32],
-    [0.06250000f32, 0.43750000f32],
-    [0.06250000f32, 0.43750000f32],
-    [0.10416666f32, 0.43750000f32],
-    [0.10416666f32, 0.43750000f32],
-    [0.14583333f32, 0.43750000f32],
-    [0.14583333f32, 0.43750000f32],
-    [0.18750000f32, 0.43750000f32],
-    [0.18750000f32, 0.43750000f32],
-    [0.22916667f32, 0.43750000f32],
-    [0.22916667f32, 0.43750000f32],
-    [0.27083334f32, 0.43750000f32],
-    [0.27083334f32, 0.43750000f32],
-    [0.31250000f32, 0.43750000f32],
-    [0.31250000f32, 0.43750000f32],
-    [0.35416666f32, 0.43750000f32],
-    [0.35416666f32, 0.43750000f32],
-    [0.39583334f32, 0.43750000f32],
-    [0.39583334f32, 0.43750000f32],
-    [0.43750000f32, 0.43750000f32],
-    [0.43750000f32, 0.43750000f32],
-    [0.47916666f32, 0.43750000f32],
-    [0.47916666f32, 0.43750000f32],
-    [0.52083330f32, 0.43750000f32],
-    [0.52083330f32, 0.43750000f32],
-    [0.56250000f32, 0.43750000f32],
-    [0.56250000f32, 0.43750000f32],
-    [0.60416670f32, 0.43750000f32],
-    [0.60416670f32, 0.43750000f32],
-    [0.64583330f32, 0.43750000f32],
-    [0.64583330f32, 0.43750000f32],
-    [0.68750000f32, 0.43750000f32],
-    [0.68750000f32, 0.43750000f32],
-    [0.72916670f32, 0.43750000f32],
-    [0.72916670f32, 0.43750000f32],
-    [0.77083330f32, 0.43750000f32],
-    [0.77083330f32, 0.43750000f32],
-    [0.81250000f32, 0.43750000f32],
-    [0.81250000f32, 0.43750000f32],
-    [0.85416670f32, 0.43750000f32],
-    [0.85416670f32, 0.43750000f32],
-    [0.89583330f32, 0.43750000f32],
-    [0.89583330f32, 0.43750000f32],
-    [0.93750000f32, 0.43750000f32],
-    [0.93750000f32, 0.43750000f32],
-    [0.97916670f32, 0.43750000f32],
-    [0.97916670f32, 0.43750000f32],
-    [0.02083333f32, 0.47916666f32],
-    [0.02083333f32, 0.47916666f32],
-    [0.06250000f32, 0.47916666f32],
-    [0.06250000f32, 0.47916666f32],
-    [0.10416666f32, 0.47916666f32],
-    [0.10416666f32, 0.47916666f32],
-    [0.14583333f32, 0.47916666f32],
-    [0.14583333f32, 0.47916666f32],
-    [0.18750000f32, 0.47916666f32],
-    [0.18750000f32, 0.47916666f32],
-    [0.22916667f32, 0.47916666f32],
-    [0.22916667f32, 0.47916666f32],
-    [0.27083334f32, 0.47916666f32],
-    [0.27083334f32, 0.47916666f32],
-    [0.31250000f32, 0.47916666f32],
-    [0.31250000f32, 0.47916666f32],
-    [0.35416666f32, 0.47916666f32],
-    [0.35416666f32, 0.47916666f32],
-    [0.39583334f32, 0.47916666f32],
-    [0.39583334f32, 0.47916666f32],
-    [0.43750000f32, 0.47916666f32],
-    [0.43750000f32, 0.47916666f32],
-    [0.47916666f32, 0.47916666f32],
-    [0.47916666f32, 0.47916666f32],
-    [0.52083330f32, 0.47916666f32],
-    [0.52083330f32, 0.47916666f32],
-    [0.56250000f32, 0.47916666f32],
-    [0.56250000f32, 0.47916666f32],
-    [0.60416670f32, 0.47916666f32],
-    [0.60416670f32, 0.47916666f32],
-    [0.64583330f32, 0.47916666f32],
-    [0.64583330f32, 0.47916666f32],
-    [0.68750000f32, 0.47916666f32],
-    [0.68750000f32, 0.47916666f32],
-    [0.72916670f32, 0.47916666f32],
-    [0.72916670f32, 0.47916666f32],
-    [0.77083330f32, 0.47916666f32],
-    [0.77083330f32, 0.47916666f32],
-    [0.81250000f32, 0.47916666f32],
-    [0.81250000f32, 0.47916666f32],
-    [0.85416670f32, 0.47916666f32],
-    [0.85416670f32, 0.47916666f32],
-    [0.89583330f32, 0.47916666f32],
-    [0.89583330f32, 0.47916666f32],
-    [0.93750000f32, 0.47916666f32],
-    [0.93750000f32, 0.47916666f32],
-    [0.97916670f32, 0.47916666f32],
-    [0.97916670f32, 0.47916666f32],
-    [0.02083333f32, 0.52083330f32],
-    [0.02083333f32, 0.52083330f32],
-    [0.06250000f32, 0.52083330f32],
-    [0.06250000f32, 0.52083330f32],
-    [0.10416666f32, 0.52083330f32],
-    [0.10416666f32, 0.52083330f32],
-    [0.14583333f32, 0.52083330f32],
-    [0.14583333f32, 0.52083330f32],
-    [0.18750000f32, 0.52083330f32],
-    [0.18750000f32, 0.52083330f32],
-    [0.22916667f32, 0.52083330f32],
-    [0.22916667f32, 0.52083330f32],
-    [0.27083334f32, 0.52083330f32],
-    [0.27083334f32, 0.52083330f32],
-    [0.31250000f32, 0.52083330f32],
-    [0.31250000f32, 0.52083330f32],
-    [0.35416666f32, 0.52083330f32],
-    [0.35416666f32, 0.52083330f32],
-    [0.39583334f32, 0.52083330f32],
-    [0.39583334f32, 0.52083330f32],
-    [0.43750000f32, 0.52083330f32],
-    [0.43750000f32, 0.52083330f32],
-    [0.47916666f32, 0.52083330f32],
-    [0.47916666f32, 0.52083330f32],
-    [0.52083330f32, 0.52083330f32],
-    [0.52083330f32, 0.52083330f32],
-    [0.56250000f32, 0.52083330f32],
-    [0.56250000f32, 0.52083330f32],
-    [0.60416670f32, 0.52083330f32],
-    [0.60416670f32, 0.52083330f32],
-    [0.64583330f32, 0.52083330f32],
-    [0.64583330f32, 0.52083330f32],
-    [0.68750000f32, 0.52083330f32],
-    [0.68750000f32, 0.52083330f32],
-    [0.72916670f32, 0.52083330f32],
-    [0.72916670f32, 0.52083330f32],
-    [0.77083330f32, 0.52083330f32],
-    [0.77083330f32, 0.52083330f32],
-    [0.81250000f32, 0.52083330f32],
-    [0.81250000f32, 0.52083330f32],
-    [0.85416670f32, 0.52083330f32],
-    [0.85416670f32, 0.52083330f32],
-    [0.89583330f32, 0.52083330f32],
-    [0.89583330f32, 0.52083330f32],
-    [0.93750000f32, 0.52083330f32],
-    [0.93750000f32, 0.52083330f32],
-    [0.97916670f32, 0.52083330f32],
-    [0.97916670f32, 0.52083330f32],
-    [0.02083333f32, 0.56250000f32],
-    [0.02083333f32, 0.56250000f32],
-    [0.06250000f32, 0.56250000f32],
-    [0.06250000f32, 0.56250000f32],
-    [0.10416666f32, 0.56250000f32],
-    [0.10416666f32, 0.56250000f32],
-    [0.14583333f32, 0.56250000f32],
-    [0.14583333f32, 0.56250000f32],
-    [0.18750000f32, 0.56250000f32],
-    [0.18750000f32, 0.56250000f32],
-    [0.22916667f32, 0.56250000f32],
-    [0.22916667f32, 0.56250000f32],
-    [0.27083334f32, 0.56250000f32],
-    [0.27083334f32, 0.56250000f32],
-    [0.31250000f32, 0.56250000f32],
-    [0.31250000f32, 0.56250000f32],
-    [0.35416666f32, 0.56250000f32],
-    [0.35416666f32, 0.56250000f32],
-    [0.39583334f32, 0.56250000f32],
-    [0.39583334f32, 0.56250000f32],
-    [0.43750000f32, 0.56250000f32],
-    [0.43750000f32, 0.56250000f32],
-    [0.47916666f32, 0.56250000f32],
-    [0.47916666f32, 0.56250000f32],
-    [0.52083330f32, 0.56250000f32],
-    [0.52083330f32, 0.56250000f32],
-    [0.56250000f32, 0.56250000f32],
-    [0.56250000f32, 0.56250000f32],
-    [0.60416670f32, 0.56250000f32],
-    [0.60416670f32, 0.56250000f32],
-    [0.64583330f32, 0.56250000f32],
-    [0.64583330f32, 0.56250000f32],
-    [0.68750000f32, 0.56250000f32],
-    [0.68750000f32, 0.56250000f32],
-    [0.72916670f32, 0.56250000f32],
-    [0.72916670f32, 0.56250000f32],
-    [0.77083330f32, 0.56250000f32],
-    [0.77083330f32, 0.56250000f32],
-    [0.81250000f32, 0.56250000f32],
-    [0.81250000f32, 0.56250000f32],
-    [0.85416670f32, 0.56250000f32],
-    [0.85416670f32, 0.56250000f32],
-    [0.89583330f32, 0.56250000f32],
-    [0.89583330f32, 0.56250000f32],
-    [0.93750000f32, 0.56250000f32],
-    [0.93750000f32, 0.56250000f32],
-    [0.97916670f32, 0.56250000f32],
-    [0.97916670f32, 0.56250000f32],
-    [0.02083333f32, 0.60416670f32],
-    [0.02083333f32, 0.60416670f32],
-    [0.06250000f32, 0.60416670f32],
-    [0.06250000f32, 0.60416670f32],
-    [0.10416666f32, 0.60416670f32],
-    [0.10416666f32, 0.60416670f32],
-    [0.14583333f32, 0.60416670f32],
-    [0.14583333f32, 0.60416670f32],
-    [0.18750000f32, 0.60416670f32],
-    [0.18750000f32, 0.60416670f32],
-    [0.22916667f32, 0.60416670f32],
-    [0.22916667f32, 0.60416670f32],
-    [0.27083334f32, 0.60416670f32],
-    [0.27083334f32, 0.60416670f32],
-    [0.31250000f32, 0.60416670f32],
-    [0.31250000f32, 0.60416670f32],
-    [0.35416666f32, 0.60416670f32],
-    [0.35416666f32, 0.60416670f32],
-    [0.39583334f32, 0.60416670f32],
-    [0.39583334f32, 0.60416670f32],
-    [0.43750000f32, 0.60416670f32],
-    [0.43750000f32, 0.60416670f32],
-    [0.47916666f32, 0.60416670f32],
-    [0.47916666f32, 0.60416670f32],
-    [0.52083330f32, 0.60416670f32],
-    [0.52083330f32, 0.60416670f32],
-    [0.56250000f32, 0.60416670f32],
-    [0.56250000f32, 0.60416670f32],
-    [0.60416670f32, 0.60416670f32],
-    [0.60416670f32, 0.60416670f32],
-    [0.64583330f32, 0.60416670f32],
-    [0.64583330f32, 0.60416670f32],
-    [0.68750000f32, 0.60416670f32],
-    [0.68750000f32, 0.60416670f32],
-    [0.72916670f32, 0.60416670f32],
-    [0.72916670f32, 0.60416670f32],
-    [0.77083330f32, 0.60416670f32],
-    [0.77083330f32, 0.60416670f32],
-    [0.81250000f32, 0.60416670f32],
-    [0.81250000f32, 0.60416670f32],
-    [0.85416670f32, 0.60416670f32],
-    [0.85416670f32, 0.60416670f32],
-    [0.89583330f32, 0.60416670f32],
-    [0.89583330f32, 0.60416670f32],
-    [0.93750000f32, 0.60416670f32],
-    [0.93750000f32, 0.60416670f32],
-    [0.97916670f32, 0.60416670f32],
-    [0.97916670f32, 0.60416670f32],
-    [0.02083333f32, 0.64583330f32],
-    [0.02083333f32, 0.64583330f32],
-    [0.06250000f32, 0.64583330f32],
-    [0.06250000f32, 0.64583330f32],
-    [0.10416666f32, 0.64583330f32],
-    [0.10416666f32, 0.64583330f32],
-    [0.14583333f32, 0.64583330f32],
-    [0.14583333f32, 0.64583330f32],
-    [0.18750000f32, 0.64583330f32],
-    [0.18750000f32, 0.64583330f32],
-    [0.22916667f32, 0.64583330f32],
-    [0.22916667f32, 0.64583330f32],
-    [0.27083334f32, 0.64583330f32],
-    [0.27083334f32, 0.64583330f32],
-    [0.31250000f32, 0.64583330f32],
-    [0.31250000f32, 0.64583330f32],
-    [0.35416666f32, 0.64583330f32],
-    [0.35416666f32, 0.64583330f32],
-    [0.39583334f32, 0.64583330f32],
-    [0.39583334f32, 0.64583330f32],
-    [0.43750000f32, 0.64583330f32],
-    [0.43750000f32, 0.64583330f32],
-    [0.47916666f32, 0.64583330f32],
-    [0.47916666f32, 0.64583330f32],
-    [0.52083330f32, 0.64583330f32],
-    [0.52083330f32, 0.64583330f32],
-    [0.56250000f32, 0.64583330f32],
-    [0.56250000f32, 0.64583330f32],
-    [0.60416670f32, 0.64583330f32],
-    [0.60416670f32, 0.64583330f32],
-    [0.64583330f32, 0.64583330f32],
-    [0.64583330f32, 0.64583330f32],
-    [0.68750000f32, 0.64583330f32],
-    [0.68750000f32, 0.64583330f32],
-    [0.72916670f32, 0.64583330f32],
-    [0.72916670f32, 0.64583330f32],
-    [0.77083330f32, 0.64583330f32],
-    [0.77083330f32, 0.64583330f32],
-    [0.81250000f32, 0.64583330f32],
-    [0.81250000f32, 0.64583330f32],
-    [0.85416670f32, 0.64583330f32],
-    [0.85416670f32, 0.64583330f32],
-    [0.89583330f32, 0.64583330f32],
-    [0.89583330f32, 0.64583330f32],
-    [0.93750000f32, 0.64583330f32],
-    [0.93750000f32, 0.64583330f32],
-    [0.97916670f32, 0.64583330f32],
-    [0.97916670f32, 0.64583330f32],
-    [0.02083333f32, 0.68750000f32],
-    [0.02083333f32, 0.68750000f32],
-    [0.06250000f32, 0.68750000f32],
-    [0.06250000f32, 0.68750000f32],
-    [0.10416666f32, 0.68750000f32],
-    [0.10416666f32, 0.68750000f32],
-    [0.14583333f32, 0.68750000f32],
-    [0.14583333f32, 0.68750000f32],
-    [0.18750000f32, 0.68750000f32],
-    [0.18750000f32, 0.68750000f32],
-    [0.22916667f32, 0.68750000f32],
-    [0.22916667f32, 0.68750000f32],
-    [0.27083334f32, 0.68750000f32],
-    [0.27083334f32, 0.68750000f32],
-    [0.31250000f32, 0.68750000f32],
-    [0.31250000f32, 0.68750000f32],
-    [0.35416666f32, 0.68750000f32],
-    [0.35416666f32, 0.68750000f32],
-    [0.39583334f32, 0.68750000f32],
-    [0.39583334f32, 0.68750000f32],
-    [0.43750000f32, 0.68750000f32],
-    [0.43750000f32, 0.68750000f32],
-    [0.47916666f32, 0.68750000f32],
-    [0.47916666f32, 0.68750000f32],
-    [0.52083330f32, 0.68750000f32],
-    [0.52083330f32, 0.68750000f32],
-    [0.56250000f32, 0.68750000f32],
-    [0.56250000f32, 0.68750000f32],
-    [0.60416670f32, 0.68750000f32],
-    [0.60416670f32, 0.68750000f32],
-    [0.64583330f32, 0.68750000f32],
-    [0.64583330f32, 0.68750000f32],
-    [0.68750000f32, 0.68750000f32],
-    [0.68750000f32, 0.68750000f32],
-    [0.72916670f32, 0.68750000f32],
-    [0.72916670f32, 0.68750000f32],
-    [0.77083330f32, 0.68750000f32],
-    [0.77083330f32, 0.68750000f32],
-    [0.81250000f32, 0.68750000f32],
-    [0.81250000f32, 0.68750000f32],
-    [0.85416670f32, 0.68750000f32],
-    [0.85416670f32, 0.68750000f32],
-    [0.89583330f32, 0.68750000f32],
-    [0.89583330f32, 0.68750000f32],
-    [0.93750000f32, 0.68750000f32],
-    [0.93750000f32, 0.68750000f32],
-    [0.97916670f32, 0.68750000f32],
-    [0.97916670f32, 0.68750000f32],
-    [0.02083333f32, 0.72916670f32],
-    [0.02083333f32, 0.72916670f32],
-    [0.06250000f32, 0.72916670f32],
-    [0.06250000f32, 0.72916670f32],
-    [0.10416666f32, 0.72916670f32],
-    [0.10416666f32, 0.72916670f32],
-    [0.14583333f32, 0.72916670f32],
-    [0.14583333f32, 0.72916670f32],
-    [0.18750000f32, 0.72916670f32],
-    [0.18750000f32, 0.72916670f32],
-    [0.22916667f32, 0.72916670f32],
-    [0.22916667f32, 0.72916670f32],
-    [0.27083334f32, 0.72916670f32],
-    [0.27083334f32, 0.72916670f32],
-    [0.31250000f32, 0.72916670f32],
-    [0.31250000f32, 0.72916670f32],
-    [0.35416666f32, 0.72916670f32],
-    [0.35416666f32, 0.72916670f32],
-    [0.39583334f32, 0.72916670f32],
-    [0.39583334f32, 0.72916670f32],
-    [0.43750000f32, 0.72916670f32],
-    [0.43750000f32, 0.72916670f32],
-    [0.47916666f32, 0.72916670f32],
-    [0.47916666f32, 0.72916670f32],
-    [0.52083330f32, 0.72916670f32],
-    [0.52083330f32, 0.72916670f32],
-    [0.56250000f32, 0.72916670f32],
-    [0.56250000f32, 0.72916670f32],
-    [0.60416670f32, 0.72916670f32],
-    [0.60416670f32, 0.72916670f32],
-    [0.64583330f32, 0.72916670f32],
-    [0.64583330f32, 0.72916670f32],
-    [0.68750000f32, 0.72916670f32],
-    [0.68750000f32, 0.72916670f32],
-    [0.72916670f32, 0.72916670f32],
-    [0.72916670f32, 0.72916670f32],
-    [0.77083330f32, 0.72916670f32],
-    [0.77083330f32, 0.72916670f32],
-    [0.81250000f32, 0.72916670f32],
-    [0.81250000f32, 0.72916670f32],
-    [0.85416670f32, 0.72916670f32],
-    [0.85416670f32, 0.72916670f32],
-    [0.89583330f32, 0.72916670f32],
-    [0.89583330f32, 0.72916670f32],
-    [0.93750000f32, 0.72916670f32],
-    [0.93750000f32, 0.72916670f32],
-    [0.97916670f32, 0.72916670f32],
-    [0.97916670f32, 0.72916670f32],
-    [0.02083333f32, 0.77083330f32],
-    [0.02083333f32, 0.77083330f32],
-    [0.06250000f32, 0.77083330f32],
-    [0.06250000f32, 0.77083330f32],
-    [0.10416666f32, 0.77083330f32],
-    [0.10416666f32, 0.77083330f32],
-    [0.14583333f32, 0.77083330f32],
-    [0.14583333f32, 0.77083330f32],
-    [0.18750000f32, 0.77083330f32],
-    [0.18750000f32, 0.77083330f32],
-    [0.22916667f32, 0.77083330f32],
-    [0.22916667f32, 0.77083330f32],
-    [0.27083334f32, 0.77083330f32],
-    [0.27083334f32, 0.77083330f32],
-    [0.31250000f32, 0.77083330f32],
-    [0.31250000f32, 0.77083330f32],
-    [0.35416666f32, 0.77083330f32],
-    [0.35416666f32, 0.77083330f32],
-    [0.39583334f32, 0.77083330f32],
-    [0.39583334f32, 0.77083330f32],
-    [0.43750000f32, 0.77083330f32],
-    [0.43750000f32, 0.77083330f32],
-    [0.47916666f32, 0.77083330f32],
-    [0.47916666f32, 0.77083330f32],
-    [0.52083330f32, 0.77083330f32],
-    [0.52083330f32, 0.77083330f32],
-    [0.56250000f32, 0.77083330f32],
-    [0.56250000f32, 0.77083330f32],
-    [0.60416670f32, 0.77083330f32],
-    [0.60416670f32, 0.77083330f32],
-    [0.64583330f32, 0.77083330f32],
-    [0.64583330f32, 0.77083330f32],
-    [0.68750000f32, 0.77083330f32],
-    [0.68750000f32, 0.77083330f32],
-    [0.72916670f32, 0.77083330f32],
-    [0.72916670f32, 0.77083330f32],
-    [0.77083330f32, 0.77083330f32],
-    [0.77083330f32, 0.77083330f32],
-    [0.81250000f32, 0.77083330f32],
-    [0.81250000f32, 0.77083330f32],
-    [0.85416670f32, 0.77083330f32],
-    [0.85416670f32, 0.77083330f32],
-    [0.89583330f32, 0.77083330f32],
-    [0.89583330f32, 0.77083330f32],
-    [0.93750000f32, 0.77083330f32],
-    [0.93750000f32, 0.77083330f32],
-    [0.97916670f32, 0.77083330f32],
-    [0.97916670f32, 0.77083330f32],
-    [0.02083333f32, 0.81250000f32],
-    [0.02083333f32, 0.81250000f32],
-    [0.06250000f32, 0.81250000f32],
-    [0.06250000f32, 0.81250000f32],
-    [0.10416666f32, 0.81250000f32],
-    [0.10416666f32, 0.81250000f32],
-    [0.14583333f32, 0.81250000f32],
-    [0.14583333f32, 0.81250000f32],
-    [0.18750000f32, 0.81250000f32],
-    [0.18750000f32, 0.81250000f32],
-    [0.22916667f32, 0.81250000f32],
-    [0.22916667f32, 0.81250000f32],
-    [0.27083334f32, 0.81250000f32],
-    [0.27083334f32, 0.81250000f32],
-    [0.31250000f32, 0.81250000f32],
-    [0.31250000f32, 0.81250000f32],
-    [0.35416666f32, 0.81250000f32],
-    [0.35416666f32, 0.81250000f32],
-    [0.39583334f32, 0.81250000f32],
-    [0.39583334f32, 0.81250000f32],
-    [0.43750000f32, 0.81250000f32],
-    [0.43750000f32, 0.81250000f32],
-    [0.47916666f32, 0.81250000f32],
-    [0.47916666f32, 0.81250000f32],
-    [0.52083330f32, 0.81250000f32],
-    [0.52083330f32, 0.81250000f32],
-    [0.56250000f32, 0.81250000f32],
-    [0.56250000f32, 0.81250000f32],
-    [0.60416670f32, 0.81250000f32],
-    [0.60416670f32, 0.81250000f32],
-    [0.64583330f32, 0.81250000f32],
-    [0.64583330f32, 0.81250000f32],
-    [0.68750000f32, 0.81250000f32],
-    [0.68750000f32, 0.81250000f32],
-    [0.72916670f32, 0.81250000f32],
-    [0.72916670f32, 0.81250000f32],
-    [0.77083330f32, 0.81250000f32],
-    [0.77083330f32, 0.81250000f32],
-    [0.81250000f32, 0.81250000f32],
-    [0.81250000f32, 0.81250000f32],
-    [0.85416670f32, 0.81250000f32],
-    [0.85416670f32, 0.81250000f32],
-    [0.89583330f32, 0.81250000f32],
-    [0.89583330f32, 0.81250000f32],
-    [0.93750000f32, 0.81250000f32],
-    [0.93750000f32, 0.81250000f32],
-    [0.97916670f32, 0.81250000f32],
-    [0.97916670f32, 0.81250000f32],
-    [0.02083333f32, 0.85416670f32],
-    [0.02083333f32, 0.85416670f32],
-    [0.06250000f32, 0.85416670f32],
-    [0.06250000f32, 0.85416670f32],
-    [0.10416666f32, 0.85416670f32],
-    [0.10416666f32, 0.85416670f32],
-    [0.14583333f32, 0.85416670f32],
-    [0.14583333f32, 0.85416670f32],
-    [0.18750000f32, 0.85416670f32],
-    [0.18750000f32, 0.85416670f32],
-    [0.22916667f32, 0.85416670f32],
-    [0.22916667f32, 0.85416670f32],
-    [0.27083334f32, 0.85416670f32],
-    [0.27083334f32, 0.85416670f32],
-    [0.31250000f32, 0.85416670f32],
-    [0.31250000f32, 0.85416670f32],
-    [0.35416666f32, 0.85416670f32],
-    [0.35416666f32, 0.85416670f32],
-    [0.39583334f32, 0.85416670f32],
-    [0.39583334f32, 0.85416670f32],
-    [0.43750000f32, 0.85416670f32],
-    [0.43750000f32, 0.85416670f32],
-    [0.47916666f32, 0.85416670f32],
-    [0.47916666f32, 0.85416670f32],
-    [0.52083330f32, 0.85416670f32],
-    [0.52083330f32, 0.85416670f32],
-    [0.56250000f32, 0.85416670f32],
-    [0.56250000f32, 0.85416670f32],
-    [0.60416670f32, 0.85416670f32],
-    [0.60416670f32, 0.85416670f32],
-    [0.64583330f32, 0.85416670f32],
-    [0.64583330f32, 0.85416670f32],
-    [0.68750000f32, 0.85416670f32],
-    [0.68750000f32, 0.85416670f32],
-    [0.72916670f32, 0.85416670f32],
-    [0.72916670f32, 0.85416670f32],
-    [0.77083330f32, 0.85416670f32],
-    [0.77083330f32, 0.85416670f32],
-    [0.81250000f32, 0.85416670f32],
-    [0.81250000f32, 0.85416670f32],
-    [0.85416670f32, 0.85416670f32],
-    [0.85416670f32, 0.85416670f32],
-    [0.89583330f32, 0.85416670f32],
-    [0.89583330f32, 0.85416670f32],
-    [0.93750000f32, 0.85416670f32],
-    [0.93750000f32, 0.85416670f32],
-    [0.97916670f32, 0.85416670f32],
-    [0.97916670f32, 0.85416670f32],
-    [0.02083333f32, 0.89583330f32],
-    [0.02083333f32, 0.89583330f32],
-    [0.06250000f32, 0.89583330f32],
-    [0.06250000f32, 0.89583330f32],
-    [0.10416666f32, 0.89583330f32],
-    [0.10416666f32, 0.89583330f32],
-    [0.14583333f32, 0.89583330f32],
-    [0.14583333f32, 0.89583330f32],
-    [0.18750000f32, 0.89583330f32],
-    [0.18750000f32, 0.89583330f32],
-    [0.22916667f32, 0.89583330f32],
-    [0.22916667f32, 0.89583330f32],
-    [0.27083334f32, 0.89583330f32],
-    [0.27083334f32, 0.89583330f32],
-    [0.31250000f32, 0.89583330f32],
-    [0.31250000f32, 0.89583330f32],
-    [0.35416666f32, 0.89583330f32],
-    [0.35416666f32, 0.89583330f32],
-    [0.39583334f32, 0.89583330f32],
-    [0.39583334f32, 0.89583330f32],
-    [0.43750000f32, 0.89583330f32],
-    [0.43750000f32, 0.89583330f32],
-    [0.47916666f32, 0.89583330f32],
-    [0.47916666f32, 0.89583330f32],
-    [0.52083330f32, 0.89583330f32],
-    [0.52083330f32, 0.89583330f32],
-    [0.56250000f32, 0.89583330f32],
-    [0.56250000f32, 0.89583330f32],
-    [0.60416670f32, 0.89583330f32],
-    [0.60416670f32, 0.89583330f32],
-    [0.64583330f32, 0.89583330f32],
-    [0.64583330f32, 0.89583330f32],
-    [0.68750000f32, 0.89583330f32],
-    [0.68750000f32, 0.89583330f32],
-    [0.72916670f32, 0.89583330f32],
-    [0.72916670f32, 0.89583330f32],
-    [0.77083330f32, 0.89583330f32],
-    [0.77083330f32, 0.89583330f32],
-    [0.81250000f32, 0.89583330f32],
-    [0.81250000f32, 0.89583330f32],
-    [0.85416670f32, 0.89583330f32],
-    [0.85416670f32, 0.89583330f32],
-    [0.89583330f32, 0.89583330f32],
-    [0.89583330f32, 0.89583330f32],
-    [0.93750000f32, 0.89583330f32],
-    [0.93750000f32, 0.89583330f32],
-    [0.97916670f32, 0.89583330f32],
-    [0.97916670f32, 0.89583330f32],
-    [0.02083333f32, 0.93750000f32],
-    [0.02083333f32, 0.93750000f32],
-    [0.06250000f32, 0.93750000f32],
-    [0.06250000f32, 0.93750000f32],
-    [0.10416666f32, 0.93750000f32],
-    [0.10416666f32, 0.93750000f32],
-    [0.14583333f32, 0.93750000f32],
-    [0.14583333f32, 0.93750000f32],
-    [0.18750000f32, 0.93750000f32],
-    [0.18750000f32, 0.93750000f32],
-    [0.22916667f32, 0.93750000f32],
-    [0.22916667f32, 0.93750000f32],
-    [0.27083334f32, 0.93750000f32],
-    [0.27083334f32, 0.93750000f32],
-    [0.31250000f32, 0.93750000f32],
-    [0.31250000f32, 0.93750000f32],
-    [0.35416666f32, 0.93750000f32],
-    [0.35416666f32, 0.93750000f32],
-    [0.39583334f32, 0.93750000f32],
-    [0.39583334f32, 0.93750000f32],
-    [0.43750000f32, 0.93750000f32],
-    [0.43750000f32, 0.93750000f32],
-    [0.47916666f32, 0.93750000f32],
-    [0.47916666f32, 0.93750000f32],
-    [0.52083330f32, 0.93750000f32],
-    [0.52083330f32, 0.93750000f32],
-    [0.56250000f32, 0.93750000f32],
-    [0.56250000f32, 0.93750000f32],
-    [0.60416670f32, 0.93750000f32],
-    [0.60416670f32, 0.93750000f32],
-    [0.64583330f32, 0.93750000f32],
-    [0.64583330f32, 0.93750000f32],
-    [0.68750000f32, 0.93750000f32],
-    [0.68750000f32, 0.93750000f32],
-    [0.72916670f32, 0.93750000f32],
-    [0.72916670f32, 0.93750000f32],
-    [0.77083330f32, 0.93750000f32],
-    [0.77083330f32, 0.93750000f32],
-    [0.81250000f32, 0.93750000f32],
-    [0.81250000f32, 0.93750000f32],
-    [0.85416670f32, 0.93750000f32],
-    [0.85416670f32, 0.93750000f32],
-    [0.89583330f32, 0.93750000f32],
-    [0.89583330f32, 0.93750000f32],
-    [0.93750000f32, 0.93750000f32],
-    [0.93750000f32, 0.93750000f32],
-    [0.97916670f32, 0.93750000f32],
-    [0.97916670f32, 0.93750000f32],
-    [0.02083333f32, 0.97916670f32],
-    [0.02083333f32, 0.97916670f32],
-    [0.06250000f32, 0.97916670f32],
-    [0.06250000f32, 0.97916670f32],
-    [0.10416666f32, 0.97916670f32],
-    [0.10416666f32, 0.97916670f32],
-    [0.14583333f32, 0.97916670f32],
-    [0.14583333f32, 0.97916670f32],
-    [0.18750000f32, 0.97916670f32],
-    [0.18750000f32, 0.97916670f32],
-    [0.22916667f32, 0.97916670f32],
-    [0.22916667f32, 0.97916670f32],
-    [0.27083334f32, 0.97916670f32],
-    [0.27083334f32, 0.97916670f32],
-    [0.31250000f32, 0.97916670f32],
-    [0.31250000f32, 0.97916670f32],
-    [0.35416666f32, 0.97916670f32],
-    [0.35416666f32, 0.97916670f32],
-    [0.39583334f32, 0.97916670f32],
-    [0.39583334f32, 0.97916670f32],
-    [0.43750000f32, 0.97916670f32],
-    [0.43750000f32, 0.97916670f32],
-    [0.47916666f32, 0.97916670f32],
-    [0.47916666f32, 0.97916670f32],
-    [0.52083330f32, 0.97916670f32],
-    [0.52083330f32, 0.97916670f32],
-    [0.56250000f32, 0.97916670f32],
-    [0.56250000f32, 0.97916670f32],
-    [0.60416670f32, 0.97916670f32],
-    [0.60416670f32, 0.97916670f32],
-    [0.64583330f32, 0.97916670f32],
-    [0.64583330f32, 0.97916670f32],
-    [0.68750000f32, 0.97916670f32],
-    [0.68750000f32, 0.97916670f32],
-    [0.72916670f32, 0.97916670f32],
-    [0.72916670f32, 0.97916670f32],
-    [0.77083330f32, 0.97916670f32],
-    [0.77083330f32, 0.97916670f32],
-    [0.81250000f32, 0.97916670f32],
-    [0.81250000f32, 0.97916670f32],
-    [0.85416670f32, 0.97916670f32],
-    [0.85416670f32, 0.97916670f32],
-    [0.89583330f32, 0.97916670f32],
-    [0.89583330f32, 0.97916670f32],
-    [0.93750000f32, 0.97916670f32],
-    [0.93750000f32, 0.97916670f32],
-    [0.97916670f32, 0.97916670f32],
-    [0.97916670f32, 0.97916670f32],
-    [0.04166667f32, 0.04166667f32],
-    [0.04166667f32, 0.04166667f32],
-    [0.04166667f32, 0.04166667f32],
-    [0.04166667f32, 0.04166667f32],
-    [0.04166667f32, 0.04166667f32],
-    [0.04166667f32, 0.04166667f32],
-    [0.12500000f32, 0.04166667f32],
-    [0.12500000f32, 0.04166667f32],
-    [0.12500000f32, 0.04166667f32],
-    [0.12500000f32, 0.04166667f32],
-    [0.12500000f32, 0.04166667f32],
-    [0.12500000f32, 0.04166667f32],
-    [0.20833333f32, 0.04166667f32],
-    [0.20833333f32, 0.04166667f32],
-    [0.20833333f32, 0.04166667f32],
-    [0.20833333f32, 0.04166667f32],
-    [0.20833333f32, 0.04166667f32],
-    [0.20833333f32, 0.04166667f32],
-    [0.29166666f32, 0.04166667f32],
-    [0.29166666f32, 0.04166667f32],
-    [0.29166666f32, 0.04166667f32],
-    [0.29166666f32, 0.04166667f32],
-    [0.29166666f32, 0.04166667f32],
-    [0.29166666f32, 0.04166667f32],
-    [0.37500000f32, 0.04166667f32],
-    [0.37500000f32, 0.04166667f32],
-    [0.37500000f32, 0.04166667f32],
-    [0.37500000f32, 0.04166667f32],
-    [0.37500000f32, 0.04166667f32],
-    [0.37500000f32, 0.04166667f32],
-    [0.45833334f32, 0.04166667f32],
-    [0.45833334f32, 0.04166667f32],
-    [0.45833334f32, 0.04166667f32],
-    [0.45833334f32, 0.04166667f32],
-    [0.45833334f32, 0.04166667f32],
-    [0.45833334f32, 0.04166667f32],
-    [0.54166670f32, 0.04166667f32],
-    [0.54166670f32, 0.04166667f32],
-    [0.54166670f32, 0.04166667f32],
-    [0.54166670f32, 0.04166667f32],
-    [0.54166670f32, 0.04166667f32],
-    [0.54166670f32, 0.04166667f32],
-    [0.62500000f32, 0.04166667f32],
-    [0.62500000f32, 0.04166667f32],
-    [0.62500000f32, 0.04166667f32],
-    [0.62500000f32, 0.04166667f32],
-    [0.62500000f32, 0.04166667f32],
-    [0.62500000f32, 0.04166667f32],
-    [0.70833330f32, 0.04166667f32],
-    [0.70833330f32, 0.04166667f32],
-    [0.70833330f32, 0.04166667f32],
-    [0.70833330f32, 0.04166667f32],
-    [0.70833330f32, 0.04166667f32],
-    [0.70833330f32, 0.04166667f32],
-    [0.79166670f32, 0.04166667f32],
-    [0.79166670f32, 0.04166667f32],
-    [0.79166670f32, 0.04166667f32],
-    [0.79166670f32, 0.04166667f32],
-    [0.79166670f32, 0.04166667f32],
-    [0.79166670f32, 0.04166667f32],
-    [0.87500000f32, 0.04166667f32],
-    [0.87500000f32, 0.04166667f32],
-    [0.87500000f32, 0.04166667f32],
-    [0.87500000f32, 0.04166667f32],
-    [0.87500000f32, 0.04166667f32],
-    [0.87500000f32, 0.04166667f32],
-    [0.95833330f32, 0.04166667f32],
-    [0.95833330f32, 0.04166667f32],
-    [0.95833330f32, 0.04166667f32],
-    [0.95833330f32, 0.04166667f32],
-    [0.95833330f32, 0.04166667f32],
-    [0.95833330f32, 0.04166667f32],
-    [0.04166667f32, 0.12500000f32],
-    [0.04166667f32, 0.12500000f32],
-    [0.04166667f32, 0.12500000f32],
-    [0.04166667f32, 0.12500000f32],
-    [0.04166667f32, 0.12500000f32],
-    [0.04166667f32, 0.12500000f32],
-    [0.12500000f32, 0.12500000f32],
-    [0.12500000f32, 0.12500000f32],
-    [0.12500000f32, 0.12500000f32],
-    [0.12500000f32, 0.12500000f32],
-    [0.12500000f32, 0.12500000f32],
-    [0.12500000f32, 0.12500000f32],
-    [0.20833333f32, 0.12500000f32],
-    [0.20833333f32, 0.12500000f32],
-    [0.20833333f32, 0.12500000f32],
-    [0.20833333f32, 0.12500000f32],
-    [0.20833333f32, 0.12500000f32],
-    [0.20833333f32, 0.12500000f32],
-    [0.29166666f32, 0.12500000f32],
-    [0.29166666f32, 0.12500000f32],
-    [0.29166666f32, 0.12500000f32],
-    [0.29166666f32, 0.12500000f32],
-    [0.29166666f32, 0.12500000f32],
-    [0.29166666f32, 0.12500000f32],
-    [0.37500000f32, 0.12500000f32],
-    [0.37500000f32, 0.12500000f32],
-    [0.37500000f32, 0.12500000f32],
-    [0.37500000f32, 0.12500000f32],
-    [0.37500000f32, 0.12500000f32],
-    [0.37500000f32, 0.12500000f32],
-    [0.45833334f32, 0.12500000f32],
-    [0.45833334f32, 0.12500000f32],
-    [0.45833334f32, 0.12500000f32],
-    [0.45833334f32, 0.12500000f32],
-    [0.45833334f32, 0.12500000f32],
-    [0.45833334f32, 0.12500000f32],
-    [0.54166670f32, 0.12500000f32],
-    [0.54166670f32, 0.12500000f32],
-    [0.54166670f32, 0.12500000f32],
-    [0.54166670f32, 0.12500000f32],
-    [0.54166670f32, 0.12500000f32],
-    [0.54166670f32, 0.12500000f32],
-    [0.62500000f32, 0.12500000f32],
-    [0.62500000f32, 0.12500000f32],
-    [0.62500000f32, 0.12500000f32],
-    [0.62500000f32, 0.12500000f32],
-    [0.62500000f32, 0.12500000f32],
-    [0.62500000f32, 0.12500000f32],
-    [0.70833330f32, 0.12500000f32],
-    [0.70833330f32, 0.12500000f32],
-    [0.70833330f32, 0.12500000f32],
-    [0.70833330f32, 0.12500000f32],
-    [0.70833330f32, 0.12500000f32],
-    [0.70833330f32, 0.12500000f32],
-    [0.79166670f32, 0.12500000f32],
-    [0.79166670f32, 0.12500000f32],
-    [0.79166670f32, 0.12500000f32],
-    [0.79166670f32, 0.12500000f32],
-    [0.79166670f32, 0.12500000f32],
-    [0.79166670f32, 0.12500000f32],
-    [0.87500000f32, 0.12500000f32],
-    [0.87500000f32, 0.12500000f32],
-    [0.87500000f32, 0.12500000f32],
-    [0.87500000f32, 0.12500000f32],
-    [0.87500000f32, 0.12500000f32],
-    [0.87500000f32, 0.12500000f32],
-    [0.95833330f32, 0.12500000f32],
-    [0.95833330f32, 0.12500000f32],
-    [0.95833330f32, 0.12500000f32],
-    [0.95833330f32, 0.12500000f32],
-    [0.95833330f32, 0.12500000f32],
-    [0.95833330f32, 0.12500000f32],
-    [0.04166667f32, 0.20833333f32],
-    [0.04166667f32, 0.20833333f32],
-    [0.04166667f32, 0.20833333f32],
-    [0.04166667f32, 0.20833333f32],
-    [0.04166667f32, 0.20833333f32],
-    [0.04166667f32, 0.20833333f32],
-    [0.12500000f32, 0.20833333f32],
-    [0.12500000f32, 0.20833333f32],
-    [0.12500000f32, 0.20833333f32],
-    [0.12500000f32, 0.20833333f32],
-    [0.12500000f32, 0.20833333f32],
-    [0.12500000f32, 0.20833333f32],
-    [0.20833333f32, 0.20833333f32],
-    [0.20833333f32, 0.20833333f32],
-    [0.20833333f32, 0.20833333f32],
-    [0.20833333f32, 0.20833333f32],
-    [0.20833333f32, 0.20833333f32],
-    [0.20833333f32, 0.20833333f32],
-    [0.29166666f32, 0.20833333f32],
-    [0.29166666f32, 0.20833333f32],
-    [0.29166666f32, 0.20833333f32],
-    [0.29166666f32, 0.20833333f32],
-    [0.29166666f32, 0.20833333f32],
-    [0.29166666f32, 0.20833333f32],
-    [0.37500000f32, 0.20833333f32],
-    [0.37500000f32, 0.20833333f32],
-    [0.37500000f32, 0.20833333f32],
-    [0.37500000f32, 0.20833333f32],
-    [0.37500000f32, 0.20833333f32],
-    [0.37500000f32, 0.20833333f32],
-    [0.45833334f32, 0.20833333f32],
-    [0.45833334f32, 0.20833333f32],
-    [0.45833334f32, 0.20833333f32],
-    [0.45833334f32, 0.20833333f32],
-    [0.45833334f32, 0.20833333f32],
-    [0.45833334f32, 0.20833333f32],
-    [0.54166670f32, 0.20833333f32],
-    [0.54166670f32, 0.20833333f32],
-    [0.54166670f32, 0.20833333f32],
-    [0.54166670f32, 0.20833333f32],
-    [0.54166670f32, 0.20833333f32],
-    [0.54166670f32, 0.20833333f32],
-    [0.62500000f32, 0.20833333f32],
-    [0.62500000f32, 0.20833333f32],
-    [0.62500000f32, 0.20833333f32],
-    [0.62500000f32, 0.20833333f32],
-    [0.62500000f32, 0.20833333f32],
-    [0.62500000f32, 0.20833333f32],
-    [0.70833330f32, 0.20833333f32],
-    [0.70833330f32, 0.20833333f32],
-    [0.70833330f32, 0.20833333f32],
-    [0.70833330f32, 0.20833333f32],
-    [0.70833330f32, 0.20833333f32],
-    [0.70833330f32, 0.20833333f32],
-    [0.79166670f32, 0.20833333f32],
-    [0.79166670f32, 0.20833333f32],
-    [0.79166670f32, 0.20833333f32],
-    [0.79166670f32, 0.20833333f32],
-    [0.79166670f32, 0.20833333f32],
-    [0.79166670f32, 0.20833333f32],
-    [0.87500000f32, 0.20833333f32],
-    [0.87500000f32, 0.20833333f32],
-    [0.87500000f32, 0.20833333f32],
-    [0.87500000f32, 0.20833333f32],
-    [0.87500000f32, 0.20833333f32],
-    [0.87500000f32, 0.20833333f32],
-    [0.95833330f32, 0.20833333f32],
-    [0.95833330f32, 0.20833333f32],
-    [0.95833330f32, 0.20833333f32],
-    [0.95833330f32, 0.20833333f32],
-    [0.95833330f32, 0.20833333f32],
-    [0.95833330f32, 0.20833333f32],
-    [0.04166667f32, 0.29166666f32],
-    [0.04166667f32, 0.29166666f32],
-    [0.04166667f32, 0.29166666f32],
-    [0.04166667f32, 0.29166666f32],
-    [0.04166667f32, 0.29166666f32],
-    [0.04166667f32, 0.29166666f32],
-    [0.12500000f32, 0.29166666f32],
-    [0.12500000f32, 0.29166666f32],
-    [0.12500000f32, 0.29166666f32],
-    [0.12500000f32, 0.29166666f32],
-    [0.12500000f32, 0.29166666f32],
-    [0.12500000f32, 0.29166666f32],
-    [0.20833333f32, 0.29166666f32],
-    [0.20833333f32, 0.29166666f32],
-    [0.20833333f32, 0.29166666f32],
-    [0.20833333f32, 0.29166666f32],
-    [0.20833333f32, 0.29166666f32],
-    [0.20833333f32, 0.29166666f32],
-    [0.29166666f32, 0.29166666f32],
-    [0.29166666f32, 0.29166666f32],
-    [0.29166666f32, 0.29166666f32],
-    [0.29166666f32, 0.29166666f32],
-    [0.29166666f32, 0.29166666f32],
-    [0.29166666f32, 0.29166666f32],
-    [0.37500000f32, 0.29166666f32],
-    [0.37500000f32, 0.29166666f32],
-    [0.37500000f32, 0.29166666f32],
-    [0.37500000f32, 0.29166666f32],
-    [0.37500000f32, 0.29166666f32],
-    [0.37500000f32, 0.29166666f32],
-    [0.45833334f32, 0.29166666f32],
-    [0.45833334f32, 0.29166666f32],
-    [0.45833334f32, 0.29166666f32],
-    [0.45833334f32, 0.29166666f32],
-    [0.45833334f32, 0.29166666f32],
-    [0.45833334f32, 0.29166666f32],
-    [0.54166670f32, 0.29166666f32],
-    [0.54166670f32, 0.29166666f32],
-    [0.54166670f32, 0.29166666f32],
-    [0.54166670f32, 0.29166666f32],
-    [0.54166670f32, 0.29166666f32],
-    [0.54166670f32, 0.29166666f32],
-    [0.62500000f32, 0.29166666f32],
-    [0.62500000f32, 0.29166666f32],
-    [0.62500000f32, 0.29166666f32],
-    [0.62500000f32, 0.29166666f32],
-    [0.62500000f32, 0.29166666f32],
-    [0.62500000f32, 0.29166666f32],
-    [0.70833330f32, 0.29166666f32],
-    [0.70833330f32, 0.29166666f32],
-    [0.70833330f32, 0.29166666f32],
-    [0.70833330f32, 0.29166666f32],
-    [0.70833330f32, 0.29166666f32],
-    [0.70833330f32, 0.29166666f32],
-    [0.79166670f32, 0.29166666f32],
-    [0.79166670f32, 0.29166666f32],
-    [0.79166670f32, 0.29166666f32],
-    [0.79166670f32, 0.29166666f32],
-    [0.79166670f32, 0.29166666f32],
-    [0.79166670f32, 0.29166666f32],
-    [0.87500000f32, 0.29166666f32],
-    [0.87500000f32, 0.29166666f32],
-    [0.87500000f32, 0.29166666f32],
-    [0.87500000f32, 0.29166666f32],
-    [0.87500000f32, 0.29166666f32],
-    [0.87500000f32, 0.29166666f32],
-    [0.95833330f32, 0.29166666f32],
-    [0.95833330f32, 0.29166666f32],
-    [0.95833330f32, 0.29166666f32],
-    [0.95833330f32, 0.29166666f32],
-    [0.95833330f32, 0.29166666f32],
-    [0.95833330f32, 0.29166666f32],
-    [0.04166667f32, 0.37500000f32],
-    [0.04166667f32, 0.37500000f32],
-    [0.04166667f32, 0.37500000f32],
-    [0.04166667f32, 0.37500000f32],
-    [0.04166667f32, 0.37500000f32],
-    [0.04166667f32, 0.37500000f32],
-    [0.12500000f32, 0.37500000f32],
-    [0.12500000f32, 0.37500000f32],
-    [0.12500000f32, 0.37500000f32],
-    [0.12500000f32, 0.37500000f32],
-    [0.12500000f32, 0.37500000f32],
-    [0.12500000f32, 0.37500000f32],
-    [0.20833333f32, 0.37500000f32],
-    [0.20833333f32, 0.37500000f32],
-    [0.20833333f32, 0.37500000f32],
-    [0.20833333f32, 0.37500000f32],
-    [0.20833333f32, 0.37500000f32],
-    [0.20833333f32, 0.37500000f32],
-    [0.29166666f32, 0.37500000f32],
-    [0.29166666f32, 0.37500000f32],
-    [0.29166666f32, 0.37500000f32],
-    [0.29166666f32, 0.37500000f32],
-    [0.29166666f32, 0.37500000f32],
-    [0.29166666f32, 0.37500000f32],
-    [0.37500000f32, 0.37500000f32],
-    [0.37500000f32, 0.37500000f32],
-    [0.37500000f32, 0.37500000f32],
-    [0.37500000f32, 0.37500000f32],
-    [0.37500000f32, 0.37500000f32],
-    [0.37500000f32, 0.37500000f32],
-    [0.45833334f32, 0.37500000f32],
-    [0.45833334f32, 0.37500000f32],
-    [0.45833334f32, 0.37500000f32],
-    [0.45833334f32, 0.37500000f32],
-    [0.45833334f32, 0.37500000f32],
-    [0.45833334f32, 0.37500000f32],
-    [0.54166670f32, 0.37500000f32],
-    [0.54166670f32, 0.37500000f32],
-    [0.54166670f32, 0.37500000f32],
-    [0.54166670f32, 0.37500000f32],
-    [0.54166670f32, 0.37500000f32],
-    [0.54166670f32, 0.37500000f32],
-    [0.62500000f32, 0.37500000f32],
-    [0.62500000f32, 0.37500000f32],
-    [0.62500000f32, 0.37500000f32],
-    [0.62500000f32, 0.37500000f32],
-    [0.62500000f32, 0.37500000f32],
-    [0.62500000f32, 0.37500000f32],
-    [0.70833330f32, 0.37500000f32],
-    [0.70833330f32, 0.37500000f32],
-    [0.70833330f32, 0.37500000f32],
-    [0.70833330f32, 0.37500000f32],
-    [0.70833330f32, 0.37500000f32],
-    [0.70833330f32, 0.37500000f32],
-    [0.79166670f32, 0.37500000f32],
-    [0.79166670f32, 0.37500000f32],
-    [0.79166670f32, 0.37500000f32],
-    [0.79166670f32, 0.37500000f32],
-    [0.79166670f32, 0.37500000f32],
-    [0.79166670f32, 0.37500000f32],
-    [0.87500000f32, 0.37500000f32],
-    [0.87500000f32, 0.37500000f32],
-    [0.87500000f32, 0.37500000f32],
-    [0.87500000f32, 0.37500000f32],
-    [0.87500000f32, 0.37500000f32],
-    [0.87500000f32, 0.37500000f32],
-    [0.95833330f32, 0.37500000f32],
-    [0.95833330f32, 0.37500000f32],
-    [0.95833330f32, 0.37500000f32],
-    [0.95833330f32, 0.37500000f32],
-    [0.95833330f32, 0.37500000f32],
-    [0.95833330f32, 0.37500000f32],
-    [0.04166667f32, 0.45833334f32],
-    [0.04166667f32, 0.45833334f32],
-    [0.04166667f32, 0.45833334f32],
-    [0.04166667f32, 0.45833334f32],
-    [0.04166667f32, 0.45833334f32],
-    [0.04166667f32, 0.45833334f32],
-    [0.12500000f32, 0.45833334f32],
-    [0.12500000f32, 0.45833334f32],
-    [0.12500000f32, 0.45833334f32],
-    [0.12500000f32, 0.45833334f32],
-    [0.12500000f32, 0.45833334f32],
-    [0.12500000f32, 0.45833334f32],
-    [0.20833333f32, 0.45833334f32],
-    [0.20833333f32, 0.45833334f32],
-    [0.20833333f32, 0.45833334f32],
-    [0.20833333f32, 0.45833334f32],
-    [0.20833333f32, 0.45833334f32],
-    [0.20833333f32, 0.45833334f32],
-    [0.29166666f32, 0.45833334f32],
-    [0.29166666f32, 0.45833334f32],
-    [0.29166666f32, 0.45833334f32],
-    [0.29166666f32, 0.45833334f32],
-    [0.29166666f32, 0.45833334f32],
-    [0.29166666f32, 0.45833334f32],
-    [0.37500000f32, 0.45833334f32],
-    [0.37500000f32, 0.45833334f32],
-    [0.37500000f32, 0.45833334f32],
-    [0.37500000f32, 0.45833334f32],
-    [0.37500000f32, 0.45833334f32],
-    [0.37500000f32, 0.45833334f32],
-    [0.45833334f32, 0.45833334f32],
-    [0.45833334f32, 0.45833334f32],
-    [0.45833334f32, 0.45833334f32],
-    [0.45833334f32, 0.45833334f32],
-    [0.45833334f32, 0.45833334f32],
-    [0.45833334f32, 0.45833334f32],
-    [0.54166670f32, 0.45833334f32],
-    [0.54166670f32, 0.45833334f32],
-    [0.54166670f32, 0.45833334f32],
-    [0.54166670f32, 0.45833334f32],
-    [0.54166670f32, 0.45833334f32],
-    [0.54166670f32, 0.45833334f32],
-    [0.62500000f32, 0.45833334f32],
-    [0.62500000f32, 0.45833334f32],
-    [0.62500000f32, 0.45833334f32],
-    [0.62500000f32, 0.45833334f32],
-    [0.62500000f32, 0.45833334f32],
-    [0.62500000f32, 0.45833334f32],
-    [0.70833330f32, 0.45833334f32],
-    [0.70833330f32, 0.45833334f32],
-    [0.70833330f32, 0.45833334f32],
-    [0.70833330f32, 0.45833334f32],
-    [0.70833330f32, 0.45833334f32],
-    [0.70833330f32, 0.45833334f32],
-    [0.79166670f32, 0.45833334f32],
-    [0.79166670f32, 0.45833334f32],
-    [0.79166670f32, 0.45833334f32],
-    [0.79166670f32, 0.45833334f32],
-    [0.79166670f32, 0.45833334f32],
-    [0.79166670f32, 0.45833334f32],
-    [0.87500000f32, 0.45833334f32],
-    [0.87500000f32, 0.45833334f32],
-    [0.87500000f32, 0.45833334f32],
-    [0.87500000f32, 0.45833334f32],
-    [0.87500000f32, 0.45833334f32],
-    [0.87500000f32, 0.45833334f32],
-    [0.95833330f32, 0.45833334f32],
-    [0.95833330f32, 0.45833334f32],
-    [0.95833330f32, 0.45833334f32],
-    [0.95833330f32, 0.45833334f32],
-    [0.95833330f32, 0.45833334f32],
-    [0.95833330f32, 0.45833334f32],
-    [0.04166667f32, 0.54166670f32],
-    [0.04166667f32, 0.54166670f32],
-    [0.04166667f32, 0.54166670f32],
-    [0.04166667f32, 0.54166670f32],
-    [0.04166667f32, 0.54166670f32],
-    [0.04166667f32, 0.54166670f32],
-    [0.12500000f32, 0.54166670f32],
-    [0.12500000f32, 0.54166670f32],
-    [0.12500000f32, 0.54166670f32],
-    [0.12500000f32, 0.54166670f32],
-    [0.12500000f32, 0.54166670f32],
-    [0.12500000f32, 0.54166670f32],
-    [0.20833333f32, 0.54166670f32],
-    [0.20833333f32, 0.54166670f32],
-    [0.20833333f32, 0.54166670f32],
-    [0.20833333f32, 0.54166670f32],
-    [0.20833333f32, 0.54166670f32],
-    [0.20833333f32, 0.54166670f32],
-    [0.29166666f32, 0.54166670f32],
-    [0.29166666f32, 0.54166670f32],
-    [0.29166666f32, 0.54166670f32],
-    [0.29166666f32, 0.54166670f32],
-    [0.29166666f32, 0.54166670f32],
-    [0.29166666f32, 0.54166670f32],
-    [0.37500000f32, 0.54166670f32],
-    [0.37500000f32, 0.54166670f32],
-    [0.37500000f32, 0.54166670f32],
-    [0.37500000f32, 0.54166670f32],
-    [0.37500000f32, 0.54166670f32],
-    [0.37500000f32, 0.54166670f32],
-    [0.45833334f32, 0.54166670f32],
-    [0.45833334f32, 0.54166670f32],
-    [0.45833334f32, 0.54166670f32],
-    [0.45833334f32, 0.54166670f32],
-    [0.45833334f32, 0.54166670f32],
-    [0.45833334f32, 0.54166670f32],
-    [0.54166670f32, 0.54166670f32],
-    [0.54166670f32, 0.54166670f32],
-    [0.54166670f32, 0.54166670f32],
-    [0.54166670f32, 0.54166670f32],
-    [0.54166670f32, 0.54166670f32],
-    [0.54166670f32, 0.54166670f32],
-    [0.62500000f32, 0.54166670f32],
-    [0.62500000f32, 0.54166670f32],
-    [0.62500000f32, 0.54166670f32],
-    [0.62500000f32, 0.54166670f32],
-    [0.62500000f32, 0.54166670f32],
-    [0.62500000f32, 0.54166670f32],
-    [0.70833330f32, 0.54166670f32],
-    [0.70833330f32, 0.54166670f32],
-    [0.70833330f32, 0.54166670f32],
-    [0.70833330f32, 0.54166670f32],
-    [0.70833330f32, 0.54166670f32],
-    [0.70833330f32, 0.54166670f32],
-    [0.79166670f32, 0.54166670f32],
-    [0.79166670f32, 0.54166670f32],
-    [0.79166670f32, 0.54166670f32],
-    [0.79166670f32, 0.54166670f32],
-    [0.79166670f32, 0.54166670f32],
-    [0.79166670f32, 0.54166670f32],
-    [0.87500000f32, 0.54166670f32],
-    [0.87500000f32, 0.54166670f32],
-    [0.87500000f32, 0.54166670f32],
-    [0.87500000f32, 0.54166670f32],
-    [0.87500000f32, 0.54166670f32],
-    [0.87500000f32, 0.54166670f32],
-    [0.95833330f32, 0.54166670f32],
-    [0.95833330f32, 0.54166670f32],
-    [0.95833330f32, 0.54166670f32],
-    [0.95833330f32, 0.54166670f32],
-    [0.95833330f32, 0.54166670f32],
-    [0.95833330f32, 0.54166670f32],
-    [0.04166667f32, 0.62500000f32],
-    [0.04166667f32, 0.62500000f32],
-    [0.04166667f32, 0.62500000f32],
-    [0.04166667f32, 0.62500000f32],
-    [0.04166667f32, 0.62500000f32],
-    [0.04166667f32, 0.62500000f32],
-    [0.12500000f32, 0.62500000f32],
-    [0.12500000f32, 0.62500000f32],
-    [0.12500000f32, 0.62500000f32],
-    [0.12500000f32, 0.62500000f32],
-    [0.12500000f32, 0.62500000f32],
-    [0.12500000f32, 0.62500000f32],
-    [0.20833333f32, 0.62500000f32],
-    [0.20833333f32, 0.62500000f32],
-    [0.20833333f32, 0.62500000f32],
-    [0.20833333f32, 0.62500000f32],
-    [0.20833333f32, 0.62500000f32],
-    [0.20833333f32, 0.62500000f32],
-    [0.29166666f32, 0.62500000f32],
-    [0.29166666f32, 0.62500000f32],
-    [0.29166666f32, 0.62500000f32],
-    [0.29166666f32, 0.62500000f32],
-    [0.29166666f32, 0.62500000f32],
-    [0.29166666f32, 0.62500000f32],
-    [0.37500000f32, 0.62500000f32],
-    [0.37500000f32, 0.62500000f32],
-    [0.37500000f32, 0.62500000f32],
-    [0.37500000f32, 0.62500000f32],
-    [0.37500000f32, 0.62500000f32],
-    [0.37500000f32, 0.62500000f32],
-    [0.45833334f32, 0.62500000f32],
-    [0.45833334f32, 0.62500000f32],
-    [0.45833334f32, 0.62500000f32],
-    [0.45833334f32, 0.62500000f32],
-    [0.45833334f32, 0.62500000f32],
-    [0.45833334f32, 0.62500000f32],
-    [0.54166670f32, 0.62500000f32],
-    [0.54166670f32, 0.62500000f32],
-    [0.54166670f32, 0.62500000f32],
-    [0.54166670f32, 0.62500000f32],
-    [0.54166670f32, 0.62500000f32],
-    [0.54166670f32, 0.62500000f32],
-    [0.62500000f32, 0.62500000f32],
-    [0.62500000f32, 0.62500000f32],
-    [0.62500000f32, 0.62500000f32],
-    [0.62500000f32, 0.62500000f32],
-    [0.62500000f32, 0.62500000f32],
-    [0.62500000f32, 0.62500000f32],
-    [0.70833330f32, 0.62500000f32],
-    [0.70833330f32, 0.62500000f32],
-    [0.70833330f32, 0.62500000f32],
-    [0.70833330f32, 0.62500000f32],
-    [0.70833330f32, 0.62500000f32],
-    [0.70833330f32, 0.62500000f32],
-    [0.79166670f32, 0.62500000f32],
-    [0.79166670f32, 0.62500000f32],
-    [0.79166670f32, 0.62500000f32],
-    [0.79166670f32, 0.62500000f32],
-    [0.79166670f32, 0.62500000f32],
-    [0.79166670f32, 0.62500000f32],
-    [0.87500000f32, 0.62500000f32],
-    [0.87500000f32, 0.62500000f32],
-    [0.87500000f32, 0.62500000f32],
-    [0.87500000f32, 0.62500000f32],
-    [0.87500000f32, 0.62500000f32],
-    [0.87500000f32, 0.62500000f32],
-    [0.95833330f32, 0.62500000f32],
-    [0.95833330f32, 0.62500000f32],
-    [0.95833330f32, 0.62500000f32],
-    [0.95833330f32, 0.62500000f32],
-    [0.95833330f32, 0.62500000f32],
-    [0.95833330f32, 0.62500000f32],
-    [0.04166667f32, 0.70833330f32],
-    [0.04166667f32, 0.70833330f32],
-    [0.04166667f32, 0.70833330f32],
-    [0.04166667f32, 0.70833330f32],
-    [0.04166667f32, 0.70833330f32],
-    [0.04166667f32, 0.70833330f32],
-    [0.12500000f32, 0.70833330f32],
-    [0.12500000f32, 0.70833330f32],
-    [0.12500000f32, 0.70833330f32],
-    [0.12500000f32, 0.70833330f32],
-    [0.12500000f32, 0.70833330f32],
-    [0.12500000f32, 0.70833330f32],
-    [0.20833333f32, 0.70833330f32],
-    [0.20833333f32, 0.70833330f32],
-    [0.20833333f32, 0.70833330f32],
-    [0.20833333f32, 0.70833330f32],
-    [0.20833333f32, 0.70833330f32],
-    [0.20833333f32, 0.70833330f32],
-    [0.29166666f32, 0.70833330f32],
-    [0.29166666f32, 0.70833330f32],
-    [0.29166666f32, 0.70833330f32],
-    [0.29166666f32, 0.70833330f32],
-    [0.29166666f32, 0.70833330f32],
-    [0.29166666f32, 0.70833330f32],
-    [0.37500000f32, 0.70833330f32],
-    [0.37500000f32, 0.70833330f32],
-    [0.37500000f32, 0.70833330f32],
-    [0.37500000f32, 0.70833330f32],
-    [0.37500000f32, 0.70833330f32],
-    [0.37500000f32, 0.70833330f32],
-    [0.45833334f32, 0.70833330f32],
-    [0.45833334f32, 0.70833330f32],
-    [0.45833334f32, 0.70833330f32],
-    [0.45833334f32, 0.70833330f32],
-    [0.45833334f32, 0.70833330f32],
-    [0.45833334f32, 0.70833330f32],
-    [0.54166670f32, 0.70833330f32],
-    [0.54166670f32, 0.70833330f32],
-    [0.54166670f32, 0.70833330f32],
-    [0.54166670f32, 0.70833330f32],
-    [0.54166670f32, 0.70833330f32],
-    [0.54166670f32, 0.70833330f32],
-    [0.62500000f32, 0.70833330f32],
-    [0.62500000f32, 0.70833330f32],
-    [0.62500000f32, 0.70833330f32],
-    [0.62500000f32, 0.70833330f32],
-    [0.62500000f32, 0.70833330f32],
-    [0.62500000f32, 0.70833330f32],
-    [0.70833330f32, 0.70833330f32],
-    [0.70833330f32, 0.70833330f32],
-    [0.70833330f32, 0.70833330f32],
-    [0.70833330f32, 0.70833330f32],
-    [0.70833330f32, 0.70833330f32],
-    [0.70833330f32, 0.70833330f32],
-    [0.79166670f32, 0.70833330f32],
-    [0.79166670f32, 0.70833330f32],
-    [0.79166670f32, 0.70833330f32],
-    [0.79166670f32, 0.70833330f32],
-    [0.79166670f32, 0.70833330f32],
-    [0.79166670f32, 0.70833330f32],
-    [0.87500000f32, 0.70833330f32],
-    [0.87500000f32, 0.70833330f32],
-    [0.87500000f32, 0.70833330f32],
-    [0.87500000f32, 0.70833330f32],
-    [0.87500000f32, 0.70833330f32],
-    [0.87500000f32, 0.70833330f32],
-    [0.95833330f32, 0.70833330f32],
-    [0.95833330f32, 0.70833330f32],
-    [0.95833330f32, 0.70833330f32],
-    [0.95833330f32, 0.70833330f32],
-    [0.95833330f32, 0.70833330f32],
-    [0.95833330f32, 0.70833330f32],
-    [0.04166667f32, 0.79166670f32],
-    [0.04166667f32, 0.79166670f32],
-    [0.04166667f32, 0.79166670f32],
-    [0.04166667f32, 0.79166670f32],
-    [0.04166667f32, 0.79166670f32],
-    [0.04166667f32, 0.79166670f32],
-    [0.12500000f32, 0.79166670f32],
-    [0.12500000f32, 0.79166670f32],
-    [0.12500000f32, 0.79166670f32],
-    [0.12500000f32, 0.79166670f32],
-    [0.12500000f32, 0.79166670f32],
-    [0.12500000f32, 0.79166670f32],
-    [0.20833333f32, 0.79166670f32],
-    [0.20833333f32, 0.79166670f32],
-    [0.20833333f32, 0.79166670f32],
-    [0.20833333f32, 0.79166670f32],
-    [0.20833333f32, 0.79166670f32],
-    [0.20833333f32, 0.79166670f32],
-    [0.29166666f32, 0.79166670f32],
-    [0.29166666f32, 0.79166670f32],
-    [0.29166666f32, 0.79166670f32],
-    [0.29166666f32, 0.79166670f32],
-    [0.29166666f32, 0.79166670f32],
-    [0.29166666f32, 0.79166670f32],
-    [0.37500000f32, 0.79166670f32],
-    [0.37500000f32, 0.79166670f32],
-    [0.37500000f32, 0.79166670f32],
-    [0.37500000f32, 0.79166670f32],
-    [0.37500000f32, 0.79166670f32],
-    [0.37500000f32, 0.79166670f32],
-    [0.45833334f32, 0.79166670f32],
-    [0.45833334f32, 0.79166670f32],
-    [0.45833334f32, 0.79166670f32],
-    [0.45833334f32, 0.79166670f32],
-    [0.45833334f32, 0.79166670f32],
-    [0.45833334f32, 0.79166670f32],
-    [0.54166670f32, 0.79166670f32],
-    [0.54166670f32, 0.79166670f32],
-    [0.54166670f32, 0.79166670f32],
-    [0.54166670f32, 0.79166670f32],
-    [0.54166670f32, 0.79166670f32],
-    [0.54166670f32, 0.79166670f32],
-    [0.62500000f32, 0.79166670f32],
-    [0.62500000f32, 0.79166670f32],
-    [0.62500000f32, 0.79166670f32],
-    [0.62500000f32, 0.79166670f32],
-    [0.62500000f32, 0.79166670f32],
-    [0.62500000f32, 0.79166670f32],
-    [0.70833330f32, 0.79166670f32],
-    [0.70833330f32, 0.79166670f32],
-    [0.70833330f32, 0.79166670f32],
-    [0.70833330f32, 0.79166670f32],
-    [0.70833330f32, 0.79166670f32],
-    [0.70833330f32, 0.79166670f32],
-    [0.79166670f32, 0.79166670f32],
-    [0.79166670f32, 0.79166670f32],
-    [0.79166670f32, 0.79166670f32],
-    [0.79166670f32, 0.79166670f32],
-    [0.79166670f32, 0.79166670f32],
-    [0.79166670f32, 0.79166670f32],
-    [0.87500000f32, 0.79166670f32],
-    [0.87500000f32, 0.79166670f32],
-    [0.87500000f32, 0.79166670f32],
-    [0.87500000f32, 0.79166670f32],
-    [0.87500000f32, 0.79166670f32],
-    [0.87500000f32, 0.79166670f32],
-    [0.95833330f32, 0.79166670f32],
-    [0.95833330f32, 0.79166670f32],
-    [0.95833330f32, 0.79166670f32],
-    [0.95833330f32, 0.79166670f32],
-    [0.95833330f32, 0.79166670f32],
-    [0.95833330f32, 0.79166670f32],
-    [0.04166667f32, 0.87500000f32],
-    [0.04166667f32, 0.87500000f32],
-    [0.04166667f32, 0.87500000f32],
-    [0.04166667f32, 0.87500000f32],
-    [0.04166667f32, 0.87500000f32],
-    [0.04166667f32, 0.87500000f32],
-    [0.12500000f32, 0.87500000f32],
-    [0.12500000f32, 0.87500000f32],
-    [0.12500000f32, 0.87500000f32],
-    [0.12500000f32, 0.87500000f32],
-    [0.12500000f32, 0.87500000f32],
-    [0.12500000f32, 0.87500000f32],
-    [0.20833333f32, 0.87500000f32],
-    [0.20833333f32, 0.87500000f32],
-    [0.20833333f32, 0.87500000f32],
-    [0.20833333f32, 0.87500000f32],
-    [0.20833333f32, 0.87500000f32],
-    [0.20833333f32, 0.87500000f32],
-    [0.29166666f32, 0.87500000f32],
-    [0.29166666f32, 0.87500000f32],
-    [0.29166666f32, 0.87500000f32],
-    [0.29166666f32, 0.87500000f32],
-    [0.29166666f32, 0.87500000f32],
-    [0.29166666f32, 0.87500000f32],
-    [0.37500000f32, 0.87500000f32],
-    [0.37500000f32, 0.87500000f32],
-    [0.37500000f32, 0.87500000f32],
-    [0.37500000f32, 0.87500000f32],
-    [0.37500000f32, 0.87500000f32],
-    [0.37500000f32, 0.87500000f32],
-    [0.45833334f32, 0.87500000f32],
-    [0.45833334f32, 0.87500000f32],
-    [0.45833334f32, 0.87500000f32],
-    [0.45833334f32, 0.87500000f32],
-    [0.45833334f32, 0.87500000f32],
-    [0.45833334f32, 0.87500000f32],
-    [0.54166670f32, 0.87500000f32],
-    [0.54166670f32, 0.87500000f32],
-    [0.54166670f32, 0.87500000f32],
-    [0.54166670f32, 0.87500000f32],
-    [0.54166670f32, 0.87500000f32],
-    [0.54166670f32, 0.87500000f32],
-    [0.62500000f32, 0.87500000f32],
-    [0.62500000f32, 0.87500000f32],
-    [0.62500000f32, 0.87500000f32],
-    [0.62500000f32, 0.87500000f32],
-    [0.62500000f32, 0.87500000f32],
-    [0.62500000f32, 0.87500000f32],
-    [0.70833330f32, 0.87500000f32],
-    [0.70833330f32, 0.87500000f32],
-    [0.70833330f32, 0.87500000f32],
-    [0.70833330f32, 0.87500000f32],
-    [0.70833330f32, 0.87500000f32],
-    [0.70833330f32, 0.87500000f32],
-    [0.79166670f32, 0.87500000f32],
-    [0.79166670f32, 0.87500000f32],
-    [0.79166670f32, 0.87500000f32],
-    [0.79166670f32, 0.87500000f32],
-    [0.79166670f32, 0.87500000f32],
-    [0.79166670f32, 0.87500000f32],
-    [0.87500000f32, 0.87500000f32],
-    [0.87500000f32, 0.87500000f32],
-    [0.87500000f32, 0.87500000f32],
-    [0.87500000f32, 0.87500000f32],
-    [0.87500000f32, 0.87500000f32],
-    [0.87500000f32, 0.87500000f32],
-    [0.95833330f32, 0.87500000f32],
-    [0.95833330f32, 0.87500000f32],
-    [0.95833330f32, 0.87500000f32],
-    [0.95833330f32, 0.87500000f32],
-    [0.95833330f32, 0.87500000f32],
-    [0.95833330f32, 0.87500000f32],
-    [0.04166667f32, 0.95833330f32],
-    [0.04166667f32, 0.95833330f32],
-    [0.04166667f32, 0.95833330f32],
-    [0.04166667f32, 0.95833330f32],
-    [0.04166667f32, 0.95833330f32],
-    [0.04166667f32, 0.95833330f32],
-    [0.12500000f32, 0.95833330f32],
-    [0.12500000f32, 0.95833330f32],
-    [0.12500000f32, 0.95833330f32],
-    [0.12500000f32, 0.95833330f32],
-    [0.12500000f32, 0.95833330f32],
-    [0.12500000f32, 0.95833330f32],
-    [0.20833333f32, 0.95833330f32],
-    [0.20833333f32, 0.95833330f32],
-    [0.20833333f32, 0.95833330f32],
-    [0.20833333f32, 0.95833330f32],
-    [0.20833333f32, 0.95833330f32],
-    [0.20833333f32, 0.95833330f32],
-    [0.29166666f32, 0.95833330f32],
-    [0.29166666f32, 0.95833330f32],
-    [0.29166666f32, 0.95833330f32],
-    [0.29166666f32, 0.95833330f32],
-    [0.29166666f32, 0.95833330f32],
-    [0.29166666f32, 0.95833330f32],
-    [0.37500000f32, 0.95833330f32],
-    [0.37500000f32, 0.95833330f32],
-    [0.37500000f32, 0.95833330f32],
-    [0.37500000f32, 0.95833330f32],
-    [0.37500000f32, 0.95833330f32],
-    [0.37500000f32, 0.95833330f32],
-    [0.45833334f32, 0.95833330f32],
-    [0.45833334f32, 0.95833330f32],
-    [0.45833334f32, 0.95833330f32],
-    [0.45833334f32, 0.95833330f32],
-    [0.45833334f32, 0.95833330f32],
-    [0.45833334f32, 0.95833330f32],
-    [0.54166670f32, 0.95833330f32],
-    [0.54166670f32, 0.95833330f32],
-    [0.54166670f32, 0.95833330f32],
-    [0.54166670f32, 0.95833330f32],
-    [0.54166670f32, 0.95833330f32],
-    [0.54166670f32, 0.95833330f32],
-    [0.62500000f32, 0.95833330f32],
-    [0.62500000f32, 0.95833330f32],
-    [0.62500000f32, 0.95833330f32],
-    [0.62500000f32, 0.95833330f32],
-    [0.62500000f32, 0.95833330f32],
-    [0.62500000f32, 0.95833330f32],
-    [0.70833330f32, 0.95833330f32],
-    [0.70833330f32, 0.95833330f32],
-    [0.70833330f32, 0.95833330f32],
-    [0.70833330f32, 0.95833330f32],
-    [0.70833330f32, 0.95833330f32],
-    [0.70833330f32, 0.95833330f32],
-    [0.79166670f32, 0.95833330f32],
-    [0.79166670f32, 0.95833330f32],
-    [0.79166670f32, 0.95833330f32],
-    [0.79166670f32, 0.95833330f32],
-    [0.79166670f32, 0.95833330f32],
-    [0.79166670f32, 0.95833330f32],
-    [0.87500000f32, 0.95833330f32],
-    [0.87500000f32, 0.95833330f32],
-    [0.87500000f32, 0.95833330f32],
-    [0.87500000f32, 0.95833330f32],
-    [0.87500000f32, 0.95833330f32],
-    [0.87500000f32, 0.95833330f32],
-    [0.95833330f32, 0.95833330f32],
-    [0.95833330f32, 0.95833330f32],
-    [0.95833330f32, 0.95833330f32],
-    [0.95833330f32, 0.95833330f32],
-    [0.95833330f32, 0.95833330f32],
-    [0.95833330f32, 0.95833330f32],
-];
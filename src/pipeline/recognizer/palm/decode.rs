@@ -0,0 +1,191 @@
+use std::cmp::Ordering;
+
+use super::{LetterboxInfo, PalmDetectorConfig};
+use crate::types::PalmRegion;
+
+pub const PALM_LANDMARKS: usize = 7;
+/// Box centre and size, then an x/y pair per palm keypoint.
+const BOX_FEATURES: usize = 4 + PALM_LANDMARKS * 2;
+/// One entry per SSD layer of the MediaPipe palm model.
+pub const PALM_ANCHOR_STRIDES: [u32; 4] = [8, 16, 16, 16];
+/// Aspect ratio 1.0 plus the interpolated scale, both square with fixed-size
+/// anchors, so only the count matters.
+const ANCHORS_PER_LAYER: usize = 2;
+
+/// Anchor centres as fractions of the input, in the order the model emits
+/// them: grid by grid, row by row, then per cell. Consecutive layers with the
+/// same stride share one grid, as in MediaPipe's `SsdAnchorsCalculator`.
+pub fn ssd_anchors(input_size: u32, strides: &[u32]) -> Vec<[f32; 2]> {
+    let mut anchors = Vec::new();
+    let mut layer = 0;
+    while layer < strides.len() {
+        let stride = strides[layer].max(1);
+        let mut per_cell = 0;
+        while layer < strides.len() && strides[layer].max(1) == stride {
+            per_cell += ANCHORS_PER_LAYER;
+            layer += 1;
+        }
+
+        let cells = input_size.div_ceil(stride);
+        for y in 0..cells {
+            for x in 0..cells {
+                let center = [
+                    (x as f32 + 0.5) / cells as f32,
+                    (y as f32 + 0.5) / cells as f32,
+                ];
+                anchors.resize(anchors.len() + per_cell, center);
+            }
+        }
+    }
+    anchors
+}
+
+/// Turns one score and one box row per anchor into palm regions in original
+/// frame pixels, after the score threshold and NMS. `raw_boxes` holds the same
+/// number of values for every anchor, offsets in input pixels; trailing anchors
+/// without a score, box or anchor are ignored.
+pub fn decode_regions(
+    raw_scores: &[f32],
+    raw_boxes: &[f32],
+    anchors: &[[f32; 2]],
+    letterbox: &LetterboxInfo,
+    config: &PalmDetectorConfig,
+    input_size: u32,
+) -> Vec<PalmRegion> {
+    let candidates = decode_candidates(
+        raw_scores, raw_boxes, anchors, letterbox, config, input_size,
+    );
+    suppress(&candidates, config)
+}
+
+/// [`decode_regions`] before NMS: every region over the score threshold, in
+/// anchor order.
+pub fn decode_candidates(
+    raw_scores: &[f32],
+    raw_boxes: &[f32],
+    anchors: &[[f32; 2]],
+    letterbox: &LetterboxInfo,
+    config: &PalmDetectorConfig,
+    input_size: u32,
+) -> Vec<PalmRegion> {
+    let stride = raw_boxes.len() / raw_scores.len().max(1);
+    if stride < BOX_FEATURES {
+        return Vec::new();
+    }
+
+    raw_scores
+        .iter()
+        .zip(raw_boxes.chunks_exact(stride))
+        .zip(anchors)
+        .filter_map(|((&raw_score, row), anchor)| {
+            let score = sigmoid(raw_score);
+            if score < config.score_threshold {
+                return None;
+            }
+            decode_box(row, *anchor, score, letterbox, input_size as f32)
+        })
+        .collect()
+}
+
+/// The `candidates` NMS keeps, best score first.
+pub fn suppress(candidates: &[PalmRegion], config: &PalmDetectorConfig) -> Vec<PalmRegion> {
+    nms(candidates, config.nms_threshold, config.top_k)
+        .into_iter()
+        .map(|idx| candidates[idx].clone())
+        .collect()
+}
+
+/// The region one anchor scored `score` on, or `None` for an empty box.
+fn decode_box(
+    row: &[f32],
+    anchor: [f32; 2],
+    score: f32,
+    letterbox: &LetterboxInfo,
+    input_size: f32,
+) -> Option<PalmRegion> {
+    let pad_bias_x = letterbox.pad_x / letterbox.scale;
+    let pad_bias_y = letterbox.pad_y / letterbox.scale;
+    let scale = letterbox.orig_w.max(letterbox.orig_h) as f32;
+    let to_frame = |dx: f32, dy: f32| {
+        (
+            (dx / input_size + anchor[0]) * scale - pad_bias_x,
+            (dy / input_size + anchor[1]) * scale - pad_bias_y,
+        )
+    };
+
+    let (hw, hh) = (row[2] / 2.0, row[3] / 2.0);
+    let (x1, y1) = to_frame(row[0] - hw, row[1] - hh);
+    let (x2, y2) = to_frame(row[0] + hw, row[1] + hh);
+    if x2 <= x1 || y2 <= y1 {
+        return None;
+    }
+
+    let landmarks = row[4..BOX_FEATURES]
+        .chunks_exact(2)
+        .map(|point| to_frame(point[0], point[1]))
+        .collect();
+    Some(PalmRegion {
+        bbox: clamp_box([x1, y1, x2, y2], letterbox.orig_w, letterbox.orig_h),
+        landmarks,
+        score,
+    })
+}
+
+pub fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Indices of the regions kept, best score first.
+pub fn nms(regions: &[PalmRegion], threshold: f32, top_k: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..regions.len()).collect();
+    order.sort_by(|a, b| {
+        regions[*b]
+            .score
+            .partial_cmp(&regions[*a].score)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let mut keep: Vec<usize> = Vec::new();
+    'outer: for &idx in &order {
+        for &k in &keep {
+            if iou(&regions[idx].bbox, &regions[k].bbox) >= threshold {
+                continue 'outer;
+            }
+        }
+        keep.push(idx);
+        if keep.len() >= top_k {
+            break;
+        }
+    }
+    keep
+}
+
+fn iou(a: &[f32; 4], b: &[f32; 4]) -> f32 {
+    let x1 = a[0].max(b[0]);
+    let y1 = a[1].max(b[1]);
+    let x2 = a[2].min(b[2]);
+    let y2 = a[3].min(b[3]);
+
+    let inter_w = (x2 - x1).max(0.0);
+    let inter_h = (y2 - y1).max(0.0);
+    let inter = inter_w * inter_h;
+    if inter <= 0.0 {
+        return 0.0;
+    }
+
+    let area_a = (a[2] - a[0]).max(0.0) * (a[3] - a[1]).max(0.0);
+    let area_b = (b[2] - b[0]).max(0.0) * (b[3] - b[1]).max(0.0);
+    let union = area_a + area_b - inter;
+    if union <= 0.0 { 0.0 } else { inter / union }
+}
+
+fn clamp_box([x1, y1, x2, y2]: [f32; 4], w: u32, h: u32) -> [f32; 4] {
+    let max_w = (w.saturating_sub(1)) as f32;
+    let max_h = (h.saturating_sub(1)) as f32;
+    [
+        x1.clamp(0.0, max_w),
+        y1.clamp(0.0, max_h),
+        x2.clamp(0.0, max_w),
+        y2.clamp(0.0, max_h),
+    ]
+}
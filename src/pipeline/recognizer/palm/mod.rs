@@ -1,23 +1,52 @@
-mod anchors;
+mod decode;
 
-use std::{cmp::Ordering, f32::consts::PI, path::PathBuf};
+use std::{cmp::Ordering, f32::consts::PI, path::Path, time::Duration};
 
-use anchors::{ANCHORS, NUM_ANCHORS};
 use anyhow::{Context, Result, anyhow};
-use ort::session::{Session, builder::GraphOptimizationLevel};
+use ort::session::Session;
 use ort::value::Tensor;
 
 use crate::types::{Frame, PalmRegion};
 
-use super::common::{LetterboxInfo, PALM_INPUT_SIZE, prepare_frame_with_size};
+use super::{
+    common::{
+        ExecutionProvider, LetterboxInfo, MAX_HANDS, PALM_INPUT_SIZE, TensorLayout, build_session,
+        prepare_frame_with_size,
+    },
+    error::RecognizerError,
+};
 
-const PALM_LANDMARKS: usize = 7;
+pub use decode::{
+    PALM_ANCHOR_STRIDES, PALM_LANDMARKS, decode_candidates, decode_regions, ssd_anchors, suppress,
+};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct PalmDetectorConfig {
     pub score_threshold: f32,
     pub nms_threshold: f32,
     pub top_k: usize,
+    /// Palms handed on to the landmark model per frame.
+    pub max_hands: usize,
+    /// Side of the hand crop as a multiple of the palm size.
+    pub crop_enlarge: f32,
+    /// Long side frames are shrunk to before detection; 0 keeps full size.
+    pub max_input_dimension: u32,
+    /// Palm and landmark time per frame above which the input shrinks further.
+    pub inference_budget: Duration,
+    /// Frames between palm detections while every tracked hand stays above
+    /// `track_confidence`; 1 detects on every frame.
+    pub detect_interval: u32,
+    pub track_confidence: f32,
+    /// Frames in a row a tracked hand may go without a confident result
+    /// before its id is retired; a hand back within them keeps its id.
+    pub track_max_misses: u32,
+    /// `[x1, y1, x2, y2]` as fractions of the frame. When set, only palms
+    /// centred inside it are recognized and tracked hands that leave it are
+    /// dropped.
+    pub roi: Option<[f32; 4]>,
+    /// Also hand back the regions NMS dropped, for the debug overlay. Off
+    /// spares copying them every frame.
+    pub keep_candidates: bool,
 }
 
 impl Default for PalmDetectorConfig {
@@ -26,31 +55,95 @@ impl Default for PalmDetectorConfig {
             score_threshold: 0.35,
             nms_threshold: 0.3,
             top_k: 32,
+            max_hands: MAX_HANDS,
+            crop_enlarge: 3.0,
+            max_input_dimension: 640,
+            inference_budget: Duration::from_millis(33),
+            detect_interval: 5,
+            track_confidence: 0.5,
+            track_max_misses: 12,
+            roi: None,
+            keep_candidates: false,
         }
     }
 }
 
+/// What one [`PalmDetector::detect_with_candidates`] found, in frame pixels.
+pub struct PalmDetection {
+    /// The regions left after NMS, best first.
+    pub regions: Vec<PalmRegion>,
+    /// Every region over the score threshold.
+    pub candidates: Vec<PalmRegion>,
+}
+
 pub struct PalmDetector {
     session: Session,
+    layout: TensorLayout,
+    anchors: Vec<[f32; 2]>,
     cfg: PalmDetectorConfig,
+    provider: ExecutionProvider,
 }
 
 impl PalmDetector {
-    pub fn new(model_path: &PathBuf, cfg: PalmDetectorConfig) -> Result<Self> {
-        let session = Session::builder()?
-            .with_optimization_level(GraphOptimizationLevel::Level3)?
-            .with_intra_threads(2)?
-            .commit_from_file(model_path)
-            .with_context(|| {
-                format!("failed to load palm detector from {}", model_path.display())
-            })?;
-
-        Ok(Self { session, cfg })
+    pub fn new(
+        model_path: &Path,
+        cfg: PalmDetectorConfig,
+        provider: ExecutionProvider,
+    ) -> Result<Self, RecognizerError> {
+        let (session, provider) = build_session(model_path, provider)
+            .map_err(|err| RecognizerError::load_failed(model_path, err))?;
+
+        let layout = TensorLayout::of_session(&session);
+        if layout != TensorLayout::Nhwc {
+            log::info!("palm detector takes {} input", layout.label());
+        }
+
+        Ok(Self {
+            session,
+            layout,
+            anchors: ssd_anchors(PALM_INPUT_SIZE, &PALM_ANCHOR_STRIDES),
+            cfg,
+            provider,
+        })
+    }
+
+    pub fn config(&self) -> PalmDetectorConfig {
+        self.cfg
+    }
+
+    /// Takes effect from the next call to [`PalmDetector::detect`].
+    pub fn set_config(&mut self, cfg: PalmDetectorConfig) {
+        self.cfg = cfg;
     }
 
-    pub fn detect(&mut self, frame: &Frame) -> Result<Vec<PalmRegion>> {
+    /// The provider the session actually registered, after any fallback.
+    pub fn execution_provider(&self) -> ExecutionProvider {
+        self.provider
+    }
+
+    pub fn detect(&mut self, frame: &Frame) -> Result<Vec<PalmRegion>, RecognizerError> {
+        self.detect_with_candidates(frame)
+            .map(|detection| detection.regions)
+    }
+
+    /// [`PalmDetector::detect`], plus what NMS started from.
+    pub fn detect_with_candidates(
+        &mut self,
+        frame: &Frame,
+    ) -> Result<PalmDetection, RecognizerError> {
+        let candidates = self
+            .run(frame)
+            .map_err(|err| RecognizerError::from_anyhow(err, RecognizerError::PalmDetectFailed))?;
+        let regions = suppress(&candidates, &self.cfg);
+        Ok(PalmDetection {
+            regions,
+            candidates,
+        })
+    }
+
+    fn run(&mut self, frame: &Frame) -> Result<Vec<PalmRegion>> {
         let (input, letterbox) = prepare_frame_with_size(frame, PALM_INPUT_SIZE)?;
-        let tensor = Tensor::from_array(input)?;
+        let tensor = Tensor::from_array(self.layout.arrange(input))?;
 
         let outputs = self
             .session
@@ -79,6 +172,7 @@ impl PalmDetector {
                 .as_slice()
                 .ok_or_else(|| anyhow!("palm scores not contiguous"))?,
             &score_shape,
+            &self.anchors,
             &letterbox,
             &self.cfg,
         )?;
@@ -87,171 +181,95 @@ impl PalmDetector {
     }
 }
 
-fn decode_palm_outputs(
+/// Checks the tensor shapes, then hands the plain slices to
+/// [`decode_candidates`].
+pub(super) fn decode_palm_outputs(
     box_landmark: &[f32],
     box_shape: &[usize],
     scores: &[f32],
     score_shape: &[usize],
+    anchors: &[[f32; 2]],
     letterbox: &LetterboxInfo,
     cfg: &PalmDetectorConfig,
-) -> Result<Vec<PalmRegion>> {
-    if box_shape.len() < 3 {
-        return Err(anyhow!(
-            "unexpected palm box shape {:?}, need [batch, anchors, features]",
-            box_shape
-        ));
-    }
-    if score_shape.len() < 3 {
-        return Err(anyhow!(
-            "unexpected palm score shape {:?}, need [batch, anchors, 1]",
-            score_shape
-        ));
-    }
-
-    let anchor_dim = *box_shape
-        .get(box_shape.len().saturating_sub(2))
-        .ok_or_else(|| anyhow!("missing anchor dimension in palm box shape"))?;
-    let feature_dim = *box_shape
-        .last()
-        .ok_or_else(|| anyhow!("missing feature dimension in palm box shape"))?;
-
-    let score_anchor_dim = *score_shape
-        .get(score_shape.len().saturating_sub(2))
-        .ok_or_else(|| anyhow!("missing anchor dimension in palm score shape"))?;
-    let score_feature_dim = *score_shape
-        .last()
-        .ok_or_else(|| anyhow!("missing feature dimension in palm score shape"))?;
-
-    if feature_dim < 4 + PALM_LANDMARKS * 2 {
-        return Err(anyhow!(
-            "palm box feature dimension too small: {feature_dim}"
-        ));
+) -> Result<Vec<PalmRegion>, RecognizerError> {
+    let bad_boxes = || RecognizerError::BadOutputShape {
+        expected: "palm boxes [batch, anchors, features]",
+        got: box_shape.to_vec(),
+    };
+    let bad_scores = || RecognizerError::BadOutputShape {
+        expected: "palm scores [batch, anchors, 1]",
+        got: score_shape.to_vec(),
+    };
+    let &[.., anchor_dim, feature_dim] = box_shape else {
+        return Err(bad_boxes());
+    };
+    let &[.., score_anchor_dim, score_feature_dim] = score_shape else {
+        return Err(bad_scores());
+    };
+    if box_shape.len() < 3 || feature_dim < 4 + PALM_LANDMARKS * 2 {
+        return Err(bad_boxes());
     }
-
-    if anchor_dim != score_anchor_dim {
-        return Err(anyhow!(
-            "anchor dimension mismatch between boxes ({anchor_dim}) and scores ({score_anchor_dim})"
-        ));
+    if score_shape.len() < 3 || score_anchor_dim != anchor_dim {
+        return Err(bad_scores());
     }
 
-    let anchors = NUM_ANCHORS.min(anchor_dim);
-    let pad_bias_x = letterbox.pad_x / letterbox.scale;
-    let pad_bias_y = letterbox.pad_y / letterbox.scale;
-    let scale = letterbox.orig_w.max(letterbox.orig_h) as f32;
-    let target_input = PALM_INPUT_SIZE as f32;
-
-    let mut candidates = Vec::new();
-    for anchor_idx in 0..anchors {
-        let score_offset = anchor_idx
-            .checked_mul(score_feature_dim)
-            .ok_or_else(|| anyhow!("palm score offset overflow"))?;
-        let raw_score = *scores
-            .get(score_offset)
-            .ok_or_else(|| anyhow!("missing score for palm anchor {anchor_idx}"))?;
-        let score = sigmoid(raw_score);
-        if score < cfg.score_threshold {
-            continue;
-        }
-
-        let feature_offset = anchor_idx
-            .checked_mul(feature_dim)
-            .ok_or_else(|| anyhow!("palm feature offset overflow"))?;
-        let anchor = ANCHORS
-            .get(anchor_idx)
+    let count = anchor_dim.min(anchors.len());
+    let boxes = box_landmark
+        .get(..count * feature_dim)
+        .ok_or_else(bad_boxes)?;
+    // Only the first value per anchor is a score.
+    let strided: Vec<f32>;
+    let scores = if score_feature_dim == 1 {
+        scores
+    } else {
+        strided = scores
+            .iter()
+            .step_by(score_feature_dim.max(1))
             .copied()
-            .ok_or_else(|| anyhow!("missing anchor {anchor_idx}"))?;
-
-        let cx_delta = *box_landmark
-            .get(feature_offset)
-            .ok_or_else(|| anyhow!("missing cx for anchor {anchor_idx}"))?
-            / target_input;
-        let cy_delta = *box_landmark
-            .get(feature_offset + 1)
-            .ok_or_else(|| anyhow!("missing cy for anchor {anchor_idx}"))?
-            / target_input;
-        let w_delta = *box_landmark
-            .get(feature_offset + 2)
-            .ok_or_else(|| anyhow!("missing w for anchor {anchor_idx}"))?
-            / target_input;
-        let h_delta = *box_landmark
-            .get(feature_offset + 3)
-            .ok_or_else(|| anyhow!("missing h for anchor {anchor_idx}"))?
-            / target_input;
-
-        let cx = cx_delta + anchor[0];
-        let cy = cy_delta + anchor[1];
-        let hw = w_delta / 2.0;
-        let hh = h_delta / 2.0;
-
-        let mut x1 = (cx - hw) * scale - pad_bias_x;
-        let mut y1 = (cy - hh) * scale - pad_bias_y;
-        let mut x2 = (cx + hw) * scale - pad_bias_x;
-        let mut y2 = (cy + hh) * scale - pad_bias_y;
-
-        if x2 <= x1 || y2 <= y1 {
-            continue;
-        }
-
-        clamp_box(
-            &mut x1,
-            &mut y1,
-            &mut x2,
-            &mut y2,
-            letterbox.orig_w,
-            letterbox.orig_h,
-        );
-
-        let mut landmarks = Vec::with_capacity(PALM_LANDMARKS);
-        for l in 0..PALM_LANDMARKS {
-            let lx = *box_landmark
-                .get(feature_offset + 4 + l * 2)
-                .ok_or_else(|| anyhow!("missing palm landmark x for {anchor_idx}:{l}"))?
-                / target_input;
-            let ly = *box_landmark
-                .get(feature_offset + 4 + l * 2 + 1)
-                .ok_or_else(|| anyhow!("missing palm landmark y for {anchor_idx}:{l}"))?
-                / target_input;
-            let gx = (lx + anchor[0]) * scale - pad_bias_x;
-            let gy = (ly + anchor[1]) * scale - pad_bias_y;
-            landmarks.push((gx, gy));
-        }
-
-        candidates.push(PalmCandidate {
-            bbox: [x1, y1, x2, y2],
-            landmarks,
-            score,
-        });
-    }
+            .collect();
+        &strided
+    };
+    let scores = scores.get(..count).ok_or_else(bad_scores)?;
+
+    Ok(decode_candidates(
+        scores,
+        boxes,
+        anchors,
+        letterbox,
+        cfg,
+        PALM_INPUT_SIZE,
+    ))
+}
 
-    let kept = nms(&candidates, cfg.nms_threshold, cfg.top_k);
-    let mut detections = Vec::with_capacity(kept.len());
-    for idx in kept {
-        if let Some(c) = candidates.get(idx) {
-            detections.push(PalmRegion {
-                bbox: c.bbox,
-                landmarks: c.landmarks.clone(),
-                score: c.score,
-            });
-        }
-    }
+/// Whether `point`, in pixels of a `width` x `height` frame, lies inside
+/// `roi`; without one the whole frame counts.
+pub fn roi_contains(roi: Option<[f32; 4]>, point: (f32, f32), width: u32, height: u32) -> bool {
+    let Some([x1, y1, x2, y2]) = roi else {
+        return true;
+    };
+    let x = point.0 / width.max(1) as f32;
+    let y = point.1 / height.max(1) as f32;
+    (x1..=x2).contains(&x) && (y1..=y2).contains(&y)
+}
 
-    Ok(detections)
+pub fn region_center(region: &PalmRegion) -> (f32, f32) {
+    (
+        (region.bbox[0] + region.bbox[2]) * 0.5,
+        (region.bbox[1] + region.bbox[3]) * 0.5,
+    )
 }
 
-pub fn pick_primary_region<'a>(regions: &'a [PalmRegion]) -> Option<&'a PalmRegion> {
-    regions
-        .iter()
-        .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap_or(Ordering::Equal))
+pub fn pick_top_regions(regions: &[PalmRegion], max_regions: usize) -> Vec<&PalmRegion> {
+    let mut sorted: Vec<&PalmRegion> = regions.iter().collect();
+    sorted.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+    sorted.truncate(max_regions);
+    sorted
 }
 
-pub fn crop_from_palm(region: &PalmRegion) -> ((f32, f32), f32, f32) {
+pub fn crop_from_palm(region: &PalmRegion, enlarge: f32) -> ((f32, f32), f32, f32) {
     const SHIFT_Y: f32 = -0.4;
-    const ENLARGE_FACTOR: f32 = 3.0;
 
-    let bbox_center = (
-        (region.bbox[0] + region.bbox[2]) * 0.5,
-        (region.bbox[1] + region.bbox[3]) * 0.5,
-    );
+    let bbox_center = region_center(region);
 
     let base_w = (region.bbox[2] - region.bbox[0]).abs();
     let base_h = (region.bbox[3] - region.bbox[1]).abs();
@@ -269,7 +287,7 @@ pub fn crop_from_palm(region: &PalmRegion) -> ((f32, f32), f32, f32) {
         (max_x - min_x).max(max_y - min_y)
     };
 
-    let side = base_w.max(base_h).max(landmark_span).max(80.0) * ENLARGE_FACTOR;
+    let side = base_w.max(base_h).max(landmark_span).max(80.0) * enlarge;
     let angle = estimate_orientation(region);
 
     (center, side, angle)
@@ -286,66 +304,3 @@ pub fn estimate_orientation(region: &PalmRegion) -> f32 {
     let two_pi = 2.0 * PI;
     radians - two_pi * ((radians + PI) / two_pi).floor()
 }
-
-#[derive(Clone, Debug)]
-struct PalmCandidate {
-    bbox: [f32; 4],
-    landmarks: Vec<(f32, f32)>,
-    score: f32,
-}
-
-fn nms(candidates: &[PalmCandidate], threshold: f32, top_k: usize) -> Vec<usize> {
-    let mut order: Vec<usize> = candidates.iter().enumerate().map(|(i, _)| i).collect();
-    order.sort_by(|a, b| {
-        candidates[*b]
-            .score
-            .partial_cmp(&candidates[*a].score)
-            .unwrap_or(Ordering::Equal)
-    });
-
-    let mut keep: Vec<usize> = Vec::new();
-    'outer: for &idx in &order {
-        for &k in &keep {
-            if iou(&candidates[idx].bbox, &candidates[k].bbox) >= threshold {
-                continue 'outer;
-            }
-        }
-        keep.push(idx);
-        if keep.len() >= top_k {
-            break;
-        }
-    }
-    keep
-}
-
-fn iou(a: &[f32; 4], b: &[f32; 4]) -> f32 {
-    let x1 = a[0].max(b[0]);
-    let y1 = a[1].max(b[1]);
-    let x2 = a[2].min(b[2]);
-    let y2 = a[3].min(b[3]);
-
-    let inter_w = (x2 - x1).max(0.0);
-    let inter_h = (y2 - y1).max(0.0);
-    let inter = inter_w * inter_h;
-    if inter <= 0.0 {
-        return 0.0;
-    }
-
-    let area_a = (a[2] - a[0]).max(0.0) * (a[3] - a[1]).max(0.0);
-    let area_b = (b[2] - b[0]).max(0.0) * (b[3] - b[1]).max(0.0);
-    let union = area_a + area_b - inter;
-    if union <= 0.0 { 0.0 } else { inter / union }
-}
-
-fn sigmoid(x: f32) -> f32 {
-    1.0 / (1.0 + (-x).exp())
-}
-
-fn clamp_box(x1: &mut f32, y1: &mut f32, x2: &mut f32, y2: &mut f32, w: u32, h: u32) {
-    let max_w = (w.saturating_sub(1)) as f32;
-    let max_h = (h.saturating_sub(1)) as f32;
-    *x1 = x1.clamp(0.0, max_w);
-    *y1 = y1.clamp(0.0, max_h);
-    *x2 = x2.clamp(0.0, max_w);
-    *y2 = y2.clamp(0.0, max_h);
-}
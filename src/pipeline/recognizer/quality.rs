@@ -0,0 +1,172 @@
+use crate::types::{Frame, FrameQuality, QualityGateConfig};
+
+/// Samples per side of the grid the luma statistics are taken on. A 64x48
+/// grid is a few thousand pixels whatever the camera resolution.
+pub(super) const GRID_COLUMNS: u32 = 64;
+pub(super) const GRID_ROWS: u32 = 48;
+
+/// Mean and spread of the luma on a coarse grid, against `config`. Integer
+/// BT.601 weights; at most one pixel in a few hundred is read.
+pub(crate) fn frame_quality(frame: &Frame, config: &QualityGateConfig) -> FrameQuality {
+    if !config.enabled || frame.width == 0 || frame.height == 0 {
+        return FrameQuality::Good;
+    }
+
+    let step_x = (frame.width / GRID_COLUMNS).max(1) as usize;
+    let step_y = (frame.height / GRID_ROWS).max(1) as usize;
+    let channels = frame.layout.channels();
+    let stride = frame.width as usize * channels;
+    let (mut count, mut sum, mut sum_squares) = (0u64, 0u64, 0u64);
+    for row in frame.pixels.chunks_exact(stride).step_by(step_y) {
+        for pixel in row.chunks_exact(channels).step_by(step_x) {
+            let [r, g, b] = [pixel[0], pixel[1], pixel[2]].map(u64::from);
+            let luma = (77 * r + 150 * g + 29 * b) >> 8;
+            count += 1;
+            sum += luma;
+            sum_squares += luma * luma;
+        }
+    }
+    if count == 0 {
+        return FrameQuality::Good;
+    }
+
+    let mean = sum / count;
+    let variance = (sum_squares / count).saturating_sub(mean * mean);
+    let min_contrast = u64::from(config.min_contrast);
+    if mean < u64::from(config.min_luma) {
+        FrameQuality::TooDark
+    } else if mean > u64::from(config.max_luma) {
+        FrameQuality::WashedOut
+    } else if variance < min_contrast * min_contrast {
+        if mean < 128 {
+            FrameQuality::TooDark
+        } else {
+            FrameQuality::WashedOut
+        }
+    } else {
+        FrameQuality::Good
+    }
+}
+
+/// Mean luma inside `bbox` (`[x1, y1, x2, y2]` in pixels), read on the same
+/// coarse grid as [`frame_quality`]. `None` when the box misses the frame.
+pub(crate) fn region_luma(frame: &Frame, bbox: [f32; 4]) -> Option<f32> {
+    let clamp_x = |x: f32| (x.max(0.0) as u32).min(frame.width);
+    let clamp_y = |y: f32| (y.max(0.0) as u32).min(frame.height);
+    let (left, right) = (clamp_x(bbox[0].min(bbox[2])), clamp_x(bbox[0].max(bbox[2])));
+    let (top, bottom) = (clamp_y(bbox[1].min(bbox[3])), clamp_y(bbox[1].max(bbox[3])));
+    if left >= right || top >= bottom {
+        return None;
+    }
+
+    let step_x = ((right - left) / GRID_COLUMNS).max(1) as usize;
+    let step_y = ((bottom - top) / GRID_ROWS).max(1) as usize;
+    let channels = frame.layout.channels();
+    let stride = frame.width as usize * channels;
+    let (mut count, mut sum) = (0u64, 0u64);
+    for row in (top as usize..bottom as usize).step_by(step_y) {
+        let start = row * stride + left as usize * channels;
+        let end = row * stride + right as usize * channels;
+        let Some(pixels) = frame.pixels.get(start..end) else {
+            break;
+        };
+        for pixel in pixels.chunks_exact(channels).step_by(step_x) {
+            let [r, g, b] = [pixel[0], pixel[1], pixel[2]].map(u64::from);
+            count += 1;
+            sum += (77 * r + 150 * g + 29 * b) >> 8;
+        }
+    }
+    (count > 0).then(|| sum as f32 / count as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+    use crate::types::PixelLayout;
+
+    /// A 640×480 RGB frame with each pixel's grey level given by `shade`.
+    fn frame(shade: impl Fn(u32, u32) -> u8) -> Frame {
+        let (width, height) = (640, 480);
+        let mut pixels = Vec::with_capacity(PixelLayout::Rgb.buffer_len(width, height));
+        for y in 0..height {
+            for x in 0..width {
+                pixels.extend([shade(x, y); 3]);
+            }
+        }
+        let now = Instant::now();
+        Frame {
+            pixels: pixels.into(),
+            layout: PixelLayout::Rgb,
+            width,
+            height,
+            timestamp: now,
+            acquired_at: now,
+        }
+    }
+
+    /// Sensor noise of up to `amplitude` either way around `base`.
+    fn noisy(base: u8, amplitude: u8) -> impl Fn(u32, u32) -> u8 {
+        move |x, y| {
+            let noise = (x.wrapping_mul(31) ^ y.wrapping_mul(17)) % (2 * amplitude as u32 + 1);
+            (base as u32 + noise)
+                .saturating_sub(amplitude as u32)
+                .min(255) as u8
+        }
+    }
+
+    fn quality(frame: &Frame) -> FrameQuality {
+        frame_quality(frame, &QualityGateConfig::default())
+    }
+
+    #[test]
+    fn a_night_frame_is_too_dark() {
+        assert_eq!(quality(&frame(noisy(12, 10))), FrameQuality::TooDark);
+    }
+
+    #[test]
+    fn a_blown_out_frame_is_washed_out() {
+        assert_eq!(quality(&frame(noisy(245, 8))), FrameQuality::WashedOut);
+    }
+
+    #[test]
+    fn a_flat_frame_is_judged_by_its_brightness() {
+        // Bright enough on average, but with nothing in it to find a hand in.
+        assert_eq!(quality(&frame(|_, _| 80)), FrameQuality::TooDark);
+        assert_eq!(quality(&frame(|_, _| 200)), FrameQuality::WashedOut);
+    }
+
+    #[test]
+    fn a_lit_scene_passes() {
+        // A dim background with a brighter hand-sized patch in the middle.
+        let scene = frame(|x, y| {
+            if (240..400).contains(&x) && (160..320).contains(&y) {
+                180
+            } else {
+                60
+            }
+        });
+        assert_eq!(quality(&scene), FrameQuality::Good);
+        assert_eq!(quality(&frame(noisy(120, 40))), FrameQuality::Good);
+    }
+
+    #[test]
+    fn a_disabled_gate_passes_every_frame() {
+        let config = QualityGateConfig {
+            enabled: false,
+            ..QualityGateConfig::default()
+        };
+        assert_eq!(frame_quality(&frame(|_, _| 0), &config), FrameQuality::Good);
+    }
+
+    #[test]
+    fn region_luma_reads_only_inside_the_box() {
+        let split = frame(|x, _| if x < 320 { 20 } else { 220 });
+        let left = region_luma(&split, [0.0, 0.0, 320.0, 480.0]).unwrap();
+        let right = region_luma(&split, [320.0, 0.0, 640.0, 480.0]).unwrap();
+        assert!((left - 20.0).abs() < 1.0, "{left}");
+        assert!((right - 220.0).abs() < 1.0, "{right}");
+        assert_eq!(region_luma(&split, [700.0, 0.0, 800.0, 100.0]), None);
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    collections::HashMap,
+    f32::consts::PI,
+    time::{Duration, Instant},
+};
+
+use super::common::HandLandmarks;
+use crate::types::SmoothingConfig;
+
+/// A hand unseen for longer than this starts over from its next sample rather
+/// than easing in from where it was lost.
+const SMOOTHING_RESET_AFTER: Duration = Duration::from_millis(300);
+
+/// One-euro filters over every landmark coordinate, per tracked hand. The time
+/// step comes from the frame timestamps, so a dropped frame or a slow camera
+/// does not read as a jump.
+#[derive(Default)]
+pub(crate) struct LandmarkSmoother {
+    hands: HashMap<u64, HandFilter>,
+}
+
+struct HandFilter {
+    last_seen: Instant,
+    /// x then y of each projected landmark.
+    projected: Vec<OneEuroFilter>,
+    /// x, y, z of each raw landmark; empty unless raw smoothing is on.
+    raw: Vec<OneEuroFilter>,
+}
+
+impl LandmarkSmoother {
+    /// Replaces the landmarks of `hand` with their filtered values.
+    pub fn smooth(&mut self, config: &SmoothingConfig, hand: &mut HandLandmarks, at: Instant) {
+        let filter = self
+            .hands
+            .entry(hand.track_id)
+            .or_insert_with(|| HandFilter {
+                last_seen: at,
+                projected: Vec::new(),
+                raw: Vec::new(),
+            });
+        let dt = at.saturating_duration_since(filter.last_seen).as_secs_f32();
+        filter.last_seen = at;
+
+        let count = hand.projected_landmarks.len() * 2;
+        let projected = hand
+            .projected_landmarks
+            .iter_mut()
+            .flat_map(|(x, y)| [x, y]);
+        filter_all(&mut filter.projected, count, projected, config, dt);
+        if config.raw_landmarks {
+            let count = hand.raw_landmarks.len() * 3;
+            let raw = hand.raw_landmarks.iter_mut().flatten();
+            filter_all(&mut filter.raw, count, raw, config, dt);
+        } else {
+            filter.raw.clear();
+        }
+    }
+
+    /// Forgets the hands not seen within [`SMOOTHING_RESET_AFTER`] of `now`.
+    pub fn prune(&mut self, now: Instant) {
+        self.hands.retain(|_, hand| {
+            now.saturating_duration_since(hand.last_seen) <= SMOOTHING_RESET_AFTER
+        });
+    }
+
+    pub fn reset(&mut self) {
+        self.hands.clear();
+    }
+}
+
+/// Runs `values` through `filters` in order, starting over when the landmark
+/// count changed.
+fn filter_all<'a>(
+    filters: &mut Vec<OneEuroFilter>,
+    count: usize,
+    values: impl Iterator<Item = &'a mut f32>,
+    config: &SmoothingConfig,
+    dt: f32,
+) {
+    if filters.len() != count {
+        *filters = vec![OneEuroFilter::default(); count];
+    }
+    for (filter, value) in filters.iter_mut().zip(values) {
+        *value = filter.filter(*value, dt, config);
+    }
+}
+
+/// Casiez et al.'s one-euro filter: a low-pass whose cutoff rises with speed.
+#[derive(Clone, Copy, Default)]
+struct OneEuroFilter {
+    /// Filtered value and speed, once a sample has been seen.
+    state: Option<(f32, f32)>,
+}
+
+impl OneEuroFilter {
+    fn filter(&mut self, value: f32, dt: f32, config: &SmoothingConfig) -> f32 {
+        let Some((previous, derivative)) = self.state else {
+            self.state = Some((value, 0.0));
+            return value;
+        };
+        // Two results for one timestamp; keep the first.
+        if dt <= 0.0 {
+            return previous;
+        }
+
+        let speed = (value - previous) / dt;
+        let derivative = derivative + alpha(config.derivative_cutoff, dt) * (speed - derivative);
+        let cutoff = config.min_cutoff + config.beta * derivative.abs();
+        let filtered = previous + alpha(cutoff, dt) * (value - previous);
+        self.state = Some((filtered, derivative));
+        filtered
+    }
+}
+
+/// Smoothing factor of a first-order low-pass at `cutoff` Hz over `dt` seconds.
+fn alpha(cutoff: f32, dt: f32) -> f32 {
+    let tau = 1.0 / (2.0 * PI * cutoff.max(f32::EPSILON));
+    1.0 / (1.0 + tau / dt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(33);
+
+    fn hand_at(x: f32) -> HandLandmarks {
+        HandLandmarks {
+            track_id: 1,
+            raw_landmarks: vec![[x, 0.0, 0.0]],
+            projected_landmarks: vec![(x, 0.0)],
+            confidence: 0.9,
+            handedness: None,
+        }
+    }
+
+    /// The smoothed x of a hand held at `x` at `at`.
+    fn smoothed(smoother: &mut LandmarkSmoother, x: f32, at: Instant) -> f32 {
+        let mut hand = hand_at(x);
+        smoother.smooth(&SmoothingConfig::default(), &mut hand, at);
+        hand.projected_landmarks[0].0
+    }
+
+    #[test]
+    fn a_step_converges_within_a_few_frames() {
+        let mut smoother = LandmarkSmoother::default();
+        let mut at = Instant::now();
+        for _ in 0..5 {
+            assert_eq!(smoothed(&mut smoother, 0.0, at), 0.0);
+            at += FRAME;
+        }
+
+        let seen: Vec<f32> = (0..6)
+            .map(|frame| smoothed(&mut smoother, 100.0, at + FRAME * frame))
+            .collect();
+        // Eased in rather than jumping, then close to the new position.
+        assert!(seen[0] > 10.0 && seen[0] < 90.0, "{seen:?}");
+        assert!(seen.windows(2).all(|pair| pair[0] <= pair[1]), "{seen:?}");
+        assert!(seen[5] > 95.0, "{seen:?}");
+    }
+
+    #[test]
+    fn jitter_is_damped() {
+        let mut smoother = LandmarkSmoother::default();
+        let mut at = Instant::now();
+        let seen: Vec<f32> = (0..30)
+            .map(|frame| {
+                at += FRAME;
+                let jitter = if frame % 2 == 0 { 2.0 } else { -2.0 };
+                smoothed(&mut smoother, 50.0 + jitter, at)
+            })
+            .collect();
+        let settled = &seen[10..];
+        let spread = settled.iter().fold(f32::MIN, |a, b| a.max(*b))
+            - settled.iter().fold(f32::MAX, |a, b| a.min(*b));
+        assert!(spread < 1.5, "{seen:?}");
+    }
+
+    #[test]
+    fn the_time_step_comes_from_the_timestamps() {
+        let start = Instant::now();
+        let step_after = |interval: Duration| {
+            let mut smoother = LandmarkSmoother::default();
+            smoothed(&mut smoother, 0.0, start);
+            smoothed(&mut smoother, 100.0, start + interval)
+        };
+        // A longer gap lets the filter move further towards the new value.
+        assert!(step_after(FRAME * 3) > step_after(FRAME));
+        // Two results for one frame keep the first.
+        let mut smoother = LandmarkSmoother::default();
+        smoothed(&mut smoother, 10.0, start);
+        assert_eq!(smoothed(&mut smoother, 90.0, start), 10.0);
+    }
+
+    #[test]
+    fn a_hand_lost_for_long_starts_over() {
+        let start = Instant::now();
+        let after_gap = |gap: Duration| {
+            let mut smoother = LandmarkSmoother::default();
+            smoothed(&mut smoother, 0.0, start);
+            smoother.prune(start + gap);
+            smoothed(&mut smoother, 100.0, start + gap)
+        };
+        // A short dropout eases on from where the hand was.
+        assert!(after_gap(Duration::from_millis(200)) < 100.0);
+        // Past the reset time the old position is forgotten.
+        assert_eq!(after_gap(Duration::from_millis(400)), 100.0);
+
+        let mut smoother = LandmarkSmoother::default();
+        smoothed(&mut smoother, 0.0, start);
+        smoother.reset();
+        assert_eq!(smoothed(&mut smoother, 100.0, start + FRAME), 100.0);
+    }
+
+    #[test]
+    fn raw_landmarks_are_only_filtered_when_asked() {
+        let config = SmoothingConfig {
+            raw_landmarks: true,
+            ..SmoothingConfig::default()
+        };
+        let start = Instant::now();
+        for (config, filtered) in [(SmoothingConfig::default(), false), (config, true)] {
+            let mut smoother = LandmarkSmoother::default();
+            smoother.smooth(&config, &mut hand_at(0.0), start);
+            let mut hand = hand_at(100.0);
+            smoother.smooth(&config, &mut hand, start + FRAME);
+            assert_eq!(hand.raw_landmarks[0][0] < 100.0, filtered);
+        }
+    }
+}
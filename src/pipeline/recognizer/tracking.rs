@@ -0,0 +1,511 @@
+use super::{
+    common::{self, HandLandmarks},
+    crop_smoothing::{CropSmoother, square_iou},
+    error::RecognizerError,
+    input_scale::FrameScale,
+    palm::{PalmDetection, PalmDetectorConfig, crop_from_palm, pick_top_regions, region_center},
+};
+use crate::types::{PalmDebug, PalmRegion};
+
+/// What an engine carries between frames besides its models: which frames
+/// run palm detection, which crops go to the handpose model, and the id each
+/// hand is reported under.
+pub(super) struct HandTracking {
+    tracker: HandTracker,
+    crop_smoother: CropSmoother,
+    schedule: DetectionSchedule,
+}
+
+impl HandTracking {
+    pub(super) fn new() -> Self {
+        Self {
+            tracker: HandTracker::new(),
+            crop_smoother: CropSmoother::default(),
+            schedule: DetectionSchedule::default(),
+        }
+    }
+
+    /// Forgets every hand, e.g. once the models were reloaded.
+    pub(super) fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    pub(super) fn should_detect(&mut self, config: &PalmDetectorConfig) -> bool {
+        self.schedule.should_detect(config.detect_interval)
+    }
+
+    /// Crops for one frame: the best palms, then the tracked hands they
+    /// missed, up to `config.max_hands`, each steadied against its track's
+    /// last crop. `in_roi` takes a point in frame pixels.
+    pub(super) fn plan_crops(
+        &mut self,
+        palm_regions: &[PalmRegion],
+        config: &PalmDetectorConfig,
+        in_roi: impl Fn((f32, f32)) -> bool,
+        palm_debug: &mut Option<PalmDebug>,
+    ) -> Vec<HandCrop> {
+        let max_hands = config.max_hands.max(1);
+        let mut crops: Vec<HandCrop> = Vec::with_capacity(max_hands);
+        let mut claimed: Vec<u64> = Vec::with_capacity(max_hands);
+        for region in pick_top_regions(palm_regions, max_hands) {
+            if let Some(debug) = palm_debug {
+                debug.selected.push(region.clone());
+            }
+            let (center, side, angle) = crop_from_palm(region, config.crop_enlarge);
+            let track_id = self.tracker.match_track(center, side, &claimed);
+            if let Some(id) = track_id {
+                claimed.push(id);
+            }
+            crops.push(HandCrop {
+                center,
+                side,
+                angle,
+                prior_score: region.score,
+                track_id,
+                from_tracking: false,
+            });
+        }
+
+        // Hands the palm detector missed this frame (e.g. back-of-hand rotations),
+        // or every hand on frames that skipped detection, keep going from their
+        // last known landmarks.
+        for (id, (center, side, angle), score) in self.tracker.estimate_rois() {
+            if crops.len() >= max_hands {
+                break;
+            }
+            // Left out of the crops, the track misses until it ages out.
+            if claimed.contains(&id) || !in_roi(center) {
+                continue;
+            }
+            claimed.push(id);
+            if let Some(debug) = palm_debug {
+                debug.tracked.push((center, side, angle));
+            }
+            crops.push(HandCrop {
+                center,
+                side,
+                angle,
+                prior_score: score,
+                track_id: Some(id),
+                from_tracking: true,
+            });
+        }
+
+        for crop in &mut crops {
+            if let Some(id) = crop.track_id {
+                (crop.center, crop.side, crop.angle) = self
+                    .crop_smoother
+                    .smooth(id, (crop.center, crop.side, crop.angle));
+            }
+        }
+        crops
+    }
+
+    /// Projects one crop's handpose outputs into the frame and records them
+    /// on the crop's track; `None` when the model gave no landmarks.
+    pub(super) fn finish_hand(
+        &mut self,
+        crop: &HandCrop,
+        transform: &common::CropTransform,
+        raw: RawHand,
+    ) -> Option<HandLandmarks> {
+        if raw.landmarks.is_empty() {
+            return None;
+        }
+
+        let projected = common::project_landmarks_with_transform(&raw.landmarks, transform);
+        let mut confidence = (raw.confidence * crop.prior_score).clamp(0.0, 1.0);
+        if crop.from_tracking {
+            confidence *= 0.9;
+        }
+
+        let track_id = self
+            .tracker
+            .update(crop.track_id, transform, &projected, confidence);
+
+        Some(HandLandmarks {
+            track_id,
+            raw_landmarks: raw.landmarks,
+            projected_landmarks: projected,
+            confidence,
+            handedness: raw.handedness,
+        })
+    }
+
+    /// Ages out the tracks the frame missed and lets the confidences of
+    /// `hands` decide whether the next frame detects.
+    pub(super) fn end_frame(&mut self, hands: &[HandLandmarks], config: &PalmDetectorConfig) {
+        self.tracker.end_frame(config.track_max_misses);
+        let tracks = &self.tracker.tracks;
+        self.crop_smoother
+            .retain(|id| tracks.iter().any(|track| track.id == id));
+        self.schedule.observe(
+            hands.iter().map(|hand| hand.confidence),
+            config.track_confidence,
+        );
+    }
+}
+
+/// Palm regions detected on the possibly downscaled input, back in frame
+/// pixels, keeping those centred where `in_roi` allows; what NMS started
+/// from goes to `palm_debug`. A failed detection gives no regions and its
+/// error.
+pub(super) fn frame_palm_regions(
+    detection: Result<PalmDetection, RecognizerError>,
+    scale: FrameScale,
+    in_roi: impl Fn((f32, f32)) -> bool,
+    palm_debug: &mut Option<PalmDebug>,
+) -> (Vec<PalmRegion>, Option<RecognizerError>) {
+    match detection {
+        Ok(detection) => {
+            if let Some(debug) = palm_debug {
+                debug.candidates = detection
+                    .candidates
+                    .into_iter()
+                    .map(|region| scale.region_to_original(region))
+                    .collect();
+            }
+            let regions = detection
+                .regions
+                .into_iter()
+                .map(|region| scale.region_to_original(region))
+                .filter(|region| in_roi(region_center(region)))
+                .collect();
+            (regions, None)
+        }
+        Err(err) => (Vec::new(), Some(err)),
+    }
+}
+
+/// One crop's handpose outputs before projection.
+pub(super) struct RawHand {
+    pub(super) landmarks: Vec<[f32; 3]>,
+    pub(super) confidence: f32,
+    pub(super) handedness: Option<f32>,
+}
+
+/// Alternates palm detection with tracking, the way MediaPipe does: while the
+/// last frame's hands were all confident, detection only runs every
+/// `interval` frames so new hands still get picked up.
+#[derive(Default)]
+struct DetectionSchedule {
+    frames_since_detect: u32,
+    /// Whether the last frame found hands, all above the tracking confidence.
+    tracking: bool,
+}
+
+impl DetectionSchedule {
+    fn should_detect(&mut self, interval: u32) -> bool {
+        if !self.tracking || self.frames_since_detect + 1 >= interval {
+            self.frames_since_detect = 0;
+            return true;
+        }
+        self.frames_since_detect += 1;
+        false
+    }
+
+    /// Takes the confidences of the hands the frame produced.
+    fn observe(&mut self, confidences: impl IntoIterator<Item = f32>, threshold: f32) {
+        let mut confidences = confidences.into_iter().peekable();
+        self.tracking =
+            confidences.peek().is_some() && confidences.all(|confidence| confidence >= threshold);
+    }
+}
+
+pub(super) struct HandCrop {
+    pub(super) center: (f32, f32),
+    pub(super) side: f32,
+    pub(super) angle: f32,
+    prior_score: f32,
+    track_id: Option<u64>,
+    from_tracking: bool,
+}
+
+// Handpose results below this do not refresh a track, so a hand that left the
+// frame is not followed by its own guesses; the track ages out instead.
+const TRACK_MIN_CONF: f32 = 0.15;
+/// Overlap of the crop squares at which a detection continues a track even
+/// when its centre moved further than half a crop.
+const TRACK_MATCH_IOU: f32 = 0.3;
+
+/// Center, side and angle of a hand crop, as [`crop_from_palm`] gives them.
+type CropRoi = ((f32, f32), f32, f32);
+
+struct TrackedHand {
+    id: u64,
+    transform: common::CropTransform,
+    projected: Vec<(f32, f32)>,
+    confidence: f32,
+    /// Consecutive frames without a confident result.
+    misses: u32,
+    seen: bool,
+}
+
+impl TrackedHand {
+    fn center(&self) -> (f32, f32) {
+        self.transform.center
+    }
+
+    fn estimate_roi(&self) -> Option<CropRoi> {
+        if self.projected.len() < 3 {
+            return None;
+        }
+
+        let (min_x, max_x, min_y, max_y) = self
+            .projected
+            .iter()
+            .fold((f32::MAX, f32::MIN, f32::MAX, f32::MIN), |acc, (x, y)| {
+                (acc.0.min(*x), acc.1.max(*x), acc.2.min(*y), acc.3.max(*y))
+            });
+
+        if !min_x.is_finite() || !max_x.is_finite() || !min_y.is_finite() || !max_y.is_finite() {
+            return None;
+        }
+
+        let span = (max_x - min_x).max(max_y - min_y).max(1.0);
+        let expanded = span * 1.8;
+        let side = expanded
+            .max(self.transform.side * 0.7)
+            .min(self.transform.side * 2.5)
+            .max(80.0);
+
+        let center = ((min_x + max_x) * 0.5, (min_y + max_y) * 0.5);
+        let angle =
+            estimate_orientation_from_landmarks(&self.projected).unwrap_or(self.transform.angle);
+
+        Some((center, side, angle))
+    }
+}
+
+struct HandTracker {
+    tracks: Vec<TrackedHand>,
+    next_id: u64,
+}
+
+impl HandTracker {
+    fn new() -> Self {
+        Self {
+            tracks: Vec::new(),
+            next_id: 1,
+        }
+    }
+
+    /// Finds the track closest to a freshly detected crop, ignoring tracks
+    /// already claimed by another crop this frame. A track matches when the
+    /// centres are within half a crop or the crops overlap enough.
+    fn match_track(&self, center: (f32, f32), side: f32, claimed: &[u64]) -> Option<u64> {
+        self.tracks
+            .iter()
+            .filter(|t| !claimed.contains(&t.id))
+            .filter_map(|t| {
+                let (tx, ty) = t.center();
+                let dist = ((tx - center.0).powi(2) + (ty - center.1).powi(2)).sqrt();
+                let limit = side.max(t.transform.side) * 0.5;
+                let overlap = square_iou(center, side, t.center(), t.transform.side);
+                (dist <= limit || overlap >= TRACK_MATCH_IOU).then_some((t.id, dist))
+            })
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(id, _)| id)
+    }
+
+    /// Records a handpose result and returns the id it is reported under. A
+    /// result below [`TRACK_MIN_CONF`] keeps its crop's id but leaves the track
+    /// at its last confident position.
+    fn update(
+        &mut self,
+        track_id: Option<u64>,
+        transform: &common::CropTransform,
+        projected: &[(f32, f32)],
+        confidence: f32,
+    ) -> u64 {
+        let id = track_id.unwrap_or_else(|| {
+            let id = self.next_id;
+            self.next_id += 1;
+            id
+        });
+        if confidence < TRACK_MIN_CONF {
+            return id;
+        }
+
+        let tracked = TrackedHand {
+            id,
+            transform: transform.clone(),
+            projected: projected.to_vec(),
+            confidence,
+            misses: 0,
+            seen: true,
+        };
+        match self.tracks.iter_mut().find(|t| t.id == id) {
+            Some(existing) => *existing = tracked,
+            None => self.tracks.push(tracked),
+        }
+        id
+    }
+
+    fn estimate_rois(&self) -> Vec<(u64, CropRoi, f32)> {
+        self.tracks
+            .iter()
+            .filter_map(|t| t.estimate_roi().map(|roi| (t.id, roi, t.confidence)))
+            .collect()
+    }
+
+    /// Counts a miss for every track without a confident result this frame
+    /// and drops the ones missed more than `max_misses` frames in a row. Until
+    /// then a track keeps its id, so a hand that comes back continues it.
+    fn end_frame(&mut self, max_misses: u32) {
+        for track in &mut self.tracks {
+            track.misses = if track.seen { 0 } else { track.misses + 1 };
+            track.seen = false;
+        }
+        self.tracks.retain(|t| t.misses <= max_misses);
+    }
+}
+
+fn estimate_orientation_from_landmarks(points: &[(f32, f32)]) -> Option<f32> {
+    use std::f32::consts::PI;
+
+    if points.len() <= 17 {
+        return None;
+    }
+
+    let wrist = points[0];
+    let index = points[5];
+    let pinky = points[17];
+    let axis_x = ((index.0 + pinky.0) * 0.5) - wrist.0;
+    let axis_y = ((index.1 + pinky.1) * 0.5) - wrist.1;
+
+    if axis_x.abs() < f32::EPSILON && axis_y.abs() < f32::EPSILON {
+        return None;
+    }
+
+    let radians = PI / 2.0 - (-(axis_y)).atan2(axis_x);
+    let two_pi = 2.0 * PI;
+    Some(radians - two_pi * ((radians + PI) / two_pi).floor())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Whether each frame ran palm detection, with the confidence of the one
+    /// hand it then produced, or no hand for `None`.
+    fn detections(schedule: &mut DetectionSchedule, confidences: &[Option<f32>]) -> Vec<bool> {
+        let threshold = PalmDetectorConfig::default().track_confidence;
+        confidences
+            .iter()
+            .map(|confidence| {
+                let detect = schedule.should_detect(5);
+                schedule.observe(*confidence, threshold);
+                detect
+            })
+            .collect()
+    }
+
+    #[test]
+    fn a_confident_track_skips_detection_between_refreshes() {
+        let mut schedule = DetectionSchedule::default();
+        let seen = detections(&mut schedule, &[Some(0.9); 11]);
+        let every_fifth: Vec<bool> = (0..11).map(|frame| frame % 5 == 0).collect();
+        assert_eq!(seen, every_fifth);
+    }
+
+    #[test]
+    fn losing_confidence_forces_the_next_detection() {
+        let low = PalmDetectorConfig::default().track_confidence / 2.0;
+        let mut schedule = DetectionSchedule::default();
+        let script = [
+            Some(0.9),
+            Some(0.9),
+            Some(low),
+            Some(0.9),
+            Some(0.9),
+            None,
+            None,
+            Some(0.9),
+            Some(0.9),
+        ];
+        let seen = detections(&mut schedule, &script);
+        // Detection runs again right after the weak frame and after each frame
+        // without a hand, and the refresh count starts over from there.
+        assert_eq!(
+            seen,
+            [true, false, false, true, false, false, true, true, false]
+        );
+    }
+
+    /// Runs the tracker over one frame per entry, each detecting the listed
+    /// crops as `(center, side)`, and returns the ids they were reported under.
+    fn track_ids(
+        tracker: &mut HandTracker,
+        max_misses: u32,
+        frames: &[&[((f32, f32), f32)]],
+    ) -> Vec<Vec<u64>> {
+        frames
+            .iter()
+            .map(|crops| {
+                let mut claimed = Vec::new();
+                for &(center, side) in *crops {
+                    let matched = tracker.match_track(center, side, &claimed);
+                    let transform = common::CropTransform {
+                        center,
+                        side,
+                        angle: 0.0,
+                        output_size: 224,
+                        orig_w: 640,
+                        orig_h: 480,
+                    };
+                    claimed.push(tracker.update(matched, &transform, &[center], 0.9));
+                }
+                tracker.end_frame(max_misses);
+                claimed
+            })
+            .collect()
+    }
+
+    #[test]
+    fn a_hand_keeps_its_id_through_a_short_dropout() {
+        let mut tracker = HandTracker::new();
+        let left = ((160.0, 240.0), 120.0);
+        let right = ((480.0, 240.0), 120.0);
+        let drifted = ((190.0, 250.0), 120.0);
+        let ids = track_ids(
+            &mut tracker,
+            2,
+            &[
+                &[left, right],
+                &[left, right],
+                &[right],
+                &[right],
+                &[drifted, right],
+            ],
+        );
+        assert_eq!(ids[0], [1, 2]);
+        assert_eq!(ids[1], [1, 2]);
+        assert_eq!(ids[2], [2]);
+        // Back after two missed frames and slightly moved, still the same hand.
+        assert_eq!(ids[4], [1, 2]);
+    }
+
+    #[test]
+    fn an_expired_track_comes_back_under_a_new_id() {
+        let mut tracker = HandTracker::new();
+        let hand = ((320.0, 240.0), 120.0);
+        let ids = track_ids(&mut tracker, 2, &[&[hand], &[], &[], &[], &[hand], &[hand]]);
+        assert_eq!(ids[0], [1]);
+        assert_eq!(ids[4], [2]);
+        assert_eq!(ids[5], [2]);
+    }
+
+    #[test]
+    fn overlapping_crops_continue_a_track_past_half_a_crop() {
+        let crop = |x: f32| ((x, 240.0), 100.0);
+        // 52 px is over half a crop, but the squares still overlap by 0.32.
+        let mut tracker = HandTracker::new();
+        let ids = track_ids(&mut tracker, 2, &[&[crop(320.0)], &[crop(372.0)]]);
+        assert_eq!(ids, [[1], [1]]);
+        // At 60 px the overlap is down to 0.25 and a new track starts.
+        let mut tracker = HandTracker::new();
+        let ids = track_ids(&mut tracker, 2, &[&[crop(320.0)], &[crop(380.0)]]);
+        assert_eq!(ids, [[1], [2]]);
+    }
+}
@@ -0,0 +1,437 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, anyhow};
+use ndarray::Array4;
+use tract_onnx::prelude::{
+    DatumExt, Framework, InferenceModelExt, TValue, TVec, Tensor as TractTensor, TypedModel,
+    TypedRunnableModel, tvec,
+};
+use tract_onnx::tract_hir::infer::Factoid;
+
+use super::{
+    HandposeEngine, RecognizerBackend,
+    common::{
+        self, ExecutionProvider, HandLandmarks, HandposeOutput, HandposeOutputMap,
+        HandposeOutputNames, InferenceTimings, PALM_INPUT_SIZE, TensorLayout,
+        prepare_frame_with_size,
+    },
+    error::RecognizerError,
+    input_scale::{FrameScale, InputScaler},
+    palm::{
+        PALM_ANCHOR_STRIDES, PalmDetection, PalmDetectorConfig, decode_palm_outputs, roi_contains,
+        ssd_anchors, suppress,
+    },
+    tracking::{HandCrop, HandTracking, RawHand, frame_palm_regions},
+};
+use crate::{
+    model_download::{default_handpose_estimator_model_path, default_palm_detector_model_path},
+    types::{Frame, PalmDebug, PalmRegion},
+};
+
+type TractPlan = TypedRunnableModel<TypedModel>;
+
+/// Loads the tract engine for `backend` once its models are on disk.
+pub(super) fn load_engine(backend: &RecognizerBackend) -> Result<TractEngine, RecognizerError> {
+    let engine = TractEngine::from_backend(backend)?;
+    log::info!(
+        "handpose tract backend ready using {} ({}) and palm detector {} (CPU)",
+        backend.handpose_estimator_model_path().display(),
+        engine.handpose_layout.label(),
+        backend.palm_detector_model_path().display()
+    );
+    Ok(engine)
+}
+
+/// See [`RecognizerBackend::check_custom_models`].
+pub(super) fn check_custom_models(backend: &RecognizerBackend) -> Result<(), RecognizerError> {
+    let handpose_path = backend.handpose_estimator_model_path();
+    if handpose_path != default_handpose_estimator_model_path() {
+        load_handpose(&handpose_path, &backend.handpose_output_names())?;
+    }
+    let palm_path = backend.palm_detector_model_path();
+    if palm_path != default_palm_detector_model_path() {
+        load_model(&palm_path, PALM_INPUT_SIZE)?;
+    }
+    Ok(())
+}
+
+/// Loads `path` with its image input fixed to one `size` x `size` frame, in
+/// the axis order the model declares.
+fn load_model(path: &Path, size: u32) -> Result<(TractPlan, TensorLayout), RecognizerError> {
+    let load = || -> Result<(TractPlan, TensorLayout)> {
+        let model = tract_onnx::onnx().model_for_path(path)?;
+        // Dynamic dims read as -1, as they do in an ORT session.
+        let declared: Vec<i64> = model
+            .input_fact(0)?
+            .shape
+            .dims()
+            .map(|dim| {
+                dim.concretize()
+                    .and_then(|dim| dim.to_i64().ok())
+                    .unwrap_or(-1)
+            })
+            .collect();
+        let layout = TensorLayout::of_shape(&declared);
+        let size = size as usize;
+        let shape = match layout {
+            TensorLayout::Nhwc => [1, size, size, 3],
+            TensorLayout::Nchw => [1, 3, size, size],
+        };
+        let plan = model
+            .with_input_fact(0, f32::fact(shape).into())?
+            .into_optimized()?
+            .into_runnable()?;
+        Ok((plan, layout))
+    };
+    load().map_err(|err| RecognizerError::load_failed(path, err))
+}
+
+fn load_handpose(
+    path: &Path,
+    names: &HandposeOutputNames,
+) -> Result<(TractPlan, TensorLayout, HandposeOutputMap), RecognizerError> {
+    let (plan, layout) = load_model(path, common::INPUT_SIZE)?;
+    let outputs = output_facts(&plan);
+    let outputs: Vec<(&str, Option<usize>)> = outputs
+        .iter()
+        .map(|(name, len)| (name.as_str(), *len))
+        .collect();
+    let map = HandposeOutputMap::resolve(names, &outputs).map_err(|source| {
+        RecognizerError::ModelLoadFailed {
+            path: path.to_path_buf(),
+            source: source.context("unrecognized handpose outputs"),
+        }
+    })?;
+    Ok((plan, layout, map))
+}
+
+/// Name and element count of every output, in model order.
+fn output_facts(plan: &TractPlan) -> Vec<(String, Option<usize>)> {
+    let model = plan.model();
+    let Ok(outlets) = model.output_outlets() else {
+        return Vec::new();
+    };
+    outlets
+        .iter()
+        .map(|&outlet| {
+            let name = model
+                .outlet_label(outlet)
+                .unwrap_or(model.node(outlet.node).name.as_str());
+            let len = model
+                .outlet_fact(outlet)
+                .ok()
+                .and_then(|fact| fact.shape.as_concrete().map(|dims| dims.iter().product()));
+            (name.to_string(), len)
+        })
+        .collect()
+}
+
+/// `input` is (N, H, W, 3) as the prepare functions build it.
+fn run_plan(plan: &TractPlan, layout: TensorLayout, input: Array4<f32>) -> Result<TVec<TValue>> {
+    let input = layout.arrange(input);
+    let values = input
+        .as_slice()
+        .ok_or_else(|| anyhow!("model input not contiguous"))?;
+    let tensor = TractTensor::from_shape(input.shape(), values)?;
+    plan.run(tvec!(tensor.into()))
+}
+
+/// The palm model on tract, decoded the same way as
+/// [`PalmDetector`](super::palm::PalmDetector) decodes it on ORT.
+struct TractPalmDetector {
+    plan: TractPlan,
+    layout: TensorLayout,
+    anchors: Vec<[f32; 2]>,
+    cfg: PalmDetectorConfig,
+}
+
+impl TractPalmDetector {
+    fn new(model_path: &Path, cfg: PalmDetectorConfig) -> Result<Self, RecognizerError> {
+        let (plan, layout) = load_model(model_path, PALM_INPUT_SIZE)?;
+        if layout != TensorLayout::Nhwc {
+            log::info!("palm detector takes {} input", layout.label());
+        }
+        Ok(Self {
+            plan,
+            layout,
+            anchors: ssd_anchors(PALM_INPUT_SIZE, &PALM_ANCHOR_STRIDES),
+            cfg,
+        })
+    }
+
+    fn detect(&self, frame: &Frame) -> Result<PalmDetection, RecognizerError> {
+        let candidates = self
+            .run(frame)
+            .map_err(|err| RecognizerError::from_anyhow(err, RecognizerError::PalmDetectFailed))?;
+        let regions = suppress(&candidates, &self.cfg);
+        Ok(PalmDetection {
+            regions,
+            candidates,
+        })
+    }
+
+    fn run(&self, frame: &Frame) -> Result<Vec<PalmRegion>> {
+        let (input, letterbox) = prepare_frame_with_size(frame, PALM_INPUT_SIZE)?;
+        let outputs = run_plan(&self.plan, self.layout, input)
+            .context("failed to run palm detector model")?;
+        if outputs.len() < 2 {
+            return Err(anyhow!(
+                "palm detector returned {} outputs, expected at least 2",
+                outputs.len()
+            ));
+        }
+
+        let (boxes, scores) = (&outputs[0], &outputs[1]);
+        Ok(decode_palm_outputs(
+            boxes.as_slice::<f32>()?,
+            boxes.shape(),
+            scores.as_slice::<f32>()?,
+            scores.shape(),
+            &self.anchors,
+            &letterbox,
+            &self.cfg,
+        )?)
+    }
+}
+
+/// The same palm detection, tracking and crops as the ORT engine, with both
+/// models run by tract. Handpose runs one crop per call, and the
+/// execution provider setting has no effect.
+pub struct TractEngine {
+    handpose: TractPlan,
+    handpose_layout: TensorLayout,
+    handpose_outputs: HandposeOutputMap,
+    output_names: HandposeOutputNames,
+    handpose_model_path: PathBuf,
+    palm_detector: TractPalmDetector,
+    palm_detector_model_path: PathBuf,
+    tracking: HandTracking,
+    scaler: InputScaler,
+}
+
+impl TractEngine {
+    /// Loads both models from the paths and palm config of `backend`; the
+    /// models must already be on disk.
+    pub fn from_backend(backend: &RecognizerBackend) -> Result<Self, RecognizerError> {
+        Self::new(
+            &backend.handpose_estimator_model_path(),
+            &backend.palm_detector_model_path(),
+            backend.palm_config(),
+            backend.handpose_output_names(),
+        )
+    }
+
+    fn new(
+        model_path: &Path,
+        palm_detector_model_path: &Path,
+        palm_config: PalmDetectorConfig,
+        output_names: HandposeOutputNames,
+    ) -> Result<Self, RecognizerError> {
+        let (handpose, handpose_layout, handpose_outputs) =
+            load_handpose(model_path, &output_names)?;
+        let palm_detector = TractPalmDetector::new(palm_detector_model_path, palm_config)?;
+
+        Ok(Self {
+            handpose,
+            handpose_layout,
+            handpose_outputs,
+            output_names,
+            handpose_model_path: model_path.to_path_buf(),
+            palm_detector,
+            palm_detector_model_path: palm_detector_model_path.to_path_buf(),
+            tracking: HandTracking::new(),
+            scaler: InputScaler::new(
+                palm_config.max_input_dimension,
+                palm_config.inference_budget,
+            ),
+        })
+    }
+
+    /// Handpose for every crop, one model call each. Hands come back in crop
+    /// order; the tracker is only touched once every call succeeded.
+    fn infer_hands(
+        &mut self,
+        frame: &Frame,
+        input: &Frame,
+        scale: FrameScale,
+        crops: &[HandCrop],
+        crop_prep: &mut Duration,
+    ) -> Result<Vec<HandLandmarks>> {
+        let mut raw = Vec::with_capacity(crops.len());
+        for crop in crops {
+            let prep_start = Instant::now();
+            let (tensor, transform) = common::prepare_rotated_crop(
+                input,
+                scale.to_scaled(crop.center),
+                scale.length_to_scaled(crop.side),
+                crop.angle,
+                common::INPUT_SIZE,
+            )?;
+            let transform = scale.crop_to_original(transform, (frame.width, frame.height));
+            *crop_prep += prep_start.elapsed();
+            raw.push((transform, self.run_handpose(tensor)?));
+        }
+
+        Ok(crops
+            .iter()
+            .zip(raw)
+            .filter_map(|(crop, (transform, raw))| self.tracking.finish_hand(crop, &transform, raw))
+            .collect())
+    }
+
+    fn run_handpose(&self, crop: Array4<f32>) -> Result<RawHand> {
+        let outputs = run_plan(&self.handpose, self.handpose_layout, crop)
+            .context("failed to run handpose model")?;
+        let map = self.handpose_outputs;
+        let landmarks = outputs
+            .get(map.landmarks)
+            .ok_or_else(|| anyhow!("model returned no landmark output"))?;
+        // First value of an output, or 0 when the model has no such output.
+        let scalar = |idx: usize| {
+            outputs
+                .get(idx)
+                .and_then(|value| value.as_slice::<f32>().ok()?.first().copied())
+                .unwrap_or(0.0)
+        };
+
+        Ok(RawHand {
+            landmarks: common::decode_landmarks(landmarks.as_slice::<f32>()?)?,
+            confidence: map.confidence.map_or(0.0, scalar),
+            handedness: map.handedness.map(scalar),
+        })
+    }
+}
+
+impl HandposeEngine for TractEngine {
+    fn set_execution_provider(
+        &mut self,
+        provider: ExecutionProvider,
+    ) -> Result<(), RecognizerError> {
+        if provider != ExecutionProvider::Cpu {
+            log::info!("tract runs on the CPU, {} is not used", provider.label());
+        }
+        Ok(())
+    }
+
+    fn set_palm_config(&mut self, config: PalmDetectorConfig) {
+        self.scaler
+            .configure(config.max_input_dimension, config.inference_budget);
+        self.palm_detector.cfg = config;
+    }
+
+    fn reload_models(&mut self) -> Result<(), RecognizerError> {
+        let (handpose, handpose_layout, handpose_outputs) =
+            load_handpose(&self.handpose_model_path, &self.output_names)?;
+        let palm_detector =
+            TractPalmDetector::new(&self.palm_detector_model_path, self.palm_detector.cfg)?;
+
+        self.handpose = handpose;
+        self.handpose_layout = handpose_layout;
+        self.handpose_outputs = handpose_outputs;
+        self.palm_detector = palm_detector;
+        self.tracking.reset();
+        log::info!(
+            "reloaded models: handpose {}, palm detector {}",
+            self.handpose_model_path.display(),
+            self.palm_detector_model_path.display()
+        );
+        Ok(())
+    }
+
+    fn infer(&mut self, frame: &Frame) -> Result<HandposeOutput, RecognizerError> {
+        let infer_start = Instant::now();
+        let scaled = self.scaler.downscale(frame).unwrap_or_else(|err| {
+            log::warn!("input downscale failed, using the full frame: {err:?}");
+            None
+        });
+        let (input, scale) = match &scaled {
+            Some((scaled, scale)) => (scaled, *scale),
+            None => (frame, FrameScale::IDENTITY),
+        };
+
+        let palm_config = self.palm_detector.cfg;
+        let detect = self.tracking.should_detect(&palm_config);
+        let palm_start = Instant::now();
+        let in_roi = |point| roi_contains(palm_config.roi, point, frame.width, frame.height);
+        let mut palm_debug = palm_config.keep_candidates.then(PalmDebug::default);
+        let (palm_regions, palm_error) = if detect {
+            let detection = self.palm_detector.detect(input);
+            frame_palm_regions(detection, scale, in_roi, &mut palm_debug)
+        } else {
+            (Vec::new(), None)
+        };
+        let palm_time = detect.then(|| palm_start.elapsed());
+        let crops = self
+            .tracking
+            .plan_crops(&palm_regions, &palm_config, in_roi, &mut palm_debug);
+
+        let handpose_start = Instant::now();
+        let mut crop_prep = Duration::ZERO;
+        let hands = self
+            .infer_hands(frame, input, scale, &crops, &mut crop_prep)
+            .map_err(|err| RecognizerError::from_anyhow(err, RecognizerError::InferenceFailed))?;
+        self.tracking.end_frame(&hands, &palm_config);
+        let handpose_time = handpose_start.elapsed();
+        self.scaler.observe(infer_start.elapsed());
+
+        Ok(HandposeOutput {
+            hands,
+            palm_regions,
+            palm_debug,
+            timings: InferenceTimings {
+                palm_detect: palm_time,
+                crop_prep,
+                handpose: handpose_time,
+            },
+            input_size: (input.width, input.height),
+            palm_error,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PixelLayout;
+
+    fn blank_frame() -> Frame {
+        let now = Instant::now();
+        Frame {
+            pixels: vec![0; PixelLayout::Rgb.buffer_len(640, 480)].into(),
+            layout: PixelLayout::Rgb,
+            width: 640,
+            height: 480,
+            timestamp: now,
+            acquired_at: now,
+        }
+    }
+
+    #[test]
+    fn a_corrupt_replacement_model_keeps_the_loaded_engine() {
+        let models = Path::new(env!("CARGO_MANIFEST_DIR")).join("models");
+        let dir = std::env::temp_dir().join(format!("gu-tract-reload-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (handpose, palm) = (dir.join("handpose.onnx"), dir.join("palm.onnx"));
+        std::fs::copy(models.join("handpose_estimation.onnx"), &handpose).unwrap();
+        std::fs::copy(models.join("palm_detection.onnx"), &palm).unwrap();
+
+        let mut engine = TractEngine::new(
+            &handpose,
+            &palm,
+            PalmDetectorConfig::default(),
+            HandposeOutputNames::default(),
+        )
+        .unwrap();
+        assert!(engine.infer(&blank_frame()).unwrap().hands.is_empty());
+
+        std::fs::write(&handpose, b"not an onnx model").unwrap();
+        let err = engine.reload_models().unwrap_err();
+        assert_eq!(err.model_path(), Some(handpose.as_path()));
+        // Still running on the models loaded before.
+        engine.infer(&blank_frame()).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,324 @@
+use std::{
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result, anyhow};
+use crossbeam_channel::Receiver;
+use image::{ExtendedColorType, codecs::jpeg::JpegEncoder};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    pipeline::delivery::{Delivery, DeliveryMode},
+    types::{GestureResult, RecognizedFrame},
+};
+
+pub const SESSION_INDEX_FILENAME: &str = "index.ndjson";
+const JPEG_QUALITY: u8 = 85;
+/// Frames waiting for the writer before the oldest are given up.
+const FRAME_QUEUE: usize = 4;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecorderSettings {
+    /// Parent directory; every recording gets its own `session-<unix secs>` folder.
+    pub root: PathBuf,
+    /// Frames arriving faster than this are skipped.
+    pub max_fps: f32,
+    /// Recording stops by itself once the session reaches this many bytes.
+    pub max_bytes: u64,
+}
+
+impl Default for RecorderSettings {
+    fn default() -> Self {
+        Self {
+            root: PathBuf::from("recordings"),
+            max_fps: 10.0,
+            max_bytes: 1024 * 1024 * 1024,
+        }
+    }
+}
+
+/// One line of a session's `index.ndjson`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecordedFrame {
+    /// JPEG file name relative to the session directory.
+    pub image: String,
+    pub width: u32,
+    pub height: u32,
+    pub result: GestureResult,
+}
+
+#[derive(Clone, Debug)]
+pub struct RecordingStatus {
+    pub dir: PathBuf,
+    pub frames: u64,
+    pub bytes: u64,
+    /// Set once the disk budget ran out; nothing more is written.
+    pub full: bool,
+}
+
+struct Counters {
+    frames: AtomicU64,
+    bytes: AtomicU64,
+    full: AtomicBool,
+}
+
+struct ActiveSession {
+    dir: PathBuf,
+    frame_tx: Delivery<RecognizedFrame>,
+    counters: Arc<Counters>,
+    last_accepted: Option<Instant>,
+    handle: thread::JoinHandle<()>,
+}
+
+/// Writes recognized frames to disk while a session is running. Cheap to
+/// clone; the recognizer offers every frame and the UI starts and stops
+/// sessions on the same handle.
+#[derive(Clone, Default)]
+pub struct Recorder {
+    settings: Arc<Mutex<RecorderSettings>>,
+    active: Arc<Mutex<Option<ActiveSession>>>,
+    disabled: Arc<AtomicBool>,
+}
+
+impl std::fmt::Debug for Recorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Recorder")
+            .field("recording", &self.is_recording())
+            .finish()
+    }
+}
+
+impl Recorder {
+    pub fn with_settings(settings: RecorderSettings) -> Self {
+        Self {
+            settings: Arc::new(Mutex::new(settings)),
+            active: Arc::default(),
+            disabled: Arc::default(),
+        }
+    }
+
+    /// The frame rate applies from the next frame, the directory and the
+    /// budget from the next session.
+    pub fn set_settings(&self, settings: RecorderSettings) {
+        *lock(&self.settings) = settings;
+    }
+
+    pub fn settings(&self) -> RecorderSettings {
+        lock(&self.settings).clone()
+    }
+
+    /// While disabled, as in privacy mode, no session starts; turning it on
+    /// finishes the running one.
+    pub fn set_disabled(&self, disabled: bool) {
+        self.disabled.store(disabled, Ordering::Relaxed);
+        if disabled {
+            self.stop();
+        }
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.disabled.load(Ordering::Relaxed)
+    }
+
+    pub fn is_recording(&self) -> bool {
+        lock(&self.active).is_some()
+    }
+
+    pub fn status(&self) -> Option<RecordingStatus> {
+        lock(&self.active).as_ref().map(|session| RecordingStatus {
+            dir: session.dir.clone(),
+            frames: session.counters.frames.load(Ordering::Relaxed),
+            bytes: session.counters.bytes.load(Ordering::Relaxed),
+            full: session.counters.full.load(Ordering::Relaxed),
+        })
+    }
+
+    /// Creates a new session directory and starts writing into it.
+    pub fn start(&self) -> Result<PathBuf> {
+        let mut active = lock(&self.active);
+        if self.is_disabled() {
+            return Err(anyhow!("recording is disabled"));
+        }
+        if let Some(session) = active.as_ref() {
+            return Err(anyhow!("already recording to {}", session.dir.display()));
+        }
+
+        let settings = lock(&self.settings).clone();
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let dir = settings.root.join(format!("session-{stamp}"));
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("failed to create recording directory {}", dir.display()))?;
+        let index_path = dir.join(SESSION_INDEX_FILENAME);
+        let index = File::create(&index_path)
+            .with_context(|| format!("failed to create {}", index_path.display()))?;
+
+        let counters = Arc::new(Counters {
+            frames: AtomicU64::new(0),
+            bytes: AtomicU64::new(0),
+            full: AtomicBool::new(false),
+        });
+        // A few frames of slack; JPEG encoding is the slow part, and when it
+        // falls behind the oldest queued frames go unrecorded so the session
+        // keeps up with the camera.
+        let (frame_tx, frame_rx) = DeliveryMode::Lossless {
+            capacity: FRAME_QUEUE,
+        }
+        .channel();
+        let handle = {
+            let dir = dir.clone();
+            let counters = counters.clone();
+            thread::spawn(move || {
+                writer_loop(&dir, frame_rx, index, settings, &counters);
+            })
+        };
+
+        log::info!("recording session to {}", dir.display());
+        *active = Some(ActiveSession {
+            dir: dir.clone(),
+            frame_tx,
+            counters,
+            last_accepted: None,
+            handle,
+        });
+        Ok(dir)
+    }
+
+    /// Finishes the current session, waiting for queued frames to be written.
+    pub fn stop(&self) -> Option<RecordingStatus> {
+        let session = lock(&self.active).take()?;
+        let ActiveSession {
+            dir,
+            frame_tx,
+            counters,
+            handle,
+            ..
+        } = session;
+        let dropped = frame_tx.dropped();
+        drop(frame_tx);
+        let _ = handle.join();
+        if dropped > 0 {
+            log::warn!("recording fell behind and skipped {dropped} frames");
+        }
+
+        let status = RecordingStatus {
+            dir,
+            frames: counters.frames.load(Ordering::Relaxed),
+            bytes: counters.bytes.load(Ordering::Relaxed),
+            full: counters.full.load(Ordering::Relaxed),
+        };
+        log::info!(
+            "recorded {} frames ({:.1} MB) to {}",
+            status.frames,
+            status.bytes as f64 / 1024.0 / 1024.0,
+            status.dir.display()
+        );
+        Some(status)
+    }
+
+    /// Called by the recognizer for every frame. Only clones the frame when a
+    /// session is running and the frame-rate cap allows it.
+    pub fn offer(&self, recognized: &RecognizedFrame) {
+        let mut active = lock(&self.active);
+        let Some(session) = active.as_mut() else {
+            return;
+        };
+        if session.counters.full.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let max_fps = lock(&self.settings).max_fps.max(0.1);
+        let min_interval = Duration::from_secs_f32(1.0 / max_fps);
+        let timestamp = recognized.frame.timestamp;
+        if let Some(last) = session.last_accepted
+            && timestamp.saturating_duration_since(last) < min_interval
+        {
+            return;
+        }
+
+        session.frame_tx.deliver(recognized.clone());
+        session.last_accepted = Some(timestamp);
+    }
+}
+
+fn writer_loop(
+    dir: &Path,
+    frame_rx: Receiver<RecognizedFrame>,
+    index: File,
+    settings: RecorderSettings,
+    counters: &Counters,
+) {
+    let mut index = BufWriter::new(index);
+    while let Ok(recognized) = frame_rx.recv() {
+        match write_frame(dir, &recognized, &mut index) {
+            Ok(written) => {
+                counters.frames.fetch_add(1, Ordering::Relaxed);
+                let total = counters.bytes.fetch_add(written, Ordering::Relaxed) + written;
+                if total >= settings.max_bytes {
+                    log::warn!(
+                        "recording {} reached its {} byte budget, no longer writing",
+                        dir.display(),
+                        settings.max_bytes
+                    );
+                    counters.full.store(true, Ordering::Relaxed);
+                    break;
+                }
+            }
+            Err(err) => log::warn!("failed to record frame: {err:#}"),
+        }
+    }
+}
+
+/// Returns the number of bytes added to the session.
+fn write_frame(
+    dir: &Path,
+    recognized: &RecognizedFrame,
+    index: &mut BufWriter<File>,
+) -> Result<u64> {
+    let frame = &recognized.frame;
+    let image = format!("frame-{:06}.jpg", recognized.result.frame_id);
+
+    // JPEG has no alpha channel.
+    let rgb = frame.to_rgb();
+    let mut jpeg = Vec::new();
+    JpegEncoder::new_with_quality(&mut jpeg, JPEG_QUALITY)
+        .encode(&rgb, frame.width, frame.height, ExtendedColorType::Rgb8)
+        .context("failed to encode frame")?;
+    let image_path = dir.join(&image);
+    fs::write(&image_path, &jpeg)
+        .with_context(|| format!("failed to write {}", image_path.display()))?;
+
+    let entry = RecordedFrame {
+        image,
+        width: frame.width,
+        height: frame.height,
+        result: recognized.result.clone(),
+    };
+    let mut line = serde_json::to_string(&entry)?;
+    line.push('\n');
+    // Flushed per frame so the index stays usable if the app is killed mid
+    // session.
+    index
+        .write_all(line.as_bytes())
+        .and_then(|_| index.flush())
+        .context("failed to append to recording index")?;
+
+    Ok((jpeg.len() + line.len()) as u64)
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
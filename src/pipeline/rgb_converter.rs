@@ -0,0 +1,193 @@
+use std::{convert::TryFrom, sync::Arc};
+
+use crate::types::{FrameBuffer, FramePool, PixelLayout};
+use anyhow::{Result, anyhow};
+use nokhwa::{Buffer, utils::FrameFormat};
+use rayon::prelude::*;
+use yuv::{
+    YuvBiPlanarImage, YuvConversionMode, YuvPackedImage, YuvRange, YuvStandardMatrix,
+    yuv_nv12_to_rgb, yuyv422_to_rgb,
+};
+use zune_jpeg::{
+    JpegDecoder,
+    zune_core::{bytestream::ZCursor, colorspace::ColorSpace, options::DecoderOptions},
+};
+
+#[derive(Debug)]
+pub struct RgbFrame {
+    pub rgb: FrameBuffer,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Decodes into a buffer from `pool`, so steady capture does not allocate.
+/// The models only read RGB, so no alpha is added.
+pub fn convert_camera_frame(frame: &Buffer, pool: &Arc<FramePool>) -> Result<RgbFrame> {
+    let resolution = frame.resolution();
+    let width = resolution.width_x;
+    let height = resolution.height_y;
+    let data = frame.buffer();
+
+    let convert: fn(&[u8], u32, u32, &mut [u8]) -> Result<()> = match frame.source_frame_format() {
+        // The decoded size comes from the JPEG header, not the negotiated format.
+        FrameFormat::MJPEG => return mjpeg_to_rgb(data, pool),
+        FrameFormat::NV12 => nv12_to_rgb,
+        FrameFormat::YUYV => yuyv_to_rgb,
+        FrameFormat::RAWRGB => raw_rgb_to_rgb,
+        FrameFormat::RAWBGR => raw_bgr_to_rgb,
+        FrameFormat::GRAY => gray_to_rgb,
+    };
+    let mut rgb = pool.take(PixelLayout::Rgb.buffer_len(width, height));
+    convert(data, width, height, &mut rgb)?;
+
+    Ok(RgbFrame { rgb, width, height })
+}
+
+/// Flips a buffer of `layout` pixels horizontally in place.
+pub fn mirror_pixels(pixels: &mut [u8], width: u32, layout: PixelLayout) {
+    let channels = layout.channels();
+    let row_len = width as usize * channels;
+    if row_len == 0 {
+        return;
+    }
+    pixels.par_chunks_exact_mut(row_len).for_each(|row| {
+        row.reverse();
+        for pixel in row.chunks_exact_mut(channels) {
+            pixel.reverse();
+        }
+    });
+}
+
+fn nv12_to_rgb(data: &[u8], width: u32, height: u32, rgb: &mut [u8]) -> Result<()> {
+    let y_plane_len = width as usize * height as usize;
+    let uv_plane_len = y_plane_len / 2;
+
+    if data.len() < y_plane_len + uv_plane_len {
+        return Err(anyhow!(
+            "NV12 buffer too small: got {}, expected {}",
+            data.len(),
+            y_plane_len + uv_plane_len
+        ));
+    }
+
+    let y_plane = &data[..y_plane_len];
+    let uv_plane = &data[y_plane_len..y_plane_len + uv_plane_len];
+
+    let image = YuvBiPlanarImage {
+        y_plane,
+        y_stride: width,
+        uv_plane,
+        uv_stride: width,
+        width,
+        height,
+    };
+
+    yuv_nv12_to_rgb(
+        &image,
+        rgb,
+        width * 3,
+        YuvRange::Full,
+        YuvStandardMatrix::Bt709,
+        YuvConversionMode::Balanced,
+    )
+    .map_err(|err| anyhow!("NV12→RGB failed: {err:?}"))
+}
+
+fn yuyv_to_rgb(data: &[u8], width: u32, height: u32, rgb: &mut [u8]) -> Result<()> {
+    let expected_len = width as usize * height as usize * 2;
+    if data.len() < expected_len {
+        return Err(anyhow!(
+            "YUYV buffer too small: got {}, expected {}",
+            data.len(),
+            expected_len
+        ));
+    }
+
+    let packed = YuvPackedImage {
+        yuy: data,
+        yuy_stride: width * 2,
+        width,
+        height,
+    };
+
+    yuyv422_to_rgb(
+        &packed,
+        rgb,
+        width * 3,
+        YuvRange::Full,
+        YuvStandardMatrix::Bt709,
+    )
+    .map_err(|err| anyhow!("YUYV422→RGB failed: {err:?}"))
+}
+
+fn mjpeg_to_rgb(data: &[u8], pool: &Arc<FramePool>) -> Result<RgbFrame> {
+    let options = DecoderOptions::default().jpeg_set_out_colorspace(ColorSpace::RGB);
+    let mut decoder = JpegDecoder::new_with_options(ZCursor::new(data), options);
+    decoder
+        .decode_headers()
+        .map_err(|err| anyhow!("MJPEG header decode failed: {err:?}"))?;
+    let (width, height) = decoder
+        .dimensions()
+        .ok_or_else(|| anyhow!("MJPEG frame has no dimensions"))?;
+    let len = decoder
+        .output_buffer_size()
+        .ok_or_else(|| anyhow!("MJPEG dimensions do not fit usize"))?;
+
+    let mut rgb = pool.take(len);
+    decoder
+        .decode_into(&mut rgb)
+        .map_err(|err| anyhow!("MJPEG decode failed: {err:?}"))?;
+
+    Ok(RgbFrame {
+        rgb,
+        width: u32::try_from(width)?,
+        height: u32::try_from(height)?,
+    })
+}
+
+fn raw_rgb_to_rgb(data: &[u8], width: u32, height: u32, rgb: &mut [u8]) -> Result<()> {
+    let src = rgb_source(data, width, height)?;
+    rgb.copy_from_slice(src);
+    Ok(())
+}
+
+fn raw_bgr_to_rgb(data: &[u8], width: u32, height: u32, rgb: &mut [u8]) -> Result<()> {
+    let src = rgb_source(data, width, height)?;
+    rgb.par_chunks_exact_mut(3)
+        .zip(src.par_chunks_exact(3))
+        .for_each(|(dst, src)| {
+            dst[0] = src[2];
+            dst[1] = src[1];
+            dst[2] = src[0];
+        });
+    Ok(())
+}
+
+/// The first frame's worth of three-byte pixels in `data`.
+fn rgb_source(data: &[u8], width: u32, height: u32) -> Result<&[u8]> {
+    let expected_len = PixelLayout::Rgb.buffer_len(width, height);
+    data.get(..expected_len).ok_or_else(|| {
+        anyhow!(
+            "RGB buffer too small: got {}, expected {}",
+            data.len(),
+            expected_len
+        )
+    })
+}
+
+fn gray_to_rgb(data: &[u8], width: u32, height: u32, rgb: &mut [u8]) -> Result<()> {
+    let expected_len = width as usize * height as usize;
+    if data.len() < expected_len {
+        return Err(anyhow!(
+            "GRAY buffer too small: got {}, expected {}",
+            data.len(),
+            expected_len
+        ));
+    }
+
+    rgb.par_chunks_mut(3)
+        .zip(data.par_iter().copied())
+        .for_each(|(dst, value)| dst.fill(value));
+
+    Ok(())
+}
@@ -1,3 +1,17 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use swash::{
+    FontRef,
+    scale::{Render, ScaleContext, Source},
+};
+
+use crate::{
+    locale::{Locale, locale},
+    types::PixelLayout,
+};
+
 pub const CONNECTIONS: &[(usize, usize)] = &[
     (0, 1),
     (1, 2),
@@ -24,26 +38,253 @@ pub const CONNECTIONS: &[(usize, usize)] = &[
     (13, 17),
 ];
 
-
 const PALM_BOX_THICKNESS: i32 = 6;
 const PALM_SCORE_THRESHOLD: f32 = 0.25;
+/// Fraction of the box added on every side before pixelating, so fingertips
+/// the landmarks fall short of stay covered.
+const CENSOR_MARGIN: f32 = 0.3;
+/// Pixelation cells across the longer side of a censored box.
+const CENSOR_CELLS: f32 = 12.0;
+const CENSOR_MIN_CELL: u32 = 8;
+/// Dwell ring radius and stroke as fractions of the hand span.
+const DWELL_RING_RADIUS: f32 = 0.3;
+const DWELL_RING_THICKNESS: f32 = 0.04;
+/// Line segments in a full ring.
+const DWELL_RING_SEGMENTS: f32 = 64.0;
+/// Alpha of the unfilled part of the ring.
+const DWELL_TRACK_ALPHA: u8 = 70;
+/// Kept thin and faint so the zone does not compete with the hands.
+const ROI_THICKNESS: i32 = 2;
+/// Pointing ray length, in hand spans, for a finger lying flat in the image;
+/// a finger aimed at the camera shortens it down to the minimum.
+const POINTING_RAY_LENGTH: f32 = 2.0;
+const POINTING_RAY_MIN_LENGTH: f32 = 0.3;
+/// Debug overlay: palm candidates before NMS, the regions NMS kept, those
+/// cropped for the landmark model, and crops carried over from tracking.
+const DEBUG_CANDIDATE_COLOR: [u8; 4] = [148, 163, 184, 150];
+const DEBUG_KEPT_COLOR: [u8; 4] = [56, 189, 248, 230];
+const DEBUG_SELECTED_COLOR: [u8; 4] = [250, 204, 21, 255];
+const DEBUG_TRACKED_COLOR: [u8; 4] = [244, 114, 182, 230];
+/// Score digit height as a fraction of the frame height.
+const DEBUG_SCORE_HEIGHT: f32 = 0.025;
+const DEBUG_SCORE_MIN_HEIGHT: f32 = 9.0;
+/// Length of each dash, and of each gap, in a tracked crop's outline.
+const DEBUG_DASH: f32 = 8.0;
+/// Burned-in label text size as a fraction of the frame height.
+const LABEL_TEXT_RATIO: f32 = 0.045;
+const LABEL_MIN_TEXT: f32 = 12.0;
+const LABEL_BACKGROUND: [u8; 4] = [0, 0, 0, 150];
+const LABEL_TEXT: [u8; 3] = [255, 255, 255];
+/// Photo booth countdown digit height as a fraction of the frame height.
+const COUNTDOWN_HEIGHT: f32 = 0.3;
+/// Seven-segment stroke as a fraction of the digit height.
+const DIGIT_STROKE: f32 = 0.12;
+const COUNTDOWN_PLATE: [u8; 4] = [0, 0, 0, 120];
+const COUNTDOWN_COLOR: [u8; 4] = [255, 255, 255, 255];
+const CANCELLED_COLOR: [u8; 4] = [239, 68, 68, 255];
+/// Seven-segment bars lit for each digit, bit 0 the top bar and then
+/// clockwise, with the middle bar last.
+const DIGIT_SEGMENTS: [u8; 10] = [0x3f, 0x06, 0x5b, 0x4f, 0x66, 0x6d, 0x7d, 0x07, 0x7f, 0x6f];
+/// Fonts with the CJK gesture names, tried in order when none is configured.
+/// Nothing is embedded since a CJK face would be most of the binary.
+const CJK_FONT_CANDIDATES: &[&str] = &[
+    "/System/Library/Fonts/PingFang.ttc",
+    "/System/Library/Fonts/Hiragino Sans GB.ttc",
+    "/System/Library/Fonts/STHeiti Medium.ttc",
+    "C:\\Windows\\Fonts\\msyh.ttc",
+    "C:\\Windows\\Fonts\\simhei.ttf",
+    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/truetype/wqy/wqy-microhei.ttc",
+];
 
 pub const DRAW_PALM_BBOX: bool = false;
 pub const DRAW_ENLARGED_BOX: bool = false;
 pub const DRAW_ROTATED_BOX: bool = false;
 
-pub fn draw_skeleton(buffer: &mut [u8], width: u32, height: u32, points: &[(f32, f32)]) {
+/// What the overlay draws over the camera feed. The compositor and the UI both
+/// draw hands through [`draw_hand`], so the two cannot disagree.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlayMode {
+    /// A clean feed, e.g. for screen recordings.
+    None,
+    SkeletonOnly,
+    BoxesOnly,
+    /// Palm boxes and skeletons.
+    #[default]
+    Full,
+    /// Joints without bones.
+    LandmarkDots,
+    /// Skeletons plus how each palm was found: every detector candidate with
+    /// its score, what NMS kept, what was cropped and what was tracked.
+    Debug,
+}
+
+impl OverlayMode {
+    /// Cycle order of the shortcut and the settings button.
+    pub const ALL: [OverlayMode; 6] = [
+        OverlayMode::Full,
+        OverlayMode::SkeletonOnly,
+        OverlayMode::LandmarkDots,
+        OverlayMode::BoxesOnly,
+        OverlayMode::Debug,
+        OverlayMode::None,
+    ];
+
+    pub fn label(self) -> &'static str {
+        self.label_in(locale())
+    }
+
+    pub fn label_in(self, locale: Locale) -> &'static str {
+        let (zh, en) = match self {
+            OverlayMode::None => ("无", "None"),
+            OverlayMode::SkeletonOnly => ("仅骨架", "Skeleton only"),
+            OverlayMode::BoxesOnly => ("仅手掌框", "Palm boxes only"),
+            OverlayMode::Full => ("全部", "Everything"),
+            OverlayMode::LandmarkDots => ("仅关键点", "Landmarks only"),
+            OverlayMode::Debug => ("调试", "Debug"),
+        };
+        locale.pick(zh, en)
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|mode| *mode == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    pub fn draws_boxes(self) -> bool {
+        matches!(
+            self,
+            OverlayMode::BoxesOnly | OverlayMode::Full | OverlayMode::Debug
+        )
+    }
+}
+
+/// Colors and sizes used to draw hands. Sizes are fractions of the hand span
+/// so the overlay scales with the hand.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SkeletonStyle {
+    /// Bone colors, thumb through pinky.
+    pub finger_colors: [[u8; 4]; 5],
+    /// Bones across the knuckles.
+    pub palm_color: [u8; 4],
+    pub joint_color: [u8; 4],
+    /// Ring drawn around every joint.
+    pub outline_color: Option<[u8; 4]>,
+    pub bone_thickness: f32,
+    pub joint_radius: f32,
+    /// Palm box and palm keypoints drawn by [`draw_palm_regions`].
+    pub region_color: [u8; 4],
+    pub region_point_color: [u8; 4],
+    /// Progress ring drawn by [`draw_dwell_ring`].
+    pub dwell_color: [u8; 4],
+    /// Recognition zone drawn by [`draw_roi`].
+    pub roi_color: [u8; 4],
+    /// Ray drawn by [`draw_pointing_ray`].
+    pub pointing_color: [u8; 4],
+}
+
+impl Default for SkeletonStyle {
+    fn default() -> Self {
+        let line_color = [34u8, 197u8, 94u8, 255u8];
+        Self {
+            finger_colors: [line_color; 5],
+            palm_color: line_color,
+            joint_color: [248, 113, 113, 255],
+            outline_color: Some(line_color),
+            bone_thickness: 0.0125,
+            joint_radius: 0.02,
+            region_color: [16, 185, 129, 200],
+            region_point_color: [244, 114, 182, 230],
+            dwell_color: [56, 189, 248, 255],
+            roi_color: [255, 255, 255, 90],
+            pointing_color: [250, 204, 21, 230],
+        }
+    }
+}
+
+impl SkeletonStyle {
+    /// One hue per finger on white joints with a dark outline, readable on
+    /// bright footage too.
+    pub fn per_finger() -> Self {
+        Self {
+            finger_colors: [
+                [249, 115, 22, 255],
+                [250, 204, 21, 255],
+                [34, 197, 94, 255],
+                [56, 189, 248, 255],
+                [167, 139, 250, 255],
+            ],
+            palm_color: [226, 232, 240, 255],
+            joint_color: [255, 255, 255, 255],
+            outline_color: Some([15, 23, 42, 255]),
+            ..Self::default()
+        }
+    }
+
+    /// Fades the whole skeleton for hands the model is unsure about.
+    pub fn for_confidence(&self, confidence: f32) -> Self {
+        const FULL_CONFIDENCE: f32 = 0.7;
+        let factor = (confidence / FULL_CONFIDENCE).clamp(0.2, 1.0);
+        let fade = |[r, g, b, a]: [u8; 4]| [r, g, b, (a as f32 * factor) as u8];
+        Self {
+            finger_colors: self.finger_colors.map(fade),
+            palm_color: fade(self.palm_color),
+            joint_color: fade(self.joint_color),
+            outline_color: self.outline_color.map(fade),
+            ..*self
+        }
+    }
+
+    /// The same colors for drawing straight into a BGRA buffer.
+    pub fn for_bgra(&self) -> Self {
+        let swap = |[r, g, b, a]: [u8; 4]| [b, g, r, a];
+        Self {
+            finger_colors: self.finger_colors.map(swap),
+            palm_color: swap(self.palm_color),
+            joint_color: swap(self.joint_color),
+            outline_color: self.outline_color.map(swap),
+            region_color: swap(self.region_color),
+            region_point_color: swap(self.region_point_color),
+            dwell_color: swap(self.dwell_color),
+            roi_color: swap(self.roi_color),
+            pointing_color: swap(self.pointing_color),
+            ..*self
+        }
+    }
+
+    fn bone_color(&self, a: usize, b: usize) -> [u8; 4] {
+        let finger = |idx: usize| (idx.max(1) - 1) / 4;
+        if a != 0 && finger(a) != finger(b) {
+            self.palm_color
+        } else {
+            self.finger_colors[finger(b).min(4)]
+        }
+    }
+}
+
+/// Bones and joints of one hand. When `depths` is given the joints are colored
+/// by their z value relative to the rest of the hand: warm is near, cool is far.
+pub fn draw_skeleton_with_depth(
+    buffer: &mut [u8],
+    width: u32,
+    height: u32,
+    points: &[(f32, f32)],
+    depths: Option<&[f32]>,
+    style: &SkeletonStyle,
+) {
     if points.len() < 2 {
         return;
     }
 
     let hand_span = calculate_hand_span(points);
-    
-    let line_thickness = (hand_span * 0.0125).max(1.0) as i32;
-    
-    let radius_step = (hand_span * 0.006).max(1.0) as i32;
 
-    let line_color = [34u8, 197u8, 94u8, 255u8];
+    let line_thickness = (hand_span * style.bone_thickness).max(1.0) as i32;
+
+    let radius_step = (hand_span * style.joint_radius * 0.3).max(1.0) as i32;
+
     for &(a, b) in CONNECTIONS {
         if let (Some(pa), Some(pb)) = (points.get(a), points.get(b)) {
             draw_line(
@@ -52,28 +293,41 @@ pub fn draw_skeleton(buffer: &mut [u8], width: u32, height: u32, points: &[(f32,
                 height,
                 pa,
                 pb,
-                line_color,
+                style.bone_color(a, b),
                 line_thickness,
             );
         }
     }
 
-    let point_color = [248u8, 113u8, 113u8, 255u8];
-    let border_color = line_color;
+    let depth_range = depths.map(|depths| {
+        let min = depths.iter().copied().fold(f32::MAX, f32::min);
+        let max = depths.iter().copied().fold(f32::MIN, f32::max);
+        (min, (max - min).max(f32::EPSILON))
+    });
     for (i, &(x, y)) in points.iter().enumerate() {
+        let point_color = match (depths.and_then(|d| d.get(i)), depth_range) {
+            (Some(&z), Some((min, span))) => {
+                let mut color = depth_color((z - min) / span);
+                color[3] = style.joint_color[3];
+                color
+            }
+            _ => style.joint_color,
+        };
         let depth = get_point_depth(i);
-        let base_radius = (hand_span * 0.02).max(2.0) as i32;
+        let base_radius = (hand_span * style.joint_radius).max(2.0) as i32;
         let point_radius = (base_radius + depth * radius_step).max(2);
 
-        draw_circle(
-            buffer,
-            width,
-            height,
-            (x as i32, y as i32),
-            point_radius + line_thickness,
-            border_color,
-        );
-        
+        if let Some(outline_color) = style.outline_color {
+            draw_circle(
+                buffer,
+                width,
+                height,
+                (x as i32, y as i32),
+                point_radius + line_thickness,
+                outline_color,
+            );
+        }
+
         draw_circle(
             buffer,
             width,
@@ -85,26 +339,60 @@ pub fn draw_skeleton(buffer: &mut [u8], width: u32, height: u32, points: &[(f32,
     }
 }
 
+/// One hand as `mode` asks: the skeleton, its joints alone, or nothing.
+pub fn draw_hand(
+    buffer: &mut [u8],
+    width: u32,
+    height: u32,
+    points: &[(f32, f32)],
+    depths: Option<&[f32]>,
+    style: &SkeletonStyle,
+    mode: OverlayMode,
+) {
+    match mode {
+        OverlayMode::SkeletonOnly | OverlayMode::Full | OverlayMode::Debug => {
+            draw_skeleton_with_depth(buffer, width, height, points, depths, style);
+        }
+        OverlayMode::LandmarkDots => {
+            let radius = (calculate_hand_span(points) * style.joint_radius).max(2.0) as i32;
+            for &(x, y) in points {
+                let center = (x as i32, y as i32);
+                draw_circle(buffer, width, height, center, radius, style.joint_color);
+            }
+        }
+        OverlayMode::None | OverlayMode::BoxesOnly => {}
+    }
+}
+
+/// Maps 0 (nearest) .. 1 (farthest) onto a red → blue ramp.
+fn depth_color(t: f32) -> [u8; 4] {
+    let t = t.clamp(0.0, 1.0);
+    let near = [248.0, 113.0, 113.0];
+    let far = [56.0, 189.0, 248.0];
+    let mix = |i: usize| (near[i] + (far[i] - near[i]) * t) as u8;
+    [mix(0), mix(1), mix(2), 255]
+}
+
 fn calculate_hand_span(points: &[(f32, f32)]) -> f32 {
     if points.is_empty() {
-        return 100.0; 
+        return 100.0;
     }
-    
+
     let mut min_x = f32::MAX;
     let mut max_x = f32::MIN;
     let mut min_y = f32::MAX;
     let mut max_y = f32::MIN;
-    
+
     for &(x, y) in points {
         min_x = min_x.min(x);
         max_x = max_x.max(x);
         min_y = min_y.min(y);
         max_y = max_y.max(y);
     }
-    
+
     let width = max_x - min_x;
     let height = max_y - min_y;
-    
+
     width.max(height).max(100.0)
 }
 
@@ -115,12 +403,19 @@ fn get_point_depth(index: usize) -> i32 {
     (index as i32 - 1) % 4
 }
 
+/// The palm regions of a frame, and with `debug` how palm detection got to
+/// them.
 pub fn draw_palm_regions(
     buffer: &mut [u8],
     width: u32,
     height: u32,
     regions: &[crate::types::PalmRegion],
+    debug: Option<&crate::types::PalmDebug>,
+    style: &SkeletonStyle,
 ) {
+    if let Some(debug) = debug {
+        draw_palm_debug(buffer, width, height, regions, debug);
+    }
     for region in regions {
         if region.score < PALM_SCORE_THRESHOLD {
             continue;
@@ -128,7 +423,7 @@ pub fn draw_palm_regions(
 
         if DRAW_PALM_BBOX {
             let [x1, y1, x2, y2] = region.bbox;
-            let rect_color = [16u8, 185u8, 129u8, 200u8];
+            let rect_color = style.region_color;
             draw_rect(
                 buffer,
                 width,
@@ -141,7 +436,7 @@ pub fn draw_palm_regions(
                 PALM_BOX_THICKNESS,
             );
 
-            let point_color = [244u8, 114u8, 182u8, 230u8];
+            let point_color = style.region_point_color;
             for &(lx, ly) in &region.landmarks {
                 draw_circle(
                     buffer,
@@ -249,6 +544,417 @@ pub fn draw_palm_regions(
     }
 }
 
+/// Candidates as thin outlines with their scores in percent, what NMS kept
+/// over them, the selected regions thickest, and tracked crops dashed.
+fn draw_palm_debug(
+    buffer: &mut [u8],
+    width: u32,
+    height: u32,
+    regions: &[crate::types::PalmRegion],
+    debug: &crate::types::PalmDebug,
+) {
+    let digit_height = (height as f32 * DEBUG_SCORE_HEIGHT).max(DEBUG_SCORE_MIN_HEIGHT);
+    for candidate in &debug.candidates {
+        let [x1, y1, x2, y2] = candidate.bbox;
+        draw_rect(
+            buffer,
+            width,
+            height,
+            x1,
+            y1,
+            x2,
+            y2,
+            DEBUG_CANDIDATE_COLOR,
+            1,
+        );
+        let percent = (candidate.score * 100.0).round().clamp(0.0, 99.0) as u32;
+        let digit_width = digit_height * 0.5;
+        let top = y1 - digit_height - 2.0;
+        for (idx, digit) in [percent / 10, percent % 10].into_iter().enumerate() {
+            let left = x1 + idx as f32 * (digit_width + 2.0);
+            let bbox = [left, top, left + digit_width, top + digit_height];
+            draw_digit(buffer, width, height, bbox, digit, DEBUG_CANDIDATE_COLOR);
+        }
+    }
+    for (list, color, thickness) in [
+        (regions, DEBUG_KEPT_COLOR, 2),
+        (&debug.selected[..], DEBUG_SELECTED_COLOR, 4),
+    ] {
+        for region in list {
+            let [x1, y1, x2, y2] = region.bbox;
+            draw_rect(buffer, width, height, x1, y1, x2, y2, color, thickness);
+        }
+    }
+    for &(center, side, angle) in &debug.tracked {
+        let half = side / 2.0;
+        let (sin, cos) = angle.sin_cos();
+        let corners =
+            [(-half, -half), (half, -half), (half, half), (-half, half)].map(|(dx, dy)| {
+                (
+                    dx * cos - dy * sin + center.0,
+                    dx * sin + dy * cos + center.1,
+                )
+            });
+        for idx in 0..4 {
+            let (from, to) = (corners[idx], corners[(idx + 1) % 4]);
+            draw_dashed_line(buffer, width, height, from, to, DEBUG_TRACKED_COLOR, 2);
+        }
+    }
+}
+
+/// Progress ring centered on the wrist, filling clockwise from twelve o'clock
+/// over a faint full track. `points` are the hand's landmarks and set the size.
+pub fn draw_dwell_ring(
+    buffer: &mut [u8],
+    width: u32,
+    height: u32,
+    dwell: &crate::types::DwellProgress,
+    points: &[(f32, f32)],
+    style: &SkeletonStyle,
+) {
+    let span = calculate_hand_span(points);
+    let circle = (dwell.wrist, span * DWELL_RING_RADIUS);
+    let thickness = (span * DWELL_RING_THICKNESS).round().max(2.0) as i32;
+    let [r, g, b, _] = style.dwell_color;
+    let track = [r, g, b, DWELL_TRACK_ALPHA];
+    draw_arc(buffer, width, height, circle, 1.0, track, thickness);
+    let progress = dwell.progress.clamp(0.0, 1.0);
+    if progress > 0.0 {
+        draw_arc(
+            buffer,
+            width,
+            height,
+            circle,
+            progress,
+            style.dwell_color,
+            thickness,
+        );
+    }
+}
+
+/// Ray out of the index fingertip along `pointing`, foreshortened as the
+/// finger turns towards the camera. `points` are the hand's landmarks.
+pub fn draw_pointing_ray(
+    buffer: &mut [u8],
+    width: u32,
+    height: u32,
+    points: &[(f32, f32)],
+    pointing: &crate::types::PointingInfo,
+    style: &SkeletonStyle,
+) {
+    let Some(&tip) = points.get(8) else {
+        return;
+    };
+    let span = calculate_hand_span(points);
+    let flat = (1.0 - pointing.toward * pointing.toward).max(0.0).sqrt();
+    let length = span * (POINTING_RAY_LENGTH * flat).max(POINTING_RAY_MIN_LENGTH);
+    let angle = pointing.angle_deg.to_radians();
+    // Image y grows downwards.
+    let end = (tip.0 + angle.cos() * length, tip.1 - angle.sin() * length);
+    let thickness = (span * style.bone_thickness).round().max(1.0) as i32;
+    draw_line(
+        buffer,
+        width,
+        height,
+        &tip,
+        &end,
+        style.pointing_color,
+        thickness,
+    );
+    let radius = (span * style.joint_radius).round().max(2.0) as i32;
+    draw_circle(
+        buffer,
+        width,
+        height,
+        (end.0 as i32, end.1 as i32),
+        radius,
+        style.pointing_color,
+    );
+}
+
+/// A big seven-segment `digit` centered on a dim plate, so it reads without a
+/// font.
+pub fn draw_countdown(buffer: &mut [u8], width: u32, height: u32, digit: u32) {
+    let digit_height = height as f32 * COUNTDOWN_HEIGHT;
+    let (cx, cy) = (width as f32 * 0.5, height as f32 * 0.5);
+    let (left, right) = (cx - digit_height * 0.25, cx + digit_height * 0.25);
+    let (top, bottom) = (cy - digit_height * 0.5, cy + digit_height * 0.5);
+    let margin = digit_height * DIGIT_STROKE * 2.0;
+    let plate = [left - margin, top - margin, right + margin, bottom + margin];
+    fill_rect(buffer, width, height, plate, COUNTDOWN_PLATE);
+    let bbox = [left, top, right, bottom];
+    draw_digit(buffer, width, height, bbox, digit, COUNTDOWN_COLOR);
+}
+
+/// A seven-segment `digit` filling the `[x1, y1, x2, y2]` box.
+fn draw_digit(
+    buffer: &mut [u8],
+    width: u32,
+    height: u32,
+    bbox: [f32; 4],
+    digit: u32,
+    color: [u8; 4],
+) {
+    let [left, top, right, bottom] = bbox;
+    let stroke = ((bottom - top) * DIGIT_STROKE).max(1.0);
+    let (cy, half) = ((top + bottom) * 0.5, stroke * 0.5);
+    let bars = [
+        [left, top, right, top + stroke],
+        [right - stroke, top, right, cy + half],
+        [right - stroke, cy - half, right, bottom],
+        [left, bottom - stroke, right, bottom],
+        [left, cy - half, left + stroke, bottom],
+        [left, top, left + stroke, cy + half],
+        [left, cy - half, right, cy + half],
+    ];
+    let lit = DIGIT_SEGMENTS[digit.min(9) as usize];
+    for (idx, bar) in bars.into_iter().enumerate() {
+        if lit & (1 << idx) != 0 {
+            fill_rect(buffer, width, height, bar, color);
+        }
+    }
+}
+
+/// A red cross in the middle of the frame for a cancelled countdown.
+pub fn draw_cancelled(buffer: &mut [u8], width: u32, height: u32) {
+    let half = height as f32 * COUNTDOWN_HEIGHT * 0.35;
+    let (cx, cy) = (width as f32 * 0.5, height as f32 * 0.5);
+    let thickness = (half * 0.25).round().max(2.0) as i32;
+    let corners = [
+        ((cx - half, cy - half), (cx + half, cy + half)),
+        ((cx + half, cy - half), (cx - half, cy + half)),
+    ];
+    for (from, to) in corners {
+        draw_line(
+            buffer,
+            width,
+            height,
+            &from,
+            &to,
+            CANCELLED_COLOR,
+            thickness,
+        );
+    }
+}
+
+/// Lightens every pixel towards white by `strength`, 0 to 1.
+pub fn draw_flash(buffer: &mut [u8], width: u32, height: u32, strength: f32) {
+    let strength = strength.clamp(0.0, 1.0);
+    if strength <= 0.0 {
+        return;
+    }
+    for pixel in buffer.chunks_exact_mut(channels(buffer.len(), width, height)) {
+        for channel in &mut pixel[..3] {
+            *channel += ((255 - *channel) as f32 * strength) as u8;
+        }
+    }
+}
+
+/// Outlines `roi`, given as `[x1, y1, x2, y2]` fractions of the frame.
+pub fn draw_roi(buffer: &mut [u8], width: u32, height: u32, roi: [f32; 4], style: &SkeletonStyle) {
+    let [x1, y1, x2, y2] = roi;
+    let (w, h) = (width as f32, height as f32);
+    draw_rect(
+        buffer,
+        width,
+        height,
+        x1 * w,
+        y1 * h,
+        x2 * w,
+        y2 * h,
+        style.roi_color,
+        ROI_THICKNESS,
+    );
+}
+
+fn draw_arc(
+    buffer: &mut [u8],
+    width: u32,
+    height: u32,
+    (center, radius): ((f32, f32), f32),
+    fraction: f32,
+    color: [u8; 4],
+    thickness: i32,
+) {
+    let segments = (DWELL_RING_SEGMENTS * fraction).ceil().max(1.0) as usize;
+    let sweep = std::f32::consts::TAU * fraction;
+    let point = |step: usize| {
+        let angle = -std::f32::consts::FRAC_PI_2 + sweep * step as f32 / segments as f32;
+        (
+            center.0 + radius * angle.cos(),
+            center.1 + radius * angle.sin(),
+        )
+    };
+    for step in 0..segments {
+        draw_line(
+            buffer,
+            width,
+            height,
+            &point(step),
+            &point(step + 1),
+            color,
+            thickness,
+        );
+    }
+}
+
+/// Replaces `bbox`, grown by [`CENSOR_MARGIN`], with coarse blocks of its own
+/// average colors.
+pub fn pixelate_region(buffer: &mut [u8], width: u32, height: u32, bbox: [f32; 4]) {
+    let [x1, y1, x2, y2] = bbox;
+    let margin_x = (x2 - x1).abs() * CENSOR_MARGIN;
+    let margin_y = (y2 - y1).abs() * CENSOR_MARGIN;
+    let left = (x1.min(x2) - margin_x).max(0.0) as u32;
+    let top = (y1.min(y2) - margin_y).max(0.0) as u32;
+    let right = ((x1.max(x2) + margin_x).max(0.0) as u32).min(width);
+    let bottom = ((y1.max(y2) + margin_y).max(0.0) as u32).min(height);
+    let channels = channels(buffer.len(), width, height);
+    let frame_len = width as usize * height as usize * channels;
+    if left >= right || top >= bottom || buffer.len() < frame_len {
+        return;
+    }
+
+    let side = (right - left).max(bottom - top) as f32;
+    let cell = ((side / CENSOR_CELLS) as u32).max(CENSOR_MIN_CELL);
+    for cell_y in (top..bottom).step_by(cell as usize) {
+        let cell_bottom = (cell_y + cell).min(bottom);
+        for cell_x in (left..right).step_by(cell as usize) {
+            let cell_right = (cell_x + cell).min(right);
+            let mut sum = [0u32; 3];
+            for y in cell_y..cell_bottom {
+                let row = ((y * width + cell_x) as usize) * channels;
+                let end = ((y * width + cell_right) as usize) * channels;
+                for px in buffer[row..end].chunks_exact(channels) {
+                    for (total, value) in sum.iter_mut().zip(px) {
+                        *total += u32::from(*value);
+                    }
+                }
+            }
+            let count = (cell_right - cell_x) * (cell_bottom - cell_y);
+            let average = sum.map(|total| (total / count.max(1)) as u8);
+            for y in cell_y..cell_bottom {
+                let row = ((y * width + cell_x) as usize) * channels;
+                let end = ((y * width + cell_right) as usize) * channels;
+                for px in buffer[row..end].chunks_exact_mut(channels) {
+                    px[..3].copy_from_slice(&average);
+                }
+            }
+        }
+    }
+}
+
+/// Rasterizes label text into frames with a font read from disk.
+pub struct LabelPainter {
+    font_data: Vec<u8>,
+    context: ScaleContext,
+}
+
+impl LabelPainter {
+    /// Loads `path`, or the first of the usual system CJK fonts when `None`.
+    /// Collections (`.ttc`) use their first face.
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let font_data = match path {
+            Some(path) => fs::read(path)
+                .with_context(|| format!("failed to read label font {}", path.display()))?,
+            None => CJK_FONT_CANDIDATES
+                .iter()
+                .find_map(|candidate| fs::read(candidate).ok())
+                .ok_or_else(|| anyhow!("no CJK font found, set label_font_path"))?,
+        };
+        if FontRef::from_index(&font_data, 0).is_none() {
+            return Err(anyhow!("label font is not a TrueType or OpenType font"));
+        }
+        Ok(Self {
+            font_data,
+            context: ScaleContext::new(),
+        })
+    }
+
+    /// Draws `lines` in the top-left corner over a translucent box, with the
+    /// text sized to the frame height. Characters the font lacks, such as
+    /// emoji, are left out.
+    pub fn draw(&mut self, buffer: &mut [u8], width: u32, height: u32, lines: &[String]) {
+        let Some(font) = FontRef::from_index(&self.font_data, 0) else {
+            return;
+        };
+        let size = (height as f32 * LABEL_TEXT_RATIO).max(LABEL_MIN_TEXT);
+        let metrics = font.metrics(&[]).scale(size);
+        let advances = font.glyph_metrics(&[]).scale(size);
+        let charmap = font.charmap();
+
+        // Glyph ids with their pen offsets, and the width of each line.
+        let mut laid_out = Vec::with_capacity(lines.len());
+        for line in lines {
+            let mut glyphs = Vec::new();
+            let mut pen = 0.0;
+            for ch in line.chars() {
+                let id = charmap.map(ch);
+                if id == 0 {
+                    continue;
+                }
+                glyphs.push((id, pen));
+                pen += advances.advance_width(id);
+            }
+            laid_out.push((glyphs, pen));
+        }
+        let text_width = laid_out.iter().map(|(_, width)| *width).fold(0.0, f32::max);
+        if text_width <= 0.0 {
+            return;
+        }
+
+        let line_height = (metrics.ascent + metrics.descent + metrics.leading).ceil();
+        let padding = (size * 0.4).round();
+        let box_width = text_width + padding * 2.0;
+        let box_height = line_height * laid_out.len() as f32 + padding * 2.0;
+        fill_rect(
+            buffer,
+            width,
+            height,
+            [padding, padding, padding + box_width, padding + box_height],
+            LABEL_BACKGROUND,
+        );
+
+        let mut scaler = self.context.builder(font).size(size).hint(true).build();
+        let render = Render::new(&[Source::Outline]);
+        for (idx, (glyphs, _)) in laid_out.iter().enumerate() {
+            let baseline = padding * 2.0 + line_height * idx as f32 + metrics.ascent;
+            for &(id, pen) in glyphs {
+                let Some(image) = render.render(&mut scaler, id) else {
+                    continue;
+                };
+                let placement = image.placement;
+                if placement.width == 0 {
+                    continue;
+                }
+                let left = (padding * 2.0 + pen).round() as i32 + placement.left;
+                let top = baseline.round() as i32 - placement.top;
+                let rows = image.data.chunks_exact(placement.width as usize);
+                for (y, row) in (top..).zip(rows) {
+                    for (x, &coverage) in (left..).zip(row) {
+                        if coverage > 0 {
+                            let [r, g, b] = LABEL_TEXT;
+                            put_pixel_safe(buffer, width, height, x, y, [r, g, b, coverage]);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Blends `color` over the `[x1, y1, x2, y2]` box, clipped to the frame.
+fn fill_rect(buffer: &mut [u8], width: u32, height: u32, bbox: [f32; 4], color: [u8; 4]) {
+    let [x1, y1, x2, y2] = bbox.map(|value| value.round().max(0.0) as i32);
+    let right = x2.min(width as i32);
+    let bottom = y2.min(height as i32);
+    for y in y1..bottom {
+        for x in x1..right {
+            put_pixel_safe(buffer, width, height, x, y, color);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn draw_rect(
     buffer: &mut [u8],
     width: u32,
@@ -345,6 +1051,35 @@ fn draw_line(
     }
 }
 
+/// [`draw_line`] in dashes of [`DEBUG_DASH`] pixels.
+fn draw_dashed_line(
+    buffer: &mut [u8],
+    width: u32,
+    height: u32,
+    from: (f32, f32),
+    to: (f32, f32),
+    color: [u8; 4],
+    thickness: i32,
+) {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let pieces = ((dx.hypot(dy) / DEBUG_DASH).ceil() as usize).max(1);
+    let at = |step: usize| {
+        let t = (step as f32 / pieces as f32).min(1.0);
+        (from.0 + dx * t, from.1 + dy * t)
+    };
+    for step in (0..pieces).step_by(2) {
+        draw_line(
+            buffer,
+            width,
+            height,
+            &at(step),
+            &at(step + 1),
+            color,
+            thickness,
+        );
+    }
+}
+
 fn draw_circle(
     buffer: &mut [u8],
     width: u32,
@@ -371,8 +1106,28 @@ fn put_pixel_safe(buffer: &mut [u8], width: u32, height: u32, x: i32, y: i32, co
     if ux >= width || uy >= height {
         return;
     }
-    let idx = ((uy * width + ux) as usize) * 4;
-    if idx + 3 < buffer.len() {
-        buffer[idx..idx + 4].copy_from_slice(&color);
+    let channels = channels(buffer.len(), width, height);
+    let idx = ((uy * width + ux) as usize) * channels;
+    if idx + channels <= buffer.len() {
+        if color[3] == u8::MAX {
+            buffer[idx..idx + channels].copy_from_slice(&color[..channels]);
+        } else {
+            // Blend onto the frame; the frame itself stays opaque.
+            let alpha = color[3] as u16;
+            for c in 0..3 {
+                let dst = buffer[idx + c] as u16;
+                buffer[idx + c] = ((color[c] as u16 * alpha + dst * (255 - alpha)) / 255) as u8;
+            }
+        }
+    }
+}
+
+/// Bytes per pixel of a `width` by `height` buffer: three for RGB frames,
+/// four for RGBA and the BGRA preview.
+fn channels(len: usize, width: u32, height: u32) -> usize {
+    if len == PixelLayout::Rgb.buffer_len(width, height) {
+        PixelLayout::Rgb.channels()
+    } else {
+        PixelLayout::Rgba.channels()
     }
 }
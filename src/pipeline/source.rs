@@ -0,0 +1,295 @@
+use std::{
+    io::{ErrorKind, Read},
+    path::{Path, PathBuf},
+    process::{Child, ChildStdout, Command, Stdio},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, anyhow};
+use crossbeam_channel::Sender;
+use nokhwa::utils::CameraIndex;
+
+use super::camera::{CameraOpenOptions, CameraStream, FRAME_POOL_IDLE, start_camera_stream};
+use super::image_sequence::{ImageSequenceSource, load_image_frame};
+use super::metrics::{Channel, MetricsHandle};
+use super::rgb_converter;
+use crate::types::{Frame, FramePool, PixelLayout};
+
+/// Where the pipeline's frames come from.
+#[derive(Clone, Debug)]
+pub enum FrameSource {
+    /// `options` is the mode to try first; `None` leaves it to the driver.
+    Camera {
+        index: CameraIndex,
+        options: Option<CameraOpenOptions>,
+    },
+    /// A pre-recorded clip decoded through the `ffmpeg` binary. When `looping`
+    /// is false the stream stops at end of file.
+    VideoFile { path: PathBuf, looping: bool },
+    /// The images of a directory in file name order, one every `interval`.
+    ImageSequence {
+        dir: PathBuf,
+        interval: Duration,
+        looping: bool,
+    },
+}
+
+pub fn start_frame_source(
+    source: FrameSource,
+    frame_tx: Sender<Frame>,
+    metrics: MetricsHandle,
+    mirror: bool,
+) -> Result<CameraStream> {
+    match source {
+        FrameSource::Camera { index, options } => {
+            start_camera_stream(index, options, frame_tx, metrics, mirror)
+        }
+        FrameSource::VideoFile { path, looping } => {
+            start_video_stream(path, looping, frame_tx, metrics, mirror)
+        }
+        FrameSource::ImageSequence {
+            dir,
+            interval,
+            looping,
+        } => {
+            let sequence = ImageSequenceSource::open(&dir, interval)?;
+            Ok(start_image_stream(
+                sequence, looping, frame_tx, metrics, mirror,
+            ))
+        }
+    }
+}
+
+fn start_image_stream(
+    sequence: ImageSequenceSource,
+    looping: bool,
+    frame_tx: Sender<Frame>,
+    metrics: MetricsHandle,
+    mirror: bool,
+) -> CameraStream {
+    log::info!(
+        "playing {} images every {} ms",
+        sequence.paths().len(),
+        sequence.interval().as_millis()
+    );
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_flag = stop.clone();
+    let mirror = Arc::new(AtomicBool::new(mirror));
+    let mirror_flag = mirror.clone();
+
+    let handle = thread::spawn(move || {
+        let start = Instant::now();
+        let mut frame_index: u32 = 0;
+
+        'playback: loop {
+            for path in sequence.paths() {
+                if stop_flag.load(Ordering::Relaxed) {
+                    break 'playback;
+                }
+
+                let timestamp = start + sequence.interval() * frame_index;
+                frame_index += 1;
+                if let Some(wait) = timestamp.checked_duration_since(Instant::now()) {
+                    thread::sleep(wait);
+                }
+
+                let mut frame = match load_image_frame(path, timestamp) {
+                    Ok(frame) => frame,
+                    Err(err) => {
+                        log::warn!("skipping {}: {err:#}", path.display());
+                        continue;
+                    }
+                };
+                if mirror_flag.load(Ordering::Relaxed) {
+                    rgb_converter::mirror_pixels(&mut frame.pixels, frame.width, frame.layout);
+                }
+                metrics.record_capture(timestamp);
+                if frame_tx.try_send(frame).is_err() {
+                    metrics.record_drop(Channel::Camera);
+                }
+            }
+            if !looping {
+                log::info!("reached end of the image sequence");
+                break;
+            }
+        }
+    });
+
+    CameraStream::from_parts(stop, mirror, handle)
+}
+
+#[derive(Clone, Copy, Debug)]
+struct VideoInfo {
+    width: u32,
+    height: u32,
+    frame_interval: Duration,
+}
+
+fn start_video_stream(
+    path: PathBuf,
+    looping: bool,
+    frame_tx: Sender<Frame>,
+    metrics: MetricsHandle,
+    mirror: bool,
+) -> Result<CameraStream> {
+    // Fail fast before spawning the decode thread.
+    let info = probe_video(&path)?;
+    log::info!(
+        "playing {} at {}x{}, {:.2} fps",
+        path.display(),
+        info.width,
+        info.height,
+        1.0 / info.frame_interval.as_secs_f64()
+    );
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_flag = stop.clone();
+    let mirror = Arc::new(AtomicBool::new(mirror));
+    let mirror_flag = mirror.clone();
+
+    let handle = thread::spawn(move || {
+        let frame_len = PixelLayout::Rgb.buffer_len(info.width, info.height);
+        let pool = FramePool::new(FRAME_POOL_IDLE);
+        let start = Instant::now();
+        let mut frame_index: u32 = 0;
+
+        'playback: while !stop_flag.load(Ordering::Relaxed) {
+            let (mut child, mut stdout) = match spawn_decoder(&path) {
+                Ok(decoder) => decoder,
+                Err(err) => {
+                    log::error!("failed to decode {}: {err:?}", path.display());
+                    return;
+                }
+            };
+
+            loop {
+                if stop_flag.load(Ordering::Relaxed) {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    break 'playback;
+                }
+
+                let mut pixels = pool.take(frame_len);
+                match stdout.read_exact(&mut pixels) {
+                    Ok(()) => {}
+                    Err(err) if err.kind() == ErrorKind::UnexpectedEof => break,
+                    Err(err) => {
+                        log::warn!("video frame read failed: {err:?}");
+                        break;
+                    }
+                }
+                if mirror_flag.load(Ordering::Relaxed) {
+                    rgb_converter::mirror_pixels(&mut pixels, info.width, PixelLayout::Rgb);
+                }
+
+                // Timestamps follow the clip, not the wall clock, so replays are
+                // reproducible even when the pipeline falls behind.
+                let timestamp = start + info.frame_interval * frame_index;
+                frame_index += 1;
+                if let Some(wait) = timestamp.checked_duration_since(Instant::now()) {
+                    thread::sleep(wait);
+                }
+
+                let frame = Frame {
+                    pixels,
+                    layout: PixelLayout::Rgb,
+                    width: info.width,
+                    height: info.height,
+                    timestamp,
+                    acquired_at: timestamp,
+                };
+                metrics.record_capture(timestamp);
+                if frame_tx.try_send(frame).is_err() {
+                    metrics.record_drop(Channel::Camera);
+                }
+            }
+
+            let _ = child.wait();
+            if !looping {
+                log::info!("reached end of {}", path.display());
+                break;
+            }
+        }
+    });
+
+    Ok(CameraStream::from_parts(stop, mirror, handle))
+}
+
+fn probe_video(path: &Path) -> Result<VideoInfo> {
+    let output = Command::new("ffprobe")
+        .args([
+            "-v",
+            "error",
+            "-select_streams",
+            "v:0",
+            "-show_entries",
+            "stream=width,height,r_frame_rate",
+            "-of",
+            "csv=p=0",
+        ])
+        .arg(path)
+        .output()
+        .context("failed to run ffprobe, is ffmpeg installed?")?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "ffprobe failed for {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    let mut fields = text.trim().split(',');
+    let width = fields
+        .next()
+        .and_then(|v| v.parse::<u32>().ok())
+        .ok_or_else(|| anyhow!("ffprobe reported no video width for {}", path.display()))?;
+    let height = fields
+        .next()
+        .and_then(|v| v.parse::<u32>().ok())
+        .ok_or_else(|| anyhow!("ffprobe reported no video height for {}", path.display()))?;
+    let fps = fields.next().and_then(parse_frame_rate).unwrap_or(30.0);
+
+    if width == 0 || height == 0 {
+        return Err(anyhow!("video {} has no frames", path.display()));
+    }
+
+    Ok(VideoInfo {
+        width,
+        height,
+        frame_interval: Duration::from_secs_f64(1.0 / fps),
+    })
+}
+
+/// Parses ffprobe's rational frame rate, e.g. `30000/1001`.
+fn parse_frame_rate(value: &str) -> Option<f64> {
+    let (num, den) = match value.trim().split_once('/') {
+        Some((num, den)) => (num.parse::<f64>().ok()?, den.parse::<f64>().ok()?),
+        None => (value.trim().parse::<f64>().ok()?, 1.0),
+    };
+    let fps = num / den;
+    (fps.is_finite() && fps > 0.0).then_some(fps)
+}
+
+fn spawn_decoder(path: &Path) -> Result<(Child, ChildStdout)> {
+    let mut child = Command::new("ffmpeg")
+        .args(["-v", "error", "-i"])
+        .arg(path)
+        .args(["-f", "rawvideo", "-pix_fmt", "rgb24", "-"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .context("failed to run ffmpeg, is it installed?")?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| anyhow!("ffmpeg stdout unavailable"))?;
+    Ok((child, stdout))
+}
@@ -0,0 +1,230 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+
+use anyhow::Result;
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender, bounded};
+
+use crate::types::Frame;
+
+/// Where `modprobe v4l2loopback video_nr=10` puts its device.
+pub const DEFAULT_VIRTUAL_CAMERA_DEVICE: &str = "/dev/video10";
+/// How often the writer checks for a stop request while no frame arrives.
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Publishes composited frames as a camera other apps can pick, until dropped.
+/// Frames arrive at the compositor's own pace; the compositor only try_sends,
+/// so a slow consumer drops frames instead of holding it up.
+pub struct VirtualCamera {
+    device: PathBuf,
+    frame_tx: Sender<Frame>,
+    stop: Arc<AtomicBool>,
+    /// Set when the writer gave up, e.g. the device went away.
+    error: Arc<Mutex<Option<String>>>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl VirtualCamera {
+    /// Opens `device` at the source's resolution. Fails right away when the
+    /// device cannot be configured, so the caller can show why.
+    pub fn start(device: &Path, width: u32, height: u32) -> Result<Self> {
+        let output = loopback::Output::open(device, width, height)?;
+        let (frame_tx, frame_rx) = bounded(1);
+        let stop = Arc::new(AtomicBool::new(false));
+        let error = Arc::new(Mutex::new(None));
+
+        let handle = {
+            let stop = stop.clone();
+            let error = error.clone();
+            let device = device.to_path_buf();
+            thread::spawn(move || write_loop(output, &device, frame_rx, stop, error))
+        };
+
+        log::info!(
+            "virtual camera writing to {} at {width}x{height}",
+            device.display()
+        );
+        Ok(Self {
+            device: device.to_path_buf(),
+            frame_tx,
+            stop,
+            error,
+            handle: Some(handle),
+        })
+    }
+
+    pub fn device(&self) -> &Path {
+        &self.device
+    }
+
+    /// Sender to hand to the compositor as its frame tap.
+    pub fn frame_sender(&self) -> Sender<Frame> {
+        self.frame_tx.clone()
+    }
+
+    /// Why the writer stopped, once it has.
+    pub fn error(&self) -> Option<String> {
+        self.error
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
+
+impl Drop for VirtualCamera {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn write_loop(
+    mut output: loopback::Output,
+    device: &Path,
+    frame_rx: Receiver<Frame>,
+    stop: Arc<AtomicBool>,
+    error: Arc<Mutex<Option<String>>>,
+) {
+    while !stop.load(Ordering::SeqCst) {
+        let frame = match frame_rx.recv_timeout(STOP_POLL_INTERVAL) {
+            Ok(frame) => frame,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => break,
+        };
+        // A new camera or format changes the source size; follow it.
+        let written = if output.size() == (frame.width, frame.height) {
+            output.write(&frame)
+        } else {
+            loopback::Output::open(device, frame.width, frame.height).and_then(|reopened| {
+                output = reopened;
+                output.write(&frame)
+            })
+        };
+        if let Err(err) = written {
+            let message = format!("{err:#}");
+            log::warn!("virtual camera stopped: {message}");
+            *error
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(message);
+            return;
+        }
+    }
+}
+
+#[cfg(all(feature = "virtual-camera", target_os = "linux"))]
+mod loopback {
+    use std::{
+        fs::{File, OpenOptions},
+        io::Write,
+        path::Path,
+    };
+
+    use anyhow::{Context, Result, bail};
+    use v4l::{Device, Format, FourCC, video::Output as _};
+
+    use crate::types::Frame;
+
+    /// A v4l2loopback device fed YUYV through `write`, which every consumer
+    /// that takes webcams understands.
+    pub struct Output {
+        // Keeps the negotiated format in place while frames are written.
+        _device: Device,
+        file: File,
+        width: u32,
+        height: u32,
+        yuyv: Vec<u8>,
+    }
+
+    impl Output {
+        pub fn open(path: &Path, width: u32, height: u32) -> Result<Self> {
+            if !width.is_multiple_of(2) {
+                bail!("YUYV output needs an even width, the source is {width}x{height}");
+            }
+            let device = Device::with_path(path)
+                .with_context(|| format!("failed to open {}", path.display()))?;
+            let wanted = Format::new(width, height, FourCC::new(b"YUYV"));
+            let format = device.set_format(&wanted).with_context(|| {
+                format!("{} is not a v4l2loopback output device", path.display())
+            })?;
+            if (format.width, format.height, format.fourcc) != (width, height, wanted.fourcc) {
+                bail!("{} does not accept {width}x{height} YUYV", path.display());
+            }
+            let file = OpenOptions::new()
+                .write(true)
+                .open(path)
+                .with_context(|| format!("failed to open {} for writing", path.display()))?;
+            Ok(Self {
+                _device: device,
+                file,
+                width,
+                height,
+                yuyv: vec![0; width as usize * height as usize * 2],
+            })
+        }
+
+        pub fn size(&self) -> (u32, u32) {
+            (self.width, self.height)
+        }
+
+        pub fn write(&mut self, frame: &Frame) -> Result<()> {
+            rgb_to_yuyv(&frame.pixels, frame.layout.channels(), &mut self.yuyv);
+            self.file
+                .write_all(&self.yuyv)
+                .context("failed to write to the virtual camera")
+        }
+    }
+
+    /// BT.601 limited range, chroma averaged over each pixel pair.
+    fn rgb_to_yuyv(pixels: &[u8], channels: usize, yuyv: &mut [u8]) {
+        let luma = |[r, g, b]: [i32; 3]| (16 + ((66 * r + 129 * g + 25 * b + 128) >> 8)) as u8;
+        for (pair, out) in pixels
+            .chunks_exact(channels * 2)
+            .zip(yuyv.chunks_exact_mut(4))
+        {
+            let left = [pair[0], pair[1], pair[2]].map(i32::from);
+            let right = [0, 1, 2].map(|i| i32::from(pair[channels + i]));
+            let [r, g, b] = [0, 1, 2].map(|i| (left[i] + right[i]) / 2);
+            out[0] = luma(left);
+            out[1] = (128 + ((-38 * r - 74 * g + 112 * b + 128) >> 8)) as u8;
+            out[2] = luma(right);
+            out[3] = (128 + ((112 * r - 94 * g - 18 * b + 128) >> 8)) as u8;
+        }
+    }
+}
+
+#[cfg(not(all(feature = "virtual-camera", target_os = "linux")))]
+mod loopback {
+    use std::path::Path;
+
+    use anyhow::{Result, bail};
+
+    use crate::types::Frame;
+
+    /// Stands in on builds without a virtual camera backend; never opens.
+    pub enum Output {}
+
+    impl Output {
+        pub fn open(_path: &Path, _width: u32, _height: u32) -> Result<Self> {
+            if cfg!(feature = "virtual-camera") {
+                bail!("virtual camera output needs v4l2loopback, which is Linux only");
+            }
+            bail!("this build has no virtual camera; rebuild with --features virtual-camera");
+        }
+
+        pub fn size(&self) -> (u32, u32) {
+            match *self {}
+        }
+
+        pub fn write(&mut self, _frame: &Frame) -> Result<()> {
+            match *self {}
+        }
+    }
+}
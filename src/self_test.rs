@@ -0,0 +1,329 @@
+//! `--self-test`: checks the models, the cameras and one inference without
+//! opening a window, for installs that need to be verified unattended.
+
+use std::{
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use anyhow::Context;
+use serde::Serialize;
+
+#[cfg(feature = "backend-ort")]
+use crate::pipeline::recognizer::OrtEngine;
+#[cfg(feature = "backend-tract")]
+use crate::pipeline::recognizer::TractEngine;
+use crate::{
+    model_download::{
+        ModelKind, default_handpose_estimator_model_path, default_palm_detector_model_path,
+        expected_sha256, file_sha256,
+    },
+    pipeline::{
+        CameraDevice, ExecutionProvider, InferenceBackend, RecognizerBackend, available_cameras,
+        grab_test_frame,
+        image_sequence::image_frame,
+        recognizer::{
+            common::{SessionInfo, TensorInfo, build_session},
+            recognize_frame,
+        },
+    },
+};
+
+/// A hand making the OK sign, built into the binary so the inference check
+/// needs nothing on disk but the models.
+const TEST_IMAGE: &[u8] = include_bytes!("../demo/ok.png");
+const TEST_IMAGE_NAME: &str = "demo/ok.png";
+
+/// Everything the self-test looked at; printed as JSON when a check fails.
+#[derive(Debug, Serialize)]
+pub struct SelfTestReport {
+    pub passed: bool,
+    pub models: Vec<ModelCheck>,
+    /// Set when the cameras could not be listed or there are none.
+    pub camera_error: Option<String>,
+    pub cameras: Vec<CameraCheck>,
+    pub inference: InferenceCheck,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModelCheck {
+    pub model: &'static str,
+    pub path: PathBuf,
+    pub sha256: Option<String>,
+    /// `None` for a model outside the default path, which is the user's own
+    /// and is not compared against the published one.
+    pub expected_sha256: Option<&'static str>,
+    /// The provider the session was built on.
+    pub provider: Option<&'static str>,
+    pub session: Option<SessionInfo>,
+    pub error: Option<String>,
+    pub passed: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CameraCheck {
+    pub label: String,
+    pub frame_size: Option<(u32, u32)>,
+    /// Whether the frame had any contrast; a black one still proves the
+    /// camera opens, so it does not fail the check.
+    pub shows_picture: bool,
+    pub error: Option<String>,
+    pub passed: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InferenceCheck {
+    pub image: &'static str,
+    pub hands: usize,
+    pub label: Option<String>,
+    pub confidence: Option<f32>,
+    pub error: Option<String>,
+    pub passed: bool,
+}
+
+/// Runs every check, prints the report and, when anything failed, a JSON
+/// summary on the last line. Returns whether everything passed.
+pub fn run(backend: &RecognizerBackend) -> bool {
+    let report = SelfTestReport::collect(backend);
+    report.print();
+    if !report.passed {
+        match serde_json::to_string(&report) {
+            Ok(json) => println!("{json}"),
+            Err(err) => log::error!("failed to encode the self-test report: {err}"),
+        }
+    }
+    report.passed
+}
+
+impl SelfTestReport {
+    pub fn collect(backend: &RecognizerBackend) -> Self {
+        let provider = backend.execution_provider();
+        let models = vec![
+            check_model(
+                ModelKind::HandposeEstimator,
+                &backend.handpose_estimator_model_path(),
+                &default_handpose_estimator_model_path(),
+                provider,
+            ),
+            check_model(
+                ModelKind::PalmDetector,
+                &backend.palm_detector_model_path(),
+                &default_palm_detector_model_path(),
+                provider,
+            ),
+        ];
+        let (cameras, camera_error) = match available_cameras() {
+            Ok(devices) if devices.is_empty() => (Vec::new(), Some("no cameras found".to_string())),
+            Ok(devices) => (devices.iter().map(check_camera).collect(), None),
+            Err(err) => (Vec::new(), Some(format!("{err:#}"))),
+        };
+        let inference = if models.iter().all(|model| model.passed) {
+            check_inference(backend)
+        } else {
+            InferenceCheck::failed("skipped, a model failed its check".into())
+        };
+
+        let passed = models.iter().all(|model| model.passed)
+            && camera_error.is_none()
+            && cameras.iter().all(|camera| camera.passed)
+            && inference.passed;
+        Self {
+            passed,
+            models,
+            camera_error,
+            cameras,
+            inference,
+        }
+    }
+
+    pub fn print(&self) {
+        for model in &self.models {
+            println!(
+                "{} {} model: {}",
+                status(model.passed),
+                model.model,
+                model.path.display()
+            );
+            if let Some(sha256) = &model.sha256 {
+                let note = match model.expected_sha256 {
+                    Some(expected) if expected == sha256 => "matches the published model",
+                    Some(_) => "does not match the published model",
+                    None => "custom model, not checked",
+                };
+                println!("       sha256 {sha256} ({note})");
+            }
+            if let (Some(provider), Some(session)) = (model.provider, &model.session) {
+                println!("       session on {provider}");
+                print_tensors("input", &session.inputs);
+                print_tensors("output", &session.outputs);
+            }
+            if let Some(error) = &model.error {
+                println!("       {error}");
+            }
+        }
+
+        if let Some(error) = &self.camera_error {
+            println!("{} cameras: {error}", status(false));
+        }
+        for camera in &self.cameras {
+            match (camera.frame_size, &camera.error) {
+                (Some((width, height)), _) => println!(
+                    "{} camera {:?}: {width}x{height} frame{}",
+                    status(camera.passed),
+                    camera.label,
+                    if camera.shows_picture {
+                        ""
+                    } else {
+                        ", uniform"
+                    }
+                ),
+                (None, Some(error)) => {
+                    println!("{} camera {:?}: {error}", status(false), camera.label)
+                }
+                (None, None) => println!("{} camera {:?}", status(false), camera.label),
+            }
+        }
+
+        let inference = &self.inference;
+        match (&inference.label, inference.confidence, &inference.error) {
+            (Some(label), Some(confidence), None) => println!(
+                "{} inference on {}: {} hand(s), {label}, confidence {confidence:.3}",
+                status(inference.passed),
+                inference.image,
+                inference.hands
+            ),
+            (_, _, Some(error)) => {
+                println!(
+                    "{} inference on {}: {error}",
+                    status(false),
+                    inference.image
+                )
+            }
+            _ => println!("{} inference on {}", status(false), inference.image),
+        }
+
+        println!(
+            "self-test {}",
+            if self.passed { "passed" } else { "failed" }
+        );
+    }
+}
+
+impl InferenceCheck {
+    fn failed(error: String) -> Self {
+        Self {
+            image: TEST_IMAGE_NAME,
+            hands: 0,
+            label: None,
+            confidence: None,
+            error: Some(error),
+            passed: false,
+        }
+    }
+}
+
+fn status(passed: bool) -> &'static str {
+    if passed { "[ok]  " } else { "[FAIL]" }
+}
+
+fn print_tensors(kind: &str, tensors: &[TensorInfo]) {
+    for tensor in tensors {
+        match &tensor.shape {
+            Some(shape) => println!("         {kind} {:?} {shape:?}", tensor.name),
+            None => println!("         {kind} {:?} {}", tensor.name, tensor.value_type),
+        }
+    }
+}
+
+/// The file is there, has the published digest when it sits at the default
+/// path, and a session builds from it.
+fn check_model(
+    kind: ModelKind,
+    path: &Path,
+    default_path: &Path,
+    provider: ExecutionProvider,
+) -> ModelCheck {
+    let expected = (path == default_path).then(|| expected_sha256(kind));
+    let mut check = ModelCheck {
+        model: kind.label(),
+        path: path.to_path_buf(),
+        sha256: None,
+        expected_sha256: expected,
+        provider: None,
+        session: None,
+        error: None,
+        passed: false,
+    };
+    if !path.is_file() {
+        check.error = Some("missing; starting the app once downloads it".into());
+        return check;
+    }
+    let sha256 = match file_sha256(path) {
+        Ok(sha256) => sha256,
+        Err(err) => {
+            check.error = Some(format!("{err:#}"));
+            return check;
+        }
+    };
+    let digest_ok = expected.is_none_or(|expected| expected == sha256);
+    check.sha256 = Some(sha256);
+    match build_session(path, provider) {
+        Ok((session, used)) => {
+            check.provider = Some(used.label());
+            check.session = Some(SessionInfo::of_session(&session));
+            check.passed = digest_ok;
+        }
+        Err(err) => check.error = Some(format!("{err:#}")),
+    }
+    check
+}
+
+fn check_camera(device: &CameraDevice) -> CameraCheck {
+    match grab_test_frame(device) {
+        Ok((frame, shows_picture)) => CameraCheck {
+            label: device.label.clone(),
+            frame_size: Some((frame.width, frame.height)),
+            shows_picture,
+            error: None,
+            passed: true,
+        },
+        Err(err) => CameraCheck {
+            label: device.label.clone(),
+            frame_size: None,
+            shows_picture: false,
+            error: Some(format!("{err:#}")),
+            passed: false,
+        },
+    }
+}
+
+/// Palm detection, handpose and classification on [`TEST_IMAGE`], which
+/// passes when a hand is found.
+fn check_inference(backend: &RecognizerBackend) -> InferenceCheck {
+    let image = match image::load_from_memory(TEST_IMAGE).context("bundled test image") {
+        Ok(image) => image,
+        Err(err) => return InferenceCheck::failed(format!("{err:#}")),
+    };
+    let frame = image_frame(&image, Instant::now());
+    let config = backend.config();
+    let mut classifier = backend.build_classifier();
+    let result = match backend.inference_backend() {
+        #[cfg(feature = "backend-ort")]
+        InferenceBackend::Ort => OrtEngine::from_backend(backend)
+            .and_then(|mut engine| recognize_frame(&mut engine, &mut classifier, &frame, &config)),
+        #[cfg(feature = "backend-tract")]
+        InferenceBackend::Tract => TractEngine::from_backend(backend)
+            .and_then(|mut engine| recognize_frame(&mut engine, &mut classifier, &frame, &config)),
+    };
+    match result {
+        Ok(result) => InferenceCheck {
+            image: TEST_IMAGE_NAME,
+            hands: result.hands.len(),
+            label: Some(result.label()),
+            confidence: Some(result.confidence),
+            error: None,
+            passed: !result.hands.is_empty(),
+        },
+        Err(err) => InferenceCheck::failed(err.describe()),
+    }
+}
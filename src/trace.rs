@@ -0,0 +1,157 @@
+//! Landmark traces: one hand's landmarks over time as JSON lines, replayed
+//! through [`GestureClassifier`] with timestamps rebuilt from `t_ms`, so the
+//! same file always gives the same labels.
+
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, Instant, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use crate::{
+    gesture::GestureClassifier,
+    pipeline::recorder::RecordedFrame,
+    types::{GestureKind, GestureMotion},
+};
+
+/// Track id every trace frame is classified under.
+const TRACE_TRACK_ID: u64 = 1;
+
+/// One line of a trace.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TraceFrame {
+    /// Landmarks in crop pixels, as the handpose model returns them.
+    pub raw_landmarks: Vec<[f32; 3]>,
+    /// The same landmarks in frame pixels.
+    pub projected_landmarks: Vec<(f32, f32)>,
+    pub confidence: f32,
+    /// Handedness score, 1 for a right hand; missing when the model has none.
+    pub handedness: Option<f32>,
+    /// Milliseconds since the first frame of the trace.
+    pub t_ms: u64,
+}
+
+/// One line of an expected trace, frame for frame with its [`TraceFrame`]s.
+/// A replay fills in every field it can; an expected trace leaves a field out
+/// on frames where the hand is between two readings and either is right, and
+/// only the fields it has are compared.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceStep {
+    pub t_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary: Option<GestureKind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub motion: Option<GestureMotion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinch_closed: Option<bool>,
+}
+
+/// Reads a JSON lines file, skipping blank lines.
+pub fn read_jsonl<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_no, line)| {
+            serde_json::from_str(line).with_context(|| {
+                format!(
+                    "invalid entry on line {} of {}",
+                    line_no + 1,
+                    path.display()
+                )
+            })
+        })
+        .collect()
+}
+
+/// Classifies every frame in order. Frames the classifier turns down, such as
+/// ones below the confidence threshold, come out as a steady
+/// [`GestureKind::Unknown`].
+pub fn replay(classifier: &mut GestureClassifier, frames: &[TraceFrame]) -> Vec<TraceStep> {
+    let base = Instant::now();
+    frames
+        .iter()
+        .map(|frame| {
+            let detail = classifier.classify(
+                TRACE_TRACK_ID,
+                &frame.raw_landmarks,
+                &frame.projected_landmarks,
+                frame.confidence,
+                frame.handedness,
+                base + Duration::from_millis(frame.t_ms),
+            );
+            TraceStep {
+                t_ms: frame.t_ms,
+                primary: Some(detail.as_ref().map_or(GestureKind::Unknown, |d| d.primary)),
+                motion: Some(detail.as_ref().map_or(GestureMotion::Steady, |d| d.motion)),
+                pinch_closed: detail.and_then(|d| d.pinch).map(|pinch| pinch.closed),
+            }
+        })
+        .collect()
+}
+
+/// Frames of `actual` that disagree with `expected` on a field `expected` has,
+/// as (index, expected, actual). A frame missing on either side counts as a
+/// mismatch.
+pub fn mismatches(
+    expected: &[TraceStep],
+    actual: &[TraceStep],
+) -> Vec<(usize, Option<TraceStep>, Option<TraceStep>)> {
+    let len = expected.len().max(actual.len());
+    (0..len)
+        .filter_map(|idx| {
+            let (want, got) = (expected.get(idx).copied(), actual.get(idx).copied());
+            let same = match (want, got) {
+                (Some(want), Some(got)) => {
+                    agrees(want.primary, got.primary)
+                        && agrees(want.motion, got.motion)
+                        && agrees(want.pinch_closed, got.pinch_closed)
+                }
+                _ => false,
+            };
+            (!same).then_some((idx, want, got))
+        })
+        .collect()
+}
+
+/// A field the expected step leaves out agrees with anything.
+fn agrees<T: PartialEq>(want: Option<T>, got: Option<T>) -> bool {
+    want.is_none_or(|want| got == Some(want))
+}
+
+/// The hand with `track_id` from every frame of a recorded session that has
+/// it, or the first hand of each frame without one. `t_ms` counts from the
+/// first frame kept.
+pub fn trace_from_session(entries: &[RecordedFrame], track_id: Option<u64>) -> Vec<TraceFrame> {
+    let mut start = None;
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let hands = &entry.result.hands;
+            let hand = match track_id {
+                Some(id) => hands.iter().find(|hand| hand.track_id == id)?,
+                None => hands.first()?,
+            };
+            if hand.raw_landmarks.len() < 21 || hand.landmarks.len() < 21 {
+                return None;
+            }
+            let at = entry
+                .result
+                .captured_at
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or(Duration::ZERO);
+            let start = *start.get_or_insert(at);
+            Some(TraceFrame {
+                raw_landmarks: hand.raw_landmarks.clone(),
+                projected_landmarks: hand.landmarks.clone(),
+                confidence: hand.confidence,
+                handedness: hand.handedness_score,
+                t_ms: at.saturating_sub(start).as_millis() as u64,
+            })
+        })
+        .collect()
+}
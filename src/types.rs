@@ -1,39 +1,679 @@
-use std::time::Instant;
+use std::{
+    borrow::Cow,
+    fmt,
+    ops::{Deref, DerefMut},
+    sync::{
+        Arc, Mutex, RwLock, Weak,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant, SystemTime},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::locale::{Locale, locale, tr};
 
 #[derive(Clone, Debug)]
 pub struct Frame {
-    pub rgba: Vec<u8>,
+    /// Rows top to bottom, `layout.channels()` bytes per pixel.
+    pub pixels: FrameBuffer,
+    pub layout: PixelLayout,
     pub width: u32,
     pub height: u32,
-    #[allow(dead_code)]
+    /// When the frame was ready for the pipeline, after decoding.
     pub timestamp: Instant,
+    /// When the camera handed the frame over, before decoding. Sources without
+    /// a decode step use `timestamp`.
+    pub acquired_at: Instant,
 }
 
-#[derive(Clone, Debug)]
+impl Frame {
+    /// What `pixels` should hold for the frame's size and layout.
+    pub fn expected_len(&self) -> usize {
+        self.layout.buffer_len(self.width, self.height)
+    }
+
+    /// The pixels as RGB, borrowed when they already are.
+    pub fn to_rgb(&self) -> Cow<'_, [u8]> {
+        match self.layout {
+            PixelLayout::Rgb => Cow::Borrowed(&self.pixels),
+            PixelLayout::Rgba => Cow::Owned(
+                self.pixels
+                    .chunks_exact(4)
+                    .flat_map(|px| [px[0], px[1], px[2]])
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Byte order of a [`Frame`]'s pixels. Alpha is always opaque, so RGB holds
+/// the same picture in three quarters of the bytes; camera frames are RGB,
+/// decoded images may be either.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PixelLayout {
+    Rgb,
+    Rgba,
+}
+
+impl PixelLayout {
+    pub const fn channels(self) -> usize {
+        match self {
+            PixelLayout::Rgb => 3,
+            PixelLayout::Rgba => 4,
+        }
+    }
+
+    pub fn buffer_len(self, width: u32, height: u32) -> usize {
+        (width as usize)
+            .saturating_mul(height as usize)
+            .saturating_mul(self.channels())
+    }
+}
+
+/// Recycles frame-sized byte buffers, so a steady stream of frames stops
+/// allocating once the pipeline is full.
+pub struct FramePool {
+    idle: Mutex<Vec<Vec<u8>>>,
+    max_idle: usize,
+    allocations: AtomicU64,
+}
+
+impl FramePool {
+    /// `max_idle` bounds how many returned buffers are kept; it should cover
+    /// the frames in flight between capture and the UI.
+    pub fn new(max_idle: usize) -> Arc<Self> {
+        Arc::new(Self {
+            idle: Mutex::new(Vec::with_capacity(max_idle)),
+            max_idle,
+            allocations: AtomicU64::new(0),
+        })
+    }
+
+    /// A buffer of exactly `len` bytes. Reused buffers keep their old
+    /// contents, so callers must overwrite all of it.
+    pub fn take(self: &Arc<Self>, len: usize) -> FrameBuffer {
+        let reused = self.idle.lock().ok().and_then(|mut idle| idle.pop());
+        let data = match reused {
+            Some(mut data) => {
+                if data.capacity() < len {
+                    self.allocations.fetch_add(1, Ordering::Relaxed);
+                }
+                data.resize(len, 0);
+                data
+            }
+            None => {
+                self.allocations.fetch_add(1, Ordering::Relaxed);
+                vec![0; len]
+            }
+        };
+        FrameBuffer {
+            data,
+            pool: Some(Arc::downgrade(self)),
+        }
+    }
+
+    /// Buffers allocated or grown since the pool was created.
+    pub fn allocations(&self) -> u64 {
+        self.allocations.load(Ordering::Relaxed)
+    }
+
+    fn give_back(&self, data: Vec<u8>) {
+        if let Ok(mut idle) = self.idle.lock()
+            && idle.len() < self.max_idle
+        {
+            idle.push(data);
+        }
+    }
+}
+
+impl fmt::Debug for FramePool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FramePool")
+            .field("max_idle", &self.max_idle)
+            .field("allocations", &self.allocations())
+            .finish()
+    }
+}
+
+/// Pixel bytes of a [`Frame`]. Pooled buffers go back to their [`FramePool`]
+/// when dropped; plain ones are freed as usual.
+pub struct FrameBuffer {
+    data: Vec<u8>,
+    pool: Option<Weak<FramePool>>,
+}
+
+impl From<Vec<u8>> for FrameBuffer {
+    fn from(data: Vec<u8>) -> Self {
+        Self { data, pool: None }
+    }
+}
+
+impl Deref for FrameBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl DerefMut for FrameBuffer {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
+/// Clones come from the same pool, so copying a frame for an overlay does not
+/// allocate either.
+impl Clone for FrameBuffer {
+    fn clone(&self) -> Self {
+        match self.pool.as_ref().and_then(Weak::upgrade) {
+            Some(pool) => {
+                let mut copy = pool.take(self.data.len());
+                copy.copy_from_slice(&self.data);
+                copy
+            }
+            None => Self::from(self.data.clone()),
+        }
+    }
+}
+
+impl Drop for FrameBuffer {
+    fn drop(&mut self) {
+        if let Some(pool) = self.pool.take().and_then(|pool| pool.upgrade()) {
+            pool.give_back(std::mem::take(&mut self.data));
+        }
+    }
+}
+
+impl fmt::Debug for FrameBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrameBuffer")
+            .field("len", &self.data.len())
+            .field("pooled", &self.pool.is_some())
+            .finish()
+    }
+}
+
+/// Serializable so recognition output can be logged and replayed. `timestamp`
+/// is process-local and skipped; `frame_id` and `captured_at` survive a round
+/// trip instead.
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GestureResult {
-    pub label: String,
     pub confidence: f32,
     #[allow(dead_code)]
+    #[serde(skip, default = "Instant::now")]
     pub timestamp: Instant,
+    /// Monotonic index of the frame within the recognizer run.
+    pub frame_id: u64,
+    /// Wall-clock capture time of the frame.
+    pub captured_at: SystemTime,
+    /// Width and height of the frame the landmarks are expressed in.
+    #[serde(default)]
+    pub frame_size: (u32, u32),
+    /// Tracked identity of the primary hand, the same while that hand is
+    /// followed across frames and short dropouts.
+    #[serde(default)]
+    pub track_id: Option<u64>,
     pub landmarks: Option<Vec<(f32, f32)>>,
+    /// Model-space landmarks of the primary hand; z is relative depth with
+    /// smaller values closer to the camera.
+    #[serde(default)]
+    pub raw_landmarks: Option<Vec<[f32; 3]>>,
     pub detail: Option<GestureDetail>,
+    /// Set while both hands together form a two-hand gesture.
+    #[serde(default)]
+    pub composite: Option<CompositeGesture>,
     pub palm_regions: Vec<PalmRegion>,
+    /// Only filled while the debug overlay is on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub palm_debug: Option<PalmDebug>,
+    #[serde(default)]
+    pub dwell: Option<DwellProgress>,
+    /// Every hand found in the frame, best first. The top-level fields above
+    /// mirror the first entry.
+    pub hands: Vec<HandResult>,
+    /// Anything but [`FrameQuality::Good`] means the frame skipped inference
+    /// and [`GestureResult::label`] says why.
+    #[serde(default)]
+    pub frame_quality: FrameQuality,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HandResult {
+    pub track_id: u64,
+    pub confidence: f32,
+    pub landmarks: Vec<(f32, f32)>,
+    /// Model-space landmarks and handedness score as fed to the classifier, so
+    /// recorded hands can be classified again offline.
+    #[serde(default)]
+    pub raw_landmarks: Vec<[f32; 3]>,
+    /// `None` when the model has no handedness output.
+    #[serde(default)]
+    pub handedness_score: Option<f32>,
+    pub detail: Option<GestureDetail>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PalmRegion {
     pub bbox: [f32; 4],
     pub landmarks: Vec<(f32, f32)>,
     pub score: f32,
 }
 
+/// How palm detection got to the hands of one frame, for the debug overlay:
+/// what the detector proposed, what went on to the landmark model, and which
+/// hands were followed without a palm.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PalmDebug {
+    /// Every region over the score threshold, before NMS and the ROI.
+    pub candidates: Vec<PalmRegion>,
+    /// The regions cropped for the landmark model, best first; the rest of
+    /// `palm_regions` were over the hand limit.
+    pub selected: Vec<PalmRegion>,
+    /// Crops of tracked hands the detector did not find this frame, as
+    /// center, side and angle in radians.
+    pub tracked: Vec<((f32, f32), f32, f32)>,
+}
+
+/// Confidence cut-offs applied at each stage of the pipeline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RecognizerConfig {
+    /// Minimum handpose confidence for a hand to be reported at all.
+    pub detection_threshold: f32,
+    /// Minimum handpose confidence before a gesture is classified.
+    pub classification_threshold: f32,
+    /// Minimum hand confidence for the compositor to draw its skeleton.
+    pub overlay_threshold: f32,
+    /// Consecutive frames a gesture must be seen before it is reported as started.
+    pub event_start_frames: usize,
+    /// Consecutive frames a gesture must be missing before it is reported as ended.
+    pub event_end_frames: usize,
+    /// Frames are flipped horizontally before inference, so handedness has to be
+    /// swapped back.
+    pub mirror: bool,
+    /// Thumb–index tip distance, in palm lengths, below which a pinch counts as
+    /// closed. It reopens a little above this to avoid flicker.
+    pub pinch_threshold: f32,
+    /// Debug overlay: colors skeleton joints by landmark depth.
+    pub depth_overlay: bool,
+    /// Whether half-bent fingers count toward [`GestureDetail::count`].
+    pub count_half_bent: bool,
+    /// Scale finger distances are measured in before thresholding.
+    pub normalization: LandmarkNormalization,
+    /// Hold-to-select on the primary hand; off unless set.
+    pub dwell: Option<DwellConfig>,
+    /// Landmark filtering across frames; off unless set.
+    pub smoothing: Option<SmoothingConfig>,
+    /// Skips inference on frames too dark or too flat to find hands in.
+    pub quality_gate: QualityGateConfig,
+    /// Skips inference while nothing in front of the camera moves.
+    pub motion_gate: MotionGateConfig,
+}
+
+/// One-euro filter settings for hand landmarks. A still hand is filtered at
+/// `min_cutoff`; the cutoff rises by `beta` per pixel per second of movement so
+/// fast motion does not lag.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SmoothingConfig {
+    /// Hz.
+    pub min_cutoff: f32,
+    pub beta: f32,
+    /// Hz, for the speed estimate that drives `beta`.
+    pub derivative_cutoff: f32,
+    /// Also filters the crop-space landmarks the classifier measures.
+    pub raw_landmarks: bool,
+}
+
+impl Default for SmoothingConfig {
+    fn default() -> Self {
+        Self {
+            min_cutoff: 1.0,
+            beta: 0.01,
+            derivative_cutoff: 1.0,
+            raw_landmarks: false,
+        }
+    }
+}
+
+/// Luma limits, on a 0-255 scale, outside which a frame is not worth running
+/// the models on; the palm detector finds phantom hands in sensor noise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QualityGateConfig {
+    pub enabled: bool,
+    /// Mean luma below this is too dark.
+    pub min_luma: u8,
+    /// Mean luma above this is washed out.
+    pub max_luma: u8,
+    /// Luma standard deviation below this is too flat to hold a hand, dark or
+    /// washed out depending on the mean.
+    pub min_contrast: u8,
+}
+
+impl Default for QualityGateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_luma: 35,
+            max_luma: 230,
+            min_contrast: 8,
+        }
+    }
+}
+
+/// When to stop running the models on a scene that has gone still. The
+/// differences are mean absolute luma differences on a 0-255 scale; waking
+/// takes more than going idle so sensor noise does not flap the gate.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MotionGateConfig {
+    pub enabled: bool,
+    /// A frame differing from the last inferred one by less than this is still.
+    pub idle_threshold: f32,
+    /// An idle gate wakes on a frame differing by more than this from the last
+    /// one inferred.
+    pub wake_threshold: f32,
+    /// Still frames in a row, with no hand found, before the gate goes idle.
+    pub idle_frames: u32,
+}
+
+impl Default for MotionGateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            idle_threshold: 1.5,
+            wake_threshold: 4.0,
+            idle_frames: 30,
+        }
+    }
+}
+
+/// Why a frame was not run through the models, if it was not.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FrameQuality {
+    #[default]
+    Good,
+    TooDark,
+    WashedOut,
+    /// The [`MotionGateConfig`] gate is idle on a still scene.
+    Idle,
+}
+
+impl FrameQuality {
+    pub fn label(&self) -> &'static str {
+        self.label_in(locale())
+    }
+
+    pub fn label_in(&self, locale: Locale) -> &'static str {
+        let (zh, en) = match self {
+            FrameQuality::Good => ("画面正常", "Picture OK"),
+            FrameQuality::TooDark => ("画面过暗", "Too dark"),
+            FrameQuality::WashedOut => ("画面过曝", "Washed out"),
+            FrameQuality::Idle => ("画面静止", "Scene still"),
+        };
+        locale.pick(zh, en)
+    }
+
+    /// What to do about it, for the gesture panel.
+    pub fn hint(&self) -> Option<&'static str> {
+        match self {
+            FrameQuality::Good => None,
+            FrameQuality::TooDark => Some(tr(
+                "光线不足，请打开灯或面向光源",
+                "Too dark: turn on a light or face one",
+            )),
+            FrameQuality::WashedOut => Some(tr(
+                "画面过亮，请避开强光或调低曝光",
+                "Too bright: avoid strong light or lower the exposure",
+            )),
+            FrameQuality::Idle => Some(tr(
+                "识别已休眠，挥手即可唤醒",
+                "Recognition is asleep; wave to wake it",
+            )),
+        }
+    }
+}
+
+/// Holding `gesture` steady with the wrist inside `target` for the hold time
+/// completes a dwell, e.g. to select a kiosk hotspot.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DwellConfig {
+    pub gesture: GestureKind,
+    /// `[x1, y1, x2, y2]` as fractions of the frame size.
+    pub target: [f32; 4],
+    pub hold_ms: u64,
+    /// How long the hand may drift out of the gesture, the target or steady
+    /// motion before the timer restarts.
+    pub grace_ms: u64,
+}
+
+impl Default for DwellConfig {
+    fn default() -> Self {
+        Self {
+            gesture: GestureKind::Palm,
+            target: [0.0, 0.0, 1.0, 1.0],
+            hold_ms: 1_500,
+            grace_ms: 200,
+        }
+    }
+}
+
+impl DwellConfig {
+    pub fn hold(&self) -> Duration {
+        Duration::from_millis(self.hold_ms)
+    }
+
+    pub fn grace(&self) -> Duration {
+        Duration::from_millis(self.grace_ms)
+    }
+}
+
+/// Confidence-weighted voting behind the big gesture label. Each frame adds
+/// its confidence to the vote of its gesture and every vote decays with
+/// `time_constant_ms`; the leader is only shown once it is `margin` votes
+/// ahead of the runner-up.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LabelStabilizerConfig {
+    /// Time for a vote to decay to about a third; 0 shows every frame as is.
+    pub time_constant_ms: u64,
+    /// Roughly confident frames; at 30 fps and 300 ms a gesture that is
+    /// never contradicted settles at about 9 votes.
+    pub margin: f32,
+}
+
+impl Default for LabelStabilizerConfig {
+    fn default() -> Self {
+        Self {
+            time_constant_ms: 300,
+            margin: 1.5,
+        }
+    }
+}
+
+impl LabelStabilizerConfig {
+    pub fn time_constant(&self) -> Duration {
+        Duration::from_millis(self.time_constant_ms)
+    }
+}
+
+/// Gestures that must start in order, e.g. Fist, Palm, Fist, for an action
+/// that should not fire by accident.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GestureSequence {
+    pub name: String,
+    pub steps: Vec<GestureKind>,
+    /// Longest wait from one step starting to the next one starting.
+    pub max_gap_ms: u64,
+    /// Longest time from the first step to the last.
+    pub timeout_ms: u64,
+    /// When off, a frame without a recognized gesture between steps starts
+    /// the sequence over.
+    pub ignore_unknown: bool,
+}
+
+impl Default for GestureSequence {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            steps: vec![GestureKind::Fist, GestureKind::Palm, GestureKind::Fist],
+            max_gap_ms: 1_000,
+            timeout_ms: 2_000,
+            ignore_unknown: true,
+        }
+    }
+}
+
+impl GestureSequence {
+    pub fn max_gap(&self) -> Duration {
+        Duration::from_millis(self.max_gap_ms)
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms)
+    }
+}
+
+/// How far along a dwell is, reported on every frame it is running.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DwellProgress {
+    pub kind: GestureKind,
+    /// Fraction of the hold time, 0.0 to 1.0.
+    pub progress: f32,
+    /// Wrist position in frame pixels.
+    pub wrist: (f32, f32),
+    /// Set on the frame the hold completed.
+    pub completed: bool,
+}
+
+/// How landmarks are scaled before finger states and pinch are decided. Both
+/// schemes express distances in palm lengths, so the same thresholds apply.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LandmarkNormalization {
+    /// Wrist to middle-finger knuckle, which barely changes with hand pose.
+    #[default]
+    PalmLength,
+    /// The earlier scheme: the longer side of the landmark bounding box. Its
+    /// scale shrinks for a fist or a hand seen edge-on.
+    BoundingBox,
+}
+
+impl Default for RecognizerConfig {
+    fn default() -> Self {
+        Self {
+            detection_threshold: 0.2,
+            classification_threshold: 0.2,
+            overlay_threshold: 0.2,
+            event_start_frames: 3,
+            event_end_frames: 3,
+            mirror: false,
+            pinch_threshold: 0.5,
+            depth_overlay: false,
+            count_half_bent: false,
+            normalization: LandmarkNormalization::default(),
+            dwell: None,
+            smoothing: None,
+            quality_gate: QualityGateConfig::default(),
+            motion_gate: MotionGateConfig::default(),
+        }
+    }
+}
+
+/// Debounced transitions of the primary hand's gesture, and the motions and
+/// drags it makes. Drag positions are the wrist as fractions of the frame.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GestureEvent {
+    Started(GestureKind),
+    Held {
+        kind: GestureKind,
+        duration: Duration,
+    },
+    Ended(GestureKind),
+    /// The gesture in progress moved with something other than
+    /// [`GestureMotion::Steady`] this frame. Swipes and pushes come once each.
+    Motion {
+        kind: GestureKind,
+        motion: GestureMotion,
+    },
+    /// A [`DwellConfig`] hold completed.
+    DwellCompleted(GestureKind),
+    /// The [`GestureSequence`] with this name completed.
+    SequenceMatched(String),
+    /// An open palm closed into a fist.
+    DragStarted {
+        pos: (f32, f32),
+    },
+    /// The fist moved by `delta` since the last event of the drag.
+    DragMoved {
+        pos: (f32, f32),
+        delta: (f32, f32),
+    },
+    /// The fist opened again, or with `cancelled` the hand was lost or turned
+    /// into something else.
+    DragEnded {
+        pos: (f32, f32),
+        cancelled: bool,
+    },
+}
+
 #[derive(Clone, Debug)]
 pub struct RecognizedFrame {
     pub frame: Frame,
     pub result: GestureResult,
 }
 
+/// A frame captured now with no hand in it; fill in the rest with struct
+/// update syntax, e.g. for synthetic results in tests and replays.
+impl Default for GestureResult {
+    fn default() -> Self {
+        Self {
+            confidence: 0.0,
+            timestamp: Instant::now(),
+            frame_id: 0,
+            captured_at: SystemTime::now(),
+            frame_size: (0, 0),
+            track_id: None,
+            landmarks: None,
+            raw_landmarks: None,
+            detail: None,
+            composite: None,
+            palm_regions: Vec::new(),
+            palm_debug: None,
+            dwell: None,
+            hands: Vec::new(),
+            frame_quality: FrameQuality::Good,
+        }
+    }
+}
+
 impl GestureResult {
+    /// The gesture panel's headline, in the current [`Locale`]: why the frame
+    /// was skipped, else the two-hand gesture, else the primary hand's.
+    pub fn label(&self) -> String {
+        if self.frame_quality != FrameQuality::Good {
+            return self.frame_quality.label().to_string();
+        }
+        // A two-hand gesture says more than either hand on its own.
+        if let Some(composite) = self.composite {
+            return format!("{}{}", composite.emoji(), composite.display_name());
+        }
+        match &self.detail {
+            Some(detail) => format!(
+                "{}{}",
+                detail.primary.emoji(),
+                detail.primary.display_name()
+            ),
+            None if self.hands.is_empty() => tr("未检测到手", "No hand").to_string(),
+            None => tr("检测到手", "Hand detected").to_string(),
+        }
+    }
+
     #[allow(dead_code)]
     pub fn display_text(&self) -> String {
         if let Some(detail) = &self.detail {
@@ -44,12 +684,13 @@ impl GestureResult {
                 self.confidence * 100.0
             )
         } else {
-            format!("{} ({:.0}%)", self.label, self.confidence * 100.0)
+            format!("{} ({:.0}%)", self.label(), self.confidence * 100.0)
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Handedness {
     Left,
     Right,
@@ -58,15 +699,29 @@ pub enum Handedness {
 
 impl Handedness {
     pub fn label(&self) -> &'static str {
+        self.label_in(locale())
+    }
+
+    pub fn label_in(&self, locale: Locale) -> &'static str {
+        let (zh, en) = match self {
+            Handedness::Left => ("左手", "Left hand"),
+            Handedness::Right => ("右手", "Right hand"),
+            Handedness::Unknown => ("未知", "Unknown"),
+        };
+        locale.pick(zh, en)
+    }
+
+    pub fn mirrored(self) -> Self {
         match self {
-            Handedness::Left => "左手",
-            Handedness::Right => "右手",
-            Handedness::Unknown => "未知",
+            Handedness::Left => Handedness::Right,
+            Handedness::Right => Handedness::Left,
+            Handedness::Unknown => Handedness::Unknown,
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum FingerState {
     Extended,
     HalfBent,
@@ -75,15 +730,21 @@ pub enum FingerState {
 
 impl FingerState {
     pub fn label(&self) -> &'static str {
-        match self {
-            FingerState::Extended => "伸直",
-            FingerState::HalfBent => "半弯",
-            FingerState::Folded => "弯曲",
-        }
+        self.label_in(locale())
+    }
+
+    pub fn label_in(&self, locale: Locale) -> &'static str {
+        let (zh, en) = match self {
+            FingerState::Extended => ("伸直", "Extended"),
+            FingerState::HalfBent => ("半弯", "Half bent"),
+            FingerState::Folded => ("弯曲", "Folded"),
+        };
+        locale.pick(zh, en)
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum GestureKind {
     Call,
     Dislike,
@@ -119,52 +780,121 @@ pub enum GestureKind {
     TwoUp,
     TwoUpInverted,
     XSign,
+    /// An open palm swinging side to side; promoted from the motion, not a
+    /// model class.
+    Wave,
+    /// Thumb up with the index pointing sideways, the other fingers folded;
+    /// told apart from the L shape by the landmark geometry.
+    FingerGun,
+    /// Index and middle extended with their tips crossed over.
+    CrossedFingers,
+    /// Rock with the thumb held out; the model reads both as Rock and the
+    /// thumb abduction tells them apart.
+    ILoveYou,
     Unknown,
+    /// A gesture a [`crate::gesture::plugin::GesturePlugin`] introduces,
+    /// saved as its bare id.
+    #[serde(untagged)]
+    Custom(CustomGestureId),
 }
 
 impl GestureKind {
+    /// Every classifiable gesture, in model class order, then the ones derived
+    /// from motion or geometry.
+    pub const ALL: [GestureKind; 38] = [
+        GestureKind::Call,
+        GestureKind::Dislike,
+        GestureKind::Fist,
+        GestureKind::Four,
+        GestureKind::Grabbing,
+        GestureKind::Grip,
+        GestureKind::HandHeart,
+        GestureKind::HandHeart2,
+        GestureKind::Holy,
+        GestureKind::Like,
+        GestureKind::LittleFinger,
+        GestureKind::MiddleFinger,
+        GestureKind::Mute,
+        GestureKind::NoGesture,
+        GestureKind::Ok,
+        GestureKind::One,
+        GestureKind::Palm,
+        GestureKind::Peace,
+        GestureKind::PeaceInverted,
+        GestureKind::Point,
+        GestureKind::Rock,
+        GestureKind::Stop,
+        GestureKind::StopInverted,
+        GestureKind::TakePicture,
+        GestureKind::Three,
+        GestureKind::Three2,
+        GestureKind::Three3,
+        GestureKind::ThreeGun,
+        GestureKind::ThumbIndex,
+        GestureKind::ThumbIndex2,
+        GestureKind::Timeout,
+        GestureKind::TwoUp,
+        GestureKind::TwoUpInverted,
+        GestureKind::XSign,
+        GestureKind::Wave,
+        GestureKind::FingerGun,
+        GestureKind::CrossedFingers,
+        GestureKind::ILoveYou,
+    ];
+
     pub fn display_name(&self) -> &'static str {
-        match self {
-            GestureKind::Call => "打电话",
-            GestureKind::Dislike => "点踩",
-            GestureKind::Fist => "握拳",
-            GestureKind::Four => "四指",
-            GestureKind::Grabbing => "抓取",
-            GestureKind::Grip => "握持",
-            GestureKind::HandHeart => "比心",
-            GestureKind::HandHeart2 => "比心2",
-            GestureKind::Holy => "祈祷",
-            GestureKind::Like => "点赞",
-            GestureKind::LittleFinger => "小指",
-            GestureKind::MiddleFinger => "中指",
-            GestureKind::Mute => "静音",
-            GestureKind::NoGesture => "无手势",
-            GestureKind::Ok => "OK",
-            GestureKind::One => "一",
-            GestureKind::Palm => "手掌",
-            GestureKind::Peace => "和平/剪刀手",
-            GestureKind::PeaceInverted => "倒V",
-            GestureKind::Point => "指向",
-            GestureKind::Rock => "摇滚",
-            GestureKind::Stop => "停止",
-            GestureKind::StopInverted => "倒停止",
-            GestureKind::TakePicture => "拍照",
-            GestureKind::Three => "三指",
-            GestureKind::Three2 => "三指2",
-            GestureKind::Three3 => "三指3",
-            GestureKind::ThreeGun => "三指枪",
-            GestureKind::ThumbIndex => "拇指食指",
-            GestureKind::ThumbIndex2 => "拇指食指2",
-            GestureKind::Timeout => "暂停",
-            GestureKind::TwoUp => "两指向上",
-            GestureKind::TwoUpInverted => "倒两指",
-            GestureKind::XSign => "X标志",
-            GestureKind::Unknown => "未知手势",
-        }
+        self.display_name_in(locale())
+    }
+
+    pub fn display_name_in(&self, locale: Locale) -> &'static str {
+        let (zh, en) = match self {
+            GestureKind::Custom(id) => return custom_gesture(*id).display_name,
+            GestureKind::Call => ("打电话", "Call"),
+            GestureKind::Dislike => ("点踩", "Dislike"),
+            GestureKind::Fist => ("握拳", "Fist"),
+            GestureKind::Four => ("四指", "Four"),
+            GestureKind::Grabbing => ("抓取", "Grabbing"),
+            GestureKind::Grip => ("握持", "Grip"),
+            GestureKind::HandHeart => ("比心", "Hand heart"),
+            GestureKind::HandHeart2 => ("比心2", "Hand heart 2"),
+            GestureKind::Holy => ("祈祷", "Pray"),
+            GestureKind::Like => ("点赞", "Like"),
+            GestureKind::LittleFinger => ("小指", "Little finger"),
+            GestureKind::MiddleFinger => ("中指", "Middle finger"),
+            GestureKind::Mute => ("静音", "Mute"),
+            GestureKind::NoGesture => ("无手势", "No gesture"),
+            GestureKind::Ok => ("OK", "OK"),
+            GestureKind::One => ("一", "One"),
+            GestureKind::Palm => ("手掌", "Palm"),
+            GestureKind::Peace => ("和平/剪刀手", "Peace"),
+            GestureKind::PeaceInverted => ("倒V", "Inverted peace"),
+            GestureKind::Point => ("指向", "Point"),
+            GestureKind::Rock => ("摇滚", "Rock"),
+            GestureKind::Stop => ("停止", "Stop"),
+            GestureKind::StopInverted => ("倒停止", "Inverted stop"),
+            GestureKind::TakePicture => ("拍照", "Take picture"),
+            GestureKind::Three => ("三指", "Three"),
+            GestureKind::Three2 => ("三指2", "Three 2"),
+            GestureKind::Three3 => ("三指3", "Three 3"),
+            GestureKind::ThreeGun => ("三指枪", "Three gun"),
+            GestureKind::ThumbIndex => ("拇指食指", "Thumb and index"),
+            GestureKind::ThumbIndex2 => ("拇指食指2", "Thumb and index 2"),
+            GestureKind::Timeout => ("暂停", "Timeout"),
+            GestureKind::TwoUp => ("两指向上", "Two up"),
+            GestureKind::TwoUpInverted => ("倒两指", "Inverted two up"),
+            GestureKind::XSign => ("X标志", "X sign"),
+            GestureKind::Wave => ("挥手", "Wave"),
+            GestureKind::FingerGun => ("手枪", "Finger gun"),
+            GestureKind::CrossedFingers => ("交叉手指", "Crossed fingers"),
+            GestureKind::ILoveYou => ("我爱你", "I love you"),
+            GestureKind::Unknown => ("未知手势", "Unknown gesture"),
+        };
+        locale.pick(zh, en)
     }
 
     pub fn emoji(&self) -> &'static str {
         match self {
+            GestureKind::Custom(id) => custom_gesture(*id).emoji,
             GestureKind::Call => "🤙 ",
             GestureKind::Dislike => "👎 ",
             GestureKind::Fist => "✊ ",
@@ -189,7 +919,7 @@ impl GestureKind {
             GestureKind::Stop => "✋ ",
             GestureKind::StopInverted => "🤚 ",
             GestureKind::TakePicture => "📸 ",
-            GestureKind::Three => "🤟 ",
+            GestureKind::Three => "3️⃣ ",
             GestureKind::Three2 => "👌 ",
             GestureKind::Three3 => "🤏 ",
             GestureKind::ThreeGun => "👈 ",
@@ -199,36 +929,346 @@ impl GestureKind {
             GestureKind::TwoUp => "✌️ ",
             GestureKind::TwoUpInverted => "🤞 ",
             GestureKind::XSign => "❌ ",
+            GestureKind::Wave => "👋 ",
+            GestureKind::FingerGun => "🔫 ",
+            GestureKind::CrossedFingers => "🤞 ",
+            GestureKind::ILoveYou => "🤟 ",
             GestureKind::Unknown => "⋯ ",
         }
     }
 }
 
+/// How a plugin's gesture is shown; registered once under its id, see
+/// [`register_custom_gesture`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CustomGesture {
+    /// snake_case like the built-in names, e.g. `vulcan_salute`.
+    pub id: &'static str,
+    pub display_name: &'static str,
+    pub emoji: &'static str,
+}
+
+impl CustomGesture {
+    pub const fn kind(&self) -> GestureKind {
+        GestureKind::Custom(CustomGestureId(self.id))
+    }
+}
+
+/// Emoji shown for custom ids that were loaded but never registered.
+const CUSTOM_GESTURE_EMOJI: &str = "✨ ";
+
+static CUSTOM_GESTURES: RwLock<Vec<CustomGesture>> = RwLock::new(Vec::new());
+
+/// Makes `gesture`'s name and emoji known, replacing an earlier entry with
+/// the same id. Ids must not collide with the built-in names.
+pub fn register_custom_gesture(gesture: CustomGesture) -> GestureKind {
+    let mut registry = CUSTOM_GESTURES
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match registry.iter_mut().find(|known| known.id == gesture.id) {
+        Some(known) => *known = gesture,
+        None => registry.push(gesture),
+    }
+    gesture.kind()
+}
+
+/// Every custom gesture registered or loaded so far, in registration order.
+pub fn custom_gestures() -> Vec<CustomGesture> {
+    CUSTOM_GESTURES
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// Ids that were never registered show as themselves.
+fn custom_gesture(id: CustomGestureId) -> CustomGesture {
+    let registry = CUSTOM_GESTURES
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    registry
+        .iter()
+        .find(|known| known.id == id.0)
+        .copied()
+        .unwrap_or(CustomGesture {
+            id: id.0,
+            display_name: id.0,
+            emoji: CUSTOM_GESTURE_EMOJI,
+        })
+}
+
+/// The id of a [`GestureKind::Custom`] gesture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CustomGestureId(&'static str);
+
+impl CustomGestureId {
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl Serialize for CustomGestureId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0)
+    }
+}
+
+/// Ids a config or recording names before their plugin is registered are
+/// kept for good under their own name, so each is allocated only once.
+impl<'de> Deserialize<'de> for CustomGestureId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = String::deserialize(deserializer)?;
+        if let Some(known) = custom_gestures().into_iter().find(|known| known.id == id) {
+            return Ok(CustomGestureId(known.id));
+        }
+        let id: &'static str = Box::leak(id.into_boxed_str());
+        register_custom_gesture(CustomGesture {
+            id,
+            display_name: id,
+            emoji: CUSTOM_GESTURE_EMOJI,
+        });
+        Ok(CustomGestureId(id))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum GestureMotion {
     Steady,
     Fanning,
     VerticalWave,
     Moving,
+    /// A single decisive stroke; reported for one frame only.
+    SwipeLeft,
+    SwipeRight,
+    SwipeUp,
+    SwipeDown,
+    /// Hand pushed toward the camera; reported for one frame only.
+    Approaching,
+    /// Hand pulled away from the camera; reported for one frame only.
+    Receding,
 }
 
 impl GestureMotion {
-    #[allow(dead_code)]
+    pub const ALL: [GestureMotion; 10] = [
+        GestureMotion::Steady,
+        GestureMotion::Fanning,
+        GestureMotion::VerticalWave,
+        GestureMotion::Moving,
+        GestureMotion::SwipeLeft,
+        GestureMotion::SwipeRight,
+        GestureMotion::SwipeUp,
+        GestureMotion::SwipeDown,
+        GestureMotion::Approaching,
+        GestureMotion::Receding,
+    ];
+
     pub fn label(&self) -> &'static str {
+        self.label_in(locale())
+    }
+
+    pub fn label_in(&self, locale: Locale) -> &'static str {
+        let (zh, en) = match self {
+            GestureMotion::Steady => ("保持", "Holding"),
+            GestureMotion::Fanning => ("左右扇动", "Fanning"),
+            GestureMotion::VerticalWave => ("上下挥动", "Waving up and down"),
+            GestureMotion::Moving => ("移动中", "Moving"),
+            GestureMotion::SwipeLeft => ("向左滑", "Swipe left"),
+            GestureMotion::SwipeRight => ("向右滑", "Swipe right"),
+            GestureMotion::SwipeUp => ("向上滑", "Swipe up"),
+            GestureMotion::SwipeDown => ("向下滑", "Swipe down"),
+            GestureMotion::Approaching => ("推近", "Pushing in"),
+            GestureMotion::Receding => ("拉远", "Pulling back"),
+        };
+        locale.pick(zh, en)
+    }
+}
+
+/// Gestures made with both hands together.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompositeGesture {
+    /// Two open palms meeting quickly; reported for one frame only.
+    Clap,
+    Heart,
+    /// Two L shapes marking opposite corners of a rectangle.
+    Frame,
+}
+
+impl CompositeGesture {
+    pub fn display_name(&self) -> &'static str {
+        self.display_name_in(locale())
+    }
+
+    pub fn display_name_in(&self, locale: Locale) -> &'static str {
+        let (zh, en) = match self {
+            CompositeGesture::Clap => ("拍手", "Clap"),
+            CompositeGesture::Heart => ("双手比心", "Two-hand heart"),
+            CompositeGesture::Frame => ("取景框", "Viewfinder"),
+        };
+        locale.pick(zh, en)
+    }
+
+    pub fn emoji(&self) -> &'static str {
         match self {
-            GestureMotion::Steady => "保持",
-            GestureMotion::Fanning => "左右扇动",
-            GestureMotion::VerticalWave => "上下挥动",
-            GestureMotion::Moving => "移动中",
+            CompositeGesture::Clap => "👏 ",
+            CompositeGesture::Heart => "🫶 ",
+            CompositeGesture::Frame => "🎬 ",
         }
     }
 }
 
-#[derive(Clone, Debug)]
+/// Flexion of one finger in degrees, measured on the normalized landmarks:
+/// 180 is straight and smaller is more bent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FingerAngles {
+    /// Knuckle angle between the wrist and the proximal bone; for the thumb the
+    /// angle at landmark 2.
+    pub mcp: f32,
+    /// Middle joint; for the thumb its single IP joint.
+    pub pip: f32,
+    /// Thumb only: spread between the thumb and index metacarpals.
+    #[serde(default)]
+    pub abduction: Option<f32>,
+    /// Thumb only: how far the tip stands out of the palm plane, in palm
+    /// lengths. Positive is in front of the palm, where the thumb opposes the
+    /// fingers for a pinch or an OK ring; negative is behind it.
+    #[serde(default)]
+    pub opposition: Option<f32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GestureDetail {
     pub primary: GestureKind,
+    /// Runner-up of `scores`, if any.
     pub secondary: Option<GestureKind>,
     pub handedness: Handedness,
+    /// How far the handedness score is from 0.5, 0–1; values near 0 are
+    /// close to a guess. 0 when `handedness` is `Unknown`.
+    #[serde(default)]
+    pub handedness_confidence: f32,
     pub finger_states: [FingerState; 5],
+    /// Thumb first, like `finger_states`.
+    #[serde(default)]
+    pub finger_angles: [FingerAngles; 5],
     pub motion: GestureMotion,
+    #[serde(default)]
+    pub pinch: Option<PinchInfo>,
+    /// Raised fingers, voted over the last few frames so it does not flicker.
+    /// Set even when `primary` is `Unknown`.
+    #[serde(default)]
+    pub count: Option<u8>,
+    /// Best candidate gestures with a 0–1 score each, highest first.
+    #[serde(default)]
+    pub scores: Vec<(GestureKind, f32)>,
+    /// Where the index finger is aimed, smoothed over frames. Only set while
+    /// `primary` is `Point` or `One`.
+    #[serde(default)]
+    pub point_direction: Option<PointingInfo>,
+    /// Set while the wrist goes round in a circle with a closed pinch or a
+    /// pointed finger, once it has turned about a third of the way.
+    #[serde(default)]
+    pub rotation: Option<RotationInfo>,
+    /// Which side of the hand faces the camera, e.g. a fist's knuckles or
+    /// its curled fingers. `None` when the handedness is unknown.
+    #[serde(default)]
+    pub orientation: Option<HandOrientation>,
+}
+
+impl GestureDetail {
+    /// A steady right hand showing `primary` with its fingers folded and
+    /// nothing else measured, for synthetic results.
+    pub fn new(primary: GestureKind) -> Self {
+        Self {
+            primary,
+            secondary: None,
+            handedness: Handedness::Right,
+            handedness_confidence: 1.0,
+            finger_states: [FingerState::Folded; 5],
+            finger_angles: Default::default(),
+            motion: GestureMotion::Steady,
+            pinch: None,
+            count: None,
+            scores: Vec::new(),
+            point_direction: None,
+            rotation: None,
+            orientation: None,
+        }
+    }
+
+    /// Raised fingers in this frame alone. The thumb only counts when fully
+    /// extended, since a half-bent thumb usually rests against the palm.
+    pub fn finger_count(&self, count_half_bent: bool) -> u8 {
+        let [thumb, fingers @ ..] = &self.finger_states;
+        let thumb = u8::from(*thumb == FingerState::Extended);
+        let fingers = fingers
+            .iter()
+            .filter(|state| match state {
+                FingerState::Extended => true,
+                FingerState::HalfBent => count_half_bent,
+                FingerState::Folded => false,
+            })
+            .count() as u8;
+        thumb + fingers
+    }
+}
+
+/// Direction of the index finger, knuckle to tip.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PointingInfo {
+    /// In the image plane, counterclockwise from pointing right, so 90 is up;
+    /// -180 to 180.
+    pub angle_deg: f32,
+    /// How much of the finger is aimed at the camera, from -1 (straight away)
+    /// to 1 (straight at it). Coarse, since landmark depth is.
+    pub toward: f32,
+}
+
+/// Which way the palm faces, from the palm's normal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HandOrientation {
+    /// Palm toward the camera; for a fist, the curled fingers.
+    PalmToward,
+    /// Back of the hand toward the camera; for a fist, the knuckles.
+    BackToward,
+    /// Palm seen edge-on, e.g. a fist from the thumb side.
+    Side,
+}
+
+impl HandOrientation {
+    pub fn label(&self) -> &'static str {
+        self.label_in(locale())
+    }
+
+    pub fn label_in(&self, locale: Locale) -> &'static str {
+        let (zh, en) = match self {
+            HandOrientation::PalmToward => ("掌心朝前", "Palm toward"),
+            HandOrientation::BackToward => ("手背朝前", "Back toward"),
+            HandOrientation::Side => ("侧面", "Side on"),
+        };
+        locale.pick(zh, en)
+    }
+}
+
+/// A dial being turned by the wrist, e.g. for volume. Clockwise on screen is
+/// positive.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RotationInfo {
+    /// Degrees turned since the rotation engaged, including the turn it took
+    /// to engage.
+    pub total_degrees: f32,
+    /// Degrees turned since the previous frame; zero on the frame it engages.
+    pub delta_degrees: f32,
+}
+
+/// Continuous thumb–index reading, e.g. for zoom or scroll.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PinchInfo {
+    /// Tip distance in palm lengths, so it does not depend on how far the hand
+    /// is from the camera.
+    pub distance: f32,
+    /// Smoothed change of `distance` since the pinch closed; zero while it is
+    /// open.
+    pub delta: f32,
+    pub closed: bool,
 }
@@ -0,0 +1,509 @@
+use std::time::Instant;
+
+use super::{
+    AnyElement, AppView, Button, Context, FluentBuilder, InteractiveElement, IntoElement,
+    ParentElement, SharedString, Styled, Window, div, h_flex, v_flex,
+};
+use crate::actions::{
+    ActionBinding, KeyCombo,
+    cursor::{Corner, CursorCalibration, CursorConfig},
+    default_actions_config_path,
+};
+use crate::locale::{self, Locale, locale, tr};
+use crate::types::{GestureKind, GestureMotion, GestureResult};
+use gpui::KeyDownEvent;
+use gpui_component::{StyledExt, button::ButtonVariants};
+
+impl AppView {
+    pub(super) fn render_actions_panel(
+        &self,
+        panel_width: f32,
+        cx: &mut Context<'_, Self>,
+    ) -> AnyElement {
+        let enabled = self.action_config.enabled;
+
+        let header = h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_sm()
+                    .font_semibold()
+                    .text_color(gpui::rgb(0xffffff))
+                    .child(tr("手势快捷键", "Gesture shortcuts")),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new(SharedString::from("actions-add"))
+                            .outline()
+                            .label(tr("+ 添加", "+ Add"))
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.action_config.bindings.push(ActionBinding::new(
+                                    GestureKind::Palm,
+                                    KeyCombo::default(),
+                                ));
+                                this.save_action_config();
+                                cx.notify();
+                            })),
+                    )
+                    .child({
+                        let button = Button::new(SharedString::from("actions-enabled"))
+                            .label(if enabled {
+                                tr("已启用", "Enabled")
+                            } else {
+                                tr("已停用", "Disabled")
+                            })
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                if this.action_config.enabled {
+                                    this.set_cursor_mode(false);
+                                }
+                                this.action_config.enabled = !this.action_config.enabled;
+                                this.action_dispatcher.reset();
+                                this.save_action_config();
+                                cx.notify();
+                            }));
+                        if enabled {
+                            button.primary()
+                        } else {
+                            button.outline()
+                        }
+                    }),
+            );
+
+        let mut rows = v_flex().gap_2();
+        for idx in 0..self.action_config.bindings.len() {
+            rows = rows.child(self.action_row(idx, cx));
+        }
+        if self.action_config.bindings.is_empty() {
+            rows = rows.child(div().text_xs().text_color(gpui::rgb(0x6b7280)).child(tr(
+                "还没有绑定，点击“添加”把手势映射到按键",
+                "No bindings yet, click “Add” to map a gesture to keys",
+            )));
+        }
+
+        let mouse_drag = h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("握拳拖动鼠标", "Drag the mouse with a fist")),
+            )
+            .child(
+                Button::new(SharedString::from("actions-mouse-drag"))
+                    .outline()
+                    .label(if self.action_config.mouse_drag {
+                        tr("开", "On")
+                    } else {
+                        tr("关", "Off")
+                    })
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.action_config.mouse_drag = !this.action_config.mouse_drag;
+                        this.save_action_config();
+                        cx.notify();
+                    })),
+            );
+
+        v_flex()
+            .w(super::px(panel_width))
+            .gap_3()
+            .p_4()
+            .rounded_lg()
+            .bg(gpui::rgb(0x0f172a))
+            .border_1()
+            .border_color(gpui::rgba(0xffffff1a))
+            .track_focus(&self.actions_focus)
+            .on_key_down(cx.listener(Self::record_action_key))
+            .child(header)
+            .child(rows)
+            .child(mouse_drag)
+            .child(self.render_cursor_controls(cx))
+            .into_any_element()
+    }
+
+    fn render_cursor_controls(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let active = self.cursor.is_active();
+        let config = &self.action_config.cursor;
+        let key = super::CURSOR_KEY.to_uppercase();
+        let panic = config.panic_gesture.display_name();
+        let hold = config.panic_hold.as_secs_f32();
+        let mode_text = match locale() {
+            Locale::ZhCn => format!("食指控制光标（{key} 或{panic}{hold:.1}秒）"),
+            Locale::En => {
+                format!("Index finger drives the cursor ({key} or {panic} for {hold:.1}s)")
+            }
+        };
+        let calibration_text = match &self.cursor_calibration {
+            Some(calibration) => {
+                let corner = calibration.corner();
+                let step = Corner::ALL.iter().position(|c| *c == corner).unwrap_or(0) + 1;
+                match locale() {
+                    Locale::ZhCn => format!("指向屏幕{}后捏合（{step}/4）", corner.label()),
+                    Locale::En => format!(
+                        "Point at the {} and pinch ({step}/4)",
+                        corner.label().to_lowercase()
+                    ),
+                }
+            }
+            None => tr("光标校准", "Cursor calibration").to_string(),
+        };
+        let label = |text: String| div().text_xs().text_color(gpui::rgb(0x94a3b8)).child(text);
+
+        v_flex()
+            .w_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .w_full()
+                    .justify_between()
+                    .items_center()
+                    .child(label(mode_text))
+                    .child(
+                        Button::new(SharedString::from("actions-cursor"))
+                            .outline()
+                            .label(locale::on_off(active))
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.toggle_cursor_mode();
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .justify_between()
+                    .items_center()
+                    .child(label(calibration_text))
+                    .child(
+                        Button::new(SharedString::from("actions-cursor-calibrate"))
+                            .outline()
+                            .label(if self.cursor_calibration.is_some() {
+                                tr("取消", "Cancel")
+                            } else {
+                                tr("校准", "Calibrate")
+                            })
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                if this.cursor_calibration.take().is_none() {
+                                    this.set_cursor_mode(false);
+                                    this.cursor_calibration = Some(CursorCalibration::new());
+                                }
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .items_center()
+                    .child(label(tr("增益", "Gain").to_string()))
+                    .child(
+                        Button::new(SharedString::from("actions-cursor-gain"))
+                            .outline()
+                            .label(format!("{:.2}×", config.gain))
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                let cursor = &mut this.action_config.cursor;
+                                cursor.gain = next_preset(&CursorConfig::GAIN_PRESETS, cursor.gain);
+                                this.cursor.set_config(this.action_config.cursor.clone());
+                                this.save_action_config();
+                                cx.notify();
+                            })),
+                    )
+                    .child(label(tr("死区", "Dead zone").to_string()))
+                    .child(
+                        Button::new(SharedString::from("actions-cursor-dead-zone"))
+                            .outline()
+                            .label(format!("{:.1}%", config.dead_zone * 100.0))
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                let cursor = &mut this.action_config.cursor;
+                                cursor.dead_zone =
+                                    next_preset(&CursorConfig::DEAD_ZONE_PRESETS, cursor.dead_zone);
+                                this.cursor.set_config(this.action_config.cursor.clone());
+                                this.save_action_config();
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn action_row(&self, idx: usize, cx: &mut Context<'_, Self>) -> AnyElement {
+        let binding = &self.action_config.bindings[idx];
+        let gesture_text = match &binding.sequence {
+            Some(name) => format!("🔗{name}"),
+            None => format!(
+                "{}{}",
+                binding.gesture.emoji(),
+                binding.gesture.display_name()
+            ),
+        };
+        let trigger_text = if binding.sequence.is_some() {
+            tr("组合", "Sequence")
+        } else {
+            binding
+                .motion
+                .map(|motion| motion.label())
+                .unwrap_or(tr("任意动作", "Any motion"))
+        };
+        let key_text = if self.action_recording == Some(idx) {
+            tr("按下按键…", "Press keys…").to_string()
+        } else {
+            binding.keys.label()
+        };
+
+        h_flex()
+            .w_full()
+            .gap_1()
+            .items_center()
+            .child(
+                Button::new(SharedString::from(format!("action-{idx}-prev")))
+                    .outline()
+                    .label("‹")
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        this.cycle_action_gesture(idx, -1);
+                        cx.notify();
+                    })),
+            )
+            .child(
+                div()
+                    .w(super::px(96.0))
+                    .text_sm()
+                    .text_color(gpui::rgb(0xe0f2fe))
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .whitespace_nowrap()
+                    .child(gesture_text),
+            )
+            .child(
+                Button::new(SharedString::from(format!("action-{idx}-next")))
+                    .outline()
+                    .label("›")
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        this.cycle_action_gesture(idx, 1);
+                        cx.notify();
+                    })),
+            )
+            .child(
+                Button::new(SharedString::from(format!("action-{idx}-motion")))
+                    .outline()
+                    .label(trigger_text)
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        this.cycle_action_trigger(idx);
+                        cx.notify();
+                    })),
+            )
+            .when(
+                binding.motion.is_none() && binding.sequence.is_none(),
+                |this| {
+                    this.child(
+                        Button::new(SharedString::from(format!("action-{idx}-confirm")))
+                            .outline()
+                            .label(binding.confirm.label())
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.cycle_action_confirm(idx);
+                                cx.notify();
+                            })),
+                    )
+                },
+            )
+            .child(
+                Button::new(SharedString::from(format!("action-{idx}-keys")))
+                    .outline()
+                    .label(key_text)
+                    .on_click(cx.listener(move |this, _, window, cx| {
+                        this.action_recording = Some(idx);
+                        window.focus(&this.actions_focus);
+                        cx.notify();
+                    })),
+            )
+            .child(div().flex_1())
+            .child(
+                Button::new(SharedString::from(format!("action-{idx}-remove")))
+                    .outline()
+                    .label("✕")
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        if idx < this.action_config.bindings.len() {
+                            this.action_config.bindings.remove(idx);
+                            this.action_recording = None;
+                            this.action_dispatcher.reset();
+                            this.save_action_config();
+                        }
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    fn record_action_key(
+        &mut self,
+        event: &KeyDownEvent,
+        _: &mut Window,
+        cx: &mut Context<'_, Self>,
+    ) {
+        let Some(idx) = self.action_recording else {
+            return;
+        };
+        self.action_recording = None;
+
+        let keystroke = &event.keystroke;
+        if keystroke.key == "escape" && !keystroke.modifiers.modified() {
+            cx.notify();
+            return;
+        }
+
+        if let Some(binding) = self.action_config.bindings.get_mut(idx) {
+            binding.keys = KeyCombo {
+                ctrl: keystroke.modifiers.control,
+                alt: keystroke.modifiers.alt,
+                shift: keystroke.modifiers.shift,
+                meta: keystroke.modifiers.platform,
+                key: keystroke.key.clone(),
+            };
+            self.save_action_config();
+        }
+        cx.stop_propagation();
+        cx.notify();
+    }
+
+    fn cycle_action_gesture(&mut self, idx: usize, step: isize) {
+        let gestures: Vec<GestureKind> = GestureKind::ALL
+            .into_iter()
+            .filter(|kind| *kind != GestureKind::NoGesture)
+            .collect();
+        let Some(binding) = self.action_config.bindings.get_mut(idx) else {
+            return;
+        };
+        let current = gestures
+            .iter()
+            .position(|kind| *kind == binding.gesture)
+            .unwrap_or(0) as isize;
+        let next = (current + step).rem_euclid(gestures.len() as isize) as usize;
+        binding.gesture = gestures[next];
+        binding.sequence = None;
+        self.action_dispatcher.reset();
+        self.save_action_config();
+    }
+
+    /// Steps through any motion, each motion, then each named sequence.
+    fn cycle_action_trigger(&mut self, idx: usize) {
+        // `(None, None)` means "any motion"; Steady is not a useful trigger on
+        // its own.
+        let mut triggers: Vec<(Option<GestureMotion>, Option<String>)> = vec![(None, None)];
+        triggers.extend(
+            GestureMotion::ALL
+                .into_iter()
+                .filter(|motion| *motion != GestureMotion::Steady)
+                .map(|motion| (Some(motion), None)),
+        );
+        triggers.extend(
+            self.sequences
+                .iter()
+                .map(|sequence| (None, Some(sequence.name.clone()))),
+        );
+        let Some(binding) = self.action_config.bindings.get_mut(idx) else {
+            return;
+        };
+        let current = triggers
+            .iter()
+            .position(|(motion, sequence)| {
+                *motion == binding.motion && *sequence == binding.sequence
+            })
+            .unwrap_or(0);
+        (binding.motion, binding.sequence) = triggers[(current + 1) % triggers.len()].clone();
+        self.action_dispatcher.reset();
+        self.save_action_config();
+    }
+
+    /// Also hands the compositor the bindings it times holds for.
+    fn save_action_config(&self) {
+        self.push_compositor_settings();
+        if let Err(err) = self.action_config.save(&default_actions_config_path()) {
+            log::warn!("failed to save actions: {err:#}");
+        }
+    }
+
+    pub(super) fn poll_hold_confirm(&mut self) {
+        while let Ok(idx) = self.hold_confirm_rx.try_recv() {
+            self.action_dispatcher
+                .confirm_hold(&self.action_config, idx, Instant::now());
+        }
+    }
+
+    fn cycle_action_confirm(&mut self, idx: usize) {
+        let Some(binding) = self.action_config.bindings.get_mut(idx) else {
+            return;
+        };
+        binding.confirm = binding.confirm.next();
+        self.action_dispatcher.reset();
+        self.save_action_config();
+    }
+
+    /// Feeds the calibration while one runs, otherwise the cursor mode, which
+    /// only runs while actions are on.
+    pub(super) fn observe_cursor(&mut self, result: &GestureResult) {
+        if let Some(calibration) = self.cursor_calibration.as_mut() {
+            if let Some(mapping) = calibration.observe(result) {
+                self.cursor_calibration = None;
+                self.action_config.cursor.mapping = mapping;
+                self.cursor.set_config(self.action_config.cursor.clone());
+                self.save_action_config();
+                self.capture_toast = Some((
+                    tr("光标校准完成", "Cursor calibrated").to_string(),
+                    Instant::now(),
+                ));
+            }
+            return;
+        }
+        if !self.action_config.enabled {
+            return;
+        }
+        let was_active = self.cursor.is_active();
+        let commands = self.cursor.observe(result);
+        self.action_dispatcher
+            .apply_cursor(&self.action_config, &commands);
+        if self.cursor.is_active() != was_active {
+            self.show_cursor_toast();
+        }
+    }
+
+    pub(super) fn toggle_cursor_mode(&mut self) {
+        if !self.action_config.enabled {
+            self.capture_toast = Some((
+                tr("请先启用手势快捷键", "Turn on gesture shortcuts first").to_string(),
+                Instant::now(),
+            ));
+            return;
+        }
+        self.set_cursor_mode(!self.cursor.is_active());
+        self.show_cursor_toast();
+    }
+
+    /// Lets go of a button the cursor mode holds when it turns off.
+    fn set_cursor_mode(&mut self, active: bool) {
+        let commands = self.cursor.set_active(active);
+        self.action_dispatcher
+            .apply_cursor(&self.action_config, &commands);
+    }
+
+    fn show_cursor_toast(&mut self) {
+        let text = if self.cursor.is_active() {
+            tr("光标模式已开启", "Cursor mode on")
+        } else {
+            tr("光标模式已关闭", "Cursor mode off")
+        };
+        self.capture_toast = Some((text.to_string(), Instant::now()));
+    }
+}
+
+/// The preset after `current`; a value set in the file goes back to the first.
+fn next_preset(presets: &[f32], current: f32) -> f32 {
+    presets
+        .iter()
+        .position(|preset| (preset - current).abs() < 1e-4)
+        .map_or(presets[0], |idx| presets[(idx + 1) % presets.len()])
+}
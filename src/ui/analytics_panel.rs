@@ -0,0 +1,162 @@
+use std::time::Instant;
+
+use super::{
+    AnyElement, AppView, Button, Context, IntoElement, ParentElement, SharedString, Styled, div,
+    h_flex, v_flex,
+};
+use crate::analytics::{ExportFormat, GestureAnalytics, KindSummary};
+use crate::locale::{self, Locale, locale, tr};
+use gpui_component::StyledExt;
+
+impl AppView {
+    pub(super) fn render_analytics_panel(
+        &self,
+        panel_width: f32,
+        cx: &mut Context<'_, Self>,
+    ) -> AnyElement {
+        let enabled = self.analytics.is_some();
+        let header = h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_sm()
+                    .font_semibold()
+                    .text_color(gpui::rgb(0xffffff))
+                    .child(tr("手势统计", "Gesture statistics")),
+            )
+            .child(
+                Button::new(SharedString::from("analytics-toggle"))
+                    .outline()
+                    .label(locale::on_off(enabled))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.analytics = match this.analytics {
+                            Some(_) => None,
+                            None => Some(GestureAnalytics::default()),
+                        };
+                        cx.notify();
+                    })),
+            );
+
+        let panel = v_flex()
+            .w(super::px(panel_width))
+            .gap_3()
+            .p_4()
+            .rounded_lg()
+            .bg(gpui::rgb(0x0f172a))
+            .border_1()
+            .border_color(gpui::rgba(0xffffff1a))
+            .child(header);
+        let Some(analytics) = &self.analytics else {
+            return panel
+                .child(div().text_xs().text_color(gpui::rgb(0x6b7280)).child(tr(
+                    "只在本机统计本次会话，打开后开始计数",
+                    "Counts this session on this machine only, starting when turned on",
+                )))
+                .into_any_element();
+        };
+
+        let summary = analytics.summary();
+        let efficiency = summary
+            .efficiency
+            .map_or("—".to_string(), |e| format!("{:.0}%", e * 100.0));
+        let mut rows = v_flex().gap_1();
+        for row in &summary.gestures {
+            rows = rows.child(analytics_row(row));
+        }
+        if summary.gestures.is_empty() {
+            rows = rows.child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x6b7280))
+                    .child(tr("还没有识别到手势", "No gestures recognized yet")),
+            );
+        }
+
+        let mut buttons = h_flex().gap_2().child(
+            Button::new(SharedString::from("analytics-reset"))
+                .outline()
+                .label(tr("重置", "Reset"))
+                .on_click(cx.listener(|this, _, _, cx| {
+                    if let Some(analytics) = this.analytics.as_mut() {
+                        analytics.reset();
+                    }
+                    cx.notify();
+                })),
+        );
+        for format in ExportFormat::ALL {
+            buttons = buttons.child(
+                Button::new(SharedString::from(format!(
+                    "analytics-export-{}",
+                    format.label().to_lowercase()
+                )))
+                .outline()
+                .label(format!("{} {}", tr("导出", "Export"), format.label()))
+                .on_click(cx.listener(move |this, _, _, cx| {
+                    this.export_analytics(format);
+                    cx.notify();
+                })),
+            );
+        }
+
+        panel
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(match locale() {
+                        Locale::ZhCn => format!("{} 帧，识别率 {efficiency}", summary.frames),
+                        Locale::En => format!("{} frames, recognized {efficiency}", summary.frames),
+                    }),
+            )
+            .child(rows)
+            .child(buttons)
+            .into_any_element()
+    }
+
+    fn export_analytics(&mut self, format: ExportFormat) {
+        let Some(analytics) = &self.analytics else {
+            return;
+        };
+        let message = match analytics
+            .summary()
+            .export(&self.settings.captures_dir(), format)
+        {
+            Ok(path) => format!("{}: {}", tr("已导出统计", "Exported stats"), path.display()),
+            Err(err) => {
+                log::warn!("analytics export failed: {err:#}");
+                format!("{}: {err:#}", tr("导出统计失败", "Stats export failed"))
+            }
+        };
+        self.capture_toast = Some((message, Instant::now()));
+    }
+}
+
+fn analytics_row(row: &KindSummary) -> AnyElement {
+    let confidence = row
+        .average_confidence
+        .map_or("—".to_string(), |c| format!("{:.0}%", c * 100.0));
+    h_flex()
+        .w_full()
+        .justify_between()
+        .items_center()
+        .child(
+            div()
+                .text_sm()
+                .text_color(gpui::rgb(0xe0f2fe))
+                .child(format!("{}{}", row.kind.emoji(), row.kind.display_name())),
+        )
+        .child(
+            div()
+                .text_xs()
+                .text_color(gpui::rgb(0x94a3b8))
+                .child(match locale() {
+                    Locale::ZhCn => {
+                        format!("{} 次 · {:.1} 秒 · {confidence}", row.count, row.held_secs)
+                    }
+                    Locale::En => format!("{}× · {:.1}s · {confidence}", row.count, row.held_secs),
+                }),
+        )
+        .into_any_element()
+}
@@ -0,0 +1,210 @@
+use std::time::Instant;
+
+use super::{
+    AnyElement, AppView, Button, Context, IntoElement, ParentElement, SharedString, Styled, div,
+    h_flex, v_flex,
+};
+use crate::locale::tr;
+use crate::pipeline::{
+    CameraControlInfo, CameraKnob, exposure_for_luma, recognizer::quality::region_luma,
+};
+use gpui_component::{Disableable, StyledExt};
+
+/// Clicks a stepper takes to cross a control's whole range, whatever its
+/// own step is; some drivers report exposure in single microseconds.
+const STEPS_PER_RANGE: i64 = 20;
+
+impl AppView {
+    pub(super) fn render_camera_tuning_panel(
+        &self,
+        panel_width: f32,
+        cx: &mut Context<'_, Self>,
+    ) -> AnyElement {
+        let controls = self.pipeline.camera().controls();
+        let mut rows = v_flex().gap_2();
+        for knob in CameraKnob::ALL {
+            let info = controls.iter().find(|info| info.knob == knob).copied();
+            rows = rows.child(self.camera_knob_row(knob, info, cx));
+        }
+        let can_optimize = controls
+            .iter()
+            .any(|info| info.knob == CameraKnob::Exposure);
+
+        v_flex()
+            .w(super::px(panel_width))
+            .gap_3()
+            .p_4()
+            .rounded_lg()
+            .bg(gpui::rgb(0x0f172a))
+            .border_1()
+            .border_color(gpui::rgba(0xffffff1a))
+            .child(
+                h_flex()
+                    .w_full()
+                    .justify_between()
+                    .items_center()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(gpui::rgb(0xffffff))
+                            .child(tr("摄像头调节", "Camera tuning")),
+                    )
+                    .child(
+                        Button::new(SharedString::from("camera-tuning-optimize"))
+                            .outline()
+                            .label(tr("为手部优化", "Tune for hands"))
+                            .disabled(!can_optimize)
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.optimize_exposure_for_hands();
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x6b7280))
+                    .child(tr("按手掌区域的亮度锁定曝光，背光时比整幅画面测光更准", "Locks exposure to the brightness of the palm, which beats metering the whole picture against a backlight")),
+            )
+            .child(rows)
+            .into_any_element()
+    }
+
+    /// A stepper for `knob`, greyed out when the device does not offer it.
+    fn camera_knob_row(
+        &self,
+        knob: CameraKnob,
+        info: Option<CameraControlInfo>,
+        cx: &mut Context<'_, Self>,
+    ) -> AnyElement {
+        let id = format!("{knob:?}").to_lowercase();
+        let step = info.map_or(1, |info| {
+            info.step
+                .max((info.max - info.min) / STEPS_PER_RANGE)
+                .max(1)
+        });
+        let (label_color, value_color) = if info.is_some() {
+            (0x94a3b8, 0xe0f2fe)
+        } else {
+            (0x4b5563, 0x4b5563)
+        };
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(label_color))
+                    .child(knob.label()),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(
+                        Button::new(SharedString::from(format!("camera-{id}-down")))
+                            .outline()
+                            .label("−")
+                            .disabled(info.is_none())
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.adjust_camera_control(knob, -step);
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        div()
+                            .w(super::px(56.0))
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(gpui::rgb(value_color))
+                            .child(match info {
+                                Some(info) => info.value.to_string(),
+                                None => tr("不支持", "Unsupported").to_string(),
+                            }),
+                    )
+                    .child(
+                        Button::new(SharedString::from(format!("camera-{id}-up")))
+                            .outline()
+                            .label("+")
+                            .disabled(info.is_none())
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.adjust_camera_control(knob, step);
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn adjust_camera_control(&mut self, knob: CameraKnob, delta: i64) {
+        let controls = self.pipeline.camera().controls();
+        let Some(info) = controls.iter().find(|info| info.knob == knob) else {
+            return;
+        };
+        self.set_camera_control(knob, info.clamp(info.value.saturating_add(delta)));
+    }
+
+    /// Meters the most confident palm on the frame on screen and sets the
+    /// exposure that brings it to the target brightness. The overlay's thin
+    /// lines barely move the average.
+    fn optimize_exposure_for_hands(&mut self) {
+        let controls = self.pipeline.camera().controls();
+        let Some(exposure) = controls
+            .iter()
+            .find(|info| info.knob == CameraKnob::Exposure)
+            .copied()
+        else {
+            self.capture_toast = Some((
+                tr("摄像头不支持调节曝光", "The camera cannot adjust exposure").to_string(),
+                Instant::now(),
+            ));
+            return;
+        };
+        let palm = self.latest_result.as_ref().and_then(|result| {
+            result
+                .palm_regions
+                .iter()
+                .max_by(|a, b| a.score.total_cmp(&b.score))
+        });
+        let luma = self
+            .latest_frame
+            .as_ref()
+            .zip(palm)
+            .and_then(|(frame, palm)| region_luma(frame, palm.bbox));
+        let Some(luma) = luma else {
+            self.capture_toast = Some((
+                tr("画面中没有手，无法测光", "No hand in view to meter").to_string(),
+                Instant::now(),
+            ));
+            return;
+        };
+        let value = exposure_for_luma(&exposure, luma);
+        self.set_camera_control(CameraKnob::Exposure, value);
+        self.capture_toast = Some((
+            format!("{} {value}", tr("曝光已锁定为", "Exposure locked at")),
+            Instant::now(),
+        ));
+    }
+
+    /// Applies `value` now and remembers it for the selected camera, so it is
+    /// set again whenever that camera is opened.
+    fn set_camera_control(&mut self, knob: CameraKnob, value: i64) {
+        self.pipeline.camera_mut().set_control(knob, value);
+        if self.video_file.is_some() {
+            return;
+        }
+        let Some(device) = self
+            .selected_camera_idx
+            .and_then(|idx| self.available_cameras.get(idx))
+        else {
+            return;
+        };
+        self.camera_controls
+            .entry(device.label.clone())
+            .or_default()
+            .insert(knob, value);
+    }
+}
@@ -1,9 +1,18 @@
 use super::{
-    ActiveTheme, AnyElement, AppView, CameraDevice, CameraState, Context, FluentBuilder,
-    InteractiveElement, IntoElement, ParentElement, Screen, Styled, StyledExt, Window, div, h_flex,
-    v_flex,
+    ActiveTheme, AnyElement, AppView, Button, CameraDevice, CameraIndex, CameraOpenOptions,
+    CameraState, CameraStatus, Context, FluentBuilder, InteractiveElement, IntoElement,
+    ParentElement, Screen, SharedString, Styled, StyledExt, Window, div, h_flex, v_flex,
 };
-use crate::pipeline;
+use crate::{
+    config::AppSettings,
+    locale::{self, Locale, tr},
+    pipeline::{
+        self, CameraProbe, DEFAULT_IMAGE_INTERVAL, FrameSource, RecognizerControl, describe_mode,
+    },
+};
+use crossbeam_channel::TryRecvError;
+use gpui_component::button::ButtonVariants;
+use std::{mem, path::PathBuf, time::Instant};
 
 impl AppView {
     fn render_camera_picker_startup(
@@ -33,13 +42,13 @@ impl AppView {
                     .text_lg()
                     .font_bold()
                     .text_color(gpui::rgb(0xffffff))
-                    .child("选择摄像头"),
+                    .child(tr("选择摄像头", "Choose a camera")),
             )
             .child(
                 div()
                     .text_xs()
                     .text_color(gpui::rgb(0x525252))
-                    .child(format!("可用设备: {}", cameras.len())),
+                    .child(format!("{}: {}", tr("可用设备", "Devices"), cameras.len())),
             );
 
         picker = picker.child(title_row);
@@ -151,7 +160,7 @@ impl AppView {
                             .text_sm()
                             .font_semibold()
                             .text_color(gpui::rgb(0xffffff))
-                            .child("选择摄像头"),
+                            .child(tr("选择摄像头", "Choose a camera")),
                     ),
             )
             .child(
@@ -249,6 +258,11 @@ impl AppView {
             );
         }
 
+        if let Some(formats) = self.render_camera_formats(cx) {
+            picker = picker.child(formats);
+        }
+        picker = picker.child(self.render_video_file_row(cx));
+
         if let Some(err) = &self.camera_error {
             picker = picker.child(
                 h_flex()
@@ -281,7 +295,196 @@ impl AppView {
         picker.into_any_element()
     }
 
-    pub(super) fn initial_camera_state() -> (CameraState, Vec<CameraDevice>) {
+    /// The selected camera's modes, "自动" first, with the mode the driver
+    /// actually negotiated next to the heading.
+    fn render_camera_formats(&self, cx: &mut Context<'_, Self>) -> Option<AnyElement> {
+        self.selected_camera_idx?;
+        let (_, formats) = self.camera_formats.as_ref()?;
+        if formats.is_empty() {
+            return None;
+        }
+
+        let mut buttons = h_flex().gap_1().flex_wrap();
+        let choices = std::iter::once(None).chain(formats.iter().copied().map(Some));
+        for (idx, choice) in choices.enumerate() {
+            let label = choice.map_or_else(|| tr("自动", "Auto").to_string(), |mode| mode.label());
+            let button = Button::new(SharedString::from(format!("camera-format-{idx}")))
+                .label(label)
+                .on_click(cx.listener(move |this, _, _, cx| {
+                    this.set_camera_format(choice);
+                    cx.notify();
+                }));
+            let button = if choice == self.camera_format {
+                button.primary()
+            } else {
+                button.outline()
+            };
+            buttons = buttons.child(button);
+        }
+
+        let negotiated = match &self.camera_status {
+            Some(CameraStatus::Streaming {
+                width,
+                height,
+                fps,
+                format,
+            }) => describe_mode(*width, *height, *fps, *format),
+            _ => String::new(),
+        };
+
+        Some(
+            v_flex()
+                .gap_1()
+                .mt_2()
+                .child(
+                    h_flex()
+                        .justify_between()
+                        .items_center()
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(gpui::rgb(0x94a3b8))
+                                .child(tr("格式", "Format")),
+                        )
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(gpui::rgb(0x525252))
+                                .child(negotiated),
+                        ),
+                )
+                .child(buttons)
+                .into_any_element(),
+        )
+    }
+
+    fn render_video_file_row(&mut self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let is_selected = self.video_file.is_some();
+        let loop_label = if self.loop_video {
+            tr("循环播放: 开", "Loop: on")
+        } else {
+            tr("循环播放: 关", "Loop: off")
+        };
+
+        h_flex()
+            .w_full()
+            .gap_3()
+            .items_center()
+            .p_3()
+            .rounded_lg()
+            .bg(if is_selected {
+                gpui::rgb(0x171717)
+            } else {
+                gpui::rgb(0x0a0a0a)
+            })
+            .border_1()
+            .border_color(if is_selected {
+                gpui::rgb(0x525252)
+            } else {
+                gpui::rgb(0x0a0a0a)
+            })
+            .child(
+                div()
+                    .flex_1()
+                    .cursor_pointer()
+                    .text_sm()
+                    .text_color(if is_selected {
+                        gpui::rgb(0xffffff)
+                    } else {
+                        gpui::rgb(0xa3a3a3)
+                    })
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .whitespace_nowrap()
+                    .hover(|this| this.text_color(gpui::rgb(0xffffff)))
+                    .on_mouse_down(
+                        gpui::MouseButton::Left,
+                        cx.listener(|this, _, _, cx| {
+                            this.open_video_file(cx);
+                        }),
+                    )
+                    .child(match &self.video_file {
+                        Some(path) => format!("▶ {}", video_label(path)),
+                        None => tr(
+                            "▶ 打开视频文件或图片文件夹…",
+                            "▶ Open a video file or image folder…",
+                        )
+                        .to_string(),
+                    }),
+            )
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .cursor_pointer()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .hover(|this| this.text_color(gpui::rgb(0xffffff)))
+                    .on_mouse_down(
+                        gpui::MouseButton::Left,
+                        cx.listener(|this, _, _, cx| {
+                            this.loop_video = !this.loop_video;
+                            cx.notify();
+                        }),
+                    )
+                    .child(loop_label),
+            )
+            .into_any_element()
+    }
+
+    fn open_video_file(&mut self, cx: &mut Context<'_, Self>) {
+        let paths = cx.prompt_for_paths(gpui::PathPromptOptions {
+            files: true,
+            directories: true,
+            multiple: false,
+            prompt: None,
+        });
+
+        cx.spawn(async move |this, cx| {
+            let Ok(Ok(Some(paths))) = paths.await else {
+                return;
+            };
+            let Some(path) = paths.into_iter().next() else {
+                return;
+            };
+            let _ = this.update(cx, |this, cx| {
+                this.start_video_file(path);
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    /// Plays a clip, or replays every image of a directory.
+    fn start_video_file(&mut self, path: PathBuf) {
+        let source = if path.is_dir() {
+            FrameSource::ImageSequence {
+                dir: path.clone(),
+                interval: DEFAULT_IMAGE_INTERVAL,
+                looping: self.loop_video,
+            }
+        } else {
+            FrameSource::VideoFile {
+                path: path.clone(),
+                looping: self.loop_video,
+            }
+        };
+        match self.pipeline.camera_mut().set_source(source) {
+            Ok(()) => {
+                self.selected_camera_idx = None;
+                self.video_file = Some(path);
+                self.clear_source_state();
+                self.camera_picker_open = false;
+            }
+            Err(err) => {
+                self.camera_error = Some(format!(
+                    "{}: {err:#}",
+                    tr("无法打开视频文件", "Cannot open the video file")
+                ));
+            }
+        }
+    }
+
+    pub(super) fn initial_camera_state(settings: &AppSettings) -> (CameraState, Vec<CameraDevice>) {
         match pipeline::available_cameras() {
             Ok(cameras) if cameras.is_empty() => (
                 CameraState::Unavailable {
@@ -289,14 +492,25 @@ impl AppView {
                 },
                 Vec::new(),
             ),
-            Ok(cameras) => (
-                CameraState::Selection {
-                    options: cameras.clone(),
-                    selected: 0,
-                    start_error: None,
-                },
-                cameras,
-            ),
+            Ok(cameras) => {
+                let state = match saved_camera(&cameras, settings) {
+                    SavedCamera::Found(selected) => CameraState::Selection {
+                        options: cameras.clone(),
+                        selected,
+                        start_error: None,
+                    },
+                    saved => CameraState::Detecting {
+                        options: cameras.clone(),
+                        probe: pipeline::probe_cameras(cameras.clone()),
+                        trying: 0,
+                        missing: match saved {
+                            SavedCamera::Missing(label) => Some(label),
+                            _ => None,
+                        },
+                    },
+                };
+                (state, cameras)
+            }
             Err(err) => {
                 log::error!("failed to enumerate cameras: {err:?}");
                 (
@@ -316,15 +530,32 @@ impl AppView {
         cx: &mut Context<'_, Self>,
     ) -> AnyElement {
         let (cam_color, cam_icon, cam_text) = match state {
-            CameraState::Unavailable { .. } => (gpui::hsla(0.0, 0.8, 0.5, 1.0), "!", "无设备"),
-            CameraState::Selection { .. } => (gpui::hsla(0.1, 0.8, 0.5, 1.0), "●", "选择中"),
-            CameraState::Ready => (gpui::hsla(0.3, 0.8, 0.5, 1.0), "●", "启动中"),
+            CameraState::Unavailable { .. } => (
+                gpui::hsla(0.0, 0.8, 0.5, 1.0),
+                "!",
+                tr("无设备", "No device"),
+            ),
+            CameraState::Detecting { .. } => (
+                gpui::hsla(0.1, 0.8, 0.5, 1.0),
+                "●",
+                tr("检测中", "Detecting"),
+            ),
+            CameraState::Selection { .. } => (
+                gpui::hsla(0.1, 0.8, 0.5, 1.0),
+                "●",
+                tr("选择中", "Choosing"),
+            ),
+            CameraState::Ready => (
+                gpui::hsla(0.3, 0.8, 0.5, 1.0),
+                "●",
+                tr("启动中", "Starting"),
+            ),
         };
 
         let titlebar = self.render_titlebar(
             gpui::hsla(0.0, 0.0, 0.5, 1.0),
             "○",
-            "未启动",
+            tr("未启动", "Not started"),
             cam_color,
             cam_icon,
             cam_text,
@@ -361,13 +592,13 @@ impl AppView {
                                         .text_lg()
                                         .font_bold()
                                         .text_color(gpui::rgb(0xffffff))
-                                        .child("没有可用摄像头"),
+                                        .child(tr("没有可用摄像头", "No camera available")),
                                 )
                                 .child(
                                     div()
                                         .text_xs()
                                         .text_color(gpui::rgb(0x525252))
-                                        .child("请检查连接"),
+                                        .child(tr("请检查连接", "Check the connection")),
                                 ),
                         )
                         .when(!message.is_empty(), |this| {
@@ -432,29 +663,118 @@ impl AppView {
                                             gpui::rgb(0xa3a3a3)
                                         })
                                         .child(if self.is_refreshing_cameras {
-                                            "刷新中..."
+                                            tr("刷新中...", "Refreshing...")
                                         } else {
-                                            "刷新摄像头列表"
+                                            tr("刷新摄像头列表", "Refresh camera list")
                                         }),
                                 ),
                         ),
                 )
                 .into_any_element(),
+            CameraState::Detecting {
+                options,
+                probe,
+                trying,
+                missing,
+            } => {
+                let mut outcome = None;
+                loop {
+                    match probe.try_recv() {
+                        Ok(CameraProbe::Trying(idx)) => *trying = idx,
+                        Ok(CameraProbe::Done(live)) => {
+                            outcome = Some(live);
+                            break;
+                        }
+                        Err(TryRecvError::Empty) => break,
+                        Err(TryRecvError::Disconnected) => {
+                            outcome = Some(None);
+                            break;
+                        }
+                    }
+                }
+                if let Some(live) = outcome {
+                    let (options, missing) = (mem::take(options), missing.take());
+                    *state = self.finish_camera_detection(options, live, missing);
+                    return div()
+                        .child(div().child(tr("正在启动摄像头...", "Starting camera...")))
+                        .into_any_element();
+                }
+
+                let label = options
+                    .get(*trying)
+                    .map(|device| device.label.clone())
+                    .unwrap_or_default();
+                div()
+                    .flex_1()
+                    .w_full()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .bg(gpui::rgb(0x1a2332))
+                    .child(
+                        v_flex()
+                            .w(super::px(super::STARTUP_CARD_WIDTH))
+                            .gap_2()
+                            .p_4()
+                            .rounded_xl()
+                            .bg(gpui::rgb(0x0a0a0a))
+                            .border_1()
+                            .border_color(gpui::rgb(0x262626))
+                            .shadow_xl()
+                            .child(
+                                div()
+                                    .text_lg()
+                                    .font_bold()
+                                    .text_color(gpui::rgb(0xffffff))
+                                    .child(tr("正在检测摄像头…", "Looking for cameras…")),
+                            )
+                            .when_some(missing.as_ref(), |this, missing| {
+                                this.child(div().text_xs().text_color(gpui::rgb(0xa3a3a3)).child(
+                                    match locale::locale() {
+                                        Locale::ZhCn => format!("上次使用的「{missing}」未连接"),
+                                        Locale::En => {
+                                            format!("“{missing}”, used last time, is not connected")
+                                        }
+                                    },
+                                ))
+                            })
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(gpui::rgb(0x525252))
+                                    .overflow_hidden()
+                                    .text_ellipsis()
+                                    .whitespace_nowrap()
+                                    .child(format!(
+                                        "{}/{} {label}",
+                                        (*trying + 1).min(options.len()),
+                                        options.len()
+                                    )),
+                            ),
+                    )
+                    .into_any_element()
+            }
             CameraState::Selection {
                 options,
                 selected,
                 start_error,
             } => {
-                if options.len() == 1 && self.camera_stream.is_none() && start_error.is_none() {
+                if options.len() == 1
+                    && !self.pipeline.camera().is_running()
+                    && start_error.is_none()
+                {
                     match self.start_camera_for_device(&options[0]) {
                         Ok(()) => {
                             *state = CameraState::Ready;
                             return div()
-                                .child(div().child("正在启动摄像头..."))
+                                .child(div().child(tr("正在启动摄像头...", "Starting camera...")))
                                 .into_any_element();
                         }
                         Err(err) => {
-                            *start_error = Some(format!("无法启动摄像头: {err}"));
+                            *start_error = Some(format!(
+                                "{}: {err}",
+                                tr("无法启动摄像头", "Cannot start the camera")
+                            ));
                         }
                     }
                 }
@@ -493,7 +813,7 @@ impl AppView {
                                 div()
                                     .text_sm()
                                     .text_color(theme.foreground)
-                                    .child("⟳ 正在启动摄像头..."),
+                                    .child(tr("⟳ 正在启动摄像头...", "⟳ Starting camera...")),
                             ),
                     )
                     .into_any_element()
@@ -509,7 +829,8 @@ impl AppView {
 
     pub(super) fn switch_camera(&mut self, idx: usize) {
         if idx >= self.available_cameras.len() {
-            self.camera_error = Some("无法找到所选摄像头".to_string());
+            self.camera_error =
+                Some(tr("无法找到所选摄像头", "Cannot find the chosen camera").to_string());
             return;
         }
 
@@ -521,7 +842,10 @@ impl AppView {
                 self.camera_picker_open = false;
             }
             Err(err) => {
-                self.camera_error = Some(format!("无法启动摄像头: {err}"));
+                self.camera_error = Some(format!(
+                    "{}: {err}",
+                    tr("无法启动摄像头", "Cannot start the camera")
+                ));
             }
         }
     }
@@ -532,36 +856,72 @@ impl AppView {
             selected: current,
             start_error,
         }) = &mut self.screen
+            && selected < options.len()
         {
-            if selected < options.len() {
-                *current = selected;
-                *start_error = None;
-                self.selected_camera_idx = Some(selected);
-                self.available_cameras = options.clone();
-            }
-        }
-    }
-
-    fn stop_camera_stream(&mut self) {
-        if let Some(stream) = self.camera_stream.take() {
-            stream.stop();
+            *current = selected;
+            *start_error = None;
+            self.selected_camera_idx = Some(selected);
+            self.available_cameras = options.clone();
         }
     }
 
     fn start_camera_for_device(&mut self, device: &CameraDevice) -> Result<(), String> {
-        self.stop_camera_stream();
-
-        pipeline::start_camera_stream(device.index.clone(), self.camera_frame_tx.clone())
-            .map(|stream| {
-                self.camera_stream = Some(stream);
-                self.latest_frame = None;
-                self.latest_result = None;
-                self.latest_image = None;
-                self.camera_error = None;
+        self.load_camera_formats(&device.index);
+        let saved = self.camera_controls.get(&device.label).cloned();
+        let camera = self.pipeline.camera_mut();
+        camera.set_saved_controls(saved.unwrap_or_default());
+        camera
+            .set_camera(device.index.clone(), self.camera_format)
+            .map(|()| {
+                self.video_file = None;
+                self.clear_source_state();
             })
             .map_err(|err| format!("{err:#}"))
     }
 
+    /// Lists the modes before the device is streamed from, since some drivers
+    /// refuse a second handle, and keeps them while it stays selected.
+    fn load_camera_formats(&mut self, index: &CameraIndex) {
+        if self
+            .camera_formats
+            .as_ref()
+            .is_some_and(|(listed, _)| listed == index)
+        {
+            return;
+        }
+        let formats = pipeline::supported_formats(index).unwrap_or_else(|err| {
+            log::warn!("{err:#}");
+            Vec::new()
+        });
+        self.camera_formats = Some((index.clone(), formats));
+    }
+
+    /// Reopens the selected camera in `format`, or with the driver's choice.
+    fn set_camera_format(&mut self, format: Option<CameraOpenOptions>) {
+        if format == self.camera_format {
+            return;
+        }
+        self.camera_format = format;
+        if let Some(idx) = self.selected_camera_idx {
+            self.switch_camera(idx);
+        }
+    }
+
+    /// Forgets everything derived from the previous source, including the
+    /// recognizer's motion history so stale wrist positions cannot read as a
+    /// swipe on the new feed.
+    fn clear_source_state(&mut self) {
+        self.latest_frame = None;
+        self.latest_result = None;
+        self.stable_gesture = None;
+        self.latest_image = None;
+        self.camera_error = None;
+        self.camera_status = None;
+        let _ = self
+            .recognizer_control_tx
+            .send(RecognizerControl::ResetTracking);
+    }
+
     fn start_selected_camera(&mut self) {
         let selected_device = match &self.screen {
             Screen::Camera(CameraState::Selection {
@@ -578,7 +938,8 @@ impl AppView {
 
         let Some((selected_idx, device)) = selected_device else {
             if let Screen::Camera(CameraState::Selection { start_error, .. }) = &mut self.screen {
-                *start_error = Some("无法找到所选摄像头".to_string());
+                *start_error =
+                    Some(tr("无法找到所选摄像头", "Cannot find the chosen camera").to_string());
             }
             return;
         };
@@ -594,20 +955,121 @@ impl AppView {
             Err(err) => {
                 if let Screen::Camera(CameraState::Selection { start_error, .. }) = &mut self.screen
                 {
-                    *start_error = Some(format!("无法启动摄像头: {err}"));
+                    *start_error = Some(format!(
+                        "{}: {err}",
+                        tr("无法启动摄像头", "Cannot start the camera")
+                    ));
                 }
             }
         }
     }
 
+    /// Starts the camera the probe found, remembered like a picked one, or
+    /// falls back to the picker when none showed a picture.
+    fn finish_camera_detection(
+        &mut self,
+        options: Vec<CameraDevice>,
+        live: Option<usize>,
+        missing: Option<String>,
+    ) -> CameraState {
+        let Some(idx) = live.filter(|&idx| idx < options.len()) else {
+            log::warn!("no camera delivered a picture while probing");
+            return CameraState::Selection {
+                options,
+                selected: 0,
+                start_error: Some(
+                    tr(
+                        "没有检测到有画面的摄像头，请手动选择",
+                        "No camera delivered a picture, please choose one",
+                    )
+                    .to_string(),
+                ),
+            };
+        };
+        let device = options[idx].clone();
+        self.available_cameras = options.clone();
+        match self.start_camera_for_device(&device) {
+            Ok(()) => {
+                self.selected_camera_idx = Some(idx);
+                self.camera_error = None;
+                if let Some(missing) = missing {
+                    log::info!(
+                        "saved camera {missing:?} is not connected, using {:?}",
+                        device.label
+                    );
+                    let notice = match locale::locale() {
+                        Locale::ZhCn => format!("「{missing}」未连接，已改用「{}」", device.label),
+                        Locale::En => {
+                            format!(
+                                "“{missing}” is not connected, using “{}” instead",
+                                device.label
+                            )
+                        }
+                    };
+                    self.capture_toast = Some((notice, Instant::now()));
+                } else {
+                    log::info!("picked camera {:?}", device.label);
+                }
+                CameraState::Ready
+            }
+            Err(err) => CameraState::Selection {
+                options,
+                selected: idx,
+                start_error: Some(format!(
+                    "{}: {err}",
+                    tr("无法启动摄像头", "Cannot start the camera")
+                )),
+            },
+        }
+    }
+
     pub(super) fn refresh_cameras(&mut self) {
-        let (new_state, new_cameras) = Self::initial_camera_state();
+        let (new_state, new_cameras) = Self::initial_camera_state(&self.settings);
         self.screen = Screen::Camera(new_state);
         self.available_cameras = new_cameras;
-        self.selected_camera_idx = if self.available_cameras.is_empty() {
-            None
-        } else {
-            Some(0)
-        };
+        self.selected_camera_idx = preferred_camera_idx(&self.available_cameras, &self.settings);
     }
 }
+
+enum SavedCamera {
+    Found(usize),
+    /// Saved by label, which no connected camera has.
+    Missing(String),
+    Unset,
+}
+
+/// Where the saved camera is in `cameras`. Settings from before labels were
+/// saved only have an index, which is trusted as long as it is in range.
+fn saved_camera(cameras: &[CameraDevice], settings: &AppSettings) -> SavedCamera {
+    match (&settings.camera_label, settings.camera_index) {
+        (Some(label), _) => cameras
+            .iter()
+            .position(|device| &device.label == label)
+            .map_or_else(|| SavedCamera::Missing(label.clone()), SavedCamera::Found),
+        (None, Some(idx)) if idx < cameras.len() => SavedCamera::Found(idx),
+        (None, _) => SavedCamera::Unset,
+    }
+}
+
+/// The saved camera if it is still connected, matched by label before index
+/// because indices shift when devices are plugged in or out.
+pub(super) fn preferred_camera_idx(
+    cameras: &[CameraDevice],
+    settings: &AppSettings,
+) -> Option<usize> {
+    if cameras.is_empty() {
+        return None;
+    }
+    let by_label = settings
+        .camera_label
+        .as_deref()
+        .and_then(|label| cameras.iter().position(|device| device.label == label));
+    let by_index = settings.camera_index.filter(|&idx| idx < cameras.len());
+    Some(by_label.or(by_index).unwrap_or(0))
+}
+
+pub(super) fn video_label(path: &std::path::Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
@@ -0,0 +1,123 @@
+use std::time::Instant;
+
+use crossbeam_channel::{Receiver, TryRecvError};
+
+use super::{AnyElement, AppView, Button, Context, IntoElement, SharedString};
+use crate::locale::tr;
+use crate::pipeline::{ClipBuffer, ClipExport, ClipFormat, spawn_clip_export};
+use gpui_component::Disableable;
+
+/// The composited frames kept for "export the last few seconds", and the
+/// export in flight.
+#[derive(Default)]
+pub(super) struct ClipExporter {
+    /// Shared with the compositor, which fills it.
+    pub(super) buffer: ClipBuffer,
+    pub(super) format: ClipFormat,
+    rx: Option<Receiver<ClipExport>>,
+    /// Frames written and frames in the clip.
+    progress: Option<(usize, usize)>,
+}
+
+impl ClipExporter {
+    pub(super) fn new(format: ClipFormat) -> Self {
+        Self {
+            format,
+            ..Self::default()
+        }
+    }
+}
+
+impl AppView {
+    pub(super) fn render_clip_button(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let exporter = &self.clip_exporter;
+        let label = match (&exporter.rx, exporter.progress) {
+            (Some(_), Some((written, total))) => {
+                format!(
+                    "{} {}%",
+                    tr("导出中", "Exporting"),
+                    written * 100 / total.max(1)
+                )
+            }
+            (Some(_), None) => tr("导出中…", "Exporting…").to_string(),
+            (None, _) => format!(
+                "▣ {} ({})",
+                tr("导出片段", "Export clip"),
+                exporter.format.label()
+            ),
+        };
+        Button::new(SharedString::from("export-clip"))
+            .outline()
+            .label(label)
+            .disabled(self.privacy_mode)
+            .on_click(cx.listener(|this, _, _, cx| {
+                this.export_clip();
+                cx.notify();
+            }))
+            .into_any_element()
+    }
+
+    /// Writes the last few seconds of the composited feed, overlay included,
+    /// to the captures directory.
+    fn export_clip(&mut self) {
+        if self.clip_exporter.rx.is_some() {
+            return;
+        }
+        if self.privacy_mode {
+            self.capture_toast = Some((
+                tr(
+                    "隐私模式下不能导出片段",
+                    "Clip export is off in privacy mode",
+                )
+                .to_string(),
+                Instant::now(),
+            ));
+            return;
+        }
+        let frames = self.clip_exporter.buffer.snapshot();
+        if frames.is_empty() {
+            self.capture_toast = Some((
+                tr("还没有可导出的画面", "No frames to export yet").to_string(),
+                Instant::now(),
+            ));
+            return;
+        }
+        self.clip_exporter.progress = None;
+        self.clip_exporter.rx = Some(spawn_clip_export(
+            self.settings.captures_dir(),
+            frames,
+            self.clip_exporter.format,
+        ));
+    }
+
+    pub(super) fn poll_clip_export(&mut self) {
+        let Some(rx) = &self.clip_exporter.rx else {
+            return;
+        };
+        let done = loop {
+            match rx.try_recv() {
+                Ok(ClipExport::Progress { written, total }) => {
+                    self.clip_exporter.progress = Some((written, total));
+                }
+                Ok(ClipExport::Done(done)) => break done,
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => {
+                    break Err(anyhow::anyhow!(tr(
+                        "写入线程意外退出",
+                        "the writer thread exited unexpectedly"
+                    )));
+                }
+            }
+        };
+        let message = match done {
+            Ok(path) => format!("{}: {}", tr("已导出片段", "Exported clip"), path.display()),
+            Err(err) => {
+                log::warn!("clip export failed: {err:#}");
+                format!("{}: {err:#}", tr("导出片段失败", "Clip export failed"))
+            }
+        };
+        self.clip_exporter.rx = None;
+        self.clip_exporter.progress = None;
+        self.capture_toast = Some((message, Instant::now()));
+    }
+}
@@ -2,6 +2,7 @@ use super::{
     AnyElement, AppView, Context, DownloadMessage, DownloadState, IntoElement, ParentElement,
     RecognizerBackend, Sender, Styled, StyledExt, div, h_flex, thread, v_flex,
 };
+use crate::locale::tr;
 use crate::model_download::{
     ensure_gesture_classifier_model_ready, ensure_handpose_estimator_model_ready,
     ensure_palm_detector_model_ready,
@@ -16,7 +17,7 @@ impl AppView {
                 DownloadMessage::Error(err) => {
                     state.error = Some(err);
                     state.finished = false;
-                    state.message = "Model download failed".to_string();
+                    state.message = tr("模型下载失败", "Model download failed").to_string();
                 }
             }
         }
@@ -29,7 +30,7 @@ impl AppView {
     ) -> AnyElement {
         let bar = progress_bar_string(state.downloaded, state.total);
         let detail = match (state.total, state.finished) {
-            (_, true) => "下载完成".to_string(),
+            (_, true) => tr("下载完成", "Download complete").to_string(),
             (Some(total), false) if total > 0 => {
                 let percent = (state.downloaded as f64 / total as f64 * 100.0).clamp(0.0, 100.0);
                 format!("{percent:.1}%")
@@ -38,11 +39,15 @@ impl AppView {
         };
 
         let (status_icon, status_text, status_color) = if state.finished && state.error.is_none() {
-            ("✓", "模型就绪", gpui::rgb(0x4ade80))
+            ("✓", tr("模型就绪", "Models ready"), gpui::rgb(0x4ade80))
         } else if state.error.is_some() {
-            ("✕", "下载失败", gpui::rgb(0xf87171))
+            ("✕", tr("下载失败", "Download failed"), gpui::rgb(0xf87171))
         } else {
-            ("⟳", "正在下载模型...", gpui::rgb(0xe2e8f0))
+            (
+                "⟳",
+                tr("正在下载模型...", "Downloading models..."),
+                gpui::rgb(0xe2e8f0),
+            )
         };
 
         let mut container = v_flex()
@@ -119,7 +124,7 @@ impl AppView {
                             .text_sm()
                             .font_semibold()
                             .text_color(gpui::rgb(0xfca5a5))
-                            .child("错误详情"),
+                            .child(tr("错误详情", "Error details")),
                     )
                     .child(
                         div()
@@ -154,8 +159,8 @@ pub(super) fn spawn_model_download(
         if let Err(err) = ensure_palm_detector_model_ready(&palm_detector_model_path, |event| {
             let _ = tx.send(DownloadMessage::Event(event));
         }) {
-            log::error!("failed to prepare palm detector model: {err:?}");
-            let _ = tx.send(DownloadMessage::Error(format!("{err:#}")));
+            log::error!("failed to prepare palm detector model: {}", err.describe());
+            let _ = tx.send(DownloadMessage::Error(err.describe()));
             return;
         }
 
@@ -164,8 +169,11 @@ pub(super) fn spawn_model_download(
                 let _ = tx.send(DownloadMessage::Event(event));
             })
         {
-            log::error!("failed to prepare handpose estimator model: {err:?}");
-            let _ = tx.send(DownloadMessage::Error(format!("{err:#}")));
+            log::error!(
+                "failed to prepare handpose estimator model: {}",
+                err.describe()
+            );
+            let _ = tx.send(DownloadMessage::Error(err.describe()));
             return;
         }
 
@@ -174,8 +182,11 @@ pub(super) fn spawn_model_download(
                 let _ = tx.send(DownloadMessage::Event(event));
             })
         {
-            log::error!("failed to prepare gesture classifier model: {err:?}");
-            let _ = tx.send(DownloadMessage::Error(format!("{err:#}")));
+            log::error!(
+                "failed to prepare gesture classifier model: {}",
+                err.describe()
+            );
+            let _ = tx.send(DownloadMessage::Error(err.describe()));
         }
     })
 }
@@ -197,8 +208,9 @@ fn progress_bar_string(downloaded: u64, total: Option<u64>) -> String {
         _ => {
             let spinner_width = ((downloaded / 64) as usize % (BAR_LEN.max(1))) + 1;
             format!(
-                "[{:-<width$}] unknown size",
+                "[{:-<width$}] {}",
                 ">",
+                tr("大小未知", "unknown size"),
                 width = spinner_width.min(BAR_LEN)
             )
         }
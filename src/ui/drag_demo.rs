@@ -0,0 +1,124 @@
+use super::{
+    AnyElement, AppView, Button, Context, FluentBuilder, IntoElement, ParentElement, SharedString,
+    Styled, div, h_flex,
+};
+use crate::locale::{self, tr};
+use crate::types::GestureEvent;
+
+/// Side of the demo box as a fraction of the frame width.
+const BOX_SIZE: f32 = 0.18;
+/// How far outside the box, as a fraction of the frame, a grab still takes it.
+const GRAB_MARGIN: f32 = 0.05;
+
+/// A box on the preview that a fist can pick up and carry, to try out drags.
+pub(super) struct DragDemo {
+    pub(super) enabled: bool,
+    /// Top-left corner as fractions of the frame.
+    origin: (f32, f32),
+    held: bool,
+}
+
+impl Default for DragDemo {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            origin: (0.5 - BOX_SIZE / 2.0, 0.4),
+            held: false,
+        }
+    }
+}
+
+impl AppView {
+    pub(super) fn observe_drag_demo(&mut self, event: &GestureEvent) {
+        let ratio = self.camera_aspect_ratio();
+        let demo = &mut self.drag_demo;
+        if !demo.enabled {
+            return;
+        }
+        let size = (BOX_SIZE, BOX_SIZE * ratio);
+        match *event {
+            GestureEvent::DragStarted { pos: (x, y) } => {
+                let (left, top) = demo.origin;
+                demo.held = (left - GRAB_MARGIN..=left + size.0 + GRAB_MARGIN).contains(&x)
+                    && (top - GRAB_MARGIN..=top + size.1 + GRAB_MARGIN).contains(&y);
+            }
+            GestureEvent::DragMoved { delta, .. } if demo.held => {
+                demo.origin = (
+                    (demo.origin.0 + delta.0).clamp(0.0, (1.0 - size.0).max(0.0)),
+                    (demo.origin.1 + delta.1).clamp(0.0, (1.0 - size.1).max(0.0)),
+                );
+            }
+            GestureEvent::DragEnded { .. } => demo.held = false,
+            _ => {}
+        }
+    }
+
+    /// The box over a preview of `width` by `height` pixels, placed on the
+    /// frame the preview fits inside it.
+    pub(super) fn render_drag_demo(&self, width: f32, height: f32) -> Option<AnyElement> {
+        let demo = &self.drag_demo;
+        if !demo.enabled || width <= 0.0 || height <= 0.0 {
+            return None;
+        }
+        let ratio = self.camera_aspect_ratio();
+        let (shown_width, shown_height) = if width / height > ratio {
+            (height * ratio, height)
+        } else {
+            (width, width / ratio)
+        };
+        let left = (width - shown_width) / 2.0 + demo.origin.0 * shown_width;
+        let top = (height - shown_height) / 2.0 + demo.origin.1 * shown_height;
+        let side = BOX_SIZE * shown_width;
+
+        let element = div()
+            .absolute()
+            .left(super::px(left))
+            .top(super::px(top))
+            .w(super::px(side))
+            .h(super::px(side))
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded_md()
+            .border_2()
+            .text_xs()
+            .map(|this| {
+                if demo.held {
+                    this.bg(gpui::rgba(0xf59e0b66))
+                        .border_color(gpui::rgb(0xf59e0b))
+                        .text_color(gpui::rgb(0xfef3c7))
+                        .child(tr("抓住了", "Grabbed"))
+                } else {
+                    this.bg(gpui::rgba(0x38bdf833))
+                        .border_color(gpui::rgb(0x38bdf8))
+                        .text_color(gpui::rgb(0xe0f2fe))
+                        .child(tr("握拳抓我", "Make a fist to grab me"))
+                }
+            });
+        Some(element.into_any_element())
+    }
+
+    pub(super) fn drag_demo_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("拖拽演示", "Drag demo")),
+            )
+            .child(
+                Button::new(SharedString::from("drag-demo-toggle"))
+                    .outline()
+                    .label(locale::on_off(self.drag_demo.enabled))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.drag_demo.enabled = !this.drag_demo.enabled;
+                        this.drag_demo.held = false;
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+}
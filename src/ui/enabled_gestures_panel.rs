@@ -0,0 +1,89 @@
+use super::{
+    AnyElement, AppView, Button, Context, IntoElement, ParentElement, SharedString, Styled, div,
+    h_flex, v_flex,
+};
+use crate::{locale::tr, pipeline::RecognizerControl, types::GestureKind};
+use gpui_component::{StyledExt, checkbox::Checkbox};
+
+impl AppView {
+    pub(super) fn render_enabled_gestures_panel(
+        &self,
+        panel_width: f32,
+        cx: &mut Context<'_, Self>,
+    ) -> AnyElement {
+        let all_enabled = GestureKind::ALL
+            .iter()
+            .all(|kind| self.enabled_gestures.contains(kind));
+        let header = h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_sm()
+                    .font_semibold()
+                    .text_color(gpui::rgb(0xffffff))
+                    .child(tr("启用的手势", "Enabled gestures")),
+            )
+            .child(
+                Button::new(SharedString::from("enabled-gestures-all"))
+                    .outline()
+                    .label(if all_enabled {
+                        tr("全部关闭", "Disable all")
+                    } else {
+                        tr("全部启用", "Enable all")
+                    })
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        this.enabled_gestures = if all_enabled {
+                            Default::default()
+                        } else {
+                            GestureKind::ALL.into_iter().collect()
+                        };
+                        this.push_enabled_gestures();
+                        cx.notify();
+                    })),
+            );
+
+        let mut grid = h_flex().w_full().flex_wrap().gap_2();
+        for kind in GestureKind::ALL {
+            grid = grid.child(
+                Checkbox::new(SharedString::from(format!("enabled-gesture-{kind:?}")))
+                    .label(kind.display_name())
+                    .checked(self.enabled_gestures.contains(&kind))
+                    .on_click(cx.listener(move |this, checked: &bool, _, cx| {
+                        if *checked {
+                            this.enabled_gestures.insert(kind);
+                        } else {
+                            this.enabled_gestures.remove(&kind);
+                        }
+                        this.push_enabled_gestures();
+                        cx.notify();
+                    })),
+            );
+        }
+
+        v_flex()
+            .w(super::px(panel_width))
+            .gap_3()
+            .p_4()
+            .rounded_lg()
+            .bg(gpui::rgb(0x0f172a))
+            .border_1()
+            .border_color(gpui::rgba(0xffffff1a))
+            .child(header)
+            .child(div().text_xs().text_color(gpui::rgb(0x6b7280)).child(tr(
+                "未勾选的手势不会被识别，由下一个候选手势代替",
+                "Unchecked gestures are not recognized; the next candidate takes their place",
+            )))
+            .child(grid)
+            .into_any_element()
+    }
+
+    fn push_enabled_gestures(&self) {
+        let _ = self
+            .recognizer_control_tx
+            .send(RecognizerControl::SetEnabledGestures(
+                self.enabled_gestures.clone(),
+            ));
+    }
+}
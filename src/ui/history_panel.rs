@@ -0,0 +1,234 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use super::{
+    AnyElement, AppView, Button, Context, InteractiveElement, IntoElement, ParentElement,
+    SharedString, Styled, div, h_flex, v_flex,
+};
+use crate::locale::{self, Locale, tr};
+use crate::types::{FingerState, GestureKind, GestureResult};
+use gpui::StatefulInteractiveElement;
+use gpui_component::StyledExt;
+
+const HISTORY_CAPACITY: usize = 20;
+
+/// A run of consecutive frames with the same primary gesture.
+#[derive(Clone, Debug)]
+pub(super) struct HistoryEntry {
+    pub id: u64,
+    /// `None` when no hand was detected.
+    pub kind: Option<GestureKind>,
+    pub started: Instant,
+    pub ended: Instant,
+    pub peak_confidence: f32,
+    /// Finger states of the most confident frame in the run.
+    pub finger_states: Option<[FingerState; 5]>,
+}
+
+impl HistoryEntry {
+    fn duration(&self) -> Duration {
+        self.ended.saturating_duration_since(self.started)
+    }
+
+    /// Unknown gestures and frames without a hand; hidden by default.
+    fn is_noise(&self) -> bool {
+        matches!(self.kind, None | Some(GestureKind::Unknown))
+    }
+}
+
+/// Collapses the result stream into the last few gesture runs, newest first.
+#[derive(Debug, Default)]
+pub(super) struct GestureHistory {
+    entries: VecDeque<HistoryEntry>,
+    next_id: u64,
+}
+
+impl GestureHistory {
+    pub fn observe(&mut self, result: &GestureResult) {
+        let detail = result.detail.as_ref();
+        let kind = detail.map(|d| d.primary);
+        let finger_states = detail.map(|d| d.finger_states);
+        let now = result.timestamp;
+
+        if let Some(entry) = self.entries.front_mut().filter(|entry| entry.kind == kind) {
+            entry.ended = now;
+            if result.confidence > entry.peak_confidence {
+                entry.peak_confidence = result.confidence;
+                entry.finger_states = finger_states;
+            }
+            return;
+        }
+
+        self.entries.push_front(HistoryEntry {
+            id: self.next_id,
+            kind,
+            started: now,
+            ended: now,
+            peak_confidence: result.confidence,
+            finger_states,
+        });
+        self.next_id += 1;
+        self.entries.truncate(HISTORY_CAPACITY);
+    }
+
+    pub fn entries(&self, include_noise: bool) -> impl Iterator<Item = &HistoryEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| include_noise || !entry.is_noise())
+    }
+
+    pub fn get(&self, id: u64) -> Option<&HistoryEntry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+}
+
+impl AppView {
+    pub(super) fn render_history_panel(
+        &self,
+        panel_width: f32,
+        cx: &mut Context<'_, Self>,
+    ) -> AnyElement {
+        let show_all = self.history_show_all;
+        let header = h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_sm()
+                    .font_semibold()
+                    .text_color(gpui::rgb(0xffffff))
+                    .child(tr("手势记录", "Gesture history")),
+            )
+            .child(
+                Button::new(SharedString::from("history-show-all"))
+                    .outline()
+                    .label(if show_all {
+                        tr("显示全部", "Show all")
+                    } else {
+                        tr("隐藏未识别", "Hide unrecognized")
+                    })
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.history_show_all = !this.history_show_all;
+                        cx.notify();
+                    })),
+            );
+
+        let now = Instant::now();
+        let mut list = v_flex()
+            .id("gesture-history-list")
+            .max_h(super::px(240.0))
+            .overflow_y_scroll()
+            .gap_1();
+        let mut empty = true;
+        for entry in self.gesture_history.entries(show_all) {
+            empty = false;
+            list = list.child(self.history_row(entry, now, cx));
+        }
+        if empty {
+            list = list.child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x6b7280))
+                    .child(tr("还没有识别到手势", "No gestures recognized yet")),
+            );
+        }
+
+        let selected = self
+            .history_selected
+            .and_then(|id| self.gesture_history.get(id));
+        let detail = selected.map(|entry| match entry.finger_states {
+            Some(states) => {
+                let finger_labels = locale::finger_names();
+                let mut row = h_flex().gap_2().flex_wrap();
+                for (label, state) in finger_labels.iter().zip(states) {
+                    row = row.child(self.finger_chip(label, state));
+                }
+                row.into_any_element()
+            }
+            None => div()
+                .text_xs()
+                .text_color(gpui::rgb(0x6b7280))
+                .child(tr("该时刻没有手指数据", "No finger data at this moment"))
+                .into_any_element(),
+        });
+
+        v_flex()
+            .w(super::px(panel_width))
+            .gap_3()
+            .p_4()
+            .rounded_lg()
+            .bg(gpui::rgb(0x0f172a))
+            .border_1()
+            .border_color(gpui::rgba(0xffffff1a))
+            .child(header)
+            .child(list)
+            .children(detail)
+            .into_any_element()
+    }
+
+    fn history_row(
+        &self,
+        entry: &HistoryEntry,
+        now: Instant,
+        cx: &mut Context<'_, Self>,
+    ) -> AnyElement {
+        let id = entry.id;
+        let selected = self.history_selected == Some(id);
+        let gesture_text = match entry.kind {
+            Some(kind) => format!("{}{}", kind.emoji(), kind.display_name()),
+            None => tr("未检测到手", "No hand").to_string(),
+        };
+        let ago = now.saturating_duration_since(entry.started).as_secs_f32();
+
+        h_flex()
+            .id(SharedString::from(format!("history-entry-{id}")))
+            .w_full()
+            .justify_between()
+            .items_center()
+            .px_2()
+            .py_1()
+            .rounded_md()
+            .cursor_pointer()
+            .bg(if selected {
+                gpui::rgba(0x38bdf82a)
+            } else {
+                gpui::rgba(0xffffff08)
+            })
+            .hover(|this| this.bg(gpui::rgba(0xffffff14)))
+            .on_click(cx.listener(move |this, _, _, cx| {
+                this.history_selected = if this.history_selected == Some(id) {
+                    None
+                } else {
+                    Some(id)
+                };
+                cx.notify();
+            }))
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(gpui::rgb(0xe0f2fe))
+                    .child(gesture_text),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(match locale::locale() {
+                        Locale::ZhCn => format!(
+                            "{ago:.1}s 前 · 持续 {:.1}s · 最高 {:.0}%",
+                            entry.duration().as_secs_f32(),
+                            entry.peak_confidence * 100.0
+                        ),
+                        Locale::En => format!(
+                            "{ago:.1}s ago · held {:.1}s · peak {:.0}%",
+                            entry.duration().as_secs_f32(),
+                            entry.peak_confidence * 100.0
+                        ),
+                    }),
+            )
+            .into_any_element()
+    }
+}
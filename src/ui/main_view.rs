@@ -5,10 +5,17 @@ use super::{
     ObjectFit, PanelResizeState, ParentElement, RIGHT_PANEL_MAX_WIDTH, RIGHT_PANEL_MIN_WIDTH,
     SharedString, Styled, StyledImage, Window, h_flex, v_flex,
 };
-use crate::pipeline::CompositedFrame;
-use crate::types::{FingerState, GestureMotion};
-use gpui_component::StyledExt;
-use std::sync::Arc;
+use crate::gesture::StableLabel;
+use crate::locale::{self, Locale, tr};
+use crate::pipeline::{
+    CameraStatus, CompositedFrame, RecognizerControl, RecognizerError, RecognizerStatus,
+    VirtualCamera, describe_mode, spawn_capture,
+};
+use crate::types::{FingerState, GestureEvent, GestureKind, GestureMotion};
+use crossbeam_channel::TryRecvError;
+use gpui::StatefulInteractiveElement;
+use gpui_component::{Disableable, StyledExt, button::ButtonVariants};
+use std::{sync::Arc, time::Instant};
 
 impl AppView {
     pub(super) fn render_main(
@@ -16,46 +23,40 @@ impl AppView {
         window: &mut Window,
         cx: &mut Context<'_, Self>,
     ) -> AnyElement {
-        let composited_rx = self.composited_rx.take();
-        if let Some(rx) = composited_rx.as_ref() {
-            let mut frames = Vec::new();
-            while let Ok(frame) = rx.try_recv() {
-                frames.push(frame);
-            }
-
-            for frame in frames {
-                let CompositedFrame { frame, result } = frame;
-
-                self.latest_result = Some(result);
-
-                if let Some(image) = frame_to_image(&frame, None) {
-                    self.replace_latest_image(image, window, cx);
-                }
-                self.latest_frame = Some(frame);
-                if let Some(ts) = self.latest_frame.as_ref().map(|f| f.timestamp) {
-                    self.update_fps(ts);
-                }
-            }
-        }
-        self.composited_rx = composited_rx;
+        self.poll_pipeline(Some(&mut *window), cx);
 
         let camera_label = self
             .selected_camera_idx
             .and_then(|idx| self.available_cameras.get(idx))
             .map(|c| c.label.clone())
+            .or_else(|| {
+                self.video_file.as_deref().map(|path| {
+                    format!(
+                        "{} {}",
+                        tr("视频", "Video"),
+                        super::camera_view::video_label(path)
+                    )
+                })
+            })
             .unwrap_or_else(|| {
                 if self.available_cameras.is_empty() {
-                    "未检测到摄像头".to_string()
+                    tr("未检测到摄像头", "No camera found").to_string()
                 } else {
-                    "未选择摄像头".to_string()
+                    tr("未选择摄像头", "No camera selected").to_string()
                 }
             });
 
         let frame_status = self
             .latest_frame
             .as_ref()
-            .map(|f| format!("摄像头: {camera_label} {}x{} (最新)", f.width, f.height))
-            .unwrap_or_else(|| format!("摄像头: {camera_label}，等待画面..."));
+            .map(|f| match locale::locale() {
+                Locale::ZhCn => format!("摄像头: {camera_label} {}x{} (最新)", f.width, f.height),
+                Locale::En => format!("Camera: {camera_label} {}x{} (latest)", f.width, f.height),
+            })
+            .unwrap_or_else(|| match locale::locale() {
+                Locale::ZhCn => format!("摄像头: {camera_label}，等待画面..."),
+                Locale::En => format!("Camera: {camera_label}, waiting for frames..."),
+            });
 
         let confidence_text = self
             .latest_result
@@ -91,7 +92,7 @@ impl AppView {
                 .text_sm()
                 .text_color(gpui::rgb(0x8b95a5))
                 .rounded_t_lg()
-                .child("等待摄像头...")
+                .child(tr("等待摄像头...", "Waiting for camera..."))
                 .into_any_element()
         };
 
@@ -102,7 +103,9 @@ impl AppView {
             .overflow_hidden()
             .rounded_t_lg()
             .bg(gpui::rgb(0x000000))
-            .child(frame_view);
+            .child(frame_view)
+            .children(self.render_drag_demo(panel_width, camera_height))
+            .children(self.render_roi_editor(cx));
 
         let mut picker_panel: Option<AnyElement> = None;
         if self.camera_picker_open && !self.available_cameras.is_empty() {
@@ -126,6 +129,25 @@ impl AppView {
                     )
                     .into_any_element(),
             );
+        } else if let Some(message) = self.camera_status_message() {
+            picker_panel = Some(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .p_3()
+                    .rounded_lg()
+                    .bg(gpui::rgba(0xf59e0b33))
+                    .border_1()
+                    .border_color(gpui::rgba(0xf59e0bff))
+                    .child(super::div().text_base().child("🔌"))
+                    .child(
+                        super::div()
+                            .text_xs()
+                            .text_color(gpui::rgb(0xfcd34d))
+                            .child(message),
+                    )
+                    .into_any_element(),
+            );
         }
 
         let metrics = h_flex()
@@ -135,28 +157,88 @@ impl AppView {
                 super::div()
                     .text_xs()
                     .text_color(gpui::rgb(0xa0aab8))
-                    .child(format!("置信度: {confidence_text}")),
+                    .child(format!("{}: {confidence_text}", tr("置信度", "Confidence"))),
             )
             .child(
                 super::div()
                     .text_xs()
                     .text_color(gpui::rgb(0xa0aab8))
-                    .child(format!("帧率: {fps_text}")),
+                    .child(format!("{}: {fps_text}", tr("帧率", "FPS"))),
             );
 
-        let mut info_row = h_flex()
-            .justify_between()
-            .items_center()
+        let recording = self.recorder.status();
+        let record_label = match &recording {
+            Some(status) if status.full => {
+                format!(
+                    "■ {} ({} {})",
+                    tr("已满", "Full"),
+                    status.frames,
+                    tr("帧", "frames")
+                )
+            }
+            Some(status) => format!(
+                "■ {} ({} {})",
+                tr("停止录制", "Stop recording"),
+                status.frames,
+                tr("帧", "frames")
+            ),
+            None => tr("● 录制", "● Record").to_string(),
+        };
+        let record_button = Button::new(SharedString::from("record-toggle"))
+            .label(record_label)
+            .disabled(self.privacy_mode)
+            .on_click(cx.listener(|this, _, _, cx| {
+                this.toggle_recording();
+                cx.notify();
+            }));
+        let mut controls = h_flex()
             .gap_2()
-            .child(metrics);
+            .items_center()
+            .child(if recording.is_some() {
+                record_button.primary()
+            } else {
+                record_button.outline()
+            })
+            .child(
+                Button::new(SharedString::from("capture-frame"))
+                    .outline()
+                    .label(tr("◎ 截图", "◎ Screenshot"))
+                    .disabled(self.privacy_mode)
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.capture_frame();
+                        cx.notify();
+                    })),
+            )
+            .child(self.render_clip_button(cx))
+            .child(
+                Button::new(SharedString::from("pause-toggle"))
+                    .outline()
+                    .label(if self.paused {
+                        format!(
+                            "▶ {} ({})",
+                            tr("继续", "Resume"),
+                            super::PAUSE_KEY.to_uppercase()
+                        )
+                    } else {
+                        format!(
+                            "⏸ {} ({})",
+                            tr("暂停", "Pause"),
+                            super::PAUSE_KEY.to_uppercase()
+                        )
+                    })
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.toggle_pause();
+                        cx.notify();
+                    })),
+            );
 
-        if self.available_cameras.len() > 1 {
+        if !self.available_cameras.is_empty() {
             let picker_label = if self.camera_picker_open {
-                "◉ 关闭"
+                tr("◉ 关闭", "◉ Off")
             } else {
-                "◉ 切换"
+                tr("◉ 切换", "◉ Switch")
             };
-            info_row = info_row.child(
+            controls = controls.child(
                 Button::new(SharedString::from("camera-picker-toggle"))
                     .outline()
                     .label(picker_label)
@@ -167,6 +249,50 @@ impl AppView {
             );
         }
 
+        let info_row = h_flex()
+            .justify_between()
+            .items_center()
+            .gap_2()
+            .child(metrics)
+            .child(controls);
+
+        let stage_metrics = self.latest_metrics;
+        let metrics_text = format!(
+            "{} {:.0} fps · {} {:.1}ms · {} {:.0}ms · {} {}x{} · {} {}x{} · {} {:.1}ms ({:.0}%) · {} {:.1}ms ({} {:.1}ms) · {} {:.1}ms · {} {:.1}ms · {} {}/{}/{} · {} {}/{} · {} {}",
+            tr("采集", "Capture"),
+            stage_metrics.capture_fps,
+            tr("解码", "decode"),
+            stage_metrics.decode_ms,
+            tr("延迟", "latency"),
+            stage_metrics.latency_ms,
+            tr("输入", "input"),
+            stage_metrics.input_width,
+            stage_metrics.input_height,
+            tr("输出", "output"),
+            stage_metrics.output_width,
+            stage_metrics.output_height,
+            tr("手掌", "palm"),
+            stage_metrics.palm_detect_ms,
+            stage_metrics.palm_detect_rate * 100.0,
+            tr("关键点", "landmarks"),
+            stage_metrics.handpose_ms,
+            tr("裁剪", "crop"),
+            stage_metrics.crop_prep_ms,
+            tr("分类", "classify"),
+            stage_metrics.classify_ms,
+            tr("合成", "compose"),
+            stage_metrics.compose_ms,
+            tr("丢帧", "dropped"),
+            stage_metrics.dropped_camera,
+            stage_metrics.dropped_recognized,
+            stage_metrics.dropped_composited,
+            tr("跳帧", "skipped"),
+            stage_metrics.skipped_camera,
+            stage_metrics.skipped_recognized,
+            tr("错误", "errors"),
+            stage_metrics.recognizer_errors
+        );
+
         let mut camera_card = super::div().relative().w(super::px(panel_width)).child(
             v_flex()
                 .w_full()
@@ -175,18 +301,75 @@ impl AppView {
                 .bg(gpui::rgb(0x0f1419))
                 .child(camera_shell)
                 .child(
-                    v_flex().gap_2().p_3().child(info_row).child(
-                        super::div()
-                            .text_xs()
-                            .text_color(gpui::rgb(0x8b95a5))
-                            .overflow_hidden()
-                            .text_ellipsis()
-                            .whitespace_nowrap()
-                            .child(frame_status.clone()),
-                    ),
+                    v_flex()
+                        .gap_2()
+                        .p_3()
+                        .child(info_row)
+                        .child(
+                            super::div()
+                                .text_xs()
+                                .text_color(gpui::rgb(0x8b95a5))
+                                .overflow_hidden()
+                                .text_ellipsis()
+                                .whitespace_nowrap()
+                                .child(frame_status.clone()),
+                        )
+                        .child(
+                            super::div()
+                                .text_xs()
+                                .text_color(gpui::rgb(0x6b7280))
+                                .overflow_hidden()
+                                .text_ellipsis()
+                                .whitespace_nowrap()
+                                .child(metrics_text),
+                        ),
                 ),
         );
 
+        if self.paused {
+            camera_card = camera_card.child(
+                super::div()
+                    .absolute()
+                    .top(super::px(12.0))
+                    .left(super::px(12.0))
+                    .px_3()
+                    .py_1()
+                    .rounded_md()
+                    .bg(gpui::rgba(0xf59e0bd9))
+                    .text_xs()
+                    .font_semibold()
+                    .text_color(gpui::rgb(0x1a2332))
+                    .child(tr(
+                        "⏸ 已暂停，画面未被处理",
+                        "⏸ Paused, frames are not being processed",
+                    )),
+            );
+        }
+
+        if let Some(toast) = self.capture_toast_text() {
+            camera_card = camera_card.child(
+                super::div()
+                    .absolute()
+                    .top(super::px(12.0))
+                    .right(super::px(12.0))
+                    .max_w(super::px(panel_width - 24.0))
+                    .px_3()
+                    .py_1()
+                    .rounded_md()
+                    .bg(gpui::rgba(0x0f172ae6))
+                    .text_xs()
+                    .text_color(gpui::rgb(0xe2e8f0))
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .whitespace_nowrap()
+                    .child(toast),
+            );
+        }
+
+        if let Some(err) = &self.recognizer_error {
+            camera_card = camera_card.child(self.render_recognizer_error(err, cx));
+        }
+
         if let Some(picker) = picker_panel {
             camera_card = camera_card.child(
                 super::div()
@@ -206,19 +389,42 @@ impl AppView {
         let theme = cx.theme();
 
         let (camera_icon, camera_text, camera_color) = if self.latest_frame.is_some() {
-            ("●", "摄像头就绪", theme.success)
+            ("●", tr("摄像头就绪", "Camera ready"), theme.success)
         } else {
-            ("○", "等待摄像头", theme.muted_foreground)
+            (
+                "○",
+                tr("等待摄像头", "Waiting for camera"),
+                theme.muted_foreground,
+            )
         };
 
         let (recognizer_icon, recognizer_text, recognizer_color) =
-            if self.recognizer_handle.is_some() {
-                ("●", "识别运行中", theme.success)
+            if self.recognizer_error.is_some() {
+                (
+                    "●",
+                    tr("识别未启动", "Recognizer not started"),
+                    theme.danger,
+                )
+            } else if self.paused {
+                ("●", tr("识别已暂停", "Recognizer paused"), theme.warning)
+            } else if self.pipeline.recognizer_started() {
+                ("●", tr("识别运行中", "Recognizer running"), theme.success)
             } else {
-                ("○", "正在初始化", theme.muted_foreground)
+                (
+                    "○",
+                    tr("正在初始化", "Initializing"),
+                    theme.muted_foreground,
+                )
             };
 
         let gesture_panel = self.render_gesture_panel(panel_width, cx);
+        let history_panel = self.render_history_panel(panel_width, cx);
+        let analytics_panel = self.render_analytics_panel(panel_width, cx);
+        let settings_panel = self.render_settings_panel(panel_width, cx);
+        let camera_tuning_panel = self.render_camera_tuning_panel(panel_width, cx);
+        let actions_panel = self.render_actions_panel(panel_width, cx);
+        let sequences_panel = self.render_sequences_panel(panel_width, cx);
+        let enabled_gestures_panel = self.render_enabled_gestures_panel(panel_width, cx);
 
         let panel_handle = super::div()
             .absolute()
@@ -238,7 +444,22 @@ impl AppView {
             .w(super::px(panel_width))
             .h_full()
             .overflow_hidden()
-            .child(v_flex().gap_3().child(camera_card).child(gesture_panel))
+            .child(
+                v_flex()
+                    .id("right-panel-content")
+                    .size_full()
+                    .overflow_y_scroll()
+                    .gap_3()
+                    .child(camera_card)
+                    .child(gesture_panel)
+                    .child(history_panel)
+                    .child(analytics_panel)
+                    .child(settings_panel)
+                    .child(camera_tuning_panel)
+                    .child(enabled_gestures_panel)
+                    .child(actions_panel)
+                    .child(sequences_panel),
+            )
             .child(panel_handle);
 
         let titlebar = self.render_titlebar(
@@ -260,6 +481,26 @@ impl AppView {
             })
             .on_mouse_move(cx.listener(Self::update_panel_resize))
             .on_mouse_up(MouseButton::Left, cx.listener(Self::finish_panel_resize))
+            .on_action(cx.listener(|this, _: &super::CaptureFrame, _, cx| {
+                this.capture_frame();
+                cx.notify();
+            }))
+            .on_action(cx.listener(|this, _: &super::CycleOverlayMode, _, cx| {
+                this.cycle_overlay_mode();
+                cx.notify();
+            }))
+            .on_action(cx.listener(|this, _: &super::ToggleDebugOverlay, _, cx| {
+                this.toggle_debug_overlay();
+                cx.notify();
+            }))
+            .on_action(cx.listener(|this, _: &super::TogglePause, _, cx| {
+                this.toggle_pause();
+                cx.notify();
+            }))
+            .on_action(cx.listener(|this, _: &super::ToggleCursorMode, _, cx| {
+                this.toggle_cursor_mode();
+                cx.notify();
+            }))
             .child(titlebar)
             .child(
                 h_flex()
@@ -270,16 +511,18 @@ impl AppView {
                     .child(super::div().flex_1())
                     .child(right_panel),
             )
+            .child(self.render_status_bar(cx))
             .into_any_element()
     }
 
     fn render_gesture_panel(&self, panel_width: f32, cx: &mut Context<'_, Self>) -> AnyElement {
         let theme = cx.theme();
-        let finger_labels = ["拇指", "食指", "中指", "无名指", "小指"];
+        let finger_labels = locale::finger_names();
 
         let (
             primary_text,
             secondary_text,
+            other_hand_text,
             confidence_text,
             handedness_text,
             motion_state,
@@ -287,12 +530,44 @@ impl AppView {
         ) = match &self.latest_result {
             Some(result) => {
                 let detail = result.detail.as_ref();
-                let primary = detail
-                    .map(|d| format!("{}{}", d.primary.emoji(), d.primary.display_name()))
-                    .unwrap_or_else(|| result.label.clone());
-                let secondary = detail.and_then(|d| {
-                    d.secondary
-                        .map(|s| format!("也可能是 {}{}", s.emoji(), s.display_name()))
+                let primary = match (result.composite, detail) {
+                    (Some(composite), _) => {
+                        format!("{}{}", composite.emoji(), composite.display_name())
+                    }
+                    (None, Some(_)) => match self.stable_label {
+                        Some(StableLabel { kind, .. }) => {
+                            format!("{}{}", kind.emoji(), kind.display_name())
+                        }
+                        None => tr("识别中…", "Recognizing…").to_string(),
+                    },
+                    (None, None) => result.label(),
+                };
+                // Top three candidates, so a close call is visible at a glance.
+                let secondary = detail.filter(|d| d.scores.len() > 1).map(|d| {
+                    let candidates: Vec<String> = d
+                        .scores
+                        .iter()
+                        .take(3)
+                        .map(|(kind, score)| {
+                            format!(
+                                "{}{} {:.0}%",
+                                kind.emoji(),
+                                kind.display_name(),
+                                score * 100.0
+                            )
+                        })
+                        .collect();
+                    format!("{}: {}", tr("候选", "Candidates"), candidates.join(" · "))
+                });
+                let secondary = secondary.or_else(|| result.frame_quality.hint().map(String::from));
+                let other_hand = result.hands.get(1).map(|hand| {
+                    hand.detail
+                        .as_ref()
+                        .map(|d| format!("{}{}", d.primary.emoji(), d.primary.display_name()))
+                        .map_or_else(
+                            || tr("另一只手: 检测到手", "Other hand: detected").to_string(),
+                            |name| format!("{}: {name}", tr("另一只手", "Other hand")),
+                        )
                 });
                 let motion = detail.map(|d| d.motion).unwrap_or(GestureMotion::Steady);
                 let handedness = detail
@@ -300,10 +575,13 @@ impl AppView {
                     .unwrap_or_else(|| "--".to_string());
                 let states = detail.map(|d| d.finger_states);
                 let conf = format!("{:.0}%", (result.confidence * 100.0).clamp(0.0, 100.0));
-                (primary, secondary, conf, handedness, motion, states)
+                (
+                    primary, secondary, other_hand, conf, handedness, motion, states,
+                )
             }
             None => (
-                "等待手部进入画面".to_string(),
+                tr("等待手部进入画面", "Waiting for a hand").to_string(),
+                None,
                 None,
                 "--".to_string(),
                 "--".to_string(),
@@ -312,18 +590,113 @@ impl AppView {
             ),
         };
 
+        // Dimmed while the recent frames lean toward another gesture.
+        let label_uncertain = self.latest_result.as_ref().is_some_and(|result| {
+            result.composite.is_none()
+                && result.detail.is_some()
+                && self.stable_label.is_none_or(|label| label.uncertain)
+        });
+        let stable_text = self.stable_gesture.map(|(kind, duration)| {
+            format!(
+                "{}: {}{} {:.1}s",
+                tr("稳定手势", "Stable gesture"),
+                kind.emoji(),
+                kind.display_name(),
+                duration.as_secs_f32()
+            )
+        });
+
         let status_color = if finger_states.is_some() {
             theme.success
         } else {
             theme.muted_foreground
         };
 
-        let motion_chip = match motion_state {
-            GestureMotion::Fanning => self.stat_chip("状态", "扇风/摇动", gpui::rgb(0x22c55e)),
-            GestureMotion::VerticalWave => self.stat_chip("状态", "上下挥动", gpui::rgb(0xf97316)),
-            GestureMotion::Moving => self.stat_chip("状态", "移动中", gpui::rgb(0xfbbf24)),
-            GestureMotion::Steady => self.stat_chip("状态", "保持", theme.muted_foreground),
+        // MCP/PIP per finger plus the thumb's spread and opposition, for tuning the
+        // cutoffs.
+        let angles_text = self
+            .latest_result
+            .as_ref()
+            .and_then(|result| result.detail.as_ref())
+            .map(|detail| {
+                let readouts: Vec<String> = finger_labels
+                    .iter()
+                    .zip(detail.finger_angles)
+                    .map(|(name, angles)| {
+                        let mut readout = format!("{name} {:.0}°/{:.0}°", angles.mcp, angles.pip);
+                        if let Some(spread) = angles.abduction {
+                            readout.push_str(&format!(" {}{spread:.0}°", tr("外展", "spread ")));
+                        }
+                        if let Some(opposition) = angles.opposition {
+                            readout.push_str(&format!(
+                                " {}{opposition:.2}",
+                                tr("对掌", "opposition ")
+                            ));
+                        }
+                        readout
+                    })
+                    .collect();
+                format!(
+                    "{} MCP/PIP: {}",
+                    tr("关节角", "Joint angles"),
+                    readouts.join(" · ")
+                )
+            });
+
+        let count_text = self
+            .latest_result
+            .as_ref()
+            .and_then(|result| result.detail.as_ref())
+            .and_then(|detail| detail.count)
+            .map(|count| count.to_string());
+
+        let pinch_text = self
+            .latest_result
+            .as_ref()
+            .and_then(|result| result.detail.as_ref())
+            .and_then(|detail| detail.pinch)
+            .map(|pinch| {
+                format!(
+                    "{} {:.2} ({:+.2})",
+                    if pinch.closed {
+                        tr("闭合", "closed")
+                    } else {
+                        tr("张开", "open")
+                    },
+                    pinch.distance,
+                    pinch.delta
+                )
+            });
+
+        let rotation_text = self
+            .latest_result
+            .as_ref()
+            .and_then(|result| result.detail.as_ref())
+            .and_then(|detail| detail.rotation)
+            .map(|rotation| format!("{:+.0}°", rotation.total_degrees));
+
+        // Mostly of interest for telling a fist's knuckles from its fingers,
+        // or an open hand's palm from its back.
+        let orientation_text = self
+            .latest_result
+            .as_ref()
+            .and_then(|result| result.detail.as_ref())
+            .filter(|detail| matches!(detail.primary, GestureKind::Fist | GestureKind::Palm))
+            .and_then(|detail| detail.orientation)
+            .map(|orientation| orientation.label());
+
+        let motion_color: gpui::Rgba = match motion_state {
+            GestureMotion::Fanning => gpui::rgb(0x22c55e),
+            GestureMotion::VerticalWave => gpui::rgb(0xf97316),
+            GestureMotion::Moving => gpui::rgb(0xfbbf24),
+            GestureMotion::Steady => theme.muted_foreground.into(),
+            GestureMotion::Approaching | GestureMotion::Receding => gpui::rgb(0xa78bfa),
+            GestureMotion::SwipeLeft
+            | GestureMotion::SwipeRight
+            | GestureMotion::SwipeUp
+            | GestureMotion::SwipeDown => gpui::rgb(0x38bdf8),
         };
+        let motion_chip = self.stat_chip(tr("状态", "Motion"), motion_state.label(), motion_color);
 
         let finger_block: AnyElement = if let Some(states) = finger_states {
             let mut first_row = h_flex().gap_2();
@@ -340,12 +713,20 @@ impl AppView {
                 .gap_1()
                 .child(first_row)
                 .child(second_row)
+                .when_some(angles_text, |this, text| {
+                    this.child(
+                        super::div()
+                            .text_xs()
+                            .text_color(gpui::rgb(0x94a3b8))
+                            .child(text),
+                    )
+                })
                 .into_any_element()
         } else {
             super::div()
                 .text_xs()
                 .text_color(gpui::rgb(0x6b7280))
-                .child("等检测到手势后，这里会展示各手指的状态与动作")
+                .child(tr("等检测到手势后，这里会展示各手指的状态与动作", "Once a gesture is detected, each finger's state and the hand's motion show here"))
                 .into_any_element()
         };
 
@@ -378,14 +759,14 @@ impl AppView {
                                     .text_sm()
                                     .font_semibold()
                                     .text_color(gpui::rgb(0xffffff))
-                                    .child("当前手势"),
+                                    .child(tr("当前手势", "Current gesture")),
                             ),
                     )
                     .child(
                         super::div()
                             .text_xs()
                             .text_color(gpui::rgb(0x94a3b8))
-                            .child("实时更新"),
+                            .child(tr("实时更新", "Live")),
                     ),
             )
             .child(
@@ -396,7 +777,11 @@ impl AppView {
                         super::div()
                             .text_3xl()
                             .font_bold()
-                            .text_color(gpui::rgb(0xe0f2fe))
+                            .text_color(if label_uncertain {
+                                gpui::rgb(0x94a3b8)
+                            } else {
+                                gpui::rgb(0xe0f2fe)
+                            })
                             .child(primary_text.clone()),
                     )
                     .child(
@@ -406,7 +791,7 @@ impl AppView {
                                 super::div()
                                     .text_sm()
                                     .text_color(gpui::rgb(0xa5b4fc))
-                                    .child("检测结果"),
+                                    .child(tr("检测结果", "Detection")),
                             )
                             .when(secondary_text.is_some(), |this| {
                                 this.child(
@@ -415,6 +800,22 @@ impl AppView {
                                         .text_color(gpui::rgb(0x94a3b8))
                                         .child(secondary_text.clone().unwrap_or_default()),
                                 )
+                            })
+                            .when(stable_text.is_some(), |this| {
+                                this.child(
+                                    super::div()
+                                        .text_xs()
+                                        .text_color(gpui::rgb(0x86efac))
+                                        .child(stable_text.clone().unwrap_or_default()),
+                                )
+                            })
+                            .when(other_hand_text.is_some(), |this| {
+                                this.child(
+                                    super::div()
+                                        .text_xs()
+                                        .text_color(gpui::rgb(0x94a3b8))
+                                        .child(other_hand_text.clone().unwrap_or_default()),
+                                )
                             }),
                     ),
             )
@@ -422,9 +823,45 @@ impl AppView {
                 h_flex()
                     .gap_2()
                     .items_center()
-                    .child(self.stat_chip("置信度", &confidence_text, theme.success))
-                    .child(self.stat_chip("惯用手", &handedness_text, gpui::rgb(0x38bdf8)))
-                    .child(motion_chip),
+                    .child(self.stat_chip(
+                        tr("置信度", "Confidence"),
+                        &confidence_text,
+                        theme.success,
+                    ))
+                    .child(self.stat_chip(
+                        tr("惯用手", "Hand"),
+                        &handedness_text,
+                        gpui::rgb(0x38bdf8),
+                    ))
+                    .child(motion_chip)
+                    .when(count_text.is_some(), |this| {
+                        this.child(self.stat_chip(
+                            tr("手指数", "Fingers"),
+                            count_text.as_deref().unwrap_or_default(),
+                            gpui::rgb(0x34d399),
+                        ))
+                    })
+                    .when(pinch_text.is_some(), |this| {
+                        this.child(self.stat_chip(
+                            tr("捏合", "Pinch"),
+                            pinch_text.as_deref().unwrap_or_default(),
+                            gpui::rgb(0xc084fc),
+                        ))
+                    })
+                    .when(rotation_text.is_some(), |this| {
+                        this.child(self.stat_chip(
+                            tr("旋转", "Rotation"),
+                            rotation_text.as_deref().unwrap_or_default(),
+                            gpui::rgb(0x2dd4bf),
+                        ))
+                    })
+                    .when_some(orientation_text, |this, orientation| {
+                        this.child(self.stat_chip(
+                            tr("朝向", "Facing"),
+                            orientation,
+                            gpui::rgb(0xf472b6),
+                        ))
+                    }),
             )
             .child(
                 v_flex()
@@ -433,7 +870,7 @@ impl AppView {
                         super::div()
                             .text_xs()
                             .text_color(gpui::rgb(0x94a3b8))
-                            .child("手指展开度"),
+                            .child(tr("手指展开度", "Finger extension")),
                     )
                     .child(finger_block),
             );
@@ -444,19 +881,239 @@ impl AppView {
                     .pt_1()
                     .text_xs()
                     .text_color(gpui::rgb(0x6b7280))
-                    .child("让手掌进入画面，尝试各种手势（打电话、点赞、OK、握拳、和平、摇滚等），基于HAGRID数据集的模型识别"),
+                    .child(tr("让手掌进入画面，尝试各种手势（打电话、点赞、OK、握拳、和平、摇滚等），基于HAGRID数据集的模型识别", "Bring a hand into view and try gestures such as call, like, OK, fist, peace or rock, recognized by a model trained on the HaGRID dataset")),
             );
         }
 
         container.into_any_element()
     }
 
-    fn camera_aspect_ratio(&self) -> f32 {
-        if let Some(frame) = &self.latest_frame {
-            if frame.height > 0 {
-                return frame.width as f32 / frame.height as f32;
+    fn toggle_recording(&mut self) {
+        if self.recorder.stop().is_some() || self.privacy_mode {
+            return;
+        }
+        if let Err(err) = self.recorder.start() {
+            self.camera_error = Some(format!(
+                "{}: {err:#}",
+                tr("无法开始录制", "Cannot start recording")
+            ));
+        }
+    }
+
+    /// Stops recognition, e.g. during a screen share, without closing the
+    /// camera or unloading the models, so resuming takes one frame.
+    fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+        // The worker hears first, so a frame already on its way is dropped too.
+        let _ = self
+            .recognizer_control_tx
+            .send(RecognizerControl::SetPaused(self.paused));
+        self.pipeline.camera_mut().set_paused(self.paused);
+    }
+
+    /// Saves the frame currently on screen, overlay included, with its result.
+    fn capture_frame(&mut self) {
+        if self.capture_rx.is_some() {
+            return;
+        }
+        if self.privacy_mode {
+            self.capture_toast = Some((
+                tr("隐私模式下不能截图", "Screenshots are off in privacy mode").to_string(),
+                Instant::now(),
+            ));
+            return;
+        }
+        let (Some(frame), Some(result)) = (&self.latest_frame, &self.latest_result) else {
+            self.capture_toast = Some((
+                tr("还没有可截取的画面", "No frame to capture yet").to_string(),
+                Instant::now(),
+            ));
+            return;
+        };
+        self.capture_rx = Some(spawn_capture(
+            self.settings.captures_dir(),
+            frame.clone(),
+            result.clone(),
+        ));
+    }
+
+    fn poll_capture(&mut self) {
+        let Some(rx) = &self.capture_rx else {
+            return;
+        };
+        let message = match rx.try_recv() {
+            Ok(Ok(path)) => format!(
+                "{}: {}",
+                tr("已保存截图", "Screenshot saved"),
+                path.display()
+            ),
+            Ok(Err(err)) => {
+                log::warn!("capture failed: {err:#}");
+                format!("{}: {err:#}", tr("截图失败", "Screenshot failed"))
+            }
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => tr(
+                "截图失败: 写入线程意外退出",
+                "Screenshot failed: the writer thread exited unexpectedly",
+            )
+            .to_string(),
+        };
+        self.capture_rx = None;
+        self.capture_toast = Some((message, Instant::now()));
+    }
+
+    /// Starts writing the composited feed to the configured loopback device at
+    /// the current frame size, or stops it.
+    pub(super) fn toggle_virtual_camera(&mut self) {
+        if self.virtual_camera.take().is_none() {
+            let device = self.settings.virtual_camera_device();
+            let Some(frame) = &self.latest_frame else {
+                self.capture_toast = Some((
+                    tr("还没有可输出的画面", "No frame to output yet").to_string(),
+                    Instant::now(),
+                ));
+                return;
+            };
+            let message = match VirtualCamera::start(&device, frame.width, frame.height) {
+                Ok(camera) => {
+                    self.virtual_camera = Some(camera);
+                    format!(
+                        "{}: {}",
+                        tr("虚拟摄像头已开启", "Virtual camera on"),
+                        device.display()
+                    )
+                }
+                Err(err) => {
+                    log::warn!("virtual camera failed to start: {err:#}");
+                    format!(
+                        "{}: {err:#}",
+                        tr("虚拟摄像头启动失败", "Virtual camera failed to start")
+                    )
+                }
+            };
+            self.capture_toast = Some((message, Instant::now()));
+        }
+        self.push_recognizer_config();
+    }
+
+    fn poll_virtual_camera(&mut self) {
+        let Some(error) = self.virtual_camera.as_ref().and_then(VirtualCamera::error) else {
+            return;
+        };
+        self.virtual_camera = None;
+        self.push_recognizer_config();
+        self.capture_toast = Some((
+            format!(
+                "{}: {error}",
+                tr("虚拟摄像头已停止", "Virtual camera stopped")
+            ),
+            Instant::now(),
+        ));
+    }
+
+    /// Covers the whole camera card: without models there is nothing to show.
+    fn render_recognizer_error(
+        &self,
+        err: &RecognizerError,
+        cx: &mut Context<'_, Self>,
+    ) -> AnyElement {
+        let path = err
+            .model_path()
+            .map(|path| format!("{}: {}", tr("模型文件", "Model file"), path.display()));
+
+        super::div()
+            .absolute()
+            .top_0()
+            .left_0()
+            .size_full()
+            .flex()
+            .items_center()
+            .justify_center()
+            .p_4()
+            .rounded_lg()
+            .bg(gpui::rgba(0x0f172af2))
+            .child(
+                v_flex()
+                    .gap_2()
+                    .p_4()
+                    .max_w(super::px(400.0))
+                    .rounded_lg()
+                    .bg(gpui::rgba(0xef444433))
+                    .border_1()
+                    .border_color(gpui::rgba(0xef4444ff))
+                    .child(
+                        super::div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(gpui::rgb(0xfca5a5))
+                            .child(tr(
+                                "⚠️ 手势识别无法启动",
+                                "⚠️ Gesture recognition could not start",
+                            )),
+                    )
+                    .when_some(path, |this, path| {
+                        this.child(
+                            super::div()
+                                .text_xs()
+                                .text_color(gpui::rgb(0xe2e8f0))
+                                .child(path),
+                        )
+                    })
+                    .child(
+                        super::div()
+                            .text_xs()
+                            .text_color(gpui::rgb(0xfca5a5))
+                            .child(err.describe()),
+                    )
+                    .child(
+                        Button::new(SharedString::from("recognizer-retry"))
+                            .outline()
+                            .label(tr("重试", "Retry"))
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.recognizer_error = None;
+                                let _ = this
+                                    .recognizer_control_tx
+                                    .send(RecognizerControl::ReloadModels);
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn capture_toast_text(&self) -> Option<String> {
+        self.capture_toast
+            .as_ref()
+            .filter(|(_, shown_at)| shown_at.elapsed() < super::CAPTURE_TOAST_DURATION)
+            .map(|(text, _)| text.clone())
+    }
+
+    /// Banner text while the camera is down; `None` when it is streaming.
+    fn camera_status_message(&self) -> Option<String> {
+        match self.camera_status.as_ref()? {
+            CameraStatus::Streaming { .. } => None,
+            CameraStatus::Disconnected { error } => Some(match locale::locale() {
+                Locale::ZhCn => format!("摄像头已断开（{error}），正在重试…"),
+                Locale::En => format!("Camera disconnected ({error}), retrying…"),
+            }),
+            CameraStatus::Reconnecting { attempt, delay } => {
+                let delay = delay.as_secs_f32();
+                Some(match locale::locale() {
+                    Locale::ZhCn => format!("摄像头已断开，{delay:.1}s 后第 {attempt} 次重试…"),
+                    Locale::En => {
+                        format!("Camera disconnected, retry {attempt} in {delay:.1}s…")
+                    }
+                })
             }
         }
+    }
+
+    pub(super) fn camera_aspect_ratio(&self) -> f32 {
+        if let Some(frame) = &self.latest_frame
+            && frame.height > 0
+        {
+            return frame.width as f32 / frame.height as f32;
+        }
         DEFAULT_CAMERA_RATIO
     }
 
@@ -507,6 +1164,114 @@ impl AppView {
         }
     }
 
+    /// Drains the worker channels. Without a window, as when only the mini
+    /// window is left, no preview image is made.
+    pub(super) fn poll_pipeline(
+        &mut self,
+        mut window: Option<&mut Window>,
+        cx: &mut Context<'_, Self>,
+    ) {
+        let composited_rx = self.composited_rx.take();
+        if let Some(rx) = composited_rx.as_ref() {
+            let mut frames = Vec::new();
+            while let Ok(frame) = rx.try_recv() {
+                frames.push(frame);
+            }
+
+            for composited in frames {
+                if let Some(window) = window.as_deref_mut() {
+                    let image = frame_to_image(
+                        &composited,
+                        self.privacy_mode,
+                        None,
+                        &self.skeleton_style,
+                        self.overlay_mode,
+                    );
+                    if let Some(image) = image {
+                        self.replace_latest_image(image, window, cx);
+                    }
+                }
+                let CompositedFrame { frame, result, .. } = composited;
+
+                self.gesture_history.observe(&result);
+                if let Some(analytics) = self.analytics.as_mut() {
+                    analytics.observe_frame(&result);
+                }
+                self.observe_cursor(&result);
+                self.stable_label = self.label_stabilizer.observe(
+                    result.detail.as_ref().map(|d| d.primary),
+                    result.confidence,
+                    result.timestamp,
+                );
+                self.latest_result = Some(result);
+                self.latest_frame = Some(frame);
+                if let Some(ts) = self.latest_frame.as_ref().map(|f| f.timestamp) {
+                    self.update_fps(ts);
+                }
+            }
+        }
+        self.composited_rx = composited_rx;
+
+        self.poll_photo_booth();
+        self.poll_hold_confirm();
+        self.poll_capture();
+        self.poll_clip_export();
+        self.poll_virtual_camera();
+        while let Ok(status) = self.recognizer_status_rx.try_recv() {
+            self.recognizer_error = match status {
+                RecognizerStatus::Ready => None,
+                RecognizerStatus::Failed(err) => Some(err),
+            };
+        }
+        self.latest_metrics = self.metrics.snapshot();
+        if self.settings_checked_at.elapsed() >= super::SETTINGS_SAVE_INTERVAL {
+            self.save_settings_if_changed();
+        }
+        if let Some(status) = self.pipeline.camera().poll_status() {
+            if let CameraStatus::Streaming {
+                width,
+                height,
+                fps,
+                format,
+            } = &status
+            {
+                log::info!(
+                    "camera streaming {}",
+                    describe_mode(*width, *height, *fps, *format)
+                );
+            }
+            self.camera_status = Some(status);
+        }
+        self.refresh_status_bar();
+
+        while let Ok(event) = self.gesture_event_rx.try_recv() {
+            self.action_dispatcher
+                .handle(&self.action_config, &event, std::time::Instant::now());
+            self.observe_drag_demo(&event);
+            if let Some(analytics) = self.analytics.as_mut() {
+                analytics.observe_event(&event);
+            }
+            self.stable_gesture = match event {
+                GestureEvent::Started(kind) => Some((kind, std::time::Duration::ZERO)),
+                GestureEvent::Held { kind, duration } => Some((kind, duration)),
+                GestureEvent::Ended(_) => None,
+                GestureEvent::Motion { .. } | GestureEvent::DwellCompleted(_) => {
+                    self.stable_gesture
+                }
+                GestureEvent::SequenceMatched(name) => {
+                    self.capture_toast = Some((
+                        format!("{}: {name}", tr("组合完成", "Sequence done")),
+                        Instant::now(),
+                    ));
+                    self.stable_gesture
+                }
+                GestureEvent::DragStarted { .. }
+                | GestureEvent::DragMoved { .. }
+                | GestureEvent::DragEnded { .. } => self.stable_gesture,
+            };
+        }
+    }
+
     fn replace_latest_image(
         &mut self,
         new_image: Arc<super::RenderImage>,
@@ -552,7 +1317,7 @@ impl AppView {
             .into_any_element()
     }
 
-    fn finger_chip(&self, label: &str, state: FingerState) -> AnyElement {
+    pub(super) fn finger_chip(&self, label: &str, state: FingerState) -> AnyElement {
         let (bg, fg) = match state {
             FingerState::Extended => (gpui::rgba(0x15803d40), gpui::rgb(0x34d399)),
             FingerState::HalfBent => (gpui::rgba(0x1d4ed840), gpui::rgb(0x93c5fd)),
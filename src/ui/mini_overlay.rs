@@ -0,0 +1,304 @@
+use gpui::{
+    App, AppContext, Bounds, DisplayId, Entity, Pixels, WindowBackgroundAppearance, WindowBounds,
+    WindowHandle, WindowKind, point, relative, size,
+};
+use gpui_component::StyledExt;
+
+use super::{
+    AnyElement, AppView, Button, Context, InteractiveElement, IntoElement, MouseButton,
+    ParentElement, Render, SharedString, Styled, Window, WindowControlArea, WindowDecorations,
+    WindowOptions, div, h_flex, px, v_flex,
+};
+use crate::{
+    config::{MiniOverlaySettings, OverlayCorner},
+    gesture::StableLabel,
+    locale::{self, tr},
+};
+
+const MINI_WIDTH: f32 = 240.0;
+const MINI_HEIGHT: f32 = 84.0;
+/// Gap to the monitor edges when placed in a corner.
+const MINI_MARGIN: f32 = 24.0;
+
+/// The main view's side of the mini window.
+pub(super) struct MiniOverlayState {
+    window: Option<WindowHandle<MiniOverlay>>,
+    pub(super) settings: MiniOverlaySettings,
+    /// The main window was closed while the mini one stayed open; the mini
+    /// window drains the pipeline from then on.
+    main_closed: bool,
+}
+
+impl MiniOverlayState {
+    pub(super) fn new(settings: MiniOverlaySettings) -> Self {
+        Self {
+            window: None,
+            settings,
+            main_closed: false,
+        }
+    }
+
+    /// Where the window goes: back where it was left on the monitor it was
+    /// on, or in the chosen corner of the primary monitor.
+    fn placement(&self, cx: &App) -> (Option<DisplayId>, Bounds<Pixels>) {
+        let window_size = size(px(MINI_WIDTH), px(MINI_HEIGHT));
+        let saved = self.settings.display.as_deref().and_then(|uuid| {
+            cx.displays()
+                .into_iter()
+                .find(|display| display.uuid().is_ok_and(|id| id.to_string() == uuid))
+        });
+        let offset = self.settings.offset.filter(|_| saved.is_some());
+        let Some(display) = saved.or_else(|| cx.primary_display()) else {
+            let origin = point(px(MINI_MARGIN), px(MINI_MARGIN));
+            return (None, Bounds::new(origin, window_size));
+        };
+
+        let area = display.bounds();
+        let (left, top) = (f32::from(area.origin.x), f32::from(area.origin.y));
+        let (x, y) = match offset {
+            Some([x, y]) => (left + x, top + y),
+            None => {
+                let (width, height) = (f32::from(area.size.width), f32::from(area.size.height));
+                let right = left + width - MINI_WIDTH - MINI_MARGIN;
+                let bottom = top + height - MINI_HEIGHT - MINI_MARGIN;
+                match self.settings.corner {
+                    OverlayCorner::TopRight => (right, top + MINI_MARGIN),
+                    OverlayCorner::BottomRight => (right, bottom),
+                    OverlayCorner::BottomLeft => (left + MINI_MARGIN, bottom),
+                    OverlayCorner::TopLeft => (left + MINI_MARGIN, top + MINI_MARGIN),
+                }
+            }
+        };
+        (
+            Some(display.id()),
+            Bounds::new(point(px(x), px(y)), window_size),
+        )
+    }
+
+    /// Keeps the saved placement in step with the window as it gets moved.
+    fn remember_placement(&mut self, window: &Window, cx: &App) {
+        let Some(display) = window.display(cx) else {
+            return;
+        };
+        let (origin, area) = (window.bounds().origin, display.bounds().origin);
+        let offset = [
+            f32::from(origin.x) - f32::from(area.x),
+            f32::from(origin.y) - f32::from(area.y),
+        ];
+        self.settings.offset = Some(offset);
+        self.settings.display = display.uuid().ok().map(|id| id.to_string());
+    }
+}
+
+/// A small frameless window over everything else showing the current gesture
+/// and its confidence, for presenting. It never takes focus; gpui has no
+/// click-through, so clicks on it still land on it.
+pub(super) struct MiniOverlay {
+    app: Entity<AppView>,
+}
+
+impl Render for MiniOverlay {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        cx.defer_in(window, |_, _, cx| {
+            cx.notify();
+        });
+        self.app.update(cx, |view, cx| {
+            if view.mini_overlay.main_closed {
+                view.poll_pipeline(None, cx);
+            }
+            view.mini_overlay.remember_placement(window, cx);
+        });
+
+        let (emoji, label, confidence) = self.app.read(cx).mini_overlay_summary();
+        h_flex()
+            .window_control_area(WindowControlArea::Drag)
+            .on_mouse_down(MouseButton::Left, |_, window, _| window.start_window_move())
+            .size_full()
+            .items_center()
+            .gap_3()
+            .px_3()
+            .rounded_lg()
+            .bg(gpui::rgba(0x0f172ae6))
+            .border_1()
+            .border_color(gpui::rgba(0xffffff1f))
+            .child(div().text_3xl().child(emoji.unwrap_or("🖐")))
+            .child(
+                v_flex()
+                    .flex_1()
+                    .gap_1()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(gpui::rgb(0xe0f2fe))
+                            .child(label),
+                    )
+                    .child(
+                        div()
+                            .h(px(6.0))
+                            .w_full()
+                            .rounded_full()
+                            .bg(gpui::rgba(0xffffff1f))
+                            .child(
+                                div()
+                                    .h_full()
+                                    .w(relative(confidence.unwrap_or(0.0)))
+                                    .rounded_full()
+                                    .bg(gpui::rgb(0x38bdf8)),
+                            ),
+                    )
+                    .child(div().text_xs().text_color(gpui::rgb(0x94a3b8)).child(
+                        match confidence {
+                            Some(confidence) => format!("{:.0}%", confidence * 100.0),
+                            None => "--".to_string(),
+                        },
+                    )),
+            )
+    }
+}
+
+impl AppView {
+    pub(super) fn open_mini_overlay(&mut self, cx: &mut Context<'_, Self>) {
+        if self.mini_overlay.window.is_some() {
+            return;
+        }
+        let (display_id, bounds) = self.mini_overlay.placement(cx);
+        let options = WindowOptions {
+            titlebar: None,
+            window_bounds: Some(WindowBounds::Windowed(bounds)),
+            display_id,
+            // Pop-ups stay above other windows and do not take focus.
+            kind: WindowKind::PopUp,
+            focus: false,
+            is_movable: true,
+            is_resizable: false,
+            window_background: WindowBackgroundAppearance::Transparent,
+            window_decorations: Some(WindowDecorations::Client),
+            ..Default::default()
+        };
+        let app = cx.entity();
+        let opened = cx.open_window(options, move |window, cx| {
+            let closing = app.clone();
+            window.on_window_should_close(cx, move |_, cx| {
+                closing.update(cx, |view, cx| view.mini_overlay_closed(cx));
+                true
+            });
+            cx.new(|_| MiniOverlay { app })
+        });
+        match opened {
+            Ok(handle) => {
+                self.mini_overlay.window = Some(handle);
+                self.mini_overlay.settings.open = true;
+            }
+            Err(err) => log::warn!("failed to open the mini window: {err:#}"),
+        }
+    }
+
+    fn close_mini_overlay(&mut self, cx: &mut App) {
+        if let Some(handle) = self.mini_overlay.window.take() {
+            let _ = handle.update(cx, |_, window, _| window.remove_window());
+        }
+        self.mini_overlay.settings.open = false;
+    }
+
+    fn mini_overlay_closed(&mut self, cx: &mut Context<'_, Self>) {
+        self.mini_overlay.window = None;
+        self.mini_overlay.settings.open = false;
+        if self.mini_overlay.main_closed {
+            self.shutdown_pipeline();
+            cx.quit();
+        }
+    }
+
+    /// Called as the main window closes; whether the app should quit, which
+    /// it should not while the mini window is still showing results.
+    pub(super) fn main_window_closing(&mut self) -> bool {
+        if self.mini_overlay.window.is_some() {
+            self.mini_overlay.main_closed = true;
+            self.save_settings_if_changed();
+            return false;
+        }
+        self.shutdown_pipeline();
+        true
+    }
+
+    /// Emoji, label and confidence as the big label shows them.
+    fn mini_overlay_summary(&self) -> (Option<&'static str>, String, Option<f32>) {
+        let Some(result) = &self.latest_result else {
+            return (
+                None,
+                tr("等待手部进入画面", "Waiting for a hand").to_string(),
+                None,
+            );
+        };
+        let confidence = Some(result.confidence.clamp(0.0, 1.0));
+        match (result.composite, &result.detail, self.stable_label) {
+            (Some(composite), _, _) => (
+                Some(composite.emoji()),
+                composite.display_name().to_string(),
+                confidence,
+            ),
+            (None, Some(_), Some(StableLabel { kind, .. })) => (
+                Some(kind.emoji()),
+                kind.display_name().to_string(),
+                confidence,
+            ),
+            (None, Some(_), None) => (None, tr("识别中…", "Recognizing…").to_string(), confidence),
+            (None, None, _) => (None, result.label(), confidence),
+        }
+    }
+
+    pub(super) fn mini_overlay_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let open = self.mini_overlay.window.is_some();
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("迷你窗口", "Mini window")),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(
+                        Button::new(SharedString::from("mini-overlay-corner"))
+                            .outline()
+                            .label(self.mini_overlay.settings.corner.label())
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.cycle_mini_overlay_corner(cx);
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new(SharedString::from("mini-overlay-toggle"))
+                            .outline()
+                            .label(locale::on_off(open))
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                if this.mini_overlay.window.is_some() {
+                                    this.close_mini_overlay(cx);
+                                } else {
+                                    this.open_mini_overlay(cx);
+                                }
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    /// Forgets where the window was moved to, so it jumps to the new corner.
+    fn cycle_mini_overlay_corner(&mut self, cx: &mut Context<'_, Self>) {
+        let settings = &mut self.mini_overlay.settings;
+        settings.corner = settings.corner.next();
+        settings.offset = None;
+        if self.mini_overlay.window.is_some() {
+            self.close_mini_overlay(cx);
+            self.open_mini_overlay(cx);
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use std::{
+    collections::{BTreeMap, HashSet},
     mem,
+    path::PathBuf,
     sync::Arc,
     thread,
     time::{Duration, Instant},
@@ -8,27 +10,55 @@ use std::{
 use crossbeam_channel::{Receiver, Sender, unbounded};
 use gpui::prelude::FluentBuilder;
 use gpui::{
-    AnyElement, App, AppContext, Context, Hsla, InteractiveElement, IntoElement, MouseButton,
-    MouseDownEvent, MouseMoveEvent, MouseUpEvent, ObjectFit, ParentElement, Render, RenderImage,
-    SharedString, Styled, StyledImage, TitlebarOptions, Window, WindowControlArea,
-    WindowDecorations, WindowOptions, div, img, px,
+    AnyElement, App, AppContext, Context, FocusHandle, Hsla, InteractiveElement, IntoElement,
+    KeyBinding, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent, ObjectFit,
+    ParentElement, Render, RenderImage, SharedString, Styled, StyledImage, TitlebarOptions, Window,
+    WindowControlArea, WindowDecorations, WindowOptions, div, img, px,
 };
 use gpui_component::{ActiveTheme, Root, StyledExt, button::Button, h_flex, v_flex};
 use image::{Frame as ImageFrame, ImageBuffer, Rgba};
+use nokhwa::utils::CameraIndex;
 
 use crate::{
+    actions::{
+        ActionConfig, ActionDispatcher,
+        cursor::{CursorCalibration, CursorController},
+        default_actions_config_path,
+    },
+    analytics::GestureAnalytics,
+    config::{AppSettings, default_settings_path},
+    gesture::{LabelStabilizer, StableLabel},
+    locale::{self, Locale},
     model_download::{ModelDownloadEvent, ModelKind},
+    mqtt_output::MqttStatusHandle,
     pipeline::{
-        CameraDevice, CameraStream, CompositedFrame, RecognizerBackend, start_frame_compositor,
-        start_recognizer,
+        CameraDevice, CameraKnob, CameraManager, CameraOpenOptions, CameraProbe, CameraStatus,
+        CompositedFrame, CompositorSettings, ExecutionProvider, MetricsHandle, OutputSize,
+        PalmDetectorConfig, PipelineHandles, PipelineMetrics, RecognizerBackend, RecognizerControl,
+        RecognizerError, RecognizerStatus, Recorder, VirtualCamera, available_cameras,
+        skeleton::{OverlayMode, SkeletonStyle},
+        start_frame_compositor, start_recognizer,
     },
-    types::{Frame, GestureResult, RecognizedFrame},
+    types::{Frame, GestureEvent, GestureKind, GestureResult, GestureSequence, RecognizerConfig},
 };
 
+mod actions_panel;
+mod analytics_panel;
+mod camera_tuning;
 mod camera_view;
+mod clip_export;
 mod download;
+mod drag_demo;
+mod enabled_gestures_panel;
+mod history_panel;
 mod main_view;
+mod mini_overlay;
+mod photo_booth;
 mod render_util;
+mod roi_editor;
+mod sequences_panel;
+mod settings_panel;
+mod status_bar;
 mod titlebar;
 
 const CAMERA_MIN_SIZE: (f32, f32) = (240.0, 180.0);
@@ -38,12 +68,37 @@ const RIGHT_PANEL_MIN_WIDTH: f32 = 320.0;
 const RIGHT_PANEL_MAX_WIDTH: f32 = 720.0;
 const RIGHT_PANEL_INITIAL_WIDTH: f32 = 480.0;
 const STARTUP_CARD_WIDTH: f32 = 420.0;
+const PIPELINE_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(2);
+/// Settings changes are batched so dragging a stepper does not rewrite the file
+/// on every click.
+const SETTINGS_SAVE_INTERVAL: Duration = Duration::from_secs(1);
+const CAPTURE_TOAST_DURATION: Duration = Duration::from_secs(3);
+/// A single key, so a capture can be taken without moving the hand out of frame.
+const CAPTURE_KEY: &str = "f12";
+const OVERLAY_MODE_KEY: &str = "f9";
+const DEBUG_OVERLAY_KEY: &str = "f10";
+const PAUSE_KEY: &str = "f8";
+const CURSOR_KEY: &str = "f7";
+
+gpui::actions!(
+    gesture_universe,
+    [
+        CaptureFrame,
+        CycleOverlayMode,
+        ToggleDebugOverlay,
+        TogglePause,
+        ToggleCursorMode
+    ]
+);
 
 pub fn launch_ui(
     app: &mut App,
     camera_frame_rx: Receiver<Frame>,
     camera_frame_tx: Sender<Frame>,
     recognizer_backend: RecognizerBackend,
+    metrics: MetricsHandle,
+    settings: AppSettings,
+    mqtt_status: Option<MqttStatusHandle>,
 ) -> gpui::Result<()> {
     let window_options = WindowOptions {
         titlebar: Some(TitlebarOptions {
@@ -61,9 +116,41 @@ pub fn launch_ui(
         }),
         ..Default::default()
     };
+    app.bind_keys([
+        KeyBinding::new(CAPTURE_KEY, CaptureFrame, None),
+        KeyBinding::new(OVERLAY_MODE_KEY, CycleOverlayMode, None),
+        KeyBinding::new(DEBUG_OVERLAY_KEY, ToggleDebugOverlay, None),
+        KeyBinding::new(PAUSE_KEY, TogglePause, None),
+        KeyBinding::new(CURSOR_KEY, ToggleCursorMode, None),
+    ]);
 
+    let open_mini = settings.mini_overlay.as_ref().is_some_and(|mini| mini.open);
     app.open_window(window_options, move |window, app| {
-        let view = app.new(|_| AppView::new(camera_frame_rx, camera_frame_tx, recognizer_backend));
+        let view = app.new(|cx| {
+            AppView::new(
+                camera_frame_rx,
+                camera_frame_tx,
+                recognizer_backend,
+                metrics,
+                settings,
+                mqtt_status,
+                cx,
+            )
+        });
+        // Stop the pipeline threads before the window goes away; leaked workers
+        // blocked in `recv` would otherwise keep the process alive. An open mini
+        // window keeps them running instead.
+        let closing_view = view.clone();
+        window.on_window_should_close(app, move |_, cx| {
+            if closing_view.update(cx, |view, _| view.main_window_closing()) {
+                cx.quit();
+            }
+            true
+        });
+        if open_mini {
+            let view = view.clone();
+            app.defer(move |cx| view.update(cx, |view, cx| view.open_mini_overlay(cx)));
+        }
         app.new(|cx| {
             let root = Root::new(view, window, cx);
             #[cfg(target_os = "macos")]
@@ -82,15 +169,85 @@ struct AppView {
     screen: Screen,
     composited_rx: Option<Receiver<CompositedFrame>>,
     camera_frame_rx: Option<Receiver<Frame>>,
-    camera_frame_tx: Sender<Frame>,
-    recognized_tx: Sender<RecognizedFrame>,
     recognizer_backend: RecognizerBackend,
-    _frame_compositor_handle: thread::JoinHandle<()>,
-    recognizer_handle: Option<thread::JoinHandle<()>>,
-    camera_stream: Option<CameraStream>,
+    recognizer_config: RecognizerConfig,
+    execution_provider: ExecutionProvider,
+    palm_config: PalmDetectorConfig,
+    /// Shared by the compositor overlay and any overlay drawn in the UI.
+    skeleton_style: SkeletonStyle,
+    overlay_mode: OverlayMode,
+    output_size: OutputSize,
+    /// Opt-in; the compositor pixelates these gestures.
+    censor_gestures: HashSet<GestureKind>,
+    burn_in_label: bool,
+    pointing_ray: bool,
+    /// The camera image is never shown or saved, only the overlay on a blank
+    /// frame.
+    privacy_mode: bool,
+    /// Named combos the recognizer watches for.
+    sequences: Vec<GestureSequence>,
+    /// Gestures the classifier may report.
+    enabled_gestures: HashSet<GestureKind>,
+    recognizer_control_tx: Sender<RecognizerControl>,
+    recognizer_control_rx: Option<Receiver<RecognizerControl>>,
+    recognizer_status_rx: Receiver<RecognizerStatus>,
+    /// Why the recognizer did not start; blocks the view until a retry works.
+    recognizer_error: Option<RecognizerError>,
+    compositor_settings_tx: Sender<CompositorSettings>,
+    gesture_event_tx: Sender<GestureEvent>,
+    gesture_event_rx: Receiver<GestureEvent>,
+    stable_gesture: Option<(GestureKind, Duration)>,
+    /// Votes the big label over recent frames so it does not flicker.
+    label_stabilizer: LabelStabilizer,
+    stable_label: Option<StableLabel>,
+    gesture_history: history_panel::GestureHistory,
+    /// Session stats for the stats panel, `None` while they are off.
+    analytics: Option<GestureAnalytics>,
+    history_show_all: bool,
+    history_selected: Option<u64>,
+    metrics: MetricsHandle,
+    latest_metrics: PipelineMetrics,
+    action_config: ActionConfig,
+    /// What was last loaded or written to disk.
+    settings: AppSettings,
+    settings_checked_at: Instant,
+    action_dispatcher: ActionDispatcher,
+    cursor: CursorController,
+    /// Set while the cursor mapping is being calibrated.
+    cursor_calibration: Option<CursorCalibration>,
+    /// The compositor times hold-to-confirm bindings and sends their index
+    /// here once held.
+    hold_confirm_tx: Sender<usize>,
+    hold_confirm_rx: Receiver<usize>,
+    action_recording: Option<usize>,
+    actions_focus: FocusHandle,
+    pipeline: PipelineHandles,
+    recorder: Recorder,
+    /// Recognition is stopped while the camera and models stay ready.
+    paused: bool,
+    /// Pending capture write, polled like the other worker channels.
+    capture_rx: Option<Receiver<anyhow::Result<PathBuf>>>,
+    capture_toast: Option<(String, Instant)>,
+    clip_exporter: clip_export::ClipExporter,
+    photo_booth: photo_booth::PhotoBoothState,
+    /// Running while the composited feed is offered to other apps.
+    virtual_camera: Option<VirtualCamera>,
+    /// Set when the MQTT output is configured; the publisher itself lives in
+    /// `main` so headless runs have it too.
+    mqtt_status: Option<MqttStatusHandle>,
     available_cameras: Vec<CameraDevice>,
     selected_camera_idx: Option<usize>,
+    /// Mode asked of the camera; `None` leaves it to the driver's defaults.
+    camera_format: Option<CameraOpenOptions>,
+    /// Modes the selected camera offers, listed when it was first opened.
+    camera_formats: Option<(CameraIndex, Vec<CameraOpenOptions>)>,
+    /// Tuning panel values per camera label, set again on every open.
+    camera_controls: BTreeMap<String, BTreeMap<CameraKnob, i64>>,
+    video_file: Option<PathBuf>,
+    loop_video: bool,
     camera_error: Option<String>,
+    /// Last status reported by the camera capture thread.
+    camera_status: Option<CameraStatus>,
     latest_frame: Option<Frame>,
     latest_result: Option<GestureResult>,
     latest_image: Option<Arc<RenderImage>>,
@@ -101,6 +258,10 @@ struct AppView {
     camera_picker_open: bool,
     right_panel_width: f32,
     panel_resize_state: Option<PanelResizeState>,
+    roi_editor: roi_editor::RoiEditor,
+    drag_demo: drag_demo::DragDemo,
+    mini_overlay: mini_overlay::MiniOverlayState,
+    status_bar: status_bar::StatusBar,
     is_refreshing_cameras: bool,
 }
 
@@ -114,6 +275,15 @@ enum CameraState {
     Unavailable {
         message: String,
     },
+    /// No saved camera to start, or it is not connected; the cameras are tried
+    /// in order for one that shows a picture.
+    Detecting {
+        options: Vec<CameraDevice>,
+        probe: Receiver<CameraProbe>,
+        trying: usize,
+        /// Label of the saved camera that is not connected.
+        missing: Option<String>,
+    },
     Selection {
         options: Vec<CameraDevice>,
         selected: usize,
@@ -140,7 +310,7 @@ impl DownloadState {
         Self {
             downloaded: 0,
             total: None,
-            message: "Preparing model download...".to_string(),
+            message: locale::tr("正在准备下载模型...", "Preparing model download...").to_string(),
             error: None,
             finished: false,
             handpose_ready: false,
@@ -154,9 +324,10 @@ impl DownloadState {
     fn update_from_event(&mut self, event: ModelDownloadEvent) {
         match event {
             ModelDownloadEvent::AlreadyPresent { model } => {
-                self.message = format!(
-                    "{} model already present, continuing...",
-                    model_label(model)
+                self.message = model_message(
+                    model,
+                    "模型已存在，继续...",
+                    " model already present, continuing...",
                 );
                 self.set_ready(model);
                 self.downloaded = 0;
@@ -166,7 +337,7 @@ impl DownloadState {
                 self.current_model = Some(model);
                 self.downloaded = 0;
                 self.total = total;
-                self.message = format!("Downloading {} model...", model_label(model));
+                self.message = downloading_message(model);
             }
             ModelDownloadEvent::Progress {
                 model,
@@ -176,11 +347,23 @@ impl DownloadState {
                 self.current_model = Some(model);
                 self.downloaded = downloaded;
                 self.total = total;
-                self.message = format!("Downloading {} model...", model_label(model));
+                self.message = downloading_message(model);
+            }
+            ModelDownloadEvent::Verifying { model } => {
+                self.current_model = Some(model);
+                self.message = match locale::locale() {
+                    Locale::ZhCn => format!("正在校验{}模型...", model.display_name()),
+                    Locale::En => format!("Verifying {} model...", model.display_name()),
+                };
             }
             ModelDownloadEvent::Finished { model } => {
                 self.set_ready(model);
-                self.message = format!("{} model ready", model_label(model));
+                self.message = model_message(model, "模型就绪", " model ready");
+            }
+            ModelDownloadEvent::Failed { model, error } => {
+                self.current_model = Some(model);
+                self.message = model_message(model, "模型下载失败", " model failed");
+                self.error = Some(error);
             }
         }
         self.finished = self.handpose_ready && self.palm_ready && self.gesture_classifier_ready;
@@ -195,11 +378,15 @@ impl DownloadState {
     }
 }
 
-fn model_label(model: ModelKind) -> &'static str {
-    match model {
-        ModelKind::HandposeEstimator => "Handpose estimator",
-        ModelKind::PalmDetector => "Palm detector",
-        ModelKind::GestureClassifier => "Gesture classifier",
+/// The model's name followed by `zh` or `en`, whichever the language is.
+fn model_message(model: ModelKind, zh: &'static str, en: &'static str) -> String {
+    format!("{}{}", model.display_name(), locale::tr(zh, en))
+}
+
+fn downloading_message(model: ModelKind) -> String {
+    match locale::locale() {
+        Locale::ZhCn => format!("正在下载{}模型...", model.display_name()),
+        Locale::En => format!("Downloading {} model...", model.display_name()),
     }
 }
 
@@ -218,32 +405,135 @@ impl AppView {
         camera_frame_rx: Receiver<Frame>,
         camera_frame_tx: Sender<Frame>,
         recognizer_backend: RecognizerBackend,
+        metrics: MetricsHandle,
+        settings: AppSettings,
+        mqtt_status: Option<MqttStatusHandle>,
+        cx: &mut Context<'_, Self>,
     ) -> Self {
+        let privacy_mode = settings.privacy_mode.unwrap_or(false);
+        let recorder = Recorder::with_settings(settings.recording.clone().unwrap_or_default());
+        recorder.set_disabled(privacy_mode);
+        let (recognizer_status_tx, recognizer_status_rx) = unbounded();
+        let recognizer_backend = recognizer_backend
+            .with_recorder(recorder.clone())
+            .with_status_tap(recognizer_status_tx);
+        let recognizer_config = recognizer_backend.config();
+        let execution_provider = recognizer_backend.execution_provider();
+        let palm_config = recognizer_backend.palm_config();
+        let sequences = recognizer_backend.sequences().to_vec();
+        let enabled_gestures = recognizer_backend.enabled_gestures().clone();
+        let skeleton_style = settings.skeleton_style();
+        let overlay_mode = settings.overlay_mode.unwrap_or_default();
+        let output_size = settings.output_size.unwrap_or_default();
+        let censor_gestures = settings.censor_gestures();
+        let burn_in_label = settings.burn_in_label.unwrap_or(false);
+        let pointing_ray = settings.pointing_ray.unwrap_or(false);
+        let clip_exporter =
+            clip_export::ClipExporter::new(settings.clip_format.unwrap_or_default());
+        let photo_booth =
+            photo_booth::PhotoBoothState::new(settings.photo_booth.unwrap_or_default());
+        let label_stabilizer = LabelStabilizer::new(settings.label_stabilizer.unwrap_or_default());
+        let mini_overlay =
+            mini_overlay::MiniOverlayState::new(settings.mini_overlay.clone().unwrap_or_default());
         let (recognized_tx, recognized_rx) = crossbeam_channel::bounded(1);
-        let (composited_rx, compositor_handle) = start_frame_compositor(recognized_rx);
+        let (recognizer_control_tx, recognizer_control_rx) = unbounded();
+        let (compositor_settings_tx, compositor_settings_rx) = unbounded();
+        let (gesture_event_tx, gesture_event_rx) = crossbeam_channel::bounded(64);
+        let action_config = ActionConfig::load_or_default(&default_actions_config_path());
+        let cursor = CursorController::new(action_config.cursor.clone());
+        let (hold_confirm_tx, hold_confirm_rx) = unbounded();
+        let (composited_rx, compositor_handle) = start_frame_compositor(
+            recognized_rx,
+            CompositorSettings::from(&recognizer_config)
+                .with_skeleton_style(skeleton_style)
+                .with_overlay_mode(overlay_mode)
+                .with_output_size(output_size)
+                .with_censor_gestures(censor_gestures.clone())
+                .with_burn_in_label(burn_in_label, settings.label_font_path.clone())
+                .with_roi(palm_config.roi)
+                .with_pointing_ray(pointing_ray)
+                .with_privacy_mode(privacy_mode)
+                .with_clip_buffer(clip_exporter.buffer.clone())
+                .with_photo_booth(photo_booth.config, photo_booth.events())
+                .with_hold_confirm(action_config.hold_bindings(), hold_confirm_tx.clone()),
+            compositor_settings_rx,
+            metrics.clone(),
+        );
         let (download_tx, download_rx) = unbounded();
         let download_handle =
             download::spawn_model_download(recognizer_backend.clone(), download_tx);
-        let (_initial_camera_state, available_cameras) = Self::initial_camera_state();
-        let selected_camera_idx = if available_cameras.is_empty() {
-            None
-        } else {
-            Some(0)
-        };
+        let camera_manager =
+            CameraManager::new(camera_frame_tx, metrics.clone(), recognizer_config.mirror);
+        // Listed again when the camera screen opens; probing waits until then.
+        let available_cameras = available_cameras().unwrap_or_default();
+        let selected_camera_idx = camera_view::preferred_camera_idx(&available_cameras, &settings);
+        let camera_format = settings.camera_format();
+        let camera_controls = settings.camera_controls.clone().unwrap_or_default();
 
         Self {
             screen: Screen::Download(DownloadState::new()),
             composited_rx: Some(composited_rx),
             camera_frame_rx: Some(camera_frame_rx),
-            camera_frame_tx,
-            recognized_tx,
             recognizer_backend,
-            _frame_compositor_handle: compositor_handle,
-            recognizer_handle: None,
-            camera_stream: None,
+            recognizer_config,
+            execution_provider,
+            palm_config,
+            skeleton_style,
+            overlay_mode,
+            output_size,
+            censor_gestures,
+            burn_in_label,
+            pointing_ray,
+            privacy_mode,
+            sequences,
+            enabled_gestures,
+            recognizer_control_tx,
+            recognizer_control_rx: Some(recognizer_control_rx),
+            recognizer_status_rx,
+            recognizer_error: None,
+            compositor_settings_tx,
+            gesture_event_tx,
+            gesture_event_rx,
+            stable_gesture: None,
+            label_stabilizer,
+            stable_label: None,
+            gesture_history: history_panel::GestureHistory::default(),
+            analytics: settings
+                .gesture_analytics
+                .unwrap_or(false)
+                .then(GestureAnalytics::default),
+            history_show_all: false,
+            history_selected: None,
+            metrics,
+            latest_metrics: PipelineMetrics::default(),
+            action_config,
+            settings,
+            settings_checked_at: Instant::now(),
+            action_dispatcher: ActionDispatcher::new(),
+            cursor,
+            cursor_calibration: None,
+            hold_confirm_tx,
+            hold_confirm_rx,
+            action_recording: None,
+            actions_focus: cx.focus_handle(),
+            pipeline: PipelineHandles::new(camera_manager, recognized_tx, compositor_handle),
+            recorder,
+            paused: false,
+            capture_rx: None,
+            capture_toast: None,
+            clip_exporter,
+            photo_booth,
+            virtual_camera: None,
+            mqtt_status,
             available_cameras,
             selected_camera_idx,
+            camera_format,
+            camera_formats: None,
+            camera_controls,
+            video_file: None,
+            loop_video: true,
             camera_error: None,
+            camera_status: None,
             latest_frame: None,
             latest_result: None,
             latest_image: None,
@@ -254,38 +544,148 @@ impl AppView {
             camera_picker_open: false,
             right_panel_width: RIGHT_PANEL_INITIAL_WIDTH,
             panel_resize_state: None,
+            roi_editor: roi_editor::RoiEditor::default(),
+            drag_demo: drag_demo::DragDemo::default(),
+            mini_overlay,
+            status_bar: status_bar::StatusBar::default(),
             is_refreshing_cameras: false,
         }
     }
 
     fn start_recognizer_if_needed(&mut self) {
-        if self.recognizer_handle.is_some() {
+        if self.pipeline.recognizer_started() {
             return;
         }
+        let Some(recognized_tx) = self.pipeline.recognized_sender() else {
+            return;
+        };
 
-        let Some(frame_rx) = self.camera_frame_rx.take() else {
+        let (Some(frame_rx), Some(control_rx)) = (
+            self.camera_frame_rx.take(),
+            self.recognizer_control_rx.take(),
+        ) else {
             log::warn!("missing frame receiver for recognizer");
             return;
         };
 
-        let backend = self.recognizer_backend.clone();
-        let handle = start_recognizer(backend, frame_rx, self.recognized_tx.clone());
-        self.recognizer_handle = Some(handle);
+        let backend = self
+            .recognizer_backend
+            .clone()
+            .with_config(self.recognizer_config)
+            .with_execution_provider(self.execution_provider)
+            .with_palm_config(self.palm_config)
+            .with_sequences(self.sequences.clone())
+            .with_enabled_gestures(self.enabled_gestures.clone());
+        let handle = start_recognizer(
+            backend,
+            frame_rx,
+            control_rx,
+            recognized_tx,
+            self.gesture_event_tx.clone(),
+            self.metrics.clone(),
+        );
+        self.pipeline.set_recognizer(handle);
+    }
+
+    fn shutdown_pipeline(&mut self) {
+        self.save_settings_if_changed();
+        self.recorder.stop();
+        if !self.pipeline.shutdown(PIPELINE_SHUTDOWN_TIMEOUT) {
+            log::warn!("pipeline did not shut down cleanly");
+        }
+    }
+
+    /// Snapshot of the current choices layered over the loaded settings, so
+    /// keys this build does not manage survive the rewrite.
+    fn current_settings(&self) -> AppSettings {
+        let config = self.recognizer_config;
+        let camera = self
+            .selected_camera_idx
+            .and_then(|idx| self.available_cameras.get(idx).map(|device| (idx, device)));
+        AppSettings {
+            camera_label: camera
+                .map(|(_, device)| device.label.clone())
+                .or_else(|| self.settings.camera_label.clone()),
+            camera_index: camera.map(|(idx, _)| idx).or(self.settings.camera_index),
+            camera_format: self.camera_format.map(Into::into),
+            mirror: Some(config.mirror),
+            camera_controls: (!self.camera_controls.is_empty())
+                .then(|| self.camera_controls.clone()),
+            detection_threshold: Some(config.detection_threshold),
+            classification_threshold: Some(config.classification_threshold),
+            overlay_threshold: Some(config.overlay_threshold),
+            pinch_threshold: Some(config.pinch_threshold),
+            palm_score_threshold: Some(self.palm_config.score_threshold),
+            max_input_dimension: Some(self.palm_config.max_input_dimension),
+            inference_budget_ms: Some(self.palm_config.inference_budget.as_millis() as u64),
+            palm_detect_interval: Some(self.palm_config.detect_interval),
+            track_max_misses: Some(self.palm_config.track_max_misses),
+            roi: self.palm_config.roi,
+            depth_overlay: Some(config.depth_overlay),
+            count_half_bent: Some(config.count_half_bent),
+            normalization: Some(config.normalization),
+            dwell: config.dwell,
+            smoothing: config.smoothing,
+            quality_gate: Some(config.quality_gate),
+            motion_gate: Some(config.motion_gate),
+            sequences: Some(self.sequences.clone()),
+            enabled_gestures: Some(
+                GestureKind::ALL
+                    .into_iter()
+                    .filter(|kind| self.enabled_gestures.contains(kind))
+                    .collect(),
+            ),
+            skeleton_style: Some(AppSettings::skeleton_style_name(&self.skeleton_style).into()),
+            overlay_mode: Some(self.overlay_mode),
+            output_size: Some(self.output_size),
+            execution_provider: Some(self.execution_provider.label().to_string()),
+            burn_in_label: Some(self.burn_in_label),
+            pointing_ray: Some(self.pointing_ray),
+            privacy_mode: Some(self.privacy_mode),
+            mini_overlay: Some(self.mini_overlay.settings.clone()),
+            gesture_analytics: Some(self.analytics.is_some()),
+            locale: Some(locale::locale()),
+            clip_format: Some(self.clip_exporter.format),
+            photo_booth: Some(self.photo_booth.config),
+            recording: Some(self.recorder.settings()),
+            label_stabilizer: Some(self.label_stabilizer.config()),
+            censor_gestures: Some(
+                GestureKind::ALL
+                    .into_iter()
+                    .filter(|kind| self.censor_gestures.contains(kind))
+                    .collect(),
+            ),
+            ..self.settings.clone()
+        }
+    }
+
+    fn save_settings_if_changed(&mut self) {
+        self.settings_checked_at = Instant::now();
+        let settings = self.current_settings();
+        if settings == self.settings {
+            return;
+        }
+        let path = default_settings_path();
+        if let Err(err) = settings.save(&path) {
+            log::warn!("failed to save settings: {err:#}");
+        }
+        // Remembered even on failure so a read-only directory does not retry
+        // every second.
+        self.settings = settings;
     }
 
     fn update_fps(&mut self, ts: Instant) {
-        if let Some(prev) = self.last_frame_ts.replace(ts) {
-            if let Some(delta) = ts.checked_duration_since(prev) {
-                if delta.as_secs_f32() > 0.0 {
-                    let current = 1.0 / delta.as_secs_f32();
-                    let smoothed = if let Some(prev_fps) = self.latest_fps {
-                        prev_fps * 0.8 + current * 0.2
-                    } else {
-                        current
-                    };
-                    self.latest_fps = Some(smoothed.min(240.0));
-                }
-            }
+        if let Some(prev) = self.last_frame_ts.replace(ts)
+            && let Some(delta) = ts.checked_duration_since(prev)
+            && delta.as_secs_f32() > 0.0
+        {
+            let current = 1.0 / delta.as_secs_f32();
+            let smoothed = if let Some(prev_fps) = self.latest_fps {
+                prev_fps * 0.8 + current * 0.2
+            } else {
+                current
+            };
+            self.latest_fps = Some(smoothed.min(240.0));
         }
     }
 }
@@ -321,7 +721,7 @@ impl Render for AppView {
                 let should_switch = state.finished && state.error.is_none() && min_time_passed;
                 let view = self.render_download_view(&state, cx);
                 if should_switch {
-                    let (initial_camera_state, _) = Self::initial_camera_state();
+                    let (initial_camera_state, _) = Self::initial_camera_state(&self.settings);
                     screen = Screen::Camera(initial_camera_state);
                 } else {
                     screen = Screen::Download(state);
@@ -0,0 +1,176 @@
+use std::time::Instant;
+
+use crossbeam_channel::{Receiver, Sender, unbounded};
+
+use super::{
+    AnyElement, AppView, Button, Context, IntoElement, ParentElement, SharedString, Styled, div,
+    h_flex,
+};
+use crate::{
+    locale::{self, tr},
+    pipeline::{PhotoBoothConfig, PhotoBoothEvent, spawn_capture},
+    types::GestureKind,
+};
+use gpui_component::StyledExt;
+
+/// Trigger hold times offered, in ms.
+const HOLD_TIMES: [u64; 5] = [500, 1_000, 1_500, 2_000, 3_000];
+
+/// Settings of the gesture-triggered countdown photos, and the channel the
+/// compositor reports shots on.
+pub(super) struct PhotoBoothState {
+    pub(super) config: PhotoBoothConfig,
+    events_tx: Sender<PhotoBoothEvent>,
+    events_rx: Receiver<PhotoBoothEvent>,
+}
+
+impl PhotoBoothState {
+    pub(super) fn new(config: PhotoBoothConfig) -> Self {
+        let (events_tx, events_rx) = unbounded();
+        Self {
+            config,
+            events_tx,
+            events_rx,
+        }
+    }
+
+    /// Sender to hand to the compositor.
+    pub(super) fn events(&self) -> Sender<PhotoBoothEvent> {
+        self.events_tx.clone()
+    }
+}
+
+impl AppView {
+    pub(super) fn photo_booth_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let config = self.photo_booth.config;
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("拍照模式", "Photo booth")),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(
+                        Button::new(SharedString::from("photo-booth-gesture"))
+                            .outline()
+                            .label(format!(
+                                "{}{}",
+                                config.gesture.emoji(),
+                                config.gesture.display_name()
+                            ))
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.cycle_photo_booth_gesture();
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new(SharedString::from("photo-booth-toggle"))
+                            .outline()
+                            .label(locale::on_off(config.enabled))
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.photo_booth.config.enabled = !this.photo_booth.config.enabled;
+                                this.push_compositor_settings();
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    pub(super) fn photo_booth_hold_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let value = format!("{}ms", self.photo_booth.config.hold_ms);
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("拍照保持", "Hold to shoot")),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(
+                        Button::new(SharedString::from("photo-booth-hold-down"))
+                            .outline()
+                            .label("−")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.step_photo_booth_hold(-1);
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        div()
+                            .w(super::px(56.0))
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(gpui::rgb(0xe0f2fe))
+                            .child(value),
+                    )
+                    .child(
+                        Button::new(SharedString::from("photo-booth-hold-up"))
+                            .outline()
+                            .label("+")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.step_photo_booth_hold(1);
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn cycle_photo_booth_gesture(&mut self) {
+        let config = &mut self.photo_booth.config;
+        let next = GestureKind::ALL
+            .iter()
+            .position(|kind| *kind == config.gesture)
+            .map_or(0, |idx| (idx + 1) % GestureKind::ALL.len());
+        config.gesture = GestureKind::ALL[next];
+        self.push_compositor_settings();
+    }
+
+    fn step_photo_booth_hold(&mut self, step: isize) {
+        let config = &mut self.photo_booth.config;
+        let current = HOLD_TIMES
+            .iter()
+            .position(|ms| *ms == config.hold_ms)
+            .unwrap_or(1);
+        let next = current
+            .saturating_add_signed(step)
+            .min(HOLD_TIMES.len() - 1);
+        config.hold_ms = HOLD_TIMES[next];
+        self.push_compositor_settings();
+    }
+
+    /// Saves the shots the compositor took through the capture button's
+    /// writer, so they land next to the other captures.
+    pub(super) fn poll_photo_booth(&mut self) {
+        while let Ok(event) = self.photo_booth.events_rx.try_recv() {
+            match event {
+                PhotoBoothEvent::Shot { frame, result } => {
+                    self.capture_rx =
+                        Some(spawn_capture(self.settings.captures_dir(), frame, *result));
+                }
+                PhotoBoothEvent::Cancelled => {
+                    self.capture_toast = Some((
+                        tr("拍照已取消", "Photo cancelled").to_string(),
+                        Instant::now(),
+                    ));
+                }
+            }
+        }
+    }
+}
@@ -1,20 +1,57 @@
 use super::{Arc, ImageBuffer, ImageFrame, RenderImage, Rgba};
-use crate::{pipeline::skeleton, types::Frame};
+use crate::{
+    pipeline::{
+        CompositedFrame,
+        skeleton::{self, OverlayMode, SkeletonStyle},
+    },
+    types::PixelLayout,
+};
 
+/// `None` while `privacy_mode` is on for a frame that still shows the camera,
+/// e.g. one composited before the setting reached the compositor.
 pub(super) fn frame_to_image(
-    frame: &Frame,
+    composited: &CompositedFrame,
+    privacy_mode: bool,
     overlay: Option<&[(f32, f32)]>,
+    style: &SkeletonStyle,
+    mode: OverlayMode,
 ) -> Option<Arc<RenderImage>> {
-    let mut rgba = frame.rgba.clone();
-    if let Some(points) = overlay {
-        skeleton::draw_skeleton(&mut rgba, frame.width, frame.height, points);
+    if privacy_mode && !composited.blanked {
+        return None;
     }
-
-    for px in rgba.chunks_exact_mut(4) {
-        px.swap(0, 2);
+    let frame = &composited.frame;
+    // GPUI wants BGRA. Swapping while copying touches every pixel once instead
+    // of cloning and then swapping; the copy belongs to the `RenderImage`, so
+    // it cannot come from the frame pool.
+    let mut bgra = Vec::with_capacity(frame.width as usize * frame.height as usize * 4);
+    match frame.layout {
+        PixelLayout::Rgb => bgra.extend(
+            frame
+                .pixels
+                .chunks_exact(3)
+                .flat_map(|px| [px[2], px[1], px[0], 255]),
+        ),
+        PixelLayout::Rgba => bgra.extend(
+            frame
+                .pixels
+                .chunks_exact(4)
+                .flat_map(|px| [px[2], px[1], px[0], px[3]]),
+        ),
+    }
+    if let Some(points) = overlay {
+        let style = style.for_bgra();
+        skeleton::draw_hand(
+            &mut bgra,
+            frame.width,
+            frame.height,
+            points,
+            None,
+            &style,
+            mode,
+        );
     }
 
-    let buffer = ImageBuffer::<Rgba<u8>, Vec<u8>>::from_raw(frame.width, frame.height, rgba)?;
+    let buffer = ImageBuffer::<Rgba<u8>, Vec<u8>>::from_raw(frame.width, frame.height, bgra)?;
     let frame = ImageFrame::new(buffer);
 
     Some(Arc::new(RenderImage::new(vec![frame])))
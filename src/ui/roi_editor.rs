@@ -0,0 +1,181 @@
+use gpui::{Bounds, Pixels, Point, canvas};
+
+use super::{
+    AnyElement, AppView, Context, InteractiveElement, IntoElement, MouseButton, MouseDownEvent,
+    MouseMoveEvent, MouseUpEvent, ParentElement, Styled, Window, div,
+};
+use crate::pipeline::RecognizerControl;
+
+/// How close to a corner, as a fraction of the frame, a press grabs it.
+const CORNER_GRAB: f32 = 0.05;
+/// Zones smaller than this on either side are taken for a stray click.
+const MIN_ROI_SIZE: f32 = 0.05;
+
+/// Dragging out the recognition zone on the preview.
+#[derive(Default)]
+pub(super) struct RoiEditor {
+    pub(super) editing: bool,
+    /// Where the preview was last laid out, in window pixels.
+    preview_bounds: Option<Bounds<Pixels>>,
+    drag: Option<RoiDrag>,
+}
+
+struct RoiDrag {
+    /// The corner that stays put, as fractions of the frame.
+    anchor: (f32, f32),
+    /// Restored when the drag ends too small.
+    previous: Option<[f32; 4]>,
+}
+
+impl AppView {
+    /// Layer over the preview while the zone is edited. A press near a corner
+    /// of the zone moves that corner; anywhere else starts a new zone.
+    pub(super) fn render_roi_editor(&self, cx: &mut Context<'_, Self>) -> Option<AnyElement> {
+        if !self.roi_editor.editing {
+            return None;
+        }
+
+        let view = cx.entity();
+        let bounds_probe = canvas(
+            move |bounds, _, cx| {
+                view.update(cx, |this, _| this.roi_editor.preview_bounds = Some(bounds));
+            },
+            |_, _, _, _| {},
+        )
+        .size_full();
+
+        let layer = div()
+            .absolute()
+            .top_0()
+            .left_0()
+            .size_full()
+            .cursor_crosshair()
+            .child(bounds_probe)
+            .on_mouse_down(MouseButton::Left, cx.listener(Self::start_roi_drag))
+            .on_mouse_move(cx.listener(Self::update_roi_drag))
+            .on_mouse_up(MouseButton::Left, cx.listener(Self::finish_roi_drag))
+            .on_mouse_up_out(MouseButton::Left, cx.listener(Self::finish_roi_drag));
+        Some(layer.into_any_element())
+    }
+
+    pub(super) fn toggle_roi_editing(&mut self) {
+        self.roi_editor.editing = !self.roi_editor.editing;
+        self.roi_editor.drag = None;
+    }
+
+    pub(super) fn clear_roi(&mut self) {
+        self.roi_editor.drag = None;
+        self.apply_roi(None);
+    }
+
+    fn start_roi_drag(
+        &mut self,
+        event: &MouseDownEvent,
+        _: &mut Window,
+        cx: &mut Context<'_, Self>,
+    ) {
+        let Some(point) = self.roi_point(event.position) else {
+            return;
+        };
+        let previous = self.palm_config.roi;
+        let anchor = previous
+            .and_then(|roi| opposite_corner(roi, point))
+            .unwrap_or(point);
+        self.roi_editor.drag = Some(RoiDrag { anchor, previous });
+        self.preview_roi(span(anchor, point));
+        cx.notify();
+    }
+
+    fn update_roi_drag(
+        &mut self,
+        event: &MouseMoveEvent,
+        _: &mut Window,
+        cx: &mut Context<'_, Self>,
+    ) {
+        let Some(anchor) = self.roi_editor.drag.as_ref().map(|drag| drag.anchor) else {
+            return;
+        };
+        // The button went up outside the window.
+        if !event.dragging() {
+            self.end_roi_drag();
+        } else if let Some(point) = self.roi_point(event.position) {
+            self.preview_roi(span(anchor, point));
+        }
+        cx.notify();
+    }
+
+    fn finish_roi_drag(&mut self, _: &MouseUpEvent, _: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.roi_editor.drag.is_some() {
+            self.end_roi_drag();
+            cx.notify();
+        }
+    }
+
+    fn end_roi_drag(&mut self) {
+        let Some(drag) = self.roi_editor.drag.take() else {
+            return;
+        };
+        let roi = self
+            .palm_config
+            .roi
+            .filter(|[x1, y1, x2, y2]| x2 - x1 >= MIN_ROI_SIZE && y2 - y1 >= MIN_ROI_SIZE)
+            .or(drag.previous);
+        self.apply_roi(roi);
+    }
+
+    /// Shown by the overlay only; the recognizer takes the zone once the drag
+    /// ends.
+    fn preview_roi(&mut self, roi: [f32; 4]) {
+        self.palm_config.roi = Some(roi);
+        self.push_compositor_settings();
+    }
+
+    fn apply_roi(&mut self, roi: Option<[f32; 4]>) {
+        self.palm_config.roi = roi;
+        let _ = self
+            .recognizer_control_tx
+            .send(RecognizerControl::SetPalmConfig(self.palm_config));
+        self.push_compositor_settings();
+    }
+
+    /// `position` as fractions of the frame, clamped to it. The preview fits
+    /// the frame inside its box, so the bars around it are left out.
+    fn roi_point(&self, position: Point<Pixels>) -> Option<(f32, f32)> {
+        let bounds = self.roi_editor.preview_bounds?;
+        let width = f32::from(bounds.size.width);
+        let height = f32::from(bounds.size.height);
+        if width <= 0.0 || height <= 0.0 {
+            return None;
+        }
+
+        let ratio = self.camera_aspect_ratio();
+        let (shown_width, shown_height) = if width / height > ratio {
+            (height * ratio, height)
+        } else {
+            (width, width / ratio)
+        };
+        let left = f32::from(bounds.origin.x) + (width - shown_width) / 2.0;
+        let top = f32::from(bounds.origin.y) + (height - shown_height) / 2.0;
+        Some((
+            ((f32::from(position.x) - left) / shown_width).clamp(0.0, 1.0),
+            ((f32::from(position.y) - top) / shown_height).clamp(0.0, 1.0),
+        ))
+    }
+}
+
+/// The corner across from the one `point` grabs, if it is close to one.
+fn opposite_corner([x1, y1, x2, y2]: [f32; 4], point: (f32, f32)) -> Option<(f32, f32)> {
+    [(x1, y1), (x2, y1), (x1, y2), (x2, y2)]
+        .into_iter()
+        .map(|corner| {
+            let distance = (corner.0 - point.0).hypot(corner.1 - point.1);
+            (corner, distance)
+        })
+        .filter(|(_, distance)| *distance <= CORNER_GRAB)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|((x, y), _)| (x1 + x2 - x, y1 + y2 - y))
+}
+
+fn span(a: (f32, f32), b: (f32, f32)) -> [f32; 4] {
+    [a.0.min(b.0), a.1.min(b.1), a.0.max(b.0), a.1.max(b.1)]
+}
@@ -0,0 +1,205 @@
+use super::{
+    AnyElement, AppView, Button, Context, IntoElement, ParentElement, SharedString, Styled, div,
+    h_flex, v_flex,
+};
+use crate::{
+    locale::{Locale, locale, tr},
+    pipeline::RecognizerControl,
+    types::{GestureKind, GestureSequence},
+};
+use gpui_component::StyledExt;
+
+/// Longest combo the panel builds; longer ones can still be written by hand.
+const MAX_STEPS: usize = 6;
+const TIMEOUTS_MS: [u64; 4] = [1_000, 2_000, 3_000, 5_000];
+
+impl AppView {
+    pub(super) fn render_sequences_panel(
+        &self,
+        panel_width: f32,
+        cx: &mut Context<'_, Self>,
+    ) -> AnyElement {
+        let header = h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_sm()
+                    .font_semibold()
+                    .text_color(gpui::rgb(0xffffff))
+                    .child(tr("组合手势", "Gesture sequences")),
+            )
+            .child(
+                Button::new(SharedString::from("sequences-add"))
+                    .outline()
+                    .label(tr("+ 添加", "+ Add"))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        let name = this.unused_sequence_name();
+                        this.sequences.push(GestureSequence {
+                            name,
+                            ..Default::default()
+                        });
+                        this.push_sequences();
+                        cx.notify();
+                    })),
+            );
+
+        let mut rows = v_flex().gap_2();
+        for idx in 0..self.sequences.len() {
+            rows = rows.child(self.sequence_row(idx, cx));
+        }
+        if self.sequences.is_empty() {
+            rows = rows.child(div().text_xs().text_color(gpui::rgb(0x6b7280)).child(tr(
+                "还没有组合，点击“添加”后可在快捷键里选用",
+                "No sequences yet, click “Add” and then pick them in the shortcuts",
+            )));
+        }
+
+        v_flex()
+            .w(super::px(panel_width))
+            .gap_3()
+            .p_4()
+            .rounded_lg()
+            .bg(gpui::rgb(0x0f172a))
+            .border_1()
+            .border_color(gpui::rgba(0xffffff1a))
+            .child(header)
+            .child(rows)
+            .into_any_element()
+    }
+
+    fn sequence_row(&self, idx: usize, cx: &mut Context<'_, Self>) -> AnyElement {
+        let sequence = &self.sequences[idx];
+
+        let mut steps = h_flex().gap_1().items_center();
+        for (step, kind) in sequence.steps.iter().enumerate() {
+            // Each step cycles through the gestures on click.
+            steps = steps.child(
+                Button::new(SharedString::from(format!("sequence-{idx}-step-{step}")))
+                    .outline()
+                    .label(kind.emoji().trim())
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        this.cycle_sequence_step(idx, step);
+                        cx.notify();
+                    })),
+            );
+        }
+        if sequence.steps.len() < MAX_STEPS {
+            steps = steps.child(
+                Button::new(SharedString::from(format!("sequence-{idx}-push")))
+                    .outline()
+                    .label("+")
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        if let Some(sequence) = this.sequences.get_mut(idx) {
+                            sequence.steps.push(GestureKind::Palm);
+                            this.push_sequences();
+                        }
+                        cx.notify();
+                    })),
+            );
+        }
+        if sequence.steps.len() > 1 {
+            steps = steps.child(
+                Button::new(SharedString::from(format!("sequence-{idx}-pop")))
+                    .outline()
+                    .label("−")
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        if let Some(sequence) = this.sequences.get_mut(idx) {
+                            sequence.steps.pop();
+                            this.push_sequences();
+                        }
+                        cx.notify();
+                    })),
+            );
+        }
+
+        h_flex()
+            .w_full()
+            .gap_1()
+            .items_center()
+            .child(
+                div()
+                    .w(super::px(64.0))
+                    .text_sm()
+                    .text_color(gpui::rgb(0xe0f2fe))
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .whitespace_nowrap()
+                    .child(sequence.name.clone()),
+            )
+            .child(steps)
+            .child(div().flex_1())
+            .child(
+                Button::new(SharedString::from(format!("sequence-{idx}-timeout")))
+                    .outline()
+                    .label(match locale() {
+                        Locale::ZhCn => format!("{}s 内", sequence.timeout_ms as f32 / 1000.0),
+                        Locale::En => format!("within {}s", sequence.timeout_ms as f32 / 1000.0),
+                    })
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        this.cycle_sequence_timeout(idx);
+                        cx.notify();
+                    })),
+            )
+            .child(
+                Button::new(SharedString::from(format!("sequence-{idx}-remove")))
+                    .outline()
+                    .label("✕")
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        if idx < this.sequences.len() {
+                            this.sequences.remove(idx);
+                            this.push_sequences();
+                        }
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    fn cycle_sequence_step(&mut self, idx: usize, step: usize) {
+        let gestures: Vec<GestureKind> = GestureKind::ALL
+            .into_iter()
+            .filter(|kind| *kind != GestureKind::NoGesture)
+            .collect();
+        let Some(kind) = self
+            .sequences
+            .get_mut(idx)
+            .and_then(|sequence| sequence.steps.get_mut(step))
+        else {
+            return;
+        };
+        let current = gestures.iter().position(|g| g == kind).unwrap_or(0);
+        *kind = gestures[(current + 1) % gestures.len()];
+        self.push_sequences();
+    }
+
+    /// Steps through the timeouts, keeping the gap between steps within it.
+    fn cycle_sequence_timeout(&mut self, idx: usize) {
+        let Some(sequence) = self.sequences.get_mut(idx) else {
+            return;
+        };
+        let current = TIMEOUTS_MS
+            .iter()
+            .position(|ms| *ms == sequence.timeout_ms)
+            .unwrap_or(0);
+        sequence.timeout_ms = TIMEOUTS_MS[(current + 1) % TIMEOUTS_MS.len()];
+        sequence.max_gap_ms = sequence.max_gap_ms.min(sequence.timeout_ms);
+        self.push_sequences();
+    }
+
+    fn unused_sequence_name(&self) -> String {
+        (1..)
+            .map(|n| format!("{} {n}", tr("组合", "Combo")))
+            .find(|name| self.sequences.iter().all(|sequence| sequence.name != *name))
+            .unwrap_or_default()
+    }
+
+    /// Hands the edited sequences to the recognizer; the settings file picks
+    /// them up with the next save.
+    fn push_sequences(&self) {
+        let _ = self
+            .recognizer_control_tx
+            .send(RecognizerControl::SetSequences(self.sequences.clone()));
+    }
+}
@@ -0,0 +1,1133 @@
+use super::{
+    AnyElement, AppView, Button, Context, IntoElement, ParentElement, SharedString, Styled, Window,
+    div, h_flex, v_flex,
+};
+use crate::{
+    locale::{self, Locale, locale, tr},
+    mqtt_output::MqttStatus,
+    pipeline::{
+        CompositorSettings, ExecutionProvider, RecognizerControl, VirtualCamera,
+        recorder::RecorderSettings,
+        skeleton::{OverlayMode, SkeletonStyle},
+    },
+    types::{GestureKind, SmoothingConfig},
+};
+use gpui_component::{StyledExt, button::ButtonVariants};
+
+const THRESHOLD_STEP: f32 = 0.05;
+/// Palm scores below this are noise no matter how sensitive the detector is set.
+const MIN_PALM_SCORE: f32 = 0.05;
+/// Long-side caps offered for the inference input, smallest first; 0 is full
+/// resolution.
+const INPUT_DIMENSIONS: [u32; 6] = [320, 480, 640, 960, 1280, 0];
+/// Label vote time constants offered, in ms; 0 shows every frame as is.
+const LABEL_TIME_CONSTANTS: [u64; 5] = [0, 150, 300, 600, 1_000];
+/// Recording frame rate caps offered.
+const RECORD_FPS: [f32; 4] = [5.0, 10.0, 15.0, 30.0];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum ThresholdKind {
+    Detection,
+    Classification,
+    Overlay,
+}
+
+impl ThresholdKind {
+    const ALL: [ThresholdKind; 3] = [
+        ThresholdKind::Detection,
+        ThresholdKind::Classification,
+        ThresholdKind::Overlay,
+    ];
+
+    fn label(&self) -> &'static str {
+        self.label_in(locale())
+    }
+
+    fn label_in(&self, locale: Locale) -> &'static str {
+        let (zh, en) = match self {
+            ThresholdKind::Detection => ("检测阈值", "Detection threshold"),
+            ThresholdKind::Classification => ("识别阈值", "Recognition threshold"),
+            ThresholdKind::Overlay => ("骨架阈值", "Skeleton threshold"),
+        };
+        locale.pick(zh, en)
+    }
+
+    fn id(&self) -> &'static str {
+        match self {
+            ThresholdKind::Detection => "detection",
+            ThresholdKind::Classification => "classification",
+            ThresholdKind::Overlay => "overlay",
+        }
+    }
+}
+
+impl AppView {
+    pub(super) fn render_settings_panel(
+        &self,
+        panel_width: f32,
+        cx: &mut Context<'_, Self>,
+    ) -> AnyElement {
+        let mut rows = v_flex().gap_2();
+        for kind in ThresholdKind::ALL {
+            rows = rows.child(self.threshold_row(kind, cx));
+        }
+
+        v_flex()
+            .w(super::px(panel_width))
+            .gap_3()
+            .p_4()
+            .rounded_lg()
+            .bg(gpui::rgb(0x0f172a))
+            .border_1()
+            .border_color(gpui::rgba(0xffffff1a))
+            .child(
+                div()
+                    .text_sm()
+                    .font_semibold()
+                    .text_color(gpui::rgb(0xffffff))
+                    .child(tr("识别设置", "Recognition settings")),
+            )
+            .child(self.language_row(cx))
+            .child(rows)
+            .child(self.sensitivity_row(cx))
+            .child(self.input_dimension_row(cx))
+            .child(self.roi_row(cx))
+            .child(self.mirror_row(cx))
+            .child(self.depth_overlay_row(cx))
+            .child(self.count_half_bent_row(cx))
+            .child(self.smoothing_row(cx))
+            .child(self.label_stabilizer_row(cx))
+            .child(self.quality_gate_row(cx))
+            .child(self.motion_gate_row(cx))
+            .child(self.censor_row(cx))
+            .child(self.burn_in_label_row(cx))
+            .child(self.pointing_ray_row(cx))
+            .child(self.privacy_mode_row(cx))
+            .child(self.drag_demo_row(cx))
+            .child(self.record_fps_row(cx))
+            .child(self.clip_format_row(cx))
+            .child(self.photo_booth_row(cx))
+            .child(self.photo_booth_hold_row(cx))
+            .child(self.virtual_camera_row(cx))
+            .child(self.mini_overlay_row(cx))
+            .children(self.mqtt_row())
+            .child(self.skeleton_style_row(cx))
+            .child(self.overlay_mode_row(cx))
+            .child(self.output_size_row(cx))
+            .child(self.execution_provider_row(cx))
+            .child(self.reload_models_row(cx))
+            .into_any_element()
+    }
+
+    /// Labelled in both languages, so it can be found whichever is showing.
+    fn language_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child("语言 / Language"),
+            )
+            .child(
+                Button::new(SharedString::from("locale-cycle"))
+                    .outline()
+                    .label(locale::locale().label())
+                    .on_click(cx.listener(|_, _, _, cx| {
+                        locale::set_locale(locale::locale().next());
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    fn mirror_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let mirror = self.recognizer_config.mirror;
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("镜像画面", "Mirror view")),
+            )
+            .child(
+                Button::new(SharedString::from("mirror-toggle"))
+                    .outline()
+                    .label(locale::on_off(mirror))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.toggle_mirror();
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    fn toggle_mirror(&mut self) {
+        self.recognizer_config.mirror = !self.recognizer_config.mirror;
+        self.pipeline
+            .camera_mut()
+            .set_mirror(self.recognizer_config.mirror);
+        self.push_recognizer_config();
+    }
+
+    fn depth_overlay_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let enabled = self.recognizer_config.depth_overlay;
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("深度着色", "Depth shading")),
+            )
+            .child(
+                Button::new(SharedString::from("depth-overlay-toggle"))
+                    .outline()
+                    .label(locale::on_off(enabled))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.toggle_depth_overlay();
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    fn count_half_bent_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let enabled = self.recognizer_config.count_half_bent;
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("半弯手指计数", "Count half-bent fingers")),
+            )
+            .child(
+                Button::new(SharedString::from("count-half-bent-toggle"))
+                    .outline()
+                    .label(locale::on_off(enabled))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.recognizer_config.count_half_bent =
+                            !this.recognizer_config.count_half_bent;
+                        this.push_recognizer_config();
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    fn smoothing_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let enabled = self.recognizer_config.smoothing.is_some();
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("关键点平滑", "Landmark smoothing")),
+            )
+            .child(
+                Button::new(SharedString::from("smoothing-toggle"))
+                    .outline()
+                    .label(locale::on_off(enabled))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.recognizer_config.smoothing = match this.recognizer_config.smoothing {
+                            Some(_) => None,
+                            None => Some(SmoothingConfig::default()),
+                        };
+                        this.push_recognizer_config();
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    /// Skips inference on frames too dark or washed out to find hands in.
+    fn quality_gate_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let enabled = self.recognizer_config.quality_gate.enabled;
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("暗光检测", "Low-light check")),
+            )
+            .child(
+                Button::new(SharedString::from("quality-gate-toggle"))
+                    .outline()
+                    .label(locale::on_off(enabled))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        let gate = &mut this.recognizer_config.quality_gate;
+                        gate.enabled = !gate.enabled;
+                        this.push_recognizer_config();
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    /// Stops inference while the scene is still and nobody is in it.
+    fn motion_gate_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let enabled = self.recognizer_config.motion_gate.enabled;
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("静止休眠", "Sleep when still")),
+            )
+            .child(
+                Button::new(SharedString::from("motion-gate-toggle"))
+                    .outline()
+                    .label(locale::on_off(enabled))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        let gate = &mut this.recognizer_config.motion_gate;
+                        gate.enabled = !gate.enabled;
+                        this.push_recognizer_config();
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    /// Pixelates the hand while it shows the middle finger, for streaming.
+    fn censor_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let enabled = self.censor_gestures.contains(&GestureKind::MiddleFinger);
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("遮挡不雅手势", "Censor rude gestures")),
+            )
+            .child(
+                Button::new(SharedString::from("censor-toggle"))
+                    .outline()
+                    .label(locale::on_off(enabled))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        if !this.censor_gestures.remove(&GestureKind::MiddleFinger) {
+                            this.censor_gestures.insert(GestureKind::MiddleFinger);
+                        }
+                        this.push_recognizer_config();
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    /// Writes the gesture into the frame pixels, for virtual cameras and other
+    /// consumers that never see the app chrome.
+    fn burn_in_label_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("画面内标注", "Label in frame")),
+            )
+            .child(
+                Button::new(SharedString::from("burn-in-label-toggle"))
+                    .outline()
+                    .label(locale::on_off(self.burn_in_label))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.burn_in_label = !this.burn_in_label;
+                        this.push_recognizer_config();
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    fn pointing_ray_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("指向射线", "Pointing ray")),
+            )
+            .child(
+                Button::new(SharedString::from("pointing-ray-toggle"))
+                    .outline()
+                    .label(locale::on_off(self.pointing_ray))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.pointing_ray = !this.pointing_ray;
+                        this.push_compositor_settings();
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    /// Only the overlay, on a blank background, is shown; nothing is recorded,
+    /// captured or exported.
+    fn privacy_mode_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("隐私模式", "Privacy mode")),
+            )
+            .child(
+                Button::new(SharedString::from("privacy-mode-toggle"))
+                    .outline()
+                    .label(locale::on_off(self.privacy_mode))
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.set_privacy_mode(!this.privacy_mode, window, cx);
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    /// Turning it on also drops the camera image on screen; the next frame to
+    /// show is a blank one from the compositor.
+    fn set_privacy_mode(&mut self, enabled: bool, window: &mut Window, cx: &mut Context<'_, Self>) {
+        self.privacy_mode = enabled;
+        self.recorder.set_disabled(enabled);
+        if let Some(image) = self.latest_image.take_if(|_| enabled) {
+            cx.drop_image(image, Some(window));
+        }
+        self.push_compositor_settings();
+    }
+
+    fn record_fps_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let value = format!("{} fps", self.recorder.settings().max_fps);
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("录制帧率", "Recording frame rate")),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(
+                        Button::new(SharedString::from("record-fps-down"))
+                            .outline()
+                            .label("−")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.step_record_fps(-1);
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        div()
+                            .w(super::px(56.0))
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(gpui::rgb(0xe0f2fe))
+                            .child(value),
+                    )
+                    .child(
+                        Button::new(SharedString::from("record-fps-up"))
+                            .outline()
+                            .label("+")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.step_record_fps(1);
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    /// The directory and the disk budget are only set in the settings file
+    /// and are kept as they are.
+    fn step_record_fps(&mut self, step: isize) {
+        let settings = self.recorder.settings();
+        let current = RECORD_FPS
+            .iter()
+            .position(|fps| *fps == settings.max_fps)
+            .unwrap_or(1);
+        let next = current
+            .saturating_add_signed(step)
+            .min(RECORD_FPS.len() - 1);
+        self.recorder.set_settings(RecorderSettings {
+            max_fps: RECORD_FPS[next],
+            ..settings
+        });
+    }
+
+    fn clip_format_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("片段格式", "Clip format")),
+            )
+            .child(
+                Button::new(SharedString::from("clip-format-cycle"))
+                    .outline()
+                    .label(self.clip_exporter.format.label())
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.clip_exporter.format = this.clip_exporter.format.next();
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    fn virtual_camera_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let running = self.virtual_camera.is_some();
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("虚拟摄像头", "Virtual camera")),
+            )
+            .child(
+                Button::new(SharedString::from("virtual-camera-toggle"))
+                    .outline()
+                    .label(locale::on_off(running))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.toggle_virtual_camera();
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    /// Only shown when an MQTT broker is configured.
+    fn mqtt_row(&self) -> Option<AnyElement> {
+        let (text, color) = match self.mqtt_status.as_ref()?.get() {
+            MqttStatus::Connecting => (tr("连接中", "Connecting").to_string(), gpui::rgb(0x94a3b8)),
+            MqttStatus::Connected => (tr("已连接", "Connected").to_string(), gpui::rgb(0x4ade80)),
+            MqttStatus::Disconnected(reason) => (
+                format!("{}: {reason}", tr("未连接", "Disconnected")),
+                gpui::rgb(0xf87171),
+            ),
+        };
+
+        let row = h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .gap_2()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child("MQTT"),
+            )
+            .child(div().text_xs().text_color(color).child(text));
+        Some(row.into_any_element())
+    }
+
+    fn skeleton_style_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let per_finger = self.skeleton_style == SkeletonStyle::per_finger();
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("骨架配色", "Skeleton colors")),
+            )
+            .child(
+                Button::new(SharedString::from("skeleton-style-toggle"))
+                    .outline()
+                    .label(if per_finger {
+                        tr("分指", "Per finger")
+                    } else {
+                        tr("默认", "Default")
+                    })
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        this.skeleton_style = if per_finger {
+                            SkeletonStyle::default()
+                        } else {
+                            SkeletonStyle::per_finger()
+                        };
+                        this.push_recognizer_config();
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    fn overlay_mode_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(format!(
+                        "{} ({})",
+                        tr("叠加显示", "Overlay"),
+                        super::OVERLAY_MODE_KEY.to_uppercase()
+                    )),
+            )
+            .child(
+                Button::new(SharedString::from("overlay-mode-cycle"))
+                    .outline()
+                    .label(self.overlay_mode.label())
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.cycle_overlay_mode();
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    fn output_size_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("输出分辨率", "Output size")),
+            )
+            .child(
+                Button::new(SharedString::from("output-size-cycle"))
+                    .outline()
+                    .label(self.output_size.label())
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.output_size = this.output_size.next();
+                        this.push_compositor_settings();
+                        cx.notify();
+                    })),
+            )
+            .into_any_element()
+    }
+
+    pub(super) fn cycle_overlay_mode(&mut self) {
+        self.set_overlay_mode(self.overlay_mode.next());
+    }
+
+    /// Between the debug overlay and the default one.
+    pub(super) fn toggle_debug_overlay(&mut self) {
+        let mode = if self.overlay_mode == OverlayMode::Debug {
+            OverlayMode::default()
+        } else {
+            OverlayMode::Debug
+        };
+        self.set_overlay_mode(mode);
+    }
+
+    fn set_overlay_mode(&mut self, mode: OverlayMode) {
+        self.overlay_mode = mode;
+        // The palm candidates are only copied out while they are drawn.
+        let keep_candidates = mode == OverlayMode::Debug;
+        if self.palm_config.keep_candidates != keep_candidates {
+            self.palm_config.keep_candidates = keep_candidates;
+            let _ = self
+                .recognizer_control_tx
+                .send(RecognizerControl::SetPalmConfig(self.palm_config));
+        }
+        self.push_recognizer_config();
+    }
+
+    fn toggle_depth_overlay(&mut self) {
+        self.recognizer_config.depth_overlay = !self.recognizer_config.depth_overlay;
+        self.push_recognizer_config();
+    }
+
+    fn execution_provider_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let mut buttons = h_flex().gap_1().flex_wrap();
+        for provider in ExecutionProvider::ALL {
+            let button = Button::new(SharedString::from(format!(
+                "execution-provider-{}",
+                provider.label()
+            )))
+            .label(provider.display_name())
+            .on_click(cx.listener(move |this, _, _, cx| {
+                this.set_execution_provider(provider);
+                cx.notify();
+            }));
+            let button = if provider == self.execution_provider {
+                button.primary()
+            } else {
+                button.outline()
+            };
+            buttons = buttons.child(button);
+        }
+
+        v_flex()
+            .gap_1()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("推理后端", "Inference backend")),
+            )
+            .child(buttons)
+            .into_any_element()
+    }
+
+    fn set_execution_provider(&mut self, provider: ExecutionProvider) {
+        if provider == self.execution_provider {
+            return;
+        }
+        self.execution_provider = provider;
+        // A recognizer that has not started yet picks the provider up from its backend.
+        if !self.pipeline.recognizer_started() {
+            return;
+        }
+        let _ = self
+            .recognizer_control_tx
+            .send(RecognizerControl::SetExecutionProvider(provider));
+    }
+
+    fn reload_models_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        // The files actually in use, which the flags and variables may change.
+        let paths = [
+            (
+                tr("手部", "Hand"),
+                self.recognizer_backend.handpose_estimator_model_path(),
+            ),
+            (
+                tr("手掌", "Palm"),
+                self.recognizer_backend.palm_detector_model_path(),
+            ),
+        ];
+        let row = h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("模型文件", "Model files")),
+            )
+            .child(
+                Button::new(SharedString::from("reload-models"))
+                    .outline()
+                    .label(tr("重新加载", "Reload"))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        // Before the recognizer starts it loads the files anyway.
+                        if this.pipeline.recognizer_started() {
+                            let _ = this
+                                .recognizer_control_tx
+                                .send(RecognizerControl::ReloadModels);
+                        }
+                        cx.notify();
+                    })),
+            );
+
+        let mut column = v_flex().gap_1().child(row);
+        for (label, path) in paths {
+            column = column.child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x6b7280))
+                    .child(format!("{label}: {}", path.display())),
+            );
+        }
+        column.into_any_element()
+    }
+
+    fn threshold_row(&self, kind: ThresholdKind, cx: &mut Context<'_, Self>) -> AnyElement {
+        let value = self.threshold_value(kind);
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(kind.label()),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(
+                        Button::new(SharedString::from(format!("threshold-{}-down", kind.id())))
+                            .outline()
+                            .label("−")
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.adjust_threshold(kind, -THRESHOLD_STEP);
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        div()
+                            .w(super::px(44.0))
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(gpui::rgb(0xe0f2fe))
+                            .child(format!("{value:.2}")),
+                    )
+                    .child(
+                        Button::new(SharedString::from(format!("threshold-{}-up", kind.id())))
+                            .outline()
+                            .label("+")
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.adjust_threshold(kind, THRESHOLD_STEP);
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    /// Detection sensitivity is the palm score threshold turned around, so
+    /// "more" finds smaller and farther hands.
+    fn sensitivity_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let sensitivity = 1.0 - self.palm_config.score_threshold;
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("检测灵敏度", "Detection sensitivity")),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(
+                        Button::new(SharedString::from("palm-sensitivity-down"))
+                            .outline()
+                            .label("−")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.adjust_palm_sensitivity(-THRESHOLD_STEP);
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        div()
+                            .w(super::px(44.0))
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(gpui::rgb(0xe0f2fe))
+                            .child(format!("{sensitivity:.2}")),
+                    )
+                    .child(
+                        Button::new(SharedString::from("palm-sensitivity-up"))
+                            .outline()
+                            .label("+")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.adjust_palm_sensitivity(THRESHOLD_STEP);
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    /// While editing, dragging on the preview sets the zone.
+    fn roi_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let editing = self.roi_editor.editing;
+        let has_roi = self.palm_config.roi.is_some();
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(if has_roi {
+                        tr("识别区域", "Detection area")
+                    } else {
+                        tr("识别区域 (全画面)", "Detection area (whole frame)")
+                    }),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(
+                        Button::new(SharedString::from("roi-edit-toggle"))
+                            .outline()
+                            .label(if editing {
+                                tr("完成", "Done")
+                            } else {
+                                tr("框选", "Select")
+                            })
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.toggle_roi_editing();
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new(SharedString::from("roi-clear"))
+                            .outline()
+                            .label(tr("清除", "Clear"))
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.clear_roi();
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn adjust_palm_sensitivity(&mut self, delta: f32) {
+        let threshold = (self.palm_config.score_threshold - delta).clamp(MIN_PALM_SCORE, 1.0);
+        self.palm_config.score_threshold = threshold;
+        let _ = self
+            .recognizer_control_tx
+            .send(RecognizerControl::SetPalmConfig(self.palm_config));
+    }
+
+    /// The configured maximum; the controller may run below it while
+    /// inference is over budget, which the metrics line shows.
+    fn input_dimension_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let value = match self.palm_config.max_input_dimension {
+            0 => tr("原始", "Native").to_string(),
+            dimension => format!("{dimension}px"),
+        };
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("输入分辨率上限", "Input size limit")),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(
+                        Button::new(SharedString::from("input-dimension-down"))
+                            .outline()
+                            .label("−")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.step_input_dimension(-1);
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        div()
+                            .w(super::px(56.0))
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(gpui::rgb(0xe0f2fe))
+                            .child(value),
+                    )
+                    .child(
+                        Button::new(SharedString::from("input-dimension-up"))
+                            .outline()
+                            .label("+")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.step_input_dimension(1);
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn label_stabilizer_row(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let value = match self.label_stabilizer.config().time_constant_ms {
+            0 => tr("关", "Off").to_string(),
+            ms => format!("{ms}ms"),
+        };
+
+        h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x94a3b8))
+                    .child(tr("标签稳定", "Label stabilizing")),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(
+                        Button::new(SharedString::from("label-stabilizer-down"))
+                            .outline()
+                            .label("−")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.step_label_time_constant(-1);
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        div()
+                            .w(super::px(56.0))
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(gpui::rgb(0xe0f2fe))
+                            .child(value),
+                    )
+                    .child(
+                        Button::new(SharedString::from("label-stabilizer-up"))
+                            .outline()
+                            .label("+")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.step_label_time_constant(1);
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    /// The margin is only set in the settings file and is kept as is.
+    fn step_label_time_constant(&mut self, step: isize) {
+        let mut config = self.label_stabilizer.config();
+        let current = LABEL_TIME_CONSTANTS
+            .iter()
+            .position(|ms| *ms == config.time_constant_ms)
+            .unwrap_or(2);
+        let next = current
+            .saturating_add_signed(step)
+            .min(LABEL_TIME_CONSTANTS.len() - 1);
+        config.time_constant_ms = LABEL_TIME_CONSTANTS[next];
+        self.label_stabilizer.set_config(config);
+        self.stable_label = None;
+    }
+
+    fn step_input_dimension(&mut self, step: isize) {
+        let current = INPUT_DIMENSIONS
+            .iter()
+            .position(|dimension| *dimension == self.palm_config.max_input_dimension)
+            .unwrap_or(2);
+        let next = current
+            .saturating_add_signed(step)
+            .min(INPUT_DIMENSIONS.len() - 1);
+        self.palm_config.max_input_dimension = INPUT_DIMENSIONS[next];
+        let _ = self
+            .recognizer_control_tx
+            .send(RecognizerControl::SetPalmConfig(self.palm_config));
+    }
+
+    fn threshold_value(&self, kind: ThresholdKind) -> f32 {
+        match kind {
+            ThresholdKind::Detection => self.recognizer_config.detection_threshold,
+            ThresholdKind::Classification => self.recognizer_config.classification_threshold,
+            ThresholdKind::Overlay => self.recognizer_config.overlay_threshold,
+        }
+    }
+
+    pub(super) fn adjust_threshold(&mut self, kind: ThresholdKind, delta: f32) {
+        let value = (self.threshold_value(kind) + delta).clamp(0.0, 1.0);
+        let config = &mut self.recognizer_config;
+        match kind {
+            ThresholdKind::Detection => config.detection_threshold = value,
+            ThresholdKind::Classification => config.classification_threshold = value,
+            ThresholdKind::Overlay => config.overlay_threshold = value,
+        }
+        self.push_recognizer_config();
+    }
+
+    pub(super) fn push_recognizer_config(&self) {
+        let _ = self
+            .recognizer_control_tx
+            .send(RecognizerControl::UpdateConfig(self.recognizer_config));
+        self.push_compositor_settings();
+    }
+
+    pub(super) fn push_compositor_settings(&self) {
+        let frame_tap = self
+            .virtual_camera
+            .as_ref()
+            .map(VirtualCamera::frame_sender);
+        let _ = self.compositor_settings_tx.send(
+            CompositorSettings::from(&self.recognizer_config)
+                .with_skeleton_style(self.skeleton_style)
+                .with_overlay_mode(self.overlay_mode)
+                .with_output_size(self.output_size)
+                .with_censor_gestures(self.censor_gestures.clone())
+                .with_burn_in_label(self.burn_in_label, self.settings.label_font_path.clone())
+                .with_frame_tap(frame_tap)
+                .with_roi(self.palm_config.roi)
+                .with_pointing_ray(self.pointing_ray)
+                .with_privacy_mode(self.privacy_mode)
+                .with_clip_buffer(self.clip_exporter.buffer.clone())
+                .with_photo_booth(self.photo_booth.config, self.photo_booth.events())
+                .with_hold_confirm(
+                    self.action_config.hold_bindings(),
+                    self.hold_confirm_tx.clone(),
+                ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_threshold_has_a_label_in_both_locales() {
+        for kind in ThresholdKind::ALL {
+            let labels = Locale::ALL.map(|locale| kind.label_in(locale));
+            assert!(
+                labels.iter().all(|label| !label.is_empty()),
+                "{}",
+                kind.id()
+            );
+            assert!(labels[1].is_ascii(), "{}", kind.id());
+        }
+    }
+}
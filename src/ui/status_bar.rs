@@ -0,0 +1,432 @@
+use std::time::{Duration, Instant};
+
+use super::{
+    ActiveTheme, AnyElement, AppView, Button, Context, FluentBuilder, Hsla, InteractiveElement,
+    IntoElement, ParentElement, SharedString, Styled, div, h_flex, v_flex,
+};
+use crate::{
+    locale::{self, Locale, tr},
+    pipeline::{CameraStatus, describe_mode},
+};
+use gpui::{ClickEvent, StatefulInteractiveElement};
+use gpui_component::{StyledExt, tooltip::Tooltip};
+
+/// The bar is rebuilt at most this often, however fast frames come in.
+const STATUS_REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+/// Inference rates from which the rate shows green, then amber; below is red.
+const GOOD_INFERENCE_FPS: f32 = 15.0;
+const FAIR_INFERENCE_FPS: f32 = 8.0;
+/// Lost frame shares, in percent, up to which the share shows green, then
+/// amber; above is red.
+const GOOD_LOST_PERCENT: f32 = 5.0;
+const FAIR_LOST_PERCENT: f32 = 20.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Health {
+    Good,
+    Warning,
+    Error,
+    /// Nothing to judge yet, or not a health value at all.
+    Neutral,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SegmentKind {
+    Camera,
+    Models,
+    Inference,
+    LostFrames,
+    Provider,
+    Privacy,
+}
+
+impl SegmentKind {
+    fn id(self) -> &'static str {
+        match self {
+            SegmentKind::Camera => "status-camera",
+            SegmentKind::Models => "status-models",
+            SegmentKind::Inference => "status-inference",
+            SegmentKind::LostFrames => "status-lost-frames",
+            SegmentKind::Provider => "status-provider",
+            SegmentKind::Privacy => "status-privacy",
+        }
+    }
+
+    fn tooltip(self) -> &'static str {
+        match self {
+            SegmentKind::Camera => tr(
+                "画面来源，以及摄像头实际协商的分辨率和帧率。点击切换摄像头",
+                "The frame source, with the resolution and frame rate the camera actually agreed to. Click to switch cameras",
+            ),
+            SegmentKind::Models => tr(
+                "识别后端和正在使用的模型文件。点击查看诊断信息",
+                "The recognizer backend and the model files in use. Click for diagnostics",
+            ),
+            SegmentKind::Inference => tr(
+                "最近两秒识别器每秒处理完的帧数；画面静止时休眠",
+                "Frames the recognizer finished per second over the last two seconds; it sleeps while the picture is still",
+            ),
+            SegmentKind::LostFrames => tr(
+                "最近两秒采集到却没有显示出来的帧所占的比例",
+                "Share of frames captured over the last two seconds that were never shown",
+            ),
+            SegmentKind::Provider => tr(
+                "选择的执行提供程序；无法注册时会回退到 CPU",
+                "The chosen execution provider; falls back to CPU when it cannot be registered",
+            ),
+            SegmentKind::Privacy => tr(
+                "只显示骨架，不显示摄像头画面；录制、截图、导出片段和拍照都已停用。在设置中关闭",
+                "Only the skeleton is shown, never the camera picture; recording, screenshots, clip export and photos are off. Turn it off in the settings",
+            ),
+        }
+    }
+}
+
+struct Segment {
+    kind: SegmentKind,
+    text: String,
+    health: Health,
+}
+
+/// Pipeline health along the bottom of the window, and the diagnostics the
+/// model segment opens.
+#[derive(Default)]
+pub(super) struct StatusBar {
+    segments: Vec<Segment>,
+    refreshed_at: Option<Instant>,
+    diagnostics_open: bool,
+}
+
+impl AppView {
+    /// Rebuilds the segments unless they were rebuilt less than
+    /// [`STATUS_REFRESH_INTERVAL`] ago, so the text does not churn.
+    pub(super) fn refresh_status_bar(&mut self) {
+        let fresh = self
+            .status_bar
+            .refreshed_at
+            .is_some_and(|at| at.elapsed() < STATUS_REFRESH_INTERVAL);
+        if fresh {
+            return;
+        }
+        let privacy = self.privacy_mode.then(|| Segment {
+            kind: SegmentKind::Privacy,
+            text: tr("隐私模式", "Privacy mode").to_string(),
+            health: Health::Warning,
+        });
+        self.status_bar.segments = vec![
+            self.camera_segment(),
+            self.models_segment(),
+            self.inference_segment(),
+            self.lost_frames_segment(),
+            Segment {
+                kind: SegmentKind::Provider,
+                text: format!(
+                    "{} {}",
+                    tr("执行", "Provider"),
+                    self.execution_provider.display_name()
+                ),
+                health: Health::Neutral,
+            },
+        ];
+        self.status_bar.segments.extend(privacy);
+        self.status_bar.refreshed_at = Some(Instant::now());
+    }
+
+    fn camera_segment(&self) -> Segment {
+        let (text, health) = match (&self.video_file, &self.camera_status) {
+            (Some(path), _) => (
+                format!(
+                    "{} {}",
+                    tr("视频", "Video"),
+                    super::camera_view::video_label(path)
+                ),
+                Health::Good,
+            ),
+            (
+                None,
+                Some(CameraStatus::Streaming {
+                    width,
+                    height,
+                    fps,
+                    format,
+                }),
+            ) => (
+                format!(
+                    "{} {}",
+                    tr("摄像头", "Camera"),
+                    describe_mode(*width, *height, *fps, *format)
+                ),
+                Health::Good,
+            ),
+            (None, Some(CameraStatus::Reconnecting { attempt, .. })) => {
+                let text = match locale::locale() {
+                    Locale::ZhCn => format!("摄像头重连中 (第 {attempt} 次)"),
+                    Locale::En => format!("Camera reconnecting (attempt {attempt})"),
+                };
+                (text, Health::Warning)
+            }
+            (None, Some(CameraStatus::Disconnected { .. })) => (
+                tr("摄像头已断开", "Camera disconnected").to_string(),
+                Health::Error,
+            ),
+            (None, None) if self.latest_frame.is_some() => (
+                tr("摄像头已连接", "Camera connected").to_string(),
+                Health::Good,
+            ),
+            (None, None) => (
+                tr("等待摄像头", "Waiting for camera").to_string(),
+                Health::Neutral,
+            ),
+        };
+        Segment {
+            kind: SegmentKind::Camera,
+            text,
+            health,
+        }
+    }
+
+    fn models_segment(&self) -> Segment {
+        let file_name = |path: std::path::PathBuf| {
+            path.file_name().map_or_else(
+                || path.display().to_string(),
+                |name| name.to_string_lossy().into_owned(),
+            )
+        };
+        let text = format!(
+            "ONNX Runtime · {} · {}",
+            file_name(self.recognizer_backend.handpose_estimator_model_path()),
+            file_name(self.recognizer_backend.palm_detector_model_path())
+        );
+        let health = if self.recognizer_error.is_some() {
+            Health::Error
+        } else if self.paused {
+            Health::Warning
+        } else if self.pipeline.recognizer_started() {
+            Health::Good
+        } else {
+            Health::Neutral
+        };
+        Segment {
+            kind: SegmentKind::Models,
+            text,
+            health,
+        }
+    }
+
+    fn inference_segment(&self) -> Segment {
+        let fps = self.latest_metrics.inference_fps;
+        let running = self.pipeline.recognizer_started() && !self.paused;
+        let (text, health) = if !running {
+            (
+                tr("推理 -- fps", "Inference -- fps").to_string(),
+                Health::Neutral,
+            )
+        } else if self.latest_metrics.idle {
+            (
+                tr("推理 休眠", "Inference asleep").to_string(),
+                Health::Neutral,
+            )
+        } else {
+            let text = format!("{} {fps:.1} fps", tr("推理", "Inference"));
+            if fps >= GOOD_INFERENCE_FPS {
+                (text, Health::Good)
+            } else if fps >= FAIR_INFERENCE_FPS {
+                (text, Health::Warning)
+            } else {
+                (text, Health::Error)
+            }
+        };
+        Segment {
+            kind: SegmentKind::Inference,
+            text,
+            health,
+        }
+    }
+
+    fn lost_frames_segment(&self) -> Segment {
+        let metrics = &self.latest_metrics;
+        let lost = metrics.lost_percent();
+        let health = if metrics.captured_frames == 0 {
+            Health::Neutral
+        } else if lost <= GOOD_LOST_PERCENT {
+            Health::Good
+        } else if lost <= FAIR_LOST_PERCENT {
+            Health::Warning
+        } else {
+            Health::Error
+        };
+        Segment {
+            kind: SegmentKind::LostFrames,
+            text: format!("{} {lost:.0}%", tr("丢帧", "Lost")),
+            health,
+        }
+    }
+
+    pub(super) fn render_status_bar(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let theme = cx.theme();
+        let color = |health: Health| -> Hsla {
+            match health {
+                Health::Good => theme.success,
+                Health::Warning => theme.warning,
+                Health::Error => theme.danger,
+                Health::Neutral => theme.muted_foreground,
+            }
+        };
+        let colors: Vec<Hsla> = self
+            .status_bar
+            .segments
+            .iter()
+            .map(|segment| color(segment.health))
+            .collect();
+
+        let mut bar = h_flex()
+            .w_full()
+            .h(super::px(26.0))
+            .px_3()
+            .gap_4()
+            .items_center()
+            .bg(gpui::rgb(0x111827))
+            .border_t_1()
+            .border_color(gpui::rgba(0xffffff1a));
+        for (segment, color) in self.status_bar.segments.iter().zip(colors) {
+            let kind = segment.kind;
+            let clickable = matches!(kind, SegmentKind::Camera | SegmentKind::Models);
+            bar = bar.child(
+                h_flex()
+                    .id(kind.id())
+                    .gap_1()
+                    .items_center()
+                    .text_xs()
+                    .when(clickable, |this| this.cursor_pointer())
+                    .child(div().text_color(color).child("●"))
+                    .child(
+                        div()
+                            .text_color(gpui::rgb(0xcbd5e1))
+                            .whitespace_nowrap()
+                            .child(segment.text.clone()),
+                    )
+                    .tooltip(move |window, cx| Tooltip::new(kind.tooltip()).build(window, cx))
+                    .on_click(cx.listener(move |this, _: &ClickEvent, _, cx| {
+                        match kind {
+                            SegmentKind::Camera => {
+                                this.camera_picker_open = !this.available_cameras.is_empty();
+                            }
+                            SegmentKind::Models => {
+                                this.status_bar.diagnostics_open =
+                                    !this.status_bar.diagnostics_open;
+                            }
+                            _ => return,
+                        }
+                        cx.notify();
+                    })),
+            );
+        }
+
+        div()
+            .relative()
+            .w_full()
+            .child(bar)
+            .when(self.status_bar.diagnostics_open, |this| {
+                this.child(self.render_diagnostics(cx))
+            })
+            .into_any_element()
+    }
+
+    /// What the model segment opens: the files and provider in use and the
+    /// recognizer's recent trouble, above the bar.
+    fn render_diagnostics(&self, cx: &mut Context<'_, Self>) -> AnyElement {
+        let metrics = &self.latest_metrics;
+        let mut lines = vec![
+            format!(
+                "{}: {}",
+                tr("手部模型", "Hand model"),
+                self.recognizer_backend
+                    .handpose_estimator_model_path()
+                    .display()
+            ),
+            format!(
+                "{}: {}",
+                tr("手掌模型", "Palm model"),
+                self.recognizer_backend.palm_detector_model_path().display()
+            ),
+            format!(
+                "{}: {}",
+                tr("执行提供程序", "Execution provider"),
+                self.execution_provider.display_name()
+            ),
+            match locale::locale() {
+                Locale::ZhCn => format!(
+                    "推理输入: {}x{} · 手掌检测 {:.0}% 的帧",
+                    metrics.input_width,
+                    metrics.input_height,
+                    metrics.palm_detect_rate * 100.0
+                ),
+                Locale::En => format!(
+                    "Inference input: {}x{} · palm detection on {:.0}% of frames",
+                    metrics.input_width,
+                    metrics.input_height,
+                    metrics.palm_detect_rate * 100.0
+                ),
+            },
+            format!(
+                "{}: {}x{}",
+                tr("合成输出", "Composited output"),
+                metrics.output_width,
+                metrics.output_height
+            ),
+            format!(
+                "{}: {}",
+                tr("最近两秒出错的帧", "Failed frames in the last two seconds"),
+                metrics.recognizer_errors
+            ),
+        ];
+        if let Some(err) = &self.recognizer_error {
+            lines.push(format!(
+                "{}: {}",
+                tr("识别器错误", "Recognizer error"),
+                err.describe()
+            ));
+        }
+
+        let mut body = v_flex().gap_1();
+        for line in lines {
+            body = body.child(div().text_xs().text_color(gpui::rgb(0xcbd5e1)).child(line));
+        }
+
+        v_flex()
+            .absolute()
+            .bottom(super::px(30.0))
+            .left(super::px(12.0))
+            .max_w(super::px(560.0))
+            .gap_2()
+            .p_3()
+            .rounded_lg()
+            .bg(gpui::rgb(0x0f172a))
+            .border_1()
+            .border_color(gpui::rgba(0xffffff1a))
+            .child(
+                h_flex()
+                    .justify_between()
+                    .items_center()
+                    .gap_4()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(gpui::rgb(0xffffff))
+                            .child(tr("诊断信息", "Diagnostics")),
+                    )
+                    .child(
+                        Button::new(SharedString::from("diagnostics-close"))
+                            .outline()
+                            .label(tr("关闭", "Close"))
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.status_bar.diagnostics_open = false;
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .child(body)
+            .into_any_element()
+    }
+}
@@ -7,6 +7,7 @@ use super::{
 use super::SharedString;
 
 impl AppView {
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn render_titlebar(
         &self,
         recognizer_color: Hsla,
@@ -174,7 +175,7 @@ impl AppView {
     fn render_linux_controls(
         &self,
         _window: &mut Window,
-        cx: &mut Context<'_, Self>,
+        _cx: &mut Context<'_, Self>,
     ) -> AnyElement {
         let button_size = px(28.0);
         let icon_size = px(16.0);
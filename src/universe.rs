@@ -0,0 +1,291 @@
+//! Camera, recognizer and result channels wired together in one call, for
+//! apps that embed the recognizer and only want the gestures out.
+
+use std::thread;
+
+use anyhow::{Result, anyhow};
+use crossbeam_channel::{Receiver, Sender, bounded, never, unbounded};
+
+use crate::{
+    pipeline::{
+        CameraDevice, CameraOpenOptions, CameraStream, DeliveryMode, MetricsHandle,
+        RecognizerBackend, RecognizerControl, available_cameras, start_camera_stream,
+        start_recognizer,
+    },
+    types::{GestureEvent, RecognizedFrame},
+};
+
+/// Gesture events kept for a reader that has fallen behind; newer ones are
+/// dropped while it is full.
+const EVENT_QUEUE: usize = 64;
+
+/// A running camera and recognizer. Dropping it stops both.
+pub struct GestureUniverse {
+    camera: Option<CameraStream>,
+    control_tx: Option<Sender<RecognizerControl>>,
+    /// Dropped on shutdown to free a blocking worker, even when the caller
+    /// still holds a clone of the results receiver.
+    release_tx: Option<Sender<()>>,
+    results: Receiver<RecognizedFrame>,
+    events: Receiver<GestureEvent>,
+    recognizer: Option<thread::JoinHandle<()>>,
+}
+
+/// Set up by [`GestureUniverse::builder`]; anything left unset uses the
+/// first camera and the default recognizer.
+#[derive(Default)]
+pub struct GestureUniverseBuilder {
+    backend: Option<RecognizerBackend>,
+    camera: Option<CameraDevice>,
+    open_options: Option<CameraOpenOptions>,
+    metrics: Option<MetricsHandle>,
+    delivery: DeliveryMode,
+}
+
+impl GestureUniverse {
+    pub fn builder() -> GestureUniverseBuilder {
+        GestureUniverseBuilder::default()
+    }
+
+    /// Each recognized frame with its result. By default frames finished
+    /// while the last one is unread are dropped, so a slow reader skips frames
+    /// rather than falling behind; [`GestureUniverseBuilder::delivery`]
+    /// changes that. With [`DeliveryMode::Blocking`], stopping does not wait
+    /// for a clone of this receiver to be read or dropped.
+    pub fn results(&self) -> &Receiver<RecognizedFrame> {
+        &self.results
+    }
+
+    /// Debounced gesture starts, holds and ends, plus dwell, sequence and
+    /// drag events.
+    pub fn events(&self) -> &Receiver<GestureEvent> {
+        &self.events
+    }
+
+    /// Sends `control` to the recognizer, e.g. a new config or a pause.
+    pub fn send(&self, control: RecognizerControl) {
+        if let Some(control_tx) = &self.control_tx {
+            let _ = control_tx.send(control);
+        }
+    }
+
+    /// Stops the camera and waits for the recognizer to finish.
+    pub fn stop(mut self) {
+        self.shut_down();
+    }
+
+    fn shut_down(&mut self) {
+        // Stopping the camera drops the last frame sender, which ends the
+        // worker loop.
+        if let Some(camera) = self.camera.take() {
+            camera.stop();
+        }
+        self.control_tx = None;
+        // A blocking worker may be waiting on an unread result; the release
+        // lets it go, even if the caller cloned the receiver.
+        self.release_tx = None;
+        self.results = never();
+        if let Some(recognizer) = self.recognizer.take() {
+            let _ = recognizer.join();
+        }
+    }
+}
+
+impl Drop for GestureUniverse {
+    fn drop(&mut self) {
+        self.shut_down();
+    }
+}
+
+impl GestureUniverseBuilder {
+    /// Models, thresholds and taps; `RecognizerBackend::default()` with the
+    /// model paths from the environment unless set.
+    pub fn backend(mut self, backend: RecognizerBackend) -> Self {
+        self.backend = Some(backend);
+        self
+    }
+
+    pub fn camera(mut self, camera: CameraDevice) -> Self {
+        self.camera = Some(camera);
+        self
+    }
+
+    /// The capture mode to ask for first; the camera's preferred one unless
+    /// set.
+    pub fn open_options(mut self, options: CameraOpenOptions) -> Self {
+        self.open_options = Some(options);
+        self
+    }
+
+    /// Where the pipeline records its timings, to read back with
+    /// [`MetricsHandle::snapshot`].
+    pub fn metrics(mut self, metrics: MetricsHandle) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// How [`GestureUniverse::results`] is fed; [`DeliveryMode::LatestOnly`]
+    /// unless set.
+    pub fn delivery(mut self, mode: DeliveryMode) -> Self {
+        self.delivery = mode;
+        self
+    }
+
+    /// Opens the camera and starts the recognizer on it.
+    pub fn start(self) -> Result<GestureUniverse> {
+        let backend = self
+            .backend
+            .unwrap_or_else(|| RecognizerBackend::default().with_model_paths_from_env());
+        let camera = match self.camera {
+            Some(camera) => camera,
+            None => available_cameras()?
+                .into_iter()
+                .next()
+                .ok_or_else(|| anyhow!("no camera available"))?,
+        };
+        log::info!("using camera: {}", camera.label);
+        let metrics = self.metrics.unwrap_or_default();
+
+        let (frame_tx, frame_rx) = bounded(1);
+        let (control_tx, control_rx) = unbounded();
+        let (result_tx, results) = self.delivery.channel();
+        let (release_tx, release_rx) = bounded(0);
+        let (event_tx, events) = bounded(EVENT_QUEUE);
+
+        let mirror = backend.config().mirror;
+        let stream = start_camera_stream(
+            camera.index,
+            self.open_options,
+            frame_tx,
+            metrics.clone(),
+            mirror,
+        )?;
+        let recognizer = start_recognizer(
+            backend,
+            frame_rx,
+            control_rx,
+            result_tx.with_release(release_rx),
+            event_tx,
+            metrics,
+        );
+        Ok(GestureUniverse {
+            camera: Some(stream),
+            control_tx: Some(control_tx),
+            release_tx: Some(release_tx),
+            results,
+            events,
+            recognizer: Some(recognizer),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::{
+        gesture::GestureClassifier,
+        pipeline::{
+            ExecutionProvider, PalmDetectorConfig, RecognizerError,
+            recognizer::{HandposeEngine, common::HandposeOutput, run_worker_loop},
+        },
+        types::{Frame, PixelLayout},
+    };
+
+    /// Never sees a hand.
+    struct NoHands;
+
+    impl HandposeEngine for NoHands {
+        fn infer(&mut self, _: &Frame) -> Result<HandposeOutput, RecognizerError> {
+            Ok(HandposeOutput::skipped())
+        }
+
+        fn set_execution_provider(&mut self, _: ExecutionProvider) -> Result<(), RecognizerError> {
+            Ok(())
+        }
+
+        fn set_palm_config(&mut self, _: PalmDetectorConfig) {}
+
+        fn reload_models(&mut self) -> Result<(), RecognizerError> {
+            Ok(())
+        }
+    }
+
+    fn frame() -> Frame {
+        let now = Instant::now();
+        Frame {
+            pixels: vec![0; PixelLayout::Rgb.buffer_len(8, 8)].into(),
+            layout: PixelLayout::Rgb,
+            width: 8,
+            height: 8,
+            timestamp: now,
+            acquired_at: now,
+        }
+    }
+
+    /// A universe whose worker is stuck handing over a blocking result nobody
+    /// reads. It has no camera; the frames it would have sent are fed here
+    /// until the worker stops taking them.
+    fn stalled_universe() -> GestureUniverse {
+        let (frame_tx, frame_rx) = bounded(1);
+        let (control_tx, control_rx) = unbounded();
+        let (result_tx, results) = DeliveryMode::Blocking.channel();
+        let (release_tx, release_rx) = bounded(0);
+        let (event_tx, events) = bounded(EVENT_QUEUE);
+        let recognizer = thread::spawn(move || {
+            let backend = RecognizerBackend::default();
+            let classifier = GestureClassifier::rules_only(&backend.config());
+            run_worker_loop(
+                NoHands,
+                classifier,
+                &backend,
+                frame_rx,
+                control_rx,
+                result_tx.with_release(release_rx),
+                event_tx,
+                MetricsHandle::default(),
+            );
+        });
+
+        // Every frame makes a result, so the worker stops taking frames once
+        // it waits on the reader.
+        while frame_tx
+            .send_timeout(frame(), Duration::from_millis(200))
+            .is_ok()
+        {}
+        assert_eq!(results.len(), 1);
+
+        GestureUniverse {
+            camera: None,
+            control_tx: Some(control_tx),
+            release_tx: Some(release_tx),
+            results,
+            events,
+            recognizer: Some(recognizer),
+        }
+    }
+
+    fn stops_in_time(universe: GestureUniverse) -> bool {
+        let (done_tx, done_rx) = bounded(1);
+        thread::spawn(move || {
+            universe.stop();
+            let _ = done_tx.send(());
+        });
+        done_rx.recv_timeout(Duration::from_secs(2)).is_ok()
+    }
+
+    #[test]
+    fn stopping_frees_a_blocking_worker_nobody_reads_from() {
+        assert!(stops_in_time(stalled_universe()));
+    }
+
+    #[test]
+    fn stopping_frees_a_blocking_worker_while_a_results_clone_is_held() {
+        let universe = stalled_universe();
+        let results = universe.results().clone();
+        assert!(stops_in_time(universe));
+        // Only the result that fit before the worker stalled was delivered.
+        assert_eq!(results.try_iter().count(), 1);
+    }
+}
@@ -0,0 +1,234 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener, TcpStream},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender, TrySendError, bounded};
+use serde::Serialize;
+use tungstenite::Message;
+
+use crate::{
+    pipeline::{Delivery, DeliveryMode},
+    types::{
+        CompositeGesture, FingerAngles, GestureKind, GestureMotion, GestureResult, HandOrientation,
+        Handedness, PinchInfo, RotationInfo,
+    },
+};
+
+pub const DEFAULT_WS_PORT: u16 = 9002;
+/// Loopback only, so landmarks stay on this machine unless another address
+/// is asked for.
+pub const DEFAULT_WS_BIND: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// Messages a client may lag behind before it is dropped.
+const CLIENT_QUEUE: usize = 16;
+/// Results waiting for the broadcaster; past that the oldest are dropped.
+const RESULT_QUEUE: usize = 64;
+
+/// JSON payload pushed to every client, one per recognized frame.
+#[derive(Serialize)]
+struct WsGestureMessage {
+    frame_id: u64,
+    kind: Option<GestureKind>,
+    composite: Option<CompositeGesture>,
+    confidence: f32,
+    handedness: Option<Handedness>,
+    handedness_confidence: Option<f32>,
+    motion: Option<GestureMotion>,
+    pinch: Option<PinchInfo>,
+    rotation: Option<RotationInfo>,
+    orientation: Option<HandOrientation>,
+    /// Stable count of raised fingers.
+    count: Option<u8>,
+    /// Joint angles in degrees, thumb first.
+    finger_angles: Option<[FingerAngles; 5]>,
+    /// Primary hand landmarks divided by the frame size, so 0..1 on both axes.
+    landmarks: Vec<[f32; 2]>,
+    /// Primary hand landmarks in model space, z included.
+    raw_landmarks: Vec<[f32; 3]>,
+}
+
+impl WsGestureMessage {
+    fn new(result: &GestureResult) -> Self {
+        let detail = result.detail.as_ref();
+        let (width, height) = result.frame_size;
+        let (width, height) = (width.max(1) as f32, height.max(1) as f32);
+        Self {
+            frame_id: result.frame_id,
+            kind: detail.map(|d| d.primary),
+            composite: result.composite,
+            confidence: result.confidence,
+            handedness: detail.map(|d| d.handedness),
+            handedness_confidence: detail.map(|d| d.handedness_confidence),
+            motion: detail.map(|d| d.motion),
+            count: detail.and_then(|d| d.count),
+            finger_angles: detail.map(|d| d.finger_angles),
+            pinch: detail.and_then(|d| d.pinch),
+            rotation: detail.and_then(|d| d.rotation),
+            orientation: detail.and_then(|d| d.orientation),
+            landmarks: result
+                .landmarks
+                .iter()
+                .flatten()
+                .map(|(x, y)| [x / width, y / height])
+                .collect(),
+            raw_landmarks: result.raw_landmarks.clone().unwrap_or_default(),
+        }
+    }
+}
+
+type Clients = Arc<Mutex<Vec<Sender<String>>>>;
+
+/// Broadcasts gesture results to WebSocket clients until dropped.
+pub struct WsServer {
+    addr: SocketAddr,
+    result_tx: Delivery<GestureResult>,
+    stop: Arc<AtomicBool>,
+    clients: Clients,
+    handles: Vec<thread::JoinHandle<()>>,
+}
+
+impl WsServer {
+    pub fn start(bind: IpAddr, port: u16) -> Result<Self> {
+        let listener = TcpListener::bind((bind, port))
+            .with_context(|| format!("failed to listen on {bind} port {port}"))?;
+        listener.set_nonblocking(true)?;
+        let addr = listener.local_addr()?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let clients: Clients = Arc::default();
+        // The recognizer never waits on this, so a stalled broadcaster costs it
+        // nothing; a brief stall only loses results once the queue is full.
+        let (result_tx, result_rx) = DeliveryMode::Lossless {
+            capacity: RESULT_QUEUE,
+        }
+        .channel();
+
+        let accept_handle = {
+            let stop = stop.clone();
+            let clients = clients.clone();
+            thread::spawn(move || accept_loop(listener, stop, clients))
+        };
+        let broadcast_handle = {
+            let stop = stop.clone();
+            let clients = clients.clone();
+            thread::spawn(move || broadcast_loop(result_rx, stop, clients))
+        };
+
+        log::info!("websocket output listening on ws://{addr}");
+        Ok(Self {
+            addr,
+            result_tx,
+            stop,
+            clients,
+            handles: vec![accept_handle, broadcast_handle],
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Sender to hand to the recognizer as its result tap.
+    pub fn result_sender(&self) -> Delivery<GestureResult> {
+        self.result_tx.clone()
+    }
+}
+
+impl Drop for WsServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        // Dropping the queues ends every client thread.
+        self.clients
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clear();
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn accept_loop(listener: TcpListener, stop: Arc<AtomicBool>, clients: Clients) {
+    while !stop.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, peer)) => {
+                let clients = clients.clone();
+                thread::spawn(move || serve_client(stream, peer, clients));
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                thread::sleep(POLL_INTERVAL);
+            }
+            Err(err) => {
+                log::warn!("websocket accept failed: {err:?}");
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+fn serve_client(stream: TcpStream, peer: SocketAddr, clients: Clients) {
+    if let Err(err) = stream.set_nonblocking(false) {
+        log::warn!("failed to configure websocket stream for {peer}: {err:?}");
+        return;
+    }
+    let mut socket = match tungstenite::accept(stream) {
+        Ok(socket) => socket,
+        Err(err) => {
+            log::warn!("websocket handshake with {peer} failed: {err}");
+            return;
+        }
+    };
+
+    let (tx, rx) = bounded::<String>(CLIENT_QUEUE);
+    clients
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(tx);
+    log::info!("websocket client {peer} connected");
+
+    while let Ok(text) = rx.recv() {
+        if let Err(err) = socket.send(Message::text(text)) {
+            log::info!("websocket client {peer} disconnected: {err}");
+            return;
+        }
+    }
+    let _ = socket.close(None);
+}
+
+fn broadcast_loop(result_rx: Receiver<GestureResult>, stop: Arc<AtomicBool>, clients: Clients) {
+    while !stop.load(Ordering::Relaxed) {
+        let result = match result_rx.recv_timeout(POLL_INTERVAL) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => break,
+        };
+
+        let text = match serde_json::to_string(&WsGestureMessage::new(&result)) {
+            Ok(text) => text,
+            Err(err) => {
+                log::warn!("failed to encode websocket message: {err:?}");
+                continue;
+            }
+        };
+
+        let mut clients = clients
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        clients.retain(|client| match client.try_send(text.clone()) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_)) => {
+                log::warn!("dropping slow websocket client");
+                false
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        });
+    }
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use gesture_universe::{
+    analytics::GestureAnalytics,
+    types::{GestureEvent, GestureKind, GestureResult},
+};
+
+fn held(kind: GestureKind, millis: u64) -> GestureEvent {
+    GestureEvent::Held {
+        kind,
+        duration: Duration::from_millis(millis),
+    }
+}
+
+/// A frame with one hand showing `kind`, or no hand at all for `None`.
+fn frame(kind: Option<GestureKind>, confidence: f32) -> GestureResult {
+    let mut result = common::result(kind, Instant::now());
+    result.confidence = confidence;
+    for hand in &mut result.hands {
+        hand.confidence = confidence;
+    }
+    result
+}
+
+#[test]
+fn holds_count_once_and_add_up_their_longest_duration() {
+    let mut analytics = GestureAnalytics::default();
+    for event in [
+        GestureEvent::Started(GestureKind::Fist),
+        held(GestureKind::Fist, 500),
+        held(GestureKind::Fist, 1200),
+        GestureEvent::Ended(GestureKind::Fist),
+        GestureEvent::Started(GestureKind::Palm),
+        held(GestureKind::Palm, 300),
+        GestureEvent::SequenceMatched("wave".into()),
+        // A new start closes the palm hold even without an Ended.
+        GestureEvent::Started(GestureKind::Fist),
+        held(GestureKind::Fist, 2000),
+    ] {
+        analytics.observe_event(&event);
+    }
+
+    let summary = analytics.summary();
+    let rows: Vec<(GestureKind, u32, f64)> = summary
+        .gestures
+        .iter()
+        .map(|row| (row.kind, row.count, row.held_secs))
+        .collect();
+    // The second fist is still held and counts up to its last Held event.
+    assert_eq!(
+        rows,
+        [(GestureKind::Fist, 2, 3.2), (GestureKind::Palm, 1, 0.3)]
+    );
+}
+
+#[test]
+fn frames_give_efficiency_and_average_confidence() {
+    let mut analytics = GestureAnalytics::default();
+    assert_eq!(analytics.summary().efficiency, None);
+
+    analytics.observe_frame(&frame(Some(GestureKind::Peace), 0.9));
+    analytics.observe_frame(&frame(Some(GestureKind::Peace), 0.7));
+    analytics.observe_frame(&frame(None, 0.0));
+    analytics.observe_frame(&frame(None, 0.0));
+
+    let summary = analytics.summary();
+    assert_eq!(summary.frames, 4);
+    assert_eq!(summary.detected_frames, 2);
+    assert_eq!(summary.efficiency, Some(0.5));
+    let peace = &summary.gestures[0];
+    assert_eq!(peace.kind, GestureKind::Peace);
+    // Seen on frames but never debounced into a start.
+    assert_eq!(peace.count, 0);
+    assert!((peace.average_confidence.unwrap() - 0.8).abs() < 1e-6);
+}
+
+#[test]
+fn reset_starts_the_session_over() {
+    let mut analytics = GestureAnalytics::default();
+    analytics.observe_event(&GestureEvent::Started(GestureKind::Like));
+    analytics.observe_frame(&frame(Some(GestureKind::Like), 0.8));
+    analytics.reset();
+
+    let summary = analytics.summary();
+    assert_eq!(summary.frames, 0);
+    assert!(summary.gestures.is_empty());
+
+    // A Held left over from before the reset does not revive the old count.
+    analytics.observe_event(&held(GestureKind::Like, 400));
+    analytics.observe_event(&GestureEvent::Ended(GestureKind::Like));
+    let summary = analytics.summary();
+    assert_eq!(summary.gestures[0].count, 0);
+    assert_eq!(summary.gestures[0].held_secs, 0.4);
+}
+
+#[test]
+fn csv_has_one_row_per_gesture() {
+    let mut analytics = GestureAnalytics::default();
+    analytics.observe_event(&GestureEvent::Started(GestureKind::Fist));
+    analytics.observe_event(&held(GestureKind::Fist, 1500));
+    analytics.observe_frame(&frame(Some(GestureKind::Fist), 0.75));
+
+    let csv = analytics.summary().to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            "# frames=1 detected_frames=1 efficiency=1.0000",
+            "kind,count,held_secs,average_confidence",
+            "fist,1,1.500,0.7500",
+        ]
+    );
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "async")]
+
+use std::time::Duration;
+
+use crossbeam_channel::{RecvTimeoutError, bounded, unbounded};
+use gesture_universe::{
+    RecognizerBackend,
+    pipeline::{MetricsHandle, RecognizerStatus},
+    start_recognizer_async,
+};
+
+/// A recognizer whose models are missing, so its worker parks waiting for a
+/// retry and reports through `status`.
+fn parked_backend(status: crossbeam_channel::Sender<RecognizerStatus>) -> RecognizerBackend {
+    RecognizerBackend::default()
+        .with_handpose_estimator_model_path("missing-handpose.onnx".into())
+        .with_palm_detector_model_path("missing-palm.onnx".into())
+        .with_status_tap(status)
+}
+
+/// Waits for the worker to drop its status sender, which it does on exit.
+fn worker_stopped(status: &crossbeam_channel::Receiver<RecognizerStatus>) -> bool {
+    loop {
+        match status.recv_timeout(Duration::from_secs(5)) {
+            Ok(_) => {}
+            Err(RecvTimeoutError::Disconnected) => return true,
+            Err(RecvTimeoutError::Timeout) => return false,
+        }
+    }
+}
+
+#[test]
+fn dropping_both_receivers_stops_the_worker() {
+    let (status_tx, status_rx) = unbounded();
+    // Held for the whole test, so only the dropped receivers can end it.
+    let (_frame_tx, frame_rx) = bounded(1);
+    let (_control_tx, control_rx) = unbounded();
+    let gestures = start_recognizer_async(
+        parked_backend(status_tx),
+        frame_rx,
+        control_rx,
+        MetricsHandle::default(),
+    );
+    assert!(matches!(
+        status_rx.recv_timeout(Duration::from_secs(5)),
+        Ok(RecognizerStatus::Failed(_))
+    ));
+
+    drop(gestures);
+    assert!(worker_stopped(&status_rx));
+}
+
+#[test]
+fn a_resubscribed_receiver_keeps_the_worker_running() {
+    let (status_tx, status_rx) = unbounded();
+    let (_frame_tx, frame_rx) = bounded(1);
+    let (_control_tx, control_rx) = unbounded();
+    let gestures = start_recognizer_async(
+        parked_backend(status_tx),
+        frame_rx,
+        control_rx,
+        MetricsHandle::default(),
+    );
+    let events = gestures.events.resubscribe();
+    drop(gestures);
+    assert!(matches!(
+        status_rx.recv_timeout(Duration::from_secs(1)),
+        Ok(RecognizerStatus::Failed(_))
+    ));
+    assert!(matches!(
+        status_rx.recv_timeout(Duration::from_millis(500)),
+        Err(RecvTimeoutError::Timeout)
+    ));
+
+    drop(events);
+    assert!(worker_stopped(&status_rx));
+}
+
+#[tokio::test]
+async fn results_close_once_the_frame_source_ends() {
+    let (status_tx, _status_rx) = unbounded();
+    let (frame_tx, frame_rx) = bounded(1);
+    let (_control_tx, control_rx) = unbounded();
+    let mut gestures = start_recognizer_async(
+        parked_backend(status_tx),
+        frame_rx,
+        control_rx,
+        MetricsHandle::default(),
+    );
+    drop(frame_tx);
+    let changed = tokio::time::timeout(Duration::from_secs(5), gestures.results.changed()).await;
+    assert!(matches!(changed, Ok(Err(_))), "results still open");
+    assert!(gestures.results.borrow().is_none());
+}
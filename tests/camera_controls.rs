@@ -0,0 +1,43 @@
+use gesture_universe::pipeline::{
+    CameraControlInfo, CameraKnob, camera::HAND_TARGET_LUMA, exposure_for_luma,
+};
+
+fn exposure(value: i64) -> CameraControlInfo {
+    CameraControlInfo {
+        knob: CameraKnob::Exposure,
+        min: 3,
+        max: 2047,
+        step: 4,
+        default: 250,
+        value,
+    }
+}
+
+#[test]
+fn clamp_snaps_to_the_step_from_the_minimum() {
+    let info = exposure(250);
+    assert_eq!(info.clamp(3), 3);
+    assert_eq!(info.clamp(8), 7);
+    assert_eq!(info.clamp(9), 11);
+    assert_eq!(info.clamp(-100), 3);
+    assert_eq!(info.clamp(10_000), 2047);
+}
+
+#[test]
+fn dark_hands_get_a_longer_exposure() {
+    let info = exposure(400);
+    let value = exposure_for_luma(&info, HAND_TARGET_LUMA / 2.0);
+    assert_eq!(value, 799);
+}
+
+#[test]
+fn hands_at_the_target_keep_their_exposure() {
+    let info = exposure(399);
+    assert_eq!(exposure_for_luma(&info, HAND_TARGET_LUMA), 399);
+}
+
+#[test]
+fn black_or_blown_out_hands_stay_in_range() {
+    assert_eq!(exposure_for_luma(&exposure(1000), 0.0), 2047);
+    assert_eq!(exposure_for_luma(&exposure(7), 255.0), 3);
+}
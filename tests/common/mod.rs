@@ -0,0 +1,36 @@
+//! Synthetic results shared by the integration tests.
+
+use std::time::Instant;
+
+use gesture_universe::types::{GestureDetail, GestureKind, GestureResult, HandResult};
+
+/// A 640×480 frame at `at` with one steady right hand showing `kind`, its
+/// landmarks all in the middle, or no hand at all for `None`. Tests adjust
+/// the fields they care about from there.
+pub fn result(kind: Option<GestureKind>, at: Instant) -> GestureResult {
+    let empty = GestureResult {
+        confidence: 0.9,
+        timestamp: at,
+        frame_size: (640, 480),
+        ..GestureResult::default()
+    };
+    let Some(kind) = kind else {
+        return empty;
+    };
+    let landmarks = vec![(320.0, 240.0); 21];
+    let detail = GestureDetail::new(kind);
+    GestureResult {
+        track_id: Some(1),
+        landmarks: Some(landmarks.clone()),
+        detail: Some(detail.clone()),
+        hands: vec![HandResult {
+            track_id: 1,
+            confidence: empty.confidence,
+            landmarks,
+            raw_landmarks: Vec::new(),
+            handedness_score: None,
+            detail: Some(detail),
+        }],
+        ..empty
+    }
+}
@@ -0,0 +1,139 @@
+use std::time::{Duration, Instant};
+
+use gesture_universe::{
+    pipeline::{
+        ClipBuffer, CompositedFrame, CompositorSettings, MetricsHandle, OutputSize,
+        compositor::PRIVACY_BACKGROUND, start_frame_compositor,
+    },
+    types::{Frame, GestureResult, HandResult, PalmRegion, PixelLayout, RecognizedFrame},
+};
+
+const WIDTH: u32 = 320;
+const HEIGHT: u32 = 240;
+/// Not a color the overlay draws with.
+const CAMERA_PIXEL: [u8; 3] = [201, 7, 99];
+
+#[test]
+fn native_keeps_the_camera_size() {
+    assert_eq!(OutputSize::Native.fit((3840, 2160)), (3840, 2160));
+}
+
+#[test]
+fn bigger_frames_fit_inside_keeping_their_aspect() {
+    assert_eq!(OutputSize::Fit720p.fit((3840, 2160)), (1280, 720));
+    assert_eq!(OutputSize::Fit1080p.fit((2560, 1440)), (1920, 1080));
+    // Portrait and 4:3 frames are bounded by the height.
+    assert_eq!(OutputSize::Fit720p.fit((720, 1280)), (405, 720));
+    assert_eq!(OutputSize::Fit480p.fit((1280, 960)), (640, 480));
+}
+
+#[test]
+fn smaller_frames_are_not_upscaled() {
+    assert_eq!(OutputSize::Fit1080p.fit((1280, 720)), (1280, 720));
+    assert_eq!(OutputSize::Fit480p.fit((640, 480)), (640, 480));
+}
+
+#[test]
+fn cycling_visits_every_size() {
+    let mut size = OutputSize::Native;
+    for expected in OutputSize::ALL.into_iter().skip(1) {
+        size = size.next();
+        assert_eq!(size, expected);
+    }
+    assert_eq!(size.next(), OutputSize::Native);
+}
+
+/// A camera frame of nothing but [`CAMERA_PIXEL`], with a hand and its palm
+/// box in the middle for the overlay to draw.
+fn recognized() -> RecognizedFrame {
+    let now = Instant::now();
+    let landmarks: Vec<(f32, f32)> = (0..21)
+        .map(|i| (100.0 + (i % 5) as f32 * 30.0, 200.0 - (i / 5) as f32 * 30.0))
+        .collect();
+    let hand = HandResult {
+        track_id: 1,
+        confidence: 0.9,
+        landmarks: landmarks.clone(),
+        raw_landmarks: Vec::new(),
+        handedness_score: None,
+        detail: None,
+    };
+    RecognizedFrame {
+        frame: Frame {
+            pixels: CAMERA_PIXEL.repeat((WIDTH * HEIGHT) as usize).into(),
+            layout: PixelLayout::Rgb,
+            width: WIDTH,
+            height: HEIGHT,
+            timestamp: now,
+            acquired_at: now,
+        },
+        result: GestureResult {
+            confidence: 0.9,
+            timestamp: now,
+            frame_size: (WIDTH, HEIGHT),
+            track_id: Some(1),
+            landmarks: Some(landmarks),
+            palm_regions: vec![PalmRegion {
+                bbox: [80.0, 60.0, 240.0, 220.0],
+                landmarks: Vec::new(),
+                score: 0.9,
+            }],
+            hands: vec![hand],
+            ..GestureResult::default()
+        },
+    }
+}
+
+fn composite(settings: CompositorSettings) -> CompositedFrame {
+    let (recognized_tx, recognized_rx) = crossbeam_channel::bounded(1);
+    let (_settings_tx, settings_rx) = crossbeam_channel::unbounded();
+    let (composited_rx, handle) = start_frame_compositor(
+        recognized_rx,
+        settings,
+        settings_rx,
+        MetricsHandle::default(),
+    );
+    recognized_tx.send(recognized()).unwrap();
+    let composited = composited_rx
+        .recv_timeout(Duration::from_secs(5))
+        .expect("a composited frame");
+    drop(recognized_tx);
+    handle.join().unwrap();
+    composited
+}
+
+#[test]
+fn privacy_mode_leaves_nothing_of_the_camera_image() {
+    let clips = ClipBuffer::default();
+    let composited = composite(
+        CompositorSettings::default()
+            .with_clip_buffer(clips.clone())
+            .with_privacy_mode(true),
+    );
+    assert!(composited.blanked);
+    let frame = &composited.frame;
+    assert_eq!((frame.width, frame.height), (WIDTH, HEIGHT));
+    assert_eq!(frame.pixels.len(), (WIDTH * HEIGHT * 3) as usize);
+    let pixels: Vec<&[u8]> = frame.pixels.chunks_exact(3).collect();
+    assert!(pixels.iter().all(|pixel| *pixel != CAMERA_PIXEL));
+    // The overlay is still drawn, onto the background.
+    assert!(pixels.contains(&&PRIVACY_BACKGROUND[..]));
+    assert!(pixels.iter().any(|pixel| *pixel != PRIVACY_BACKGROUND));
+    assert!(clips.snapshot().is_empty());
+}
+
+#[test]
+fn without_privacy_mode_the_camera_image_comes_through() {
+    let clips = ClipBuffer::default();
+    let composited = composite(CompositorSettings::default().with_clip_buffer(clips.clone()));
+    assert!(!composited.blanked);
+    let frame = &composited.frame;
+    assert_eq!(&frame.pixels[..3], CAMERA_PIXEL);
+    assert!(
+        frame
+            .pixels
+            .chunks_exact(3)
+            .all(|pixel| pixel != PRIVACY_BACKGROUND)
+    );
+    assert_eq!(clips.snapshot().len(), 1);
+}
@@ -0,0 +1,103 @@
+use std::f32::consts::PI;
+
+use gesture_universe::pipeline::recognizer::crop_smoothing::CropSmoother;
+
+type Crop = ((f32, f32), f32, f32);
+
+/// Deterministic noise in `[-1, 1]`.
+fn noise(seed: &mut u32) -> f32 {
+    *seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+    (*seed >> 8) as f32 / (1u32 << 23) as f32 - 1.0
+}
+
+/// A still hand seen through a jittery detector: a few pixels of centre and
+/// size noise, and an angle wobbling across ±π.
+fn jittery(frames: usize) -> Vec<Crop> {
+    let mut seed = 7;
+    (0..frames)
+        .map(|_| {
+            let center = (
+                320.0 + 4.0 * noise(&mut seed),
+                240.0 + 4.0 * noise(&mut seed),
+            );
+            let side = 200.0 + 6.0 * noise(&mut seed);
+            let angle = PI - 0.01 + 0.05 * noise(&mut seed);
+            let angle = if angle >= PI { angle - 2.0 * PI } else { angle };
+            (center, side, angle)
+        })
+        .collect()
+}
+
+fn smooth_all(crops: &[Crop]) -> Vec<Crop> {
+    let mut smoother = CropSmoother::default();
+    crops.iter().map(|&crop| smoother.smooth(1, crop)).collect()
+}
+
+fn variance(values: impl Iterator<Item = f32> + Clone) -> f32 {
+    let count = values.clone().count() as f32;
+    let mean = values.clone().sum::<f32>() / count;
+    values.map(|value| (value - mean).powi(2)).sum::<f32>() / count
+}
+
+/// Distance from π either way round, so angles on both sides of the wrap
+/// line up.
+fn from_pi(angle: f32) -> f32 {
+    if angle < 0.0 { angle + PI } else { angle - PI }
+}
+
+#[test]
+fn jitter_is_damped_on_every_parameter() {
+    let raw = jittery(200);
+    let smoothed = smooth_all(&raw);
+
+    let parameters: [fn(&Crop) -> f32; 4] = [
+        |crop| crop.0.0,
+        |crop| crop.0.1,
+        |crop| crop.1,
+        |crop| from_pi(crop.2),
+    ];
+    for (index, parameter) in parameters.iter().enumerate() {
+        let before = variance(raw.iter().map(parameter));
+        let after = variance(smoothed.iter().map(parameter));
+        assert!(
+            after < before * 0.6,
+            "parameter {index}: {after} vs {before}"
+        );
+    }
+}
+
+#[test]
+fn blending_across_the_wrap_stays_near_pi() {
+    let mut smoother = CropSmoother::default();
+    smoother.smooth(1, ((320.0, 240.0), 200.0, PI - 0.05));
+    let (_, _, angle) = smoother.smooth(1, ((320.0, 240.0), 200.0, -PI + 0.05));
+    // 0.6 of the 0.1 rad step round through π, not 6 rad back through zero.
+    assert!((from_pi(angle) - 0.01).abs() < 1e-4, "angle {angle}");
+}
+
+#[test]
+fn a_real_jump_is_followed_on_the_same_frame() {
+    let mut crops = jittery(30);
+    let moved = ((620.0, 180.0), 210.0, 0.3);
+    crops.push(moved);
+    let smoothed = smooth_all(&crops);
+    assert_eq!(*smoothed.last().unwrap(), moved);
+}
+
+#[test]
+fn tracks_are_smoothed_apart_and_forgotten() {
+    let mut smoother = CropSmoother::default();
+    let left = ((200.0, 240.0), 150.0, 0.0);
+    let right = ((440.0, 240.0), 150.0, 0.0);
+    smoother.smooth(1, left);
+    smoother.smooth(2, right);
+
+    let nudged = ((210.0, 240.0), 150.0, 0.0);
+    let ((x, y), _, _) = smoother.smooth(1, nudged);
+    assert!((x - 206.0).abs() < 1e-3 && y == 240.0, "({x}, {y})");
+    assert_eq!(smoother.smooth(2, right), right);
+
+    smoother.retain(|id| id != 1);
+    let nudged_again = ((220.0, 240.0), 150.0, 0.0);
+    assert_eq!(smoother.smooth(1, nudged_again), nudged_again);
+}
@@ -0,0 +1,190 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use gesture_universe::{
+    actions::cursor::{Corner, CursorCalibration, CursorCommand, CursorConfig, CursorController},
+    types::{GestureKind, GestureResult, PinchInfo},
+};
+
+const FRAME: Duration = Duration::from_millis(50);
+/// The size of the frames [`common::result`] makes.
+const FRAME_SIZE: (u32, u32) = (640, 480);
+
+/// The primary hand showing `kind` with its index fingertip at `tip`, as
+/// fractions of the frame.
+fn frame(kind: GestureKind, tip: (f32, f32), pinched: bool, at: Instant) -> GestureResult {
+    let mut result = common::result(Some(kind), at);
+    if let Some(landmarks) = &mut result.landmarks {
+        landmarks[8] = (tip.0 * FRAME_SIZE.0 as f32, tip.1 * FRAME_SIZE.1 as f32);
+    }
+    if let Some(detail) = &mut result.detail {
+        detail.pinch = Some(PinchInfo {
+            distance: if pinched { 0.1 } else { 0.8 },
+            delta: 0.0,
+            closed: pinched,
+        });
+    }
+    result
+}
+
+fn unsmoothed() -> CursorConfig {
+    CursorConfig {
+        smoothing: 1.0,
+        ..CursorConfig::default()
+    }
+}
+
+fn moves(commands: &[CursorCommand]) -> Vec<(f32, f32)> {
+    commands
+        .iter()
+        .filter_map(|command| match command {
+            CursorCommand::MoveTo(x, y) => Some((*x, *y)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn close(a: (f32, f32), b: (f32, f32)) -> bool {
+    (a.0 - b.0).abs() < 1e-4 && (a.1 - b.1).abs() < 1e-4
+}
+
+#[test]
+fn the_mapping_rectangle_spans_the_screen_and_gain_works_about_its_middle() {
+    let config = CursorConfig::default();
+    assert!(close(config.map((0.2, 0.2)), (0.0, 0.0)));
+    assert!(close(config.map((0.5, 0.65)), (0.5, 0.75)));
+    assert!(close(config.map((0.95, 0.1)), (1.0, 0.0)));
+
+    let faster = CursorConfig {
+        gain: 2.0,
+        ..config
+    };
+    assert!(close(faster.map((0.5, 0.5)), (0.5, 0.5)));
+    assert!(close(faster.map((0.35, 0.575)), (0.0, 0.75)));
+}
+
+#[test]
+fn the_cursor_follows_a_pointing_finger_outside_the_dead_zone() {
+    let mut cursor = CursorController::new(unsmoothed());
+    let at = Instant::now();
+    assert!(
+        cursor
+            .observe(&frame(GestureKind::Point, (0.5, 0.5), false, at))
+            .is_empty()
+    );
+
+    cursor.set_active(true);
+    let first = cursor.observe(&frame(GestureKind::Point, (0.5, 0.5), false, at));
+    assert_eq!(first, vec![CursorCommand::MoveTo(0.5, 0.5)]);
+    // A tremble smaller than the dead zone.
+    let still = cursor.observe(&frame(GestureKind::Point, (0.5005, 0.5), false, at + FRAME));
+    assert!(still.is_empty(), "{still:?}");
+    let moved = cursor.observe(&frame(GestureKind::One, (0.6, 0.5), false, at + FRAME * 2));
+    assert_eq!(moves(&moved).len(), 1);
+    assert!(close(moves(&moved)[0], (2.0 / 3.0, 0.5)));
+
+    // An open hand does not steer.
+    let palm = cursor.observe(&frame(GestureKind::Palm, (0.3, 0.3), false, at + FRAME * 3));
+    assert!(palm.is_empty(), "{palm:?}");
+}
+
+#[test]
+fn a_pinch_presses_and_drags_until_it_opens() {
+    let mut cursor = CursorController::new(unsmoothed());
+    cursor.set_active(true);
+    let at = Instant::now();
+    // Pinching before pointing anywhere does not click.
+    let unaimed = cursor.observe(&frame(GestureKind::Ok, (0.5, 0.5), true, at));
+    assert!(!unaimed.contains(&CursorCommand::Press));
+    cursor.observe(&frame(GestureKind::Ok, (0.5, 0.5), false, at + FRAME));
+
+    cursor.observe(&frame(
+        GestureKind::Point,
+        (0.5, 0.5),
+        false,
+        at + FRAME * 2,
+    ));
+    let down = cursor.observe(&frame(GestureKind::Ok, (0.5, 0.5), true, at + FRAME * 3));
+    assert_eq!(down, vec![CursorCommand::Press]);
+    let dragged = cursor.observe(&frame(GestureKind::Ok, (0.7, 0.5), true, at + FRAME * 4));
+    assert_eq!(moves(&dragged).len(), 1);
+    assert!(!dragged.contains(&CursorCommand::Release));
+    let up = cursor.observe(&frame(GestureKind::Ok, (0.7, 0.5), false, at + FRAME * 5));
+    assert!(up.contains(&CursorCommand::Release));
+
+    // Turning the mode off mid-drag lets go of the button.
+    cursor.observe(&frame(GestureKind::Ok, (0.7, 0.5), true, at + FRAME * 6));
+    assert_eq!(cursor.set_active(false), vec![CursorCommand::Release]);
+}
+
+#[test]
+fn holding_the_panic_gesture_turns_the_mode_off_and_back_on() {
+    let config = unsmoothed();
+    let hold = config.panic_hold;
+    let mut cursor = CursorController::new(config);
+    cursor.set_active(true);
+    let mut at = Instant::now();
+    cursor.observe(&frame(GestureKind::Point, (0.5, 0.5), false, at));
+    cursor.observe(&frame(GestureKind::Ok, (0.5, 0.5), true, at + FRAME));
+
+    let end = at + hold + FRAME * 3;
+    let mut released = false;
+    while at < end {
+        at += FRAME;
+        let commands = cursor.observe(&frame(GestureKind::Fist, (0.5, 0.5), false, at));
+        released |= commands.contains(&CursorCommand::Release);
+    }
+    assert!(released);
+    assert!(!cursor.is_active());
+
+    // Opening the hand lets the hold fire again.
+    at += FRAME;
+    cursor.observe(&frame(GestureKind::Palm, (0.5, 0.5), false, at));
+    let end = at + hold + FRAME * 3;
+    while at < end {
+        at += FRAME;
+        cursor.observe(&frame(GestureKind::Fist, (0.5, 0.5), false, at));
+    }
+    assert!(cursor.is_active());
+}
+
+/// Aims at `tip`, then pinches and opens.
+fn take_corner(
+    calibration: &mut CursorCalibration,
+    tip: (f32, f32),
+    at: &mut Instant,
+) -> Option<[f32; 4]> {
+    calibration.observe(&frame(GestureKind::Point, tip, false, *at));
+    // Pinching pulls the fingertip towards the thumb.
+    let pulled = (tip.0 + 0.05, tip.1 + 0.05);
+    let mapping = calibration.observe(&frame(GestureKind::Ok, pulled, true, *at + FRAME));
+    calibration.observe(&frame(GestureKind::Palm, pulled, false, *at + FRAME * 2));
+    *at += FRAME * 3;
+    mapping
+}
+
+#[test]
+fn calibration_takes_the_four_corners_where_the_finger_aimed() {
+    let mut calibration = CursorCalibration::new();
+    let mut at = Instant::now();
+    let corners = [(0.3, 0.25), (0.72, 0.27), (0.7, 0.77), (0.32, 0.75)];
+    let mut mapping = None;
+    for (expected, tip) in Corner::ALL.into_iter().zip(corners) {
+        assert_eq!(calibration.corner(), expected);
+        mapping = take_corner(&mut calibration, tip, &mut at);
+    }
+    let [x1, y1, x2, y2] = mapping.expect("four corners give a mapping");
+    assert!((x1 - 0.31).abs() < 1e-4 && (y1 - 0.26).abs() < 1e-4);
+    assert!((x2 - 0.71).abs() < 1e-4 && (y2 - 0.76).abs() < 1e-4);
+}
+
+#[test]
+fn calibration_starts_over_when_the_corners_are_bunched_up() {
+    let mut calibration = CursorCalibration::new();
+    let mut at = Instant::now();
+    for _ in Corner::ALL {
+        assert_eq!(take_corner(&mut calibration, (0.5, 0.5), &mut at), None);
+    }
+    assert_eq!(calibration.corner(), Corner::TopLeft);
+}
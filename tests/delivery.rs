@@ -0,0 +1,121 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+
+use crossbeam_channel::bounded;
+use gesture_universe::pipeline::{Delivery, DeliveryMode};
+
+#[test]
+fn a_full_lossless_queue_drops_the_oldest() {
+    let (tx, rx) = DeliveryMode::Lossless { capacity: 3 }.channel();
+    let delivered: Vec<bool> = (1..=5).map(|n| tx.deliver(n)).collect();
+    assert_eq!(delivered, [true, true, true, false, false]);
+    assert_eq!(rx.try_iter().collect::<Vec<_>>(), [3, 4, 5]);
+    assert_eq!(tx.dropped(), 2);
+
+    // Emptied, it takes new ones again without dropping any.
+    assert!(tx.deliver(6));
+    assert_eq!(rx.try_recv(), Ok(6));
+    assert_eq!(tx.dropped(), 2);
+}
+
+#[test]
+fn clones_share_the_dropped_count() {
+    let (tx, _rx) = DeliveryMode::Lossless { capacity: 1 }.channel();
+    let clone = tx.clone();
+    tx.deliver(1);
+    clone.deliver(2);
+    tx.deliver(3);
+    assert_eq!(tx.dropped(), 2);
+    assert_eq!(clone.dropped(), 2);
+}
+
+#[test]
+fn latest_only_keeps_the_unread_result() {
+    let (tx, rx) = bounded(1);
+    let tx = Delivery::from(tx);
+    assert!(tx.deliver(1));
+    assert!(!tx.deliver(2));
+    assert_eq!(rx.try_iter().collect::<Vec<_>>(), [1]);
+    assert_eq!(tx.dropped(), 1);
+
+    let (tx, rx) = DeliveryMode::LatestOnly.channel();
+    tx.deliver(1);
+    tx.deliver(2);
+    assert_eq!(rx.try_iter().collect::<Vec<_>>(), [1]);
+}
+
+#[test]
+fn blocking_waits_for_the_reader() {
+    let (tx, rx) = DeliveryMode::Blocking.channel();
+    let sent = Arc::new(AtomicUsize::new(0));
+    let sender = {
+        let sent = sent.clone();
+        thread::spawn(move || {
+            for n in 0..4 {
+                assert!(tx.deliver(n));
+                sent.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+    };
+    // One result fits; the second holds the sender up until it is read.
+    thread::sleep(Duration::from_millis(200));
+    assert_eq!(sent.load(Ordering::SeqCst), 1);
+
+    let received: Vec<_> = rx.iter().take(4).collect();
+    sender.join().unwrap();
+    assert_eq!(received, [0, 1, 2, 3]);
+    assert_eq!(sent.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn dropping_the_reader_releases_a_blocked_sender() {
+    let (tx, rx) = DeliveryMode::Blocking.channel();
+    let sender = thread::spawn(move || (tx.deliver(1), tx.deliver(2), tx.dropped()));
+    thread::sleep(Duration::from_millis(100));
+    drop(rx);
+
+    let (first, second, dropped) = sender.join().unwrap();
+    assert!(first);
+    assert!(!second);
+    // Nothing is dropped by a blocking sender; the reader went away instead.
+    assert_eq!(dropped, 0);
+}
+
+#[test]
+fn the_release_frees_a_sender_whose_reader_is_still_held() {
+    let (release_tx, release_rx) = bounded::<()>(0);
+    let (tx, rx) = DeliveryMode::Blocking.channel();
+    let tx = tx.with_release(release_rx);
+    let sender = thread::spawn(move || (tx.deliver(1), tx.deliver(2)));
+    thread::sleep(Duration::from_millis(100));
+    drop(release_tx);
+
+    assert_eq!(sender.join().unwrap(), (true, false));
+    assert_eq!(rx.try_iter().collect::<Vec<_>>(), [1]);
+}
+
+#[test]
+fn a_sender_reports_the_mode_it_was_made_with() {
+    for mode in [
+        DeliveryMode::LatestOnly,
+        DeliveryMode::Lossless { capacity: 4 },
+        DeliveryMode::Blocking,
+    ] {
+        let (tx, _rx) = mode.channel::<u8>();
+        assert_eq!(tx.mode(), mode);
+    }
+    let (tx, _rx) = bounded::<u8>(1);
+    let tx = Delivery::from(tx);
+    tx.deliver(1);
+    tx.deliver(2);
+    assert_eq!(
+        format!("{tx:?}"),
+        "Delivery { mode: LatestOnly, dropped: 1 }"
+    );
+}
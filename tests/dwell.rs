@@ -0,0 +1,119 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use gesture_universe::{
+    gesture::DwellDetector,
+    types::{DwellConfig, DwellProgress, GestureKind, GestureMotion, GestureResult},
+};
+
+use common::result;
+
+const FRAME: Duration = Duration::from_millis(50);
+
+fn palm(at: Instant) -> GestureResult {
+    result(Some(GestureKind::Palm), at)
+}
+
+fn moving(at: Instant) -> GestureResult {
+    let mut result = palm(at);
+    result.detail.as_mut().unwrap().motion = GestureMotion::Moving;
+    result
+}
+
+fn fist(at: Instant) -> GestureResult {
+    result(Some(GestureKind::Fist), at)
+}
+
+/// Feeds `frame` for `length` at the frame rate starting at `*at`, and returns
+/// the progress of the last frame and how many frames completed the dwell.
+fn feed(
+    dwell: &mut DwellDetector,
+    frame: fn(Instant) -> GestureResult,
+    at: &mut Instant,
+    length: Duration,
+) -> (Option<DwellProgress>, usize) {
+    let end = *at + length;
+    let mut last = None;
+    let mut completed = 0;
+    while *at < end {
+        last = dwell.observe(&frame(*at));
+        completed += usize::from(last.is_some_and(|progress| progress.completed));
+        *at += FRAME;
+    }
+    (last, completed)
+}
+
+#[test]
+fn a_steady_palm_completes_once_at_the_hold_time() {
+    let config = DwellConfig::default();
+    let mut dwell = DwellDetector::new(config);
+    let mut at = Instant::now();
+    let (progress, completed) = feed(&mut dwell, palm, &mut at, config.hold() / 2);
+    let progress = progress.unwrap();
+    assert!((progress.progress - 0.47).abs() < 0.01, "{progress:?}");
+    assert_eq!(progress.wrist, (320.0, 240.0));
+    assert_eq!(completed, 0);
+
+    let (progress, completed) = feed(&mut dwell, palm, &mut at, config.hold());
+    assert_eq!(progress.unwrap().progress, 1.0);
+    assert_eq!(completed, 1);
+}
+
+#[test]
+fn jitter_within_the_grace_period_keeps_the_timer() {
+    let config = DwellConfig::default();
+    let mut dwell = DwellDetector::new(config);
+    let mut at = Instant::now();
+    feed(&mut dwell, palm, &mut at, config.hold() / 2);
+    let (progress, _) = feed(&mut dwell, moving, &mut at, config.grace() - FRAME);
+    let progress = progress.expect("a short lapse keeps the dwell");
+    assert!(progress.progress > 0.5, "{progress:?}");
+
+    // The timer ran on through the jitter rather than starting over.
+    let (_, completed) = feed(&mut dwell, palm, &mut at, config.hold() / 2);
+    assert_eq!(completed, 1);
+}
+
+#[test]
+fn moving_past_the_grace_period_restarts_the_timer() {
+    let config = DwellConfig::default();
+    let mut dwell = DwellDetector::new(config);
+    let mut at = Instant::now();
+    feed(&mut dwell, palm, &mut at, config.hold() * 4 / 5);
+    let (progress, _) = feed(&mut dwell, moving, &mut at, config.grace() * 2);
+    assert_eq!(progress, None);
+
+    let (progress, completed) = feed(&mut dwell, palm, &mut at, config.hold() / 2);
+    assert!(progress.unwrap().progress < 0.5);
+    assert_eq!(completed, 0);
+}
+
+#[test]
+fn switching_gesture_restarts_the_timer() {
+    let config = DwellConfig::default();
+    let mut dwell = DwellDetector::new(config);
+    let mut at = Instant::now();
+    feed(&mut dwell, palm, &mut at, config.hold() * 4 / 5);
+    // A one-frame misread is forgiven like any other jitter.
+    assert!(dwell.observe(&fist(at)).is_some());
+    at += FRAME;
+    feed(&mut dwell, palm, &mut at, FRAME);
+
+    let (progress, _) = feed(&mut dwell, fist, &mut at, config.grace() * 2);
+    assert_eq!(progress, None);
+    let (_, completed) = feed(&mut dwell, palm, &mut at, config.hold() / 2);
+    assert_eq!(completed, 0);
+}
+
+#[test]
+fn a_wrist_outside_the_target_never_starts() {
+    let mut dwell = DwellDetector::new(DwellConfig {
+        target: [0.6, 0.0, 1.0, 0.4],
+        ..DwellConfig::default()
+    });
+    let mut at = Instant::now();
+    let (progress, completed) = feed(&mut dwell, palm, &mut at, Duration::from_secs(3));
+    assert_eq!(progress, None);
+    assert_eq!(completed, 0);
+}
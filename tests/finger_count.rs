@@ -0,0 +1,43 @@
+use gesture_universe::types::{
+    FingerState::{self, Extended as E, Folded as F, HalfBent as H},
+    GestureDetail, GestureKind,
+};
+
+fn count(states: [FingerState; 5], count_half_bent: bool) -> u8 {
+    let mut detail = GestureDetail::new(GestureKind::Unknown);
+    detail.finger_states = states;
+    detail.finger_count(count_half_bent)
+}
+
+#[test]
+fn every_count_from_a_fist_to_an_open_hand() {
+    let hands = [
+        [F, F, F, F, F],
+        [F, E, F, F, F],
+        [F, E, E, F, F],
+        [F, E, E, E, F],
+        [F, E, E, E, E],
+        [E, E, E, E, E],
+    ];
+    for (expected, states) in hands.into_iter().enumerate() {
+        assert_eq!(count(states, false), expected as u8, "{states:?}");
+        assert_eq!(count(states, true), expected as u8, "{states:?}");
+    }
+}
+
+#[test]
+fn half_bent_fingers_count_only_when_asked_to() {
+    let states = [F, E, H, H, F];
+    assert_eq!(count(states, false), 1);
+    assert_eq!(count(states, true), 3);
+}
+
+#[test]
+fn only_a_thumb_held_out_counts() {
+    // A thumb lying against the palm is not raised, even counting half bent.
+    assert_eq!(count([H, E, E, F, F], true), 2);
+    assert_eq!(count([F, E, E, F, F], true), 2);
+    assert_eq!(count([E, E, E, F, F], false), 3);
+    // Thumbs up is one.
+    assert_eq!(count([E, F, F, F, F], false), 1);
+}
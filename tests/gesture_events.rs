@@ -0,0 +1,96 @@
+use std::time::{Duration, Instant};
+
+use gesture_universe::{
+    gesture::{GestureClassifier, GestureEventTracker},
+    types::{GestureEvent, GestureKind, GestureMotion, RecognizerConfig},
+};
+
+const FRAME: Duration = Duration::from_millis(33);
+
+fn tracker() -> GestureEventTracker {
+    let config = RecognizerConfig::default();
+    GestureEventTracker::new(GestureClassifier::rules_only(&config), &config)
+}
+
+/// Victory on even frames and unrecognized on odd ones.
+fn flicker(frame: u32) -> GestureKind {
+    if frame.is_multiple_of(2) {
+        GestureKind::Peace
+    } else {
+        GestureKind::Unknown
+    }
+}
+
+#[test]
+fn a_flickering_victory_never_starts() {
+    let mut tracker = tracker();
+    let mut at = Instant::now();
+    let mut events = Vec::new();
+    for frame in 0..30 {
+        events.extend(tracker.observe(Some(flicker(frame)), at));
+        at += FRAME;
+    }
+    assert!(events.is_empty(), "{events:?}");
+}
+
+#[test]
+fn a_held_victory_survives_single_frame_dropouts_and_ends_once() {
+    let config = RecognizerConfig::default();
+    let mut tracker = tracker();
+    let mut at = Instant::now();
+    let mut events = Vec::new();
+    for _ in 0..config.event_start_frames {
+        events.extend(tracker.observe(Some(GestureKind::Peace), at));
+        at += FRAME;
+    }
+    assert_eq!(events, vec![GestureEvent::Started(GestureKind::Peace)]);
+
+    events.clear();
+    for frame in 0..30 {
+        events.extend(tracker.observe(Some(flicker(frame)), at));
+        at += FRAME;
+    }
+    // Neither started again nor ended, only held.
+    assert!(
+        events
+            .iter()
+            .all(|event| matches!(event, GestureEvent::Held { .. })),
+        "{events:?}"
+    );
+
+    events.clear();
+    for _ in 0..config.event_end_frames * 2 {
+        events.extend(tracker.observe(None, at));
+        at += FRAME;
+    }
+    assert_eq!(events, vec![GestureEvent::Ended(GestureKind::Peace)]);
+}
+
+#[test]
+fn a_swipe_comes_as_one_motion_event_of_the_held_gesture() {
+    let config = RecognizerConfig::default();
+    let mut tracker = tracker();
+    let mut at = Instant::now();
+    assert_eq!(tracker.motion_event(GestureMotion::SwipeLeft), None);
+    for _ in 0..config.event_start_frames {
+        tracker.observe(Some(GestureKind::Palm), at);
+        at += FRAME;
+    }
+    assert_eq!(tracker.motion_event(GestureMotion::Steady), None);
+
+    // The swipe blurs the hand, so this frame is not recognized.
+    tracker.observe(None, at);
+    assert_eq!(
+        tracker.motion_event(GestureMotion::SwipeLeft),
+        Some(GestureEvent::Motion {
+            kind: GestureKind::Palm,
+            motion: GestureMotion::SwipeLeft,
+        })
+    );
+
+    for _ in 0..config.event_end_frames {
+        at += FRAME;
+        tracker.observe(None, at);
+    }
+    assert_eq!(tracker.motion_event(GestureMotion::SwipeLeft), None);
+}
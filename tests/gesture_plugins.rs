@@ -0,0 +1,158 @@
+use std::{
+    path::Path,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Instant,
+};
+
+use gesture_universe::{
+    gesture::{
+        GestureClassifier,
+        plugin::{GestureContext, GesturePlugin, builtin_plugins},
+    },
+    trace::{TraceFrame, read_jsonl},
+    types::{CustomGesture, GestureKind, RecognizerConfig},
+};
+
+const SHAKA: CustomGesture = CustomGesture {
+    id: "test_shaka",
+    display_name: "沙卡",
+    emoji: "🤙 ",
+};
+
+const BUILT_IN: [&str; 6] = [
+    "crossed_fingers",
+    "finger_gun",
+    "rock_or_i_love_you",
+    "thumb_direction",
+    "ok_ring",
+    "rule_templates",
+];
+
+/// Reports the same gesture for every hand and counts how often it was asked.
+struct Always {
+    name: &'static str,
+    kind: GestureKind,
+    calls: Arc<AtomicUsize>,
+}
+
+impl Always {
+    fn new(name: &'static str, kind: GestureKind) -> Self {
+        Self {
+            name,
+            kind,
+            calls: Arc::default(),
+        }
+    }
+}
+
+impl GesturePlugin for Always {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn detect(&mut self, _: &GestureContext<'_>) -> Option<(GestureKind, f32)> {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        Some((self.kind, 0.9))
+    }
+
+    fn custom_gestures(&self) -> &[CustomGesture] {
+        if self.kind == SHAKA.kind() {
+            &[SHAKA]
+        } else {
+            &[]
+        }
+    }
+}
+
+fn first_fist_frame() -> TraceFrame {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/fist_to_palm.jsonl");
+    let frames: Vec<TraceFrame> = read_jsonl(&path).unwrap();
+    frames.into_iter().next().expect("trace has frames")
+}
+
+fn primary(classifier: &mut GestureClassifier) -> GestureKind {
+    let frame = first_fist_frame();
+    classifier
+        .classify(
+            1,
+            &frame.raw_landmarks,
+            &frame.projected_landmarks,
+            frame.confidence,
+            frame.handedness,
+            Instant::now(),
+        )
+        .expect("hand is confident enough to classify")
+        .primary
+}
+
+#[test]
+fn built_in_plugins_come_in_a_fixed_order() {
+    let names: Vec<String> = builtin_plugins()
+        .iter()
+        .map(|plugin| plugin.name().to_string())
+        .collect();
+    assert_eq!(names, BUILT_IN);
+
+    let classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+    assert_eq!(classifier.plugin_names(), BUILT_IN);
+}
+
+#[test]
+fn registered_plugins_run_in_order_ahead_of_the_built_in_ones() {
+    let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+    assert_eq!(primary(&mut classifier), GestureKind::Fist);
+
+    classifier.register_plugin(Box::new(Always::new("first", GestureKind::Palm)));
+    classifier.register_plugin(Box::new(Always::new("second", GestureKind::Peace)));
+    assert_eq!(
+        classifier.plugin_names()[..3],
+        ["first", "second", "crossed_fingers"]
+    );
+    assert_eq!(primary(&mut classifier), GestureKind::Palm);
+}
+
+#[test]
+fn a_disabled_hit_makes_way_for_the_next_plugin() {
+    let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+    let first = Always::new("first", GestureKind::Palm);
+    let calls = first.calls.clone();
+    classifier.register_plugin(Box::new(first));
+    classifier.register_plugin(Box::new(Always::new("second", GestureKind::Peace)));
+    classifier.set_enabled_gestures(
+        GestureKind::ALL
+            .into_iter()
+            .filter(|kind| *kind != GestureKind::Palm)
+            .collect(),
+    );
+    assert_eq!(primary(&mut classifier), GestureKind::Peace);
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn set_plugins_replaces_the_built_in_ones() {
+    let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+    classifier.set_plugins(vec![Box::new(Always::new("only", GestureKind::Like))]);
+    assert_eq!(classifier.plugin_names(), ["only"]);
+    assert_eq!(primary(&mut classifier), GestureKind::Like);
+}
+
+#[test]
+fn custom_gestures_get_their_name_and_survive_a_round_trip() {
+    let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+    classifier.register_plugin(Box::new(Always::new("shaka", SHAKA.kind())));
+    let kind = primary(&mut classifier);
+    assert_eq!(kind, SHAKA.kind());
+    assert_eq!(kind.display_name(), "沙卡");
+    assert_eq!(kind.emoji(), "🤙 ");
+
+    let json = serde_json::to_string(&kind).unwrap();
+    assert_eq!(json, "\"test_shaka\"");
+    assert_eq!(serde_json::from_str::<GestureKind>(&json).unwrap(), kind);
+    assert_eq!(
+        serde_json::from_str::<GestureKind>("\"fist\"").unwrap(),
+        GestureKind::Fist
+    );
+}
@@ -0,0 +1,102 @@
+use gesture_universe::{
+    gesture::hand_orientation,
+    types::{HandOrientation, Handedness},
+};
+
+/// Crop landmarks of a right hand held up with its palm to the camera, then
+/// turned `yaw_deg` about the vertical axis through the wrist (positive turns
+/// the thumb side away) and `roll_deg` in the image plane. Only the wrist and
+/// the index and little knuckles are placed; everything else sits on the
+/// wrist. As the camera sees it, a right palm has its thumb on the right.
+fn right_hand(yaw_deg: f32, roll_deg: f32) -> Vec<[f32; 3]> {
+    let (yaw, roll) = (yaw_deg.to_radians(), roll_deg.to_radians());
+    let place = |x: f32, y: f32| {
+        // Image y grows downwards and smaller z is nearer the camera.
+        let (x, z) = (x * yaw.cos(), x * yaw.sin());
+        let (x, y) = (
+            x * roll.cos() - y * roll.sin(),
+            x * roll.sin() + y * roll.cos(),
+        );
+        [200.0 + x, 300.0 + y, z]
+    };
+    let mut points = vec![place(0.0, 0.0); 21];
+    points[5] = place(30.0, -80.0);
+    points[17] = place(-30.0, -70.0);
+    points
+}
+
+/// The same hand as a left one: reflected left to right.
+fn left_hand(yaw_deg: f32, roll_deg: f32) -> Vec<[f32; 3]> {
+    right_hand(yaw_deg, roll_deg)
+        .into_iter()
+        .map(|[x, y, z]| [400.0 - x, y, z])
+        .collect()
+}
+
+#[test]
+fn a_right_hand_faces_the_way_its_palm_is_turned() {
+    let cases = [
+        (0.0, HandOrientation::PalmToward),
+        (45.0, HandOrientation::PalmToward),
+        (-45.0, HandOrientation::PalmToward),
+        (80.0, HandOrientation::Side),
+        (-90.0, HandOrientation::Side),
+        (135.0, HandOrientation::BackToward),
+        (180.0, HandOrientation::BackToward),
+    ];
+    for (yaw, expected) in cases {
+        let orientation = hand_orientation(&right_hand(yaw, 0.0), Handedness::Right);
+        assert_eq!(orientation, Some(expected), "yaw {yaw}°");
+    }
+}
+
+#[test]
+fn turning_the_hand_in_the_image_plane_changes_nothing() {
+    for roll in [-150.0, -90.0, 30.0, 90.0, 180.0] {
+        assert_eq!(
+            hand_orientation(&right_hand(0.0, roll), Handedness::Right),
+            Some(HandOrientation::PalmToward),
+            "roll {roll}°"
+        );
+        assert_eq!(
+            hand_orientation(&right_hand(180.0, roll), Handedness::Right),
+            Some(HandOrientation::BackToward),
+            "roll {roll}°"
+        );
+    }
+}
+
+#[test]
+fn handedness_flips_the_normal() {
+    // The reflection alone turns the normal round, so a left palm reads as
+    // the back of a right hand and the other way round.
+    assert_eq!(
+        hand_orientation(&left_hand(0.0, 0.0), Handedness::Left),
+        Some(HandOrientation::PalmToward)
+    );
+    assert_eq!(
+        hand_orientation(&left_hand(0.0, 0.0), Handedness::Right),
+        Some(HandOrientation::BackToward)
+    );
+    assert_eq!(
+        hand_orientation(&left_hand(180.0, 0.0), Handedness::Left),
+        Some(HandOrientation::BackToward)
+    );
+    assert_eq!(
+        hand_orientation(&left_hand(90.0, 0.0), Handedness::Left),
+        Some(HandOrientation::Side)
+    );
+}
+
+#[test]
+fn an_unknown_side_or_a_flat_palm_gives_none() {
+    assert_eq!(
+        hand_orientation(&right_hand(0.0, 0.0), Handedness::Unknown),
+        None
+    );
+    assert_eq!(
+        hand_orientation(&[[1.0, 2.0, 3.0]; 21], Handedness::Right),
+        None
+    );
+    assert_eq!(hand_orientation(&[[0.0; 3]; 4], Handedness::Right), None);
+}
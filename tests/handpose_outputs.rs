@@ -0,0 +1,96 @@
+use gesture_universe::pipeline::recognizer::common::{HandposeOutputMap, HandposeOutputNames};
+
+const LANDMARK_VALUES: Option<usize> = Some(63);
+const SCALAR: Option<usize> = Some(1);
+
+fn map(
+    landmarks: usize,
+    confidence: Option<usize>,
+    handedness: Option<usize>,
+) -> HandposeOutputMap {
+    HandposeOutputMap {
+        landmarks,
+        confidence,
+        handedness,
+    }
+}
+
+#[test]
+fn names_pick_the_outputs_in_either_order() {
+    let names = HandposeOutputNames::default();
+    let mediapipe = [
+        ("Identity", LANDMARK_VALUES),
+        ("Identity_1", SCALAR),
+        ("Identity_2", SCALAR),
+    ];
+    assert_eq!(
+        HandposeOutputMap::resolve(&names, &mediapipe).unwrap(),
+        map(0, Some(1), Some(2))
+    );
+
+    let score_first = [
+        ("Identity_1", SCALAR),
+        ("Identity_2", SCALAR),
+        ("Identity", LANDMARK_VALUES),
+    ];
+    assert_eq!(
+        HandposeOutputMap::resolve(&names, &score_first).unwrap(),
+        map(2, Some(0), Some(1))
+    );
+}
+
+#[test]
+fn configured_names_win_over_the_session_order() {
+    let names = HandposeOutputNames {
+        landmarks: "xyz".to_string(),
+        confidence: "presence".to_string(),
+        handedness: "side".to_string(),
+    };
+    // Both scalars are listed the other way round from the shape fallback.
+    let outputs = [
+        ("side", SCALAR),
+        ("presence", SCALAR),
+        ("xyz", LANDMARK_VALUES),
+    ];
+    assert_eq!(
+        HandposeOutputMap::resolve(&names, &outputs).unwrap(),
+        map(2, Some(1), Some(0))
+    );
+}
+
+#[test]
+fn unknown_names_fall_back_to_shapes() {
+    let names = HandposeOutputNames::default();
+    let score_first = [
+        ("score", SCALAR),
+        ("handedness", SCALAR),
+        ("landmarks", LANDMARK_VALUES),
+    ];
+    assert_eq!(
+        HandposeOutputMap::resolve(&names, &score_first).unwrap(),
+        map(2, Some(0), Some(1))
+    );
+
+    // A world landmark output of the same size comes after the screen one.
+    let with_world = [
+        ("landmarks", LANDMARK_VALUES),
+        ("score", SCALAR),
+        ("world", LANDMARK_VALUES),
+    ];
+    assert_eq!(
+        HandposeOutputMap::resolve(&names, &with_world).unwrap(),
+        map(0, Some(1), None)
+    );
+}
+
+#[test]
+fn a_model_without_landmarks_is_an_error() {
+    let names = HandposeOutputNames::default();
+    let outputs = [
+        ("score", SCALAR),
+        ("heatmap", Some(64 * 64)),
+        ("dynamic", None),
+    ];
+    let err = HandposeOutputMap::resolve(&names, &outputs).unwrap_err();
+    assert!(err.to_string().contains("63 values"), "{err}");
+}
@@ -0,0 +1,131 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use gesture_universe::{
+    actions::{ActionBinding, ActionConfig, Confirm},
+    gesture::HoldConfirm,
+    types::{GestureKind, GestureMotion},
+};
+
+use common::result;
+
+const HOLD: Duration = Duration::from_millis(1_000);
+const FRAME: Duration = Duration::from_millis(50);
+
+/// Feeds `kind` for `length` at the frame rate starting at `*at`, and counts
+/// the completed holds.
+fn feed(
+    hold: &mut HoldConfirm,
+    kind: Option<GestureKind>,
+    at: &mut Instant,
+    length: Duration,
+) -> usize {
+    let end = *at + length;
+    let mut completed = 0;
+    while *at < end {
+        let progress = hold.observe(&result(kind, *at));
+        completed += usize::from(progress.is_some_and(|progress| progress.completed));
+        *at += FRAME;
+    }
+    completed
+}
+
+#[test]
+fn releasing_before_the_hold_time_does_not_fire() {
+    let mut hold = HoldConfirm::new(GestureKind::Fist, HOLD);
+    let mut at = Instant::now();
+    assert_eq!(
+        feed(&mut hold, Some(GestureKind::Fist), &mut at, HOLD * 4 / 5),
+        0
+    );
+    let progress = hold.observe(&result(Some(GestureKind::Fist), at)).unwrap();
+    assert!((progress.progress - 0.8).abs() < 1e-3);
+    assert_eq!(progress.wrist, (320.0, 240.0));
+
+    // The hand opens, and an open palm is not a lapse to forgive.
+    assert!(
+        hold.observe(&result(Some(GestureKind::Palm), at + FRAME))
+            .is_none()
+    );
+    at += FRAME * 2;
+    assert_eq!(feed(&mut hold, Some(GestureKind::Palm), &mut at, HOLD), 0);
+}
+
+#[test]
+fn losing_the_hand_at_eighty_percent_does_not_fire() {
+    let mut hold = HoldConfirm::new(GestureKind::Fist, HOLD);
+    let mut at = Instant::now();
+    assert_eq!(
+        feed(&mut hold, Some(GestureKind::Fist), &mut at, HOLD * 4 / 5),
+        0
+    );
+    assert_eq!(feed(&mut hold, None, &mut at, HOLD), 0);
+    // Coming back starts over rather than finishing the old hold.
+    assert_eq!(
+        feed(&mut hold, Some(GestureKind::Fist), &mut at, HOLD / 2),
+        0
+    );
+}
+
+#[test]
+fn a_short_unknown_blip_still_fires() {
+    let mut hold = HoldConfirm::new(GestureKind::Fist, HOLD);
+    let mut at = Instant::now();
+    let mut completed = feed(&mut hold, Some(GestureKind::Fist), &mut at, HOLD / 2);
+    let blip = Duration::from_millis(150);
+    completed += feed(&mut hold, Some(GestureKind::Unknown), &mut at, blip);
+    completed += feed(&mut hold, Some(GestureKind::Fist), &mut at, HOLD);
+    assert_eq!(completed, 1);
+}
+
+#[test]
+fn a_completed_hold_fires_again_only_after_a_release() {
+    let mut hold = HoldConfirm::new(GestureKind::Fist, HOLD);
+    let mut at = Instant::now();
+    assert_eq!(
+        feed(&mut hold, Some(GestureKind::Fist), &mut at, HOLD * 3),
+        1
+    );
+    assert_eq!(
+        feed(&mut hold, None, &mut at, Duration::from_millis(300)),
+        0
+    );
+    assert_eq!(
+        feed(&mut hold, Some(GestureKind::Fist), &mut at, HOLD * 2),
+        1
+    );
+}
+
+#[test]
+fn confirm_is_stored_in_milliseconds_and_only_plain_bindings_wait() {
+    let json = r#"{"gesture":"fist","keys":{"key":"m"},"confirm":{"hold_for":1500}}"#;
+    let binding: ActionBinding = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        binding.confirm,
+        Confirm::HoldFor(Duration::from_millis(1_500))
+    );
+    assert_eq!(
+        serde_json::to_value(binding.confirm).unwrap(),
+        serde_json::json!({"hold_for": 1500})
+    );
+
+    let plain: ActionBinding =
+        serde_json::from_str(r#"{"gesture":"fist","keys":{"key":"m"}}"#).unwrap();
+    assert_eq!(plain.confirm, Confirm::Immediate);
+
+    let mut swipe = binding.clone();
+    swipe.motion = Some(GestureMotion::SwipeLeft);
+    let mut config = ActionConfig {
+        enabled: true,
+        bindings: vec![plain, binding, swipe],
+        mouse_drag: false,
+        cursor: Default::default(),
+    };
+    assert_eq!(
+        config.hold_bindings(),
+        [(1, GestureKind::Fist, Duration::from_millis(1_500))]
+    );
+    config.enabled = false;
+    assert!(config.hold_bindings().is_empty());
+}
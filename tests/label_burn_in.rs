@@ -0,0 +1,60 @@
+use std::path::{Path, PathBuf};
+
+use gesture_universe::pipeline::skeleton::LabelPainter;
+use image::RgbaImage;
+
+const SIDE: u32 = 64;
+
+fn testdata(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join(name)
+}
+
+/// `lines` burned into a mid-grey 64×64 frame with the checked-in font, so the
+/// pixels do not depend on the fonts installed.
+fn burned(lines: &[&str]) -> RgbaImage {
+    let mut painter = LabelPainter::load(Some(&testdata("fonts/Tuffy.ttf"))).unwrap();
+    let mut pixels = RgbaImage::from_pixel(SIDE, SIDE, image::Rgba([90, 120, 150, 255])).into_raw();
+    let lines: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    painter.draw(&mut pixels, SIDE, SIDE, &lines);
+    RgbaImage::from_raw(SIDE, SIDE, pixels).unwrap()
+}
+
+#[test]
+fn the_label_matches_the_golden_patch() {
+    let patch = burned(&["OK 92%", "Swipe"]);
+    let golden = testdata("label_patch.png");
+    // Regenerate after an intended change to the painter.
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        patch.save(&golden).unwrap();
+    }
+    let expected = image::open(&golden).unwrap().to_rgba8();
+    let differing = patch
+        .pixels()
+        .zip(expected.pixels())
+        .filter(|(seen, expected)| {
+            seen.0
+                .iter()
+                .zip(expected.0)
+                .any(|(seen, expected)| seen.abs_diff(expected) > 2)
+        })
+        .count();
+    // Allow for small differences in glyph antialiasing between platforms.
+    assert!(
+        differing <= 8,
+        "{differing} pixels differ from {}",
+        golden.display()
+    );
+}
+
+#[test]
+fn characters_missing_from_the_font_are_left_out() {
+    // The test font has no CJK or emoji glyphs.
+    assert_eq!(burned(&["OK 好 👌"]), burned(&["OK  "]));
+}
+
+#[test]
+fn blank_text_draws_nothing() {
+    assert_eq!(burned(&["好"]), burned(&[]));
+}
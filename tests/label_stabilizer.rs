@@ -0,0 +1,113 @@
+use std::time::{Duration, Instant};
+
+use gesture_universe::{
+    gesture::{LabelStabilizer, StableLabel},
+    types::{GestureKind, LabelStabilizerConfig},
+};
+
+use GestureKind::{Fist, Peace, Three};
+
+const FRAME: Duration = Duration::from_millis(33);
+
+/// Feeds one frame per entry at 30 fps and returns the label after each.
+fn show(
+    stabilizer: &mut LabelStabilizer,
+    frames: impl IntoIterator<Item = (Option<GestureKind>, f32)>,
+) -> Vec<Option<StableLabel>> {
+    let start = Instant::now();
+    frames
+        .into_iter()
+        .zip(0u32..)
+        .map(|((kind, confidence), idx)| stabilizer.observe(kind, confidence, start + FRAME * idx))
+        .collect()
+}
+
+/// Three out of every ten frames read as Three and the rest as Peace, in a
+/// fixed pseudo-random order, with the confidence wobbling as well.
+fn borderline_victory(frames: usize) -> Vec<(Option<GestureKind>, f32)> {
+    let mut state = 0x2545_f491_u32;
+    (0..frames)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let kind = if state % 10 < 3 { Three } else { Peace };
+            let confidence = 0.6 + (state >> 8) as f32 % 40.0 / 100.0;
+            (Some(kind), confidence)
+        })
+        .collect()
+}
+
+#[test]
+fn a_noisy_mix_settles_on_the_majority_and_never_flashes_the_minority() {
+    let mut stabilizer = LabelStabilizer::new(LabelStabilizerConfig::default());
+    let frames = borderline_victory(300);
+    let threes = frames
+        .iter()
+        .filter(|(kind, _)| *kind == Some(Three))
+        .count();
+    assert!((75..=105).contains(&threes), "{threes} of 300");
+
+    let shown = show(&mut stabilizer, frames);
+    assert!(
+        shown.iter().flatten().all(|label| label.kind == Peace),
+        "{shown:?}"
+    );
+    let settled = shown.iter().position(Option::is_some).unwrap();
+    assert!(settled < 15, "settled at frame {settled}");
+    assert!(shown[settled..].iter().all(Option::is_some));
+}
+
+#[test]
+fn a_real_change_replaces_the_label_after_an_uncertain_spell() {
+    let mut stabilizer = LabelStabilizer::new(LabelStabilizerConfig::default());
+    let frames = [(Some(Fist), 0.9); 30]
+        .into_iter()
+        .chain([(Some(Peace), 0.9); 30]);
+    let shown = show(&mut stabilizer, frames);
+
+    let label = |idx: usize| shown[idx].unwrap();
+    let fist = |uncertain| StableLabel {
+        kind: Fist,
+        uncertain,
+    };
+    assert_eq!(label(29), fist(false));
+    let switched = (30..60).find(|&idx| label(idx).kind == Peace).unwrap();
+    assert!(switched < 45, "switched at frame {switched}");
+    // Until then the old label stays up, marked uncertain once the new
+    // gesture has caught up with it.
+    assert_eq!(label(switched - 1), fist(true));
+    assert!((30..switched).all(|idx| label(idx).kind == Fist));
+    assert_eq!(
+        label(59),
+        StableLabel {
+            kind: Peace,
+            uncertain: false
+        }
+    );
+}
+
+#[test]
+fn a_lost_hand_clears_the_label_once_its_votes_decay() {
+    let mut stabilizer = LabelStabilizer::new(LabelStabilizerConfig::default());
+    let frames = [(Some(Peace), 0.9); 20]
+        .into_iter()
+        .chain([(None, 0.0); 60]);
+    let shown = show(&mut stabilizer, frames);
+    assert_eq!(shown[21].map(|label| label.kind), Some(Peace));
+    assert_eq!(shown[79], None);
+}
+
+#[test]
+fn a_zero_time_constant_shows_every_frame() {
+    let mut stabilizer = LabelStabilizer::new(LabelStabilizerConfig {
+        time_constant_ms: 0,
+        ..LabelStabilizerConfig::default()
+    });
+    let shown = show(
+        &mut stabilizer,
+        [(Some(Peace), 0.9), (Some(Three), 0.4), (None, 0.0)],
+    );
+    let kinds: Vec<Option<GestureKind>> = shown.iter().map(|l| l.map(|l| l.kind)).collect();
+    assert_eq!(kinds, [Some(Peace), Some(Three), None]);
+}
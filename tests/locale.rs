@@ -0,0 +1,134 @@
+mod common;
+
+use std::time::Instant;
+
+use gesture_universe::{
+    actions::cursor::Corner,
+    config::OverlayCorner,
+    locale::{Locale, on_off, set_locale},
+    model_download::ModelKind,
+    pipeline::{CameraKnob, ExecutionProvider, OutputSize, skeleton::OverlayMode},
+    types::{CompositeGesture, FingerState, FrameQuality, GestureKind, GestureMotion, Handedness},
+};
+
+#[test]
+fn every_gesture_has_a_name_in_both_locales() {
+    for kind in GestureKind::ALL {
+        let names = Locale::ALL.map(|locale| kind.display_name_in(locale));
+        assert!(names.iter().all(|name| !name.is_empty()), "{kind:?}");
+        assert!(names[1].is_ascii(), "{kind:?} English name {:?}", names[1]);
+    }
+}
+
+#[test]
+fn every_label_has_text_in_both_locales() {
+    for locale in Locale::ALL {
+        let mut labels = Vec::new();
+        labels.extend(
+            [
+                FingerState::Extended,
+                FingerState::HalfBent,
+                FingerState::Folded,
+            ]
+            .map(|state| state.label_in(locale)),
+        );
+        labels.extend(
+            [Handedness::Left, Handedness::Right, Handedness::Unknown]
+                .map(|hand| hand.label_in(locale)),
+        );
+        labels.extend(GestureMotion::ALL.map(|motion| motion.label_in(locale)));
+        labels.extend(
+            [
+                CompositeGesture::Clap,
+                CompositeGesture::Heart,
+                CompositeGesture::Frame,
+            ]
+            .map(|composite| composite.display_name_in(locale)),
+        );
+        labels.extend(
+            [
+                FrameQuality::Good,
+                FrameQuality::TooDark,
+                FrameQuality::WashedOut,
+                FrameQuality::Idle,
+            ]
+            .map(|quality| quality.label_in(locale)),
+        );
+        assert!(labels.iter().all(|label| !label.is_empty()), "{locale:?}");
+    }
+}
+
+#[test]
+fn every_setting_has_a_label_in_both_locales() {
+    for locale in Locale::ALL {
+        let mut labels = Vec::new();
+        labels.extend(OverlayCorner::ALL.map(|corner| corner.label_in(locale)));
+        labels.extend(OverlayMode::ALL.map(|mode| mode.label_in(locale)));
+        labels.extend(Corner::ALL.map(|corner| corner.label_in(locale)));
+        labels.extend(CameraKnob::ALL.map(|knob| knob.label_in(locale)));
+        labels.extend(ExecutionProvider::ALL.map(|provider| provider.display_name_in(locale)));
+        labels.extend(
+            [
+                ModelKind::HandposeEstimator,
+                ModelKind::PalmDetector,
+                ModelKind::GestureClassifier,
+            ]
+            .map(|model| model.display_name_in(locale)),
+        );
+        assert!(labels.iter().all(|label| !label.is_empty()), "{locale:?}");
+        if locale == Locale::En {
+            assert!(labels.iter().all(|label| label.is_ascii()), "{labels:?}");
+        }
+    }
+}
+
+#[test]
+fn provider_labels_still_parse_back() {
+    // The label is what settings persist, so only the display name may change.
+    for provider in ExecutionProvider::ALL {
+        assert_eq!(ExecutionProvider::parse(provider.label()), Some(provider));
+    }
+}
+
+#[test]
+fn language_tags_pick_chinese_only_for_zh() {
+    assert_eq!(Locale::from_language_tag("zh_CN.UTF-8"), Locale::ZhCn);
+    assert_eq!(Locale::from_language_tag("zh_TW"), Locale::ZhCn);
+    assert_eq!(Locale::from_language_tag("ZH"), Locale::ZhCn);
+    assert_eq!(Locale::from_language_tag("en_US.UTF-8"), Locale::En);
+    assert_eq!(Locale::from_language_tag("de_DE"), Locale::En);
+}
+
+#[test]
+fn the_picker_cycles_through_every_locale() {
+    let mut seen = vec![Locale::default()];
+    let mut locale = Locale::default().next();
+    while locale != Locale::default() {
+        seen.push(locale);
+        locale = locale.next();
+    }
+    assert_eq!(seen, Locale::ALL);
+}
+
+/// The only test that switches the shared locale, so the others can run
+/// alongside it.
+#[test]
+fn result_labels_follow_a_language_switch() {
+    let mut result = common::result(Some(GestureKind::Fist), Instant::now());
+    set_locale(Locale::En);
+    assert_eq!(result.label(), "✊ Fist");
+    set_locale(Locale::ZhCn);
+    assert_eq!(result.label(), "✊ 握拳");
+
+    // A skipped frame says why, whatever was recognized before.
+    result.frame_quality = FrameQuality::TooDark;
+    assert_eq!(result.label(), "画面过暗");
+    set_locale(Locale::En);
+    assert_eq!(result.label(), "Too dark");
+
+    // Labels outside the result follow the switch too.
+    assert_eq!((on_off(true), OutputSize::Native.label()), ("On", "Native"));
+    set_locale(Locale::ZhCn);
+    assert_eq!((on_off(true), OutputSize::Native.label()), ("开", "原始"));
+    set_locale(Locale::default());
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "metrics-http")]
+
+use std::{
+    collections::HashMap,
+    io::{Read, Write},
+    net::TcpStream,
+    time::{Duration, Instant},
+};
+
+use gesture_universe::{
+    metrics_http::{DEFAULT_METRICS_BIND, MetricsServer},
+    pipeline::{
+        MetricsHandle,
+        metrics::{Channel, Stage},
+    },
+    types::{GestureEvent, GestureKind},
+};
+
+fn get(server: &MetricsServer, path: &str) -> String {
+    let mut stream = TcpStream::connect(("127.0.0.1", server.local_addr().port())).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    write!(stream, "GET {path} HTTP/1.0\r\nHost: localhost\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+/// Sample lines of the body by name and labels, comments skipped.
+fn samples(response: &str) -> HashMap<String, f64> {
+    let (_, body) = response
+        .split_once("\r\n\r\n")
+        .expect("response has a body");
+    body.lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (name, value) = line.rsplit_once(' ').expect("sample has a value");
+            (
+                name.to_string(),
+                value.parse().expect("sample value is a number"),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn serves_pipeline_counters() {
+    let metrics = MetricsHandle::default();
+    for _ in 0..3 {
+        metrics.record_capture(Instant::now());
+    }
+    metrics.record_stage(Stage::Classify, Duration::from_millis(1));
+    metrics.record_stage(Stage::Compose, Duration::from_millis(4));
+    metrics.record_drop(Channel::Recognized);
+    metrics.record_recognition(0.9, &[GestureEvent::Started(GestureKind::Palm)]);
+    metrics.record_recognition(0.8, &[GestureEvent::Ended(GestureKind::Palm)]);
+
+    let server = MetricsServer::start(DEFAULT_METRICS_BIND, 0, metrics).unwrap();
+    let response = get(&server, "/metrics");
+    assert!(response.starts_with("HTTP/1."), "{response}");
+    assert!(response.contains(" 200 "), "{response}");
+
+    let samples = samples(&response);
+    let value = |name: &str| {
+        samples
+            .get(name)
+            .copied()
+            .unwrap_or_else(|| panic!("no {name}"))
+    };
+    assert_eq!(value("gesture_universe_frames_captured_total"), 3.0);
+    assert_eq!(value("gesture_universe_frames_inferred_total"), 1.0);
+    assert_eq!(
+        value("gesture_universe_frames_dropped_total{channel=\"recognized\"}"),
+        1.0
+    );
+    assert_eq!(
+        value("gesture_universe_frames_dropped_total{channel=\"camera\"}"),
+        0.0
+    );
+    assert_eq!(
+        value("gesture_universe_gestures_recognized_total{gesture=\"palm\"}"),
+        1.0
+    );
+    assert_eq!(
+        value("gesture_universe_gestures_recognized_total{gesture=\"fist\"}"),
+        0.0
+    );
+    assert!((value("gesture_universe_last_confidence") - 0.8).abs() < 1e-6);
+    assert_eq!(
+        value("gesture_universe_stage_duration_seconds_count{stage=\"compose\"}"),
+        1.0
+    );
+    assert_eq!(
+        value("gesture_universe_stage_duration_seconds_bucket{stage=\"compose\",le=\"0.005\"}"),
+        1.0
+    );
+    assert_eq!(
+        value("gesture_universe_stage_duration_seconds_bucket{stage=\"compose\",le=\"0.0025\"}"),
+        0.0
+    );
+}
+
+#[test]
+fn other_paths_are_not_found() {
+    let server = MetricsServer::start(DEFAULT_METRICS_BIND, 0, MetricsHandle::default()).unwrap();
+    let response = get(&server, "/");
+    assert!(response.contains(" 404 "), "{response}");
+}
+
+#[test]
+fn the_server_listens_on_loopback_by_default() {
+    let server = MetricsServer::start(DEFAULT_METRICS_BIND, 0, MetricsHandle::default()).unwrap();
+    assert!(server.local_addr().ip().is_loopback());
+}
@@ -0,0 +1,89 @@
+use std::{path::Path, time::Instant};
+
+use gesture_universe::{
+    gesture::GestureClassifier,
+    pipeline::rgb_converter::mirror_pixels,
+    trace::{TraceFrame, read_jsonl},
+    types::{GestureKind, Handedness, PixelLayout, RecognizerConfig},
+};
+
+/// Each pixel holds its column, its row and then 200 for any channel left.
+fn numbered(width: u32, height: u32, layout: PixelLayout) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(layout.buffer_len(width, height));
+    for y in 0..height {
+        for x in 0..width {
+            pixels.extend_from_slice(&[x as u8, y as u8, 200, 201][..layout.channels()]);
+        }
+    }
+    pixels
+}
+
+#[test]
+fn mirroring_swaps_the_edge_columns_and_keeps_channel_order() {
+    for layout in [PixelLayout::Rgb, PixelLayout::Rgba] {
+        for width in [1, 2, 5, 8] {
+            let height = 3;
+            let original = numbered(width, height, layout);
+            let mut pixels = original.clone();
+            mirror_pixels(&mut pixels, width, layout);
+
+            let channels = layout.channels();
+            let row_len = width as usize * channels;
+            for (row, (mirrored, source)) in pixels
+                .chunks_exact(row_len)
+                .zip(original.chunks_exact(row_len))
+                .enumerate()
+            {
+                for x in 0..width as usize {
+                    let from = width as usize - 1 - x;
+                    assert_eq!(
+                        mirrored[x * channels..][..channels],
+                        source[from * channels..][..channels],
+                        "{layout:?} width {width} row {row} column {x}"
+                    );
+                }
+            }
+
+            mirror_pixels(&mut pixels, width, layout);
+            assert_eq!(pixels, original, "{layout:?} width {width} round trip");
+        }
+    }
+}
+
+#[test]
+fn mirroring_an_empty_frame_does_nothing() {
+    let mut pixels = Vec::new();
+    mirror_pixels(&mut pixels, 0, PixelLayout::Rgb);
+    assert!(pixels.is_empty());
+}
+
+#[test]
+fn a_mirrored_feed_reports_the_other_hand_with_the_same_gesture() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/left_victory.jsonl");
+    let frames: Vec<TraceFrame> = read_jsonl(&path).unwrap();
+    let frame = &frames[0];
+    let classify = |mirror: bool| {
+        let config = RecognizerConfig {
+            mirror,
+            ..RecognizerConfig::default()
+        };
+        GestureClassifier::rules_only(&config)
+            .classify(
+                1,
+                &frame.raw_landmarks,
+                &frame.projected_landmarks,
+                frame.confidence,
+                frame.handedness,
+                Instant::now(),
+            )
+            .expect("hand is confident enough to classify")
+    };
+
+    let seen = classify(false);
+    let mirrored = classify(true);
+    assert_eq!(seen.handedness, Handedness::Left);
+    assert_eq!(mirrored.handedness, Handedness::Right);
+    assert_eq!(seen.primary, GestureKind::Peace);
+    assert_eq!(mirrored.primary, GestureKind::Peace);
+    assert_eq!(mirrored.finger_states, seen.finger_states);
+}
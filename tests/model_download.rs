@@ -0,0 +1,189 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use gesture_universe::model_download::{ModelDownloadEvent, ModelKind, download_model, model_urls};
+use reqwest::Url;
+use sha2::{Digest, Sha256};
+
+const BODY: &[u8] = b"not really an onnx model";
+
+/// Answers `/ok/...` with [`BODY`] and everything else with 403 Forbidden,
+/// keeping the requested paths in order.
+struct FileServer {
+    addr: SocketAddr,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl FileServer {
+    fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests: Arc<Mutex<Vec<String>>> = Arc::default();
+        let seen = requests.clone();
+        thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut header = String::new();
+                while reader.read_line(&mut header).unwrap_or(0) > 2 {
+                    header.clear();
+                }
+                let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+                seen.lock().unwrap().push(path.to_string());
+                let (status, body) = if path.starts_with("/ok/") {
+                    ("200 OK", BODY)
+                } else {
+                    ("403 Forbidden", &b"forbidden"[..])
+                };
+                let head = format!(
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                );
+                let _ = stream.write_all(head.as_bytes());
+                let _ = stream.write_all(body);
+            }
+        });
+        Self { addr, requests }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("http://{}{path}", self.addr)
+    }
+
+    fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// An empty directory of its own for each test.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("gu-model-download-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn download(urls: &[String], sha256: &str, dest: &Path) -> anyhow::Result<Vec<ModelDownloadEvent>> {
+    let mut events = Vec::new();
+    download_model(ModelKind::PalmDetector, urls, sha256, dest, &mut |event| {
+        events.push(event)
+    })?;
+    Ok(events)
+}
+
+#[test]
+fn the_mirror_is_tried_before_the_published_url() {
+    let published = model_urls(ModelKind::PalmDetector, None);
+    assert_eq!(published.len(), 1);
+    assert!(published[0].ends_with("/palm_detection.onnx"));
+
+    let mirrored = model_urls(ModelKind::PalmDetector, Some("http://mirror.local/models/"));
+    assert_eq!(
+        mirrored,
+        [
+            "http://mirror.local/models/palm_detection.onnx",
+            published[0].as_str()
+        ]
+    );
+    assert_eq!(
+        model_urls(ModelKind::HandposeEstimator, Some("file:///srv/models"))[0],
+        "file:///srv/models/handpose_estimation.onnx"
+    );
+    assert_eq!(model_urls(ModelKind::PalmDetector, Some("")), published);
+}
+
+#[test]
+fn a_forbidden_mirror_falls_back_to_the_next_url() {
+    let server = FileServer::start();
+    let dest = scratch_dir("fallback").join("palm_detection.onnx");
+    let urls = [
+        server.url("/forbidden/palm_detection.onnx"),
+        server.url("/ok/palm_detection.onnx"),
+    ];
+
+    let events = download(&urls, &sha256_hex(BODY), &dest).unwrap();
+    assert_eq!(fs::read(&dest).unwrap(), BODY);
+    assert_eq!(
+        server.requests(),
+        ["/forbidden/palm_detection.onnx", "/ok/palm_detection.onnx"]
+    );
+    assert!(matches!(
+        events.last(),
+        Some(ModelDownloadEvent::Finished { .. })
+    ));
+    assert!(!dest.with_file_name("palm_detection.onnx.part").exists());
+}
+
+#[test]
+fn the_first_url_that_works_is_the_last_one_tried() {
+    let server = FileServer::start();
+    let dest = scratch_dir("first").join("palm_detection.onnx");
+    let urls = [
+        server.url("/ok/palm_detection.onnx"),
+        server.url("/forbidden/palm_detection.onnx"),
+    ];
+
+    download(&urls, &sha256_hex(BODY), &dest).unwrap();
+    assert_eq!(server.requests(), ["/ok/palm_detection.onnx"]);
+}
+
+#[test]
+fn file_urls_are_copied_from_disk() {
+    let dir = scratch_dir("file");
+    let share = dir.join("share");
+    fs::create_dir_all(&share).unwrap();
+    fs::write(share.join("palm_detection.onnx"), BODY).unwrap();
+    let mirror = Url::from_directory_path(&share).unwrap().to_string();
+    let urls = model_urls(ModelKind::PalmDetector, Some(&mirror));
+
+    let dest = dir.join("palm_detection.onnx");
+    download(&urls[..1], &sha256_hex(BODY), &dest).unwrap();
+    assert_eq!(fs::read(&dest).unwrap(), BODY);
+}
+
+#[test]
+fn every_url_tried_is_in_the_error() {
+    let server = FileServer::start();
+    let dir = scratch_dir("failures");
+    let missing = Url::from_file_path(dir.join("missing.onnx"))
+        .unwrap()
+        .to_string();
+    let urls = [
+        server.url("/forbidden/palm_detection.onnx"),
+        // Served fine, but not the file that was asked for.
+        server.url("/ok/palm_detection.onnx"),
+        missing.clone(),
+    ];
+    let dest = dir.join("palm_detection.onnx");
+
+    let err = download(&urls, &sha256_hex(b"the real model"), &dest).unwrap_err();
+    let message = format!("{err:#}");
+    for url in &urls {
+        assert!(
+            message.contains(url.as_str()),
+            "{url} missing from {message}"
+        );
+    }
+    assert!(message.contains("403"), "{message}");
+    assert!(
+        message.contains("does not match the expected SHA-256"),
+        "{message}"
+    );
+    let after_missing = &message[message.find(&missing).unwrap()..];
+    assert!(after_missing.contains("failed to read"), "{message}");
+    assert!(!dest.exists());
+}
@@ -0,0 +1,126 @@
+use std::time::Instant;
+
+use gesture_universe::{
+    pipeline::recognizer::motion::MotionGate,
+    types::{Frame, MotionGateConfig, PixelLayout},
+};
+
+const WIDTH: u32 = 128;
+const HEIGHT: u32 = 96;
+
+fn config() -> MotionGateConfig {
+    MotionGateConfig {
+        enabled: true,
+        idle_threshold: 1.5,
+        wake_threshold: 4.0,
+        idle_frames: 5,
+    }
+}
+
+/// A grey frame with a brighter square whose left edge is at `offset`.
+fn frame(offset: u32, grey: u8) -> Frame {
+    let mut pixels = vec![grey; (WIDTH * HEIGHT * 3) as usize];
+    for y in 20..60 {
+        for x in offset..(offset + 40).min(WIDTH) {
+            let at = ((y * WIDTH + x) * 3) as usize;
+            pixels[at..at + 3].fill(220);
+        }
+    }
+    let now = Instant::now();
+    Frame {
+        pixels: pixels.into(),
+        layout: PixelLayout::Rgb,
+        width: WIDTH,
+        height: HEIGHT,
+        timestamp: now,
+        acquired_at: now,
+    }
+}
+
+/// Which of `frames` the gate turned away.
+fn skipped(gate: &mut MotionGate, frames: &[Frame], config: &MotionGateConfig) -> Vec<bool> {
+    frames
+        .iter()
+        .map(|frame| gate.observe(frame, config))
+        .collect()
+}
+
+#[test]
+fn goes_idle_after_more_than_idle_frames_still_frames() {
+    let mut gate = MotionGate::default();
+    let still = vec![frame(10, 60); 8];
+    // The first frame is the reference, then five still frames are let
+    // through and the sixth goes idle.
+    assert_eq!(
+        skipped(&mut gate, &still, &config()),
+        [false, false, false, false, false, false, true, true]
+    );
+    assert!(gate.is_idle());
+}
+
+#[test]
+fn a_moving_scene_never_goes_idle() {
+    let mut gate = MotionGate::default();
+    let moving: Vec<Frame> = (0..20).map(|step| frame(step * 4, 60)).collect();
+    assert!(
+        skipped(&mut gate, &moving, &config())
+            .iter()
+            .all(|skip| !skip)
+    );
+    assert!(!gate.is_idle());
+}
+
+#[test]
+fn wakes_on_the_first_frame_past_the_wake_threshold() {
+    let mut gate = MotionGate::default();
+    let still = vec![frame(10, 60); 7];
+    skipped(&mut gate, &still, &config());
+    assert!(gate.is_idle());
+
+    // Between the thresholds: not enough to wake.
+    assert!(gate.observe(&frame(10, 62), &config()));
+    assert!(gate.is_idle());
+    // A hand sweeping in wakes it on that very frame.
+    assert!(!gate.observe(&frame(60, 60), &config()));
+    assert!(!gate.is_idle());
+    // And it takes a full run of still frames to go idle again.
+    let settled = vec![frame(60, 60); 5];
+    assert!(
+        skipped(&mut gate, &settled, &config())
+            .iter()
+            .all(|skip| !skip)
+    );
+    assert!(gate.observe(&frame(60, 60), &config()));
+}
+
+#[test]
+fn noise_between_the_thresholds_keeps_an_awake_gate_awake() {
+    let mut gate = MotionGate::default();
+    let flicker: Vec<Frame> = (0..20).map(|step| frame(10, 60 + (step % 2) * 2)).collect();
+    assert!(
+        skipped(&mut gate, &flicker, &config())
+            .iter()
+            .all(|skip| !skip)
+    );
+}
+
+#[test]
+fn a_hand_found_holds_the_gate_awake() {
+    let mut gate = MotionGate::default();
+    let still = frame(10, 60);
+    for _ in 0..20 {
+        assert!(!gate.observe(&still, &config()));
+        gate.keep_awake();
+    }
+}
+
+#[test]
+fn disabled_lets_everything_through() {
+    let mut gate = MotionGate::default();
+    let config = MotionGateConfig {
+        enabled: false,
+        ..config()
+    };
+    let still = vec![frame(10, 60); 10];
+    assert!(skipped(&mut gate, &still, &config).iter().all(|skip| !skip));
+}
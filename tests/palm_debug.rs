@@ -0,0 +1,110 @@
+use gesture_universe::{
+    pipeline::{
+        PalmDetectorConfig,
+        recognizer::{
+            common::LetterboxInfo,
+            palm::{decode_candidates, decode_regions, suppress},
+        },
+        skeleton::{SkeletonStyle, draw_palm_regions},
+    },
+    types::{PalmDebug, PalmRegion},
+};
+
+const INPUT: u32 = 192;
+const FEATURES: usize = 18;
+
+/// A 192 px input that is the frame itself, so box offsets land on the anchor
+/// plus the offset in frame pixels.
+fn letterbox() -> LetterboxInfo {
+    LetterboxInfo {
+        scale: 1.0,
+        pad_x: 0.0,
+        pad_y: 0.0,
+        orig_w: INPUT,
+        orig_h: INPUT,
+    }
+}
+
+/// Three overlapping palms at the top left, one at the bottom right and one
+/// below the score threshold in the middle, as raw scores and box rows.
+fn detector_output() -> (Vec<f32>, Vec<f32>, Vec<[f32; 2]>) {
+    let anchors = vec![
+        [0.25, 0.25],
+        [0.26, 0.25],
+        [0.25, 0.26],
+        [0.75, 0.75],
+        [0.5, 0.5],
+    ];
+    let scores = vec![2.0, 1.0, 0.5, 1.5, -3.0];
+    let mut boxes = Vec::new();
+    for _ in &anchors {
+        let mut row = vec![0.0; FEATURES];
+        row[2] = 40.0;
+        row[3] = 40.0;
+        boxes.extend(row);
+    }
+    (scores, boxes, anchors)
+}
+
+#[test]
+fn candidates_are_everything_over_the_threshold_before_nms() {
+    let (scores, boxes, anchors) = detector_output();
+    let config = PalmDetectorConfig::default();
+    let candidates = decode_candidates(&scores, &boxes, &anchors, &letterbox(), &config, INPUT);
+    assert_eq!(candidates.len(), 4);
+
+    let kept = suppress(&candidates, &config);
+    let kept_scores: Vec<f32> = kept.iter().map(|region| region.score).collect();
+    assert_eq!(kept_scores.len(), 2);
+    assert!(
+        kept_scores[0] > 0.88 && kept_scores[1] > 0.81,
+        "{kept_scores:?}"
+    );
+
+    let regions = decode_regions(&scores, &boxes, &anchors, &letterbox(), &config, INPUT);
+    assert_eq!(regions.len(), kept.len());
+    assert!(
+        regions
+            .iter()
+            .zip(&kept)
+            .all(|(region, kept)| region.bbox == kept.bbox)
+    );
+}
+
+fn region(bbox: [f32; 4], score: f32) -> PalmRegion {
+    PalmRegion {
+        bbox,
+        landmarks: Vec::new(),
+        score,
+    }
+}
+
+fn lit(buffer: &[u8], width: u32, (x, y): (u32, u32)) -> bool {
+    let idx = ((y * width + x) * 3) as usize;
+    buffer[idx..idx + 3] != [0, 0, 0]
+}
+
+#[test]
+fn the_debug_overlay_draws_rejected_candidates_and_tracked_crops() {
+    let (width, height) = (200, 200);
+    let debug = PalmDebug {
+        candidates: vec![region([20.0, 40.0, 60.0, 80.0], 0.42)],
+        selected: Vec::new(),
+        tracked: vec![((150.0, 150.0), 40.0, 0.0)],
+    };
+
+    let mut plain = vec![0u8; (width * height * 3) as usize];
+    let style = SkeletonStyle::default();
+    draw_palm_regions(&mut plain, width, height, &[], None, &style);
+    assert!(plain.iter().all(|&value| value == 0));
+
+    let mut buffer = plain.clone();
+    draw_palm_regions(&mut buffer, width, height, &[], Some(&debug), &style);
+    // The candidate's outline, and its score above it.
+    assert!(lit(&buffer, width, (40, 40)));
+    assert!(!lit(&buffer, width, (40, 60)));
+    assert!((20..60).any(|x| (25..40).any(|y| lit(&buffer, width, (x, y)))));
+    // The tracked crop is dashed: its top edge has both lit and dark stretches.
+    let edge: Vec<bool> = (132..168).map(|x| lit(&buffer, width, (x, 130))).collect();
+    assert!(edge.contains(&true) && edge.contains(&false));
+}
@@ -0,0 +1,130 @@
+use std::path::Path;
+
+use gesture_universe::pipeline::recognizer::{
+    PalmDetectorConfig,
+    common::LetterboxInfo,
+    palm::{PALM_ANCHOR_STRIDES, PALM_LANDMARKS, decode_regions, ssd_anchors},
+};
+
+const INPUT_SIZE: u32 = 192;
+/// Box centre and size, then the palm keypoints.
+const ROW: usize = 4 + PALM_LANDMARKS * 2;
+
+/// The palm model's input taken as the frame, unscaled and unpadded.
+fn letterbox() -> LetterboxInfo {
+    LetterboxInfo {
+        scale: 1.0,
+        pad_x: 0.0,
+        pad_y: 0.0,
+        orig_w: INPUT_SIZE,
+        orig_h: INPUT_SIZE,
+    }
+}
+
+fn logit(probability: f32) -> f32 {
+    (probability / (1.0 - probability)).ln()
+}
+
+/// Raw model outputs scoring `palms` as `(anchor, probability, size)`, with a
+/// box of `size` input pixels centred on each anchor and nothing elsewhere.
+fn outputs(anchors: usize, palms: &[(usize, f32, f32)]) -> (Vec<f32>, Vec<f32>) {
+    let mut scores = vec![-10.0; anchors];
+    let mut boxes = vec![0.0; anchors * ROW];
+    for &(anchor, probability, size) in palms {
+        scores[anchor] = logit(probability);
+        boxes[anchor * ROW + 2] = size;
+        boxes[anchor * ROW + 3] = size;
+    }
+    (scores, boxes)
+}
+
+/// The first anchor of grid cell `(x, y)` on the finest, stride 8 grid.
+fn fine_anchor(x: usize, y: usize) -> usize {
+    let cells = (INPUT_SIZE / PALM_ANCHOR_STRIDES[0]) as usize;
+    (y * cells + x) * 2
+}
+
+#[test]
+fn a_lower_score_threshold_lets_fainter_palms_through() {
+    let anchors = ssd_anchors(INPUT_SIZE, &PALM_ANCHOR_STRIDES);
+    let palms = [
+        (fine_anchor(2, 2), 0.9, 30.0),
+        (fine_anchor(12, 12), 0.5, 30.0),
+        (fine_anchor(20, 20), 0.25, 30.0),
+    ];
+    let (scores, boxes) = outputs(anchors.len(), &palms);
+    let decode = |score_threshold| {
+        let config = PalmDetectorConfig {
+            score_threshold,
+            ..PalmDetectorConfig::default()
+        };
+        decode_regions(&scores, &boxes, &anchors, &letterbox(), &config, INPUT_SIZE)
+    };
+
+    let strict = decode(PalmDetectorConfig::default().score_threshold);
+    let sensitive = decode(0.2);
+    assert_eq!(strict.len(), 2);
+    assert_eq!(sensitive.len(), 3);
+    assert!((sensitive[2].score - 0.25).abs() < 1e-4);
+}
+
+#[test]
+fn generated_anchors_match_the_old_mediapipe_table() {
+    // The table the detector shipped with before anchors were generated.
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/palm_anchors_192.txt");
+    let table: Vec<[f32; 2]> = std::fs::read_to_string(&path)
+        .unwrap()
+        .lines()
+        .map(|line| {
+            let mut values = line.split(' ').map(|value| value.parse().unwrap());
+            [values.next().unwrap(), values.next().unwrap()]
+        })
+        .collect();
+
+    let anchors = ssd_anchors(INPUT_SIZE, &PALM_ANCHOR_STRIDES);
+    assert_eq!(anchors.len(), table.len());
+    for (idx, (generated, old)) in anchors.iter().zip(&table).enumerate() {
+        let off = (generated[0] - old[0])
+            .abs()
+            .max((generated[1] - old[1]).abs());
+        assert!(off < 1e-6, "anchor {idx}: {generated:?} vs {old:?}");
+    }
+}
+
+#[test]
+fn overlapping_palms_are_merged_and_mapped_out_of_the_letterbox() {
+    let anchors = ssd_anchors(INPUT_SIZE, &PALM_ANCHOR_STRIDES);
+    // Neighbouring cells 8 input pixels apart see the same palm.
+    let palms = [
+        (fine_anchor(12, 12), 0.8, 40.0),
+        (fine_anchor(13, 12), 0.9, 40.0),
+        (fine_anchor(3, 20), 0.7, 40.0),
+    ];
+    let (scores, boxes) = outputs(anchors.len(), &palms);
+    // A 640×480 frame fits the input at 0.3, with 24 pixels of bars above and
+    // below.
+    let letterbox = LetterboxInfo {
+        scale: 0.3,
+        pad_x: 0.0,
+        pad_y: 24.0,
+        orig_w: 640,
+        orig_h: 480,
+    };
+    let config = PalmDetectorConfig::default();
+    let regions = decode_regions(&scores, &boxes, &anchors, &letterbox, &config, INPUT_SIZE);
+
+    let kept: Vec<f32> = regions.iter().map(|region| region.score).collect();
+    assert_eq!(kept.len(), 2, "{kept:?}");
+    assert!(
+        (kept[0] - 0.9).abs() < 1e-4 && (kept[1] - 0.7).abs() < 1e-4,
+        "{kept:?}"
+    );
+
+    // Cell (13, 12) is centred at (108, 100) input pixels.
+    let [x1, y1, x2, y2] = regions[0].bbox;
+    let expected_center = (108.0 / 0.3, (100.0 - 24.0) / 0.3);
+    let center = ((x1 + x2) / 2.0, (y1 + y2) / 2.0);
+    assert!((center.0 - expected_center.0).abs() < 0.01, "{center:?}");
+    assert!((center.1 - expected_center.1).abs() < 0.01, "{center:?}");
+    assert!((x2 - x1 - 40.0 / 0.3).abs() < 0.01, "{:?}", regions[0].bbox);
+}
@@ -0,0 +1,96 @@
+use std::{
+    path::PathBuf,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crossbeam_channel::{Receiver, Sender};
+use gesture_universe::{
+    pipeline::{
+        CameraManager, CompositedFrame, CompositorSettings, FrameSource, MetricsHandle,
+        PipelineHandles, start_frame_compositor,
+    },
+    types::{Frame, GestureResult, RecognizedFrame},
+};
+
+const DEADLINE: Duration = Duration::from_secs(2);
+
+/// A directory of two small images for the camera manager to loop through.
+fn image_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("gu-shutdown-{}-{name}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    for (i, shade) in [40, 200].into_iter().enumerate() {
+        image::RgbImage::from_pixel(64, 48, image::Rgb([shade; 3]))
+            .save(dir.join(format!("{i}.png")))
+            .unwrap();
+    }
+    dir
+}
+
+/// Everything but the recognizer, which each test stands in for, running off
+/// a looping image sequence.
+fn pipeline(name: &str) -> (PipelineHandles, Receiver<Frame>, Receiver<CompositedFrame>) {
+    let metrics = MetricsHandle::default();
+    let (frame_tx, frame_rx) = crossbeam_channel::bounded(1);
+    let mut camera = CameraManager::new(frame_tx, metrics.clone(), false);
+    camera
+        .set_source(FrameSource::ImageSequence {
+            dir: image_dir(name),
+            interval: Duration::from_millis(10),
+            looping: true,
+        })
+        .unwrap();
+
+    let (recognized_tx, recognized_rx) = crossbeam_channel::bounded(1);
+    let (_settings_tx, settings_rx) = crossbeam_channel::unbounded();
+    let (composited_rx, compositor) = start_frame_compositor(
+        recognized_rx,
+        CompositorSettings::default(),
+        settings_rx,
+        metrics,
+    );
+    let handles = PipelineHandles::new(camera, recognized_tx, compositor);
+    (handles, frame_rx, composited_rx)
+}
+
+/// Passes frames through unrecognized until the camera side disconnects.
+fn pass_through(frame_rx: Receiver<Frame>, result_tx: Sender<RecognizedFrame>) {
+    while let Ok(frame) = frame_rx.recv() {
+        let result = GestureResult {
+            frame_size: (frame.width, frame.height),
+            ..GestureResult::default()
+        };
+        let _ = result_tx.try_send(RecognizedFrame { frame, result });
+    }
+}
+
+#[test]
+fn shutdown_stops_every_stage_promptly() {
+    let (mut handles, frame_rx, composited_rx) = pipeline("prompt");
+    let result_tx = handles.recognized_sender().unwrap();
+    handles.set_recognizer(thread::spawn(move || pass_through(frame_rx, result_tx)));
+    composited_rx
+        .recv_timeout(DEADLINE)
+        .expect("frames reach the compositor");
+
+    let start = Instant::now();
+    assert!(handles.shutdown(DEADLINE));
+    assert!(start.elapsed() < DEADLINE, "{:?}", start.elapsed());
+    assert!(!handles.camera().is_running());
+    assert!(handles.recognized_sender().is_none());
+}
+
+#[test]
+fn a_stuck_stage_is_detached_at_the_timeout() {
+    let (mut handles, _frame_rx, _composited_rx) = pipeline("stuck");
+    // Never looks at its input, so closing the camera does not reach it.
+    let (_release, stuck) = crossbeam_channel::bounded::<()>(0);
+    handles.set_recognizer(thread::spawn(move || {
+        let _ = stuck.recv();
+    }));
+
+    let timeout = Duration::from_millis(200);
+    let start = Instant::now();
+    assert!(!handles.shutdown(timeout));
+    assert!(start.elapsed() < DEADLINE, "{:?}", start.elapsed());
+}
@@ -0,0 +1,72 @@
+use gesture_universe::{gesture::pointing_direction, types::PointingInfo};
+
+/// Degrees a synthetic finger may be read off by.
+const TOLERANCE_DEG: f32 = 2.0;
+
+/// A hand with every landmark on the index knuckle except the tip, which sits
+/// `length` pixels away at `angle_deg` (counterclockwise from pointing right)
+/// and `depth` pixels nearer the camera.
+fn index_finger(angle_deg: f32, length: f32, depth: f32) -> (Vec<[f32; 3]>, Vec<(f32, f32)>) {
+    let knuckle = (320.0, 240.0);
+    let angle = angle_deg.to_radians();
+    // Image y grows downwards.
+    let tip = (
+        knuckle.0 + angle.cos() * length,
+        knuckle.1 - angle.sin() * length,
+    );
+    let mut projected = vec![knuckle; 21];
+    projected[8] = tip;
+    let raw = projected
+        .iter()
+        .enumerate()
+        .map(|(idx, &(x, y))| [x, y, if idx == 8 { -depth } else { 0.0 }])
+        .collect();
+    (raw, projected)
+}
+
+fn assert_angle(pointing: Option<PointingInfo>, expected_deg: f32) {
+    let pointing = pointing.expect("finger long enough to give a direction");
+    let diff = (pointing.angle_deg - expected_deg + 540.0).rem_euclid(360.0) - 180.0;
+    assert!(
+        diff.abs() <= TOLERANCE_DEG,
+        "expected {expected_deg}°, got {}°",
+        pointing.angle_deg
+    );
+}
+
+#[test]
+fn pointing_right() {
+    let (raw, projected) = index_finger(0.0, 80.0, 0.0);
+    assert_angle(pointing_direction(&raw, &projected), 0.0);
+}
+
+#[test]
+fn pointing_up() {
+    let (raw, projected) = index_finger(90.0, 80.0, 0.0);
+    assert_angle(pointing_direction(&raw, &projected), 90.0);
+}
+
+#[test]
+fn pointing_up_and_right() {
+    let (raw, projected) = index_finger(45.0, 80.0, 0.0);
+    assert_angle(pointing_direction(&raw, &projected), 45.0);
+}
+
+#[test]
+fn finger_aimed_at_the_camera_reads_as_toward() {
+    let (raw, projected) = index_finger(45.0, 40.0, 40.0);
+    let pointing = pointing_direction(&raw, &projected).unwrap();
+    assert!(
+        (pointing.toward - 0.707).abs() < 0.01,
+        "toward {}",
+        pointing.toward
+    );
+
+    let (raw, projected) = index_finger(45.0, 40.0, -40.0);
+    let pointing = pointing_direction(&raw, &projected).unwrap();
+    assert!(
+        (pointing.toward + 0.707).abs() < 0.01,
+        "toward {}",
+        pointing.toward
+    );
+}
@@ -0,0 +1,78 @@
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use gesture_universe::{
+    model_download::{ModelDownloadEvent, ensure_handpose_estimator_model_ready},
+    pipeline::RecognizerError,
+};
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("gu-error-{}-{name}", std::process::id()))
+}
+
+#[test]
+fn a_missing_custom_model_is_reported_by_path() {
+    let path = temp_path("missing.onnx");
+    let mut events = Vec::new();
+    let err = ensure_handpose_estimator_model_ready(&path, |event| events.push(event)).unwrap_err();
+    assert!(
+        matches!(&err, RecognizerError::ModelMissing(missing) if *missing == path),
+        "{err:?}"
+    );
+    assert_eq!(err.model_path(), Some(path.as_path()));
+    // Someone else's model is never downloaded in its place.
+    assert!(events.is_empty(), "{events:?}");
+}
+
+#[test]
+fn a_present_custom_model_is_used_as_is() {
+    let path = temp_path("present.onnx");
+    std::fs::write(&path, b"whatever the user exported").unwrap();
+    let mut events = Vec::new();
+    ensure_handpose_estimator_model_ready(&path, |event| events.push(event)).unwrap();
+    assert!(
+        matches!(
+            events[..],
+            [
+                ModelDownloadEvent::AlreadyPresent { .. },
+                ModelDownloadEvent::Finished { .. }
+            ]
+        ),
+        "{events:?}"
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn a_session_failure_tells_a_missing_file_from_a_bad_one() {
+    let missing = temp_path("gone.onnx");
+    let err = RecognizerError::load_failed(&missing, anyhow!("no such file"));
+    assert!(matches!(err, RecognizerError::ModelMissing(_)), "{err:?}");
+
+    let corrupt = temp_path("corrupt.onnx");
+    std::fs::write(&corrupt, b"not an onnx model").unwrap();
+    let err = RecognizerError::load_failed(&corrupt, anyhow!("protobuf parsing failed"));
+    assert!(
+        matches!(&err, RecognizerError::ModelLoadFailed { path, .. } if *path == corrupt),
+        "{err:?}"
+    );
+    assert_eq!(err.model_path(), Some(corrupt.as_path()));
+    // The panel shows the cause along with the path.
+    let text = err.describe();
+    assert!(text.contains("corrupt.onnx"), "{text}");
+    assert!(text.ends_with(": protobuf parsing failed"), "{text}");
+    std::fs::remove_file(&corrupt).unwrap();
+}
+
+#[test]
+fn frame_errors_carry_no_model_path() {
+    let err = RecognizerError::BadOutputShape {
+        expected: "[1, 2016, 18]",
+        got: vec![1, 896, 18],
+    };
+    assert_eq!(err.model_path(), None);
+    assert_eq!(
+        err.to_string(),
+        "unexpected output shape [1, 896, 18], expected [1, 2016, 18]"
+    );
+}
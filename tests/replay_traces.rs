@@ -0,0 +1,96 @@
+use std::path::{Path, PathBuf};
+
+use gesture_universe::{
+    gesture::GestureClassifier,
+    trace::{TraceFrame, TraceStep, mismatches, read_jsonl, replay},
+    types::RecognizerConfig,
+};
+
+fn testdata(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join(name)
+}
+
+fn replay_trace(name: &str) -> Vec<TraceStep> {
+    let frames: Vec<TraceFrame> = read_jsonl(&testdata(&format!("{name}.jsonl"))).unwrap();
+    let mut classifier = GestureClassifier::rules_only(&RecognizerConfig::default());
+    replay(&mut classifier, &frames)
+}
+
+/// Every frame has to match what the expected trace says about it; frames
+/// between two poses leave out what either reading would get right.
+fn assert_trace(name: &str) {
+    let expected: Vec<TraceStep> =
+        read_jsonl(&testdata(&format!("{name}.expected.jsonl"))).unwrap();
+    let actual = replay_trace(name);
+    let wrong = mismatches(&expected, &actual);
+    assert!(
+        wrong.is_empty(),
+        "{name}: {} of {} frames differ from the expected trace:\n{}",
+        wrong.len(),
+        expected.len(),
+        wrong
+            .iter()
+            .map(|(idx, want, got)| format!("  frame {idx}: expected {want:?}, got {got:?}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
+
+#[test]
+fn open_palm_wave() {
+    assert_trace("open_palm_wave");
+}
+
+/// The pinch closes once and opens once, with no flicker in between.
+fn assert_single_pinch(name: &str) {
+    let closed: Vec<bool> = replay_trace(name)
+        .iter()
+        .map(|step| step.pinch_closed.expect("every frame has a hand"))
+        .collect();
+    let changes: Vec<usize> = closed
+        .windows(2)
+        .enumerate()
+        .filter(|(_, pair)| pair[0] != pair[1])
+        .map(|(idx, _)| idx + 1)
+        .collect();
+    assert!(!closed[0], "{name}: starts pinched");
+    assert_eq!(
+        changes.len(),
+        2,
+        "{name}: pinch changes at frames {changes:?}"
+    );
+}
+
+#[test]
+fn pinch() {
+    assert_trace("pinch");
+    assert_single_pinch("pinch");
+}
+
+#[test]
+fn thumbs_up() {
+    assert_trace("thumbs_up");
+}
+
+#[test]
+fn fist_to_palm() {
+    assert_trace("fist_to_palm");
+}
+
+#[test]
+fn left_hand_victory() {
+    assert_trace("left_victory");
+}
+
+#[test]
+fn left_hand_thumbs_up() {
+    assert_trace("left_thumbs_up");
+}
+
+#[test]
+fn left_hand_pinch() {
+    assert_trace("left_pinch");
+    assert_single_pinch("left_pinch");
+}
@@ -0,0 +1,127 @@
+
+mod common;
+
+use std::time::{Duration, Instant, UNIX_EPOCH};
+
+use gesture_universe::types::{
+    CompositeGesture, DwellProgress, FingerAngles, FingerState, GestureKind, GestureMotion,
+    GestureResult, HandOrientation, Handedness, PalmDebug, PalmRegion, PinchInfo, PointingInfo,
+    RotationInfo,
+};
+use serde_json::json;
+
+/// Every optional field set, on both the primary hand and its copy in `hands`.
+fn populated() -> GestureResult {
+    let mut result = common::result(Some(GestureKind::Peace), Instant::now());
+    result.frame_id = 42;
+    result.captured_at = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+    result.raw_landmarks = Some((0..21).map(|i| [i as f32, 2.0 * i as f32, -0.5]).collect());
+    let palm = PalmRegion {
+        bbox: [10.0, 20.0, 110.0, 140.0],
+        landmarks: vec![(15.0, 25.0); 7],
+        score: 0.87,
+    };
+    result.palm_regions = vec![palm.clone()];
+    result.palm_debug = Some(PalmDebug {
+        candidates: vec![palm.clone()],
+        selected: vec![palm],
+        tracked: vec![((60.0, 80.0), 130.0, 0.25)],
+    });
+    result.composite = Some(CompositeGesture::Heart);
+    result.dwell = Some(DwellProgress {
+        kind: GestureKind::Peace,
+        progress: 0.5,
+        wrist: (320.0, 400.0),
+        completed: false,
+    });
+
+    let detail = result.detail.as_mut().unwrap();
+    detail.secondary = Some(GestureKind::Three);
+    detail.handedness = Handedness::Left;
+    detail.handedness_confidence = 0.8;
+    detail.finger_states = [
+        FingerState::HalfBent,
+        FingerState::Extended,
+        FingerState::Extended,
+        FingerState::Folded,
+        FingerState::Folded,
+    ];
+    detail.finger_angles[0] = FingerAngles {
+        mcp: 150.0,
+        pip: 160.0,
+        abduction: Some(35.0),
+        opposition: Some(-0.1),
+    };
+    detail.motion = GestureMotion::SwipeLeft;
+    detail.pinch = Some(PinchInfo {
+        distance: 0.6,
+        delta: -0.1,
+        closed: false,
+    });
+    detail.count = Some(2);
+    detail.scores = vec![(GestureKind::Peace, 0.7), (GestureKind::Three, 0.2)];
+    detail.point_direction = Some(PointingInfo {
+        angle_deg: 90.0,
+        toward: 0.1,
+    });
+    detail.rotation = Some(RotationInfo {
+        total_degrees: 140.0,
+        delta_degrees: 3.5,
+    });
+    detail.orientation = Some(HandOrientation::PalmToward);
+    let detail = detail.clone();
+
+    let hand = &mut result.hands[0];
+    hand.raw_landmarks = result.raw_landmarks.clone().unwrap();
+    hand.handedness_score = Some(0.1);
+    hand.detail = Some(detail);
+    result
+}
+
+#[test]
+fn a_fully_populated_result_survives_a_round_trip() {
+    let result = populated();
+    let json = serde_json::to_string(&result).unwrap();
+    let back: GestureResult = serde_json::from_str(&json).unwrap();
+    // Nothing is lost, the fields not checked below included.
+    assert_eq!(
+        serde_json::to_value(&back).unwrap(),
+        serde_json::to_value(&result).unwrap()
+    );
+
+    assert_eq!(back.frame_id, 42);
+    assert_eq!(back.captured_at, result.captured_at);
+    assert_eq!(back.landmarks, result.landmarks);
+    assert_eq!(back.raw_landmarks, result.raw_landmarks);
+    assert_eq!(back.palm_regions[0].bbox, [10.0, 20.0, 110.0, 140.0]);
+    assert_eq!(
+        back.palm_debug.unwrap().tracked,
+        vec![((60.0, 80.0), 130.0, 0.25)]
+    );
+    let detail = back.detail.unwrap();
+    assert_eq!(
+        detail.finger_states,
+        result.detail.as_ref().unwrap().finger_states
+    );
+    assert_eq!(detail.rotation, result.detail.as_ref().unwrap().rotation);
+    assert_eq!(detail.motion, GestureMotion::SwipeLeft);
+}
+
+#[test]
+fn enums_serialize_as_snake_case_names() {
+    let value = serde_json::to_value(populated()).unwrap();
+    let detail = &value["detail"];
+    assert_eq!(detail["primary"], json!("peace"));
+    assert_eq!(detail["handedness"], json!("left"));
+    assert_eq!(detail["motion"], json!("swipe_left"));
+    assert_eq!(detail["finger_states"][0], json!("half_bent"));
+    assert_eq!(detail["orientation"], json!("palm_toward"));
+    assert_eq!(value["composite"], json!("heart"));
+}
+
+#[test]
+fn the_process_local_timestamp_is_left_out() {
+    let value = serde_json::to_value(populated()).unwrap();
+    assert!(value.get("timestamp").is_none());
+    assert!(value.get("captured_at").is_some());
+}
@@ -0,0 +1,73 @@
+use std::{f32::consts::FRAC_PI_2, time::Instant};
+
+use gesture_universe::{
+    pipeline::recognizer::common::prepare_rotated_crop,
+    types::{Frame, PixelLayout},
+};
+
+const WIDTH: u32 = 64;
+const HEIGHT: u32 = 48;
+
+/// Red counts columns and green counts rows, so a sample tells where in the
+/// frame it came from.
+fn coordinate_frame(layout: PixelLayout) -> Frame {
+    let mut pixels = Vec::with_capacity(layout.buffer_len(WIDTH, HEIGHT));
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            pixels.extend_from_slice(&[x as u8, y as u8, 200]);
+            if layout == PixelLayout::Rgba {
+                pixels.push(u8::MAX);
+            }
+        }
+    }
+    let now = Instant::now();
+    Frame {
+        pixels: pixels.into(),
+        layout,
+        width: WIDTH,
+        height: HEIGHT,
+        timestamp: now,
+        acquired_at: now,
+    }
+}
+
+#[test]
+fn an_upright_crop_on_pixel_centers_copies_the_frame() {
+    for layout in [PixelLayout::Rgb, PixelLayout::Rgba] {
+        let frame = coordinate_frame(layout);
+        // Output pixel (x, y) lands on frame pixel (20 + x, 10 + y).
+        let (crop, transform) = prepare_rotated_crop(&frame, (23.5, 13.5), 8.0, 0.0, 8).unwrap();
+        assert_eq!(crop.shape(), [1, 8, 8, 3]);
+        assert_eq!(transform.output_size, 8);
+        for y in 0..8 {
+            for x in 0..8 {
+                let expected = [(20 + x) as f32, (10 + y) as f32, 200.0];
+                for (c, value) in expected.iter().enumerate() {
+                    let sampled = crop[[0, y, x, c]] * 255.0;
+                    assert!((sampled - value).abs() < 1e-3, "{layout:?} ({x}, {y}) {c}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn a_turned_crop_samples_along_the_turned_axes_and_is_black_off_the_frame() {
+    let frame = coordinate_frame(PixelLayout::Rgb);
+    // A quarter turn: going right in the crop goes down the frame, and going
+    // down the crop goes left.
+    let (crop, _) = prepare_rotated_crop(&frame, (8.0, 24.0), 32.0, FRAC_PI_2, 32).unwrap();
+    for y in 0..32 {
+        for x in 0..32 {
+            let src_x = 8.0 - (y as f32 + 0.5 - 16.0);
+            let src_y = 24.0 + (x as f32 + 0.5 - 16.0);
+            let rgb = [0, 1, 2].map(|c| crop[[0, y, x, c]] * 255.0);
+            if src_x >= 0.0 && src_x <= (WIDTH - 1) as f32 {
+                assert!((rgb[0] - src_x).abs() < 0.01, "({x}, {y}) {rgb:?}");
+                assert!((rgb[1] - src_y).abs() < 0.01, "({x}, {y}) {rgb:?}");
+            } else if src_x < -1.0 {
+                assert_eq!(rgb, [0.0, 0.0, 0.0], "({x}, {y})");
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::{
+    f32::consts::TAU,
+    time::{Duration, Instant},
+};
+
+use gesture_universe::{gesture::RotationTracker, types::RotationInfo};
+
+/// Hand span in pixels; the paths below are sized against it.
+const SPAN: f32 = 100.0;
+const FPS: f32 = 30.0;
+
+/// Wrist positions sampled at [`FPS`] from `path(t)`, `t` in seconds.
+fn sample(frames: usize, path: impl Fn(f32) -> (f32, f32)) -> Vec<(f32, f32)> {
+    (0..frames).map(|i| path(i as f32 / FPS)).collect()
+}
+
+/// One lap every 1.5 s around (320, 240), clockwise on screen unless
+/// `counter`.
+fn circle(frames: usize, counter: bool) -> Vec<(f32, f32)> {
+    let sign = if counter { -1.0 } else { 1.0 };
+    sample(frames, |t| {
+        let angle = sign * TAU * t / 1.5;
+        (320.0 + SPAN * angle.cos(), 240.0 + SPAN * angle.sin())
+    })
+}
+
+/// A lemniscate 3 spans across at the same pace, starting at `phase`.
+fn figure_eight(frames: usize, phase: f32) -> Vec<(f32, f32)> {
+    sample(frames, |t| {
+        let s = TAU * t / 1.5 + phase;
+        let d = 1.0 + s.sin().powi(2);
+        (
+            320.0 + 1.5 * SPAN * s.cos() / d,
+            240.0 + 1.5 * SPAN * s.sin() * s.cos() / d,
+        )
+    })
+}
+
+fn track(points: &[(f32, f32)]) -> Vec<Option<RotationInfo>> {
+    let mut tracker = RotationTracker::default();
+    let start = Instant::now();
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &point)| {
+            let now = start + Duration::from_secs_f32(i as f32 / FPS);
+            tracker.update(point, SPAN, now, true)
+        })
+        .collect()
+}
+
+fn first_engaged(rotations: &[Option<RotationInfo>]) -> Option<usize> {
+    rotations.iter().position(Option::is_some)
+}
+
+#[test]
+fn a_circle_engages_after_a_third_of_a_turn_and_streams_deltas() {
+    let rotations = track(&circle(90, false));
+    // 8° a frame, so 120° takes 15 frames.
+    let engaged = first_engaged(&rotations).expect("circle engages");
+    assert!((14..=16).contains(&engaged), "engaged on frame {engaged}");
+    let first = rotations[engaged].unwrap();
+    assert_eq!(first.delta_degrees, 0.0);
+    assert!(first.total_degrees >= 120.0);
+
+    for rotation in &rotations[engaged + 1..] {
+        let rotation = rotation.expect("stays engaged while circling");
+        assert!((rotation.delta_degrees - 8.0).abs() < 0.5);
+    }
+    let last = rotations.last().unwrap().unwrap();
+    assert!((last.total_degrees - 89.0 * 8.0).abs() < 5.0);
+}
+
+#[test]
+fn counter_clockwise_turns_are_negative() {
+    let rotations = track(&circle(60, true));
+    let last = rotations.last().unwrap().expect("engaged");
+    assert!(last.delta_degrees < -7.5);
+    assert!(last.total_degrees < -120.0);
+}
+
+#[test]
+fn a_figure_eight_never_engages() {
+    for phase in [0.0, TAU / 4.0, TAU / 8.0] {
+        let rotations = track(&figure_eight(120, phase));
+        assert_eq!(first_engaged(&rotations), None, "phase {phase}");
+    }
+}
+
+#[test]
+fn a_straight_path_never_engages() {
+    let line = sample(60, |t| (100.0 + 150.0 * t, 200.0 + 20.0 * t));
+    assert_eq!(first_engaged(&track(&line)), None);
+}
+
+#[test]
+fn straightening_out_ends_the_turn() {
+    // A lap and a half, then off along the tangent at the same speed.
+    let mut points = circle(45, false);
+    let speed = TAU * SPAN / 1.5 / FPS;
+    let (x, y) = *points.last().unwrap();
+    let angle = TAU * 44.0 / 45.0;
+    let tangent = (-angle.sin(), angle.cos());
+    points.extend((1..=30).map(|k| {
+        let k = k as f32 * speed;
+        (x + tangent.0 * k, y + tangent.1 * k)
+    }));
+
+    let rotations = track(&points);
+    assert!(rotations[44].is_some());
+    assert!(rotations[74].is_none());
+    let ended = rotations[45..].iter().position(Option::is_none).unwrap() + 45;
+    assert!(ended < 70, "still turning on frame {ended}");
+}
+
+#[test]
+fn leaving_the_pose_starts_over() {
+    let points = circle(60, false);
+    let mut tracker = RotationTracker::default();
+    let start = Instant::now();
+    let at = |i: usize| start + Duration::from_secs_f32(i as f32 / FPS);
+    for (i, &point) in points.iter().enumerate().take(30) {
+        tracker.update(point, SPAN, at(i), true);
+    }
+    assert!(tracker.update(points[30], SPAN, at(30), true).is_some());
+    assert!(tracker.update(points[31], SPAN, at(31), false).is_none());
+    // The path has to build up again before it re-engages.
+    assert!(tracker.update(points[32], SPAN, at(32), true).is_none());
+}
@@ -0,0 +1,127 @@
+use std::time::{Duration, Instant};
+
+use gesture_universe::{
+    gesture::SequenceMatcher,
+    types::{GestureEvent, GestureKind, GestureSequence},
+};
+
+use GestureKind::{Fist, Palm, Peace, Unknown};
+
+fn sequence(name: &str, steps: &[GestureKind]) -> GestureSequence {
+    GestureSequence {
+        name: name.to_string(),
+        steps: steps.to_vec(),
+        ..GestureSequence::default()
+    }
+}
+
+/// Starts each gesture at its offset in milliseconds and returns the names of
+/// the sequences matched along the way. Unknown stands for a frame where no
+/// gesture was recognized, which starts nothing.
+fn play(matcher: &mut SequenceMatcher, starts: &[(u64, GestureKind)]) -> Vec<String> {
+    let origin = Instant::now();
+    starts
+        .iter()
+        .flat_map(|&(ms, kind)| {
+            let at = origin + Duration::from_millis(ms);
+            let started = (kind != Unknown).then_some(GestureEvent::Started(kind));
+            matcher.observe(Some(kind), started.as_slice(), at)
+        })
+        .map(|event| match event {
+            GestureEvent::SequenceMatched(name) => name,
+            other => panic!("unexpected {other:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn sequences_sharing_a_prefix_each_match_their_own_ending() {
+    let sequences = vec![
+        sequence("grab", &[Fist, Palm, Fist]),
+        sequence("wave", &[Fist, Palm, Peace]),
+    ];
+    let mut matcher = SequenceMatcher::new(sequences.clone());
+    assert_eq!(
+        play(&mut matcher, &[(0, Fist), (400, Palm), (800, Peace)]),
+        ["wave"]
+    );
+    let mut matcher = SequenceMatcher::new(sequences);
+    assert_eq!(
+        play(&mut matcher, &[(0, Fist), (400, Palm), (800, Fist)]),
+        ["grab"]
+    );
+}
+
+#[test]
+fn a_match_uses_up_every_attempt_in_progress() {
+    let mut matcher = SequenceMatcher::new(vec![sequence("grab", &[Fist, Palm, Fist])]);
+    let starts = [
+        (0, Fist),
+        (300, Palm),
+        (600, Fist),
+        (900, Palm),
+        (1200, Fist),
+    ];
+    // The closing fist does not double as the first step of another grab.
+    assert_eq!(play(&mut matcher, &starts), ["grab"]);
+
+    let mut matcher = SequenceMatcher::new(vec![sequence("grab", &[Fist, Palm, Fist])]);
+    let again = [
+        (0, Fist),
+        (300, Palm),
+        (600, Fist),
+        (900, Fist),
+        (1200, Palm),
+        (1500, Fist),
+    ];
+    assert_eq!(play(&mut matcher, &again), ["grab", "grab"]);
+}
+
+#[test]
+fn the_timeout_expires_mid_sequence() {
+    let mut slow = sequence("grab", &[Fist, Palm, Fist]);
+    // Loose enough that only the overall timeout decides.
+    slow.max_gap_ms = 1_500;
+    let mut matcher = SequenceMatcher::new(vec![slow.clone()]);
+    assert_eq!(
+        play(&mut matcher, &[(0, Fist), (1_000, Palm), (2_100, Fist)]),
+        Vec::<String>::new()
+    );
+    let mut matcher = SequenceMatcher::new(vec![slow]);
+    assert_eq!(
+        play(&mut matcher, &[(0, Fist), (1_000, Palm), (1_900, Fist)]),
+        ["grab"]
+    );
+}
+
+#[test]
+fn a_long_gap_between_steps_starts_over() {
+    let mut matcher = SequenceMatcher::new(vec![sequence("grab", &[Fist, Palm, Fist])]);
+    assert_eq!(
+        play(&mut matcher, &[(0, Fist), (1_200, Palm), (1_500, Fist)]),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn an_unexpected_gesture_in_between_drops_the_attempt() {
+    let mut matcher = SequenceMatcher::new(vec![sequence("grab", &[Fist, Palm, Fist])]);
+    let starts = [(0, Fist), (300, Palm), (600, Peace), (900, Fist)];
+    assert_eq!(play(&mut matcher, &starts), Vec::<String>::new());
+}
+
+#[test]
+fn unknown_frames_break_a_sequence_only_when_not_ignored() {
+    let starts = [(0, Fist), (300, Palm), (500, Unknown), (700, Fist)];
+    for (ignore_unknown, expected) in [(true, vec!["grab"]), (false, vec![])] {
+        let mut matcher = SequenceMatcher::new(vec![GestureSequence {
+            ignore_unknown,
+            ..sequence("grab", &[Fist, Palm, Fist])
+        }]);
+        assert_eq!(
+            play(&mut matcher, &starts),
+            expected,
+            "ignore_unknown {ignore_unknown}"
+        );
+    }
+}
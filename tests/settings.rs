@@ -0,0 +1,199 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use gesture_universe::{
+    config::{AppSettings, CameraFormatSetting, MiniOverlaySettings, OverlayCorner},
+    locale::Locale,
+    mqtt_output::MqttConfig,
+    osc_output::OscConfig,
+    pipeline::{
+        CameraKnob, ClipFormat, HandposeOutputNames, OutputSize, PhotoBoothConfig,
+        recorder::RecorderSettings, skeleton::OverlayMode,
+    },
+    types::{
+        DwellConfig, GestureKind, GestureSequence, LabelStabilizerConfig, LandmarkNormalization,
+        MotionGateConfig, QualityGateConfig, SmoothingConfig,
+    },
+};
+
+/// A settings file holding `text`, in a directory of its own.
+fn settings_file(name: &str, text: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("gu-settings-{}-{name}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("settings.toml");
+    std::fs::write(&path, text).unwrap();
+    path
+}
+
+#[test]
+fn a_corrupt_file_falls_back_to_the_defaults() {
+    for (name, text) in [
+        ("truncated", "camera_label = \"FaceTime"),
+        ("not_toml", r#"{"camera_label": "FaceTime"}"#),
+        ("wrong_type", "mirror = \"yes\""),
+    ] {
+        let path = settings_file(name, text);
+        assert!(AppSettings::load(&path).is_err(), "{name}");
+        assert_eq!(
+            AppSettings::load_or_default(&path),
+            AppSettings::default(),
+            "{name}"
+        );
+        // Kept aside before the first save can replace it.
+        let backup = path.with_extension("toml.bak");
+        assert_eq!(std::fs::read_to_string(&backup).unwrap(), text, "{name}");
+    }
+}
+
+#[test]
+fn a_bad_key_is_dropped_alone_and_the_rest_survives_a_save() {
+    let text = "mirror = \"yes\"\ndetection_threshold = 0.4\n\n[from_a_newer_version]\nlevel = 3\n";
+    let path = settings_file("bad_key", text);
+    let mut settings = AppSettings::load_or_default(&path);
+    assert_eq!(settings.mirror, None);
+    assert_eq!(settings.detection_threshold, Some(0.4));
+    assert!(settings.extra.contains_key("from_a_newer_version"));
+
+    settings.camera_label = Some("FaceTime HD".into());
+    settings.save(&path).unwrap();
+    let saved = AppSettings::load(&path).unwrap();
+    assert_eq!(saved, settings);
+    assert_eq!(
+        saved.extra["from_a_newer_version"]["level"].as_integer(),
+        Some(3)
+    );
+
+    // Another bad file is backed up next to the first instead of over it,
+    // and loading it again does not pile up copies.
+    let other = text.replace("0.4", "0.5");
+    std::fs::write(&path, &other).unwrap();
+    AppSettings::load_or_default(&path);
+    AppSettings::load_or_default(&path);
+    let backup = |suffix: &str| std::fs::read_to_string(path.with_extension(suffix)).ok();
+    assert_eq!(backup("toml.bak").as_deref(), Some(text));
+    assert_eq!(backup("toml.bak.1"), Some(other));
+    assert_eq!(backup("toml.bak.2"), None);
+}
+
+#[test]
+fn a_missing_file_is_the_defaults() {
+    let path = std::env::temp_dir().join("gu-settings-missing/settings.toml");
+    assert_eq!(AppSettings::load_or_default(&path), AppSettings::default());
+}
+
+#[test]
+fn a_partial_file_keeps_the_defaults_for_the_rest() {
+    let path = settings_file("partial", "mirror = false\ndetection_threshold = 0.4\n");
+    let settings = AppSettings::load_or_default(&path);
+    assert_eq!(settings.mirror, Some(false));
+    assert_eq!(settings.detection_threshold, Some(0.4));
+    assert_eq!(
+        AppSettings {
+            mirror: None,
+            detection_threshold: None,
+            ..settings
+        },
+        AppSettings::default()
+    );
+}
+
+#[test]
+fn unknown_keys_survive_a_rewrite() {
+    let path = settings_file(
+        "unknown",
+        "mirror = true\n\n[from_a_newer_version]\nlevel = 3\ntags = [\"a\", \"b\"]\n",
+    );
+    let mut settings = AppSettings::load_or_default(&path);
+    let newer: toml::Value = "level = 3\ntags = [\"a\", \"b\"]"
+        .parse::<toml::Table>()
+        .unwrap()
+        .into();
+    assert_eq!(settings.extra.get("from_a_newer_version"), Some(&newer));
+
+    settings.camera_label = Some("FaceTime HD".into());
+    settings.save(&path).unwrap();
+    let written: toml::Table = std::fs::read_to_string(&path).unwrap().parse().unwrap();
+    assert_eq!(written["from_a_newer_version"], newer);
+    assert_eq!(written["camera_label"].as_str(), Some("FaceTime HD"));
+    assert_eq!(AppSettings::load(&path).unwrap(), settings);
+}
+
+#[test]
+fn every_setting_survives_a_round_trip() {
+    let mut extra = toml::Table::new();
+    extra.insert("from_a_newer_version".into(), toml::Value::Integer(3));
+    let settings = AppSettings {
+        camera_label: Some("FaceTime HD".into()),
+        camera_index: Some(1),
+        camera_format: Some(CameraFormatSetting {
+            width: 1920,
+            height: 1080,
+            fps: 30,
+            format: "MJPEG".into(),
+        }),
+        mirror: Some(false),
+        camera_controls: Some(BTreeMap::from([(
+            "FaceTime HD".into(),
+            BTreeMap::from([(CameraKnob::Exposure, -4), (CameraKnob::Gain, 12)]),
+        )])),
+        detection_threshold: Some(0.4),
+        classification_threshold: Some(0.6),
+        overlay_threshold: Some(0.3),
+        pinch_threshold: Some(0.05),
+        palm_score_threshold: Some(0.55),
+        max_input_dimension: Some(480),
+        inference_budget_ms: Some(25),
+        palm_detect_interval: Some(3),
+        track_max_misses: Some(4),
+        roi: Some([0.1, 0.2, 0.9, 0.8]),
+        depth_overlay: Some(true),
+        count_half_bent: Some(true),
+        normalization: Some(LandmarkNormalization::BoundingBox),
+        skeleton_style: Some("per_finger".into()),
+        overlay_mode: Some(OverlayMode::Debug),
+        handpose_model_path: Some("/opt/models/handpose.onnx".into()),
+        palm_detector_model_path: Some("/opt/models/palm.onnx".into()),
+        model_mirror: Some("file:///mnt/share/models".into()),
+        handpose_outputs: Some(HandposeOutputNames::default()),
+        execution_provider: Some("cpu".into()),
+        dwell: Some(DwellConfig::default()),
+        smoothing: Some(SmoothingConfig::default()),
+        quality_gate: Some(QualityGateConfig::default()),
+        motion_gate: Some(MotionGateConfig::default()),
+        sequences: Some(vec![GestureSequence {
+            name: "unlock".into(),
+            ..GestureSequence::default()
+        }]),
+        enabled_gestures: Some(vec![GestureKind::Palm, GestureKind::Fist]),
+        censor_gestures: Some(vec![GestureKind::MiddleFinger]),
+        burn_in_label: Some(true),
+        label_font_path: Some("/usr/share/fonts/noto.ttc".into()),
+        pointing_ray: Some(true),
+        privacy_mode: Some(false),
+        label_stabilizer: Some(LabelStabilizerConfig::default()),
+        output_size: Some(OutputSize::Fit720p),
+        clip_format: Some(ClipFormat::default()),
+        captures_dir: Some("/tmp/captures".into()),
+        recording: Some(RecorderSettings {
+            root: "/tmp/recordings".into(),
+            max_fps: 15.0,
+            max_bytes: 512 * 1024 * 1024,
+        }),
+        photo_booth: Some(PhotoBoothConfig::default()),
+        virtual_camera_device: Some("/dev/video11".into()),
+        mqtt: Some(MqttConfig::default()),
+        osc: Some(OscConfig::default()),
+        mini_overlay: Some(MiniOverlaySettings {
+            open: false,
+            corner: OverlayCorner::BottomLeft,
+            display: Some("3f2a".into()),
+            offset: Some([40.0, 60.0]),
+        }),
+        gesture_analytics: Some(true),
+        locale: Some(Locale::En),
+        extra,
+    };
+
+    let path = settings_file("round_trip", "");
+    settings.save(&path).unwrap();
+    assert_eq!(AppSettings::load(&path).unwrap(), settings);
+}
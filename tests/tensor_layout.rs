@@ -0,0 +1,137 @@
+use std::path::Path;
+
+use gesture_universe::pipeline::recognizer::{
+    ExecutionProvider,
+    common::{self, HandposeOutputMap, HandposeOutputNames, TensorLayout},
+};
+use ndarray::{Array4, Axis};
+use ort::{session::Session, value::Tensor};
+
+/// The stand-ins from scripts/make_layout_fixtures.py: a (batch, 3, 4, 4)
+/// input, every landmark the per-channel mean, the score the overall mean.
+fn fixture(name: &str) -> Session {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join(name);
+    common::build_session(&path, ExecutionProvider::Cpu)
+        .unwrap()
+        .0
+}
+
+/// A 4×4 NHWC crop whose channels hold `rgb`, as the prepare functions build it.
+fn crop(rgb: [f32; 3]) -> Array4<f32> {
+    Array4::from_shape_fn((1, 4, 4, 3), |(_, _, _, c)| rgb[c])
+}
+
+/// Runs `crops` through `session` the way the handpose engine does and
+/// returns each crop's landmarks and score.
+fn run(session: &mut Session, crops: &[Array4<f32>]) -> Vec<(Vec<[f32; 3]>, f32)> {
+    let layout = TensorLayout::of_session(session);
+    let map = HandposeOutputMap::of_session(session, &HandposeOutputNames::default()).unwrap();
+    let views: Vec<_> = crops.iter().map(Array4::view).collect();
+    let batch = ndarray::concatenate(Axis(0), &views).unwrap();
+    let input = Tensor::from_array(layout.arrange(batch)).unwrap();
+    let outputs = session.run(ort::inputs![input]).unwrap();
+
+    let landmarks = outputs[map.landmarks].try_extract_array::<f32>().unwrap();
+    assert_eq!(landmarks.shape(), [crops.len(), 63]);
+    let scores = common::output_batch_scalars(&outputs, map.confidence, crops.len());
+    landmarks
+        .outer_iter()
+        .zip(scores)
+        .map(|(row, score)| {
+            let flat: Vec<f32> = row.iter().copied().collect();
+            (common::decode_landmarks(&flat).unwrap(), score)
+        })
+        .collect()
+}
+
+fn assert_close(got: f32, want: f32) {
+    assert!((got - want).abs() < 1e-5, "got {got}, want {want}");
+}
+
+#[test]
+fn channels_at_dim_one_mean_nchw() {
+    assert_eq!(
+        TensorLayout::of_shape(&[1, 3, 224, 224]),
+        TensorLayout::Nchw
+    );
+    assert_eq!(
+        TensorLayout::of_shape(&[-1, 3, 224, 224]),
+        TensorLayout::Nchw
+    );
+    assert_eq!(
+        TensorLayout::of_shape(&[1, 224, 224, 3]),
+        TensorLayout::Nhwc
+    );
+    // Fully dynamic but for the channels, as tf2onnx exports.
+    assert_eq!(TensorLayout::of_shape(&[-1, -1, -1, 3]), TensorLayout::Nhwc);
+    // A 3×3 image is ambiguous and stays NHWC.
+    assert_eq!(TensorLayout::of_shape(&[1, 3, 3, 3]), TensorLayout::Nhwc);
+    assert_eq!(TensorLayout::of_shape(&[1, 63]), TensorLayout::Nhwc);
+}
+
+#[test]
+fn nchw_moves_each_value_to_its_channel_plane() {
+    let (height, width) = (2, 5);
+    // Each value encodes where it came from.
+    let nhwc = Array4::from_shape_fn((1, height, width, 3), |(_, y, x, c)| {
+        (y * 100 + x * 10 + c) as f32
+    });
+
+    assert_eq!(TensorLayout::Nhwc.arrange(nhwc.clone()), nhwc);
+    let nchw = TensorLayout::Nchw.arrange(nhwc);
+    assert_eq!(nchw.shape(), [1, 3, height, width]);
+    assert!(nchw.is_standard_layout());
+    for ((_, c, y, x), value) in nchw.indexed_iter() {
+        assert_eq!(*value, (y * 100 + x * 10 + c) as f32);
+    }
+    // Memory order is whole planes, red first.
+    let flat = nchw.as_slice().unwrap();
+    assert_eq!(flat[..3], [0.0, 10.0, 20.0]);
+    assert_eq!(flat[height * width], 1.0);
+}
+
+#[test]
+fn a_channels_first_model_sees_each_channel_in_its_own_plane() {
+    let mut session = fixture("nchw_fixed_batch.onnx");
+    assert_eq!(TensorLayout::of_session(&session), TensorLayout::Nchw);
+    assert!(!common::has_dynamic_batch(&session));
+
+    let hands = run(&mut session, &[crop([0.1, 0.5, 0.9])]);
+    assert_eq!(hands.len(), 1);
+    let (landmarks, score) = &hands[0];
+    assert_eq!(landmarks.len(), 21);
+    for point in landmarks {
+        for (got, want) in point.iter().zip([0.1, 0.5, 0.9]) {
+            assert_close(*got, want);
+        }
+    }
+    assert_close(*score, 0.5);
+}
+
+#[test]
+fn a_dynamic_batch_runs_one_crop_or_several() {
+    let mut session = fixture("nchw_dynamic_batch.onnx");
+    assert_eq!(TensorLayout::of_session(&session), TensorLayout::Nchw);
+    assert!(common::has_dynamic_batch(&session));
+    let map = HandposeOutputMap::of_session(&session, &HandposeOutputNames::default()).unwrap();
+    assert_eq!(
+        (map.landmarks, map.confidence, map.handedness),
+        (0, Some(1), Some(2))
+    );
+
+    let single = run(&mut session, &[crop([0.3, 0.3, 0.6])]);
+    assert_eq!(single.len(), 1);
+    assert_close(single[0].1, 0.4);
+
+    let pair = run(
+        &mut session,
+        &[crop([0.0, 0.0, 0.3]), crop([0.6, 0.9, 0.9])],
+    );
+    assert_eq!(pair.len(), 2);
+    assert_close(pair[0].0[20][2], 0.3);
+    assert_close(pair[1].0[0][0], 0.6);
+    assert_close(pair[0].1, 0.1);
+    assert_close(pair[1].1, 0.8);
+}
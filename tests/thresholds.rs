@@ -0,0 +1,90 @@
+use std::{path::Path, time::Instant};
+
+use gesture_universe::{
+    gesture::GestureClassifier,
+    pipeline::recognizer::{
+        ExecutionProvider, HandposeEngine, PalmDetectorConfig, RecognizerError,
+        common::{HandLandmarks, HandposeOutput},
+        recognize_frame,
+    },
+    trace::{TraceFrame, read_jsonl},
+    types::{Frame, GestureResult, PixelLayout, RecognizerConfig},
+};
+
+/// Below the default cut-offs of 0.2.
+const WEAK: f32 = 0.15;
+
+/// Reports the first hand of a trace at a fixed confidence for every frame.
+struct WeakHand(TraceFrame);
+
+impl HandposeEngine for WeakHand {
+    fn infer(&mut self, _: &Frame) -> Result<HandposeOutput, RecognizerError> {
+        let mut output = HandposeOutput::skipped();
+        output.hands.push(HandLandmarks {
+            track_id: 1,
+            raw_landmarks: self.0.raw_landmarks.clone(),
+            projected_landmarks: self.0.projected_landmarks.clone(),
+            confidence: WEAK,
+            handedness: self.0.handedness,
+        });
+        Ok(output)
+    }
+
+    fn set_execution_provider(&mut self, _: ExecutionProvider) -> Result<(), RecognizerError> {
+        Ok(())
+    }
+
+    fn set_palm_config(&mut self, _: PalmDetectorConfig) {}
+
+    fn reload_models(&mut self) -> Result<(), RecognizerError> {
+        Ok(())
+    }
+}
+
+fn recognize(config: RecognizerConfig) -> GestureResult {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/fist_to_palm.jsonl");
+    let frames: Vec<TraceFrame> = read_jsonl(&path).unwrap();
+    let mut engine = WeakHand(frames.into_iter().next().expect("trace has frames"));
+    let now = Instant::now();
+    let frame = Frame {
+        pixels: vec![0; PixelLayout::Rgb.buffer_len(640, 480)].into(),
+        layout: PixelLayout::Rgb,
+        width: 640,
+        height: 480,
+        timestamp: now,
+        acquired_at: now,
+    };
+    let mut classifier = GestureClassifier::rules_only(&config);
+    recognize_frame(&mut engine, &mut classifier, &frame, &config).unwrap()
+}
+
+#[test]
+fn a_weak_hand_is_dropped_at_the_default_thresholds() {
+    let result = recognize(RecognizerConfig::default());
+    assert!(result.hands.is_empty());
+    assert!(result.detail.is_none());
+    // The best score is still reported, so the UI can show how close it was.
+    assert_eq!(result.confidence, WEAK);
+}
+
+#[test]
+fn lowering_the_detection_threshold_reports_the_hand_unclassified() {
+    let result = recognize(RecognizerConfig {
+        detection_threshold: 0.1,
+        ..RecognizerConfig::default()
+    });
+    assert_eq!(result.hands.len(), 1);
+    assert!(result.landmarks.is_some());
+    assert!(result.detail.is_none());
+}
+
+#[test]
+fn lowering_both_thresholds_classifies_the_hand() {
+    let result = recognize(RecognizerConfig {
+        detection_threshold: 0.1,
+        classification_threshold: 0.1,
+        ..RecognizerConfig::default()
+    });
+    assert_eq!(result.hands.len(), 1);
+    assert!(result.detail.is_some());
+}
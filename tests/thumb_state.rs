@@ -0,0 +1,119 @@
+use std::{path::Path, time::Instant};
+
+use gesture_universe::{
+    gesture::GestureClassifier,
+    trace::{TraceFrame, read_jsonl},
+    types::{FingerState, GestureDetail, GestureKind, RecognizerConfig},
+};
+
+fn first_frame(name: &str) -> TraceFrame {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("testdata/{name}.jsonl"));
+    let frames: Vec<TraceFrame> = read_jsonl(&path).unwrap();
+    frames.into_iter().next().expect("trace has frames")
+}
+
+fn classify(frame: &TraceFrame) -> GestureDetail {
+    GestureClassifier::rules_only(&RecognizerConfig::default())
+        .classify(
+            1,
+            &frame.raw_landmarks,
+            &frame.projected_landmarks,
+            frame.confidence,
+            frame.handedness,
+            Instant::now(),
+        )
+        .expect("hand is confident enough to classify")
+}
+
+/// Turns the crop landmarks `yaw_deg` about the vertical axis through the
+/// wrist. The projected landmarks are left alone, so only the depth changes.
+fn turn(frame: &mut TraceFrame, yaw_deg: f32) {
+    let (sin, cos) = yaw_deg.to_radians().sin_cos();
+    let [wx, _, wz] = frame.raw_landmarks[0];
+    for [x, _, z] in &mut frame.raw_landmarks {
+        let (dx, dz) = (*x - wx, *z - wz);
+        (*x, *z) = (wx + dx * cos - dz * sin, wz + dx * sin + dz * cos);
+    }
+}
+
+#[test]
+fn a_side_on_thumbs_up_keeps_its_thumb_extended() {
+    // The trace's hand has its palm towards the camera, smaller z. Seen side
+    // on, the model places the thumb's IP joint well behind the palm, which
+    // bends the thumb in 3D even though it points straight up.
+    let mut frame = first_frame("thumbs_up");
+    frame.raw_landmarks[3][2] += 30.0;
+    turn(&mut frame, 80.0);
+
+    let detail = classify(&frame);
+    assert_eq!(detail.finger_states[0], FingerState::Extended);
+    assert_eq!(detail.primary, GestureKind::Like);
+}
+
+#[test]
+fn a_thumb_tucked_across_a_fist_is_folded() {
+    // The thumb lies across the folded fingers in front of the palm, its tip
+    // past the middle knuckle. In 3D that is far from both edge knuckles and
+    // well clear of the wrist, like a thumb held out.
+    let mut frame = first_frame("fist_to_palm");
+    frame.raw_landmarks[3] = [122.0, 137.0, -19.2];
+    frame.raw_landmarks[4] = [92.0, 128.0, -32.0];
+
+    let detail = classify(&frame);
+    assert_eq!(detail.finger_states[0], FingerState::Folded);
+    assert_eq!(detail.primary, GestureKind::Fist);
+    let opposition = detail.finger_angles[0]
+        .opposition
+        .expect("thumb opposition");
+    assert!(opposition > 0.3, "{opposition}");
+    assert_eq!(detail.finger_angles[1].opposition, None);
+}
+
+/// Turns the crop landmarks `degrees` in the image plane about the wrist, as a
+/// crop cut at another angle would see the same hand.
+fn roll_crop(frame: &mut TraceFrame, degrees: f32) {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    let [wx, wy, _] = frame.raw_landmarks[0];
+    for [x, y, _] in &mut frame.raw_landmarks {
+        let (dx, dy) = (*x - wx, *y - wy);
+        (*x, *y) = (wx + dx * cos - dy * sin, wy + dx * sin + dy * cos);
+    }
+}
+
+/// Turns the image landmarks `degrees` about the wrist, as if the whole hand
+/// had turned in front of the camera.
+fn roll_image(frame: &mut TraceFrame, degrees: f32) {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    let (wx, wy) = frame.projected_landmarks[0];
+    for (x, y) in &mut frame.projected_landmarks {
+        let (dx, dy) = (*x - wx, *y - wy);
+        (*x, *y) = (wx + dx * cos - dy * sin, wy + dx * sin + dy * cos);
+    }
+}
+
+#[test]
+fn thumb_direction_does_not_depend_on_the_crop_angle() {
+    for degrees in [90.0, 180.0, 270.0] {
+        let mut frame = first_frame("thumbs_up");
+        roll_crop(&mut frame, degrees);
+        assert_eq!(classify(&frame).primary, GestureKind::Like, "{degrees}°");
+    }
+}
+
+#[test]
+fn thumb_direction_follows_the_image() {
+    let mut upside_down = first_frame("thumbs_up");
+    roll_crop(&mut upside_down, 180.0);
+    roll_image(&mut upside_down, 180.0);
+    assert_eq!(classify(&upside_down).primary, GestureKind::Dislike);
+
+    // Pointing sideways is neither up nor down.
+    let mut sideways = first_frame("thumbs_up");
+    roll_crop(&mut sideways, 90.0);
+    roll_image(&mut sideways, 90.0);
+    let primary = classify(&sideways).primary;
+    assert!(
+        !matches!(primary, GestureKind::Like | GestureKind::Dislike),
+        "{primary:?}"
+    );
+}